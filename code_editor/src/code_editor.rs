@@ -275,8 +275,14 @@ impl CodeEditor {
                 session.move_down(!shift);
                 cx.redraw_all();
             }
-            Hit::TextInput(TextInputEvent { ref input, .. }) if input.len()>0 => {
-                session.insert(input.into());
+            Hit::TextInput(TextInputEvent { ref input, replace_last, .. }) if input.len()>0 || replace_last => {
+                // an input method updating its composition replaces what it sent before
+                if replace_last {
+                    session.revert_last_edit();
+                }
+                if input.len() > 0 {
+                    session.insert(input.into());
+                }
                 cx.redraw_all();
                 dispatch_action(cx, CodeEditorAction::TextDidChange);
             }
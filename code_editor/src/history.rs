@@ -3,6 +3,7 @@ use crate::{state::SessionId, Change, Selection, Text};
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct History {
     current_edit: Option<(SessionId, EditKind)>,
+    last_edit_len: Option<usize>,
     undos: Vec<(Vec<Selection>, Vec<Change>)>,
     redos: Vec<(Vec<Selection>, Vec<Change>)>,
 }
@@ -23,6 +24,7 @@ impl History {
         selections: &[Selection],
         inverted_changes: Vec<Change>,
     ) {
+        self.last_edit_len = Some(inverted_changes.len());
         if self
             .current_edit
             .map_or(false, |(current_origin_id, current_kind)| current_origin_id == origin_id && current_kind.can_merge(kind))
@@ -35,7 +37,25 @@ impl History {
         self.redos.clear();
     }
 
+    /// Reverts only the most recent edit, even if it was merged into a larger group. Used to
+    /// replace in-progress input method compositions, so it doesn't touch the redo stack.
+    pub fn revert_last_edit(&mut self, text: &mut Text) -> Option<Vec<Change>> {
+        let len = self.last_edit_len.take()?;
+        let (_, group) = self.undos.last_mut()?;
+        let mut inverted_changes = group.split_off(group.len() - len);
+        if group.is_empty() {
+            self.undos.pop();
+            self.current_edit = None;
+        }
+        inverted_changes.reverse();
+        for inverted_change in inverted_changes.iter().cloned() {
+            text.apply_change(inverted_change);
+        }
+        Some(inverted_changes)
+    }
+
     pub fn undo(&mut self, text: &mut Text) -> Option<(Vec<Selection>, Vec<Change>)> {
+        self.last_edit_len = None;
         if let Some((selections, mut inverted_changes)) = self.undos.pop() {
             self.current_edit = None;
             let mut changes = Vec::new();
@@ -54,6 +74,7 @@ impl History {
     }
 
     pub fn redo(&mut self, text: &mut Text) -> Option<(Vec<Selection>, Vec<Change>)> {
+        self.last_edit_len = None;
         if let Some((selections, changes)) = self.redos.pop() {
             self.current_edit = None;
             let mut inverted_changes = Vec::new();
@@ -573,6 +573,10 @@ impl Session {
         self.document.borrow_mut().redo(self.id)
     }
 
+    pub fn revert_last_edit(&mut self) -> bool {
+        self.document.borrow_mut().revert_last_edit(self.id)
+    }
+
     fn update_y(&mut self) {
         let start = self.y.len();
         let end = self.document.borrow().text.as_lines().len();
@@ -1153,6 +1157,15 @@ impl Document {
         }
     }
 
+    fn revert_last_edit(&mut self, origin_id: SessionId) -> bool {
+        if let Some(changes) = self.history.revert_last_edit(&mut self.text) {
+            self.apply_changes(origin_id, None, &changes);
+            true
+        } else {
+            false
+        }
+    }
+
     fn redo(&mut self, origin_id: SessionId) -> bool {
         if let Some((selections, changes)) = self.history.redo(&mut self.text) {
             self.apply_changes(origin_id, Some(selections), &changes);
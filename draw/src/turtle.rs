@@ -5,7 +5,7 @@ use {
     }
 };
 
-#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub struct Layout {
     #[live] pub scroll: DVec2,
@@ -31,7 +31,7 @@ impl Default for Layout{
     }
 }
 
-#[derive(Copy, Clone, Default, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Default, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub struct Walk {
     #[live] pub abs_pos: Option<DVec2>,
@@ -40,14 +40,14 @@ pub struct Walk {
     #[live] pub height: Size,
 }
 
-#[derive(Clone, Copy, Default, Debug, Live, LiveHook)]
+#[derive(Clone, Copy, Default, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub struct Align {
     #[live] pub x: f64,
     #[live] pub y: f64
 }
 
-#[derive(Clone, Copy, Default, Debug, Live)]
+#[derive(Clone, Copy, Default, Debug, Live, LiveRead)]
 #[live_ignore]
 pub struct Padding {
     #[live] pub left: f64,
@@ -56,7 +56,7 @@ pub struct Padding {
     #[live] pub bottom: f64
 }
 
-#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum Axis {
     #[pick] Horizontal,
@@ -69,7 +69,7 @@ impl Default for Axis {
     }
 }
 
-#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum Flow {
     #[pick] Right,
@@ -77,7 +77,7 @@ pub enum Flow {
     Overlay
 }
 
-#[derive(Copy, Clone, Debug, Live)]
+#[derive(Copy, Clone, Debug, Live, LiveRead)]
 #[live_ignore]
 pub enum Size {
     #[pick] Fill,
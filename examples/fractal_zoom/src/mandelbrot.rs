@@ -153,6 +153,8 @@ impl TileCache {
                 format: TextureFormat::ImageBGRA,
                 width: Some(TILE_SIZE_X),
                 height: Some(TILE_SIZE_Y),
+                mipmaps: true,
+                ..Default::default()
             });
             textures.push(texture);
         }
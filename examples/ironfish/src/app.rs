@@ -39,7 +39,12 @@ live_design!{
             window: {inner_size: vec2(1280, 1000)},
             pass: {clear_color: #2A}
             block_signal_event: true; 
-            <AppDesktop> {}
+            if os == android || os == ios {
+                <AppMobile> {}
+            }
+            else {
+                <AppDesktop> {}
+            }
         }
         
         /*
@@ -207,6 +207,9 @@ fn derive_live_impl_inner(parser: &mut TokenParser, tb: &mut TokenBuilder) -> Re
             tb.add("        _=> self.apply_value_unknown(cx, apply_from, index, nodes)");
         }
         tb.add("            }");
+        // template args of a definition, instances only see the values they were substituted into
+        tb.add("        } else if nodes[index].origin.has_prop_type(LivePropType::Template){");
+        tb.add("            nodes.skip_node(index)");
         tb.add("        } else {");
         
         if let Some(deref_field) = deref_field {
@@ -42,8 +42,15 @@ fn derive_live_read_impl_inner(parser: &mut TokenParser, tb: &mut TokenBuilder)
         };
         
         for field in &mut fields {
-            if field.attrs.len() == 1 && field.attrs[0].name != "live" && field.attrs[0].name != "calc" && field.attrs[0].name != "rust" {
-                return error_result(&format!("Field {} does not have a live, calc into or rust attribute", field.name));
+            if field.attrs.len() == 1
+                && field.attrs[0].name != "walk"
+                && field.attrs[0].name != "layout"
+                && field.attrs[0].name != "live"
+                && field.attrs[0].name != "calc"
+                && field.attrs[0].name != "animator"
+                && field.attrs[0].name != "rust"
+                && field.attrs[0].name != "deref" {
+                return error_result(&format!("Field {} does not have a live, calc, rust, animator, deref, walk, layout attribute", field.name));
             }
             if field.attrs.is_empty() { // insert a default
                 field.attrs.push(Attribute {name: "live".to_string(), args: None});
@@ -55,12 +62,22 @@ fn derive_live_read_impl_inner(parser: &mut TokenParser, tb: &mut TokenBuilder)
         
         tb.add("    fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>){");
         tb.add("        out.push(LiveNode::from_id_value(id, LiveValue::Object));");
+        tb.add("        self.live_read_fields(out);");
+        tb.add("        out.push(LiveNode::from_id_value(id, LiveValue::Close));");
+        tb.add("    }");
+        
+        tb.add("    fn live_read_fields(&self, out:&mut Vec<LiveNode>){");
         for field in &fields {
-            if field.attrs[0].name == "live"{
+            let attr = &field.attrs[0];
+            if attr.name == "live"{
                 tb.add("self.").ident(&field.name).add(".live_read_to(LiveId(").suf_u64(LiveId::from_str(&field.name).0).add("), out);");
             }
+            // walk and layout are splatted into the struct and a deref base applies its
+            // fields as if they were ours, so read them back the same way
+            else if attr.name == "walk" || attr.name == "layout" || attr.name == "deref"{
+                tb.add("self.").ident(&field.name).add(".live_read_fields(out);");
+            }
         }
-        tb.add("        out.push(LiveNode::from_id_value(id, LiveValue::Close));");
         tb.add("    }");
         
         tb.add("}");
@@ -131,7 +148,7 @@ fn derive_live_read_impl_inner(parser: &mut TokenParser, tb: &mut TokenBuilder)
                     tb.add("            LiveId(").suf_u64(LiveId::from_str(&item.name).0).add(")");
                     tb.add("        )));");
                     for field in fields {
-                        tb.ident(&format!("prefix_{}", field.name)).add(".live_read_to(LiveId(").suf_u64(LiveId::from_str(&field.name).0).add(", out);");
+                        tb.ident(&format!("prefix_{}", field.name)).add(".live_read_to(LiveId(").suf_u64(LiveId::from_str(&field.name).0).add("), out);");
                     }
                     tb.add("        out.push(LiveNode::from_id_value(id, LiveValue::Close));");
                     tb.add("    },");
@@ -16,6 +16,10 @@ pub mod live_component;
 pub mod live_node_cbor;
 //pub mod live_node_cbor;
 pub mod live_node_reader;
+pub mod live_node_writer;
+pub mod live_diagnostics;
+pub mod live_localization;
+pub mod live_rust_types;
 
 pub use makepad_math;
 pub use makepad_derive_live;
@@ -68,6 +72,9 @@ pub use {
         live_node_reader::{
             LiveNodeReader,
         },
+        live_node_writer::{
+            LiveNodeSliceToLiveDesign,
+        },
         live_node::{
             LiveProp,
             LiveIdAsProp,
@@ -98,6 +105,18 @@ pub use {
             LiveError,
             LiveFileError
         },
+        live_diagnostics::{
+            LiveDiagnostic,
+            LiveDiagnosticStage,
+            LiveSeverity,
+            nearest_name
+        },
+        live_localization::{
+            LiveLocalization,
+            LiveCatalog,
+            LiveCatalogError,
+            LivePluralCategory
+        },
         live_document::{LiveOriginal, LiveExpanded}
     }
 };
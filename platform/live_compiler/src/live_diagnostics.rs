@@ -0,0 +1,136 @@
+use {
+    std::fmt,
+    crate::{
+        makepad_micro_serde::*,
+        live_error::LiveFileError,
+    }
+};
+
+// structured form of the errors the live system logs, so tools and CI can collect them
+// instead of scraping the log. Lines and columns are 1 based like the log output
+
+#[derive(Clone, Copy, Debug, PartialEq, SerJson, DeJson)]
+pub enum LiveSeverity {
+    Error,
+    Warning
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, SerJson, DeJson)]
+pub enum LiveDiagnosticStage {
+    Parse,
+    Expand,
+    Apply,
+    Shader
+}
+
+#[derive(Clone, Debug, PartialEq, SerJson, DeJson)]
+pub struct LiveDiagnostic {
+    pub severity: LiveSeverity,
+    pub stage: LiveDiagnosticStage,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub message: String,
+    pub suggestion: Option<String>,
+    // the rust source location that raised it
+    pub origin: String,
+}
+
+impl LiveDiagnostic {
+    // prefix of the lines an app started with --live-check writes to stdout
+    pub const LIVE_CHECK_PREFIX: &'static str = "live_check: ";
+
+    pub fn from_live_file_error(severity: LiveSeverity, stage: LiveDiagnosticStage, err: LiveFileError) -> Self {
+        Self {
+            severity,
+            stage,
+            file: err.file,
+            line: err.span.start.line + 1,
+            column: err.span.start.column + 1,
+            message: err.message,
+            suggestion: None,
+            origin: err.origin.to_string().trim_end().to_string()
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == LiveSeverity::Error
+    }
+
+    pub fn to_live_check_line(&self) -> String {
+        format!("{}{}", Self::LIVE_CHECK_PREFIX, self.serialize_json())
+    }
+
+    pub fn from_live_check_line(line: &str) -> Option<Self> {
+        let json = line.strip_prefix(Self::LIVE_CHECK_PREFIX) ?;
+        Self::deserialize_json(json).ok()
+    }
+}
+
+impl fmt::Display for LiveDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            LiveSeverity::Error => "error",
+            LiveSeverity::Warning => "warning"
+        };
+        let stage = match self.stage {
+            LiveDiagnosticStage::Parse => "parse",
+            LiveDiagnosticStage::Expand => "expand",
+            LiveDiagnosticStage::Apply => "apply",
+            LiveDiagnosticStage::Shader => "shader"
+        };
+        write!(f, "{}:{}:{}: {}[{}]: {}", self.file, self.line, self.column, severity, stage, self.message) ?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n    help: {}", suggestion) ?;
+        }
+        Ok(())
+    }
+}
+
+// the candidate closest to name by edit distance, if it is close enough to be a typo
+pub fn nearest_name<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut best = None;
+    for candidate in candidates {
+        let distance = edit_distance(name, candidate);
+        if distance == 0 || distance > max_distance {
+            continue;
+        }
+        if let Some((best_distance, _)) = best {
+            if distance >= best_distance {
+                continue;
+            }
+        }
+        best = Some((distance, candidate));
+    }
+    best.map( | (_, candidate) | candidate)
+}
+
+// levenshtein distance where swapping two neighbouring chars counts as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] {0} else {1};
+            let mut d = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
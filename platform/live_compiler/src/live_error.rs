@@ -68,6 +68,11 @@ impl fmt::Display for LiveFileError {
     }
 }
 
+impl fmt::Debug for LiveFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
 
 
 impl LiveError{
@@ -15,7 +15,7 @@ pub use {
 };
 
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum LiveEval {
     Float64(f64),
     Vec2(Vec2),
@@ -43,6 +43,18 @@ impl LiveError {
         Self::eval_error(origin, index, nodes, format!("Expression call not implemented ident:{} with number of args: {}", ident, args))
     }
     
+    fn eval_error_wrong_arg_count(origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], ident: LiveId, expected: &str, args: usize)->Self {
+        Self::eval_error(origin, index, nodes, format!("{}() takes {} arguments but {} were given", ident, expected, args))
+    }
+    
+    fn eval_error_wrong_arg_type(origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], ident: LiveId, arg: usize, expected: &str, a: &LiveEval)->Self {
+        Self::eval_error(origin, index, nodes, format!("argument {} of {}() should be {} but is {:?}", arg + 1, ident, expected, a))
+    }
+    
+    fn eval_error_unnamed_arg(origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], ident: LiveId, arg: usize)->Self {
+        Self::eval_error(origin, index, nodes, format!("argument {} of {}() needs a name, like count: 3", arg + 1, ident))
+    }
+    
     fn eval_error_cant_find_target(origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], id: LiveId)->Self {
         Self::eval_error(origin, index, nodes, format!("cant find target: {}", id))
     }
@@ -60,10 +72,17 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
     Ok(match &nodes[*index].value {
         LiveValue::Str(_) |
         LiveValue::InlineString(_) => {
-            LiveEval::String(Rc::new(live_registry.live_node_as_string(&nodes[*index]).unwrap()))
+            *index += 1;
+            LiveEval::String(Rc::new(live_registry.live_node_as_string(&nodes[*index - 1]).unwrap()))
+        }
+        LiveValue::Dependency(v) => {
+            *index += 1;
+            LiveEval::String(v.clone())
+        }
+        LiveValue::String(v) => {
+            *index += 1;
+            LiveEval::String(v.clone())
         }
-        LiveValue::Dependency(v)=>LiveEval::String(v.clone()),
-        LiveValue::String(v)=>LiveEval::String(v.clone()),
         LiveValue::Float32(v) => {
             *index += 1;
             LiveEval::Float64(*v as f64)
@@ -166,52 +185,42 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                     value_to_live_value(live_registry, index, nodes)?
                 }
                 else {
-                    return Err(LiveError::eval_error_cant_find_target(live_error_origin!(), *index, nodes, *id))
+                    return Err(LiveError::eval_error_cant_find_target(live_error_origin!(), *index - 1, nodes, *id))
                 }
             }
             else {
-                return Err(LiveError::eval_error_cant_find_target(live_error_origin!(), *index, nodes, *id))
+                return Err(LiveError::eval_error_cant_find_target(live_error_origin!(), *index - 1, nodes, *id))
             }
         },
         LiveValue::ExprUnOp(op) => {
+            let op_index = *index;
             *index += 1;
             let a = live_eval(live_registry, start, index, nodes)?;
             match op {
                 LiveUnOp::Not => match a {
                     LiveEval::Bool(va) => LiveEval::Bool(!va),
-                    _ => return Err(LiveError::eval_error_unop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a))
+                    _ => return Err(LiveError::eval_error_unop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a))
                 }
                 LiveUnOp::Neg => match a {
                     LiveEval::Float64(va) => LiveEval::Float64(-va),
                     LiveEval::Int64(va) => LiveEval::Int64(-va),
-                    _ => return Err(LiveError::eval_error_unop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a))
+                    _ => return Err(LiveError::eval_error_unop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a))
                 }
             }
         }
         LiveValue::ExprCall {ident, args} => {
+            let call_index = *index;
             *index += 1;
-            match ident {
-                live_id!(blend) if *args == 2 => {
-                    let a = live_eval(live_registry, start, index, nodes)?;
-                    let b = live_eval(live_registry, start, index, nodes)?;
-                    if let LiveEval::Vec4(va) = a {
-                        if let LiveEval::Vec4(vb) = b {
-                            // ok so how do we blend this eh.
-                            return Ok(LiveEval::Vec4(vec4(
-                                va.x + (vb.x - va.x) * vb.w,
-                                va.y + (vb.y - va.y) * vb.w,
-                                va.z + (vb.z - va.z) * vb.w,
-                                va.w
-                            )))
-                        }
-                    }
-                }
-                _ => {}
+            let mut arg_indices = Vec::with_capacity(*args);
+            let mut arg_values = Vec::with_capacity(*args);
+            for _ in 0..*args {
+                arg_indices.push(*index);
+                arg_values.push(live_eval(live_registry, start, index, nodes)?);
             }
-            
-            return Err(LiveError::eval_error_expression_call_not_implemented(live_error_origin!(), *index, nodes, *ident, *args))
+            live_eval_call(live_registry, *ident, call_index, &arg_indices, &arg_values, nodes)?
         }
         LiveValue::ExprBinOp(op) => {
+            let op_index = *index;
             *index += 1;
             let a = live_eval(live_registry, start, index, nodes)?;
             let b = live_eval(live_registry, start, index, nodes)?;
@@ -219,135 +228,142 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                 LiveBinOp::Or => match a {
                     LiveEval::Bool(va) => match b {
                         LiveEval::Bool(vb) => LiveEval::Bool(va || vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 }
                 LiveBinOp::And => match a {
                     LiveEval::Bool(va) => match b {
                         LiveEval::Bool(vb) => LiveEval::Bool(va && vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Eq => match a {
                     LiveEval::Bool(va) => match b {
                         LiveEval::Bool(vb) => LiveEval::Bool(va == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va == vb),
                         LiveEval::Float64(vb) => LiveEval::Bool(va as f64 == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va == vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Bool(va == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Bool(va == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Bool(va == vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Ne => match a {
                     LiveEval::Bool(va) => match b {
                         LiveEval::Bool(vb) => LiveEval::Bool(va != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va != vb),
                         LiveEval::Float64(vb) => LiveEval::Bool(va as f64 != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va != vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Bool(va != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Bool(va != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Bool(va != vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Lt => match a {
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va < vb),
                         LiveEval::Float64(vb) => LiveEval::Bool((va as f64) < vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va < vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va < vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Le => match a {
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va <= vb),
                         LiveEval::Float64(vb) => LiveEval::Bool((va as f64) <= vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va <= vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va <= vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Gt => match a {
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va > vb),
                         LiveEval::Float64(vb) => LiveEval::Bool((va as f64) > vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va > vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va > vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Ge => match a {
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va >= vb),
                         LiveEval::Float64(vb) => LiveEval::Bool((va as f64) >= vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Bool(va >= vb as f64),
                         LiveEval::Float64(vb) => LiveEval::Bool(va >= vb),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Add => match a {
+                    LiveEval::String(ref va) => match b {
+                        LiveEval::String(ref vb) => LiveEval::String(Rc::new(format!("{}{}", va, vb))),
+                        LiveEval::Int64(vb) => LiveEval::String(Rc::new(format!("{}{}", va, vb))),
+                        LiveEval::Float64(vb) => LiveEval::String(Rc::new(format!("{}{}", va, vb))),
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
+                    }
                     LiveEval::Int64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Int64(va + vb),
                         LiveEval::Float64(vb) => LiveEval::Float64((va as f64) + vb),
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb + va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb + va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb + va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        LiveEval::String(ref vb) => LiveEval::String(Rc::new(format!("{}{}", va, vb))),
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Float64(va + vb as f64),
@@ -355,27 +371,28 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb + va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb + va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb + va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        LiveEval::String(ref vb) => LiveEval::String(Rc::new(format!("{}{}", va, vb))),
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Vec2(va + vb),
                         LiveEval::Int64(vb) => LiveEval::Vec2(va + vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec2(va + vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Vec3(va + vb),
                         LiveEval::Int64(vb) => LiveEval::Vec3(va + vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec3(va + vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Vec4(va + vb),
                         LiveEval::Int64(vb) => LiveEval::Vec4(va + vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec4(va + vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Sub => match a {
                     LiveEval::Int64(va) => match b {
@@ -384,7 +401,7 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb - va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb - va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb - va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Float64(va - vb as f64),
@@ -392,27 +409,27 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb - va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb - va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb - va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Vec2(va - vb),
                         LiveEval::Int64(vb) => LiveEval::Vec2(va - vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec2(va - vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Vec3(va - vb),
                         LiveEval::Int64(vb) => LiveEval::Vec3(va - vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec3(va - vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Vec4(va - vb),
                         LiveEval::Int64(vb) => LiveEval::Vec4(va - vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec4(va - vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Mul => match a {
                     LiveEval::Int64(va) => match b {
@@ -421,7 +438,7 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb * va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb * va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb * va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Float64(va * vb as f64),
@@ -429,27 +446,27 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb * va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb * va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb * va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Vec2(va * vb),
                         LiveEval::Int64(vb) => LiveEval::Vec2(va * vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec2(va * vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Vec3(va * vb),
                         LiveEval::Int64(vb) => LiveEval::Vec3(va * vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec3(va * vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Vec4(va * vb),
                         LiveEval::Int64(vb) => LiveEval::Vec4(va * vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec4(va * vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
                 LiveBinOp::Div => match a {
                     LiveEval::Int64(va) => match b {
@@ -458,7 +475,7 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb / va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb / va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb / va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Float64(va) => match b {
                         LiveEval::Int64(vb) => LiveEval::Float64(va / vb as f64),
@@ -466,26 +483,26 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec2(vb) => LiveEval::Vec2(vb / va as f32),
                         LiveEval::Vec3(vb) => LiveEval::Vec3(vb / va as f32),
                         LiveEval::Vec4(vb) => LiveEval::Vec4(vb / va as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec2(va) => match b {
                         LiveEval::Vec2(vb) => LiveEval::Vec2(va / vb),
                         LiveEval::Int64(vb) => LiveEval::Vec2(va / vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec2(va / vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec3(va) => match b {
                         LiveEval::Vec3(vb) => LiveEval::Vec3(va / vb),
                         LiveEval::Int64(vb) => LiveEval::Vec3(va / vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec3(va / vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                     }
                     LiveEval::Vec4(va) => match b {
                         LiveEval::Vec4(vb) => LiveEval::Vec4(va / vb),
                         LiveEval::Int64(vb) => LiveEval::Vec4(va / vb as f32),
                         LiveEval::Float64(vb) => LiveEval::Vec4(va / vb as f32),
-                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
-                    } _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
+                    } _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), op_index, nodes, *op, a, b))
                 },
             }
         }
@@ -494,3 +511,178 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
         }
     })
 }
+
+impl LiveEval {
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float64(v) => Some(*v),
+            Self::Int64(v) => Some(*v as f64),
+            _ => None
+        }
+    }
+}
+
+// applies f per component, ints stay ints
+fn live_eval_map(a: &LiveEval, f: impl Fn(f64) -> f64) -> Option<LiveEval> {
+    let c = | x: f32 | f(x as f64) as f32;
+    Some(match a {
+        LiveEval::Int64(a) => LiveEval::Int64(f(*a as f64) as i64),
+        LiveEval::Float64(a) => LiveEval::Float64(f(*a)),
+        LiveEval::Vec2(a) => LiveEval::Vec2(vec2(c(a.x), c(a.y))),
+        LiveEval::Vec3(a) => LiveEval::Vec3(vec3(c(a.x), c(a.y), c(a.z))),
+        LiveEval::Vec4(a) => LiveEval::Vec4(vec4(c(a.x), c(a.y), c(a.z), c(a.w))),
+        _ => return None
+    })
+}
+
+// applies f per component of two values, a scalar is widened to the size of the other vector
+fn live_eval_zip(a: &LiveEval, b: &LiveEval, f: impl Fn(f64, f64) -> f64) -> Option<LiveEval> {
+    let c = | x: f32, y: f32 | f(x as f64, y as f64) as f32;
+    Some(match (a, b) {
+        (LiveEval::Vec2(a), LiveEval::Vec2(b)) => LiveEval::Vec2(vec2(c(a.x, b.x), c(a.y, b.y))),
+        (LiveEval::Vec3(a), LiveEval::Vec3(b)) => LiveEval::Vec3(vec3(c(a.x, b.x), c(a.y, b.y), c(a.z, b.z))),
+        (LiveEval::Vec4(a), LiveEval::Vec4(b)) => LiveEval::Vec4(vec4(c(a.x, b.x), c(a.y, b.y), c(a.z, b.z), c(a.w, b.w))),
+        (LiveEval::Vec2(a), b) => {
+            let b = b.as_f64()? as f32;
+            LiveEval::Vec2(vec2(c(a.x, b), c(a.y, b)))
+        }
+        (LiveEval::Vec3(a), b) => {
+            let b = b.as_f64()? as f32;
+            LiveEval::Vec3(vec3(c(a.x, b), c(a.y, b), c(a.z, b)))
+        }
+        (LiveEval::Vec4(a), b) => {
+            let b = b.as_f64()? as f32;
+            LiveEval::Vec4(vec4(c(a.x, b), c(a.y, b), c(a.z, b), c(a.w, b)))
+        }
+        (a, LiveEval::Vec2(b)) => {
+            let a = a.as_f64()? as f32;
+            LiveEval::Vec2(vec2(c(a, b.x), c(a, b.y)))
+        }
+        (a, LiveEval::Vec3(b)) => {
+            let a = a.as_f64()? as f32;
+            LiveEval::Vec3(vec3(c(a, b.x), c(a, b.y), c(a, b.z)))
+        }
+        (a, LiveEval::Vec4(b)) => {
+            let a = a.as_f64()? as f32;
+            LiveEval::Vec4(vec4(c(a, b.x), c(a, b.y), c(a, b.z), c(a, b.w)))
+        }
+        (a, b) => LiveEval::Float64(f(a.as_f64()?, b.as_f64()?))
+    })
+}
+
+fn live_eval_call(live_registry: &LiveRegistry, ident: LiveId, call_index: usize, arg_indices: &[usize], args: &[LiveEval], nodes: &[LiveNode]) -> Result<LiveEval, LiveError> {
+    let check_args = | min: usize, max: usize | {
+        if args.len() < min || args.len() > max {
+            let expected = if min == max {format!("{}", min)} else {format!("{} to {}", min, max)};
+            return Err(LiveError::eval_error_wrong_arg_count(live_error_origin!(), call_index, nodes, ident, &expected, args.len()))
+        }
+        Ok(())
+    };
+    let wrong_type = | arg: usize, expected: &str | {
+        LiveError::eval_error_wrong_arg_type(live_error_origin!(), arg_indices[arg], nodes, ident, arg, expected, &args[arg])
+    };
+    let number = | arg: usize | {
+        args[arg].as_f64().ok_or_else( || wrong_type(arg, "a number"))
+    };
+    let color = | arg: usize | {
+        if let LiveEval::Vec4(v) = args[arg] {Ok(v)} else {Err(wrong_type(arg, "a color"))}
+    };
+    let wrong_zip_type = | | {
+        if live_eval_map(&args[0], | a | a).is_none() {wrong_type(0, "a number or vector")} else {wrong_type(1, "a number or vector")}
+    };
+    // the optional alpha argument of the color constructors
+    let opt_alpha = | arg: usize | {
+        if arg < args.len() {number(arg).map( | v | v as f32)} else {Ok(1.0)}
+    };
+    
+    Ok(match ident {
+        live_id!(blend) => {
+            check_args(2, 2) ?;
+            let (a, b) = (color(0) ?, color(1) ?);
+            LiveEval::Vec4(vec4(
+                a.x + (b.x - a.x) * b.w,
+                a.y + (b.y - a.y) * b.w,
+                a.z + (b.z - a.z) * b.w,
+                a.w
+            ))
+        }
+        live_id!(mix) => {
+            check_args(3, 3) ?;
+            let t = number(2) ?;
+            live_eval_zip(&args[0], &args[1], | a, b | a + (b - a) * t).ok_or_else(wrong_zip_type) ?
+        }
+        live_id!(min) | live_id!(max) => {
+            check_args(2, 2) ?;
+            let is_min = ident == live_id!(min);
+            match (&args[0], &args[1]) {
+                (LiveEval::Int64(a), LiveEval::Int64(b)) => LiveEval::Int64(if is_min {*a.min(b)} else {*a.max(b)}),
+                (a, b) => live_eval_zip(a, b, | a, b | if is_min {a.min(b)} else {a.max(b)}).ok_or_else(wrong_zip_type) ?
+            }
+        }
+        live_id!(clamp) => {
+            check_args(3, 3) ?;
+            match (&args[0], &args[1], &args[2]) {
+                (LiveEval::Int64(x), LiveEval::Int64(lo), LiveEval::Int64(hi)) => LiveEval::Int64(*x.max(lo).min(hi)),
+                (x, _, _) => {
+                    let (lo, hi) = (number(1) ?, number(2) ?);
+                    live_eval_map(x, | x | x.max(lo).min(hi)).ok_or_else( || wrong_type(0, "a number or vector")) ?
+                }
+            }
+        }
+        live_id!(abs) | live_id!(floor) | live_id!(ceil) | live_id!(round) => {
+            check_args(1, 1) ?;
+            let f = match ident {
+                live_id!(abs) => f64::abs,
+                live_id!(floor) => f64::floor,
+                live_id!(ceil) => f64::ceil,
+                _ => f64::round
+            };
+            live_eval_map(&args[0], f).ok_or_else( || wrong_type(0, "a number or vector")) ?
+        }
+        live_id!(lighten) | live_id!(darken) | live_id!(saturate) => {
+            check_args(2, 2) ?;
+            let mut hsl = color(0) ?.to_hsla();
+            let amount = number(1) ? as f32;
+            match ident {
+                live_id!(lighten) => hsl.z = (hsl.z + amount).clamp(0.0, 1.0),
+                live_id!(darken) => hsl.z = (hsl.z - amount).clamp(0.0, 1.0),
+                _ => hsl.y = (hsl.y + amount).clamp(0.0, 1.0),
+            }
+            LiveEval::Vec4(Vec4::from_hsla(hsl))
+        }
+        // hue is in degrees like css, the other components go from 0 to 1
+        live_id!(hsl) => {
+            check_args(3, 4) ?;
+            let hue = (number(0) ? / 360.0).rem_euclid(1.0) as f32;
+            LiveEval::Vec4(Vec4::from_hsla(vec4(hue, number(1) ? as f32, number(2) ? as f32, opt_alpha(3) ?)))
+        }
+        live_id!(hsv) => {
+            check_args(3, 4) ?;
+            let hue = (number(0) ? / 360.0).rem_euclid(1.0) as f32;
+            let mut rgb = Vec4::from_hsva(vec4(hue, number(1) ? as f32, number(2) ? as f32, 1.0));
+            rgb.w = opt_alpha(3) ?;
+            LiveEval::Vec4(rgb)
+        }
+        live_id!(alpha) => {
+            check_args(2, 2) ?;
+            let mut c = color(0) ?;
+            c.w = number(1) ? as f32;
+            LiveEval::Vec4(c)
+        }
+        // tr("message-id", name: value, ..) looks the message up in the translation catalogs
+        live_id!(tr) => {
+            check_args(1, args.len().max(1)) ?;
+            let message_id = if let LiveEval::String(s) = &args[0] {s} else {return Err(wrong_type(0, "a message id string"))};
+            let mut tr_args = Vec::with_capacity(args.len() - 1);
+            for arg in 1..args.len() {
+                let name = nodes[arg_indices[arg]].id;
+                if name.is_empty() {
+                    return Err(LiveError::eval_error_unnamed_arg(live_error_origin!(), arg_indices[arg], nodes, ident, arg))
+                }
+                tr_args.push((name, args[arg].clone()));
+            }
+            LiveEval::String(Rc::new(live_registry.localization.format(message_id, &tr_args)))
+        }
+        _ => return Err(LiveError::eval_error_expression_call_not_implemented(live_error_origin!(), call_index, nodes, ident, args.len()))
+    })
+}
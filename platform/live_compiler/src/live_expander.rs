@@ -8,7 +8,7 @@ use {
         live_ptr::{LiveFileId, LivePtr, LiveFileGeneration},
         live_error::{LiveError},
         live_document::{LiveOriginal, LiveExpanded},
-        live_node::{LiveValue, LiveNode, LiveFieldKind, LivePropType},
+        live_node::{LiveValue, LiveNode, LiveFieldKind, LivePropType, LiveBinOp, LiveUnOp},
         live_node_vec::{LiveNodeSliceApi, LiveNodeVecApi},
         live_registry::{LiveRegistry, LiveScopeTarget},
     }
@@ -53,6 +53,8 @@ impl<'a> LiveExpander<'a> {
             value: LiveValue::Root {id_resolve: Box::default()}
         });
         let mut current_parent = vec![(LiveId(0), 0usize)];
+        // for the if branches we are in, the index of their close and where to continue after it
+        let mut if_stack: Vec<(usize, usize)> = Vec::new();
         let mut in_index = 1;
         let mut lazy_define_value = None;
         loop {
@@ -72,10 +74,35 @@ impl<'a> LiveExpander<'a> {
             match in_value {
                 
                 LiveValue::Close => {
+                    if let Some((branch_close, next_index)) = if_stack.last().cloned() {
+                        if branch_close == in_index {
+                            if_stack.pop();
+                            in_index = next_index;
+                            continue;
+                        }
+                    }
                     current_parent.pop();
                     in_index += 1;
                     continue;
                 }
+                LiveValue::If => {
+                    // the picked branch expands as if it was written in place of the if block
+                    let next_index = in_doc.nodes.skip_node(in_index);
+                    match self.select_if_branch(&in_doc.nodes, in_index + 1) {
+                        Ok(Some(branch)) => {
+                            if_stack.push((in_doc.nodes.skip_node(branch) - 1, next_index));
+                            in_index = branch + 1;
+                        }
+                        Ok(None) => {
+                            in_index = next_index;
+                        }
+                        Err(err) => {
+                            self.errors.push(err);
+                            in_index = next_index;
+                        }
+                    }
+                    continue;
+                }
                 LiveValue::Import(live_import) => {
                     // lets verify it points anywhere
                     let mut found = false;
@@ -283,6 +310,12 @@ impl<'a> LiveExpander<'a> {
                             message: format!("Can't find live definition of {} did you forget to call live_design for it?", clone)
                         });
                     }
+                    // clones inside a component are template instances, top level definitions
+                    // keep their template args so they can be derived from with other defaults
+                    if current_parent.len() > 1 {
+                        // the args are substituted here, the body carries on after them
+                        in_index = self.instantiate_template(&in_doc.nodes, in_index, &mut out_doc.nodes, out_index) - 1;
+                    }
                     current_parent.push((out_doc.nodes[out_index].id, out_index));
                 },
                 LiveValue::Class {live_type, ..} => {
@@ -437,5 +470,189 @@ impl<'a> LiveExpander<'a> {
         }
     }
     
+    // returns the branch object of the if block to expand, None if the condition is false and there is no else
+    fn select_if_branch(&self, nodes: &[LiveNode], expr_index: usize) -> Result<Option<usize>, LiveError> {
+        let mut index = expr_index + 1;
+        let value = self.eval_condition(nodes, &mut index) ?;
+        let then_index = nodes.skip_node(expr_index);
+        if Self::condition_is_true(nodes, expr_index, &value) ? {
+            return Ok(Some(then_index))
+        }
+        let else_index = nodes.skip_node(then_index);
+        if nodes[else_index].is_close() {
+            return Ok(None)
+        }
+        Ok(Some(else_index))
+    }
+    
+    fn condition_is_true(nodes: &[LiveNode], index: usize, value: &LiveValue) -> Result<bool, LiveError> {
+        match value {
+            LiveValue::Bool(v) => Ok(*v),
+            // a name that isnt set
+            LiveValue::Id(_) => Ok(false),
+            _ => Err(Self::condition_error(nodes, index, format!("if condition should be a bool but is {:?}", value)))
+        }
+    }
+    
+    fn condition_error(nodes: &[LiveNode], index: usize, message: String) -> LiveError {
+        LiveError {
+            origin: live_error_origin!(),
+            span: nodes[index].origin.token_id().unwrap().into(),
+            message
+        }
+    }
+    
+    // if conditions are evaluated before anything is expanded, so names can only refer to
+    // the conditions set on the registry. names that arent set evaluate to themselves, that way
+    // os == macos compares against the id macos
+    fn eval_condition(&self, nodes: &[LiveNode], index: &mut usize) -> Result<LiveValue, LiveError> {
+        let node_index = *index;
+        *index += 1;
+        Ok(match &nodes[node_index].value {
+            LiveValue::Id(id) => {
+                self.live_registry.conditions.get(id).cloned().unwrap_or(LiveValue::Id(*id))
+            }
+            LiveValue::ExprUnOp(op) => {
+                let a = self.eval_condition(nodes, index) ?;
+                match op {
+                    LiveUnOp::Not => LiveValue::Bool(!Self::condition_is_true(nodes, node_index, &a) ?),
+                    LiveUnOp::Neg => if let Some(a) = a.as_float() {
+                        LiveValue::Float64(-a)
+                    }
+                    else {
+                        return Err(Self::condition_error(nodes, node_index, format!("Operation {:?} undefined for {:?}", op, a)))
+                    }
+                }
+            }
+            LiveValue::ExprBinOp(op) => {
+                let a = self.eval_condition(nodes, index) ?;
+                let b = self.eval_condition(nodes, index) ?;
+                match op {
+                    LiveBinOp::Or => LiveValue::Bool(Self::condition_is_true(nodes, node_index, &a) ? || Self::condition_is_true(nodes, node_index, &b) ?),
+                    LiveBinOp::And => LiveValue::Bool(Self::condition_is_true(nodes, node_index, &a) ? && Self::condition_is_true(nodes, node_index, &b) ?),
+                    LiveBinOp::Eq | LiveBinOp::Ne => {
+                        let eq = match (a.as_float(), b.as_float()) {
+                            (Some(a), Some(b)) => a == b,
+                            _ => a == b
+                        };
+                        LiveValue::Bool(eq == (*op == LiveBinOp::Eq))
+                    }
+                    // comparing with a name that isnt set is false as well
+                    LiveBinOp::Lt | LiveBinOp::Le | LiveBinOp::Gt | LiveBinOp::Ge if matches!(a, LiveValue::Id(_)) || matches!(b, LiveValue::Id(_)) => {
+                        LiveValue::Bool(false)
+                    }
+                    _ => if let (Some(fa), Some(fb)) = (a.as_float(), b.as_float()) {
+                        match op {
+                            LiveBinOp::Lt => LiveValue::Bool(fa < fb),
+                            LiveBinOp::Le => LiveValue::Bool(fa <= fb),
+                            LiveBinOp::Gt => LiveValue::Bool(fa > fb),
+                            LiveBinOp::Ge => LiveValue::Bool(fa >= fb),
+                            LiveBinOp::Add => LiveValue::Float64(fa + fb),
+                            LiveBinOp::Sub => LiveValue::Float64(fa - fb),
+                            LiveBinOp::Mul => LiveValue::Float64(fa * fb),
+                            _ => LiveValue::Float64(fa / fb),
+                        }
+                    }
+                    else {
+                        return Err(Self::condition_error(nodes, node_index, format!("Operation {:?} undefined between {:?} and {:?}", op, a, b)))
+                    }
+                }
+            }
+            value if value.is_value_type() => value.clone(),
+            value => return Err(Self::condition_error(nodes, node_index, format!("{:?} cannot be used in an if condition", value)))
+        })
+    }
+    
+    // substitutes the template args of a cloned component into its body, <Card>(title: "Hello") {}
+    // turns text: (title) inside Card into text: "Hello". args not given keep the default Card declared.
+    // returns the index in the document where the body continues after the args
+    fn instantiate_template(&mut self, in_nodes: &[LiveNode], in_index: usize, out_nodes: &mut Vec<LiveNode>, out_index: usize) -> usize {
+        let mut args: Vec<(LiveId, Vec<LiveNode>)> = Vec::new();
+        let mut child = out_nodes.first_child(out_index);
+        while let Some(index) = child {
+            child = out_nodes.next_child(index);
+            if out_nodes[index].origin.has_prop_type(LivePropType::Template) {
+                args.push((out_nodes[index].id, out_nodes.node_slice(index).to_vec()));
+            }
+        }
+        let mut args_end = in_index + 1;
+        while !in_nodes[args_end].is_close() && in_nodes[args_end].origin.has_prop_type(LivePropType::Template) {
+            let arg = in_nodes.node_slice(args_end).to_vec();
+            if let Some(slot) = args.iter_mut().find( | (id, _) | *id == arg[0].id) {
+                slot.1 = arg;
+            }
+            else {
+                self.errors.push(LiveError {
+                    origin: live_error_origin!(),
+                    span: in_nodes[args_end].origin.token_id().unwrap().into(),
+                    message: format!("{} has no template argument {}", in_nodes[in_index].value.get_clone_name(), arg[0].id)
+                });
+            }
+            args_end = in_nodes.skip_node(args_end);
+        }
+        if args.is_empty() {
+            return args_end
+        }
+        // take the declared args out, widgets only see the values they ended up in
+        let mut index = out_index + 1;
+        while !out_nodes[index].is_close() {
+            let next_index = out_nodes.skip_node(index);
+            if out_nodes[index].origin.has_prop_type(LivePropType::Template) {
+                out_nodes.drain(index..next_index);
+            }
+            else {
+                index = next_index;
+            }
+        }
+        
+        let mut end = index;
+        let mut index = out_index + 1;
+        while index < end {
+            if !out_nodes[index].is_expr() {
+                index += 1;
+                continue;
+            }
+            let expr_end = out_nodes.skip_node(index);
+            // an expression that is just the arg takes the arg as is, so it can be any value
+            if let (3, LiveValue::Id(id)) = (expr_end - index, &out_nodes[index + 1].value) {
+                if let Some((_, arg)) = args.iter().find( | (arg_id, _) | arg_id == id) {
+                    let mut value = arg.clone();
+                    if value.len() == 1 {
+                        // keeps the origin so prefixes like instance still apply
+                        value[0].origin = out_nodes[index].origin;
+                    }
+                    else {
+                        value[0].origin.set_prop_type(out_nodes[index].origin.prop_type());
+                        value.last_mut().unwrap().id = out_nodes[index].id;
+                    }
+                    value[0].id = out_nodes[index].id;
+                    let value_len = value.len();
+                    out_nodes.splice(index..expr_end, value);
+                    end = end + value_len - (expr_end - index);
+                    index += value_len;
+                    continue;
+                }
+            }
+            for i in index + 1..expr_end - 1 {
+                if let LiveValue::Id(id) = &out_nodes[i].value {
+                    if let Some((_, arg)) = args.iter().find( | (arg_id, _) | arg_id == id) {
+                        if arg.len() == 1 && arg[0].value.is_value_type() {
+                            out_nodes[i].value = arg[0].value.clone();
+                        }
+                        else {
+                            self.errors.push(LiveError {
+                                origin: live_error_origin!(),
+                                span: arg[0].origin.token_id().unwrap().into(),
+                                message: format!("Template argument {} is used inside an expression, it has to be a single value", id)
+                            });
+                        }
+                    }
+                }
+            }
+            index = expr_end;
+        }
+        args_end
+    }
+    
 }
 
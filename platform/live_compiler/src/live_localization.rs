@@ -0,0 +1,457 @@
+use {
+    std::{
+        collections::HashMap,
+        fmt::Write,
+    },
+    crate::{
+        makepad_live_id::*,
+        live_eval::LiveEval,
+    }
+};
+
+// Translation catalogs for the tr() expression in live_design and Cx::tr. Catalogs use a subset
+// of the Fluent syntax, one file per locale:
+//
+//   # comment
+//   -brand = Makepad
+//   hello = Hello { $name }, welcome to { -brand }
+//   inbox-count = { $count ->
+//       [0] No messages
+//       [one] One message
+//      *[other] { $count } messages
+//   }
+//
+// Select variants match an exact number, a plural category of the locale or a string argument,
+// the one marked with * is the default. Messages can continue on indented lines.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LivePluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other
+}
+
+impl LivePluralCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other"
+        }
+    }
+
+    // the cardinal plural rules from CLDR for the languages we know, others pluralize like english
+    pub fn for_number(language: &str, n: f64) -> Self {
+        let n = n.abs();
+        let is_int = n.fract() == 0.0;
+        let i = n.trunc() as u64;
+        match language {
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" => Self::Other,
+            "fr" | "pt" | "hi" | "fa" | "bn" => if i == 0 || i == 1 {Self::One} else {Self::Other},
+            "ru" | "uk" | "be" => {
+                if !is_int {Self::Other}
+                else if i % 10 == 1 && i % 100 != 11 {Self::One}
+                else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {Self::Few}
+                else {Self::Many}
+            }
+            "pl" => {
+                if !is_int {Self::Other}
+                else if i == 1 {Self::One}
+                else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {Self::Few}
+                else {Self::Many}
+            }
+            "cs" | "sk" => {
+                if !is_int {Self::Many}
+                else if i == 1 {Self::One}
+                else if (2..=4).contains(&i) {Self::Few}
+                else {Self::Other}
+            }
+            "ar" => {
+                if !is_int {Self::Other}
+                else if i == 0 {Self::Zero}
+                else if i == 1 {Self::One}
+                else if i == 2 {Self::Two}
+                else if (3..=10).contains(&(i % 100)) {Self::Few}
+                else if (11..=99).contains(&(i % 100)) {Self::Many}
+                else {Self::Other}
+            }
+            "he" => {
+                if is_int && i == 1 {Self::One}
+                else if is_int && i == 2 {Self::Two}
+                else {Self::Other}
+            }
+            _ => if is_int && i == 1 {Self::One} else {Self::Other}
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum TrKey {
+    Number(f64),
+    Name(String)
+}
+
+#[derive(Clone, Debug)]
+enum TrPart {
+    Text(String),
+    Var(LiveId),
+    // another message, or a term when it starts with -
+    Message(String),
+    Select {var: LiveId, variants: Vec<(TrKey, Vec<TrPart>)>, default: usize}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiveCatalogError {
+    // 1 based
+    pub line: usize,
+    pub message: String
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LiveCatalog {
+    messages: HashMap<String, Vec<TrPart>>
+}
+
+impl LiveCatalog {
+    pub fn parse(source: &str) -> Result<Self, LiveCatalogError> {
+        let mut messages = HashMap::new();
+        let lines: Vec<&str> = source.lines().collect();
+        let mut line = 0;
+        while line < lines.len() {
+            let text = lines[line];
+            let start_line = line;
+            line += 1;
+            if text.trim().is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if text.starts_with(char::is_whitespace) {
+                return Err(LiveCatalogError {line: start_line + 1, message: "expected a message id at the start of the line".to_string()})
+            }
+            let (id, first) = text.split_once('=').ok_or_else( || LiveCatalogError {
+                line: start_line + 1,
+                message: format!("expected = after message id in: {}", text)
+            }) ?;
+            let id = id.trim();
+            if id.is_empty() || !id.chars().all( | c | c.is_alphanumeric() || c == '-' || c == '_') {
+                return Err(LiveCatalogError {line: start_line + 1, message: format!("invalid message id: {}", id)})
+            }
+            // indented lines belong to the message, so do blank lines in between them.
+            // every line stays a line in value so errors can point at it
+            let mut value = first.trim().to_string();
+            while line < lines.len() && Self::continues_message(&lines[line..]) {
+                value.push('\n');
+                value.push_str(lines[line].trim());
+                line += 1;
+            }
+            let mut parser = TrParser {chars: value.trim_end().chars().collect(), pos: 0};
+            parser.skip_ws();
+            let parts = parser.parse_pattern(false).map_err( | message | LiveCatalogError {
+                line: start_line + 1 + parser.line(),
+                message: format!("{} in message {}", message, id)
+            }) ?;
+            messages.insert(id.to_string(), parts);
+        }
+        Ok(Self {messages})
+    }
+
+    fn continues_message(lines: &[&str]) -> bool {
+        match lines.iter().find( | line | !line.trim().is_empty()) {
+            // the closing } of a select can sit at the start of a line
+            Some(line) => line.starts_with(char::is_whitespace) || line.starts_with('}'),
+            None => false
+        }
+    }
+
+    pub fn has_message(&self, id: &str) -> bool {
+        self.messages.contains_key(id)
+    }
+}
+
+struct TrParser {
+    chars: Vec<char>,
+    pos: usize
+}
+
+impl TrParser {
+    fn line(&self) -> usize {
+        self.chars[0..self.pos].iter().filter( | c | **c == '\n').count()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn accept(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            return true
+        }
+        false
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if !self.accept(c) {
+            return Err(format!("expected {}", c))
+        }
+        Ok(())
+    }
+
+    fn ident(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            // the - of a -> belongs to the select
+            if (!c.is_alphanumeric() && c != '-' && c != '_') || (c == '-' && self.chars.get(self.pos + 1) == Some(&'>')) {
+                break
+            }
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    // a variant pattern stops at the end of its line
+    fn parse_pattern(&mut self, in_variant: bool) -> Result<Vec<TrPart>, String> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    self.pos += 1;
+                    if !text.is_empty() {
+                        parts.push(TrPart::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.parse_placeable() ?);
+                }
+                '}' if in_variant => break,
+                '}' => return Err("unexpected }".to_string()),
+                '\n' if in_variant => break,
+                c => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(TrPart::Text(text));
+        }
+        Ok(parts)
+    }
+
+    fn parse_placeable(&mut self) -> Result<TrPart, String> {
+        self.skip_ws();
+        let part = match self.peek() {
+            Some('$') => {
+                self.pos += 1;
+                let name = self.ident();
+                if name.is_empty() {
+                    return Err("expected a variable name after $".to_string())
+                }
+                TrPart::Var(LiveId::from_str_with_lut(&name).unwrap())
+            }
+            Some('"') => {
+                self.pos += 1;
+                let mut text = String::new();
+                loop {
+                    match self.peek() {
+                        Some('"') => {self.pos += 1; break}
+                        Some('\\') => {
+                            self.pos += 1;
+                            text.extend(self.peek());
+                            self.pos += 1;
+                        }
+                        Some(c) => {self.pos += 1; text.push(c)}
+                        None => return Err("unterminated string literal".to_string())
+                    }
+                }
+                TrPart::Text(text)
+            }
+            Some(_) => {
+                let name = self.ident();
+                if name.is_empty() {
+                    return Err("expected a variable, message or string in {}".to_string())
+                }
+                TrPart::Message(name)
+            }
+            None => return Err("unterminated {".to_string())
+        };
+        self.skip_ws();
+        if self.accept('-') {
+            self.expect('>') ?;
+            let var = if let TrPart::Var(var) = part {var} else {
+                return Err("only variables can be selected on".to_string())
+            };
+            return self.parse_select(var)
+        }
+        self.expect('}') ?;
+        Ok(part)
+    }
+
+    fn parse_select(&mut self, var: LiveId) -> Result<TrPart, String> {
+        let mut variants = Vec::new();
+        let mut default = None;
+        loop {
+            self.skip_ws();
+            if self.accept('}') {
+                break
+            }
+            if self.accept('*') {
+                if default.is_some() {
+                    return Err("more than one default variant".to_string())
+                }
+                default = Some(variants.len());
+            }
+            self.expect('[') ?;
+            self.skip_ws();
+            let start = self.pos;
+            while let Some(c) = self.peek() {
+                if c == ']' || c.is_whitespace() {
+                    break
+                }
+                self.pos += 1;
+            }
+            let key: String = self.chars[start..self.pos].iter().collect();
+            self.skip_ws();
+            self.expect(']') ?;
+            let key = if let Ok(n) = key.parse::<f64>() {TrKey::Number(n)} else if key.is_empty() {
+                return Err("empty variant key".to_string())
+            } else {TrKey::Name(key)};
+            while self.peek() == Some(' ') || self.peek() == Some('\t') {
+                self.pos += 1;
+            }
+            variants.push((key, self.parse_pattern(true) ?));
+        }
+        let default = default.ok_or_else( || "select has no default variant marked with *".to_string()) ?;
+        Ok(TrPart::Select {var, variants, default})
+    }
+}
+
+pub struct LiveLocalization {
+    locale: String,
+    fallback_locale: String,
+    catalogs: HashMap<String, LiveCatalog>,
+}
+
+impl Default for LiveLocalization {
+    fn default() -> Self {
+        Self {
+            locale: "en".to_string(),
+            fallback_locale: "en".to_string(),
+            catalogs: HashMap::new()
+        }
+    }
+}
+
+impl LiveLocalization {
+    // messages are looked up in the locale, its language (nl for nl-BE) and then the fallback locale
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn set_locale(&mut self, locale: &str) -> bool {
+        if self.locale == locale {
+            return false
+        }
+        self.locale = locale.to_string();
+        true
+    }
+
+    pub fn set_fallback_locale(&mut self, locale: &str) {
+        self.fallback_locale = locale.to_string();
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.catalogs.keys().map( | s | s.as_str())
+    }
+
+    // replaces the catalog of a locale
+    pub fn add_catalog(&mut self, locale: &str, source: &str) -> Result<(), LiveCatalogError> {
+        let catalog = LiveCatalog::parse(source) ?;
+        self.catalogs.insert(locale.to_string(), catalog);
+        Ok(())
+    }
+
+    fn language(locale: &str) -> &str {
+        locale.split(['-', '_']).next().unwrap_or(locale)
+    }
+
+    fn find_message(&self, id: &str) -> Option<(&str, &Vec<TrPart>)> {
+        let language = Self::language(&self.locale);
+        for locale in [self.locale.as_str(), language, self.fallback_locale.as_str()] {
+            if let Some(parts) = self.catalogs.get(locale).and_then( | catalog | catalog.messages.get(id)) {
+                return Some((locale, parts))
+            }
+        }
+        None
+    }
+
+    pub fn has_message(&self, id: &str) -> bool {
+        self.find_message(id).is_some()
+    }
+
+    // a message that isnt in any catalog formats as its id
+    pub fn format(&self, id: &str, args: &[(LiveId, LiveEval)]) -> String {
+        let mut out = String::new();
+        self.format_message(id, args, 0, &mut out);
+        out
+    }
+
+    fn format_message(&self, id: &str, args: &[(LiveId, LiveEval)], depth: usize, out: &mut String) {
+        if let Some((locale, parts)) = self.find_message(id) {
+            if depth < 8 {
+                return self.format_parts(Self::language(locale), parts, args, depth, out)
+            }
+        }
+        out.push_str(id);
+    }
+
+    fn format_parts(&self, language: &str, parts: &[TrPart], args: &[(LiveId, LiveEval)], depth: usize, out: &mut String) {
+        let arg = | var: &LiveId | args.iter().find( | (id, _) | id == var).map( | (_, v) | v);
+        for part in parts {
+            match part {
+                TrPart::Text(text) => out.push_str(text),
+                TrPart::Message(id) => self.format_message(id, args, depth + 1, out),
+                TrPart::Var(var) => match arg(var) {
+                    Some(value) => Self::format_value(value, out),
+                    None => {let _ = write!(out, "{{${}}}", var);}
+                }
+                TrPart::Select {var, variants, default} => {
+                    let value = arg(var);
+                    let selected = variants.iter().position( | (key, _) | match (key, value) {
+                        (TrKey::Number(n), Some(v)) => v.as_f64() == Some(*n),
+                        (TrKey::Name(name), Some(LiveEval::String(s))) => name == s.as_str(),
+                        (TrKey::Name(name), Some(v)) => match v.as_f64() {
+                            Some(n) => LivePluralCategory::for_number(language, n).name() == name,
+                            None => false
+                        },
+                        _ => false
+                    }).unwrap_or(*default);
+                    self.format_parts(language, &variants[selected].1, args, depth, out);
+                }
+            }
+        }
+    }
+
+    fn format_value(value: &LiveEval, out: &mut String) {
+        let _ = match value {
+            LiveEval::String(s) => write!(out, "{}", s),
+            LiveEval::Int64(v) => write!(out, "{}", v),
+            LiveEval::Float64(v) => write!(out, "{}", v),
+            LiveEval::Bool(v) => write!(out, "{}", v),
+            v => write!(out, "{:?}", v),
+        };
+    }
+}
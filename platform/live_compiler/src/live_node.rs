@@ -69,6 +69,9 @@ pub enum LiveValue {
         expand_index: Option<u32>
     },
     Import (Box<LiveImport>),
+    // if <expr> {..} else {..}, holds the condition and one or two branch objects.
+    // only exists in original documents, the expander keeps the branch that matches
+    If,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -235,7 +238,7 @@ impl fmt::Debug for LiveNodeOrigin {
 pub enum LivePropType {
     Field = 0,
     Instance = 1,
-    //Template = 2,
+    Template = 2,
     Nameless = 3
 }
 
@@ -336,7 +339,7 @@ impl LivePropType {
         match val {
             0 => Self::Field,
             1 => Self::Instance,
-            //2 => Self::Template,
+            2 => Self::Template,
             _ => Self::Nameless
         }
     }
@@ -458,6 +461,7 @@ impl LiveValue {
             Self::Object | // subnodes including this one
             Self::Clone {..} | // subnodes including this one
             Self::Class {..} | 
+            Self::If |
             Self::Root {..} => true, // subnodes including this one
             _ => false
         }
@@ -686,6 +690,7 @@ impl LiveValue {
             
             Self::DSL {..} => 29,
             Self::Import {..} => 30,
+            Self::If => 31,
             //Self::Registry {..} => 30,
         }
     }
@@ -3,6 +3,7 @@ use {
     std::convert::TryInto,
     crate::{
         makepad_live_tokenizer::LiveId,
+        makepad_math::*,
         live_node::*,
     }
 };
@@ -82,11 +83,13 @@ const CBOR_BREAK: u8 = 0xff; //	"break" stop code
 
 /* some Rust keyword abuse here 
 key:{move:{}} // clone
-key:{dyn:{}} // dyn created
+key:{dyn:{}} // dyn created, instance props
 key:{ref:{}} // template
 key:{in:[v1,v2]} // vec
 key:{as:u32} // color
-key {enum:"String"} // enum
+key:{if:"String"} // bare enum
+key:{enum:["String",[]]} // tuple enum, or a map for a named enum
+key:{id:"String"} // id
 */
 
 impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
@@ -228,9 +231,15 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                 }
             }
             
-            let prop_type = node.origin.prop_type();
-            if prop_type != LivePropType::Field && prop_type != LivePropType::Nameless {
-                return Err("Non field types not implemented".into())
+            match node.origin.prop_type() {
+                LivePropType::Field | LivePropType::Nameless => (),
+                LivePropType::Instance => {
+                    out.push(1 + CBOR_MAP_START);
+                    encode_str("dyn", &mut out);
+                }
+                LivePropType::Template => {
+                    return Err("Cannot serialise template props".into())
+                }
             }
             
             fn encode_str(s: &str, out: &mut Vec<u8>) {
@@ -296,14 +305,14 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                     encode_str("in", &mut out);
                     out.push(2 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                 },
                 LiveValue::Vec3(v) => {
                     out.push(1 + CBOR_MAP_START);
                     encode_str("in", &mut out);
                     out.push(3 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                     encode_f32(v.z, &mut out);
                 },
                 LiveValue::Vec4(v) => {
@@ -311,7 +320,7 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                     encode_str("in", &mut out);
                     out.push(4 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                     encode_f32(v.z, &mut out);
                     encode_f32(v.w, &mut out);
                 },
@@ -346,8 +355,10 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                 }, // subnodes including this one
                 LiveValue::Close => {},
                 // TODO ITEMS
-                LiveValue::Id(_) => {
-                    return Err("Cannot serialise LiveValue::Id".into())
+                LiveValue::Id(id) => {
+                    out.push(1 + CBOR_MAP_START);
+                    encode_str("id", &mut out);
+                    encode_id(*id, &mut out);
                 },
                 LiveValue::Clone(_) => {
                     return Err("Cannot serialise LiveValue::Clone".into())
@@ -379,6 +390,9 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                 LiveValue::Import(..) => {
                     return Err("Cannot serialise LiveValue::Import".into())
                 }
+                LiveValue::If => {
+                    return Err("Cannot serialise LiveValue::If".into())
+                }
                 LiveValue::Root{..} => {
                     return Err("Cannot serialise LiveValue::Registry".into())
                 }
@@ -677,20 +691,42 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
             Ok(None)
         }
         
+        fn decode_f32(data: &[u8], o: &mut usize) -> Result<f32, LiveNodeFromCborError> {
+            // whole numbers are written as integers
+            if let Some(v) = decode_i64(data, o) ? {
+                return Ok(v as f32)
+            }
+            assert_len(*o, 1, data) ?;
+            match data[*o] {
+                CBOR_FLOAT32 => {
+                    *o += 1;
+                    read_f32(data, o)
+                }
+                CBOR_FLOAT64 => {
+                    *o += 1;
+                    Ok(read_f64(data, o) ? as f32)
+                }
+                _ => Err(LiveNodeFromCborError::UnexpectedValue)
+            }
+        }
+        
         struct StackItem {len: usize, count: usize, has_keys: bool}
         
         let mut stack = vec![StackItem {count: 0, len: 1, has_keys: false}];
-        let origin = LiveNodeOrigin::field();
+        let field = LiveNodeOrigin::field();
         let mut o = 0;
         while o < data.len() {
             
-            if stack.last().unwrap().count == stack.last().unwrap().len {
-                self.push(LiveNode {id: LiveId(0), origin, value: LiveValue::Close});
+            while stack.len() > 1 && stack.last().unwrap().count == stack.last().unwrap().len {
+                self.push(LiveNode {id: LiveId(0), origin: field, value: LiveValue::Close});
                 stack.pop();
             }
             
             // ok lets read
             let stack_item = stack.last_mut().unwrap();
+            if stack_item.count == stack_item.len {
+                return Err(LiveNodeFromCborError::UnexpectedValue)
+            }
             let id = if stack_item.has_keys {
                 let id = decode_id(data, &mut o) ?;
                 if id.is_none() {return Err(LiveNodeFromCborError::ExpectedId)}
@@ -704,6 +740,17 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
             
             assert_len(o, 1, data) ?;
             
+            // instance props are wrapped in a {dyn:value}
+            let mut origin = field;
+            if data[o] == 1 + CBOR_MAP_START {
+                let mut o1 = o + 1;
+                if let Some("dyn") = decode_str(data, &mut o1) ? {
+                    origin = LiveNodeOrigin::instance();
+                    o = o1;
+                    assert_len(o, 1, data) ?;
+                }
+            }
+            
             if let Some(v) = decode_i64(data, &mut o) ? {
                 self.push(LiveNode {id, origin, value: LiveValue::Int64(v)});
             }
@@ -727,10 +774,35 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
                     if let Some(s) = decode_str(data, &mut o1) ? {
                         match s {
                             "in" => { // its a vec
-                                return Err(LiveNodeFromCborError::NotImpl)
+                                let value = match decode_array_len(data, &mut o1) ? {
+                                    Some(2) => LiveValue::Vec2(vec2(
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?
+                                    )),
+                                    Some(3) => LiveValue::Vec3(vec3(
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?
+                                    )),
+                                    Some(4) => LiveValue::Vec4(vec4(
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?,
+                                        decode_f32(data, &mut o1) ?
+                                    )),
+                                    _ => return Err(LiveNodeFromCborError::UnexpectedValue)
+                                };
+                                self.push(LiveNode {id, origin, value});
+                                o = o1;
+                                continue;
                             }
                             "as" => { // its a color
-                                return Err(LiveNodeFromCborError::NotImpl)
+                                if let Some(v) = decode_u64(data, &mut o1) ? {
+                                    self.push(LiveNode {id, origin, value: LiveValue::Color(v as u32)});
+                                    o = o1;
+                                    continue;
+                                }
+                                return Err(LiveNodeFromCborError::UnexpectedValue)
                             }
                             "if" => { // bare enum
                                 if let Some(variant) = decode_id(data, &mut o1) ? {
@@ -746,8 +818,37 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
                                     return Err(LiveNodeFromCborError::ExpectedBareEnumString)
                                 }
                             }
-                            "enum" => { // other enum
-                                return Err(LiveNodeFromCborError::NotImpl)
+                            "id" => {
+                                if let Some(v) = decode_id(data, &mut o1) ? {
+                                    self.push(LiveNode {id, origin, value: LiveValue::Id(v)});
+                                    o = o1;
+                                    continue;
+                                }
+                                return Err(LiveNodeFromCborError::ExpectedId)
+                            }
+                            "enum" => { // tuple or named enum
+                                if decode_array_len(data, &mut o1) ? != Some(2) {
+                                    return Err(LiveNodeFromCborError::UnexpectedValue)
+                                }
+                                let variant = if let Some(variant) = decode_id(data, &mut o1) ? {
+                                    variant
+                                }
+                                else {
+                                    return Err(LiveNodeFromCborError::ExpectedId)
+                                };
+                                if let Some(len) = decode_array_len(data, &mut o1) ? {
+                                    stack.push(StackItem {count: 0, len, has_keys: false});
+                                    self.push(LiveNode {id, origin, value: LiveValue::TupleEnum(variant)});
+                                }
+                                else if let Some(len) = decode_map_len(data, &mut o1) ? {
+                                    stack.push(StackItem {count: 0, len, has_keys: true});
+                                    self.push(LiveNode {id, origin, value: LiveValue::NamedEnum(variant)});
+                                }
+                                else {
+                                    return Err(LiveNodeFromCborError::UnexpectedValue)
+                                }
+                                o = o1;
+                                continue;
                             }
                             _ => ()
                         }
@@ -787,12 +888,13 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
             };
         }
         // lets unwind the stack
-        while let Some(item) = stack.pop() {
-            if item.count != item.len {
-                return Err(LiveNodeFromCborError::StackNotClosed)
-            }
+        while stack.len() > 1 && stack.last().unwrap().count == stack.last().unwrap().len {
+            self.push(LiveNode {id: LiveId(0), origin: field, value: LiveValue::Close});
+            stack.pop();
+        }
+        if stack.len() != 1 || stack[0].count != stack[0].len {
+            return Err(LiveNodeFromCborError::StackNotClosed)
         }
-        self.push(LiveNode {id: LiveId(0), origin, value: LiveValue::Close});
         Ok(())
     }
 }
@@ -648,7 +648,7 @@ impl<T> LiveNodeSliceApi for T where T: AsRef<[LiveNode]> {
             let pt = match node.origin.prop_type() {
                 LivePropType::Field => ":",
                 LivePropType::Instance => "=",
-                //LivePropType::Template => "=?",
+                LivePropType::Template => "()",
                 LivePropType::Nameless => "??"
             };
             match &node.value {
@@ -766,6 +766,10 @@ impl<T> LiveNodeSliceApi for T where T: AsRef<[LiveNode]> {
                 LiveValue::Import(live_import) => {
                     writeln!(f, "<Import> {}::{} as {}", live_import.module_id, live_import.import_id, node.id).unwrap();
                 }
+                LiveValue::If => {
+                    writeln!(f, "<If>").unwrap();
+                    stack_depth += 1;
+                }
                 /*LiveValue::Registry(component_id) => {
                     writeln!(f, "<Registry> {}::{}", component_id, node.id).unwrap();
                 }*/
@@ -0,0 +1,542 @@
+use {
+    std::fmt::Write,
+    crate::{
+        makepad_live_tokenizer::{LiveId, Delim},
+        makepad_live_id::*,
+        live_node::*,
+        live_node_vec::LiveNodeSliceApi,
+        live_token::LiveToken,
+        live_registry::LiveRegistry,
+    }
+};
+
+// writes nodes back out as live_design! source that parses to the same nodes again.
+// its meant for original documents, expanded ones have lost their clones and imports.
+// the registry is needed for the rust type names of classes, for the tokens of fn bodies
+// and for the const/instance/uniform prefixes, the nodes themselves dont store those.
+pub trait LiveNodeSliceToLiveDesign {
+    fn to_live_design(&self, parent_index: usize, live_registry: &LiveRegistry) -> Result<String, String>;
+}
+
+impl<T> LiveNodeSliceToLiveDesign for T where T: AsRef<[LiveNode]> {
+    // writes the children of parent_index, for the root of a document thats the body of live_design!{}
+    fn to_live_design(&self, parent_index: usize, live_registry: &LiveRegistry) -> Result<String, String> {
+        let nodes = self.as_ref();
+        if parent_index >= nodes.len() || !nodes[parent_index].is_open() {
+            return Err(format!("to_live_design node {} is not an object", parent_index))
+        }
+        let mut writer = LiveDesignWriter {
+            nodes,
+            live_registry,
+            out: String::new()
+        };
+        writer.write_body(parent_index + 1, 0, "", &mut 0) ?;
+        Ok(writer.out)
+    }
+}
+
+struct LiveDesignWriter<'a> {
+    nodes: &'a [LiveNode],
+    live_registry: &'a LiveRegistry,
+    out: String,
+}
+
+impl<'a> LiveDesignWriter<'a> {
+
+    fn write_indent(&mut self, indent: usize) {
+        for _ in 0..indent {
+            self.out.push_str("    ");
+        }
+    }
+
+    fn write_id(&mut self, id: LiveId) -> Result<(), String> {
+        id.as_string( | s | if let Some(s) = s {
+            self.out.push_str(s);
+            Ok(())
+        }
+        else {
+            Err(format!("to_live_design id {} has no name", id.0))
+        })
+    }
+
+    fn write_string(&mut self, s: &str) {
+        self.out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.out.push_str("\\\""),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                c => self.out.push(c)
+            }
+        }
+        self.out.push('"');
+    }
+
+    fn write_float(&mut self, v: f64) -> Result<(), String> {
+        if !v.is_finite() {
+            return Err(format!("to_live_design cannot write float {}", v))
+        }
+        // debug formatting always keeps the dot, so it comes back as a float
+        write!(self.out, "{:?}", v).unwrap();
+        Ok(())
+    }
+
+    fn write_color(&mut self, v: u32) {
+        if v & 0xff == 0xff {
+            write!(self.out, "#x{:06X}", v >> 8).unwrap()
+        }
+        else {
+            write!(self.out, "#x{:08X}", v).unwrap()
+        }
+    }
+
+    fn write_token(&mut self, token: &LiveToken) -> Result<(), String> {
+        match token {
+            LiveToken::Eof => (),
+            LiveToken::String(v) => self.write_string(v),
+            LiveToken::Punct(id) => {
+                // punctuation doesnt go through the id interner
+                const PUNCT: [&str; 45] = [
+                    "...", "..=", "<<=", ">>=", "!=", "%=", "&&", "&=", "*=", "+=", "-=", "->", "..", "/=", "::",
+                    "<<", "<=", "==", "=>", ">=", ">>", "^=", "|=", "||", "!", "#", "$", "%", "&", "*", "+",
+                    ",", "-", ".", "/", ":", ";", "<", "=", ">", "?", "@", "^", "|", "~"
+                ];
+                let punct = PUNCT.iter().find( | p | LiveId::from_str(p) == *id)
+                    .ok_or_else( || format!("to_live_design unknown punctuation {}", id.0)) ?;
+                self.out.push_str(punct);
+            }
+            LiveToken::Ident(id) => self.write_id(*id) ?,
+            LiveToken::Open(Delim::Paren) => self.out.push('('),
+            LiveToken::Open(Delim::Brace) => self.out.push('{'),
+            LiveToken::Open(Delim::Bracket) => self.out.push('['),
+            LiveToken::Close(Delim::Paren) => self.out.push(')'),
+            LiveToken::Close(Delim::Brace) => self.out.push('}'),
+            LiveToken::Close(Delim::Bracket) => self.out.push(']'),
+            LiveToken::Bool(v) => write!(self.out, "{}", v).unwrap(),
+            LiveToken::Int(v) => write!(self.out, "{}", v).unwrap(),
+            LiveToken::Float(v) => self.write_float(*v) ?,
+            LiveToken::Color(v) => self.write_color(*v),
+        }
+        Ok(())
+    }
+
+    // writes the children up to the matching close node, one per line. returns the index of the close.
+    // nameless_id counts the nameless components, if branches continue the count of the body they are in
+    fn write_body(&mut self, mut index: usize, indent: usize, delim: &str, nameless_id: &mut usize) -> Result<usize, String> {
+        while index < self.nodes.len() {
+            let node = &self.nodes[index];
+            if node.is_close() {
+                return Ok(index)
+            }
+            self.write_indent(indent);
+            match &node.value {
+                LiveValue::Import(import) => {
+                    self.out.push_str("import ");
+                    // the registry patched crate:: into the crate name, put it back
+                    let own_crate = node.origin.token_id().and_then( | v | v.file_id()).map( | v | self.live_registry.file_id_to_file(v).module_id.0);
+                    if own_crate == Some(import.module_id.0) {
+                        self.out.push_str("crate");
+                    }
+                    else {
+                        self.write_id(import.module_id.0) ?;
+                    }
+                    self.out.push_str("::");
+                    self.write_id(import.module_id.1) ?;
+                    self.out.push_str("::");
+                    if import.import_id == LiveId(0) {
+                        self.out.push('*');
+                    }
+                    else {
+                        self.write_id(import.import_id) ?;
+                    }
+                    if node.id != import.import_id {
+                        self.out.push_str(" as ");
+                        self.write_id(node.id) ?;
+                    }
+                    self.out.push_str(";\n");
+                    index += 1;
+                    continue;
+                }
+                LiveValue::DSL {token_start, token_count, ..} => {
+                    self.write_dsl(node, *token_start as usize, *token_count as usize, indent) ?;
+                    self.out.push('\n');
+                    index += 1;
+                    continue;
+                }
+                LiveValue::If => {
+                    index = self.write_if(index, indent, nameless_id) ?;
+                    self.out.push('\n');
+                    continue;
+                }
+                LiveValue::Clone(clone) if *clone != live_id!(struct)
+                    && node.id == LiveId::from_str(&format!("nameless_{}", nameless_id)) => {
+                    *nameless_id += 1;
+                }
+                _ => match node.origin.prop_type() {
+                    LivePropType::Nameless => (),
+                    prop_type => {
+                        self.write_prefix(node) ?;
+                        self.write_id(node.id) ?;
+                        self.write_edit_info(node) ?;
+                        if let LivePropType::Field = prop_type {
+                            self.out.push_str(": ");
+                        }
+                        else {
+                            self.out.push_str(" = ");
+                        }
+                    }
+                }
+            }
+            index = self.write_value(index, indent) ?;
+            self.out.push_str(delim);
+            self.out.push('\n');
+        }
+        Err("to_live_design unexpected end of nodes".to_string())
+    }
+
+    // const, instance, uniform and friends are only in the token stream, right before the name
+    fn write_prefix(&mut self, node: &LiveNode) -> Result<(), String> {
+        if !node.origin.node_has_prefix() {
+            return Ok(())
+        }
+        if let Some(token_id) = node.origin.token_id() {
+            let tokens = &self.live_registry.token_id_to_origin_doc(token_id).tokens;
+            if let Some(LiveToken::Ident(prefix)) = token_id.token_index().checked_sub(1).and_then( | i | tokens.get(i)).map( | t | &t.token) {
+                self.write_id(*prefix) ?;
+                self.out.push(' ');
+                return Ok(())
+            }
+        }
+        Err(format!("to_live_design cannot find the prefix of {}", node.id))
+    }
+
+    fn write_edit_info(&mut self, node: &LiveNode) -> Result<(), String> {
+        let (edit_info, token_id) = if let (Some(edit_info), Some(token_id)) = (node.origin.edit_info(), node.origin.token_id()) {
+            (edit_info, token_id)
+        }
+        else {
+            return Ok(())
+        };
+        let edit_nodes = &self.live_registry.token_id_to_origin_doc(token_id).edit_info;
+        self.out.push_str(".{");
+        let mut index = edit_info.edit_info_index() + 1;
+        while let Some(edit_node) = edit_nodes.get(index) {
+            if edit_node.is_close() {
+                break;
+            }
+            if index > edit_info.edit_info_index() + 1 {
+                self.out.push_str(", ");
+            }
+            self.write_id(edit_node.id) ?;
+            self.out.push_str(": ");
+            self.write_literal(&edit_node.value) ?;
+            index += 1;
+        }
+        self.out.push('}');
+        Ok(())
+    }
+
+    // writes the tokens of a fn body with the line breaks and spacing they had in the source
+    fn write_dsl(&mut self, node: &LiveNode, token_start: usize, token_count: usize, indent: usize) -> Result<(), String> {
+        let token_id = node.origin.token_id().ok_or_else( || format!("to_live_design fn {} has no tokens", node.id)) ?;
+        let tokens = &self.live_registry.token_id_to_origin_doc(token_id).tokens;
+        let tokens = tokens.get(token_start..token_start + token_count).ok_or_else( || format!("to_live_design fn {} tokens out of range", node.id)) ?;
+        let base_column = if let Some(first) = tokens.first() {first.span.start.column} else {return Ok(())};
+        let mut last_end = tokens[0].span.start;
+        for token in tokens {
+            let start = token.span.start;
+            if start.line > last_end.line {
+                for _ in 0..(start.line - last_end.line).min(2) {
+                    self.out.push('\n');
+                }
+                self.write_indent(indent);
+                for _ in 0..start.column.saturating_sub(base_column) {
+                    self.out.push(' ');
+                }
+            }
+            else {
+                for _ in 0..start.column.saturating_sub(last_end.column) {
+                    self.out.push(' ');
+                }
+            }
+            self.write_token(&token.token) ?;
+            last_end = token.span.end;
+        }
+        Ok(())
+    }
+
+    fn write_literal(&mut self, value: &LiveValue) -> Result<(), String> {
+        match value {
+            LiveValue::Str(v) => self.write_string(v),
+            LiveValue::String(v) => self.write_string(v),
+            LiveValue::InlineString(v) => self.write_string(v.as_str()),
+            LiveValue::Bool(v) => write!(self.out, "{}", v).unwrap(),
+            LiveValue::Int64(v) => write!(self.out, "{}", v).unwrap(),
+            LiveValue::Float32(v) => write!(self.out, "{:?}", v).unwrap(),
+            LiveValue::Float64(v) => self.write_float(*v) ?,
+            LiveValue::Color(v) => self.write_color(*v),
+            LiveValue::Id(id) | LiveValue::BareEnum(id) => self.write_id(*id) ?,
+            value => return Err(format!("to_live_design cannot write {:?} as a literal", value))
+        }
+        Ok(())
+    }
+
+    // writes the value of the node at index, returns the index of its next sibling
+    fn write_value(&mut self, index: usize, indent: usize) -> Result<usize, String> {
+        let node = &self.nodes[index];
+        match &node.value {
+            LiveValue::Str(_)
+                | LiveValue::String(_)
+                | LiveValue::InlineString(_)
+                | LiveValue::Bool(_)
+                | LiveValue::Int64(_)
+                | LiveValue::Float32(_)
+                | LiveValue::Float64(_)
+                | LiveValue::Color(_)
+                | LiveValue::Id(_)
+                | LiveValue::BareEnum(_) => self.write_literal(&node.value) ?,
+            LiveValue::Dependency(v) => {
+                self.out.push_str("dep(");
+                self.write_string(v);
+                self.out.push(')');
+            }
+            LiveValue::Vec2(v) => write!(self.out, "vec2({:?}, {:?})", v.x, v.y).unwrap(),
+            LiveValue::Vec3(v) => write!(self.out, "vec3({:?}, {:?}, {:?})", v.x, v.y, v.z).unwrap(),
+            LiveValue::Vec4(v) => write!(self.out, "vec4({:?}, {:?}, {:?}, {:?})", v.x, v.y, v.z, v.w).unwrap(),
+            LiveValue::IdPath(path) => {
+                for (i, id) in path.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push('.');
+                    }
+                    self.write_id(*id) ?;
+                }
+            }
+            LiveValue::Expr {..} => {
+                self.out.push('(');
+                let next = self.write_expr(index + 1) ?;
+                self.out.push(')');
+                if next >= self.nodes.len() || !self.nodes[next].is_close() {
+                    return Err(format!("to_live_design expression {} is not closed", node.id))
+                }
+                return Ok(next + 1)
+            }
+            LiveValue::Array => {
+                // arrays of plain values go on one line
+                let close = self.nodes.skip_node(index) - 1;
+                if self.nodes[index + 1..close].iter().all( | v | !v.is_open()) {
+                    self.out.push('[');
+                    for i in index + 1..close {
+                        if i > index + 1 {
+                            self.out.push_str(", ");
+                        }
+                        self.write_value(i, indent) ?;
+                    }
+                    self.out.push(']');
+                    return Ok(close + 1)
+                }
+                self.out.push_str("[\n");
+                let close = self.write_body(index + 1, indent + 1, ",", &mut 0) ?;
+                self.write_indent(indent);
+                self.out.push(']');
+                return Ok(close + 1)
+            }
+            LiveValue::TupleEnum(variant) => {
+                self.write_id(*variant) ?;
+                self.out.push('(');
+                let mut i = index + 1;
+                while i < self.nodes.len() && !self.nodes[i].is_close() {
+                    if i > index + 1 {
+                        self.out.push_str(", ");
+                    }
+                    i = self.write_value(i, indent) ?;
+                }
+                self.out.push(')');
+                return Ok(i + 1)
+            }
+            LiveValue::NamedEnum(variant) => {
+                self.write_id(*variant) ?;
+                self.out.push(' ');
+                return self.write_block(index + 1, indent)
+            }
+            LiveValue::Object | LiveValue::Root {..} => {
+                return self.write_block(index + 1, indent)
+            }
+            LiveValue::Clone(clone) => {
+                if *clone == live_id!(struct) {
+                    self.out.push_str("struct ");
+                }
+                else {
+                    self.out.push('<');
+                    self.write_id(*clone) ?;
+                    self.out.push('>');
+                }
+                // template args are the first children
+                let mut body = index + 1;
+                while self.nodes.get(body).map( | v | !v.is_close() && v.origin.has_prop_type(LivePropType::Template)).unwrap_or(false) {
+                    self.out.push_str(if body == index + 1 {"("} else {", "});
+                    self.write_id(self.nodes[body].id) ?;
+                    self.out.push_str(": ");
+                    body = self.write_value(body, indent) ?;
+                    if !self.nodes.get(body).map( | v | !v.is_close() && v.origin.has_prop_type(LivePropType::Template)).unwrap_or(false) {
+                        self.out.push(')');
+                    }
+                }
+                self.out.push(' ');
+                return self.write_block(body, indent)
+            }
+            LiveValue::Class {live_type, ..} => {
+                let type_name = self.live_registry.live_type_infos.get(live_type)
+                    .map( | info | info.type_name)
+                    .ok_or_else( || format!("to_live_design class {} has an unknown rust type", node.id)) ?;
+                self.out.push_str("{{");
+                self.write_id(type_name) ?;
+                self.out.push_str("}} ");
+                return self.write_block(index + 1, indent)
+            }
+            value => return Err(format!("to_live_design cannot write {:?} as a value of {}", value, node.id))
+        }
+        Ok(index + 1)
+    }
+
+    // writes the block of children starting at first_child, returns the index after its close
+    fn write_block(&mut self, first_child: usize, indent: usize) -> Result<usize, String> {
+        self.write_block_counting(first_child, indent, &mut 0)
+    }
+
+    fn write_block_counting(&mut self, first_child: usize, indent: usize, nameless_id: &mut usize) -> Result<usize, String> {
+        if self.nodes.get(first_child).map( | v | v.is_close()).unwrap_or(false) {
+            self.out.push_str("{}");
+            return Ok(first_child + 1)
+        }
+        self.out.push_str("{\n");
+        let close = self.write_body(first_child, indent + 1, "", nameless_id) ?;
+        self.write_indent(indent);
+        self.out.push('}');
+        Ok(close + 1)
+    }
+
+    // if <expr> {..} else {..}, an else branch holding just another if block is written as else if
+    fn write_if(&mut self, index: usize, indent: usize, nameless_id: &mut usize) -> Result<usize, String> {
+        self.out.push_str("if ");
+        let expr_index = index + 1;
+        if !self.nodes.get(expr_index).map( | v | v.is_expr()).unwrap_or(false) {
+            return Err("to_live_design if block without a condition".to_string())
+        }
+        let then_index = self.write_expr(expr_index + 1) ? + 1;
+        self.out.push(' ');
+        let else_index = self.write_block_counting(then_index + 1, indent, nameless_id) ?;
+        let else_node = self.nodes.get(else_index).ok_or("to_live_design unexpected end of nodes") ?;
+        if else_node.is_close() {
+            return Ok(else_index + 1)
+        }
+        self.out.push_str(" else ");
+        let else_if = self.nodes.get(else_index + 1).map( | v | matches!(v.value, LiveValue::If)).unwrap_or(false);
+        let close = if else_if && self.nodes.get(self.nodes.skip_node(else_index + 1)).map( | v | v.is_close()).unwrap_or(false) {
+            self.write_if(else_index + 1, indent, nameless_id) ?
+        }
+        else {
+            self.write_block_counting(else_index + 1, indent, nameless_id) ? - 1
+        };
+        Ok(close + 1 + 1)
+    }
+
+    // expressions are stored in prefix order, operands only get parens where the precedence needs them
+    fn write_expr(&mut self, index: usize) -> Result<usize, String> {
+        let node = self.nodes.get(index).ok_or("to_live_design unexpected end of expression") ?;
+        match &node.value {
+            LiveValue::ExprBinOp(op) => {
+                let prec = Self::expr_precedence(&node.value);
+                let next = self.write_operand(index + 1, prec) ?;
+                self.out.push_str(match op {
+                    LiveBinOp::Or => " || ",
+                    LiveBinOp::And => " && ",
+                    LiveBinOp::Eq => " == ",
+                    LiveBinOp::Ne => " != ",
+                    LiveBinOp::Lt => " < ",
+                    LiveBinOp::Le => " <= ",
+                    LiveBinOp::Gt => " > ",
+                    LiveBinOp::Ge => " >= ",
+                    LiveBinOp::Add => " + ",
+                    LiveBinOp::Sub => " - ",
+                    LiveBinOp::Mul => " * ",
+                    LiveBinOp::Div => " / ",
+                });
+                // operators are left associative
+                self.write_operand(next, prec + 1)
+            }
+            LiveValue::ExprUnOp(op) => {
+                self.out.push(match op {
+                    LiveUnOp::Not => '!',
+                    LiveUnOp::Neg => '-',
+                });
+                self.write_operand(index + 1, 7)
+            }
+            LiveValue::ExprCall {ident: live_id!(cond), args: 3} => {
+                // the parser turns a ? b : c into a cond call
+                let next = self.write_operand(index + 1, 1) ?;
+                self.out.push_str(" ? ");
+                let next = self.write_expr(next) ?;
+                self.out.push_str(" : ");
+                self.write_expr(next)
+            }
+            LiveValue::ExprCall {ident, args} => {
+                self.write_id(*ident) ?;
+                self.out.push('(');
+                let mut next = index + 1;
+                for i in 0..*args {
+                    if i > 0 {
+                        self.out.push_str(", ");
+                    }
+                    if !self.nodes[next].id.is_empty() {
+                        self.write_id(self.nodes[next].id) ?;
+                        self.out.push_str(": ");
+                    }
+                    next = self.write_expr(next) ?;
+                }
+                self.out.push(')');
+                Ok(next)
+            }
+            LiveValue::ExprMember(ident) => {
+                let next = self.write_operand(index + 1, 8) ?;
+                self.out.push('.');
+                self.write_id(*ident) ?;
+                Ok(next)
+            }
+            LiveValue::Bool(_)
+                | LiveValue::Int64(_)
+                | LiveValue::Float64(_)
+                | LiveValue::Color(_)
+                | LiveValue::String(_)
+                | LiveValue::Id(_) => {
+                self.write_literal(&node.value) ?;
+                Ok(index + 1)
+            }
+            value => Err(format!("to_live_design cannot write {:?} in an expression", value))
+        }
+    }
+
+    fn expr_precedence(value: &LiveValue) -> u8 {
+        match value {
+            LiveValue::ExprCall {ident: live_id!(cond), args: 3} => 0,
+            LiveValue::ExprBinOp(LiveBinOp::Or) => 1,
+            LiveValue::ExprBinOp(LiveBinOp::And) => 2,
+            LiveValue::ExprBinOp(LiveBinOp::Eq | LiveBinOp::Ne) => 3,
+            LiveValue::ExprBinOp(LiveBinOp::Lt | LiveBinOp::Le | LiveBinOp::Gt | LiveBinOp::Ge) => 4,
+            LiveValue::ExprBinOp(LiveBinOp::Add | LiveBinOp::Sub) => 5,
+            LiveValue::ExprBinOp(LiveBinOp::Mul | LiveBinOp::Div) => 6,
+            LiveValue::ExprUnOp(_) => 7,
+            _ => 8
+        }
+    }
+
+    fn write_operand(&mut self, index: usize, min_precedence: u8) -> Result<usize, String> {
+        let node = self.nodes.get(index).ok_or("to_live_design unexpected end of expression") ?;
+        if Self::expr_precedence(&node.value) < min_precedence {
+            self.out.push('(');
+            let next = self.write_expr(index) ?;
+            self.out.push(')');
+            return Ok(next)
+        }
+        self.write_expr(index)
+    }
+}
@@ -468,12 +468,13 @@ impl<'a> LiveParser<'a> {
                 
                 let ident = self.expect_ident()?;
                 self.expect_token(LiveToken::Punct(live_id!(>))) ?;
-                self.expect_token(LiveToken::Open(Delim::Brace))?;
                 ld.nodes.push(LiveNode {
                     origin,
                     id: prop_id,
                     value: LiveValue::Clone(ident)
                 });
+                self.expect_template_args(ld) ?;
+                self.expect_token(LiveToken::Open(Delim::Brace))?;
                 self.expect_live_class(false, prop_id, ld) ?;
             }
             LiveToken::Ident(live_id!(struct)) => { // key/value map
@@ -778,8 +779,83 @@ impl<'a> LiveParser<'a> {
         Err(self.error("Expected value literal".to_string(), live_error_origin!()))
     }
     
+    // <Card>(title: "Hello") {..} passes template arguments, they go in front of the body
+    fn expect_template_args(&mut self, ld: &mut LiveOriginal) -> Result<(), LiveError> {
+        if !self.accept_token(LiveToken::Open(Delim::Paren)) {
+            return Ok(())
+        }
+        while self.peek_token() != LiveToken::Eof {
+            if self.accept_token(LiveToken::Close(Delim::Paren)) {
+                return Ok(())
+            }
+            let token_id = self.get_token_id();
+            let prop_id = self.expect_ident() ?;
+            self.expect_token(LiveToken::Punct(live_id!(:))) ?;
+            self.expect_live_value(prop_id, LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Template), ld) ?;
+            self.accept_token(LiveToken::Punct(live_id!(,)));
+        }
+        Err(self.error("Eof in template arguments".to_string(), live_error_origin!()))
+    }
+    
+    // if <expr> {..} else if <expr> {..} else {..}
+    // the branches share the nameless counter of the body they are in, they end up in it after expansion
+    fn expect_if(&mut self, token_id: LiveTokenId, nameless_id: &mut usize, ld: &mut LiveOriginal) -> Result<(), LiveError> {
+        ld.nodes.push(LiveNode {
+            origin: LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Nameless),
+            id: LiveId::empty(),
+            value: LiveValue::If
+        });
+        let expr_token_id = self.get_token_id();
+        let expr = self.expect_expr() ?;
+        self.push_expression(LiveId::empty(), LiveNodeOrigin::from_token_id(expr_token_id).with_prop_type(LivePropType::Nameless), expr, ld);
+        self.expect_if_branch(nameless_id, ld) ?;
+        
+        let else_token_id = self.get_token_id();
+        if self.accept_token(LiveToken::Ident(live_id!(else))) {
+            if self.peek_token() == LiveToken::Ident(live_id!(if)) {
+                let if_token_id = self.get_token_id();
+                self.skip_token();
+                ld.nodes.push(LiveNode {
+                    origin: LiveNodeOrigin::from_token_id(else_token_id).with_prop_type(LivePropType::Nameless),
+                    id: LiveId::empty(),
+                    value: LiveValue::Object
+                });
+                self.expect_if(if_token_id, nameless_id, ld) ?;
+                ld.nodes.push(LiveNode {
+                    origin: LiveNodeOrigin::from_token_id(self.get_token_id()),
+                    id: LiveId::empty(),
+                    value: LiveValue::Close
+                });
+            }
+            else {
+                self.expect_if_branch(nameless_id, ld) ?;
+            }
+        }
+        ld.nodes.push(LiveNode {
+            origin: LiveNodeOrigin::from_token_id(self.get_token_id()),
+            id: LiveId::empty(),
+            value: LiveValue::Close
+        });
+        Ok(())
+    }
+    
+    fn expect_if_branch(&mut self, nameless_id: &mut usize, ld: &mut LiveOriginal) -> Result<(), LiveError> {
+        let token_id = self.get_token_id();
+        self.expect_token(LiveToken::Open(Delim::Brace)) ?;
+        ld.nodes.push(LiveNode {
+            origin: LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Nameless),
+            id: LiveId::empty(),
+            value: LiveValue::Object
+        });
+        self.expect_live_class_body(false, LiveId::empty(), nameless_id, ld)
+    }
+    
     fn expect_live_class(&mut self, root: bool, prop_id: LiveId, ld: &mut LiveOriginal) -> Result<(), LiveError> {
         let mut nameless_id = 0;
+        self.expect_live_class_body(root, prop_id, &mut nameless_id, ld)
+    }
+    
+    fn expect_live_class_body(&mut self, root: bool, prop_id: LiveId, nameless_id: &mut usize, ld: &mut LiveOriginal) -> Result<(), LiveError> {
         while self.peek_token() != LiveToken::Eof {
             match self.peek_token() {
                 LiveToken::Close(Delim::Brace) => {
@@ -800,13 +876,14 @@ impl<'a> LiveParser<'a> {
                     let token_id = self.get_token_id();
                     let ident = self.expect_ident()?;
                     self.expect_token(LiveToken::Punct(live_id!(>))) ?;
-                    self.expect_token(LiveToken::Open(Delim::Brace))?;
                     ld.nodes.push(LiveNode {
                         origin: LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Instance),
                         id: LiveId::from_str(&format!("nameless_{}", nameless_id)),
                         value: LiveValue::Clone(ident)
                     });
-                    nameless_id += 1;
+                    *nameless_id += 1;
+                    self.expect_template_args(ld) ?;
+                    self.expect_token(LiveToken::Open(Delim::Brace))?;
                     self.expect_live_class(false, prop_id, ld) ?;
                 }
                 LiveToken::Ident(prop_id) => {
@@ -816,7 +893,10 @@ impl<'a> LiveParser<'a> {
                     //let span = self.begin_span();
                     // next
                     // there is another token coming
-                    if let LiveToken::Ident(_) = self.peek_token() {
+                    if prop_id == live_id!(if) && !matches!(self.peek_token(), LiveToken::Punct(live_id!(:)) | LiveToken::Punct(live_id!(=))) {
+                        self.expect_if(token_id, nameless_id, ld) ?;
+                    }
+                    else if let LiveToken::Ident(_) = self.peek_token() {
                         match prop_id {
                             live_id!(fn) => {
                                 self.expect_fn(ld) ?;
@@ -916,7 +996,11 @@ impl<'a> LiveParser<'a> {
     fn expect_expression(&mut self, prop_id: LiveId, origin: LiveNodeOrigin, ld: &mut LiveOriginal) -> Result<(), LiveError> {
         
         let expr = self.expect_prim_expr() ?;
-        
+        self.push_expression(prop_id, origin, expr, ld);
+        Ok(())
+    }
+    
+    fn push_expression(&self, prop_id: LiveId, origin: LiveNodeOrigin, expr: Expr, ld: &mut LiveOriginal) {
         ld.nodes.push(LiveNode {
             origin,
             id: prop_id,
@@ -960,6 +1044,12 @@ impl<'a> LiveParser<'a> {
                     });
                     recur_walk(*expr, ld);
                 }
+                Expr::Named {ident, expr} => {
+                    // the name goes on the first node of the argument
+                    let index = ld.nodes.len();
+                    recur_walk(*expr, ld);
+                    ld.nodes[index].id = ident;
+                }
                 Expr::Var {token_id, ident} => {
                     ld.nodes.push(LiveNode {
                         origin: LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Nameless),
@@ -995,6 +1085,13 @@ impl<'a> LiveParser<'a> {
                         value: LiveValue::Color(v)
                     });
                 }
+                Expr::String {token_id, v} => {
+                    ld.nodes.push(LiveNode {
+                        origin: LiveNodeOrigin::from_token_id(token_id).with_prop_type(LivePropType::Nameless),
+                        id: LiveId::empty(),
+                        value: LiveValue::String(v)
+                    });
+                }
             }
         }
         
@@ -1005,9 +1102,6 @@ impl<'a> LiveParser<'a> {
             id: prop_id,
             value: LiveValue::Close
         });
-        
-        
-        Ok(())
         /*
         ld.nodes.push(LiveNode {
             token_id: Some(self.get_token_id()),
@@ -1166,25 +1260,30 @@ impl<'a> LiveParser<'a> {
                 }
             }
             LiveToken::Bool(v) => {
-                self.skip_token();
                 let token_id = self.get_token_id();
+                self.skip_token();
                 Ok(Expr::Bool {token_id, v})
             }
             LiveToken::Int(v) => {
-                self.skip_token();
                 let token_id = self.get_token_id();
+                self.skip_token();
                 Ok(Expr::Int {token_id, v})
             }
             LiveToken::Float(v) => {
-                self.skip_token();
                 let token_id = self.get_token_id();
+                self.skip_token();
                 Ok(Expr::Float {token_id, v})
             }
             LiveToken::Color(v) => {
-                self.skip_token();
                 let token_id = self.get_token_id();
+                self.skip_token();
                 Ok(Expr::Color {token_id, v})
             }
+            LiveToken::String(v) => {
+                let token_id = self.get_token_id();
+                self.skip_token();
+                Ok(Expr::String {token_id, v})
+            }
             LiveToken::Open(Delim::Paren) => {
                 self.skip_token();
                 let expr = self.expect_expr() ?;
@@ -1200,7 +1299,16 @@ impl<'a> LiveParser<'a> {
         let mut arg_exprs = Vec::new();
         if !self.accept_token(LiveToken::Close(Delim::Paren)) {
             loop {
-                arg_exprs.push(self.expect_expr() ?);
+                let expr = self.expect_expr() ?;
+                // named args, like tr("inbox", count: 3)
+                if let (Expr::Var {ident, ..}, LiveToken::Punct(live_id!(:))) = (&expr, self.peek_token()) {
+                    let ident = *ident;
+                    self.skip_token();
+                    arg_exprs.push(Expr::Named {ident, expr: Box::new(self.expect_expr() ?)});
+                }
+                else {
+                    arg_exprs.push(expr);
+                }
                 if !self.accept_token(LiveToken::Punct(live_id!(,))) {
                     break;
                 }
@@ -1236,6 +1344,10 @@ enum Expr {
         ident: LiveId,
         expr: Box<Expr>
     },
+    Named {
+        ident: LiveId,
+        expr: Box<Expr>
+    },
     Var {
         token_id: LiveTokenId,
         ident: LiveId,
@@ -1255,6 +1367,10 @@ enum Expr {
     Color {
         token_id: LiveTokenId,
         v: u32
+    },
+    String {
+        token_id: LiveTokenId,
+        v: Rc<String>
     }
 }
 
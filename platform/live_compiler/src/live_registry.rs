@@ -8,20 +8,25 @@ use {
         live_error::{LiveError, LiveErrorSpan, LiveFileError},
         live_parser::LiveParser,
         live_document::{LiveOriginal, LiveExpanded},
-        live_node::{LiveNodeOrigin, LiveNode, LiveValue, LiveType, LiveTypeInfo, LiveIdAsProp},
+        live_node::{LiveNodeOrigin, LiveNode, LiveValue, LiveType, LiveTypeInfo, LiveTypeField, LiveFieldKind, LiveIdAsProp},
         /*live_node_reader::{LiveNodeMutReader},*/
         live_node_vec::{LiveNodeSliceApi, /*LiveNodeVecApi*/},
         live_ptr::{LiveFileId, LivePtr, LiveModuleId, LiveFileGeneration},
         live_token::{LiveToken, LiveTokenId, TokenWithSpan},
         span::{TextSpan, TextPos},
         live_expander::{LiveExpander},
-        live_component::{LiveComponentRegistries}
+        live_component::{LiveComponentRegistries},
+        live_localization::LiveLocalization,
     }
 };
 
 #[derive(Default)]
 pub struct LiveFile {
     pub (crate) reexpand: bool,
+    // loaded at runtime as a plain .live document instead of coming from a live_design! macro
+    pub (crate) standalone: bool,
+    // another module whose top level definitions are expanded on top of this one
+    pub (crate) overlay: Option<LiveModuleId>,
     
     pub module_id: LiveModuleId,
     pub (crate) start_pos: TextPos,
@@ -46,7 +51,11 @@ pub struct LiveRegistry {
     //pub ignore_no_dsl: HashSet<LiveId>,
     pub main_module: Option<(LiveModuleId, LiveId)>,
     pub components: LiveComponentRegistries,
-    pub package_root: Option<String>
+    pub package_root: Option<String>,
+    // the values if blocks are decided on while expanding, like os or an app feature
+    pub (crate) conditions: HashMap<LiveId, LiveValue>,
+    // the translation catalogs tr() in expressions looks messages up in
+    pub localization: LiveLocalization,
 }
 
 impl Default for LiveRegistry {
@@ -58,7 +67,9 @@ impl Default for LiveRegistry {
             live_files: Vec::new(),
             live_type_infos: HashMap::new(),
             components: LiveComponentRegistries::default(),
-            package_root: None
+            package_root: None,
+            conditions: HashMap::new(),
+            localization: LiveLocalization::default(),
         }
     }
 }
@@ -114,6 +125,9 @@ impl LiveRegistry {
     pub fn file_id_to_cargo_manifest_path(&self, file_id: LiveFileId) -> String {
         let file = &self.live_files[file_id.to_index()];
         let manifest_path = &file.cargo_manifest_path;
+        if file.standalone {
+            return manifest_path.to_string();
+        }
         if let Some(package_root) = &self.package_root {
             if file.module_id.0.0 == 0 {
                 return package_root.to_string();
@@ -379,7 +393,66 @@ impl LiveRegistry {
             }
         }
     }
-    
+
+    // the field names the rust type holding nodes[index] accepts, deref fields count as its own
+    pub fn live_type_field_names(&self, nodes: &[LiveNode], index: usize) -> Vec<LiveId> {
+        fn collect(info: &LiveTypeInfo, out: &mut Vec<LiveId>) {
+            for field in &info.fields {
+                match field.live_field_kind {
+                    LiveFieldKind::Deref => collect(&field.live_type_info, out),
+                    LiveFieldKind::Calc => (),
+                    _ => out.push(field.id)
+                }
+            }
+        }
+        let mut out = Vec::new();
+        if index == 0 {
+            return out
+        }
+        if let Some(info) = nodes.parent(index).and_then( | parent | self.node_live_type_info(nodes, parent)) {
+            collect(info, &mut out);
+        }
+        out
+    }
+
+    // the rust type nodes[index] is applied to. It walks up to the closest class with a registered
+    // type and back down through the struct fields, looking through deref fields on the way
+    pub fn node_live_type_info(&self, nodes: &[LiveNode], index: usize) -> Option<&LiveTypeInfo> {
+        let mut path = Vec::new();
+        let mut child = index;
+        let mut info = loop {
+            if let LiveValue::Class {live_type, ..} = &nodes[child].value {
+                if let Some(info) = self.live_type_infos.get(live_type) {
+                    break info
+                }
+            }
+            if child == 0 {
+                return None
+            }
+            path.push(nodes[child].id);
+            child = nodes.parent(child) ?;
+        };
+        for id in path.iter().rev() {
+            info = &Self::find_live_type_field(info, *id) ?.live_type_info;
+        }
+        Some(info)
+    }
+
+    // the field called id on info, or on the types it derefs to
+    pub fn find_live_type_field(info: &LiveTypeInfo, id: LiveId) -> Option<&LiveTypeField> {
+        for field in &info.fields {
+            if field.id == id && field.live_field_kind != LiveFieldKind::Deref {
+                return Some(field)
+            }
+            if field.live_field_kind == LiveFieldKind::Deref {
+                if let Some(field) = Self::find_live_type_field(&field.live_type_info, id) {
+                    return Some(field)
+                }
+            }
+        }
+        None
+    }
+
     pub fn token_id_to_span(&self, token_id: LiveTokenId) -> TextSpan {
         self.live_files[token_id.file_id().unwrap().to_index()].original.token_id_to_span(token_id)
     }
@@ -461,6 +534,9 @@ impl LiveRegistry {
                                 negative.push(full_token);
                             }
                         }
+                        Parse::Bang | Parse::Brace if full_token.is_ws_or_comment() => {
+                            // live_design! {
+                        }
                         Parse::Bang=> if let FullToken::Punct(live_id!(!)) = &full_token.token{
                             parse = Parse::Brace;
                         }
@@ -521,7 +597,13 @@ impl LiveRegistry {
             let file_id = self.file_name_to_file_id(&change.file_name).unwrap();
             let live_file = self.file_id_to_file_mut(file_id);
             
-            match Self::tokenize_from_str_live_design(&change.content, TextPos::default(), file_id, None) {
+            let tokens = if live_file.standalone {
+                Self::tokenize_from_str(&change.content, live_file.start_pos, file_id)
+            }
+            else {
+                Self::tokenize_from_str_live_design(&change.content, TextPos::default(), file_id, None)
+            };
+            match tokens {
                 Err(msg) => errors.push(msg), //panic!("Lex error {}", msg),
                 Ok(new_tokens) => {
                     let mut parser = LiveParser::new(&new_tokens, &live_file.live_type_infos, file_id);
@@ -564,6 +646,44 @@ impl LiveRegistry {
             Ok(lex_result) => lex_result
         };
         
+        self.register_live_tokens(file_name, cargo_manifest_path, own_module_id, source, tokens, live_type_infos, start_pos)
+    }
+    
+    // registers the live_design! block of a whole rust file the same way live reload reads it, so
+    // spans are positions in that file. for tools that read the sources instead of running the app
+    pub fn register_live_design_file(
+        &mut self,
+        file_name: &str,
+        cargo_manifest_path: &str,
+        own_module_id: LiveModuleId,
+        rust_source: String,
+        live_type_infos: Vec<LiveTypeInfo>,
+    ) -> Result<LiveFileId, LiveFileError> {
+        if self.file_ids.get(file_name).is_some() {
+            panic!("cant register same file twice {}", file_name);
+        }
+        let file_id = LiveFileId::new(self.live_files.len());
+        
+        let tokens = match Self::tokenize_from_str_live_design(&rust_source, TextPos::default(), file_id, None) {
+            Err(msg) => return Err(msg.into_live_file_error(file_name)),
+            Ok(lex_result) => lex_result
+        };
+        
+        self.register_live_tokens(file_name, cargo_manifest_path, own_module_id, rust_source, tokens, live_type_infos, TextPos::default())
+    }
+    
+    fn register_live_tokens(
+        &mut self,
+        file_name: &str,
+        cargo_manifest_path: &str,
+        own_module_id: LiveModuleId,
+        source: String,
+        tokens: Vec<TokenWithSpan>,
+        live_type_infos: Vec<LiveTypeInfo>,
+        start_pos: TextPos,
+    ) -> Result<LiveFileId, LiveFileError> {
+        let file_id = LiveFileId::new(self.live_files.len());
+        
         let mut parser = LiveParser::new(&tokens, &live_type_infos, file_id);
         
         let mut original = match parser.parse_live_document() {
@@ -617,6 +737,8 @@ impl LiveRegistry {
         let live_file = LiveFile {
             cargo_manifest_path: cargo_manifest_path.to_string(),
             reexpand: true,
+            standalone: false,
+            overlay: None,
             module_id: own_module_id,
             file_name: file_name.to_string(),
             start_pos,
@@ -636,6 +758,115 @@ impl LiveRegistry {
         Ok(file_id)
     }
     
+    // registers a .live document that isnt compiled into the binary, like a user skin or a plugin ui.
+    // it can only refer to rust types through the modules it imports, and crate://self/ dependencies
+    // resolve against base_path. loading the same file name again replaces its contents.
+    pub fn register_standalone_live_file(
+        &mut self,
+        file_name: &str,
+        base_path: &str,
+        own_module_id: LiveModuleId,
+        source: String,
+        errors: &mut Vec<LiveError>
+    ) -> Result<LiveFileId, LiveFileError> {
+        if let Some(file_id) = self.file_name_to_file_id(file_name) {
+            if self.live_files[file_id.to_index()].module_id != own_module_id {
+                return Err(LiveFileError {
+                    origin: live_error_origin!(),
+                    file: file_name.to_string(),
+                    span: TextSpan::default(),
+                    message: format!("file already registered as module {}", self.live_files[file_id.to_index()].module_id)
+                })
+            }
+            self.process_file_changes(vec![LiveFileChange {
+                file_name: file_name.to_string(),
+                content: source
+            }], errors);
+            return Ok(file_id)
+        }
+        if let Some(file_id) = self.module_id_to_file_id(own_module_id) {
+            return Err(LiveFileError {
+                origin: live_error_origin!(),
+                file: file_name.to_string(),
+                span: TextSpan::default(),
+                message: format!("module {} already registered by {}", own_module_id, self.file_id_to_file_name(file_id))
+            })
+        }
+        let file_id = self.register_live_file(file_name, base_path, own_module_id, source, Vec::new(), TextPos::default()) ?;
+        self.live_files[file_id.to_index()].standalone = true;
+        self.expand_all_documents(errors);
+        Ok(file_id)
+    }
+    
+    // expands module_id with the top level definitions of overlay merged on top, which is how
+    // themes swap out their tokens at runtime. passing None goes back to the module as written
+    pub fn set_module_overlay(&mut self, module_id: LiveModuleId, overlay: Option<LiveModuleId>, errors: &mut Vec<LiveError>) -> Result<(), LiveFileError> {
+        let file_id = if let Some(file_id) = self.module_id_to_file_id(module_id) {
+            file_id
+        }
+        else {
+            return Err(LiveFileError {
+                origin: live_error_origin!(),
+                file: String::new(),
+                span: TextSpan::default(),
+                message: format!("set_module_overlay module {} not registered", module_id)
+            })
+        };
+        if let Some(overlay) = overlay {
+            fn recur_depends_on(current: LiveModuleId, target: LiveModuleId, files: &Vec<LiveFile>) -> bool {
+                if current == target {
+                    return true
+                }
+                if let Some(file) = files.iter().find( | v | v.module_id == current) {
+                    return file.deps.iter().chain(file.overlay.iter()).any( | dep | recur_depends_on(*dep, target, files))
+                }
+                false
+            }
+            if self.module_id_to_file_id(overlay).is_none() || recur_depends_on(overlay, module_id, &self.live_files) {
+                return Err(LiveFileError {
+                    origin: live_error_origin!(),
+                    file: self.file_id_to_file_name(file_id).to_string(),
+                    span: TextSpan::default(),
+                    message: format!("cannot use {} as overlay for {}, its not registered or depends on it", overlay, module_id)
+                })
+            }
+        }
+        let live_file = &mut self.live_files[file_id.to_index()];
+        if live_file.overlay != overlay {
+            live_file.overlay = overlay;
+            live_file.reexpand = true;
+            live_file.generation.next_gen();
+            self.expand_all_documents(errors);
+        }
+        Ok(())
+    }
+    
+    pub fn module_overlay(&self, module_id: LiveModuleId) -> Option<LiveModuleId> {
+        self.module_id_to_file_id(module_id).and_then( | file_id | self.live_files[file_id.to_index()].overlay)
+    }
+    
+    // sets a value that if blocks can test, names that are not set read as false.
+    // returns true if documents using if blocks need expand_all_documents to pick it up
+    pub fn set_condition(&mut self, id: LiveId, value: LiveValue) -> bool {
+        if self.conditions.get(&id) == Some(&value) {
+            return false
+        }
+        self.conditions.insert(id, value);
+        let mut changed = false;
+        for live_file in &mut self.live_files {
+            if live_file.original.nodes.iter().any( | node | matches!(node.value, LiveValue::If)) {
+                live_file.reexpand = true;
+                live_file.generation.next_gen();
+                changed = true;
+            }
+        }
+        changed
+    }
+    
+    pub fn condition(&self, id: LiveId) -> Option<&LiveValue> {
+        self.conditions.get(&id)
+    }
+    
     pub fn expand_all_documents(&mut self, errors: &mut Vec<LiveError>) {
         // lets build up all dependencies here
         
@@ -664,7 +895,7 @@ impl LiveRegistry {
                 parent_index
             };
             
-            for dep in &file.deps {
+            for dep in file.deps.iter().chain(file.overlay.iter()) {
                 recur_insert_dep(final_index, dep_order, *dep, files);
             }
         }
@@ -686,7 +917,7 @@ impl LiveRegistry {
                 return true;
             }
             
-            for dep in &file.deps {
+            for dep in file.deps.iter().chain(file.overlay.iter()) {
                 if recur_check_reexpand(*dep, files) {
                     return true
                 }
@@ -719,6 +950,25 @@ impl LiveRegistry {
             
             let in_doc = &self.live_files[file_id.to_index()].original;
             
+            // the overlay goes in as if its definitions were written at the end of the document.
+            // we take them expanded so expressions keep resolving against the overlay module
+            let merged_doc;
+            let in_doc = if let Some(overlay_file_id) = self.live_files[file_id.to_index()].overlay.and_then( | v | self.module_id_to_file_id(v)) {
+                let overlay_nodes = &self.live_files[overlay_file_id.to_index()].expanded.nodes;
+                let mut nodes = in_doc.nodes[0..in_doc.nodes.len() - 1].to_vec();
+                nodes.extend(overlay_nodes[1..overlay_nodes.len() - 1].iter().filter( | v | !matches!(v.value, LiveValue::Import(_))).cloned());
+                nodes.push(in_doc.nodes.last().unwrap().clone());
+                merged_doc = LiveOriginal {
+                    nodes,
+                    edit_info: Vec::new(),
+                    tokens: in_doc.tokens.clone(),
+                };
+                &merged_doc
+            }
+            else {
+                in_doc
+            };
+            
             let mut live_document_expander = LiveExpander {
                 live_registry: self,
                 in_crate: module_id.0,
@@ -0,0 +1,550 @@
+use {
+    std::{
+        any::TypeId,
+        collections::{HashMap, HashSet},
+        path::Path,
+    },
+    crate::{
+        makepad_live_tokenizer::{State, Cursor, FullToken, Delim},
+        makepad_live_id::*,
+        live_ptr::{LiveModuleId, LiveFileId},
+        live_node::{LiveType, LiveTypeInfo, LiveTypeField, LiveFieldKind},
+        live_token::LiveToken,
+        live_registry::LiveRegistry,
+        span::TextPos,
+    }
+};
+
+// The app gets its LiveTypeInfos from the Live derive at compile time. Tools that never compile
+// the app, like the language server and the offline shader compiler, read the structs and enums
+// deriving Live out of the rust sources and build the same infos from those
+
+#[derive(Clone, Debug)]
+pub struct RustType {
+    pub name: String,
+    pub module_path: String,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub doc: String,
+    pub live_ignore: bool,
+    pub fields: Vec<RustField>,
+    // bare variants of #[repr(u32)] enums, which shaders can use
+    pub shader_variants: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RustField {
+    pub name: String,
+    // the first attribute, which is what the derive looks at: live, deref, animator, calc, walk..
+    pub attr: String,
+    pub ty: String,
+    pub line: u32,
+    pub column: u32,
+    pub doc: String,
+}
+
+// the fields the derive splats #[walk] and #[layout] fields into, see derive_live
+pub const WALK_SPLAT: &[&str] = &["abs_pos", "margin", "width", "height"];
+pub const LAYOUT_SPLAT: &[&str] = &["scroll", "clip_x", "clip_y", "padding", "align", "flow", "spacing"];
+
+impl RustType {
+    pub fn module_id(&self) -> LiveModuleId {
+        LiveModuleId::from_str(&self.module_path).unwrap_or_default()
+    }
+
+    // the field called name, including the ones #[walk] and #[layout] fields splat out and the ones
+    // of deref fields. Those come back with the type that declares them
+    pub fn field<'a>(&'a self, name: &str, types: &'a RustTypes) -> Option<(&'a RustType, &'a RustField)> {
+        self.field_nested(name, types, 0)
+    }
+
+    fn field_nested<'a>(&'a self, name: &str, types: &'a RustTypes, depth: usize) -> Option<(&'a RustType, &'a RustField)> {
+        if let Some(field) = self.fields.iter().find( | f | f.name == name && f.attr != "rust" && f.attr != "deref") {
+            return Some((self, field))
+        }
+        if depth > 8 {
+            return None
+        }
+        for field in &self.fields {
+            let splat = match field.attr.as_ref() {
+                "walk" => WALK_SPLAT,
+                "layout" => LAYOUT_SPLAT,
+                "deref" => {
+                    if let Some(found) = types.resolve(&field.ty, &self.module_path).and_then( | t | t.field_nested(name, types, depth + 1)) {
+                        return Some(found)
+                    }
+                    continue
+                }
+                _ => continue
+            };
+            if splat.contains(&name) {
+                let splat_type = types.resolve(&field.ty, &self.module_path) ?;
+                return Some((splat_type, splat_type.fields.iter().find( | f | f.name == name) ?))
+            }
+        }
+        None
+    }
+}
+
+struct RustToken {
+    token: FullToken,
+    text: String,
+    line: u32,
+    column: u32,
+}
+
+impl RustToken {
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.token, FullToken::Punct(_)) && self.text == punct
+    }
+
+    fn ident(&self) -> Option<&str> {
+        if let FullToken::Ident(_) = self.token {Some(&self.text)} else {None}
+    }
+}
+
+fn tokenize(source: &str) -> Vec<RustToken> {
+    let mut tokens = Vec::new();
+    let mut state = State::default();
+    let mut scratch = String::new();
+    let mut line_chars = Vec::new();
+    for (line, line_str) in source.lines().enumerate() {
+        line_chars.clear();
+        line_chars.extend(line_str.chars());
+        let mut cursor = Cursor::new(&line_chars, &mut scratch);
+        let mut column = 0;
+        loop {
+            let (next_state, full_token) = state.next(&mut cursor);
+            let full_token = if let Some(full_token) = full_token {full_token} else {break};
+            if !full_token.is_whitespace() {
+                tokens.push(RustToken {
+                    text: line_chars[column..column + full_token.len].iter().collect(),
+                    token: full_token.token,
+                    line: line as u32,
+                    column: column as u32,
+                });
+            }
+            column += full_token.len;
+            state = next_state;
+        }
+    }
+    tokens
+}
+
+// index of the token after the group opening at tokens[index]
+fn skip_group(tokens: &[RustToken], mut index: usize) -> usize {
+    let mut depth = 0;
+    while index < tokens.len() {
+        match tokens[index].token {
+            FullToken::Open(_) => depth += 1,
+            FullToken::Close(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    return index + 1
+                }
+            }
+            _ => ()
+        }
+        index += 1;
+    }
+    index
+}
+
+// skips <..> generics, the tokenizer can glue the closing brackets together
+fn skip_generics(tokens: &[RustToken], mut index: usize) -> usize {
+    if !tokens.get(index).is_some_and(| t | t.is_punct("<")) {
+        return index
+    }
+    let mut depth = 0i32;
+    while index < tokens.len() {
+        if let FullToken::Punct(_) = tokens[index].token {
+            if tokens[index].text != "->" && tokens[index].text != "=>" {
+                depth += tokens[index].text.matches('<').count() as i32;
+                depth -= tokens[index].text.matches('>').count() as i32;
+            }
+        }
+        index += 1;
+        if depth <= 0 {
+            break
+        }
+    }
+    index
+}
+
+fn doc_line(token: &RustToken, doc: &mut String) {
+    if let Some(line) = token.text.strip_prefix("///") {
+        if !doc.is_empty() {
+            doc.push('\n');
+        }
+        doc.push_str(line.strip_prefix(' ').unwrap_or(line));
+    }
+}
+
+// the first ident of the #[..] at tokens[index], all idents in it and the index after it
+fn attribute(tokens: &[RustToken], index: usize) -> (String, Vec<String>, usize) {
+    let end = skip_group(tokens, index + 1);
+    let idents: Vec<String> = tokens[index + 1..end].iter().filter_map( | t | t.ident().map( | s | s.to_string())).collect();
+    (idents.first().cloned().unwrap_or_default(), idents, end)
+}
+
+pub fn scan_rust_types(file: &str, module_path: &str, source: &str, out: &mut Vec<RustType>) {
+    let tokens = tokenize(source);
+    let mut doc = String::new();
+    let mut derive_live = false;
+    let mut live_ignore = false;
+    let mut repr_u32 = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        if token.token.is_comment() {
+            doc_line(token, &mut doc);
+            index += 1;
+            continue;
+        }
+        if token.is_punct("#") && tokens.get(index + 1).is_some_and(| t | t.token.is_open_delim(Delim::Bracket)) {
+            let (name, idents, end) = attribute(&tokens, index);
+            if name == "derive" && idents.iter().any( | i | i == "Live") {
+                derive_live = true;
+            }
+            if name == "live_ignore" {
+                live_ignore = true;
+            }
+            if name == "repr" && idents.iter().any( | i | i == "u32") {
+                repr_u32 = true;
+            }
+            index = end;
+            continue;
+        }
+        match token.ident() {
+            Some("pub") => {
+                index += 1;
+                if tokens.get(index).is_some_and(| t | t.token.is_open_delim(Delim::Paren)) {
+                    index = skip_group(&tokens, index);
+                }
+                continue;
+            }
+            Some(kind @ ("struct" | "enum")) if derive_live => {
+                index += 1;
+                if let Some(name_token) = tokens.get(index).filter( | t | t.ident().is_some()) {
+                    let mut rust_type = RustType {
+                        name: name_token.text.clone(),
+                        module_path: module_path.to_string(),
+                        file: file.to_string(),
+                        line: name_token.line,
+                        column: name_token.column,
+                        doc: std::mem::take(&mut doc),
+                        live_ignore,
+                        fields: Vec::new(),
+                        shader_variants: Vec::new(),
+                    };
+                    index = skip_generics(&tokens, index + 1);
+                    // skip a where clause up to the body
+                    while index < tokens.len() && !tokens[index].token.is_open() && !tokens[index].is_punct(";") {
+                        index += 1;
+                    }
+                    if kind == "struct" && tokens.get(index).is_some_and(| t | t.token.is_open_delim(Delim::Brace)) {
+                        let end = skip_group(&tokens, index);
+                        rust_type.fields = scan_fields(&tokens[index + 1..end - 1]);
+                        index = end;
+                    }
+                    if kind == "enum" && repr_u32 && tokens.get(index).is_some_and(| t | t.token.is_open_delim(Delim::Brace)) {
+                        let end = skip_group(&tokens, index);
+                        rust_type.shader_variants = scan_variants(&tokens[index + 1..end - 1]);
+                        index = end;
+                    }
+                    out.push(rust_type);
+                }
+            }
+            _ => index += 1
+        }
+        doc.clear();
+        derive_live = false;
+        live_ignore = false;
+        repr_u32 = false;
+    }
+}
+
+// the variant names of an enum body, skipping their attributes and values
+fn scan_variants(tokens: &[RustToken]) -> Vec<String> {
+    let mut variants = Vec::new();
+    let mut expect_variant = true;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        if token.is_punct("#") && tokens.get(index + 1).is_some_and(| t | t.token.is_open_delim(Delim::Bracket)) {
+            index = attribute(tokens, index).2;
+            continue;
+        }
+        if token.token.is_open() {
+            index = skip_group(tokens, index);
+            continue;
+        }
+        if token.is_punct(",") {
+            expect_variant = true;
+        }
+        else if let Some(name) = token.ident().filter( | _ | expect_variant) {
+            variants.push(name.to_string());
+            expect_variant = false;
+        }
+        index += 1;
+    }
+    variants
+}
+
+fn scan_fields(tokens: &[RustToken]) -> Vec<RustField> {
+    let mut fields = Vec::new();
+    let mut doc = String::new();
+    let mut attr = String::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        if token.token.is_comment() {
+            doc_line(token, &mut doc);
+            index += 1;
+            continue;
+        }
+        if token.is_punct("#") && tokens.get(index + 1).is_some_and(| t | t.token.is_open_delim(Delim::Bracket)) {
+            let (name, _, end) = attribute(tokens, index);
+            if attr.is_empty() {
+                attr = name;
+            }
+            index = end;
+            continue;
+        }
+        if token.ident() == Some("pub") {
+            index += 1;
+            if tokens.get(index).is_some_and(| t | t.token.is_open_delim(Delim::Paren)) {
+                index = skip_group(tokens, index);
+            }
+            continue;
+        }
+        if token.ident().is_some() && tokens.get(index + 1).is_some_and(| t | t.is_punct(":")) {
+            // the type runs up to the next comma outside of brackets
+            let mut ty = String::new();
+            let mut depth = 0i32;
+            index += 2;
+            while index < tokens.len() {
+                let t = &tokens[index];
+                match t.token {
+                    FullToken::Open(_) => depth += 1,
+                    FullToken::Close(_) => depth -= 1,
+                    FullToken::Punct(_) if t.text != "->" => {
+                        depth += t.text.matches('<').count() as i32;
+                        depth -= t.text.matches('>').count() as i32;
+                        if t.text == "," && depth <= 0 {
+                            break
+                        }
+                    }
+                    FullToken::Comment => {
+                        index += 1;
+                        continue
+                    }
+                    _ => ()
+                }
+                ty.push_str(&t.text);
+                if t.text == "," || t.ident() == Some("dyn") || t.ident() == Some("mut") {
+                    ty.push(' ');
+                }
+                index += 1;
+            }
+            fields.push(RustField {
+                name: token.text.clone(),
+                attr: std::mem::take(&mut attr),
+                ty,
+                line: token.line,
+                column: token.column,
+                doc: std::mem::take(&mut doc),
+            });
+        }
+        index += 1;
+    }
+    fields
+}
+
+// what module_path!() says inside a file, from its path relative to the crate directory.
+// None for files outside of src, examples, tests and benches
+pub fn module_path_in_crate(crate_name: &str, relative: &Path) -> Option<String> {
+    let relative = relative.with_extension("");
+    let mut parts: Vec<String> = relative.iter().map( | part | part.to_string_lossy().to_string()).collect();
+    let stem = parts.pop().unwrap_or_default();
+    match parts.first().map( | s | s.as_str()) {
+        // binaries, examples and tests are crates of their own named after the file
+        Some("src") if parts.get(1).map( | s | s.as_str()) == Some("bin") && parts.len() == 2 => Some(stem.replace('-', "_")),
+        Some("examples" | "tests" | "benches") if parts.len() == 1 => Some(stem.replace('-', "_")),
+        Some("src") => {
+            let mut module_path = crate_name.replace('-', "_");
+            for part in &parts[1..] {
+                module_path.push_str("::");
+                module_path.push_str(part);
+            }
+            if !(parts.len() == 1 && (stem == "lib" || stem == "main")) && stem != "mod" {
+                module_path.push_str("::");
+                module_path.push_str(&stem);
+            }
+            Some(module_path)
+        }
+        _ => None
+    }
+}
+
+// the name a type is looked up by, Option<crate::foo::Bar<T>> -> Bar
+pub fn base_type_name(ty: &str) -> &str {
+    let ty = ty.strip_prefix("Option<").and_then( | ty | ty.strip_suffix('>')).unwrap_or(ty);
+    let ty = ty.split('<').next().unwrap_or(ty);
+    ty.rsplit("::").next().unwrap_or(ty).trim()
+}
+
+#[derive(Default)]
+pub struct RustTypes {
+    pub types: Vec<RustType>,
+    // module path and name of the types a live_design block gives a {{Type}} class
+    pub live_classes: HashSet<(String, String)>,
+}
+
+impl RustTypes {
+    // the type a name refers to from inside module_path, preferring the closest one
+    pub fn resolve(&self, ty: &str, module_path: &str) -> Option<&RustType> {
+        let name = base_type_name(ty);
+        let crate_name = module_path.split("::").next().unwrap_or("");
+        let mut best = None;
+        for rust_type in self.types.iter().filter( | t | t.name == name) {
+            if rust_type.module_path == module_path {
+                return Some(rust_type)
+            }
+            if best.is_none() || rust_type.module_path.split("::").next() == Some(crate_name) {
+                best = Some(rust_type);
+            }
+        }
+        best
+    }
+
+    pub fn find(&self, module_id: LiveModuleId, name: LiveId) -> Option<&RustType> {
+        self.types.iter().find( | t | t.module_id() == module_id && LiveId::from_str(&t.name) == name)
+    }
+
+    // the {{Type}} classes of a live_design block, in the order the parser wants their infos.
+    // Call this for every file before building infos, a type is only a class if some file says so
+    pub fn live_class_names(&mut self, source: &str, module_path: &str) -> Vec<String> {
+        let tokens = LiveRegistry::tokenize_from_str_live_design(source, TextPos::default(), LiveFileId(0), None).unwrap_or_default();
+        let mut class_names = Vec::new();
+        for window in tokens.windows(3) {
+            if let (LiveToken::Open(Delim::Brace), LiveToken::Open(Delim::Brace), LiveToken::Ident(id)) = (&window[0].token, &window[1].token, &window[2].token) {
+                let name = id.to_string();
+                if let Some(rust_type) = self.resolve(&name, module_path) {
+                    self.live_classes.insert((rust_type.module_path.clone(), rust_type.name.clone()));
+                }
+                class_names.push(name);
+            }
+        }
+        class_names
+    }
+}
+
+// LiveType is a TypeId, and those only exist for types known at compile time. Every type read from
+// the sources gets one of these placeholders instead, handed out once per module path and name
+struct TypeSlot<const A: usize, const B: usize>;
+
+macro_rules! type_slots {
+    ([$($a:literal)*] $b:tt) => {{
+        let mut slots = Vec::new();
+        $(type_slots!(@row slots $a $b);)*
+        slots
+    }};
+    (@row $slots:ident $a:literal [$($b:literal)*]) => {
+        $slots.extend([$(TypeId::of::<TypeSlot<$a, $b>>()),*]);
+    };
+}
+
+pub struct LiveTypeInfoBuilder<'a> {
+    types: &'a RustTypes,
+    slots: Vec<LiveType>,
+    assigned: HashMap<String, LiveType>,
+    built: HashMap<String, LiveTypeInfo>,
+    building: HashSet<String>,
+}
+
+impl<'a> LiveTypeInfoBuilder<'a> {
+    pub fn new(types: &'a RustTypes) -> Self {
+        Self {
+            types,
+            slots: type_slots!(
+                [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+                [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63]
+            ),
+            assigned: HashMap::new(),
+            built: HashMap::new(),
+            building: HashSet::new(),
+        }
+    }
+
+    fn live_type(&mut self, key: &str) -> LiveType {
+        if let Some(live_type) = self.assigned.get(key) {
+            return *live_type
+        }
+        // when the pool runs dry the rest share one type, which only costs precision
+        let live_type = self.slots.pop().unwrap_or(TypeId::of::<()>());
+        self.assigned.insert(key.to_string(), live_type);
+        live_type
+    }
+
+    // a type that isnt a Live struct from the sources, like f64 or Vec4
+    fn plain_type_info(&mut self, ty: &str) -> LiveTypeInfo {
+        let ty = ty.trim();
+        LiveTypeInfo {
+            live_type: self.live_type(&format!("rust::{}", ty)),
+            type_name: LiveId::from_str_with_lut(ty).unwrap_or_default(),
+            module_id: LiveModuleId(live_id!(rust), live_id!(types)),
+            live_ignore: true,
+            fields: Vec::new()
+        }
+    }
+
+    // the info for a {{name}} class or field type as seen from module_path
+    pub fn live_type_info(&mut self, ty: &str, module_path: &str) -> LiveTypeInfo {
+        let rust_type = if let Some(rust_type) = self.types.resolve(ty, module_path) {
+            rust_type
+        }
+        else {
+            return self.plain_type_info(ty)
+        };
+        let key = format!("{}::{}", rust_type.module_path, rust_type.name);
+        if let Some(info) = self.built.get(&key) {
+            return info.clone()
+        }
+        let mut info = LiveTypeInfo {
+            live_type: self.live_type(&key),
+            type_name: LiveId::from_str_with_lut(&rust_type.name).unwrap_or_default(),
+            module_id: rust_type.module_id(),
+            // without a {{Type}} in its module the expander would report a missing live_design
+            live_ignore: rust_type.live_ignore || !self.types.live_classes.contains(&(rust_type.module_path.clone(), rust_type.name.clone())),
+            fields: Vec::new(),
+        };
+        // a type reached again while building its own fields ends the recursion without fields
+        if !self.building.insert(key.clone()) {
+            return info
+        }
+        for field in &rust_type.fields {
+            let live_field_kind = match field.attr.as_ref() {
+                "live" => LiveFieldKind::Live,
+                "deref" => LiveFieldKind::Deref,
+                "animator" => LiveFieldKind::Animator,
+                "calc" => LiveFieldKind::Calc,
+                _ => continue
+            };
+            let (ty, live_field_kind) = match field.ty.strip_prefix("Option<").and_then( | ty | ty.strip_suffix('>')) {
+                Some(inner) if live_field_kind == LiveFieldKind::Live => (inner, LiveFieldKind::LiveOption),
+                _ => (field.ty.as_str(), live_field_kind)
+            };
+            let live_type_info = self.live_type_info(ty, &rust_type.module_path);
+            info.fields.push(LiveTypeField {
+                id: LiveId::from_str_with_lut(&field.name).unwrap_or_default(),
+                live_type_info,
+                live_field_kind
+            });
+        }
+        self.building.remove(&key);
+        self.built.insert(key, info.clone());
+        info
+    }
+}
@@ -0,0 +1,66 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    span::TextPos,
+};
+
+fn type_info<T: 'static>(name: &str, fields: Vec<(&str, LiveFieldKind, LiveTypeInfo)>) -> LiveTypeInfo {
+    LiveTypeInfo {
+        live_type: LiveType::of::<T>(),
+        type_name: LiveId::from_str_with_lut(name).unwrap(),
+        module_id: LiveModuleId(live_id!(test), live_id!(app)),
+        live_ignore: true,
+        fields: fields.into_iter().map( | (id, live_field_kind, live_type_info) | LiveTypeField {
+            id: LiveId::from_str_with_lut(id).unwrap(),
+            live_type_info,
+            live_field_kind
+        }).collect()
+    }
+}
+
+#[test]
+fn rust_file_spans_and_field_types() {
+    struct Walk;
+    struct DrawQuad;
+    struct Button;
+    let f64_info = || type_info::<f64>("f64", vec![]);
+    let walk = type_info::<Walk>("Walk", vec![("width", LiveFieldKind::Live, f64_info())]);
+    let draw_quad = type_info::<DrawQuad>("DrawQuad", vec![("color", LiveFieldKind::Live, f64_info())]);
+    let button = type_info::<Button>("Button", vec![
+        ("walk", LiveFieldKind::Live, walk),
+        ("draw_bg", LiveFieldKind::Deref, draw_quad),
+    ]);
+
+    let mut live_registry = LiveRegistry::default();
+    let module_id = LiveModuleId(live_id!(test), live_id!(app));
+    let file_id = live_registry.register_live_design_file("test/app.rs", "", module_id, r#"use makepad_widgets::*;
+
+live_design! {
+    Button = {{Button}} {
+        color: 1.0
+        walk: {width: 2.0}
+    }
+}
+
+#[derive(Live)]
+pub struct Button {}
+"#.to_string(), vec![button]).unwrap();
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    // token positions are where they are in the rust file
+    let file = live_registry.file_id_to_file(file_id);
+    let token_index = file.original.find_token_by_pos(TextPos {line: 4, column: 8}).unwrap();
+    assert_eq!(file.original.tokens[token_index].token, LiveToken::Ident(live_id!(color)));
+
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    let type_name = | path: &[LiveProp] | {
+        let index = nodes.child_by_path(0, path).unwrap();
+        live_registry.node_live_type_info(nodes, index).map( | info | info.type_name.to_string())
+    };
+    assert_eq!(type_name(&[live_id!(Button).as_instance()]), Some("Button".to_string()));
+    assert_eq!(type_name(&[live_id!(Button).as_instance(), live_id!(walk).as_field()]), Some("Walk".to_string()));
+    assert_eq!(type_name(&[live_id!(Button).as_instance(), live_id!(color).as_field()]), Some("f64".to_string()));
+    assert_eq!(type_name(&[live_id!(Button).as_instance(), live_id!(walk).as_field(), live_id!(width).as_field()]), Some("f64".to_string()));
+}
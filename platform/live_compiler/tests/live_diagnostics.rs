@@ -0,0 +1,70 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    span::TextPos,
+};
+
+fn type_info<T: 'static>(name: &str, fields: Vec<(&str, LiveFieldKind, LiveTypeInfo)>) -> LiveTypeInfo {
+    LiveTypeInfo {
+        live_type: LiveType::of::<T>(),
+        type_name: LiveId::from_str_with_lut(name).unwrap(),
+        module_id: LiveModuleId(live_id!(test), live_id!(app)),
+        // like #[live_ignore], the expander doesnt look for live definitions of these
+        live_ignore: true,
+        fields: fields.into_iter().map( | (id, live_field_kind, live_type_info) | LiveTypeField {
+            id: LiveId::from_str_with_lut(id).unwrap(),
+            live_type_info,
+            live_field_kind
+        }).collect()
+    }
+}
+
+#[test]
+fn field_names_suggest_the_nearest() {
+    struct Walk;
+    struct DrawQuad;
+    struct Button;
+    let f64_info = || type_info::<f64>("f64", vec![]);
+    let walk = type_info::<Walk>("Walk", vec![("width", LiveFieldKind::Live, f64_info()), ("height", LiveFieldKind::Live, f64_info())]);
+    let draw_quad = type_info::<DrawQuad>("DrawQuad", vec![("color", LiveFieldKind::Live, f64_info())]);
+    let button = type_info::<Button>("Button", vec![
+        ("walk", LiveFieldKind::Live, walk),
+        ("draw_bg", LiveFieldKind::Deref, draw_quad),
+        ("area", LiveFieldKind::Calc, f64_info()),
+    ]);
+
+    let mut live_registry = LiveRegistry::default();
+    let module_id = LiveModuleId(live_id!(test), live_id!(app));
+    live_registry.register_live_file("test/app.rs", "", module_id, r#"
+        Button = {{Button}} {
+            colr: 1.0
+            walk: {heigth: 2.0}
+        }
+    "#.to_string(), vec![button], TextPos::default()).unwrap();
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    let suggest = | path: &[LiveProp] | {
+        let index = nodes.child_by_path(0, path).unwrap();
+        let fields: Vec<String> = live_registry.live_type_field_names(nodes, index).iter().map( | id | id.to_string()).collect();
+        nearest_name(&nodes[index].id.to_string(), fields.iter().map( | s | s.as_str())).map( | s | s.to_string())
+    };
+    assert_eq!(suggest(&[live_id!(Button).as_instance(), live_id!(colr).as_field()]), Some("color".to_string()));
+    assert_eq!(suggest(&[live_id!(Button).as_instance(), live_id!(walk).as_field(), live_id!(heigth).as_field()]), Some("height".to_string()));
+    assert_eq!(nearest_name("area", ["walk", "draw_bg"]), None);
+
+    let diagnostic = LiveDiagnostic {
+        severity: LiveSeverity::Error,
+        stage: LiveDiagnosticStage::Apply,
+        file: "test/app.rs".to_string(),
+        line: 4,
+        column: 13,
+        message: "no matching field: colr".to_string(),
+        suggestion: Some("did you mean `color`?".to_string()),
+        origin: String::new()
+    };
+    assert_eq!(diagnostic.to_string(), "test/app.rs:4:13: error[apply]: no matching field: colr\n    help: did you mean `color`?");
+    assert_eq!(LiveDiagnostic::from_live_check_line(&diagnostic.to_live_check_line()), Some(diagnostic));
+}
@@ -0,0 +1,118 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    makepad_math::*,
+    live_error::LiveFileError,
+    span::TextPos,
+    live_node::LiveValue,
+};
+
+fn eval_all(source: &str) -> Vec<(LiveId, Result<LiveEval, LiveFileError>)> {
+    let mut live_registry = LiveRegistry::default();
+    let module_id = LiveModuleId(live_id!(test), live_id!(live_eval));
+    live_registry.register_live_file(
+        "test/live_eval.rs",
+        "",
+        module_id,
+        source.to_string(),
+        vec![],
+        TextPos::default(),
+    ).unwrap();
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty());
+
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    let mut results = Vec::new();
+    let mut index = 1;
+    while !nodes[index].value.is_close() {
+        if let LiveValue::Expr {..} = nodes[index].value {
+            let result = live_eval(&live_registry, index, &mut (index + 1), nodes);
+            results.push((nodes[index].id, result.map_err( | e | live_registry.live_error_to_live_file_error(e))));
+        }
+        index = nodes.skip_node(index);
+    }
+    results
+}
+
+fn color(v: &Result<LiveEval, LiveFileError>) -> Vec4 {
+    if let Ok(LiveEval::Vec4(v)) = v {*v} else {panic!("expected color, got {:?}", v)}
+}
+
+fn float(v: &Result<LiveEval, LiveFileError>) -> f64 {
+    if let Ok(LiveEval::Float64(v)) = v {*v} else {panic!("expected float, got {:?}", v)}
+}
+
+#[test]
+fn builtins() {
+    let results = eval_all(r#"
+        base = #ff0000
+        mixed = (mix(#000000ff, #ffffffff, 0.5))
+        mixed_float = (mix(1, 3, 0.25))
+        lo = (min(3, 4))
+        hi = (max(1.5, 2))
+        clamped = (clamp(12, 0, 10))
+        clamped_vec = (clamp(#ff000080, 0.25, 0.75))
+        absolute = (abs(-2.5))
+        floored = (floor(2.7))
+        ceiled = (ceil(2.2))
+        rounded = (round(2.5))
+        lighter = (lighten(base, 0.25))
+        darker = (darken(base, 0.25))
+        grey = (saturate(base, -1.0))
+        from_hsl = (hsl(120, 1.0, 0.5))
+        from_hsv = (hsv(240, 1.0, 1.0, 0.5))
+        faded = (alpha(base, 0.5))
+        label = ("size: " + 10)
+    "#);
+    let get = | id: LiveId | &results.iter().find( | (i, _) | *i == id).unwrap().1;
+    let eq = | a: Vec4, b: Vec4 | assert!(a.is_equal_enough(&b, 0.01), "{:?} != {:?}", a, b);
+
+    eq(color(get(live_id!(mixed))), vec4(0.5, 0.5, 0.5, 1.0));
+    assert_eq!(float(get(live_id!(mixed_float))), 1.5);
+    assert!(matches!(get(live_id!(lo)), Ok(LiveEval::Int64(3))));
+    assert_eq!(float(get(live_id!(hi))), 2.0);
+    assert!(matches!(get(live_id!(clamped)), Ok(LiveEval::Int64(10))));
+    eq(color(get(live_id!(clamped_vec))), vec4(0.75, 0.25, 0.25, 0.5));
+    assert_eq!(float(get(live_id!(absolute))), 2.5);
+    assert_eq!(float(get(live_id!(floored))), 2.0);
+    assert_eq!(float(get(live_id!(ceiled))), 3.0);
+    assert_eq!(float(get(live_id!(rounded))), 3.0);
+    eq(color(get(live_id!(lighter))), vec4(1.0, 0.5, 0.5, 1.0));
+    eq(color(get(live_id!(darker))), vec4(0.5, 0.0, 0.0, 1.0));
+    eq(color(get(live_id!(grey))), vec4(0.5, 0.5, 0.5, 1.0));
+    eq(color(get(live_id!(from_hsl))), vec4(0.0, 1.0, 0.0, 1.0));
+    eq(color(get(live_id!(from_hsv))), vec4(0.0, 0.0, 1.0, 0.5));
+    eq(color(get(live_id!(faded))), vec4(1.0, 0.0, 0.0, 0.5));
+    match get(live_id!(label)) {
+        Ok(LiveEval::String(s)) => assert_eq!(s.as_str(), "size: 10"),
+        v => panic!("expected string, got {:?}", v)
+    }
+}
+
+#[test]
+fn errors_point_at_the_offending_argument() {
+    let source = r#"
+        wrong_type = (lighten(0.5, 0.1))
+        wrong_count = (mix(1, 2))
+        unknown = (frobnicate(1))
+    "#;
+    let results = eval_all(source);
+    let message = | id: LiveId | match &results.iter().find( | (i, _) | *i == id).unwrap().1 {
+        Err(e) => e.clone(),
+        Ok(v) => panic!("expected error, got {:?}", v)
+    };
+
+    let err = message(live_id!(wrong_type));
+    assert!(err.message.contains("argument 1 of lighten() should be a color"), "{}", err.message);
+    // the span covers the 0.5 literal on the second line
+    assert_eq!(err.span.start.line, 1);
+    assert_eq!(err.span.start.column, 30);
+
+    let err = message(live_id!(wrong_count));
+    assert!(err.message.contains("mix() takes 3 arguments but 2 were given"), "{}", err.message);
+    assert_eq!(err.span.start.line, 2);
+    assert_eq!(err.span.start.column, 23);
+
+    assert!(message(live_id!(unknown)).message.contains("frobnicate"));
+}
@@ -0,0 +1,81 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    span::TextPos,
+};
+
+const EN: &str = r#"
+# english
+-brand = Makepad
+hello = Hello { $name }, welcome to { -brand }
+inbox-count = { $count ->
+    [0] No messages
+    [one] One message
+   *[other] { $count } messages
+}
+about =
+    Line one
+
+    line two
+"#;
+
+const RU: &str = r#"
+inbox-count = { $count ->
+    [one] { $count } сообщение
+    [few] { $count } сообщения
+   *[many] { $count } сообщений
+}
+"#;
+
+fn text(live_registry: &LiveRegistry, module_id: LiveModuleId, id: LiveId) -> String {
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    let index = nodes.child_by_name(0, id.as_instance()).unwrap();
+    match live_eval(live_registry, index, &mut (index + 1), nodes) {
+        Ok(LiveEval::String(s)) => s.to_string(),
+        v => panic!("unexpected {:?}", v)
+    }
+}
+
+#[test]
+fn tr_formats_messages_in_the_current_locale() {
+    let mut live_registry = LiveRegistry::default();
+    live_registry.localization.add_catalog("en", EN).unwrap();
+    live_registry.localization.add_catalog("ru", RU).unwrap();
+    let module_id = LiveModuleId(live_id!(test), live_id!(app));
+    live_registry.register_live_file("test/app.rs", "", module_id, r#"
+        UNREAD = 22
+        greeting = (tr("hello", name: "Ada"))
+        none = (tr("inbox-count", count: 0))
+        one = (tr("inbox-count", count: 1))
+        many = (tr("inbox-count", count: UNREAD))
+        about = (tr("about"))
+        missing = (tr("not-there"))
+    "#.to_string(), vec![], TextPos::default()).unwrap();
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    assert_eq!(text(&live_registry, module_id, live_id!(greeting)), "Hello Ada, welcome to Makepad");
+    assert_eq!(text(&live_registry, module_id, live_id!(none)), "No messages");
+    assert_eq!(text(&live_registry, module_id, live_id!(one)), "One message");
+    assert_eq!(text(&live_registry, module_id, live_id!(many)), "22 messages");
+    assert_eq!(text(&live_registry, module_id, live_id!(about)), "Line one\n\nline two");
+    assert_eq!(text(&live_registry, module_id, live_id!(missing)), "not-there");
+
+    // russian plurals, and what it doesnt have comes from the fallback locale
+    assert!(live_registry.localization.set_locale("ru-RU"));
+    assert_eq!(text(&live_registry, module_id, live_id!(one)), "1 сообщение");
+    assert_eq!(text(&live_registry, module_id, live_id!(many)), "22 сообщения");
+    assert_eq!(live_registry.localization.format("inbox-count", &[(live_id!(count), LiveEval::Int64(11))]), "11 сообщений");
+    assert_eq!(text(&live_registry, module_id, live_id!(greeting)), "Hello Ada, welcome to Makepad");
+
+    assert_eq!(LivePluralCategory::for_number("pl", 22.0), LivePluralCategory::Few);
+    assert_eq!(LivePluralCategory::for_number("pl", 25.0), LivePluralCategory::Many);
+    assert_eq!(LivePluralCategory::for_number("fr", 0.0), LivePluralCategory::One);
+    assert_eq!(LivePluralCategory::for_number("en", 1.5), LivePluralCategory::Other);
+    assert_eq!(LivePluralCategory::for_number("ja", 1.0), LivePluralCategory::Other);
+
+    let err = LiveCatalog::parse("ok = fine\nbroken = { $count ->\n    [one] one\n}\n").unwrap_err();
+    assert_eq!(err.line, 4);
+    assert!(matches!(live_registry.localization.add_catalog("nl", "hallo = { $naam"), Err(LiveCatalogError {line: 1, ..})));
+}
@@ -0,0 +1,140 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    span::TextPos,
+    live_node::LiveValue,
+};
+
+struct Widget;
+
+fn register(live_registry: &mut LiveRegistry, name: LiveId, source: &str) -> LiveFileId {
+    let module_id = LiveModuleId(live_id!(test), name);
+    let type_info = LiveTypeInfo {
+        live_type: LiveType::of::<Widget>(),
+        type_name: live_id!(Widget),
+        module_id: LiveModuleId(live_id!(test), live_id!(widgets)),
+        live_ignore: false,
+        fields: Vec::new()
+    };
+    live_registry.register_live_file(
+        &format!("test/{}.rs", name),
+        "",
+        module_id,
+        source.to_string(),
+        vec![type_info],
+        TextPos::default(),
+    ).unwrap()
+}
+
+fn dsl_text(live_registry: &LiveRegistry, node: &LiveNode) -> String {
+    if let LiveValue::DSL {token_start, token_count, ..} = node.value {
+        let tokens = &live_registry.token_id_to_origin_doc(node.origin.token_id().unwrap()).tokens;
+        return tokens[token_start as usize..(token_start + token_count) as usize].iter().map( | t | format!("{} ", t.token)).collect()
+    }
+    panic!()
+}
+
+#[test]
+fn written_source_parses_to_the_same_nodes() {
+    let source = r#"
+        import makepad_draw::shader::std::*;
+        import crate::base::Button as BaseButton
+
+        const SIZE = -4.5
+        const COLOR = #x336699
+        const MIXED = (mix(COLOR, #fff, 0.5) * -SIZE + (1 - 2) / 3)
+        const PICK = (SIZE > 1.0 && !false ? "big" : "small".len)
+        const LABEL = (tr("inbox-count", count: SIZE + 1, name: "Bob"))
+        const NESTED = (-(1 + 2) * 3 - (4 - 5) - 6 / (7 * 8))
+
+        Widget = {{Widget}} {
+            label.{min: 0, max: 10.5, name: "x"}: "say \"hi\"\\n"
+            font: dep("crate://self/resources/font.ttf")
+            flow: Down
+            target: self_ref
+            path: a.b.c
+            offset: vec2(1.0, -2.0)
+            tint: vec4(0.0, 0.5, 1.0, 1.0)
+            width: Fixed(100.0)
+            margin: Inset {left: 1, top: 2.0}
+            list: [1, 2.5, #f00]
+            rows: [{a: 1}, <Row> {b: 2}]
+            empty: {}
+            draw_bg: {
+                instance hover: 0.0
+                uniform radius: 3.0
+
+                fn pixel(self) -> vec4 {
+                    let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+                    sdf.fill(mix(#x202020, #8, self.hover));
+
+                    return sdf.result
+                }
+            }
+            <Label> {text: "nameless"}
+            if os == macos && !compact {
+                <Label> {text: "mac"}
+            }
+            else if dpi > 1.5 {
+                spacing: 2.0
+            }
+            else {
+                spacing: 1.0
+            }
+            body = <Label>(title: "named", size: 2.0) {text: (title)}
+            <Label> {}
+        }
+        Other = struct {x: 1}
+    "#;
+    let mut live_registry = LiveRegistry::default();
+    let file_id = register(&mut live_registry, live_id!(original), source);
+    let original = &live_registry.file_id_to_file(file_id).original;
+    let written = original.nodes.to_live_design(0, &live_registry).unwrap();
+
+    let written_file_id = register(&mut live_registry, live_id!(written), &written);
+    let original = &live_registry.file_id_to_file(file_id).original;
+    let rewritten = &live_registry.file_id_to_file(written_file_id).original;
+
+    // writing the written version gives back the same text
+    assert_eq!(rewritten.nodes.to_live_design(0, &live_registry).unwrap(), written);
+
+    assert_eq!(original.nodes.len(), rewritten.nodes.len(), "{}", written);
+    for (a, b) in original.nodes.iter().zip(rewritten.nodes.iter()) {
+        assert_eq!(a.id, b.id, "{}", written);
+        assert_eq!(a.origin.prop_type(), b.origin.prop_type(), "{}", a.id);
+        assert_eq!(a.origin.node_has_prefix(), b.origin.node_has_prefix(), "{}", a.id);
+        assert_eq!(a.origin.edit_info().is_some(), b.origin.edit_info().is_some(), "{}", a.id);
+        if let LiveValue::DSL {..} = a.value {
+            assert_eq!(dsl_text(&live_registry, a), dsl_text(&live_registry, b));
+        }
+        else {
+            assert_eq!(a.value, b.value, "{}", a.id);
+        }
+    }
+    let edit_info = | doc: &LiveOriginal | doc.edit_info.iter().filter( | v | v.id != LiveId(0)).map( | v | (v.id, v.value.clone())).collect::<Vec<_>>();
+    assert_eq!(edit_info(original), edit_info(rewritten));
+    assert!(written.contains("    instance hover: 0.0\n"), "{}", written);
+    assert!(written.contains("list: [1, 2.5, #xFF0000]"), "{}", written);
+    assert!(written.contains("    } else if dpi > 1.5 {\n"), "{}", written);
+    assert!(written.contains("body = <Label>(title: \"named\", size: 2.0) {\n"), "{}", written);
+    assert!(written.contains("const NESTED = (-(1 + 2) * 3 - (4 - 5) - 6 / (7 * 8))"), "{}", written);
+}
+
+#[test]
+fn nodes_without_names_are_an_error() {
+    let live_registry = LiveRegistry::default();
+    let mut nodes = Vec::new();
+    nodes.open_object(LiveId(0));
+    nodes.push_float64(LiveId(1234567), 1.0);
+    nodes.close();
+    assert!(nodes.to_live_design(0, &live_registry).is_err());
+
+    let mut nodes = Vec::new();
+    nodes.open_object(LiveId(0));
+    nodes.push_float64(LiveId::from_str_with_lut("width").unwrap(), 1.0);
+    nodes.open_clone(LiveId::from_str_with_lut("label").unwrap(), LiveId::from_str_with_lut("Label").unwrap());
+    nodes.push_string(LiveId::from_str_with_lut("text").unwrap(), "hi");
+    nodes.close();
+    nodes.close();
+    assert_eq!(nodes.to_live_design(0, &live_registry).unwrap(), "width: 1.0\nlabel: <Label> {\n    text: \"hi\"\n}\n");
+}
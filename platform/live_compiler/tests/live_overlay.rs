@@ -0,0 +1,68 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    makepad_math::*,
+    span::TextPos,
+    live_node::LiveValue,
+};
+
+fn register(live_registry: &mut LiveRegistry, name: LiveId, source: &str) -> LiveModuleId {
+    let module_id = LiveModuleId(live_id!(test), name);
+    live_registry.register_live_file(
+        &format!("test/{}.rs", name),
+        "",
+        module_id,
+        source.to_string(),
+        vec![],
+        TextPos::default(),
+    ).unwrap();
+    module_id
+}
+
+fn value(live_registry: &LiveRegistry, module_id: LiveModuleId, id: LiveId) -> LiveValue {
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    let index = nodes.child_by_name(0, id.as_instance()).unwrap();
+    if let LiveValue::Expr {..} = nodes[index].value {
+        return match live_eval(live_registry, index, &mut (index + 1), nodes).unwrap() {
+            LiveEval::Vec4(v) => LiveValue::Color(v.to_u32()),
+            LiveEval::Float64(v) => LiveValue::Float64(v),
+            v => panic!("unexpected {:?}", v)
+        }
+    }
+    nodes[index].value.clone()
+}
+
+#[test]
+fn overlay_replaces_top_level_values() {
+    let mut live_registry = LiveRegistry::default();
+    let dark = register(&mut live_registry, live_id!(dark), r#"
+        BRIGHTNESS = #x40
+        SPACE = 6.0
+        BUTTON = (mix(BRIGHTNESS, #f, 0.5))
+    "#);
+    let light = register(&mut live_registry, live_id!(light), r#"
+        BRIGHTNESS = #xC0
+        SHADE = (0.5 * 2)
+    "#);
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty());
+
+    let button = value(&live_registry, dark, live_id!(BUTTON));
+    assert_eq!(button, LiveValue::Color(vec4(0.625, 0.625, 0.625, 1.0).to_u32()));
+
+    live_registry.set_module_overlay(dark, Some(light), &mut errors).unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(live_registry.module_overlay(dark), Some(light));
+    assert_eq!(value(&live_registry, dark, live_id!(BRIGHTNESS)), LiveValue::Color(0xC0C0C0FF));
+    assert_eq!(value(&live_registry, dark, live_id!(SPACE)), LiveValue::Float64(6.0));
+    // expressions in the module pick up the overlaid value, expressions in the overlay still evaluate
+    let button = value(&live_registry, dark, live_id!(BUTTON));
+    assert_eq!(button, LiveValue::Color(vec4(0.875, 0.875, 0.875, 1.0).to_u32()));
+    assert_eq!(value(&live_registry, dark, live_id!(SHADE)), LiveValue::Float64(1.0));
+
+    live_registry.set_module_overlay(dark, None, &mut errors).unwrap();
+    assert_eq!(value(&live_registry, dark, live_id!(BRIGHTNESS)), LiveValue::Color(0x404040FF));
+
+    assert!(live_registry.set_module_overlay(dark, Some(dark), &mut errors).is_err());
+}
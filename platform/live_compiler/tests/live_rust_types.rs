@@ -0,0 +1,51 @@
+use {
+    std::path::Path,
+    makepad_live_compiler::live_rust_types::*,
+};
+
+#[test]
+fn scan_structs_and_shader_enums() {
+    let mut types = Vec::new();
+    scan_rust_types("src/check_box.rs", "makepad_widgets::check_box", r#"
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawCheckBox {
+    #[deref] draw_super: DrawQuad,
+    #[live] check_type: CheckType,
+    #[calc] hover: f32,
+}
+
+#[derive(Live, LiveHook)]
+#[live_ignore]
+#[repr(u32)]
+pub enum CheckType {
+    #[pick] Check = shader_enum(1),
+    Radio = shader_enum(2),
+    // not a variant
+    Toggle = shader_enum(3),
+}
+
+#[derive(Live, LiveHook)]
+pub enum NotForShaders {
+    #[pick] A,
+    B
+}
+"#, &mut types);
+
+    assert_eq!(types.len(), 3);
+    let fields: Vec<(&str, &str, &str)> = types[0].fields.iter().map( | f | (f.attr.as_str(), f.name.as_str(), f.ty.as_str())).collect();
+    assert_eq!(fields, vec![("deref", "draw_super", "DrawQuad"), ("live", "check_type", "CheckType"), ("calc", "hover", "f32")]);
+    assert_eq!(types[1].shader_variants, vec!["Check", "Radio", "Toggle"]);
+    assert!(types[1].live_ignore);
+    assert!(types[2].shader_variants.is_empty());
+}
+
+#[test]
+fn module_paths() {
+    let module_path = | path: &str | module_path_in_crate("makepad-widgets", Path::new(path));
+    assert_eq!(module_path("src/lib.rs").as_deref(), Some("makepad_widgets"));
+    assert_eq!(module_path("src/button.rs").as_deref(), Some("makepad_widgets::button"));
+    assert_eq!(module_path("src/shader/mod.rs").as_deref(), Some("makepad_widgets::shader"));
+    assert_eq!(module_path("examples/demo.rs").as_deref(), Some("demo"));
+    assert_eq!(module_path("build.rs"), None);
+}
@@ -0,0 +1,134 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::*,
+    span::TextPos,
+    live_node::LiveValue,
+};
+
+fn register(live_registry: &mut LiveRegistry, name: LiveId, source: &str) -> LiveModuleId {
+    let module_id = LiveModuleId(live_id!(test), name);
+    live_registry.register_live_file(
+        &format!("test/{}.rs", name),
+        "",
+        module_id,
+        source.to_string(),
+        vec![],
+        TextPos::default(),
+    ).unwrap();
+    module_id
+}
+
+fn value(live_registry: &LiveRegistry, module_id: LiveModuleId, path: &[LiveProp]) -> Option<LiveValue> {
+    let nodes = live_registry.module_id_to_expanded_nodes(module_id).unwrap();
+    nodes.child_by_path(0, path).map( | index | nodes[index].value.clone())
+}
+
+#[test]
+fn if_blocks_pick_a_branch_on_conditions() {
+    let mut live_registry = LiveRegistry::default();
+    let app = register(&mut live_registry, live_id!(app), r#"
+        Base = {
+            spacing: 1.0
+        }
+        App = <Base> {
+            if os == android || os == ios {
+                spacing: 2.0
+                <Base> {mobile: true}
+            }
+            else if !compact {
+                spacing: 3.0
+            }
+            else {
+                spacing: 4.0
+            }
+            if dpi_factor >= 2 {
+                sharp: true
+            }
+            <Base> {last: true}
+        }
+    "#);
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+    let app_spacing = [live_id!(App).as_instance(), live_id!(spacing).as_field()];
+    assert_eq!(value(&live_registry, app, &app_spacing), Some(LiveValue::Float64(3.0)));
+    assert_eq!(value(&live_registry, app, &[live_id!(App).as_instance(), live_id!(sharp).as_field()]), None);
+
+    assert!(live_registry.set_condition(live_id!(os), LiveValue::Id(live_id!(ios))));
+    assert!(live_registry.set_condition(live_id!(dpi_factor), LiveValue::Float64(2.0)));
+    assert!(!live_registry.set_condition(live_id!(dpi_factor), LiveValue::Float64(2.0)));
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(value(&live_registry, app, &app_spacing), Some(LiveValue::Float64(2.0)));
+    assert_eq!(value(&live_registry, app, &[live_id!(App).as_instance(), live_id!(sharp).as_field()]), Some(LiveValue::Bool(true)));
+    // nameless components in a branch dont collide with the ones after the if block
+    let nameless_0 = LiveId::from_str("nameless_0").as_instance();
+    let nameless_1 = LiveId::from_str("nameless_1").as_instance();
+    assert_eq!(value(&live_registry, app, &[live_id!(App).as_instance(), nameless_0, live_id!(mobile).as_field()]), Some(LiveValue::Bool(true)));
+    assert_eq!(value(&live_registry, app, &[live_id!(App).as_instance(), nameless_1, live_id!(last).as_field()]), Some(LiveValue::Bool(true)));
+
+    live_registry.set_condition(live_id!(os), LiveValue::Id(live_id!(macos)));
+    live_registry.set_condition(live_id!(compact), LiveValue::Bool(true));
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(value(&live_registry, app, &app_spacing), Some(LiveValue::Float64(4.0)));
+
+    live_registry.set_condition(live_id!(compact), LiveValue::Float64(1.0));
+    live_registry.expand_all_documents(&mut errors);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn templates_substitute_their_args() {
+    let mut live_registry = LiveRegistry::default();
+    let app = register(&mut live_registry, live_id!(app), r#"
+        View = {}
+        Card = <View>(title: "Untitled", size: 2.0, width: Fit) {
+            label = <View> {text: (title), height: (size * 10)}
+            width: (width)
+        }
+        BigCard = <Card>(size: 4.0) {}
+        Page = <View> {
+            plain = <Card> {}
+            named = <Card>(title: "Hello", width: Fixed(100)) {extra: 1}
+            big = <BigCard>(title: "Big") {}
+        }
+    "#);
+    let mut errors = Vec::new();
+    live_registry.expand_all_documents(&mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+    let nodes = live_registry.module_id_to_expanded_nodes(app).unwrap();
+
+    let card = |id: LiveId, path: &[LiveProp] | {
+        let mut full = vec![live_id!(Page).as_instance(), id.as_instance()];
+        full.extend_from_slice(path);
+        value(&live_registry, app, &full)
+    };
+    let text = [live_id!(label).as_instance(), live_id!(text).as_field()];
+    let height = [live_id!(label).as_instance(), live_id!(height).as_field()];
+    assert_eq!(card(live_id!(plain), &text), Some(LiveValue::String(std::rc::Rc::new("Untitled".to_string()))));
+    assert_eq!(card(live_id!(named), &text), Some(LiveValue::String(std::rc::Rc::new("Hello".to_string()))));
+    assert_eq!(card(live_id!(big), &text), Some(LiveValue::String(std::rc::Rc::new("Big".to_string()))));
+    assert_eq!(card(live_id!(named), &[live_id!(extra).as_field()]), Some(LiveValue::Int64(1)));
+    assert_eq!(card(live_id!(plain), &[live_id!(width).as_field()]), Some(LiveValue::BareEnum(live_id!(Fit))));
+    assert_eq!(card(live_id!(named), &[live_id!(width).as_field()]), Some(LiveValue::TupleEnum(live_id!(Fixed))));
+    // args used inside a bigger expression are substituted and it stays an expression
+    let index = nodes.child_by_path(0, &[live_id!(Page).as_instance(), live_id!(big).as_instance(), height[0], height[1]]).unwrap();
+    match live_eval(&live_registry, index, &mut (index + 1), nodes).unwrap() {
+        LiveEval::Float64(v) => assert_eq!(v, 40.0),
+        v => panic!("unexpected {:?}", v)
+    }
+    // instances dont carry the args, definitions keep them to derive from
+    assert_eq!(card(live_id!(named), &[LiveProp(live_id!(title), LivePropType::Template)]), None);
+    assert!(nodes.child_by_path(0, &[live_id!(BigCard).as_instance(), LiveProp(live_id!(size), LivePropType::Template)]).is_some());
+
+    let other = register(&mut live_registry, live_id!(other), r#"
+        import test::app::Card
+        Page = {
+            card = <Card>(titel: "typo") {}
+        }
+    "#);
+    live_registry.expand_all_documents(&mut errors);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(live_registry.module_id_to_expanded_nodes(other).is_some());
+}
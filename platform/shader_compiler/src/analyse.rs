@@ -328,12 +328,12 @@ impl<'a> DrawShaderAnalyser<'a> {
             DrawShaderFieldKind::Texture {..} => {
                 let ty = self.ty_checker().ty_check_ty_expr(&decl.ty_expr) ?;
                 match ty {
-                    Ty::Texture2D => {}
+                    Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => {}
                     _ => {
                         return Err(LiveError {
                             origin: live_error_origin!(),
                             span: decl.span.into(),
-                            message: String::from("texture must be a texture2D"),
+                            message: String::from("texture must be a texture2d, texture3d or textureCube"),
                         })
                     }
                 }
@@ -95,9 +95,11 @@ impl ConstEvaluator {
         expr_if_true: &Expr,
         expr_if_false: &Expr,
     ) -> Option<Val> {
-        let val = self.try_const_eval_expr(expr)?;
-        let val_if_true = self.try_const_eval_expr(expr_if_true)?;
-        let val_if_false = self.try_const_eval_expr(expr_if_false)?;
+        // evaluate all three so each branch has its const_val set for the gatherer
+        let val = self.try_const_eval_expr(expr);
+        let val_if_true = self.try_const_eval_expr(expr_if_true);
+        let val_if_false = self.try_const_eval_expr(expr_if_false);
+        let (val, val_if_true, val_if_false) = (val?, val_if_true?, val_if_false?);
         Some(if val.to_bool().unwrap() {
             val_if_true
         } else {
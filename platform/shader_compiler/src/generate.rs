@@ -77,6 +77,165 @@ pub trait BackendWriter {
     fn write_ty_lit(&self, string: &mut String, ty_lit: TyLit);
     fn write_builtin_call_ident(&self, string: &mut String, ident: Ident, arg_exprs: &[Expr]);
     
+    // the hooks below have the C style defaults, WGSL overrides them
+    
+    fn write_fn_def_start(&self, string: &mut String, ident: &dyn fmt::Display, return_ty: &Ty) {
+        self.write_var_decl(string, "", false, false, ident, return_ty);
+    }
+    
+    // written after the parameter list, for languages with a trailing return type
+    fn write_fn_def_return_ty(&self, _string: &mut String, _return_ty: &Ty) {
+    }
+    
+    fn write_local_var_decl(&self, string: &mut String, ident: &dyn fmt::Display, ty: &Ty) {
+        self.write_var_decl(string, "", false, false, ident, ty);
+    }
+    
+    // inout params are pointers, passed with & and used through *
+    fn inout_is_pointer(&self) -> bool {
+        false
+    }
+    
+    // a ? b : c is written as select(c, b, a)
+    fn cond_expr_is_select(&self) -> bool {
+        false
+    }
+    
+    // assignments can't be nested in expressions, so a = b = c becomes two statements
+    fn assign_is_statement(&self) -> bool {
+        false
+    }
+    
+    // builtins like max(vec2, float) need the float splatted to a vec2
+    fn builtin_needs_splat_args(&self) -> bool {
+        false
+    }
+    
+    // params can't be assigned to, so the ones that are get copied into a local first
+    fn params_are_immutable(&self) -> bool {
+        false
+    }
+}
+
+// the locals and params a block assigns to or passes as inout, found by their root var
+pub struct AssignedVarCollector<'a> {
+    pub shader_registry: &'a ShaderRegistry,
+    pub assigned: BTreeSet<(Ident, ScopeSymShadow)>,
+}
+
+impl<'a> AssignedVarCollector<'a> {
+    pub fn collect_block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            match stmt {
+                Stmt::For {from_expr, to_expr, step_expr, block, ..} => {
+                    self.collect_expr(from_expr);
+                    self.collect_expr(to_expr);
+                    if let Some(step_expr) = step_expr {
+                        self.collect_expr(step_expr);
+                    }
+                    self.collect_block(block);
+                }
+                Stmt::If {expr, block_if_true, block_if_false, ..} => {
+                    self.collect_expr(expr);
+                    self.collect_block(block_if_true);
+                    if let Some(block_if_false) = block_if_false {
+                        self.collect_block(block_if_false);
+                    }
+                }
+                Stmt::Match {expr, matches, ..} => {
+                    self.collect_expr(expr);
+                    for match_item in matches {
+                        self.collect_block(&match_item.block);
+                    }
+                }
+                Stmt::Let {expr: Some(expr), ..} | Stmt::Return {expr: Some(expr), ..} | Stmt::Expr {expr, ..} => {
+                    self.collect_expr(expr);
+                }
+                Stmt::Block {block, ..} => self.collect_block(block),
+                _ => ()
+            }
+        }
+    }
+    
+    fn collect_root_var(&mut self, expr: &Expr) {
+        match &expr.kind {
+            ExprKind::Field {expr, ..} | ExprKind::Index {expr, ..} => self.collect_root_var(expr),
+            ExprKind::Var {kind, ..} => if let Some(VarKind::MutLocal {ident, shadow}) = kind.get() {
+                self.assigned.insert((ident, shadow));
+            }
+            _ => ()
+        }
+    }
+    
+    fn collect_call_args(&mut self, params: &[Param], arg_exprs: &[Expr]) {
+        for (param, arg_expr) in params[params.len() - arg_exprs.len()..].iter().zip(arg_exprs.iter()) {
+            if param.is_inout {
+                self.collect_root_var(arg_expr);
+            }
+        }
+    }
+    
+    fn collect_expr(&mut self, expr: &Expr) {
+        match &expr.kind {
+            ExprKind::Cond {expr, expr_if_true, expr_if_false, ..} => {
+                self.collect_expr(expr);
+                self.collect_expr(expr_if_true);
+                self.collect_expr(expr_if_false);
+            }
+            ExprKind::Bin {op, left_expr, right_expr, ..} => {
+                if let BinOp::Assign | BinOp::AddAssign | BinOp::SubAssign | BinOp::MulAssign | BinOp::DivAssign = op {
+                    self.collect_root_var(left_expr);
+                }
+                self.collect_expr(left_expr);
+                self.collect_expr(right_expr);
+            }
+            ExprKind::Un {expr, ..} | ExprKind::Field {expr, ..} => self.collect_expr(expr),
+            ExprKind::Index {expr, index_expr, ..} => {
+                self.collect_expr(expr);
+                self.collect_expr(index_expr);
+            }
+            ExprKind::MethodCall {ident, arg_exprs, ..} => {
+                match arg_exprs[0].ty.borrow().as_ref().unwrap() {
+                    Ty::Struct(struct_ptr) => {
+                        let struct_def = self.shader_registry.structs.get(struct_ptr).unwrap();
+                        if let Some(fn_def) = self.shader_registry.struct_method_decl_from_ident(struct_def, *ident) {
+                            self.collect_call_args(&fn_def.params, arg_exprs);
+                        }
+                    }
+                    Ty::DrawShader(shader_ptr) => {
+                        let draw_shader_def = self.shader_registry.draw_shader_defs.get(shader_ptr).unwrap();
+                        if let Some(fn_def) = self.shader_registry.draw_shader_method_decl_from_ident(draw_shader_def, *ident) {
+                            // the self arg isnt passed to draw shader methods
+                            self.collect_call_args(&fn_def.params, &arg_exprs[1..]);
+                        }
+                    }
+                    _ => ()
+                }
+                for arg_expr in arg_exprs {
+                    self.collect_expr(arg_expr);
+                }
+            }
+            ExprKind::PlainCall {fn_ptr, arg_exprs, ..} => {
+                if let Some(fn_def) = fn_ptr.and_then( | fn_ptr | self.shader_registry.all_fns.get(&fn_ptr)) {
+                    self.collect_call_args(&fn_def.params, arg_exprs);
+                }
+                for arg_expr in arg_exprs {
+                    self.collect_expr(arg_expr);
+                }
+            }
+            ExprKind::BuiltinCall {arg_exprs, ..} | ExprKind::ConsCall {arg_exprs, ..} => {
+                for arg_expr in arg_exprs {
+                    self.collect_expr(arg_expr);
+                }
+            }
+            ExprKind::StructCons {args, ..} => {
+                for (_, arg_expr) in args {
+                    self.collect_expr(arg_expr);
+                }
+            }
+            _ => ()
+        }
+    }
 }
 
 pub struct BlockGenerator<'a> {
@@ -87,6 +246,7 @@ pub struct BlockGenerator<'a> {
     pub backend_writer: &'a dyn BackendWriter,
     pub const_table_offset: Option<usize>,
     //pub use_generated_cons_fns: bool,
+    pub inout_params: &'a [(Ident, ScopeSymShadow)],
     pub indent_level: usize,
     pub string: &'a mut String,
 }
@@ -100,8 +260,8 @@ pub fn generate_cons_fn(backend_writer:&dyn BackendWriter, string: &mut String,
     if !backend_writer.use_cons_fn(&cons_name) {
         return
     }
-    backend_writer.write_ty_lit(string, ty_lit);
-    write!(string, " {}(", cons_name).unwrap();
+    backend_writer.write_fn_def_start(string, &cons_name, &ty_lit.to_ty());
+    write!(string, "(").unwrap();
     
     let mut sep = "";
     if param_tys.len() == 1 {
@@ -114,7 +274,9 @@ pub fn generate_cons_fn(backend_writer:&dyn BackendWriter, string: &mut String,
         }
     }
     
-    writeln!(string, ") {{").unwrap();
+    write!(string, ")").unwrap();
+    backend_writer.write_fn_def_return_ty(string, &ty_lit.to_ty());
+    writeln!(string, " {{").unwrap();
     write!(string, "    return ").unwrap();
     backend_writer.write_ty_lit(string, ty_lit);
     write!(string, "(").unwrap();
@@ -181,7 +343,60 @@ pub fn generate_cons_fn(backend_writer:&dyn BackendWriter, string: &mut String,
     writeln!(string, "}}\n").unwrap();
 }
 
+// the by value params a function body assigns to, if the backend can't assign to params
+pub fn params_to_copy(backend_writer: &dyn BackendWriter, shader_registry: &ShaderRegistry, params: &[Param], block: &Block) -> Vec<(Ident, ScopeSymShadow, Ty)> {
+    if !backend_writer.params_are_immutable() {
+        return Vec::new()
+    }
+    let mut collector = AssignedVarCollector {shader_registry, assigned: BTreeSet::new()};
+    collector.collect_block(block);
+    params.iter().filter( | param | !param.is_inout).filter_map( | param | {
+        let shadow = param.shadow.get() ?;
+        let ty = param.ty_expr.ty.borrow().clone().unwrap();
+        // assigning to a field of the draw shader self writes to a varying
+        if let Ty::DrawShader(_) | Ty::ClosureDecl | Ty::ClosureDef(_) = ty {
+            return None
+        }
+        if collector.assigned.contains(&(param.ident, shadow)) {
+            Some((param.ident, shadow, ty))
+        }
+        else {
+            None
+        }
+    }).collect()
+}
+
+fn param_name(param_copies: &[(Ident, ScopeSymShadow, Ty)], ident: Ident, shadow: ScopeSymShadow) -> String {
+    if param_copies.iter().any( | (copy_ident, copy_shadow, _) | *copy_ident == ident && *copy_shadow == shadow) {
+        DisplayParamName(ident, shadow).to_string()
+    }
+    else {
+        DisplayVarName(ident, shadow).to_string()
+    }
+}
+
 impl<'a> BlockGenerator<'a> {
+    // a function body that starts by copying params into locals
+    pub fn generate_fn_block(&mut self, block: &Block, param_copies: &[(Ident, ScopeSymShadow, Ty)]) {
+        if param_copies.is_empty() {
+            return self.generate_block(block)
+        }
+        writeln!(self.string, "{{").unwrap();
+        self.indent_level += 1;
+        for (ident, shadow, ty) in param_copies {
+            self.write_indent();
+            self.backend_writer.write_local_var_decl(self.string, &DisplayVarName(*ident, *shadow), ty);
+            writeln!(self.string, " = {};", DisplayParamName(*ident, *shadow)).unwrap();
+        }
+        for stmt in &block.stmts {
+            self.generate_stmt(stmt);
+            writeln!(self.string).unwrap();
+        }
+        self.indent_level -= 1;
+        self.write_indent();
+        write!(self.string, "}}").unwrap();
+    }
+    
     pub fn generate_block(&mut self, block: &Block) {
         write!(self.string, "{{\n").unwrap();
         self.write_indent();
@@ -285,9 +500,11 @@ impl<'a> BlockGenerator<'a> {
         } else {
             -1
         };
+        write!(self.string, "for (").unwrap();
+        self.backend_writer.write_local_var_decl(self.string, &ident, &Ty::Int);
         write!(
             self.string,
-            "for (int {0} = {1}; {0} {2} {3}; {0} {4} {5}) ",
+            " = {1}; {0} {2} {3}; {0} {4} {5}) ",
             ident,
             if from <= to {from} else {from - 1},
             if from <= to {"<"} else {">="},
@@ -358,11 +575,8 @@ impl<'a> BlockGenerator<'a> {
         expr: &Option<Expr>,
         shadow: &Cell<Option<ScopeSymShadow >>
     ) {
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_local_var_decl(
             &mut self.string,
-            "",
-            false, 
-            false,
             &DisplayVarName(ident, shadow.get().unwrap()),
             ty.borrow().as_ref().unwrap()
         );
@@ -388,6 +602,20 @@ impl<'a> BlockGenerator<'a> {
     }
     
     fn generate_expr_stmt(&mut self, _span: TokenSpan, expr: &Expr) {
+        if self.backend_writer.assign_is_statement() {
+            // a = b = c is written as b = c; a = b;
+            if let ExprKind::Bin {op: BinOp::Assign, left_expr, right_expr, ..} = &expr.kind {
+                if let ExprKind::Bin {op: BinOp::Assign, left_expr: inner_left_expr, ..} = &right_expr.kind {
+                    self.generate_expr_stmt(_span, right_expr);
+                    self.write_indent();
+                    self.generate_expr(left_expr);
+                    write!(self.string, " = ").unwrap();
+                    self.generate_expr(inner_left_expr);
+                    writeln!(self.string, ";").unwrap();
+                    return
+                }
+            }
+        }
         self.generate_expr(expr);
         writeln!(self.string, ";").unwrap();
     }
@@ -401,6 +629,7 @@ impl<'a> BlockGenerator<'a> {
             const_table_offset: self.const_table_offset,
             //use_hidden_params: self.use_hidden_params,
             //use_generated_cons_fns: self.use_generated_cons_fns,
+            inout_params: self.inout_params,
             string: self.string,
         }
         .generate_expr(expr)
@@ -427,6 +656,7 @@ pub struct ExprGenerator<'a> {
     pub const_table_offset: Option<usize>,
     //pub use_hidden_params2: bool,
     //pub use_generated_cons_fns: bool,
+    pub inout_params: &'a [(Ident, ScopeSymShadow)],
     pub string: &'a mut String,
 }
 
@@ -566,6 +796,16 @@ impl<'a> ExprGenerator<'a> {
         expr_if_true: &Expr,
         expr_if_false: &Expr,
     ) {
+        if self.backend_writer.cond_expr_is_select() {
+            write!(self.string, "select(").unwrap();
+            self.generate_expr(expr_if_false);
+            write!(self.string, ", ").unwrap();
+            self.generate_expr(expr_if_true);
+            write!(self.string, ", ").unwrap();
+            self.generate_expr(expr);
+            write!(self.string, ")").unwrap();
+            return
+        }
         write!(self.string, "(").unwrap();
         self.generate_expr(expr);
         write!(self.string, " ? ").unwrap();
@@ -650,6 +890,15 @@ impl<'a> ExprGenerator<'a> {
             }
         }
         
+        if self.backend_writer.assign_is_statement() {
+            if let BinOp::Assign | BinOp::AddAssign | BinOp::SubAssign | BinOp::MulAssign | BinOp::DivAssign = op {
+                self.generate_expr(left_expr);
+                write!(self.string, " {} ", op).unwrap();
+                self.generate_expr(right_expr);
+                return
+            }
+        }
+        
         write!(self.string, "(").unwrap();
         self.generate_expr(left_expr);
         write!(self.string, " {} ", op).unwrap();
@@ -711,7 +960,7 @@ impl<'a> ExprGenerator<'a> {
             )).unwrap();
             
             let mut sep = "";
-            for arg_expr in arg_exprs {
+            for (index, arg_expr) in arg_exprs.iter().enumerate() {
                 // check if the args is a closure, ifso skip it
                 match arg_expr.ty.borrow().as_ref().unwrap(){
                     Ty::ClosureDef(_)=>{
@@ -721,7 +970,7 @@ impl<'a> ExprGenerator<'a> {
                 }
                 
                 write!(self.string, "{}", sep).unwrap();
-                self.generate_expr(arg_expr);
+                self.generate_arg_expr(arg_expr, Self::param_is_inout(fn_def, arg_exprs, index));
                 sep = ", ";
             }
             // and now the closed over values
@@ -748,9 +997,9 @@ impl<'a> ExprGenerator<'a> {
         else {
             write!(self.string, "{}_{} (", fn_def.fn_ptr, fn_def.ident).unwrap();
             let mut sep = "";
-            for arg_expr in arg_exprs {
+            for (index, arg_expr) in arg_exprs.iter().enumerate() {
                 write!(self.string, "{}", sep).unwrap();
-                self.generate_expr(arg_expr);
+                self.generate_arg_expr(arg_expr, Self::param_is_inout(fn_def, arg_exprs, index));
                 sep = ", ";
             }
 
@@ -760,6 +1009,27 @@ impl<'a> ExprGenerator<'a> {
         }
     }
     
+    // draw shader methods get their args without self, so they line up with the last params
+    fn param_is_inout(fn_def: &FnDef, arg_exprs: &[Expr], index: usize) -> bool {
+        fn_def.params[fn_def.params.len() - arg_exprs.len() + index].is_inout
+    }
+    
+    fn generate_arg_expr(&mut self, arg_expr: &Expr, is_inout: bool) {
+        if is_inout && self.backend_writer.inout_is_pointer() {
+            // an inout param passed on is already a pointer
+            if let ExprKind::Var {kind, ..} = &arg_expr.kind {
+                if let Some(VarKind::MutLocal {ident, shadow}) = kind.get() {
+                    if self.inout_params.contains(&(ident, shadow)) {
+                        write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
+                        return
+                    }
+                }
+            }
+            write!(self.string, "&").unwrap();
+        }
+        self.generate_expr(arg_expr);
+    }
+    
     fn generate_field_expr(&mut self, _span: TokenSpan, expr: &Expr, field_ident: Ident, ty:&Ty) {
         match expr.ty.borrow().as_ref() {
             Some(Ty::DrawShader(_)) => {
@@ -823,12 +1093,33 @@ impl<'a> ExprGenerator<'a> {
         
         self.backend_writer.write_builtin_call_ident(&mut self.string, ident, arg_exprs);
         
+        // the widest vector among the args, to splat float args to. refract takes a float eta
+        let splat_ty_lit = if self.backend_writer.builtin_needs_splat_args() && ident != Ident(live_id!(refract)) {
+            arg_exprs.iter().filter_map( | arg_expr | match arg_expr.ty.borrow().as_ref().unwrap() {
+                Ty::Vec2 => Some((2, TyLit::Vec2)),
+                Ty::Vec3 => Some((3, TyLit::Vec3)),
+                Ty::Vec4 => Some((4, TyLit::Vec4)),
+                _ => None
+            }).max_by_key( | (size, _) | *size).map( | (_, ty_lit) | ty_lit)
+        }
+        else {
+            None
+        };
+        
         write!(self.string, "(").unwrap();
         let mut sep = "";
         for arg_expr in arg_exprs {
             write!(self.string, "{}", sep).unwrap();
             
-            self.generate_expr(arg_expr);
+            match splat_ty_lit {
+                Some(ty_lit) if *arg_expr.ty.borrow().as_ref().unwrap() == Ty::Float => {
+                    self.write_ty_lit(ty_lit);
+                    write!(self.string, "(").unwrap();
+                    self.generate_expr(arg_expr);
+                    write!(self.string, ")").unwrap();
+                }
+                _ => self.generate_expr(arg_expr)
+            }
             
             sep = ", ";
         }
@@ -861,11 +1152,15 @@ impl<'a> ExprGenerator<'a> {
         
         write!(self.string, "{}", DisplayClosureName(closure_site_info.call_ptr, closure_def_index)).unwrap();
         
+        let closure_params = match &self.fn_def.unwrap().params[param_index].ty_expr.kind {
+            TyExprKind::ClosureDecl {params, ..} => params,
+            _ => panic!()
+        };
         write!(self.string, "(").unwrap();
         let mut sep = "";
-        for arg_expr in arg_exprs {
+        for (arg_expr, closure_param) in arg_exprs.iter().zip(closure_params.iter()) {
             write!(self.string, "{}", sep).unwrap();
-            self.generate_expr(arg_expr);
+            self.generate_arg_expr(arg_expr, closure_param.is_inout);
             sep = ", ";
         }
         // alright now we have to pass in the closed over syms IN order
@@ -926,7 +1221,12 @@ impl<'a> ExprGenerator<'a> {
                 write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
             }
             VarKind::MutLocal {ident, shadow} => {
-                write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
+                if self.backend_writer.inout_is_pointer() && self.inout_params.contains(&(ident, shadow)) {
+                    write!(self.string, "(*{})", DisplayVarName(ident, shadow)).unwrap();
+                }
+                else {
+                    write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
+                }
             }
             VarKind::LiveValue(value_node_ptr) => {
                 // this is a live value.. also prefix needed
@@ -960,14 +1260,12 @@ pub struct FnDefGenerator<'a> {
 impl<'a> FnDefGenerator<'a> {
     pub fn generate_fn_def(&mut self) {
         
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_fn_def_start(
             &mut self.string,
-            "",
-            false,
-            false,
             &DisplayFnName(self.fn_def.fn_ptr, self.fn_def.ident), // here we must expand IdentPath to something
             self.fn_def.return_ty.borrow().as_ref().unwrap()
         );
+        let param_copies = params_to_copy(self.backend_writer, self.shader_registry, &self.fn_def.params, &self.fn_def.block);
         write!(self.string, "(").unwrap();
         let mut sep = "";
         for param in &self.fn_def.params {
@@ -977,7 +1275,7 @@ impl<'a> FnDefGenerator<'a> {
                     sep,
                     param.is_inout,
                     false,
-                    &DisplayVarName(param.ident, param.shadow.get().unwrap()),
+                    &param_name(&param_copies, param.ident, param.shadow.get().unwrap()),
                     param.ty_expr.ty.borrow().as_ref().unwrap(),
                 ) {
                     sep = ", ";
@@ -985,13 +1283,15 @@ impl<'a> FnDefGenerator<'a> {
             }
         }
         self.backend_writer.write_fn_def_hidden_params(self.string, self.fn_def.hidden_args.borrow().as_ref().unwrap(), sep);
-        write!(self.string, ") ").unwrap();
-        self.generate_block(&self.fn_def.block);
+        write!(self.string, ")").unwrap();
+        self.backend_writer.write_fn_def_return_ty(self.string, self.fn_def.return_ty.borrow().as_ref().unwrap());
+        write!(self.string, " ").unwrap();
+        self.generate_block(&self.fn_def.block, &param_copies);
         writeln!(self.string).unwrap();
         //self.visited.insert(self.decl.ident_path);
     }
     
-    fn generate_block(&mut self, block: &Block) {
+    fn generate_block(&mut self, block: &Block, param_copies: &[(Ident, ScopeSymShadow, Ty)]) {
         BlockGenerator {
             shader_registry: self.shader_registry,
             closure_site_info: None,
@@ -999,10 +1299,11 @@ impl<'a> FnDefGenerator<'a> {
             fn_def: self.fn_def,
             backend_writer: self.backend_writer,
             const_table_offset: self.const_table_offset,
+            inout_params: &self.fn_def.inout_params(),
             indent_level: 0,
             string: self.string,
         }
-        .generate_block(block)
+        .generate_fn_block(block, param_copies)
     }
 }
 
@@ -1077,11 +1378,8 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
     
     pub fn generate_fn_def_with_closure_args(&mut self) {
         
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_fn_def_start(
             &mut self.string,
-            "",
-            false,
-            false,
             &DisplayFnNameWithClosureArgs(
                 self.closure_site_info.site_index,
                 self.call_def.fn_ptr,
@@ -1089,6 +1387,7 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
             ), // here we must expand IdentPath to something
             self.fn_def.return_ty.borrow().as_ref().unwrap()
         );
+        let param_copies = params_to_copy(self.backend_writer, self.shader_registry, &self.fn_def.params, &self.fn_def.block);
         write!(self.string, "(").unwrap();
         let mut sep = "";
         for param in &self.fn_def.params {
@@ -1098,7 +1397,7 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
                     sep,
                     param.is_inout,
                     false,
-                    &DisplayVarName(param.ident, param.shadow.get().unwrap()),
+                    &param_name(&param_copies, param.ident, param.shadow.get().unwrap()),
                     param.ty_expr.ty.borrow().as_ref().unwrap(),
                 ) {
                     sep = ", ";
@@ -1125,17 +1424,19 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
         merged_hidden_args.extend(self.call_def.hidden_args.borrow().as_ref().unwrap().iter().cloned());
         self.backend_writer.write_fn_def_hidden_params(self.string, &merged_hidden_args, sep);
         
-        write!(self.string, ") ").unwrap();
+        write!(self.string, ")").unwrap();
+        self.backend_writer.write_fn_def_return_ty(self.string, self.fn_def.return_ty.borrow().as_ref().unwrap());
+        write!(self.string, " ").unwrap();
         // alright so here the block is generated.. however
         // we need to know the names and the closed-over-args passthrough
-        self.generate_block(&self.fn_def.block);
+        self.generate_block(&self.fn_def.block, &param_copies);
         
         
         writeln!(self.string).unwrap();
         //self.visited.insert(self.decl.ident_path);
     }
     
-    fn generate_block(&mut self, block: &Block) {
+    fn generate_block(&mut self, block: &Block, param_copies: &[(Ident, ScopeSymShadow, Ty)]) {
         BlockGenerator {
             shader_registry: self.shader_registry,
             closure_site_info: Some(self.closure_site_info.clone()),
@@ -1143,10 +1444,11 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
             fn_def: self.fn_def,
             backend_writer: self.backend_writer,
             const_table_offset: self.const_table_offset,
+            inout_params: &self.fn_def.inout_params(),
             indent_level: 0,
             string: self.string,
         }
-        .generate_block(block)
+        .generate_fn_block(block, param_copies)
     }
 }
 
@@ -1162,19 +1464,32 @@ pub struct ClosureDefGenerator<'a> {
 }
 
 impl<'a> ClosureDefGenerator<'a> {
+    fn fn_param(&self) -> &'a Param {
+        &self.fn_def.params[self.closure_site_arg.param_index]
+    }
+    
+    // the closure params that are inout, these are not params of fn_def
+    fn inout_params(&self) -> Vec<(Ident, ScopeSymShadow)> {
+        if let TyExprKind::ClosureDecl {params, ..} = &self.fn_param().ty_expr.kind {
+            params.iter().zip(self.closure_def.params.iter()).filter( | (param, _) | param.is_inout).map( | (_, closure_param) | {
+                (closure_param.ident, closure_param.shadow.get().unwrap())
+            }).collect()
+        }
+        else {
+            panic!()
+        }
+    }
+    
     pub fn generate_fn_def(&mut self) {
         
-        let fn_param = &self.fn_def.params[self.closure_site_arg.param_index];
+        let fn_param = self.fn_param();
         
         let mut sep = "";
         
         if let TyExprKind::ClosureDecl {params, return_ty, ..} = &fn_param.ty_expr.kind {
             
-            self.backend_writer.write_var_decl(
+            self.backend_writer.write_fn_def_start(
                 &mut self.string,
-                "",
-                false,
-                false,
                 &DisplayClosureName(self.call_def.fn_ptr, self.closure_site_arg.closure_def_index), // here we must expand IdentPath to something
                 return_ty.borrow().as_ref().unwrap(),
             );
@@ -1219,7 +1534,11 @@ impl<'a> ClosureDefGenerator<'a> {
         merged_hidden_args.extend(self.call_def.hidden_args.borrow().as_ref().unwrap().iter().cloned());
         self.backend_writer.write_fn_def_hidden_params(self.string, &merged_hidden_args, sep);
         
-        writeln!(self.string, ") {{").unwrap();
+        write!(self.string, ")").unwrap();
+        if let TyExprKind::ClosureDecl {return_ty, ..} = &self.fn_param().ty_expr.kind {
+            self.backend_writer.write_fn_def_return_ty(self.string, return_ty.borrow().as_ref().unwrap());
+        }
+        writeln!(self.string, " {{").unwrap();
         
         match &self.closure_def.kind {
             ClosureDefKind::Expr(expr) => {
@@ -1244,6 +1563,7 @@ impl<'a> ClosureDefGenerator<'a> {
             fn_def: self.fn_def,
             backend_writer: self.backend_writer,
             const_table_offset: self.const_table_offset,
+            inout_params: &self.inout_params(),
             indent_level: 0,
             string: self.string,
         }
@@ -1259,6 +1579,7 @@ impl<'a> ClosureDefGenerator<'a> {
             fn_def: Some(self.fn_def),
             backend_writer: self.backend_writer,
             const_table_offset: self.const_table_offset,
+            inout_params: &self.inout_params(),
             string: self.string,
         }
         .generate_expr(expr)
@@ -1336,3 +1657,11 @@ impl fmt::Display for DisplaConstructorArg {
         fmt::Result::Ok(())
     }
 }
+
+pub struct DisplayParamName(pub Ident, pub ScopeSymShadow);
+impl fmt::Display for DisplayParamName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "param_{}_{}", self.0, self.1.0);
+        fmt::Result::Ok(())
+    }
+}
@@ -192,10 +192,26 @@ impl<'a> DrawShaderGenerator<'a> {
         // alright so. we have our fn deps which have struct deps
         // and we have struct deps in our struct deps.
         let mut all_constructor_fns = BTreeSet::new();
+        let mut all_builtin_deps = BTreeSet::new();
         
         for callee in fn_deps.iter().rev() {
             let decl = self.shader_registry.all_fns.get(callee).unwrap();
             all_constructor_fns.extend(decl.constructor_fn_deps.borrow().as_ref().unwrap().iter().cloned());
+            all_builtin_deps.extend(decl.builtin_deps.borrow().as_ref().unwrap().iter().cloned());
+        }
+        
+        // sample2d comes with the platform header, these only go in when used since
+        // not every GL has 3D textures. They swizzle like sample2d does
+        for ident in &all_builtin_deps {
+            match *ident {
+                Ident(live_id!(sample3d)) => {
+                    writeln!(self.string, "vec4 sample3d(sampler3D sampler, vec3 pos){{return texture3D(sampler, pos).zyxw;}}").unwrap();
+                }
+                Ident(live_id!(sample_cube)) => {
+                    writeln!(self.string, "vec4 sample_cube(samplerCube sampler, vec3 dir){{return textureCube(sampler, dir).zyxw;}}").unwrap();
+                }
+                _ => ()
+            }
         }
         
         // all our live ref uniforms
@@ -572,6 +588,7 @@ impl<'a> DrawShaderGenerator<'a> {
             shader_registry: self.shader_registry,
             closure_site_info: None,
             const_table_offset: None,
+            inout_params: &[],
             backend_writer: self.backend_writer,
             string: self.string,
         }
@@ -891,9 +908,13 @@ impl<'a> BackendWriter for GlslBackendWriter<'a> {
                 self.write_ty_lit(string, TyLit::Mat4);
                 write!(string, " {}", ident).unwrap();
             }
-            Ty::Texture2D => {
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => {
                 write!(string, "{}", sep).unwrap();
-                self.write_ty_lit(string, TyLit::Texture2D);
+                self.write_ty_lit(string, match ty {
+                    Ty::Texture3D => TyLit::Texture3D,
+                    Ty::TextureCube => TyLit::TextureCube,
+                    _ => TyLit::Texture2D
+                });
                 write!(string, " {}", ident).unwrap();
             }
             Ty::Array {ref elem_ty, len} => {
@@ -956,6 +977,8 @@ impl<'a> BackendWriter for GlslBackendWriter<'a> {
                 TyLit::Mat3 => "mat3",
                 TyLit::Mat4 => "mat4",
                 TyLit::Texture2D => "sampler2D",
+                TyLit::Texture3D => "sampler3D",
+                TyLit::TextureCube => "samplerCube",
             }
         )
             .unwrap();
@@ -44,26 +44,33 @@ impl<'a> DrawShaderGenerator<'a> {
     fn generate_shader(&mut self) {
         
         let mut all_constructor_fns = BTreeSet::new();
+        let mut all_builtin_deps = BTreeSet::new();
         
         for fn_iter in self.draw_shader_def.all_fns.borrow().iter() {
             let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
             all_constructor_fns.extend(fn_def.constructor_fn_deps.borrow().as_ref().unwrap().iter().cloned());
+            all_builtin_deps.extend(fn_def.builtin_deps.borrow().as_ref().unwrap().iter().cloned());
         }
-
-        for fn_iter in self.draw_shader_def.all_fns.borrow().iter() {
-            let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
-            if fn_def.builtin_deps.borrow().as_ref().unwrap().contains(&Ident(live_id!(sample2d))) {
-                writeln!(self.string, "SamplerState default_texture_sampler{{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;}};").unwrap();
-                writeln!(self.string, "float4 sample2d(Texture2D tex, float2 pos){{return tex.Sample(default_texture_sampler,pos);}}").unwrap();
-                break;
-            }
-
-            if fn_def.builtin_deps.borrow().as_ref().unwrap().contains(&Ident(live_id!(sample2d_rt))) {
-                writeln!(self.string, "SamplerState default_texture_sampler{{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;}};").unwrap();
-                writeln!(self.string, "float4 sample2d_rt(Texture2D tex, float2 pos){{return tex.Sample(default_texture_sampler,pos);}}").unwrap();
-                break;
+        
+        // LiveId ordering treats ids it can't name as equal, so no BTreeSet::contains here
+        let sample_fns = [live_id!(sample2d), live_id!(sample2d_rt), live_id!(sample3d), live_id!(sample_cube)];
+        if all_builtin_deps.iter().any( | ident | sample_fns.contains(&ident.0)) {
+            writeln!(self.string, "SamplerState default_texture_sampler{{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;}};").unwrap();
+        }
+        for ident in &all_builtin_deps {
+            match *ident {
+                Ident(live_id!(sample2d)) | Ident(live_id!(sample2d_rt)) => {
+                    writeln!(self.string, "float4 {}(Texture2D tex, float2 pos){{return tex.Sample(default_texture_sampler,pos);}}", ident).unwrap();
+                }
+                Ident(live_id!(sample3d)) => {
+                    writeln!(self.string, "float4 sample3d(Texture3D tex, float3 pos){{return tex.Sample(default_texture_sampler,pos);}}").unwrap();
+                }
+                Ident(live_id!(sample_cube)) => {
+                    writeln!(self.string, "float4 sample_cube(TextureCube tex, float3 dir){{return tex.Sample(default_texture_sampler,dir);}}").unwrap();
+                }
+                _ => ()
             }
-        };
+        }
         
         self.generate_struct_decls();
         let fields_as_uniform_blocks = self.draw_shader_def.fields_as_uniform_blocks();
@@ -193,8 +200,13 @@ impl<'a> DrawShaderGenerator<'a> {
         for field in &self.draw_shader_def.fields {
             match field.kind {
                 DrawShaderFieldKind::Texture {..} => {
-                    assert_eq!(*field.ty_expr.ty.borrow().as_ref().unwrap(), Ty::Texture2D);
-                    write!(self.string, "Texture2D {}: register(t{});", DisplayDsIdent(field.ident), index).unwrap();
+                    let hlsl_ty = match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                        Ty::Texture2D => "Texture2D",
+                        Ty::Texture3D => "Texture3D",
+                        Ty::TextureCube => "TextureCube",
+                        _ => panic!()
+                    };
+                    write!(self.string, "{} {}: register(t{});", hlsl_ty, DisplayDsIdent(field.ident), index).unwrap();
                     index += 1;
                 }
                 _ => {}
@@ -457,6 +469,7 @@ impl<'a> DrawShaderGenerator<'a> {
             closure_site_info: None,
             //env: self.env,
             const_table_offset: None,
+            inout_params: &[],
             backend_writer: self.backend_writer,
             string: self.string,
         }
@@ -625,7 +638,7 @@ impl<'a> BackendWriter for HlslBackendWriter<'a> {
                 self.write_ty_lit(string, TyLit::Mat4);
                 write!(string, " {}", ident).unwrap();
             }
-            Ty::Texture2D => panic!(), // TODO
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => panic!(), // TODO
             Ty::Array {ref elem_ty, len} => {
                 self.write_var_decl(string, sep, is_inout, is_packed, ident, elem_ty);
                 write!(string, "[{}]", len).unwrap();
@@ -800,7 +813,7 @@ impl<'a> BackendWriter for HlslBackendWriter<'a> {
                 TyLit::Mat2 => "float2x2",
                 TyLit::Mat3 => "float3x3",
                 TyLit::Mat4 => "float4x4",
-                TyLit::Texture2D => panic!(), // TODO
+                TyLit::Texture2D | TyLit::Texture3D | TyLit::TextureCube => panic!(), // TODO
             }
         )
             .unwrap();
@@ -57,17 +57,30 @@ impl<'a> DrawShaderGenerator<'a> {
             all_constructor_fns.extend(fn_def.constructor_fn_deps.borrow().as_ref().unwrap().iter().cloned());
         }
         
+        let mut all_builtin_deps = BTreeSet::new();
         for fn_iter in self.draw_shader_def.all_fns.borrow().iter() {
             let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
-            if fn_def.builtin_deps.borrow().as_ref().unwrap().contains(&Ident(live_id!(sample2d))) {
-                writeln!(self.string, "float4 sample2d(texture2d<float> tex, float2 pos){{return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}}").unwrap();
-                break;
-            }
-            if fn_def.builtin_deps.borrow().as_ref().unwrap().contains(&Ident(live_id!(sample2d_rt))) {
-                writeln!(self.string, "float4 sample2d_rt(texture2d<float> tex, float2 pos){{return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}}").unwrap();
-                break;
+            all_builtin_deps.extend(fn_def.builtin_deps.borrow().as_ref().unwrap().iter().cloned());
+        }
+        
+        // filters like the GL texture parameters, the mip filter only kicks in for textures with mipmaps
+        for ident in &all_builtin_deps {
+            match *ident {
+                Ident(live_id!(sample2d)) => {
+                    writeln!(self.string, "float4 sample2d(texture2d<float> tex, float2 pos){{return tex.sample(sampler(mag_filter::nearest,min_filter::linear,mip_filter::linear),pos);}}").unwrap();
+                }
+                Ident(live_id!(sample2d_rt)) => {
+                    writeln!(self.string, "float4 sample2d_rt(texture2d<float> tex, float2 pos){{return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}}").unwrap();
+                }
+                Ident(live_id!(sample3d)) => {
+                    writeln!(self.string, "float4 sample3d(texture3d<float> tex, float3 pos){{return tex.sample(sampler(filter::linear,mip_filter::linear),pos);}}").unwrap();
+                }
+                Ident(live_id!(sample_cube)) => {
+                    writeln!(self.string, "float4 sample_cube(texturecube<float> tex, float3 dir){{return tex.sample(sampler(filter::linear,mip_filter::linear),dir);}}").unwrap();
+                }
+                _ => ()
             }
-        };
+        }
         
         self.generate_struct_defs();
         //let fields_as_uniform_blocks = self.draw_shader_def.fields_as_uniform_blocks();
@@ -166,8 +179,13 @@ impl<'a> DrawShaderGenerator<'a> {
         for field in &self.draw_shader_def.fields {
             match field.kind {
                 DrawShaderFieldKind::Texture {..} => {
-                    assert_eq!(*field.ty_expr.ty.borrow().as_ref().unwrap(), Ty::Texture2D);
-                    write!(self.string, "    texture2d<float> ").unwrap();
+                    let metal_ty = match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                        Ty::Texture2D => "texture2d<float>",
+                        Ty::Texture3D => "texture3d<float>",
+                        Ty::TextureCube => "texturecube<float>",
+                        _ => panic!()
+                    };
+                    write!(self.string, "    {} ", metal_ty).unwrap();
                     write!(self.string, "{}", &DisplayDsIdent(field.ident)).unwrap();
                     write!(self.string, " [[texture({})]];", index).unwrap();
                     index += 1;
@@ -421,6 +439,7 @@ impl<'a> DrawShaderGenerator<'a> {
             closure_site_info: None,
             //env: self.env,
             const_table_offset: None,
+            inout_params: &[],
             backend_writer: self.backend_writer,
             string: self.string,
         }
@@ -591,7 +610,7 @@ impl<'a> BackendWriter for MetalBackendWriter<'a> {
                 self.write_ty_lit(string, TyLit::Mat4);
                 write!(string, " {}{}", ref_prefix, ident).unwrap();
             }
-            Ty::Texture2D => panic!(), // TODO
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => panic!(), // TODO
             Ty::Array {ref elem_ty, len} => {
                 self.write_var_decl(string, sep, is_inout, is_packed, ident, elem_ty);
                 write!(string, "[{}]", len).unwrap();
@@ -791,7 +810,7 @@ impl<'a> BackendWriter for MetalBackendWriter<'a> {
                 TyLit::Mat2 => "float2x2",
                 TyLit::Mat3 => "float3x3",
                 TyLit::Mat4 => "float4x4",
-                TyLit::Texture2D => panic!(), // TODO
+                TyLit::Texture2D | TyLit::Texture3D | TyLit::TextureCube => panic!(), // TODO
             }
         )
             .unwrap();
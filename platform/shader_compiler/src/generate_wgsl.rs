@@ -0,0 +1,625 @@
+use {
+    std::{
+        fmt::Write,
+        fmt,
+        collections::{BTreeMap, BTreeSet}
+    },
+    crate::{
+        makepad_live_compiler::*,
+        makepad_live_id::*,
+        shader_ast::*,
+        generate::*,
+        shader_registry::ShaderRegistry,
+    }
+};
+
+// WGSL has no hidden args, uniforms and textures are module scope bindings and the geometries,
+// instances and varyings are copied into private vars by the entry points. Bindings are all in
+// group 0: live uniforms at 0, the const table at 1, then the uniform blocks in the order of
+// fields_as_uniform_blocks, then the textures in field order and the sampler after them
+
+pub struct WgslGeneratedShader{
+    pub wgsl: String,
+    pub fields_as_uniform_blocks:BTreeMap<Ident, Vec<(usize, Ident) >>
+}
+
+pub fn generate_shader(draw_shader_def: &DrawShaderDef, const_table:&DrawShaderConstTable, shader_registry: &ShaderRegistry) -> WgslGeneratedShader {
+    let mut string = String::new();
+    let fields_as_uniform_blocks = draw_shader_def.fields_as_uniform_blocks();
+    DrawShaderGenerator {
+        draw_shader_def,
+        shader_registry,
+        const_table,
+        string: &mut string,
+        fields_as_uniform_blocks: &fields_as_uniform_blocks,
+        backend_writer: &WgslBackendWriter {draw_shader_def}
+    }
+    .generate_shader();
+    WgslGeneratedShader{
+        wgsl:string,
+        fields_as_uniform_blocks
+    }
+}
+
+struct DrawShaderGenerator<'a> {
+    draw_shader_def: &'a DrawShaderDef,
+    shader_registry: &'a ShaderRegistry,
+    string: &'a mut String,
+    fields_as_uniform_blocks: &'a BTreeMap<Ident, Vec<(usize, Ident) >>,
+    backend_writer: &'a dyn BackendWriter,
+    const_table: &'a DrawShaderConstTable
+}
+
+impl<'a> DrawShaderGenerator<'a> {
+    fn generate_shader(&mut self) {
+        let mut all_constructor_fns = BTreeSet::new();
+        let mut all_builtin_deps = BTreeSet::new();
+        for fn_iter in self.draw_shader_def.all_fns.borrow().iter() {
+            let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
+            all_constructor_fns.extend(fn_def.constructor_fn_deps.borrow().as_ref().unwrap().iter().cloned());
+            all_builtin_deps.extend(fn_def.builtin_deps.borrow().as_ref().unwrap().iter().cloned());
+        }
+
+        self.generate_struct_defs();
+        self.generate_uniform_structs();
+        self.generate_texture_bindings();
+        self.generate_geometry_struct();
+        self.generate_instance_struct();
+        self.generate_varying_struct();
+
+        for ident in &all_builtin_deps {
+            match *ident {
+                Ident(live_id!(sample2d)) | Ident(live_id!(sample2d_rt)) => {
+                    writeln!(self.string, "fn {}(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {{return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}}", ident).unwrap();
+                }
+                Ident(live_id!(sample3d)) => {
+                    writeln!(self.string, "fn sample3d(tex: texture_3d<f32>, pos: vec3<f32>) -> vec4<f32> {{return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}}").unwrap();
+                }
+                Ident(live_id!(sample_cube)) => {
+                    writeln!(self.string, "fn sample_cube(tex: texture_cube<f32>, dir: vec3<f32>) -> vec4<f32> {{return textureSampleLevel(tex, default_texture_sampler, dir, 0.0);}}").unwrap();
+                }
+                // GLSL mod floors where % truncates, scalar args are splatted so these cover all overloads
+                Ident(live_id!(mod)) => {
+                    for ty in ["f32", "vec2<f32>", "vec3<f32>", "vec4<f32>"] {
+                        writeln!(self.string, "fn mod_{0}(x: {1}, y: {1}) -> {1} {{return x - y * floor(x / y);}}", ty.replace("<f32>", ""), ty).unwrap();
+                    }
+                }
+                _ => ()
+            }
+        }
+
+        for (ty_lit, ref param_tys) in all_constructor_fns{
+            generate_cons_fn(self.backend_writer, self.string, ty_lit, param_tys);
+        }
+
+        let all_fns = self.draw_shader_def.all_fns.borrow();
+        for fn_iter in all_fns.iter().rev() {
+            let const_table_offset = self.const_table.offsets.get(fn_iter).cloned();
+            let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
+            if fn_def.has_closure_args() {
+                for call_iter in all_fns.iter().rev() {
+                    // any function that depends on us, will have the closures we need
+                    let call_def = self.shader_registry.all_fns.get(call_iter).unwrap();
+                    if call_def.callees.borrow().as_ref().unwrap().contains(fn_iter) {
+                        FnDefWithClosureArgsGenerator::generate_fn_def_with_all_closures(
+                            self.string,
+                            self.shader_registry,
+                            fn_def,
+                            call_def,
+                            self.backend_writer,
+                            const_table_offset
+                        );
+                    }
+                }
+                continue
+            }
+            FnDefGenerator {
+                fn_def,
+                const_table_offset,
+                shader_registry: self.shader_registry,
+                backend_writer: self.backend_writer,
+                string: self.string,
+            }
+            .generate_fn_def()
+        }
+        self.generate_vertex_main();
+        self.generate_pixel_main();
+    }
+
+    fn generate_struct_defs(&mut self) {
+        for struct_ptr in self.draw_shader_def.all_structs.borrow().iter().rev() {
+            let struct_def = self.shader_registry.structs.get(struct_ptr).unwrap();
+            writeln!(self.string, "struct {} {{", struct_ptr).unwrap();
+            for field in &struct_def.fields {
+                write!(self.string, "    ").unwrap();
+                self.write_var_decl(&DisplayStructField(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}};").unwrap();
+        }
+    }
+
+    fn generate_uniform_structs(&mut self) {
+        // WGSL doesn't allow empty structs, so unused bindings are left out
+        let all_live_refs = self.draw_shader_def.all_live_refs.borrow();
+        if !all_live_refs.is_empty() {
+            writeln!(self.string, "struct LiveUniforms {{").unwrap();
+            for (value_node_ptr, ty) in all_live_refs.iter() {
+                write!(self.string, "    {}: ", value_node_ptr).unwrap();
+                self.write_ty_lit(ty.maybe_ty_lit().unwrap());
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}};").unwrap();
+            writeln!(self.string, "@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;").unwrap();
+        }
+
+        if !self.const_table.table.is_empty() {
+            // uniform arrays have a 16 byte stride, so the table is packed in vec4s
+            writeln!(self.string, "@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, {}>;", self.const_table.table.len().div_ceil(4)).unwrap();
+        }
+
+        for (binding, (ident, vec)) in self.fields_as_uniform_blocks.iter().enumerate() {
+            writeln!(self.string, "struct Uniforms_{} {{", ident).unwrap();
+            for (index, _item) in vec {
+                let field = &self.draw_shader_def.fields[*index];
+                write!(self.string, "    ").unwrap();
+                self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}};").unwrap();
+            writeln!(self.string, "@group(0) @binding({1}) var<uniform> uniforms_{0}: Uniforms_{0};", ident, binding + 2).unwrap();
+        }
+    }
+
+    fn generate_texture_bindings(&mut self) {
+        let first_binding = self.fields_as_uniform_blocks.len() + 2;
+        let mut binding = first_binding;
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Texture {..} = field.kind {
+                let wgsl_ty = match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                    Ty::Texture2D => "texture_2d<f32>",
+                    Ty::Texture3D => "texture_3d<f32>",
+                    Ty::TextureCube => "texture_cube<f32>",
+                    _ => panic!()
+                };
+                writeln!(self.string, "@group(0) @binding({}) var {}: {};", binding, DisplayDsIdent(field.ident), wgsl_ty).unwrap();
+                binding += 1;
+            }
+        }
+        if binding != first_binding {
+            writeln!(self.string, "@group(0) @binding({}) var default_texture_sampler: sampler;", binding).unwrap();
+        }
+    }
+
+    fn generate_geometry_struct(&mut self) {
+        writeln!(self.string, "struct Geometries {{").unwrap();
+        let mut location = 0;
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Geometry {..} = field.kind {
+                write!(self.string, "    @location({}) ", location).unwrap();
+                self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                writeln!(self.string, ",").unwrap();
+                location += 1;
+            }
+        }
+        writeln!(self.string, "}};").unwrap();
+        writeln!(self.string, "var<private> geometries: Geometries;").unwrap();
+    }
+
+    fn geometry_locations(&self) -> usize {
+        self.draw_shader_def.fields.iter().filter( | field | matches!(field.kind, DrawShaderFieldKind::Geometry {..})).count()
+    }
+
+    fn has_instances(&self) -> bool {
+        self.draw_shader_def.fields.iter().any( | field | matches!(field.kind, DrawShaderFieldKind::Instance {..}))
+    }
+
+    fn generate_instance_struct(&mut self) {
+        if !self.has_instances() {
+            return
+        }
+        let mut padding = 0;
+        let mut location = self.geometry_locations();
+        writeln!(self.string, "struct Instances {{").unwrap();
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Instance {..} = field.kind {
+                match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                    Ty::Float | Ty::Vec2 | Ty::Vec3 | Ty::Vec4 | Ty::Enum(_) => {
+                        write!(self.string, "    @location({}) ", location).unwrap();
+                        if field.ident == Ident(LiveId(0)) {
+                            self.write_var_decl(&DisplayPadding(padding), field.ty_expr.ty.borrow().as_ref().unwrap());
+                            padding += 1;
+                        }
+                        else {
+                            self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                        }
+                        writeln!(self.string, ",").unwrap();
+                        location += 1;
+                    },
+                    Ty::Mat4 | Ty::Mat3 => {
+                        let (count, ty_lit) = if let Ty::Mat4 = field.ty_expr.ty.borrow().as_ref().unwrap() {(4, TyLit::Vec4)} else {(3, TyLit::Vec3)};
+                        for i in 0..count {
+                            write!(self.string, "    @location({}) {}{}: ", location, DisplayDsIdent(field.ident), i).unwrap();
+                            self.write_ty_lit(ty_lit);
+                            writeln!(self.string, ",").unwrap();
+                            location += 1;
+                        }
+                    },
+                    Ty::Mat2 => {
+                        write!(self.string, "    @location({}) ", location).unwrap();
+                        self.write_var_decl(&DisplayDsIdent(field.ident), &Ty::Vec4);
+                        writeln!(self.string, ",").unwrap();
+                        location += 1;
+                    },
+                    _ => panic!("unsupported type in generate_instance_struct")
+                }
+            }
+        }
+        writeln!(self.string, "}};").unwrap();
+        writeln!(self.string, "var<private> instances: Instances;").unwrap();
+    }
+
+    fn generate_varying_struct(&mut self) {
+        let mut location = 0;
+        let mut write_location = | string: &mut String, ty: &Ty | {
+            // integers can't be interpolated
+            let flat = if let Ty::Enum(_) = ty {" @interpolate(flat)"} else {""};
+            write!(string, "    @location({}){} ", location, flat).unwrap();
+            location += 1;
+        };
+        writeln!(self.string, "struct Varyings {{").unwrap();
+        writeln!(self.string, "    @builtin(position) position: vec4<f32>,").unwrap();
+        for field in &self.draw_shader_def.fields {
+            let ty = field.ty_expr.ty.borrow();
+            let ty = ty.as_ref().unwrap();
+            match &field.kind {
+                DrawShaderFieldKind::Geometry {is_used_in_pixel_shader, ..} if is_used_in_pixel_shader.get() => {
+                    write_location(self.string, ty);
+                    self.write_var_decl(&DisplayDsIdent(field.ident), ty);
+                    writeln!(self.string, ",").unwrap();
+                }
+                DrawShaderFieldKind::Instance {is_used_in_pixel_shader, ..} if is_used_in_pixel_shader.get() => {
+                    match ty {
+                        Ty::Float | Ty::Vec2 | Ty::Vec3 | Ty::Vec4 | Ty::Enum(_) => {
+                            write_location(self.string, ty);
+                            self.write_var_decl(&DisplayDsIdent(field.ident), ty);
+                            writeln!(self.string, ",").unwrap();
+                        },
+                        Ty::Mat4 | Ty::Mat3 => {
+                            let (count, ty) = if let Ty::Mat4 = ty {(4, Ty::Vec4)} else {(3, Ty::Vec3)};
+                            for i in 0..count {
+                                write_location(self.string, &ty);
+                                write!(self.string, "{}{}: ", DisplayDsIdent(field.ident), i).unwrap();
+                                self.backend_writer.write_ty_lit(self.string, ty.maybe_ty_lit().unwrap());
+                                writeln!(self.string, ",").unwrap();
+                            }
+                        },
+                        Ty::Mat2 => {
+                            write_location(self.string, &Ty::Vec4);
+                            self.write_var_decl(&DisplayDsIdent(field.ident), &Ty::Vec4);
+                            writeln!(self.string, ",").unwrap();
+                        },
+                        _ => panic!("unsupported type in generate_varying_struct")
+                    }
+                }
+                DrawShaderFieldKind::Varying {..} => {
+                    write_location(self.string, ty);
+                    self.write_var_decl(&DisplayDsIdent(field.ident), ty);
+                    writeln!(self.string, ",").unwrap();
+                }
+                _ => {}
+            }
+        }
+        writeln!(self.string, "}};").unwrap();
+        writeln!(self.string, "var<private> varyings: Varyings;").unwrap();
+    }
+
+    fn generate_vertex_main(&mut self) {
+        write!(self.string, "@vertex fn vertex_main(geometries_in: Geometries").unwrap();
+        if self.has_instances() {
+            write!(self.string, ", instances_in: Instances").unwrap();
+        }
+        writeln!(self.string, ") -> Varyings {{").unwrap();
+        writeln!(self.string, "    geometries = geometries_in;").unwrap();
+        if self.has_instances() {
+            writeln!(self.string, "    instances = instances_in;").unwrap();
+        }
+
+        for decl in &self.draw_shader_def.fields {
+            match &decl.kind {
+                DrawShaderFieldKind::Geometry {is_used_in_pixel_shader, ..} if is_used_in_pixel_shader.get() => {
+                    writeln!(self.string, "    varyings.{0} = geometries.{0};", DisplayDsIdent(decl.ident)).unwrap();
+                }
+                DrawShaderFieldKind::Instance {is_used_in_pixel_shader, ..} if is_used_in_pixel_shader.get() => {
+                    match decl.ty_expr.ty.borrow().as_ref().unwrap() {
+                        Ty::Mat4 => {
+                            for i in 0..4 {
+                                writeln!(self.string, "    varyings.{0}{1} = instances.{0}{1};", DisplayDsIdent(decl.ident), i).unwrap();
+                            }
+                        }
+                        Ty::Mat3 => {
+                            for i in 0..3 {
+                                writeln!(self.string, "    varyings.{0}{1} = instances.{0}{1};", DisplayDsIdent(decl.ident), i).unwrap();
+                            }
+                        }
+                        _ => {
+                            writeln!(self.string, "    varyings.{0} = instances.{0};", DisplayDsIdent(decl.ident)).unwrap();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let vertex_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(vertex))).unwrap();
+        writeln!(self.string, "    varyings.position = {}();", DisplayFnName(vertex_def.fn_ptr, vertex_def.ident)).unwrap();
+        writeln!(self.string, "    return varyings;").unwrap();
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn generate_pixel_main(&mut self) {
+        writeln!(self.string, "@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {{").unwrap();
+        writeln!(self.string, "    varyings = varyings_in;").unwrap();
+        let pixel_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(pixel))).unwrap();
+        writeln!(self.string, "    return {}();", DisplayFnName(pixel_def.fn_ptr, pixel_def.ident)).unwrap();
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn write_var_decl(&mut self, ident: &dyn fmt::Display, ty: &Ty) {
+        self.backend_writer.write_var_decl(self.string, "", false, false, ident, ty);
+    }
+
+    fn write_ty_lit(&mut self, ty_lit: TyLit) {
+        self.backend_writer.write_ty_lit(self.string, ty_lit);
+    }
+}
+
+struct WgslBackendWriter<'a> {
+    pub draw_shader_def: &'a DrawShaderDef,
+}
+
+impl<'a> WgslBackendWriter<'a> {
+    fn write_ty(&self, string: &mut String, ty: &Ty) {
+        match ty {
+            Ty::Array {elem_ty, len} => {
+                write!(string, "array<").unwrap();
+                self.write_ty(string, elem_ty);
+                write!(string, ", {}>", len).unwrap();
+            }
+            Ty::Struct(struct_ptr) => {
+                write!(string, "{}", struct_ptr).unwrap();
+            }
+            Ty::Enum(_) => {
+                write!(string, "u32").unwrap();
+            }
+            Ty::Texture2D => {
+                write!(string, "texture_2d<f32>").unwrap();
+            }
+            Ty::Texture3D => {
+                write!(string, "texture_3d<f32>").unwrap();
+            }
+            Ty::TextureCube => {
+                write!(string, "texture_cube<f32>").unwrap();
+            }
+            ty => self.write_ty_lit(string, ty.maybe_ty_lit().unwrap())
+        }
+    }
+}
+
+impl<'a> BackendWriter for WgslBackendWriter<'a> {
+
+    fn get_struct_cons_type(&self) -> StructConsType {
+        StructConsType::Paren
+    }
+
+    fn needs_mul_fn_for_matrix_multiplication(&self) -> bool {
+        false
+    }
+
+    fn needs_unpack_for_matrix_multiplication(&self) -> bool {
+        false
+    }
+
+    fn enum_is_float(&self) -> bool {
+        false
+    }
+
+    fn const_table_is_vec4(&self) -> bool {
+        true
+    }
+
+    fn use_cons_fn(&self, what: &str) -> bool {
+        matches!(what, "consfn_mat3_mat4" | "consfn_mat2_mat4" | "consfn_mat2_mat3")
+    }
+
+    fn write_var_decl(
+        &self,
+        string: &mut String,
+        sep: &'static str,
+        is_inout: bool,
+        _is_packed: bool,
+        ident: &dyn fmt::Display,
+        ty: &Ty,
+    ) -> bool {
+        match ty {
+            Ty::DrawShader(_) | Ty::ClosureDef {..} | Ty::ClosureDecl => return false,
+            Ty::Void => {
+                write!(string, "{}{}", sep, ident).unwrap();
+            }
+            _ if is_inout => {
+                write!(string, "{}{}: ptr<function, ", sep, ident).unwrap();
+                self.write_ty(string, ty);
+                write!(string, ">").unwrap();
+            }
+            _ => {
+                write!(string, "{}{}: ", sep, ident).unwrap();
+                self.write_ty(string, ty);
+            }
+        }
+        true
+    }
+
+    // everything a hidden arg would pass is at module scope
+    fn write_call_expr_hidden_args(&self, _string: &mut String, _hidden_args: &BTreeSet<HiddenArgKind >, _sep: &str) {
+    }
+
+    fn write_fn_def_hidden_params(&self, _string: &mut String, _hidden_args: &BTreeSet<HiddenArgKind >, _sep: &str) {
+    }
+
+    fn generate_live_value_prefix(&self, string: &mut String) {
+        write!(string, "live_uniforms.").unwrap();
+    }
+
+    fn generate_draw_shader_field_expr(&self, string: &mut String, field_ident: Ident, ty: &Ty) {
+        let field_def = self.draw_shader_def.find_field(field_ident).unwrap();
+
+        match &field_def.kind {
+            DrawShaderFieldKind::Geometry {is_used_in_pixel_shader, ..} => {
+                if is_used_in_pixel_shader.get() {
+                    write!(string, "varyings.").unwrap()
+                }
+                else {
+                    write!(string, "geometries.").unwrap()
+                }
+            }
+            DrawShaderFieldKind::Instance {is_used_in_pixel_shader, ..} => {
+                let prefix = if is_used_in_pixel_shader.get() {
+                    "varyings"
+                }
+                else {
+                    "instances"
+                };
+                match ty {
+                    Ty::Mat4 | Ty::Mat3 => {
+                        let size = if let Ty::Mat4 = ty {4} else {3};
+                        write!(string, "mat{0}x{0}<f32>(", size).unwrap();
+                        for i in 0..size {
+                            for j in 0..size {
+                                if i != 0 || j != 0 {
+                                    write!(string, ",").unwrap();
+                                }
+                                write!(string, "{}.{}{}.{}", prefix, DisplayDsIdent(field_ident), j, ["x", "y", "z", "w"][i]).unwrap();
+                            }
+                        }
+                        write!(string, ")").unwrap();
+                        return
+                    },
+                    Ty::Mat2 => {
+                        write!(string, "mat2x2<f32>({0}.{1}.x, {0}.{1}.y, {0}.{1}.z, {0}.{1}.w)", prefix, DisplayDsIdent(field_ident)).unwrap();
+                        return
+                    },
+                    _ => {
+                        write!(string, "{}.", prefix).unwrap();
+                    }
+                }
+            }
+            DrawShaderFieldKind::Varying {..} => {
+                write!(string, "varyings.").unwrap()
+            }
+            DrawShaderFieldKind::Texture {..} => {}
+            DrawShaderFieldKind::Uniform {block_ident, ..} => {
+                write!(string, "uniforms_{}.", block_ident).unwrap()
+            }
+        }
+        write!(string, "{}", &DisplayDsIdent(field_ident)).unwrap();
+    }
+
+    fn write_ty_lit(&self, string: &mut String, ty_lit: TyLit) {
+        write!(
+            string,
+            "{}",
+            match ty_lit {
+                TyLit::Bool => "bool",
+                TyLit::Int => "i32",
+                TyLit::Float => "f32",
+                TyLit::Bvec2 => "vec2<bool>",
+                TyLit::Bvec3 => "vec3<bool>",
+                TyLit::Bvec4 => "vec4<bool>",
+                TyLit::Ivec2 => "vec2<i32>",
+                TyLit::Ivec3 => "vec3<i32>",
+                TyLit::Ivec4 => "vec4<i32>",
+                TyLit::Vec2 => "vec2<f32>",
+                TyLit::Vec3 => "vec3<f32>",
+                TyLit::Vec4 => "vec4<f32>",
+                TyLit::Mat2 => "mat2x2<f32>",
+                TyLit::Mat3 => "mat3x3<f32>",
+                TyLit::Mat4 => "mat4x4<f32>",
+                TyLit::Texture2D => "texture_2d<f32>",
+                TyLit::Texture3D => "texture_3d<f32>",
+                TyLit::TextureCube => "texture_cube<f32>",
+            }
+        )
+            .unwrap();
+    }
+
+    fn write_builtin_call_ident(&self, string: &mut String, ident: Ident, arg_exprs: &[Expr]) {
+        match ident {
+            Ident(live_id!(atan)) if arg_exprs.len() == 2 => {
+                write!(string, "atan2").unwrap();
+            }
+            Ident(live_id!(mod)) => {
+                let ty = match arg_exprs[0].ty.borrow().as_ref().unwrap() {
+                    Ty::Vec2 => "vec2",
+                    Ty::Vec3 => "vec3",
+                    Ty::Vec4 => "vec4",
+                    _ => "f32"
+                };
+                write!(string, "mod_{}", ty).unwrap();
+            }
+            Ident(live_id!(dFdx)) => {
+                write!(string, "dpdx").unwrap();
+            }
+            Ident(live_id!(dFdy)) => {
+                write!(string, "dpdy").unwrap();
+            }
+            Ident(live_id!(inversesqrt)) => {
+                write!(string, "inverseSqrt").unwrap();
+            }
+            Ident(live_id!(faceforward)) => {
+                write!(string, "faceForward").unwrap();
+            }
+            Ident(live_id!(not)) => {
+                write!(string, "!").unwrap();
+            }
+            _ => {
+                write!(string, "{}", ident).unwrap()
+            }
+        }
+    }
+
+    fn write_fn_def_start(&self, string: &mut String, ident: &dyn fmt::Display, _return_ty: &Ty) {
+        write!(string, "fn {}", ident).unwrap();
+    }
+
+    fn write_fn_def_return_ty(&self, string: &mut String, return_ty: &Ty) {
+        if *return_ty != Ty::Void {
+            write!(string, " -> ").unwrap();
+            self.write_ty(string, return_ty);
+        }
+    }
+
+    fn write_local_var_decl(&self, string: &mut String, ident: &dyn fmt::Display, ty: &Ty) {
+        write!(string, "var ").unwrap();
+        self.write_var_decl(string, "", false, false, ident, ty);
+    }
+
+    fn inout_is_pointer(&self) -> bool {
+        true
+    }
+
+    fn cond_expr_is_select(&self) -> bool {
+        true
+    }
+
+    fn assign_is_statement(&self) -> bool {
+        true
+    }
+
+    fn builtin_needs_splat_args(&self) -> bool {
+        true
+    }
+
+    fn params_are_immutable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,1196 @@
+use {
+    std::{
+        collections::{BTreeMap, HashMap},
+        rc::Rc,
+    },
+    crate::{
+        makepad_live_id::{LiveId, live_id},
+        shader_ast::*,
+        shader_registry::ShaderRegistry,
+        swizzle::Swizzle,
+    }
+};
+
+// The interpreter runs a draw shader on the CPU for a 2x2 quad of invocations at once.
+// Lanes are laid out as (0,0), (1,0), (0,1), (1,1) so dFdx/dFdy can be computed
+// from neighbouring lanes, and divergent control flow is handled with lane masks
+// the same way a GPU does it.
+
+pub const LANES: usize = 4;
+pub type LaneMask = [bool; LANES];
+pub const ALL_LANES: LaneMask = [true; LANES];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterpNum {
+    pub ty_lit: TyLit,
+    pub lanes: [[f32; 16]; LANES],
+}
+
+#[derive(Clone, Debug)]
+pub enum InterpValue {
+    Void,
+    Num(InterpNum),
+    Array(Vec<InterpValue>),
+    Struct(Vec<InterpValue>),
+    Texture(usize),
+    DrawShader,
+    Closure(Rc<InterpClosure>),
+}
+
+#[derive(Debug)]
+pub struct InterpClosure {
+    pub fn_ptr: FnPtr,
+    pub closure_def_index: ClosureDefIndex,
+    pub closed_over: Vec<((Ident, ScopeSymShadow), InterpValue)>,
+}
+
+pub trait InterpSampler {
+    fn sample_2d(&self, texture: usize, pos: [[f32; 2]; LANES], is_render_target: bool) -> [[f32; 4]; LANES];
+    fn sample_3d(&self, texture: usize, pos: [[f32; 3]; LANES]) -> [[f32; 4]; LANES];
+    fn sample_cube(&self, texture: usize, dir: [[f32; 3]; LANES]) -> [[f32; 4]; LANES];
+}
+
+// for shaders that don't sample, every texture reads as transparent black
+pub struct NoSampler;
+
+impl InterpSampler for NoSampler {
+    fn sample_2d(&self, _texture: usize, _pos: [[f32; 2]; LANES], _is_render_target: bool) -> [[f32; 4]; LANES] {
+        [[0.0; 4]; LANES]
+    }
+    
+    fn sample_3d(&self, _texture: usize, _pos: [[f32; 3]; LANES]) -> [[f32; 4]; LANES] {
+        [[0.0; 4]; LANES]
+    }
+    
+    fn sample_cube(&self, _texture: usize, _dir: [[f32; 3]; LANES]) -> [[f32; 4]; LANES] {
+        [[0.0; 4]; LANES]
+    }
+}
+
+pub fn ty_lit_slots(ty_lit: TyLit) -> usize {
+    match ty_lit {
+        TyLit::Bool | TyLit::Int | TyLit::Float | TyLit::Texture2D | TyLit::Texture3D | TyLit::TextureCube => 1,
+        TyLit::Bvec2 | TyLit::Ivec2 | TyLit::Vec2 => 2,
+        TyLit::Bvec3 | TyLit::Ivec3 | TyLit::Vec3 => 3,
+        TyLit::Bvec4 | TyLit::Ivec4 | TyLit::Vec4 | TyLit::Mat2 => 4,
+        TyLit::Mat3 => 9,
+        TyLit::Mat4 => 16,
+    }
+}
+
+fn mat_dim(ty_lit: TyLit) -> usize {
+    match ty_lit {
+        TyLit::Mat2 => 2,
+        TyLit::Mat3 => 3,
+        TyLit::Mat4 => 4,
+        _ => 0
+    }
+}
+
+fn ty_lit_of(ty: &Ty) -> TyLit {
+    match ty {
+        Ty::Enum(_) => TyLit::Int,
+        _ => ty.maybe_ty_lit().unwrap_or(TyLit::Float)
+    }
+}
+
+fn expr_ty_lit(expr: &Expr) -> TyLit {
+    expr.ty.borrow().as_ref().map( | ty | ty_lit_of(ty)).unwrap_or(TyLit::Float)
+}
+
+fn any_lane(mask: &LaneMask) -> bool {
+    mask.iter().any( | v | *v)
+}
+
+fn to_scalar_kind(ty_lit: TyLit, v: f32) -> f32 {
+    match ty_lit {
+        TyLit::Bool | TyLit::Bvec2 | TyLit::Bvec3 | TyLit::Bvec4 => if v != 0.0 {1.0} else {0.0},
+        TyLit::Int | TyLit::Ivec2 | TyLit::Ivec3 | TyLit::Ivec4 => v.trunc(),
+        _ => v
+    }
+}
+
+fn is_int(ty_lit: TyLit) -> bool {
+    match ty_lit {
+        TyLit::Int | TyLit::Ivec2 | TyLit::Ivec3 | TyLit::Ivec4 => true,
+        _ => false
+    }
+}
+
+impl InterpNum {
+    pub fn zero(ty_lit: TyLit) -> Self {
+        Self {ty_lit, lanes: [[0.0; 16]; LANES]}
+    }
+
+    pub fn splat(ty_lit: TyLit, values: &[f32]) -> Self {
+        let mut num = Self::zero(ty_lit);
+        for lane in &mut num.lanes {
+            for (i, v) in values.iter().take(16).enumerate() {
+                lane[i] = *v;
+            }
+        }
+        num
+    }
+
+    pub fn from_fn(ty_lit: TyLit, f: impl Fn(usize, usize) -> f32) -> Self {
+        let mut num = Self::zero(ty_lit);
+        let slots = ty_lit_slots(ty_lit);
+        for lane in 0..LANES {
+            for comp in 0..slots {
+                num.lanes[lane][comp] = f(lane, comp);
+            }
+        }
+        num
+    }
+
+    pub fn slots(&self) -> usize {
+        ty_lit_slots(self.ty_lit)
+    }
+
+    // scalars broadcast to every component
+    pub fn get(&self, lane: usize, comp: usize) -> f32 {
+        if self.slots() == 1 {
+            self.lanes[lane][0]
+        }
+        else {
+            self.lanes[lane][comp]
+        }
+    }
+
+    pub fn is_true(&self, lane: usize) -> bool {
+        self.lanes[lane][0] != 0.0
+    }
+
+    pub fn lane_vec4(&self, lane: usize) -> [f32; 4] {
+        let l = &self.lanes[lane];
+        [l[0], l[1], l[2], l[3]]
+    }
+
+    fn dot(&self, other: &InterpNum, lane: usize) -> f32 {
+        let mut sum = 0.0;
+        for comp in 0..self.slots() {
+            sum += self.get(lane, comp) * other.get(lane, comp);
+        }
+        sum
+    }
+}
+
+impl InterpValue {
+    pub fn zero(ty: &Ty, shader_registry: &ShaderRegistry) -> Self {
+        match ty {
+            Ty::Void | Ty::ClosureDecl | Ty::ClosureDef(_) => InterpValue::Void,
+            Ty::DrawShader(_) => InterpValue::DrawShader,
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => InterpValue::Texture(0),
+            Ty::Array {elem_ty, len} => InterpValue::Array(
+                (0..*len).map( | _ | Self::zero(elem_ty, shader_registry)).collect()
+            ),
+            Ty::Struct(struct_ptr) => {
+                let struct_def = shader_registry.structs.get(struct_ptr).unwrap();
+                InterpValue::Struct(struct_def.fields.iter().map( | field | {
+                    Self::zero(field.ty_expr.ty.borrow().as_ref().unwrap(), shader_registry)
+                }).collect())
+            }
+            _ => InterpValue::Num(InterpNum::zero(ty_lit_of(ty)))
+        }
+    }
+
+    // builds a value out of packed f32 slots, like the uniform and instance buffers
+    pub fn from_slots(ty: &Ty, slots: &[f32]) -> Self {
+        match ty {
+            Ty::Array {elem_ty, len} => {
+                let elem_slots = elem_ty.slots();
+                InterpValue::Array((0..*len).map( | i | {
+                    Self::from_slots(elem_ty, slots.get(i * elem_slots..).unwrap_or(&[]))
+                }).collect())
+            }
+            _ => {
+                let ty_lit = ty_lit_of(ty);
+                let n = ty_lit_slots(ty_lit).min(slots.len());
+                InterpValue::Num(InterpNum::splat(ty_lit, &slots[0..n]))
+            }
+        }
+    }
+
+    pub fn from_val(val: &Val) -> Self {
+        InterpValue::Num(match val {
+            Val::Bool(v) => InterpNum::splat(TyLit::Bool, &[if *v {1.0} else {0.0}]),
+            Val::Int(v) => InterpNum::splat(TyLit::Int, &[*v as f32]),
+            Val::Float(v) => InterpNum::splat(TyLit::Float, &[*v]),
+            Val::Vec4(v) => InterpNum::splat(TyLit::Vec4, &[v.x, v.y, v.z, v.w]),
+        })
+    }
+
+    pub fn num(&self) -> InterpNum {
+        match self {
+            InterpValue::Num(num) => *num,
+            _ => InterpNum::zero(TyLit::Float)
+        }
+    }
+
+    // writes the lanes in mask from other into self
+    pub fn merge(&mut self, other: InterpValue, mask: &LaneMask) {
+        match (self, other) {
+            (InterpValue::Num(a), InterpValue::Num(b)) if a.ty_lit == b.ty_lit => {
+                for lane in 0..LANES {
+                    if mask[lane] {
+                        a.lanes[lane] = b.lanes[lane];
+                    }
+                }
+            }
+            (InterpValue::Array(a), InterpValue::Array(b)) | (InterpValue::Struct(a), InterpValue::Struct(b)) if a.len() == b.len() => {
+                for (a, b) in a.iter_mut().zip(b.into_iter()) {
+                    a.merge(b, mask);
+                }
+            }
+            (this, other) => *this = other
+        }
+    }
+}
+
+struct Frame {
+    fn_ptr: FnPtr,
+    vars: HashMap<(Ident, ScopeSymShadow), InterpValue>,
+    // for loop counters are not shadow tracked by the analyser
+    loop_vars: Vec<(Ident, InterpValue)>,
+    ret: InterpValue,
+    returned: LaneMask,
+    brk: LaneMask,
+    cont: LaneMask,
+}
+
+pub struct Interpreter<'a> {
+    pub shader_registry: &'a ShaderRegistry,
+    pub draw_shader_def: &'a DrawShaderDef,
+    pub const_table: Option<&'a DrawShaderConstTable>,
+    pub sampler: &'a dyn InterpSampler,
+    pub fields: Vec<InterpValue>,
+    pub live_values: BTreeMap<ValuePtr, InterpValue>,
+    // the first thing that went wrong in the last call_method
+    pub error: Option<String>,
+    swizzles: HashMap<Ident, Vec<usize >>,
+    mask: LaneMask,
+    frames: Vec<Frame>,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(shader_registry: &'a ShaderRegistry, draw_shader_def: &'a DrawShaderDef, sampler: &'a dyn InterpSampler) -> Self {
+        let mut texture_slot = 0;
+        let fields = draw_shader_def.fields.iter().map( | field | {
+            if let DrawShaderFieldKind::Texture {..} = field.kind {
+                texture_slot += 1;
+                return InterpValue::Texture(texture_slot - 1)
+            }
+            InterpValue::zero(field.ty_expr.ty.borrow().as_ref().unwrap(), shader_registry)
+        }).collect();
+        let live_values = draw_shader_def.all_live_refs.borrow().iter().map( | (value_ptr, ty) | {
+            (*value_ptr, InterpValue::zero(ty, shader_registry))
+        }).collect();
+        Self {
+            shader_registry,
+            draw_shader_def,
+            const_table: None,
+            sampler,
+            fields,
+            live_values,
+            error: None,
+            swizzles: HashMap::new(),
+            mask: ALL_LANES,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn field_index(&self, ident: Ident) -> Option<usize> {
+        self.draw_shader_def.fields.iter().position( | field | field.ident == ident)
+    }
+
+    // sets a field to the same value in every lane, values are its f32 slots
+    pub fn set_field(&mut self, ident: Ident, values: &[f32]) -> bool {
+        self.set_field_with(ident, | _, comp | values.get(comp).cloned().unwrap_or(0.0))
+    }
+
+    // sets a field per lane and component, so neighbouring lanes can differ like
+    // neighbouring pixels do, which is what dFdx and dFdy look at
+    pub fn set_field_with(&mut self, ident: Ident, f: impl Fn(usize, usize) -> f32) -> bool {
+        let index = if let Some(index) = self.field_index(ident) {index} else {return false};
+        if let InterpValue::Num(num) = &self.fields[index] {
+            self.fields[index] = InterpValue::Num(InterpNum::from_fn(num.ty_lit, f));
+            return true
+        }
+        false
+    }
+
+    pub fn field(&self, ident: Ident) -> Option<&InterpValue> {
+        self.fields.get(self.field_index(ident) ?)
+    }
+
+    // runs the pixel shader on all lanes and returns the color each one wrote
+    pub fn eval_pixel(&mut self) -> Result<[[f32; 4]; LANES], String> {
+        let color = match self.call_method(Ident(live_id!(pixel)), ALL_LANES) {
+            Some(color) => color.num(),
+            None => return Err(self.error.clone().unwrap_or_else( || "no fn pixel".to_string()))
+        };
+        Ok(std::array::from_fn( | lane | color.lane_vec4(lane)))
+    }
+
+    // calls a method on the draw shader such as vertex or pixel, returns None when there
+    // is no such method or evaluating it failed, in which case error says why
+    pub fn call_method(&mut self, ident: Ident, mask: LaneMask) -> Option<InterpValue> {
+        self.error = None;
+        let fn_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, ident) ?;
+        self.mask = mask;
+        self.frames.clear();
+        let args = fn_def.params.iter().map( | param | {
+            InterpValue::zero(param.ty_expr.ty.borrow().as_ref().unwrap(), self.shader_registry)
+        }).collect();
+        let value = self.call_fn(fn_def, args).0;
+        if self.error.is_some() {
+            return None
+        }
+        Some(value)
+    }
+    
+    // keeps the first error and carries on with zero, call_method reports it at the end
+    fn num_or_error(&mut self, result: Result<InterpNum, String>, ty_lit: TyLit) -> InterpNum {
+        match result {
+            Ok(num) => num,
+            Err(error) => {
+                self.error.get_or_insert(error);
+                InterpNum::zero(ty_lit)
+            }
+        }
+    }
+
+    fn active(&self) -> LaneMask {
+        let mut mask = self.mask;
+        if let Some(frame) = self.frames.last() {
+            for lane in 0..LANES {
+                mask[lane] = mask[lane] && !frame.returned[lane] && !frame.brk[lane] && !frame.cont[lane];
+            }
+        }
+        mask
+    }
+
+    fn frame(&mut self) -> &mut Frame {
+        self.frames.last_mut().unwrap()
+    }
+
+    fn call_fn(&mut self, fn_def: &FnDef, args: Vec<InterpValue>) -> (InterpValue, Vec<Option<InterpValue >>) {
+        let mut frame = Frame {
+            fn_ptr: fn_def.fn_ptr,
+            vars: HashMap::new(),
+            loop_vars: Vec::new(),
+            ret: InterpValue::zero(fn_def.return_ty.borrow().as_ref().unwrap_or(&Ty::Void), self.shader_registry),
+            returned: [false; LANES],
+            brk: [false; LANES],
+            cont: [false; LANES],
+        };
+        for (param, arg) in fn_def.params.iter().zip(args.into_iter()) {
+            if let Some(shadow) = param.shadow.get() {
+                frame.vars.insert((param.ident, shadow), arg);
+            }
+        }
+        let saved_mask = self.mask;
+        self.mask = self.active();
+        self.frames.push(frame);
+        self.exec_block(&fn_def.block);
+        let frame = self.frames.pop().unwrap();
+        self.mask = saved_mask;
+        let outs = fn_def.params.iter().map( | param | {
+            if !param.is_inout {
+                return None
+            }
+            param.shadow.get().and_then( | shadow | frame.vars.get(&(param.ident, shadow)).cloned())
+        }).collect();
+        (frame.ret, outs)
+    }
+
+    fn call_fn_with_exprs(&mut self, fn_def: &FnDef, arg_exprs: &[Expr]) -> InterpValue {
+        let args = arg_exprs.iter().map( | arg_expr | self.eval_expr(arg_expr)).collect();
+        let (ret, outs) = self.call_fn(fn_def, args);
+        for (arg_expr, out) in arg_exprs.iter().zip(outs.into_iter()) {
+            if let Some(out) = out {
+                self.assign(arg_expr, out);
+            }
+        }
+        ret
+    }
+
+    fn call_closure(&mut self, closure: &InterpClosure, arg_exprs: &[Expr]) -> InterpValue {
+        let call_def = self.shader_registry.all_fns.get(&closure.fn_ptr).unwrap();
+        let closure_def = &call_def.closure_defs[closure.closure_def_index.0];
+        let args: Vec<InterpValue> = arg_exprs.iter().map( | arg_expr | self.eval_expr(arg_expr)).collect();
+
+        let mut frame = Frame {
+            fn_ptr: closure.fn_ptr,
+            vars: closure.closed_over.iter().cloned().collect(),
+            loop_vars: Vec::new(),
+            ret: InterpValue::Void,
+            returned: [false; LANES],
+            brk: [false; LANES],
+            cont: [false; LANES],
+        };
+        for (param, arg) in closure_def.params.iter().zip(args.into_iter()) {
+            if let Some(shadow) = param.shadow.get() {
+                frame.vars.insert((param.ident, shadow), arg);
+            }
+        }
+        let saved_mask = self.mask;
+        self.mask = self.active();
+        self.frames.push(frame);
+        match &closure_def.kind {
+            ClosureDefKind::Expr(expr) => {
+                let value = self.eval_expr(expr);
+                self.frame().ret = value;
+            }
+            ClosureDefKind::Block(block) => {
+                self.exec_block(block);
+            }
+        }
+        let frame = self.frames.pop().unwrap();
+        self.mask = saved_mask;
+        frame.ret
+    }
+
+    fn exec_block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            if !any_lane(&self.active()) {
+                return
+            }
+            self.exec_stmt(stmt);
+        }
+    }
+
+    fn exec_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Break {..} => {
+                let active = self.active();
+                let frame = self.frame();
+                for lane in 0..LANES {
+                    frame.brk[lane] |= active[lane];
+                }
+            }
+            Stmt::Continue {..} => {
+                let active = self.active();
+                let frame = self.frame();
+                for lane in 0..LANES {
+                    frame.cont[lane] |= active[lane];
+                }
+            }
+            Stmt::For {ident, from_expr, to_expr, step_expr, block, ..} => {
+                self.exec_for_stmt(*ident, from_expr, to_expr, step_expr, block)
+            }
+            Stmt::If {expr, block_if_true, block_if_false, ..} => {
+                let cond = self.eval_expr(expr).num();
+                let active = self.active();
+                let saved_mask = self.mask;
+                let mut if_true = [false; LANES];
+                let mut if_false = [false; LANES];
+                for lane in 0..LANES {
+                    if_true[lane] = active[lane] && cond.is_true(lane);
+                    if_false[lane] = active[lane] && !cond.is_true(lane);
+                }
+                if any_lane(&if_true) {
+                    self.mask = if_true;
+                    self.exec_block(block_if_true);
+                }
+                if let Some(block_if_false) = block_if_false {
+                    if any_lane(&if_false) {
+                        self.mask = if_false;
+                        self.exec_block(block_if_false);
+                    }
+                }
+                self.mask = saved_mask;
+            }
+            Stmt::Match {expr, matches, ..} => {
+                let value = self.eval_expr(expr).num();
+                let saved_mask = self.mask;
+                let mut remaining = self.active();
+                for match_item in matches {
+                    let enum_value = match_item.enum_value.get().unwrap() as f32;
+                    let mut hit = [false; LANES];
+                    for lane in 0..LANES {
+                        hit[lane] = remaining[lane] && (value.lanes[lane][0] - enum_value).abs() < 0.5;
+                        remaining[lane] &= !hit[lane];
+                    }
+                    if any_lane(&hit) {
+                        self.mask = hit;
+                        self.exec_block(&match_item.block);
+                    }
+                }
+                self.mask = saved_mask;
+            }
+            Stmt::Let {ty, shadow, ident, expr, ..} => {
+                let value = if let Some(expr) = expr {
+                    self.eval_expr(expr)
+                }
+                else {
+                    InterpValue::zero(ty.borrow().as_ref().unwrap(), self.shader_registry)
+                };
+                let shadow = shadow.get().unwrap();
+                self.frame().vars.insert((*ident, shadow), value);
+            }
+            Stmt::Return {expr, ..} => {
+                let active = self.active();
+                if let Some(expr) = expr {
+                    let value = self.eval_expr(expr);
+                    self.frame().ret.merge(value, &active);
+                }
+                let frame = self.frame();
+                for lane in 0..LANES {
+                    frame.returned[lane] |= active[lane];
+                }
+            }
+            Stmt::Block {block, ..} => self.exec_block(block),
+            Stmt::Expr {expr, ..} => {
+                self.eval_expr(expr);
+            }
+        }
+    }
+
+    fn exec_for_stmt(&mut self, ident: Ident, from_expr: &Expr, to_expr: &Expr, step_expr: &Option<Expr>, block: &Block) {
+        fn const_int(expr: &Expr) -> i32 {
+            expr.const_val.borrow().as_ref().and_then( | v | v.as_ref()).and_then( | v | v.to_int()).unwrap_or(0)
+        }
+        let from = const_int(from_expr);
+        let to = const_int(to_expr);
+        let step = if let Some(step_expr) = step_expr {
+            const_int(step_expr)
+        } else if from < to {1} else {-1};
+        if step == 0 {
+            return
+        }
+        // same iteration semantics as the generated glsl
+        let saved_mask = self.mask;
+        self.mask = self.active();
+        let (saved_brk, saved_cont) = {
+            let frame = self.frame();
+            let saved = (frame.brk, frame.cont);
+            frame.brk = [false; LANES];
+            frame.cont = [false; LANES];
+            frame.loop_vars.push((ident, InterpValue::Void));
+            saved
+        };
+        let mut i = if from <= to {from} else {from - 1};
+        while if from <= to {i < to} else {i >= to} {
+            self.frame().cont = [false; LANES];
+            if !any_lane(&self.active()) {
+                break;
+            }
+            self.frame().loop_vars.last_mut().unwrap().1 = InterpValue::Num(InterpNum::splat(TyLit::Int, &[i as f32]));
+            self.exec_block(block);
+            i += step;
+        }
+        let frame = self.frame();
+        frame.loop_vars.pop();
+        frame.brk = saved_brk;
+        frame.cont = saved_cont;
+        self.mask = saved_mask;
+    }
+
+    fn var(&self, ident: Ident, shadow: ScopeSymShadow) -> InterpValue {
+        let frame = self.frames.last().unwrap();
+        if let Some(value) = frame.vars.get(&(ident, shadow)) {
+            return value.clone()
+        }
+        if let Some((_, value)) = frame.loop_vars.iter().rev().find( | (loop_ident, _) | *loop_ident == ident) {
+            return value.clone()
+        }
+        InterpValue::Void
+    }
+
+    fn swizzle(&mut self, ident: Ident) -> Vec<usize> {
+        self.swizzles.entry(ident).or_insert_with( || {
+            Swizzle::parse(ident).map( | swizzle | swizzle.iter().cloned().collect()).unwrap_or(Vec::new())
+        }).clone()
+    }
+
+    fn assign(&mut self, expr: &Expr, value: InterpValue) {
+        let active = self.active();
+        match &expr.kind {
+            ExprKind::Var {kind, ..} => match kind.get() {
+                Some(VarKind::Local {ident, shadow}) | Some(VarKind::MutLocal {ident, shadow}) => {
+                    let frame = self.frame();
+                    if let Some(var) = frame.vars.get_mut(&(ident, shadow)) {
+                        var.merge(value, &active);
+                    }
+                    else if let Some((_, var)) = frame.loop_vars.iter_mut().rev().find( | (loop_ident, _) | *loop_ident == ident) {
+                        var.merge(value, &active);
+                    }
+                    else {
+                        frame.vars.insert((ident, shadow), value);
+                    }
+                }
+                _ => ()
+            },
+            ExprKind::Field {expr: inner, field_ident, ..} => {
+                let inner_ty = inner.ty.borrow().clone();
+                match inner_ty {
+                    Some(Ty::DrawShader(_)) => {
+                        if let Some(index) = self.field_index(*field_ident) {
+                            self.fields[index].merge(value, &active);
+                        }
+                    }
+                    Some(Ty::Struct(struct_ptr)) => {
+                        let struct_def = self.shader_registry.structs.get(&struct_ptr).unwrap();
+                        let index = struct_def.fields.iter().position( | field | field.ident == *field_ident).unwrap();
+                        let mut base = self.eval_expr(inner);
+                        if let InterpValue::Struct(fields) = &mut base {
+                            fields[index] = value;
+                        }
+                        self.assign(inner, base);
+                    }
+                    _ => {
+                        let swizzle = self.swizzle(*field_ident);
+                        let mut base = self.eval_expr(inner).num();
+                        let value = value.num();
+                        for lane in 0..LANES {
+                            for (comp, index) in swizzle.iter().enumerate() {
+                                base.lanes[lane][*index] = value.get(lane, comp);
+                            }
+                        }
+                        self.assign(inner, InterpValue::Num(base));
+                    }
+                }
+            }
+            ExprKind::Index {expr: inner, index_expr, ..} => {
+                let index = self.eval_expr(index_expr).num();
+                let mut base = self.eval_expr(inner);
+                match &mut base {
+                    InterpValue::Array(elems) => {
+                        for lane in 0..LANES {
+                            let i = (index.lanes[lane][0].max(0.0) as usize).min(elems.len() - 1);
+                            let mut lane_mask = [false; LANES];
+                            lane_mask[lane] = true;
+                            elems[i].merge(value.clone(), &lane_mask);
+                        }
+                    }
+                    InterpValue::Num(num) => {
+                        let value = value.num();
+                        let dim = mat_dim(num.ty_lit);
+                        for lane in 0..LANES {
+                            let i = index.lanes[lane][0].max(0.0) as usize;
+                            if dim > 0 {
+                                let i = i.min(dim - 1);
+                                for comp in 0..dim {
+                                    num.lanes[lane][i * dim + comp] = value.get(lane, comp);
+                                }
+                            }
+                            else {
+                                num.lanes[lane][i.min(num.slots() - 1)] = value.get(lane, 0);
+                            }
+                        }
+                    }
+                    _ => ()
+                }
+                self.assign(inner, base);
+            }
+            _ => ()
+        }
+    }
+
+    fn eval_const(&self, expr: &Expr) -> Option<InterpValue> {
+        let const_val = expr.const_val.borrow();
+        let val = const_val.as_ref()?.as_ref()?;
+        // prefer the const table so live edited values show up without a recompile
+        if let (Some(index), Some(const_table), Some(frame)) = (expr.const_index.get(), self.const_table, self.frames.last()) {
+            if let Some(offset) = const_table.offsets.get(&frame.fn_ptr) {
+                let table = &const_table.table;
+                match val {
+                    Val::Float(_) if offset + index < table.len() => {
+                        return Some(InterpValue::Num(InterpNum::splat(TyLit::Float, &table[offset + index..offset + index + 1])))
+                    }
+                    Val::Vec4(_) if offset + index + 4 <= table.len() => {
+                        return Some(InterpValue::Num(InterpNum::splat(TyLit::Vec4, &table[offset + index..offset + index + 4])))
+                    }
+                    _ => ()
+                }
+            }
+        }
+        Some(InterpValue::from_val(val))
+    }
+
+    pub fn eval_expr(&mut self, expr: &Expr) -> InterpValue {
+        if let Some(value) = self.eval_const(expr) {
+            return value
+        }
+        match &expr.kind {
+            ExprKind::Cond {expr: cond_expr, expr_if_true, expr_if_false, ..} => {
+                let cond = self.eval_expr(cond_expr).num();
+                let mut value = self.eval_expr(expr_if_false);
+                let if_true = self.eval_expr(expr_if_true);
+                let mut mask = [false; LANES];
+                for lane in 0..LANES {
+                    mask[lane] = cond.is_true(lane);
+                }
+                value.merge(if_true, &mask);
+                value
+            }
+            ExprKind::Bin {op, left_expr, right_expr, ..} => {
+                let op = match op {
+                    BinOp::Assign => {
+                        let value = self.eval_expr(right_expr);
+                        self.assign(left_expr, value);
+                        return InterpValue::Void
+                    }
+                    BinOp::AddAssign => BinOp::Add,
+                    BinOp::SubAssign => BinOp::Sub,
+                    BinOp::MulAssign => BinOp::Mul,
+                    BinOp::DivAssign => BinOp::Div,
+                    op => {
+                        let left = self.eval_expr(left_expr).num();
+                        let right = self.eval_expr(right_expr).num();
+                        let ty_lit = expr_ty_lit(expr);
+                        let active = self.active();
+                        return InterpValue::Num(self.num_or_error(bin_op(*op, &left, &right, ty_lit, &active), ty_lit))
+                    }
+                };
+                let left = self.eval_expr(left_expr).num();
+                let right = self.eval_expr(right_expr).num();
+                let active = self.active();
+                let value = self.num_or_error(bin_op(op, &left, &right, left.ty_lit, &active), left.ty_lit);
+                self.assign(left_expr, InterpValue::Num(value));
+                InterpValue::Void
+            }
+            ExprKind::Un {op, expr: inner, ..} => {
+                let value = self.eval_expr(inner).num();
+                InterpValue::Num(match op {
+                    UnOp::Not => InterpNum::from_fn(value.ty_lit, | lane, comp | if value.lanes[lane][comp] != 0.0 {0.0} else {1.0}),
+                    UnOp::Neg => InterpNum::from_fn(value.ty_lit, | lane, comp | -value.lanes[lane][comp]),
+                })
+            }
+            ExprKind::Field {expr: inner, field_ident, ..} => {
+                let inner_ty = inner.ty.borrow().clone();
+                match inner_ty {
+                    Some(Ty::DrawShader(_)) => {
+                        if let Some(index) = self.field_index(*field_ident) {
+                            return self.fields[index].clone()
+                        }
+                        InterpValue::Void
+                    }
+                    Some(Ty::Struct(struct_ptr)) => {
+                        let struct_def = self.shader_registry.structs.get(&struct_ptr).unwrap();
+                        let index = struct_def.fields.iter().position( | field | field.ident == *field_ident).unwrap();
+                        match self.eval_expr(inner) {
+                            InterpValue::Struct(mut fields) => fields.swap_remove(index),
+                            _ => InterpValue::Void
+                        }
+                    }
+                    _ => {
+                        let swizzle = self.swizzle(*field_ident);
+                        let base = self.eval_expr(inner).num();
+                        InterpValue::Num(InterpNum::from_fn(expr_ty_lit(expr), | lane, comp | {
+                            base.lanes[lane][swizzle[comp]]
+                        }))
+                    }
+                }
+            }
+            ExprKind::Index {expr: inner, index_expr, ..} => {
+                let index = self.eval_expr(index_expr).num();
+                match self.eval_expr(inner) {
+                    InterpValue::Array(elems) => {
+                        if elems.is_empty() {
+                            return InterpValue::Void
+                        }
+                        let pick = | lane: usize | (index.lanes[lane][0].max(0.0) as usize).min(elems.len() - 1);
+                        let mut value = elems[pick(0)].clone();
+                        for lane in 1..LANES {
+                            let mut lane_mask = [false; LANES];
+                            lane_mask[lane] = true;
+                            value.merge(elems[pick(lane)].clone(), &lane_mask);
+                        }
+                        value
+                    }
+                    InterpValue::Num(num) => {
+                        let dim = mat_dim(num.ty_lit);
+                        InterpValue::Num(InterpNum::from_fn(expr_ty_lit(expr), | lane, comp | {
+                            let i = index.lanes[lane][0].max(0.0) as usize;
+                            if dim > 0 {
+                                num.lanes[lane][i.min(dim - 1) * dim + comp]
+                            }
+                            else {
+                                num.lanes[lane][i.min(num.slots() - 1)]
+                            }
+                        }))
+                    }
+                    _ => InterpValue::Void
+                }
+            }
+            ExprKind::MethodCall {ident, arg_exprs, ..} => {
+                let arg_ty = arg_exprs[0].ty.borrow().clone();
+                let fn_def = match arg_ty {
+                    Some(Ty::DrawShader(shader_ptr)) => self.shader_registry.draw_shader_method_decl_from_ident(
+                        self.shader_registry.draw_shader_defs.get(&shader_ptr).unwrap(),
+                        *ident
+                    ),
+                    Some(Ty::Struct(struct_ptr)) => self.shader_registry.struct_method_decl_from_ident(
+                        self.shader_registry.structs.get(&struct_ptr).unwrap(),
+                        *ident
+                    ),
+                    _ => None
+                };
+                if let Some(fn_def) = fn_def {
+                    return self.call_fn_with_exprs(fn_def, arg_exprs)
+                }
+                InterpValue::Void
+            }
+            ExprKind::PlainCall {fn_ptr, arg_exprs, param_index, ..} => {
+                if let Some(param_index) = param_index.get() {
+                    // calling a closure that got passed in as an argument
+                    let frame_fn_ptr = self.frames.last().unwrap().fn_ptr;
+                    let fn_def = self.shader_registry.all_fns.get(&frame_fn_ptr).unwrap();
+                    let param = &fn_def.params[param_index];
+                    if let InterpValue::Closure(closure) = self.var(param.ident, param.shadow.get().unwrap()) {
+                        return self.call_closure(&closure, arg_exprs)
+                    }
+                    return InterpValue::Void
+                }
+                let fn_def = self.shader_registry.all_fns.get(&fn_ptr.unwrap()).unwrap();
+                self.call_fn_with_exprs(fn_def, arg_exprs)
+            }
+            ExprKind::BuiltinCall {ident, arg_exprs, ..} => {
+                InterpValue::Num(self.eval_builtin(*ident, arg_exprs, expr_ty_lit(expr)))
+            }
+            ExprKind::ClosureDef(closure_def_index) => {
+                let frame = self.frames.last().unwrap();
+                let fn_def = self.shader_registry.all_fns.get(&frame.fn_ptr).unwrap();
+                let closure_def = &fn_def.closure_defs[closure_def_index.0];
+                let closed_over = closure_def.closed_over_syms.borrow().as_ref().unwrap().iter().map( | sym | {
+                    let value = match sym.ty {
+                        Ty::DrawShader(_) => InterpValue::DrawShader,
+                        _ => self.var(sym.ident, sym.shadow)
+                    };
+                    ((sym.ident, sym.shadow), value)
+                }).collect();
+                InterpValue::Closure(Rc::new(InterpClosure {
+                    fn_ptr: frame.fn_ptr,
+                    closure_def_index: *closure_def_index,
+                    closed_over
+                }))
+            }
+            ExprKind::ConsCall {ty_lit, arg_exprs, ..} => {
+                let args: Vec<InterpNum> = arg_exprs.iter().map( | arg_expr | self.eval_expr(arg_expr).num()).collect();
+                InterpValue::Num(cons(*ty_lit, &args))
+            }
+            ExprKind::StructCons {struct_ptr, args, ..} => {
+                let struct_def = self.shader_registry.structs.get(struct_ptr).unwrap();
+                InterpValue::Struct(struct_def.fields.iter().map( | field | {
+                    if let Some((_, arg_expr)) = args.iter().find( | (ident, _) | *ident == field.ident) {
+                        self.eval_expr(arg_expr)
+                    }
+                    else {
+                        InterpValue::zero(field.ty_expr.ty.borrow().as_ref().unwrap(), self.shader_registry)
+                    }
+                }).collect())
+            }
+            ExprKind::Var {kind, ..} => match kind.get() {
+                Some(VarKind::Local {ident, shadow}) | Some(VarKind::MutLocal {ident, shadow}) => {
+                    self.var(ident, shadow)
+                }
+                Some(VarKind::LiveValue(value_ptr)) => {
+                    self.live_values.get(&value_ptr).cloned().unwrap_or(InterpValue::Void)
+                }
+                None => InterpValue::Void
+            },
+            ExprKind::Lit {lit, ..} => InterpValue::from_val(&lit.to_val()),
+        }
+    }
+
+    fn eval_builtin(&mut self, ident: Ident, arg_exprs: &[Expr], ty_lit: TyLit) -> InterpNum {
+        match ident.0 {
+            live_id!(sample2d) | live_id!(sample2d_rt) => {
+                let texture = if let InterpValue::Texture(texture) = self.eval_expr(&arg_exprs[0]) {texture} else {0};
+                let pos = self.eval_expr(&arg_exprs[1]).num();
+                let mut lanes = [[0.0; 2]; LANES];
+                for lane in 0..LANES {
+                    lanes[lane] = [pos.lanes[lane][0], pos.lanes[lane][1]];
+                }
+                let result = self.sampler.sample_2d(texture, lanes, ident.0 == live_id!(sample2d_rt));
+                return InterpNum::from_fn(TyLit::Vec4, | lane, comp | result[lane][comp])
+            }
+            live_id!(sample3d) | live_id!(sample_cube) => {
+                let texture = if let InterpValue::Texture(texture) = self.eval_expr(&arg_exprs[0]) {texture} else {0};
+                let pos = self.eval_expr(&arg_exprs[1]).num();
+                let lanes = std::array::from_fn( | lane | [pos.lanes[lane][0], pos.lanes[lane][1], pos.lanes[lane][2]]);
+                let result = if ident.0 == live_id!(sample3d) {
+                    self.sampler.sample_3d(texture, lanes)
+                }
+                else {
+                    self.sampler.sample_cube(texture, lanes)
+                };
+                return InterpNum::from_fn(TyLit::Vec4, | lane, comp | result[lane][comp])
+            }
+            _ => ()
+        }
+        let args: Vec<InterpNum> = arg_exprs.iter().map( | arg_expr | self.eval_expr(arg_expr).num()).collect();
+        builtin(ident, &args, ty_lit)
+    }
+}
+
+fn map1(a: &InterpNum, ty_lit: TyLit, f: impl Fn(f32) -> f32) -> InterpNum {
+    InterpNum::from_fn(ty_lit, | lane, comp | f(a.get(lane, comp)))
+}
+
+fn map2(a: &InterpNum, b: &InterpNum, ty_lit: TyLit, f: impl Fn(f32, f32) -> f32) -> InterpNum {
+    InterpNum::from_fn(ty_lit, | lane, comp | f(a.get(lane, comp), b.get(lane, comp)))
+}
+
+fn map3(a: &InterpNum, b: &InterpNum, c: &InterpNum, ty_lit: TyLit, f: impl Fn(f32, f32, f32) -> f32) -> InterpNum {
+    InterpNum::from_fn(ty_lit, | lane, comp | f(a.get(lane, comp), b.get(lane, comp), c.get(lane, comp)))
+}
+
+fn bool_f32(v: bool) -> f32 {
+    if v {1.0} else {0.0}
+}
+
+fn bin_op(op: BinOp, a: &InterpNum, b: &InterpNum, ty_lit: TyLit, active: &LaneMask) -> Result<InterpNum, String> {
+    Ok(match op {
+        BinOp::Or => map2(a, b, TyLit::Bool, | a, b | bool_f32(a != 0.0 || b != 0.0)),
+        BinOp::And => map2(a, b, TyLit::Bool, | a, b | bool_f32(a != 0.0 && b != 0.0)),
+        BinOp::Eq | BinOp::Ne => {
+            let slots = a.slots().max(b.slots());
+            InterpNum::from_fn(TyLit::Bool, | lane, _ | {
+                let eq = (0..slots).all( | comp | a.get(lane, comp) == b.get(lane, comp));
+                bool_f32(if let BinOp::Eq = op {eq} else {!eq})
+            })
+        }
+        BinOp::Lt => map2(a, b, TyLit::Bool, | a, b | bool_f32(a < b)),
+        BinOp::Le => map2(a, b, TyLit::Bool, | a, b | bool_f32(a <= b)),
+        BinOp::Gt => map2(a, b, TyLit::Bool, | a, b | bool_f32(a > b)),
+        BinOp::Ge => map2(a, b, TyLit::Bool, | a, b | bool_f32(a >= b)),
+        BinOp::Add => map2(a, b, ty_lit, | a, b | a + b),
+        BinOp::Sub => map2(a, b, ty_lit, | a, b | a - b),
+        BinOp::Mul => {
+            let a_dim = mat_dim(a.ty_lit);
+            let b_dim = mat_dim(b.ty_lit);
+            // matrices are stored column major like the uniform buffers
+            if a_dim > 0 && b_dim > 0 {
+                let n = a_dim;
+                InterpNum::from_fn(a.ty_lit, | lane, i | {
+                    let (col, row) = (i / n, i % n);
+                    (0..n).map( | k | a.lanes[lane][k * n + row] * b.lanes[lane][col * n + k]).sum()
+                })
+            }
+            else if a_dim > 0 && b.slots() == a_dim {
+                let n = a_dim;
+                InterpNum::from_fn(b.ty_lit, | lane, row | {
+                    (0..n).map( | col | a.lanes[lane][col * n + row] * b.lanes[lane][col]).sum()
+                })
+            }
+            else if b_dim > 0 && a.slots() == b_dim {
+                let n = b_dim;
+                InterpNum::from_fn(a.ty_lit, | lane, col | {
+                    (0..n).map( | row | a.lanes[lane][row] * b.lanes[lane][col * n + row]).sum()
+                })
+            }
+            else {
+                map2(a, b, ty_lit, | a, b | a * b)
+            }
+        }
+        BinOp::Div => {
+            if is_int(ty_lit) {
+                // undefined on the gpu, lanes that are switched off may still divide by zero
+                if (0..LANES).any( | lane | active[lane] && b.lanes[lane][0..b.slots()].contains(&0.0)) {
+                    return Err("integer division by zero".to_string())
+                }
+                map2(a, b, ty_lit, | a, b | if b == 0.0 {0.0} else {(a / b).trunc()})
+            }
+            else {
+                map2(a, b, ty_lit, | a, b | a / b)
+            }
+        }
+        // assignments are handled by the caller, they never get here as a value
+        _ => return Err(format!("operator {} has no value", op))
+    })
+}
+
+fn cons(ty_lit: TyLit, args: &[InterpNum]) -> InterpNum {
+    let slots = ty_lit_slots(ty_lit);
+    let dim = mat_dim(ty_lit);
+    let mut num = InterpNum::zero(ty_lit);
+    for lane in 0..LANES {
+        let out = &mut num.lanes[lane];
+        if args.len() == 1 && args[0].slots() == 1 {
+            let x = args[0].lanes[lane][0];
+            if dim > 0 {
+                for i in 0..dim {
+                    out[i * dim + i] = x;
+                }
+            }
+            else {
+                for i in 0..slots {
+                    out[i] = x;
+                }
+            }
+        }
+        else if args.len() == 1 && dim > 0 && mat_dim(args[0].ty_lit) > 0 {
+            let src_dim = mat_dim(args[0].ty_lit);
+            for col in 0..dim {
+                for row in 0..dim {
+                    out[col * dim + row] = if col < src_dim && row < src_dim {
+                        args[0].lanes[lane][col * src_dim + row]
+                    }
+                    else {
+                        bool_f32(col == row)
+                    };
+                }
+            }
+        }
+        else {
+            let mut i = 0;
+            for arg in args {
+                for comp in 0..arg.slots() {
+                    if i < slots {
+                        out[i] = arg.lanes[lane][comp];
+                        i += 1;
+                    }
+                }
+            }
+        }
+        for i in 0..slots {
+            out[i] = to_scalar_kind(ty_lit, out[i]);
+        }
+    }
+    num
+}
+
+fn invert(m: &[f32], n: usize) -> [f32; 16] {
+    // gauss-jordan on a column major matrix
+    let mut a = [[0.0f64; 8]; 4];
+    for row in 0..n {
+        for col in 0..n {
+            a[row][col] = m[col * n + row] as f64;
+        }
+        a[row][n + row] = 1.0;
+    }
+    for col in 0..n {
+        let pivot = (col..n).max_by( | x, y | a[*x][col].abs().total_cmp(&a[*y][col].abs())).unwrap();
+        a.swap(col, pivot);
+        let p = a[col][col];
+        if p == 0.0 {
+            return [0.0; 16]
+        }
+        for k in 0..2 * n {
+            a[col][k] /= p;
+        }
+        for row in 0..n {
+            if row != col {
+                let f = a[row][col];
+                for k in 0..2 * n {
+                    a[row][k] -= f * a[col][k];
+                }
+            }
+        }
+    }
+    let mut out = [0.0; 16];
+    for row in 0..n {
+        for col in 0..n {
+            out[col * n + row] = a[row][n + col] as f32;
+        }
+    }
+    out
+}
+
+fn builtin(ident: Ident, args: &[InterpNum], ty_lit: TyLit) -> InterpNum {
+    let a = args.get(0).copied().unwrap_or(InterpNum::zero(TyLit::Float));
+    let b = args.get(1).copied().unwrap_or(InterpNum::zero(TyLit::Float));
+    let c = args.get(2).copied().unwrap_or(InterpNum::zero(TyLit::Float));
+    match ident.0 {
+        live_id!(abs) => map1(&a, ty_lit, f32::abs),
+        live_id!(acos) => map1(&a, ty_lit, f32::acos),
+        live_id!(asin) => map1(&a, ty_lit, f32::asin),
+        live_id!(atan) => if args.len() == 2 {
+            map2(&a, &b, ty_lit, f32::atan2)
+        }
+        else {
+            map1(&a, ty_lit, f32::atan)
+        },
+        live_id!(ceil) => map1(&a, ty_lit, f32::ceil),
+        live_id!(cos) => map1(&a, ty_lit, f32::cos),
+        live_id!(degrees) => map1(&a, ty_lit, f32::to_degrees),
+        live_id!(exp) => map1(&a, ty_lit, f32::exp),
+        live_id!(exp2) => map1(&a, ty_lit, f32::exp2),
+        live_id!(floor) => map1(&a, ty_lit, f32::floor),
+        live_id!(fract) => map1(&a, ty_lit, | x | x - x.floor()),
+        live_id!(inversesqrt) => map1(&a, ty_lit, | x | 1.0 / x.sqrt()),
+        live_id!(log) => map1(&a, ty_lit, f32::ln),
+        live_id!(log2) => map1(&a, ty_lit, f32::log2),
+        live_id!(radians) => map1(&a, ty_lit, f32::to_radians),
+        live_id!(sign) => map1(&a, ty_lit, | x | if x == 0.0 {0.0} else {x.signum()}),
+        live_id!(sin) => map1(&a, ty_lit, f32::sin),
+        live_id!(sqrt) => map1(&a, ty_lit, f32::sqrt),
+        live_id!(tan) => map1(&a, ty_lit, f32::tan),
+        live_id!(max) => map2(&a, &b, ty_lit, f32::max),
+        live_id!(min) => map2(&a, &b, ty_lit, f32::min),
+        live_id!(mod) => map2(&a, &b, ty_lit, | x, y | x - y * (x / y).floor()),
+        live_id!(pow) => map2(&a, &b, ty_lit, f32::powf),
+        live_id!(step) => map2(&a, &b, ty_lit, | edge, x | if x < edge {0.0} else {1.0}),
+        live_id!(matrixCompMult) => map2(&a, &b, ty_lit, | x, y | x * y),
+        live_id!(clamp) => map3(&a, &b, &c, ty_lit, | x, lo, hi | x.max(lo).min(hi)),
+        live_id!(mix) => map3(&a, &b, &c, ty_lit, | x, y, t | x * (1.0 - t) + y * t),
+        live_id!(smoothstep) => map3(&a, &b, &c, ty_lit, | e0, e1, x | {
+            let t = ((x - e0) / (e1 - e0)).max(0.0).min(1.0);
+            t * t * (3.0 - 2.0 * t)
+        }),
+        live_id!(length) => InterpNum::from_fn(TyLit::Float, | lane, _ | a.dot(&a, lane).sqrt()),
+        live_id!(distance) => {
+            let d = map2(&a, &b, a.ty_lit, | x, y | x - y);
+            InterpNum::from_fn(TyLit::Float, | lane, _ | d.dot(&d, lane).sqrt())
+        }
+        live_id!(dot) => InterpNum::from_fn(TyLit::Float, | lane, _ | a.dot(&b, lane)),
+        live_id!(cross) => InterpNum::from_fn(TyLit::Vec3, | lane, comp | {
+            let (x, y) = (&a.lanes[lane], &b.lanes[lane]);
+            match comp {
+                0 => x[1] * y[2] - y[1] * x[2],
+                1 => x[2] * y[0] - y[2] * x[0],
+                _ => x[0] * y[1] - y[0] * x[1],
+            }
+        }),
+        live_id!(normalize) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            let len = a.dot(&a, lane).sqrt();
+            if len == 0.0 {0.0} else {a.get(lane, comp) / len}
+        }),
+        live_id!(faceforward) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            if c.dot(&b, lane) < 0.0 {a.get(lane, comp)} else {-a.get(lane, comp)}
+        }),
+        live_id!(reflect) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            a.get(lane, comp) - 2.0 * b.dot(&a, lane) * b.get(lane, comp)
+        }),
+        live_id!(refract) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            let d = b.dot(&a, lane);
+            let eta = c.lanes[lane][0];
+            let k = 1.0 - eta * eta * (1.0 - d * d);
+            if k < 0.0 {0.0} else {eta * a.get(lane, comp) - (eta * d + k.sqrt()) * b.get(lane, comp)}
+        }),
+        live_id!(all) => InterpNum::from_fn(TyLit::Bool, | lane, _ | bool_f32((0..a.slots()).all( | comp | a.lanes[lane][comp] != 0.0))),
+        live_id!(any) => InterpNum::from_fn(TyLit::Bool, | lane, _ | bool_f32((0..a.slots()).any( | comp | a.lanes[lane][comp] != 0.0))),
+        live_id!(not) => map1(&a, ty_lit, | x | bool_f32(x == 0.0)),
+        live_id!(equal) => map2(&a, &b, ty_lit, | x, y | bool_f32(x == y)),
+        live_id!(notEqual) => map2(&a, &b, ty_lit, | x, y | bool_f32(x != y)),
+        live_id!(lessThan) => map2(&a, &b, ty_lit, | x, y | bool_f32(x < y)),
+        live_id!(lessThanEqual) => map2(&a, &b, ty_lit, | x, y | bool_f32(x <= y)),
+        live_id!(greaterThan) => map2(&a, &b, ty_lit, | x, y | bool_f32(x > y)),
+        live_id!(greaterThanEqual) => map2(&a, &b, ty_lit, | x, y | bool_f32(x >= y)),
+        live_id!(transpose) => {
+            let n = mat_dim(a.ty_lit);
+            InterpNum::from_fn(ty_lit, | lane, i | a.lanes[lane][(i % n) * n + i / n])
+        }
+        live_id!(inverse) => {
+            let n = mat_dim(a.ty_lit);
+            let mut num = InterpNum::zero(ty_lit);
+            for lane in 0..LANES {
+                num.lanes[lane] = invert(&a.lanes[lane], n);
+            }
+            num
+        }
+        // derivatives come from the neighbouring lanes in the 2x2 quad
+        live_id!(dFdx) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            let base = lane & 2;
+            a.get(base + 1, comp) - a.get(base, comp)
+        }),
+        live_id!(dFdy) => InterpNum::from_fn(ty_lit, | lane, comp | {
+            let base = lane & 1;
+            a.get(base + 2, comp) - a.get(base, comp)
+        }),
+        _ => InterpNum::zero(ty_lit)
+    }
+}
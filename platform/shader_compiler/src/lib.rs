@@ -14,13 +14,19 @@ pub mod lhs_check;
 pub mod swizzle;
 pub mod util;
 pub mod generate;
+pub mod interpret;
+pub mod shader_offline;
 
-#[cfg(any(target_os = "android", target_os = "linux", target_arch = "wasm32"))]
+// the all_generators feature builds every backend, for tools that emit shaders for other platforms
+#[cfg(any(target_os = "android", target_os = "linux", target_arch = "wasm32", feature = "all_generators"))]
 pub mod generate_glsl;
-#[cfg(any(target_os = "macos", target_os="ios"))]
+#[cfg(any(target_os = "macos", target_os="ios", feature = "all_generators"))]
 pub mod generate_metal;
-#[cfg(any(target_os = "windows"))]
+#[cfg(any(target_os = "windows", feature = "all_generators"))]
 pub mod generate_hlsl;
+// no platform draws with WGSL yet, so it's only built for the tools and the tests
+#[cfg(feature = "all_generators")]
+pub mod generate_wgsl;
 
 pub use makepad_live_compiler;
 pub use makepad_live_compiler::makepad_math;
@@ -38,6 +44,8 @@ pub use {
             DrawShaderDef,
             DrawShaderFieldKind,
             DrawShaderFlags,
+            DrawShaderBlendMode,
+            BlendFactor,
             DrawShaderConstTable,
             ValuePtr,
         },
@@ -45,7 +53,8 @@ pub use {
             ShaderEnum,
             ShaderRegistry,
             DrawShaderQuery
-        }
+        },
+        shader_offline::OfflineShaders
     }
 };
 
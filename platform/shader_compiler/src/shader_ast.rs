@@ -67,11 +67,80 @@ pub struct DrawShaderConstTable {
     pub table_index: BTreeMap<LiveTokenId, ConstTableItem>
 }
 
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct DrawShaderFlags {
     pub debug: bool,
     pub draw_call_nocompare: bool,
     pub draw_call_always: bool,
+    pub blend_mode: DrawShaderBlendMode,
+    pub depth_test: bool,
+    pub depth_write: bool,
+}
+
+impl DrawShaderFlags {
+    // backends that bake blend and depth state into their pipelines can only share one if these match
+    pub fn same_pipeline_state(&self, other: &DrawShaderFlags) -> bool {
+        self.blend_mode == other.blend_mode && self.depth_test == other.depth_test && self.depth_write == other.depth_write
+    }
+}
+
+impl Default for DrawShaderFlags {
+    fn default() -> Self {
+        Self {
+            debug: false,
+            draw_call_nocompare: false,
+            draw_call_always: false,
+            blend_mode: DrawShaderBlendMode::Normal,
+            depth_test: true,
+            depth_write: true,
+        }
+    }
+}
+
+// how the premultiplied color a pixel shader returns is combined with the target
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DrawShaderBlendMode {
+    #[default]
+    Normal,
+    Additive,
+    Multiply,
+    Screen,
+    Replace,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    OneMinusSrcColor,
+    OneMinusSrcAlpha,
+    DstColor,
+}
+
+impl DrawShaderBlendMode {
+    pub fn from_id(id: LiveId) -> Option<Self> {
+        match id {
+            live_id!(Normal) => Some(Self::Normal),
+            live_id!(Additive) => Some(Self::Additive),
+            live_id!(Multiply) => Some(Self::Multiply),
+            live_id!(Screen) => Some(Self::Screen),
+            live_id!(Replace) => Some(Self::Replace),
+            _ => None
+        }
+    }
+    
+    // the (src rgb, dst rgb, src alpha, dst alpha) factors, all with an add equation.
+    // alpha accumulates like Normal in every mode but Replace
+    pub fn blend_factors(&self) -> [BlendFactor; 4] {
+        use BlendFactor::*;
+        match self {
+            Self::Normal => [One, OneMinusSrcAlpha, One, OneMinusSrcAlpha],
+            Self::Additive => [One, One, One, OneMinusSrcAlpha],
+            Self::Multiply => [DstColor, OneMinusSrcAlpha, One, OneMinusSrcAlpha],
+            Self::Screen => [One, OneMinusSrcColor, One, OneMinusSrcAlpha],
+            Self::Replace => [One, Zero, One, Zero],
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -536,6 +605,8 @@ pub enum ShaderTy {
     Mat3,
     Mat4,
     Texture2D,
+    Texture3D,
+    TextureCube,
     Array {elem_ty: Rc<ShaderTy>, len: usize},
     Struct(StructPtr),
     Enum(LiveType),
@@ -565,6 +636,8 @@ pub enum TyLit {
     Mat3,
     Mat4,
     Texture2D,
+    Texture3D,
+    TextureCube,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -768,6 +841,12 @@ impl FnDef {
         }
         return false
     }
+
+    pub fn inout_params(&self) -> Vec<(Ident, ScopeSymShadow)> {
+        self.params.iter().filter( | param | param.is_inout).filter_map( | param | {
+            Some((param.ident, param.shadow.get() ?))
+        }).collect()
+    }
 }
 
 impl DrawShaderDef {
@@ -988,7 +1067,7 @@ impl Ty {
             Ty::Mat2 => Some(TyLit::Mat2),
             Ty::Mat3 => Some(TyLit::Mat3),
             Ty::Mat4 => Some(TyLit::Mat4),
-            Ty::Texture2D => Some(TyLit::Bool),
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => Some(TyLit::Bool),
             Ty::Array {..} => None,
             Ty::Struct(_) => None,
             Ty::Enum(_) => None,
@@ -1036,7 +1115,7 @@ impl Ty {
             Ty::Bvec4 | Ty::Ivec4 | Ty::Vec4 | Ty::Mat2 => 4,
             Ty::Mat3 => 9,
             Ty::Mat4 => 16,
-            Ty::Texture2D {..} => panic!(),
+            Ty::Texture2D | Ty::Texture3D | Ty::TextureCube => panic!(),
             Ty::Array {elem_ty, len} => elem_ty.slots() * len,
             Ty::Enum(_) => 1,
             Ty::Struct(_) => panic!(),
@@ -1068,6 +1147,8 @@ impl Ty {
                 Ty::Mat3 => TyExprKind::Lit {ty_lit: TyLit::Mat3},
                 Ty::Mat4 => TyExprKind::Lit {ty_lit: TyLit::Mat4},
                 Ty::Texture2D => TyExprKind::Lit {ty_lit: TyLit::Texture2D},
+                Ty::Texture3D => TyExprKind::Lit {ty_lit: TyLit::Texture3D},
+                Ty::TextureCube => TyExprKind::Lit {ty_lit: TyLit::TextureCube},
                 Ty::Array {elem_ty, len} => {
                     TyExprKind::Array {
                         elem_ty_expr: Box::new(elem_ty.to_ty_expr()),
@@ -1129,6 +1210,8 @@ impl Ty {
                 live_id!(vec3) => Self::Vec3,
                 live_id!(vec4) => Self::Vec4,
                 live_id!(texture2d) => Self::Texture2D,
+                live_id!(texture3d) => Self::Texture3D,
+                live_id!(textureCube) => Self::TextureCube,
                 _ => {
                     return Err(LiveError {
                         origin: live_error_origin!(),
@@ -1174,6 +1257,8 @@ impl fmt::Display for Ty {
             Ty::Mat3 => write!(f, "mat3"),
             Ty::Mat4 => write!(f, "mat4"),
             Ty::Texture2D => write!(f, "texture2D"),
+            Ty::Texture3D => write!(f, "texture3D"),
+            Ty::TextureCube => write!(f, "textureCube"),
             Ty::Array {elem_ty, len} => write!(f, "{}[{}]", elem_ty, len),
             Ty::Struct(struct_ptr) => write!(f, "Struct:{:?}", struct_ptr),
             Ty::DrawShader(shader_ptr) => write!(f, "DrawShader:{:?}", shader_ptr),
@@ -1203,6 +1288,8 @@ impl TyLit {
             live_id!(ivec3) => Some(TyLit::Ivec4),
             live_id!(ivec4) => Some(TyLit::Ivec4),
             live_id!(texture2D) => Some(TyLit::Texture2D),
+            live_id!(texture3D) => Some(TyLit::Texture3D),
+            live_id!(textureCube) => Some(TyLit::TextureCube),
             _ => None
         }
     }
@@ -1225,6 +1312,8 @@ impl TyLit {
             TyLit::Mat3 => Ty::Mat3,
             TyLit::Mat4 => Ty::Mat4,
             TyLit::Texture2D => Ty::Texture2D,
+            TyLit::Texture3D => Ty::Texture3D,
+            TyLit::TextureCube => Ty::TextureCube,
         }
     }
     
@@ -1252,6 +1341,8 @@ impl fmt::Display for TyLit {
                 TyLit::Mat3 => "mat3",
                 TyLit::Mat4 => "mat4",
                 TyLit::Texture2D => "texture2D",
+                TyLit::Texture3D => "texture3D",
+                TyLit::TextureCube => "textureCube",
             }
         )
     }
@@ -0,0 +1,291 @@
+use {
+    std::collections::HashMap,
+    crate::{
+        makepad_live_compiler::*,
+        makepad_live_compiler::live_rust_types::{RustTypes, LiveTypeInfoBuilder, scan_rust_types},
+        makepad_live_compiler::live_eval::{live_eval, LiveEval},
+        makepad_live_id::*,
+        makepad_math::Vec4,
+        shader_ast::*,
+        interpret::{Interpreter, InterpValue, InterpSampler},
+        shader_registry::{ShaderRegistry, ShaderEnum, DrawShaderQuery},
+    }
+};
+
+// Analyses draw shaders without running the app, for the shaderc tool and the golden tests.
+// The app learns the instance fields of a shader and the shader enums from the Live derive, here
+// they are read out of the rust sources. Geometry fields come from GeometryFields impls which
+// can't be read that way, so those are a table keyed by geometry type name
+
+pub struct OfflineShaders {
+    pub live_registry: LiveRegistry,
+    pub shader_registry: ShaderRegistry,
+    pub geometry_fields: HashMap<LiveId, Vec<(LiveId, ShaderTy)>>,
+    sources: Vec<OfflineSource>,
+}
+
+struct OfflineSource {
+    file_name: String,
+    module_path: String,
+    text: String,
+}
+
+impl Default for OfflineShaders {
+    fn default() -> Self {
+        let mut geometry_fields = HashMap::new();
+        geometry_fields.insert(live_id!(GeometryQuad2D), vec![(live_id!(geom_pos), ShaderTy::Vec2)]);
+        Self {
+            live_registry: LiveRegistry::default(),
+            shader_registry: ShaderRegistry::new(),
+            geometry_fields,
+            sources: Vec::new(),
+        }
+    }
+}
+
+impl OfflineShaders {
+    pub fn add_source(&mut self, file_name: &str, module_path: &str, text: String) {
+        self.sources.push(OfflineSource {
+            file_name: file_name.to_string(),
+            module_path: module_path.to_string(),
+            text
+        });
+    }
+
+    // registers and expands all added sources, returning what went wrong on the way
+    pub fn build(&mut self) -> Vec<LiveFileError> {
+        let mut rust_types = RustTypes::default();
+        for source in &self.sources {
+            scan_rust_types(&source.file_name, &source.module_path, &source.text, &mut rust_types.types);
+        }
+        let class_names: Vec<Vec<String>> = self.sources.iter().map( | source | {
+            if source.text.contains("live_design!") {
+                rust_types.live_class_names(&source.text, &source.module_path)
+            }
+            else {
+                Vec::new()
+            }
+        }).collect();
+
+        let mut builder = LiveTypeInfoBuilder::new(&rust_types);
+        let mut errors = Vec::new();
+        for (source, class_names) in self.sources.iter().zip(class_names) {
+            if class_names.is_empty() && !source.text.contains("live_design!") {
+                continue;
+            }
+            let module_id = if let Ok(module_id) = LiveModuleId::from_str(&source.module_path) {module_id} else {continue};
+            let live_type_infos = class_names.iter().map( | name | builder.live_type_info(name, &source.module_path)).collect();
+            if let Err(err) = self.live_registry.register_live_design_file(&source.file_name, "", module_id, source.text.clone(), live_type_infos) {
+                errors.push(err);
+            }
+        }
+
+        for rust_type in rust_types.types.iter().filter( | t | !t.shader_variants.is_empty()) {
+            let info = builder.live_type_info(&rust_type.name, &rust_type.module_path);
+            self.shader_registry.register_enum(info.live_type, ShaderEnum {
+                enum_name: info.type_name,
+                variants: rust_type.shader_variants.iter().map( | v | LiveId::from_str_with_lut(v).unwrap()).collect()
+            });
+        }
+
+        let mut live_errors = Vec::new();
+        self.live_registry.expand_all_documents(&mut live_errors);
+        for live_error in live_errors {
+            errors.push(self.live_registry.live_error_to_live_file_error(live_error));
+        }
+        errors
+    }
+
+    fn is_draw_shader(&self, live_type: LiveType) -> bool {
+        fn derefs_draw_vars(info: &LiveTypeInfo) -> bool {
+            info.fields.iter().any( | field | field.live_field_kind == LiveFieldKind::Deref && (
+                field.live_type_info.type_name == live_id!(DrawVars) || derefs_draw_vars(&field.live_type_info)
+            ))
+        }
+        self.live_registry.live_type_infos.get(&live_type).is_some_and(derefs_draw_vars)
+    }
+
+    // every draw shader class in a file, named by their path like Button.draw_bg. Unnamed
+    // instances get their position in the parent instead, the ids they get differ per run
+    pub fn draw_shaders(&self, file_name: &str) -> Vec<(String, DrawShaderPtr)> {
+        let mut out = Vec::new();
+        let file_id = if let Some(file_id) = self.live_registry.file_name_to_file_id(file_name) {file_id} else {return out};
+        let nodes = &self.live_registry.file_id_to_file(file_id).expanded.nodes;
+        let mut path: Vec<String> = Vec::new();
+        let mut child_index = vec![0];
+        let mut index = 1;
+        while index < nodes.len() {
+            let node = &nodes[index];
+            if node.value.is_close() {
+                path.pop();
+                child_index.pop();
+                index += 1;
+                continue;
+            }
+            let name = if node.id.is_unique() {
+                child_index.last().unwrap().to_string()
+            }
+            else {
+                node.id.to_string()
+            };
+            *child_index.last_mut().unwrap() += 1;
+            if let LiveValue::Class {live_type, ..} = &node.value {
+                if self.is_draw_shader(*live_type) {
+                    let name = path.iter().chain(std::iter::once(&name)).cloned().collect::<Vec<_>>().join(".");
+                    out.push((name, DrawShaderPtr(self.live_registry.file_id_index_to_live_ptr(file_id, index))));
+                    // a draw shader doesnt contain other draw shaders
+                    index = nodes.skip_node(index);
+                    continue;
+                }
+            }
+            if node.value.is_open() {
+                path.push(name);
+                child_index.push(0);
+            }
+            index += 1;
+        }
+        out
+    }
+
+    pub fn find_draw_shader(&self, file_name: &str, name: &str) -> Option<DrawShaderPtr> {
+        self.draw_shaders(file_name).into_iter().find( | (n, _) | n == name).map( | (_, ptr) | ptr)
+    }
+
+    // runs the analyser the way DrawVars does in the app, and returns the const table to generate with
+    pub fn analyse(&mut self, draw_shader_ptr: DrawShaderPtr) -> Result<DrawShaderConstTable, LiveFileError> {
+        let geometry_fields = &self.geometry_fields;
+        let mut field_error = None;
+        let result = self.shader_registry.analyse_draw_shader(&self.live_registry, draw_shader_ptr, | live_registry, shader_registry, span, draw_shader_query, live_type, draw_shader_def | {
+            let info = if let Some(info) = live_registry.live_type_infos.get(&live_type) {info} else {return};
+            match draw_shader_query {
+                DrawShaderQuery::DrawShader => {
+                    fn recur_expand(
+                        shader_registry: &ShaderRegistry,
+                        level: usize,
+                        after_draw_vars: &mut bool,
+                        info: &LiveTypeInfo,
+                        draw_shader_def: &mut DrawShaderDef,
+                        span: TokenSpan,
+                        field_error: &mut Option<LiveError>
+                    ) {
+                        let mut slots = 0;
+                        for field in &info.fields {
+                            if let LiveFieldKind::Deref = field.live_field_kind {
+                                if field.live_type_info.type_name != live_id!(DrawVars) {
+                                    recur_expand(shader_registry, level + 1, after_draw_vars, &field.live_type_info, draw_shader_def, span, field_error);
+                                }
+                                else {
+                                    *after_draw_vars = true;
+                                }
+                                continue
+                            }
+                            if *after_draw_vars {
+                                let live_type = field.live_type_info.live_type;
+                                if shader_registry.enums.contains_key(&live_type) {
+                                    slots += 1;
+                                    draw_shader_def.add_instance(field.id, ShaderTy::Enum(live_type), span, field.live_field_kind);
+                                    continue
+                                }
+                                let ty = match field.live_type_info.type_name {
+                                    live_id!(f32) => ShaderTy::Float,
+                                    live_id!(Vec2) => ShaderTy::Vec2,
+                                    live_id!(Vec3) => ShaderTy::Vec3,
+                                    live_id!(Vec4) => ShaderTy::Vec4,
+                                    type_name => {
+                                        field_error.get_or_insert(LiveError {
+                                            origin: live_error_origin!(),
+                                            span: span.into(),
+                                            message: format!("Instance field {} has type {} which shaders don't understand", field.id, type_name)
+                                        });
+                                        continue
+                                    }
+                                };
+                                slots += ty.slots();
+                                draw_shader_def.add_instance(field.id, ty, span, field.live_field_kind);
+                            }
+                        }
+                        // insert padding
+                        if level > 0 && slots % 2 == 1 {
+                            draw_shader_def.add_instance(LiveId(0), ShaderTy::Float, span, LiveFieldKind::Calc);
+                        }
+                    }
+                    recur_expand(shader_registry, 0, &mut false, info, draw_shader_def, span, &mut field_error);
+                }
+                DrawShaderQuery::Geometry => {
+                    if let Some(fields) = geometry_fields.get(&info.type_name) {
+                        for (id, ty) in fields {
+                            draw_shader_def.add_geometry(*id, ty.clone(), span);
+                        }
+                    }
+                    else {
+                        field_error.get_or_insert(LiveError {
+                            origin: live_error_origin!(),
+                            span: span.into(),
+                            message: format!("Unknown geometry type {}", info.type_name)
+                        });
+                    }
+                }
+            }
+        });
+        if let Some(err) = field_error {
+            return Err(self.live_registry.live_error_to_live_file_error(err))
+        }
+        match result {
+            Err(err) => Err(self.live_registry.live_error_to_live_file_error(err)),
+            Ok(()) => Ok(self.shader_registry.compute_const_table(draw_shader_ptr))
+        }
+    }
+
+    pub fn draw_shader_def(&self, draw_shader_ptr: DrawShaderPtr) -> Option<&DrawShaderDef> {
+        self.shader_registry.draw_shader_defs.get(&draw_shader_ptr)
+    }
+
+    // an interpreter to run an analysed shader on the CPU, with the live values it
+    // references filled in from the registry like the app does for live uniforms
+    pub fn interpreter<'a>(&'a self, draw_shader_ptr: DrawShaderPtr, const_table: &'a DrawShaderConstTable, sampler: &'a dyn InterpSampler) -> Result<Interpreter<'a>, LiveError> {
+        let draw_shader_def = if let Some(def) = self.draw_shader_def(draw_shader_ptr) {def} else {
+            return Err(LiveError {
+                origin: live_error_origin!(),
+                span: self.live_registry.ptr_to_node(draw_shader_ptr.0).origin.token_id().unwrap().into(),
+                message: format!("Draw shader has not been analysed")
+            })
+        };
+        let mut interp = Interpreter::new(&self.shader_registry, draw_shader_def, sampler);
+        interp.const_table = Some(const_table);
+        for (value_ptr, ty) in draw_shader_def.all_live_refs.borrow().iter() {
+            let slots = self.live_value_slots(*value_ptr) ?;
+            interp.live_values.insert(*value_ptr, InterpValue::from_slots(ty, &slots));
+        }
+        Ok(interp)
+    }
+
+    fn live_value_slots(&self, value_ptr: ValuePtr) -> Result<Vec<f32>, LiveError> {
+        let (nodes, index) = self.live_registry.ptr_to_nodes_index(value_ptr.0);
+        let not_a_shader_value = || LiveError {
+            origin: live_error_origin!(),
+            span: nodes[index].origin.token_id().unwrap().into(),
+            message: format!("Live value {} can't be used in a shader", nodes[index].id)
+        };
+        let eval = match &nodes[index].value {
+            LiveValue::Bool(v) => LiveEval::Bool(*v),
+            LiveValue::Int64(v) => LiveEval::Int64(*v),
+            LiveValue::Float32(v) => LiveEval::Float64(*v as f64),
+            LiveValue::Float64(v) => LiveEval::Float64(*v),
+            LiveValue::Color(v) => LiveEval::Vec4(Vec4::from_u32(*v)),
+            LiveValue::Vec2(v) => LiveEval::Vec2(*v),
+            LiveValue::Vec3(v) => LiveEval::Vec3(*v),
+            LiveValue::Vec4(v) => LiveEval::Vec4(*v),
+            LiveValue::Expr {..} => live_eval(&self.live_registry, index, &mut (index + 1), nodes) ?,
+            _ => return Err(not_a_shader_value())
+        };
+        Ok(match eval {
+            LiveEval::Bool(v) => vec![if v {1.0} else {0.0}],
+            LiveEval::Int64(v) => vec![v as f32],
+            LiveEval::Float64(v) => vec![v as f32],
+            LiveEval::Vec2(v) => vec![v.x, v.y],
+            LiveEval::Vec3(v) => vec![v.x, v.y, v.z],
+            LiveEval::Vec4(v) => vec![v.x, v.y, v.z, v.w],
+            LiveEval::String(_) => return Err(not_a_shader_value())
+        })
+    }
+}
@@ -653,6 +653,12 @@ impl ShaderRegistry {
                                             live_id!(draw_call_always) => {
                                                 draw_shader_def.flags.draw_call_always = val;
                                             }
+                                            live_id!(depth_test) => {
+                                                draw_shader_def.flags.depth_test = val;
+                                            }
+                                            live_id!(depth_write) => {
+                                                draw_shader_def.flags.depth_write = val;
+                                            }
                                             _ => {} // could be input value
                                         }
                                     }
@@ -666,6 +672,18 @@ impl ShaderRegistry {
                                 }
                             };
                         }
+                        LiveValue::BareEnum(variant) if prop.id == live_id!(blend_mode) => {
+                            if let Some(blend_mode) = DrawShaderBlendMode::from_id(variant) {
+                                draw_shader_def.flags.blend_mode = blend_mode;
+                            }
+                            else {
+                                return Err(LiveError {
+                                    origin: live_error_origin!(),
+                                    span: prop.origin.token_id().unwrap().into(),
+                                    message: format!("Unknown blend_mode {}, use Normal, Additive, Multiply, Screen or Replace", variant)
+                                })
+                            }
+                        }
                         LiveValue::Class {live_type, ..} => {
                             if prop.id == live_id!(geometry) {
                                 ext_self(
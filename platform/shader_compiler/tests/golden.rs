@@ -0,0 +1,274 @@
+use {
+    std::{
+        collections::HashSet,
+        fs,
+        path::{Path, PathBuf},
+    },
+    makepad_shader_compiler::{
+        makepad_live_compiler::{
+            live_rust_types::module_path_in_crate,
+            LiveNodeSliceApi,
+            LiveTokenId,
+        },
+        OfflineShaders,
+        DrawShaderPtr,
+        DrawShaderConstTable,
+        DrawShaderDef,
+        ShaderRegistry,
+    },
+};
+
+// Generates every draw shader in makepad_draw and makepad_widgets and compares the output with
+// the files in tests/golden. The tests turn on all_generators, so every backend is checked on any
+// host. After an intended change to the compiler or the shaders, rerun with
+// MAKEPAD_UPDATE_GOLDEN=1 to write the new output and review the diff
+
+fn repo_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir).unwrap().flatten().map( | e | e.path()).collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            rust_files(&path, out);
+        }
+        else if path.extension().is_some_and( | ext | ext == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+fn load_crates(crates: &[(&str, &str)]) -> (OfflineShaders, Vec<(String, String)>) {
+    load_crates_with(crates, &[])
+}
+
+// extra sources are (file name, module path, source)
+fn load_crates_with(crates: &[(&str, &str)], extra: &[(&str, &str, &str)]) -> (OfflineShaders, Vec<(String, String)>) {
+    let mut offline = OfflineShaders::default();
+    let mut files = Vec::new();
+    for (file_name, module_path, source) in extra {
+        offline.add_source(file_name, module_path, source.to_string());
+        files.push((file_name.to_string(), module_path.to_string()));
+    }
+    for (crate_name, dir) in crates {
+        let crate_dir = repo_dir().join(dir);
+        let mut paths = Vec::new();
+        rust_files(&crate_dir.join("src"), &mut paths);
+        for path in paths {
+            let relative = path.strip_prefix(&crate_dir).unwrap();
+            let module_path = module_path_in_crate(crate_name, relative).unwrap();
+            // file names are relative so the golden output doesnt depend on where the repo is
+            let file_name = format!("{}/{}", dir, relative.to_string_lossy().replace('\\', "/"));
+            offline.add_source(&file_name, &module_path, fs::read_to_string(&path).unwrap());
+            files.push((file_name, module_path));
+        }
+    }
+    let errors = offline.build();
+    assert!(errors.is_empty(), "{}", errors.iter().map( | e | e.to_string()).collect::<Vec<_>>().join("\n"));
+    (offline, files)
+}
+
+// the shader code this file writes into a shader. Shaders without any are copies of one
+// defined elsewhere, and shaders with the same code as an earlier one are copies of that
+fn own_code(offline: &OfflineShaders, draw_shader_ptr: DrawShaderPtr) -> Vec<LiveTokenId> {
+    let (doc, _) = offline.live_registry.ptr_to_doc_node(draw_shader_ptr.0);
+    let start = draw_shader_ptr.node_index();
+    doc.nodes[start..doc.nodes.skip_node(start)].iter().filter( | node | node.value.is_dsl()).filter_map( | node | {
+        node.origin.token_id().filter( | t | t.file_id() == Some(draw_shader_ptr.file_id))
+    }).collect()
+}
+
+fn generate(def: &DrawShaderDef, const_table: &DrawShaderConstTable, shader_registry: &ShaderRegistry) -> Vec<(&'static str, String)> {
+    use makepad_shader_compiler::{generate_glsl, generate_hlsl, generate_metal, generate_wgsl};
+    vec![
+        ("glsl", format!(
+            "// vertex\n{}// pixel\n{}",
+            generate_glsl::generate_vertex_shader(def, const_table, shader_registry),
+            generate_glsl::generate_pixel_shader(def, const_table, shader_registry)
+        )),
+        ("hlsl", generate_hlsl::generate_shader(def, const_table, shader_registry)),
+        ("metal", generate_metal::generate_shader(def, const_table, shader_registry).mtlsl),
+        ("wgsl", generate_wgsl::generate_shader(def, const_table, shader_registry).wgsl),
+    ]
+}
+
+// parses and validates the output, then writes it back out with naga and parses that again
+fn validate_wgsl(source: &str) -> Result<(), String> {
+    use naga::{front::wgsl, back, valid};
+    let module = wgsl::parse_str(source).map_err( | err | err.emit_to_string(source)) ?;
+    let info = valid::Validator::new(valid::ValidationFlags::all(), valid::Capabilities::empty())
+        .validate(&module)
+        .map_err( | err | err.emit_to_string(source)) ?;
+    let written = back::wgsl::write_string(&module, &info, back::wgsl::WriterFlags::empty()).map_err( | err | err.to_string()) ?;
+    wgsl::parse_str(&written).map_err( | err | format!("naga output doesn't parse: {}", err.emit_to_string(&written))) ?;
+    Ok(())
+}
+
+fn check_golden(crates: &[(&str, &str)], file_prefix: &str) {
+    let (mut offline, files) = load_crates(crates);
+    let golden_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let update = std::env::var("MAKEPAD_UPDATE_GOLDEN").is_ok();
+    let mut failures = Vec::new();
+    let mut count = 0;
+    let mut seen = HashSet::new();
+    for (file_name, module_path) in files.iter().filter( | (f, _) | f.starts_with(file_prefix)) {
+        for (name, draw_shader_ptr) in offline.draw_shaders(file_name) {
+            let code = own_code(&offline, draw_shader_ptr);
+            if code.is_empty() || seen.contains(&code) {
+                continue
+            }
+            seen.insert(code);
+            let const_table = match offline.analyse(draw_shader_ptr) {
+                Ok(const_table) => const_table,
+                Err(err) => {
+                    failures.push(format!("{} {}: {}", module_path, name, err));
+                    continue
+                }
+            };
+            let def = offline.draw_shader_def(draw_shader_ptr).unwrap();
+            for (ext, source) in generate(def, &const_table, &offline.shader_registry) {
+                count += 1;
+                if ext == "wgsl" {
+                    if let Err(err) = validate_wgsl(&source) {
+                        failures.push(format!("{} {} is not valid WGSL:\n{}", module_path, name, err));
+                    }
+                }
+                let golden = golden_dir.join(format!("{}.{}.{}", module_path.replace("::", "."), name, ext));
+                if update {
+                    fs::create_dir_all(&golden_dir).unwrap();
+                    fs::write(&golden, &source).unwrap();
+                    continue
+                }
+                match fs::read_to_string(&golden) {
+                    Ok(expected) if expected == source => (),
+                    Ok(expected) => {
+                        let line = expected.lines().zip(source.lines()).position( | (a, b) | a != b)
+                            .unwrap_or(expected.lines().count().min(source.lines().count()));
+                        failures.push(format!("{} differs from line {}:\n  expected: {}\n  got:      {}",
+                            golden.display(),
+                            line + 1,
+                            expected.lines().nth(line).unwrap_or(""),
+                            source.lines().nth(line).unwrap_or("")
+                        ));
+                    }
+                    Err(_) => failures.push(format!("{} is missing", golden.display()))
+                }
+            }
+        }
+    }
+    assert!(count > 0, "no shaders found");
+    assert!(failures.is_empty(), "{}\nrerun with MAKEPAD_UPDATE_GOLDEN=1 if the change is intended", failures.join("\n"));
+}
+
+#[test]
+fn draw_shaders() {
+    check_golden(&[("makepad_draw", "draw")], "draw/src/shader/");
+}
+
+#[test]
+fn widget_shaders() {
+    check_golden(&[("makepad_draw", "draw"), ("makepad_widgets", "widgets")], "widgets/");
+}
+
+// the draw and widget shaders don't use closures or conditionals, so this covers those for WGSL
+#[test]
+fn wgsl_closures_and_textures() {
+    use makepad_shader_compiler::generate_wgsl;
+    let source = r#"
+use crate::makepad_draw::*;
+
+live_design!{
+    import makepad_draw::shader::std::*;
+    
+    DrawTest = {{DrawTest}} {
+        texture image: texture2d
+        
+        Shape = struct {
+            field d: float
+            fn apply(inout self, f: fn(d: float) -> float) {
+                self.d = f(self.d);
+            }
+        }
+        
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.circle(0.5, 0.5, self.radius);
+            let shape = Shape {d: sdf.shape};
+            let r = self.radius;
+            shape.apply( | d | mod(d, r) - 1.0);
+            let fade = self.pos.x > 0.5 ? max(self.pos, 0.5).x : 0.0;
+            return sample2d(self.image, self.pos) * sdf.fill(#f00) * shape.d * fade;
+        }
+    }
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawTest {
+    #[deref] draw_super: DrawQuad,
+    #[live] radius: f32,
+}
+"#;
+    let file_name = "test/src/draw_test.rs";
+    let (mut offline, _) = load_crates_with(&[("makepad_draw", "draw")], &[(file_name, "makepad_test::draw_test", source)]);
+    let draw_shader_ptr = offline.find_draw_shader(file_name, "DrawTest").unwrap();
+    let const_table = offline.analyse(draw_shader_ptr).unwrap();
+    let def = offline.draw_shader_def(draw_shader_ptr).unwrap();
+    let wgsl = generate_wgsl::generate_shader(def, &const_table, &offline.shader_registry).wgsl;
+    if let Err(err) = validate_wgsl(&wgsl) {
+        panic!("{}\n{}", wgsl, err);
+    }
+    for expected in ["fn closure_0_in_", "fn site_0_of_", "var ds_image: texture_2d<f32>", "textureSampleLevel", "select(", "fn mod_f32"] {
+        assert!(wgsl.contains(expected), "{} not in\n{}", expected, wgsl);
+    }
+}
+
+// LUTs and environment maps, which none of the draw or widget shaders use yet
+#[test]
+fn texture3d_and_cube() {
+    let source = r#"
+use crate::makepad_draw::*;
+
+live_design!{
+    DrawGraded = {{DrawGraded}} {
+        texture lut: texture3d
+        texture env: textureCube
+        
+        fn pixel(self) -> vec4 {
+            let reflected = sample_cube(self.env, normalize(vec3(self.pos - 0.5, 1.0)));
+            return sample3d(self.lut, reflected.xyz);
+        }
+    }
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawGraded {
+    #[deref] draw_super: DrawQuad,
+}
+"#;
+    let file_name = "test/src/draw_graded.rs";
+    let (mut offline, _) = load_crates_with(&[("makepad_draw", "draw")], &[(file_name, "makepad_test::draw_graded", source)]);
+    let draw_shader_ptr = offline.find_draw_shader(file_name, "DrawGraded").unwrap();
+    let const_table = offline.analyse(draw_shader_ptr).unwrap();
+    let def = offline.draw_shader_def(draw_shader_ptr).unwrap();
+    for (backend, output) in generate(def, &const_table, &offline.shader_registry) {
+        let expected: &[&str] = match backend {
+            "glsl" => &["uniform sampler3D ds_lut;", "uniform samplerCube ds_env;", "texture3D(sampler, pos)", "textureCube(sampler, dir)"],
+            "hlsl" => &["Texture3D ds_lut: register(t0);", "TextureCube ds_env: register(t1);", "float4 sample3d(Texture3D tex", "float4 sample_cube(TextureCube tex"],
+            "metal" => &["texture3d<float> ds_lut [[texture(0)]];", "texturecube<float> ds_env [[texture(1)]];", "float4 sample3d(texture3d<float> tex", "float4 sample_cube(texturecube<float> tex"],
+            _ => &["var ds_lut: texture_3d<f32>;", "var ds_env: texture_cube<f32>;", "fn sample3d(", "fn sample_cube("],
+        };
+        for expected in expected {
+            assert!(output.contains(expected), "{} not in {}\n{}", expected, backend, output);
+        }
+        assert!(!output.contains("sample2d"), "unused sample2d in {}\n{}", backend, output);
+        if backend == "wgsl" {
+            if let Err(err) = validate_wgsl(&output) {
+                panic!("{}\n{}", output, err);
+            }
+        }
+    }
+}
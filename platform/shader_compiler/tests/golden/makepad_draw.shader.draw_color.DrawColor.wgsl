@@ -0,0 +1,58 @@
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 1>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_rect_pos: vec2<f32>,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_draw_clip: vec4<f32>,
+    @location(4) ds_draw_depth: f32,
+    @location(5) pad_0: f32,
+    @location(6) ds_color: vec4<f32>,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_color: vec4<f32>,
+    @location(1) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_1_12_pixel() -> vec4<f32> {
+    return vec4<f32>((varyings.ds_color.rgb * varyings.ds_color.a), varyings.ds_color.a);
+}
+fn fn_1_9_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_1_11_vertex() -> vec4<f32> {
+    return fn_1_9_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_1_11_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_1_12_pixel();
+}
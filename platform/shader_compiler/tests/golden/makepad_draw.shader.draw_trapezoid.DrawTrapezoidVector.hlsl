@@ -0,0 +1,119 @@
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[6];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_a_xs: INSTA;
+    float4 ds_a_ys: INSTB;
+    float ds_chan: INSTC;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_chan: VARYA;
+    float2 ds_v_p0: VARYB;
+    float2 ds_v_p1: VARYC;
+    float2 ds_v_p2: VARYD;
+    float2 ds_v_p3: VARYE;
+    float2 ds_v_pixel: VARYF;
+};
+float4 consfn_vec4_vec2_float_float(float2 x0, float x1, float x2) {    return float4(x0[0], x0[1], x1, x2);}
+float2 fn_5_14_intersect_line_segment_with_horizontal_line(float2 var_p0_0, float2 var_p1_0, float var_y_0) {
+    return float2(lerp(var_p0_0.x, var_p1_0.x, ((var_y_0 - var_p0_0.y) / (var_p1_0.y - var_p0_0.y))), var_y_0);
+}
+float2 fn_5_13_intersect_line_segment_with_vertical_line(float2 var_p0_0, float2 var_p1_0, float var_x_0) {
+    return float2(var_x_0, lerp(var_p0_0.y, var_p1_0.y, ((var_x_0 - var_p0_0.x) / (var_p1_0.x - var_p0_0.x))));
+}
+float fn_5_15_compute_clamped_right_trapezoid_area(float2 var_p0_0, float2 var_p1_0, float2 var_p_min_0, float2 var_p_max_0) {
+    float var_x0_0 = clamp(var_p0_0.x, var_p_min_0.x, var_p_max_0.x);
+    float var_x1_0 = clamp(var_p1_0.x, var_p_min_0.x, var_p_max_0.x);
+    if((var_p0_0.x < var_p_min_0.x) && (var_p_min_0.x < var_p1_0.x)) {
+            (var_p0_0 = fn_5_13_intersect_line_segment_with_vertical_line (var_p0_0, var_p1_0, var_p_min_0.x));
+    }
+
+    if((var_p0_0.x < var_p_max_0.x) && (var_p_max_0.x < var_p1_0.x)) {
+            (var_p1_0 = fn_5_13_intersect_line_segment_with_vertical_line (var_p0_0, var_p1_0, var_p_max_0.x));
+    }
+
+    if((var_p0_0.y < var_p_min_0.y) && (var_p_min_0.y < var_p1_0.y)) {
+            (var_p0_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p0_0, var_p1_0, var_p_min_0.y));
+    }
+
+    if((var_p1_0.y < var_p_min_0.y) && (var_p_min_0.y < var_p0_0.y)) {
+            (var_p1_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p1_0, var_p0_0, var_p_min_0.y));
+    }
+
+    if((var_p0_0.y < var_p_max_0.y) && (var_p_max_0.y < var_p1_0.y)) {
+            (var_p1_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p0_0, var_p1_0, var_p_max_0.y));
+    }
+
+    if((var_p1_0.y < var_p_max_0.y) && (var_p_max_0.y < var_p0_0.y)) {
+            (var_p0_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p1_0, var_p0_0, var_p_max_0.y));
+    }
+
+    (var_p0_0 = clamp(var_p0_0, var_p_min_0, var_p_max_0));
+    (var_p1_0 = clamp(var_p1_0, var_p_min_0, var_p_max_0));
+    float var_h0_0 = (var_p_max_0.y - var_p0_0.y);
+    float var_h1_0 = (var_p_max_0.y - var_p1_0.y);
+    float var_a0_0 = ((var_p0_0.x - var_x0_0) * var_h0_0);
+    float var_a1_0 = (((var_p1_0.x - var_p0_0.x) * (var_h0_0 + var_h1_0)) * const_table[4].w);
+    float var_a2_0 = ((var_x1_0 - var_p1_0.x) * var_h1_0);
+    return ((var_a0_0 + var_a1_0) + var_a2_0);
+}
+float fn_5_16_compute_clamped_trapezoid_area(float2 var_p_min_0, float2 var_p_max_0, inout Varyings varyings) {
+    float var_a0_0 = fn_5_15_compute_clamped_right_trapezoid_area (varyings.ds_v_p0, varyings.ds_v_p1, var_p_min_0, var_p_max_0);
+    float var_a1_0 = fn_5_15_compute_clamped_right_trapezoid_area (varyings.ds_v_p2, varyings.ds_v_p3, var_p_min_0, var_p_max_0);
+    return (var_a0_0 - var_a1_0);
+}
+float4 fn_5_17_pixel(inout Varyings varyings) {
+    float2 var_p_min_0 = (varyings.ds_v_pixel.xy - const_table[1].x);
+    float2 var_p_max_0 = (varyings.ds_v_pixel.xy + const_table[1].y);
+    float var_t_area_0 = fn_5_16_compute_clamped_trapezoid_area (var_p_min_0, var_p_max_0, varyings);
+    if(varyings.ds_chan < const_table[1].z) {
+            return float4(var_t_area_0, const_table[1].w, const_table[2].x, const_table[2].y);
+    }
+
+    if(varyings.ds_chan < const_table[2].z) {
+            return float4(const_table[2].w, var_t_area_0, const_table[3].x, const_table[3].y);
+    }
+
+    if(varyings.ds_chan < const_table[3].z) {
+            return float4(const_table[3].w, const_table[4].x, var_t_area_0, const_table[4].y);
+    }
+
+    return float4(var_t_area_0, var_t_area_0, var_t_area_0, const_table[4].z);
+}
+float4 fn_5_18_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_pos_min_0 = float2(instances.ds_a_xs.x, min(instances.ds_a_ys.x, instances.ds_a_ys.y));
+    float2 var_pos_max_0 = float2(instances.ds_a_xs.y, max(instances.ds_a_ys.z, instances.ds_a_ys.w));
+    float2 var_pos_0 = lerp((var_pos_min_0 - const_table[0].x), (var_pos_max_0 + const_table[0].y), geometries.ds_geom_pos);
+    (varyings.ds_v_p0 = float2(instances.ds_a_xs.x, instances.ds_a_ys.x));
+    (varyings.ds_v_p1 = float2(instances.ds_a_xs.y, instances.ds_a_ys.y));
+    (varyings.ds_v_p2 = float2(instances.ds_a_xs.x, instances.ds_a_ys.z));
+    (varyings.ds_v_p3 = float2(instances.ds_a_xs.y, instances.ds_a_ys.w));
+    (varyings.ds_v_pixel = var_pos_0);
+    return mul(ds_camera_projection, consfn_vec4_vec2_float_float(var_pos_0, const_table[0].z, const_table[0].w));
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_chan = instances.ds_chan;
+    varyings.position = fn_5_18_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_5_17_pixel(varyings);
+}
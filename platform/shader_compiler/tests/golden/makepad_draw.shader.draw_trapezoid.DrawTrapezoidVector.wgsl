@@ -0,0 +1,123 @@
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 6>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_a_xs: vec2<f32>,
+    @location(2) ds_a_ys: vec4<f32>,
+    @location(3) ds_chan: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_chan: f32,
+    @location(1) ds_v_p0: vec2<f32>,
+    @location(2) ds_v_p1: vec2<f32>,
+    @location(3) ds_v_p2: vec2<f32>,
+    @location(4) ds_v_p3: vec2<f32>,
+    @location(5) ds_v_pixel: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_5_14_intersect_line_segment_with_horizontal_line(var_p0_0: vec2<f32>, var_p1_0: vec2<f32>, var_y_0: f32) -> vec2<f32> {
+    return vec2<f32>(mix(var_p0_0.x, var_p1_0.x, ((var_y_0 - var_p0_0.y) / (var_p1_0.y - var_p0_0.y))), var_y_0);
+}
+fn fn_5_13_intersect_line_segment_with_vertical_line(var_p0_0: vec2<f32>, var_p1_0: vec2<f32>, var_x_0: f32) -> vec2<f32> {
+    return vec2<f32>(var_x_0, mix(var_p0_0.y, var_p1_0.y, ((var_x_0 - var_p0_0.x) / (var_p1_0.x - var_p0_0.x))));
+}
+fn fn_5_15_compute_clamped_right_trapezoid_area(param_p0_0: vec2<f32>, param_p1_0: vec2<f32>, var_p_min_0: vec2<f32>, var_p_max_0: vec2<f32>) -> f32 {    var var_p0_0: vec2<f32> = param_p0_0;    var var_p1_0: vec2<f32> = param_p1_0;    var var_x0_0: f32 = clamp(var_p0_0.x, var_p_min_0.x, var_p_max_0.x);
+    var var_x1_0: f32 = clamp(var_p1_0.x, var_p_min_0.x, var_p_max_0.x);
+    if((var_p0_0.x < var_p_min_0.x) && (var_p_min_0.x < var_p1_0.x)) {
+            var_p0_0 = fn_5_13_intersect_line_segment_with_vertical_line (var_p0_0, var_p1_0, var_p_min_0.x);
+    }
+
+    if((var_p0_0.x < var_p_max_0.x) && (var_p_max_0.x < var_p1_0.x)) {
+            var_p1_0 = fn_5_13_intersect_line_segment_with_vertical_line (var_p0_0, var_p1_0, var_p_max_0.x);
+    }
+
+    if((var_p0_0.y < var_p_min_0.y) && (var_p_min_0.y < var_p1_0.y)) {
+            var_p0_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p0_0, var_p1_0, var_p_min_0.y);
+    }
+
+    if((var_p1_0.y < var_p_min_0.y) && (var_p_min_0.y < var_p0_0.y)) {
+            var_p1_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p1_0, var_p0_0, var_p_min_0.y);
+    }
+
+    if((var_p0_0.y < var_p_max_0.y) && (var_p_max_0.y < var_p1_0.y)) {
+            var_p1_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p0_0, var_p1_0, var_p_max_0.y);
+    }
+
+    if((var_p1_0.y < var_p_max_0.y) && (var_p_max_0.y < var_p0_0.y)) {
+            var_p0_0 = fn_5_14_intersect_line_segment_with_horizontal_line (var_p1_0, var_p0_0, var_p_max_0.y);
+    }
+
+    var_p0_0 = clamp(var_p0_0, var_p_min_0, var_p_max_0);
+    var_p1_0 = clamp(var_p1_0, var_p_min_0, var_p_max_0);
+    var var_h0_0: f32 = (var_p_max_0.y - var_p0_0.y);
+    var var_h1_0: f32 = (var_p_max_0.y - var_p1_0.y);
+    var var_a0_0: f32 = ((var_p0_0.x - var_x0_0) * var_h0_0);
+    var var_a1_0: f32 = (((var_p1_0.x - var_p0_0.x) * (var_h0_0 + var_h1_0)) * const_table[4].w);
+    var var_a2_0: f32 = ((var_x1_0 - var_p1_0.x) * var_h1_0);
+    return ((var_a0_0 + var_a1_0) + var_a2_0);
+}
+fn fn_5_16_compute_clamped_trapezoid_area(var_p_min_0: vec2<f32>, var_p_max_0: vec2<f32>) -> f32 {
+    var var_a0_0: f32 = fn_5_15_compute_clamped_right_trapezoid_area (varyings.ds_v_p0, varyings.ds_v_p1, var_p_min_0, var_p_max_0);
+    var var_a1_0: f32 = fn_5_15_compute_clamped_right_trapezoid_area (varyings.ds_v_p2, varyings.ds_v_p3, var_p_min_0, var_p_max_0);
+    return (var_a0_0 - var_a1_0);
+}
+fn fn_5_17_pixel() -> vec4<f32> {
+    var var_p_min_0: vec2<f32> = (varyings.ds_v_pixel.xy - const_table[1].x);
+    var var_p_max_0: vec2<f32> = (varyings.ds_v_pixel.xy + const_table[1].y);
+    var var_t_area_0: f32 = fn_5_16_compute_clamped_trapezoid_area (var_p_min_0, var_p_max_0);
+    if(varyings.ds_chan < const_table[1].z) {
+            return vec4<f32>(var_t_area_0, const_table[1].w, const_table[2].x, const_table[2].y);
+    }
+
+    if(varyings.ds_chan < const_table[2].z) {
+            return vec4<f32>(const_table[2].w, var_t_area_0, const_table[3].x, const_table[3].y);
+    }
+
+    if(varyings.ds_chan < const_table[3].z) {
+            return vec4<f32>(const_table[3].w, const_table[4].x, var_t_area_0, const_table[4].y);
+    }
+
+    return vec4<f32>(var_t_area_0, var_t_area_0, var_t_area_0, const_table[4].z);
+}
+fn fn_5_18_vertex() -> vec4<f32> {
+    var var_pos_min_0: vec2<f32> = vec2<f32>(instances.ds_a_xs.x, min(instances.ds_a_ys.x, instances.ds_a_ys.y));
+    var var_pos_max_0: vec2<f32> = vec2<f32>(instances.ds_a_xs.y, max(instances.ds_a_ys.z, instances.ds_a_ys.w));
+    var var_pos_0: vec2<f32> = mix((var_pos_min_0 - const_table[0].x), (var_pos_max_0 + const_table[0].y), geometries.ds_geom_pos);
+    varyings.ds_v_p0 = vec2<f32>(instances.ds_a_xs.x, instances.ds_a_ys.x);
+    varyings.ds_v_p1 = vec2<f32>(instances.ds_a_xs.y, instances.ds_a_ys.y);
+    varyings.ds_v_p2 = vec2<f32>(instances.ds_a_xs.x, instances.ds_a_ys.z);
+    varyings.ds_v_p3 = vec2<f32>(instances.ds_a_xs.y, instances.ds_a_ys.w);
+    varyings.ds_v_pixel = var_pos_0;
+    return (uniforms_pass.ds_camera_projection * vec4<f32>(var_pos_0, const_table[0].z, const_table[0].w));
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_chan = instances.ds_chan;
+    varyings.position = fn_5_18_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_5_17_pixel();
+}
@@ -0,0 +1,62 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d_rt(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[2];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_user : register(b5) {
+    float ds_marked;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_image: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    float4 ds_color: INSTF;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_pos: VARYA;
+    float2 ds_scale: VARYB;
+    float2 ds_shift: VARYC;
+};
+float4 fn_7_353_pixel(inout Varyings varyings) {
+    return (sample2d_rt(ds_image, ((varyings.ds_pos * varyings.ds_scale) + varyings.ds_shift)) + float4(ds_marked, const_table[0].y, const_table[0].z, const_table[0].w));
+}
+float4 fn_7_350_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_7_352_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float var_dpi_0 = ds_dpi_factor;
+    float2 var_ceil_size_0 = (ceil((instances.ds_rect_size * var_dpi_0)) / var_dpi_0);
+    float2 var_floor_pos_0 = (floor((instances.ds_rect_pos * var_dpi_0)) / var_dpi_0);
+    (varyings.ds_scale = (instances.ds_rect_size / var_ceil_size_0));
+    (varyings.ds_shift = ((instances.ds_rect_pos - var_floor_pos_0) / var_ceil_size_0));
+    return fn_7_350_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.position = fn_7_352_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_7_353_pixel(varyings);
+}
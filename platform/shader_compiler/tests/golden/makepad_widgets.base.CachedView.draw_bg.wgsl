@@ -0,0 +1,70 @@
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 2>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_user {
+    ds_marked: f32,
+};
+@group(0) @binding(4) var<uniform> uniforms_user: Uniforms_user;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(5) var<uniform> uniforms_view: Uniforms_view;
+@group(0) @binding(6) var ds_image: texture_2d<f32>;
+@group(0) @binding(7) var default_texture_sampler: sampler;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_rect_pos: vec2<f32>,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_draw_clip: vec4<f32>,
+    @location(4) ds_draw_depth: f32,
+    @location(5) pad_0: f32,
+    @location(6) ds_color: vec4<f32>,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_pos: vec2<f32>,
+    @location(1) ds_scale: vec2<f32>,
+    @location(2) ds_shift: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn sample2d_rt(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}
+fn fn_7_353_pixel() -> vec4<f32> {
+    return (sample2d_rt(ds_image, ((varyings.ds_pos * varyings.ds_scale) + varyings.ds_shift)) + vec4<f32>(uniforms_user.ds_marked, const_table[0].y, const_table[0].z, const_table[0].w));
+}
+fn fn_7_350_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_7_352_vertex() -> vec4<f32> {
+    var var_dpi_0: f32 = uniforms_pass.ds_dpi_factor;
+    var var_ceil_size_0: vec2<f32> = (ceil((instances.ds_rect_size * var_dpi_0)) / var_dpi_0);
+    var var_floor_pos_0: vec2<f32> = (floor((instances.ds_rect_pos * var_dpi_0)) / var_dpi_0);
+    varyings.ds_scale = (instances.ds_rect_size / var_ceil_size_0);
+    varyings.ds_shift = ((instances.ds_rect_pos - var_floor_pos_0) / var_ceil_size_0);
+    return fn_7_350_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.position = fn_7_352_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_7_353_pixel();
+}
@@ -0,0 +1,165 @@
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct_6_28 consfn_struct_6_28(
+float2 f_pos, float4 f_result, float2 f_last_pos, float2 f_start_pos, float f_shape, float f_clip, float f_has_clip, float f_old_shape, float f_blur, float f_aa, float f_scale_factor, float f_dist){;
+struct_6_28 r;
+r.f_pos = f_pos;
+r.f_result = f_result;
+r.f_last_pos = f_last_pos;
+r.f_start_pos = f_start_pos;
+r.f_shape = f_shape;
+r.f_clip = f_clip;
+r.f_has_clip = f_has_clip;
+r.f_old_shape = f_old_shape;
+r.f_blur = f_blur;
+r.f_aa = f_aa;
+r.f_scale_factor = f_scale_factor;
+r.f_dist = f_dist;
+return r;
+}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[10];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_border_width: INSTA;
+    float4 ds_border_color: INSTB;
+    float4 ds_inset: INSTC;
+    float ds_radius: INSTD;
+    float2 ds_rect_pos: INSTE;
+    float2 ds_rect_size: INSTF;
+    float4 ds_draw_clip: INSTG;
+    float ds_draw_depth: INSTH;
+    float ds_0: INSTI;
+    float4 ds_color: INSTJ;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_border_width: VARYA;
+    float4 ds_border_color: VARYB;
+    float4 ds_inset: VARYC;
+    float ds_radius: VARYD;
+    float2 ds_rect_size: VARYE;
+    float4 ds_color: VARYF;
+    float2 ds_pos: VARYG;
+};
+float2 consfn_vec2_float(float x) {    return float2(x, x);}
+float4 consfn_vec4_float(float x) {    return float4(x, x, x, x);}
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+float4 fn_7_277_get_border_color(inout Varyings varyings) {
+    return varyings.ds_border_color;
+}
+float4 fn_7_276_get_color(inout Varyings varyings) {
+    return varyings.ds_color;
+}
+void fn_6_61_circle(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[7].z, const_table[7].w);
+    float var_wb_0 = const_table[8].x;
+    if(var_self_0.f_blur > const_table[8].y) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[8].z, const_table[8].w));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(inout struct_6_28 var_self_0, float4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[7].y - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(inout struct_6_28 var_self_0, float4 var_color_0, float var_width_0) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[6].z));
+    (var_self_0.f_clip = -const_table[6].w);
+    (var_self_0.f_has_clip = const_table[7].x);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[5].z - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[5].w) {
+            float var_f2_0 = (const_table[6].x - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[6].y - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0) {
+    return (const_table[5].y / length(float2(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_6_28 fn_6_42_viewport(float2 var_pos_0) {
+    return consfn_struct_6_28(var_pos_0,consfn_vec4_float(const_table[2].w),consfn_vec2_float(const_table[3].x),consfn_vec2_float(const_table[3].y),const_table[3].z,-const_table[3].w,const_table[4].x,const_table[4].y,const_table[4].z,fn_6_41_antialias (var_pos_0),const_table[4].w,const_table[5].x);
+}
+float4 fn_7_271_pixel(inout Varyings varyings) {
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    if(varyings.ds_radius > const_table[0].y) {
+            fn_6_61_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[0].z), (varyings.ds_rect_size.y * const_table[0].w), varyings.ds_radius);
+    }else{
+            fn_6_61_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[1].x), (varyings.ds_rect_size.y * const_table[1].y), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[1].z * varyings.ds_border_width))) * const_table[1].w), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[2].x * varyings.ds_border_width))) * const_table[2].y)));
+    }
+
+    fn_6_48_fill_keep (var_sdf_0, fn_7_276_get_color (varyings));
+    if(varyings.ds_border_width > const_table[2].z) {
+            fn_6_53_stroke (var_sdf_0, fn_7_277_get_border_color (varyings), varyings.ds_border_width);
+    }
+
+    return var_sdf_0.f_result;
+}
+float4 fn_7_268_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_7_270_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_7_268_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), 0.0, float2(0.0,0.0), float4(0.0,0.0,0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_7_270_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_7_271_pixel(varyings);
+}
@@ -0,0 +1,167 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_border_width;
+    packed_float4 ds_border_color;
+    packed_float4 ds_inset;
+    float ds_radius;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    packed_float4 ds_color;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_border_width;
+    float4 ds_border_color;
+    float4 ds_inset;
+    float ds_radius;
+    float2 ds_rect_size;
+    float4 ds_color;
+    float2 ds_pos;
+};
+float4 fn_7_277_get_border_color(constant const float *const_table, thread Varyings &varyings) {
+    return varyings.ds_border_color;
+}
+float4 fn_7_276_get_color(constant const float *const_table, thread Varyings &varyings) {
+    return varyings.ds_color;
+}
+void fn_6_61_circle(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[30], const_table[31]);
+    float var_wb_0 = const_table[32];
+    if(var_self_0.f_blur > const_table[33]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[34], const_table[35]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[29] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[26]));
+    (var_self_0.f_clip = -const_table[27]);
+    (var_self_0.f_has_clip = const_table[28]);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[22] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[23]) {
+            float var_f2_0 = (const_table[24] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[25] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[21] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[11]),float2(const_table[12]),float2(const_table[13]),const_table[14],-const_table[15],const_table[16],const_table[17],const_table[18],fn_6_41_antialias (var_pos_0, const_table),const_table[19],const_table[20]};
+}
+float4 fn_7_271_pixel(constant const float *const_table, thread Varyings &varyings) {
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    if(varyings.ds_radius > const_table[1]) {
+            fn_6_61_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[2]), (varyings.ds_rect_size.y * const_table[3]), varyings.ds_radius, const_table);
+    }else{
+            fn_6_61_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[4]), (varyings.ds_rect_size.y * const_table[5]), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[6] * varyings.ds_border_width))) * const_table[7]), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[8] * varyings.ds_border_width))) * const_table[9])), const_table);
+    }
+
+    fn_6_48_fill_keep (var_sdf_0, fn_7_276_get_color (const_table, varyings), const_table);
+    if(varyings.ds_border_width > const_table[10]) {
+            fn_6_53_stroke (var_sdf_0, fn_7_277_get_border_color (const_table, varyings), varyings.ds_border_width, const_table);
+    }
+
+    return var_sdf_0.f_result;
+}
+float4 fn_7_268_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_7_270_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_7_268_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_7_270_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_7_271_pixel(const_table, varyings);
+}
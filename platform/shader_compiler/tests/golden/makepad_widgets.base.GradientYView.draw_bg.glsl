@@ -0,0 +1,146 @@
+// vertex
+uniform float const_table[8];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute vec3 packed_instance_4;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec3 packed_varying_2;
+
+vec4 ds_color2=vec4(0.0);
+float ds_dither=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+vec4 ds_color=vec4(0.0);
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_7_331_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_7_333_vertex() {
+    return fn_7_331_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_color2.xyzw = packed_instance_0.xyzw;
+    ds_dither = packed_instance_1.x;
+    ds_rect_pos.xy = packed_instance_1.yz;
+    ds_rect_size.x = packed_instance_1.w;
+    ds_rect_size.y = packed_instance_2.x;
+    ds_draw_clip.xyz = packed_instance_2.yzw;
+    ds_draw_clip.w = packed_instance_3.x;
+    ds_draw_depth = packed_instance_3.y;
+    ds_0 = packed_instance_3.z;
+    ds_color.x = packed_instance_3.w;
+    ds_color.yzw = packed_instance_4.xyz;
+
+    gl_Position = fn_7_333_vertex();
+
+    packed_varying_0.xyzw = ds_color2.xyzw;
+    packed_varying_1.x = ds_dither;
+    packed_varying_1.yzw = ds_color.xyz;
+    packed_varying_2.x = ds_color.w;
+    packed_varying_2.yz = ds_pos.xy;
+}
+// pixel
+uniform float const_table[8];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec3 packed_varying_2;
+
+vec4 ds_color2=vec4(0.0);
+float ds_dither=0.0;
+vec4 ds_color=vec4(0.0);
+vec2 ds_pos=vec2(0.0);
+
+float fn_6_12_random_2d(vec2 var_v_0) {
+    return fract((sin(dot(var_v_0.xy, vec2(const_table[2], const_table[3]))) * const_table[4]));
+}
+
+vec4 fn_7_337_get_color() {
+    float var_dither_0 = ((fn_6_12_random_2d (ds_pos.xy) * const_table[1]) * ds_dither);
+    return mix(ds_color, ds_color2, (ds_pos.y + var_dither_0));
+}
+
+vec4 fn_6_15_premul(vec4 var_v_0) {
+    return vec4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+
+vec4 fn_7_334_pixel() {
+    return fn_6_15_premul (fn_7_337_get_color ());
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_color2.xyzw = packed_varying_0.xyzw;
+    ds_dither = packed_varying_1.x;
+    ds_color.xyz = packed_varying_1.yzw;
+    ds_color.w = packed_varying_2.x;
+    ds_pos.xy = packed_varying_2.yz;
+
+    gl_FragColor = fn_7_334_pixel();
+}
@@ -0,0 +1,68 @@
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[2];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float4 ds_color2: INSTA;
+    float ds_dither: INSTB;
+    float2 ds_rect_pos: INSTC;
+    float2 ds_rect_size: INSTD;
+    float4 ds_draw_clip: INSTE;
+    float ds_draw_depth: INSTF;
+    float ds_0: INSTG;
+    float4 ds_color: INSTH;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float4 ds_color2: VARYA;
+    float ds_dither: VARYB;
+    float4 ds_color: VARYC;
+    float2 ds_pos: VARYD;
+};
+float fn_6_12_random_2d(float2 var_v_0) {
+    return frac((sin(dot(var_v_0.xy, float2(const_table[0].z, const_table[0].w))) * const_table[1].x));
+}
+float4 fn_7_337_get_color(inout Varyings varyings) {
+    float var_dither_0 = ((fn_6_12_random_2d (varyings.ds_pos.xy) * const_table[0].y) * varyings.ds_dither);
+    return lerp(varyings.ds_color, varyings.ds_color2, (varyings.ds_pos.y + var_dither_0));
+}
+float4 fn_6_15_premul(float4 var_v_0) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_7_334_pixel(inout Varyings varyings) {
+    return fn_6_15_premul (fn_7_337_get_color (varyings));
+}
+float4 fn_7_331_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_7_333_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_7_331_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), 0.0, float4(0.0,0.0,0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_color2 = instances.ds_color2;
+    varyings.ds_dither = instances.ds_dither;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_7_333_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_7_334_pixel(varyings);
+}
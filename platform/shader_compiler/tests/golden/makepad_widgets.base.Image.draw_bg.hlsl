@@ -0,0 +1,70 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[1];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_image: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_opacity: INSTA;
+    float2 ds_image_scale: INSTB;
+    float2 ds_image_pan: INSTC;
+    float2 ds_rect_pos: INSTD;
+    float2 ds_rect_size: INSTE;
+    float4 ds_draw_clip: INSTF;
+    float ds_draw_depth: INSTG;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_opacity: VARYA;
+    float2 ds_image_scale: VARYB;
+    float2 ds_image_pan: VARYC;
+    float2 ds_pos: VARYD;
+};
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+float4 fn_7_62_get_color_scale_pan(float2 var_scale_0, float2 var_pan_0, inout Varyings varyings) {
+    return sample2d(ds_image, ((varyings.ds_pos * var_scale_0) + var_pan_0)).xyzw;
+}
+float4 fn_7_63_get_color(inout Varyings varyings) {
+    return fn_7_62_get_color_scale_pan (varyings.ds_image_scale, varyings.ds_image_pan, varyings);
+}
+float4 fn_6_15_premul(float4 var_v_0) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_7_57_pixel(inout Varyings varyings) {
+    float4 var_color_0 = fn_7_63_get_color (varyings);
+    return fn_6_15_premul (consfn_vec4_vec3_float(var_color_0.xyz, (var_color_0.w * varyings.ds_opacity)));
+}
+float4 fn_7_54_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_7_56_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_7_54_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_opacity = instances.ds_opacity;
+    varyings.ds_image_scale = instances.ds_image_scale;
+    varyings.ds_image_pan = instances.ds_image_pan;
+    varyings.position = fn_7_56_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_7_57_pixel(varyings);
+}
@@ -0,0 +1,76 @@
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 1>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+@group(0) @binding(5) var ds_image: texture_2d<f32>;
+@group(0) @binding(6) var default_texture_sampler: sampler;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_opacity: f32,
+    @location(2) ds_image_scale: vec2<f32>,
+    @location(3) ds_image_pan: vec2<f32>,
+    @location(4) ds_rect_pos: vec2<f32>,
+    @location(5) ds_rect_size: vec2<f32>,
+    @location(6) ds_draw_clip: vec4<f32>,
+    @location(7) ds_draw_depth: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_opacity: f32,
+    @location(1) ds_image_scale: vec2<f32>,
+    @location(2) ds_image_pan: vec2<f32>,
+    @location(3) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn sample2d(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}
+fn fn_7_62_get_color_scale_pan(var_scale_0: vec2<f32>, var_pan_0: vec2<f32>) -> vec4<f32> {
+    return sample2d(ds_image, ((varyings.ds_pos * var_scale_0) + var_pan_0)).xyzw;
+}
+fn fn_7_63_get_color() -> vec4<f32> {
+    return fn_7_62_get_color_scale_pan (varyings.ds_image_scale, varyings.ds_image_pan);
+}
+fn fn_6_15_premul(var_v_0: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+fn fn_7_57_pixel() -> vec4<f32> {
+    var var_color_0: vec4<f32> = fn_7_63_get_color ();
+    return fn_6_15_premul (vec4<f32>(var_color_0.xyz, (var_color_0.w * varyings.ds_opacity)));
+}
+fn fn_7_54_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_7_56_vertex() -> vec4<f32> {
+    return fn_7_54_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_opacity = instances.ds_opacity;
+    varyings.ds_image_scale = instances.ds_image_scale;
+    varyings.ds_image_pan = instances.ds_image_pan;
+    varyings.position = fn_7_56_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_7_57_pixel();
+}
@@ -0,0 +1,254 @@
+// vertex
+uniform float const_table[52];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+uniform sampler2D ds_image;
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute float packed_instance_4;
+
+varying vec4 packed_varying_0;
+varying vec3 packed_varying_1;
+
+float ds_rotation=0.0;
+float ds_opacity=0.0;
+float ds_scale=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+vec4 ds_color=vec4(0.0);
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec2 fn_7_85_rotation_vertex_expansion(float var_rotation_0, float var_w_0, float var_h_0) {
+    float var_horizontal_expansion_0 = ((((abs(cos(var_rotation_0)) * var_w_0) + (abs(sin(var_rotation_0)) * var_h_0)) / var_w_0) - const_table[44]);
+    float var_vertical_expansion_0 = ((((abs(sin(var_rotation_0)) * var_w_0) + (abs(cos(var_rotation_0)) * var_h_0)) / var_h_0) - const_table[45]);
+    return vec2(var_horizontal_expansion_0, var_vertical_expansion_0);
+}
+
+vec4 fn_7_79_vertex() {
+    vec2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (ds_rotation, ds_rect_size.x, ds_rect_size.y);
+    vec2 var_adjusted_pos_0 = vec2((ds_rect_pos.x - ((ds_rect_size.x * var_rot_expansion_0.x) / const_table[0])), (ds_rect_pos.y - ((ds_rect_size.y * var_rot_expansion_0.y) / const_table[1])));
+    vec2 var_expanded_size_0 = vec2((ds_rect_size.x * (ds_scale + var_rot_expansion_0.x)), (ds_rect_size.y * (ds_scale + var_rot_expansion_0.y)));
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_expanded_size_0) + var_adjusted_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_adjusted_pos_0) / ds_rect_size));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[2]))));
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_rotation = packed_instance_0.x;
+    ds_opacity = packed_instance_0.y;
+    ds_scale = packed_instance_0.z;
+    ds_rect_pos.x = packed_instance_0.w;
+    ds_rect_pos.y = packed_instance_1.x;
+    ds_rect_size.xy = packed_instance_1.yz;
+    ds_draw_clip.x = packed_instance_1.w;
+    ds_draw_clip.yzw = packed_instance_2.xyz;
+    ds_draw_depth = packed_instance_2.w;
+    ds_0 = packed_instance_3.x;
+    ds_color.xyz = packed_instance_3.yzw;
+    ds_color.w = packed_instance_4;
+
+    gl_Position = fn_7_79_vertex();
+
+    packed_varying_0.x = ds_rotation;
+    packed_varying_0.y = ds_opacity;
+    packed_varying_0.z = ds_scale;
+    packed_varying_0.w = ds_rect_size.x;
+    packed_varying_1.x = ds_rect_size.y;
+    packed_varying_1.yz = ds_pos.xy;
+}
+// pixel
+uniform float const_table[52];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+uniform sampler2D ds_image;
+
+
+
+varying vec4 packed_varying_0;
+varying vec3 packed_varying_1;
+
+float ds_rotation=0.0;
+float ds_opacity=0.0;
+float ds_scale=0.0;
+vec2 ds_rect_size=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+vec2 fn_7_86_rotate_2d_from_center(vec2 var_coord_0, float var_a_0, vec2 var_size_0) {
+    float var_cos_a_0 = cos(-var_a_0);
+    float var_sin_a_0 = sin(-var_a_0);
+    vec2 var_centered_coord_0 = (var_coord_0 - vec2(const_table[46], const_table[47]));
+    vec2 var_denorm_coord_0 = vec2(var_centered_coord_0.x, ((var_centered_coord_0.y * var_size_0.y) / var_size_0.x));
+    vec2 var_demorm_rotated_0 = vec2(((var_denorm_coord_0.x * var_cos_a_0) - (var_denorm_coord_0.y * var_sin_a_0)), ((var_denorm_coord_0.x * var_sin_a_0) + (var_denorm_coord_0.y * var_cos_a_0)));
+    vec2 var_rotated_0 = vec2(var_demorm_rotated_0.x, ((var_demorm_rotated_0.y * var_size_0.x) / var_size_0.y));
+    return (var_rotated_0 + vec2(const_table[48], const_table[49]));
+}
+
+vec2 fn_7_85_rotation_vertex_expansion(float var_rotation_0, float var_w_0, float var_h_0) {
+    float var_horizontal_expansion_0 = ((((abs(cos(var_rotation_0)) * var_w_0) + (abs(sin(var_rotation_0)) * var_h_0)) / var_w_0) - const_table[44]);
+    float var_vertical_expansion_0 = ((((abs(sin(var_rotation_0)) * var_w_0) + (abs(cos(var_rotation_0)) * var_h_0)) / var_h_0) - const_table[45]);
+    return vec2(var_horizontal_expansion_0, var_vertical_expansion_0);
+}
+
+vec4 fn_7_87_get_color() {
+    vec2 var_rot_padding_0 = (fn_7_85_rotation_vertex_expansion (ds_rotation, ds_rect_size.x, ds_rect_size.y) / const_table[40]);
+    vec2 var_current_pos_0 = (ds_pos.xy - var_rot_padding_0);
+    vec2 var_original_pos_0 = fn_7_86_rotate_2d_from_center (var_current_pos_0, ds_rotation, ds_rect_size);
+    vec2 var_scaled_pos_0 = (var_original_pos_0 / ds_scale);
+    vec4 var_color_0 = sample2d(ds_image, var_scaled_pos_0).xyzw;
+    vec4 var_faded_color_0 = (var_color_0 * vec4(const_table[41], const_table[42], const_table[43], ds_opacity));
+    return var_faded_color_0;
+}
+
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_size_0 = vec2((const_table[33] * var_w_0), (const_table[34] * var_h_0));
+    vec2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2((const_table[35] * var_r_0), (const_table[36] * var_r_0)).xy)), vec2(const_table[37], const_table[38]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[39] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[27], const_table[28]);
+    float var_wb_0 = const_table[29];
+    if(var_self_0.f_blur > const_table[30]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[31], const_table[32]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_50_fill_keep_premul(inout struct_6_28 var_self_0, vec4 var_source_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[23] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[24]) {
+            float var_f2_0 = (const_table[25] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[26] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_51_fill_premul(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    fn_6_50_fill_keep_premul (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[20]));
+    (var_self_0.f_clip = -const_table[21]);
+    (var_self_0.f_has_clip = const_table[22]);
+    return var_self_0.f_result;
+}
+
+void fn_6_44_rotate(inout struct_6_28 var_self_0, float var_a_0, float var_x_0, float var_y_0) {
+    float var_ca_0 = cos(-var_a_0);
+    float var_sa_0 = sin(-var_a_0);
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    (var_self_0.f_pos = (vec2(((var_p_0.x * var_ca_0) - (var_p_0.y * var_sa_0)), ((var_p_0.x * var_sa_0) + (var_p_0.y * var_ca_0))) + vec2(var_x_0, var_y_0)));
+}
+
+vec2 fn_6_43_translate(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_pos -= vec2(var_x_0, var_y_0));
+    return var_self_0.f_pos;
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[19] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[9]),vec2(const_table[10]),vec2(const_table[11]),const_table[12],-const_table[13],const_table[14],const_table[15],const_table[16],fn_6_41_antialias (var_pos_0),const_table[17],const_table[18]);
+}
+
+vec4 fn_6_15_premul(vec4 var_v_0) {
+    return vec4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+
+vec4 fn_7_80_pixel() {
+    vec2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (ds_rotation, ds_rect_size.x, ds_rect_size.y);
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * ds_rect_size));
+    vec2 var_translation_offset_0 = vec2(((ds_rect_size.x * var_rot_expansion_0.x) / const_table[3]), (((ds_rect_size.y * ds_scale) * var_rot_expansion_0.y) / const_table[4]));
+    fn_6_43_translate (var_sdf_0, var_translation_offset_0.x, var_translation_offset_0.y);
+    vec2 var_center_0 = (ds_rect_size * const_table[5]);
+    fn_6_44_rotate (var_sdf_0, ds_rotation, var_center_0.x, var_center_0.y);
+    vec2 var_scaled_size_0 = (ds_rect_size * ds_scale);
+    fn_6_64_box (var_sdf_0, const_table[6], const_table[7], var_scaled_size_0.x, var_scaled_size_0.y, const_table[8]);
+    fn_6_51_fill_premul (var_sdf_0, fn_6_15_premul (fn_7_87_get_color ()));
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_rotation = packed_varying_0.x;
+    ds_opacity = packed_varying_0.y;
+    ds_scale = packed_varying_0.z;
+    ds_rect_size.x = packed_varying_0.w;
+    ds_rect_size.y = packed_varying_1.x;
+    ds_pos.xy = packed_varying_1.yz;
+
+    gl_FragColor = fn_7_80_pixel();
+}
@@ -0,0 +1,181 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct_6_28 consfn_struct_6_28(
+float2 f_pos, float4 f_result, float2 f_last_pos, float2 f_start_pos, float f_shape, float f_clip, float f_has_clip, float f_old_shape, float f_blur, float f_aa, float f_scale_factor, float f_dist){;
+struct_6_28 r;
+r.f_pos = f_pos;
+r.f_result = f_result;
+r.f_last_pos = f_last_pos;
+r.f_start_pos = f_start_pos;
+r.f_shape = f_shape;
+r.f_clip = f_clip;
+r.f_has_clip = f_has_clip;
+r.f_old_shape = f_old_shape;
+r.f_blur = f_blur;
+r.f_aa = f_aa;
+r.f_scale_factor = f_scale_factor;
+r.f_dist = f_dist;
+return r;
+}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[13];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_image: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_rotation: INSTA;
+    float ds_opacity: INSTB;
+    float ds_scale: INSTC;
+    float2 ds_rect_pos: INSTD;
+    float2 ds_rect_size: INSTE;
+    float4 ds_draw_clip: INSTF;
+    float ds_draw_depth: INSTG;
+    float ds_0: INSTH;
+    float4 ds_color: INSTI;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_rotation: VARYA;
+    float ds_opacity: VARYB;
+    float ds_scale: VARYC;
+    float2 ds_rect_size: VARYD;
+    float2 ds_pos: VARYE;
+};
+float2 consfn_vec2_float(float x) {    return float2(x, x);}
+float4 consfn_vec4_float(float x) {    return float4(x, x, x, x);}
+float2 fn_7_86_rotate_2d_from_center(float2 var_coord_0, float var_a_0, float2 var_size_0) {
+    float var_cos_a_0 = cos(-var_a_0);
+    float var_sin_a_0 = sin(-var_a_0);
+    float2 var_centered_coord_0 = (var_coord_0 - float2(const_table[11].z, const_table[11].w));
+    float2 var_denorm_coord_0 = float2(var_centered_coord_0.x, ((var_centered_coord_0.y * var_size_0.y) / var_size_0.x));
+    float2 var_demorm_rotated_0 = float2(((var_denorm_coord_0.x * var_cos_a_0) - (var_denorm_coord_0.y * var_sin_a_0)), ((var_denorm_coord_0.x * var_sin_a_0) + (var_denorm_coord_0.y * var_cos_a_0)));
+    float2 var_rotated_0 = float2(var_demorm_rotated_0.x, ((var_demorm_rotated_0.y * var_size_0.x) / var_size_0.y));
+    return (var_rotated_0 + float2(const_table[12].x, const_table[12].y));
+}
+float2 fn_7_85_rotation_vertex_expansion(float var_rotation_0, float var_w_0, float var_h_0) {
+    float var_horizontal_expansion_0 = ((((abs(cos(var_rotation_0)) * var_w_0) + (abs(sin(var_rotation_0)) * var_h_0)) / var_w_0) - const_table[11].x);
+    float var_vertical_expansion_0 = ((((abs(sin(var_rotation_0)) * var_w_0) + (abs(cos(var_rotation_0)) * var_h_0)) / var_h_0) - const_table[11].y);
+    return float2(var_horizontal_expansion_0, var_vertical_expansion_0);
+}
+float4 fn_7_87_get_color(inout Varyings varyings) {
+    float2 var_rot_padding_0 = (fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y) / const_table[10].x);
+    float2 var_current_pos_0 = (varyings.ds_pos.xy - var_rot_padding_0);
+    float2 var_original_pos_0 = fn_7_86_rotate_2d_from_center (var_current_pos_0, varyings.ds_rotation, varyings.ds_rect_size);
+    float2 var_scaled_pos_0 = (var_original_pos_0 / varyings.ds_scale);
+    float4 var_color_0 = sample2d(ds_image, var_scaled_pos_0).xyzw;
+    float4 var_faded_color_0 = (var_color_0 * float4(const_table[10].y, const_table[10].z, const_table[10].w, varyings.ds_opacity));
+    return var_faded_color_0;
+}
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[8].y * var_w_0), (const_table[8].z * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[8].w * var_r_0), (const_table[9].x * var_r_0)).xy)), float2(const_table[9].y, const_table[9].z));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[9].w * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[6].w, const_table[7].x);
+    float var_wb_0 = const_table[7].y;
+    if(var_self_0.f_blur > const_table[7].z) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[7].w, const_table[8].x));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_50_fill_keep_premul(inout struct_6_28 var_self_0, float4 var_source_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[5].w - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[6].x) {
+            float var_f2_0 = (const_table[6].y - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[6].z - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_51_fill_premul(inout struct_6_28 var_self_0, float4 var_color_0) {
+    fn_6_50_fill_keep_premul (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[5].x));
+    (var_self_0.f_clip = -const_table[5].y);
+    (var_self_0.f_has_clip = const_table[5].z);
+    return var_self_0.f_result;
+}
+void fn_6_44_rotate(inout struct_6_28 var_self_0, float var_a_0, float var_x_0, float var_y_0) {
+    float var_ca_0 = cos(-var_a_0);
+    float var_sa_0 = sin(-var_a_0);
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    (var_self_0.f_pos = (float2(((var_p_0.x * var_ca_0) - (var_p_0.y * var_sa_0)), ((var_p_0.x * var_sa_0) + (var_p_0.y * var_ca_0))) + float2(var_x_0, var_y_0)));
+}
+float2 fn_6_43_translate(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_pos -= float2(var_x_0, var_y_0));
+    return var_self_0.f_pos;
+}
+float fn_6_41_antialias(float2 var_p_0) {
+    return (const_table[4].w / length(float2(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_6_28 fn_6_42_viewport(float2 var_pos_0) {
+    return consfn_struct_6_28(var_pos_0,consfn_vec4_float(const_table[2].y),consfn_vec2_float(const_table[2].z),consfn_vec2_float(const_table[2].w),const_table[3].x,-const_table[3].y,const_table[3].z,const_table[3].w,const_table[4].x,fn_6_41_antialias (var_pos_0),const_table[4].y,const_table[4].z);
+}
+float4 fn_6_15_premul(float4 var_v_0) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_7_80_pixel(inout Varyings varyings) {
+    float2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y);
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    float2 var_translation_offset_0 = float2(((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[0].w), (((varyings.ds_rect_size.y * varyings.ds_scale) * var_rot_expansion_0.y) / const_table[1].x));
+    fn_6_43_translate (var_sdf_0, var_translation_offset_0.x, var_translation_offset_0.y);
+    float2 var_center_0 = (varyings.ds_rect_size * const_table[1].y);
+    fn_6_44_rotate (var_sdf_0, varyings.ds_rotation, var_center_0.x, var_center_0.y);
+    float2 var_scaled_size_0 = (varyings.ds_rect_size * varyings.ds_scale);
+    fn_6_64_box (var_sdf_0, const_table[1].z, const_table[1].w, var_scaled_size_0.x, var_scaled_size_0.y, const_table[2].x);
+    fn_6_51_fill_premul (var_sdf_0, fn_6_15_premul (fn_7_87_get_color (varyings)));
+    return var_sdf_0.f_result;
+}
+float4 fn_7_79_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y);
+    float2 var_adjusted_pos_0 = float2((instances.ds_rect_pos.x - ((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[0].x)), (instances.ds_rect_pos.y - ((varyings.ds_rect_size.y * var_rot_expansion_0.y) / const_table[0].y)));
+    float2 var_expanded_size_0 = float2((varyings.ds_rect_size.x * (varyings.ds_scale + var_rot_expansion_0.x)), (varyings.ds_rect_size.y * (varyings.ds_scale + var_rot_expansion_0.y)));
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_expanded_size_0) + var_adjusted_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_adjusted_pos_0) / varyings.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].z))));
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, 0.0, float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_rotation = instances.ds_rotation;
+    varyings.ds_opacity = instances.ds_opacity;
+    varyings.ds_scale = instances.ds_scale;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_7_79_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_7_80_pixel(varyings);
+}
@@ -0,0 +1,183 @@
+#include <metal_stdlib>
+using namespace metal;
+float4 sample2d(texture2d<float> tex, float2 pos){return tex.sample(sampler(mag_filter::nearest,min_filter::linear,mip_filter::linear),pos);}
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+    texture2d<float> ds_image [[texture(0)]];};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_rotation;
+    float ds_opacity;
+    float ds_scale;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    packed_float4 ds_color;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_rotation;
+    float ds_opacity;
+    float ds_scale;
+    float2 ds_rect_size;
+    float2 ds_pos;
+};
+float2 fn_7_86_rotate_2d_from_center(float2 var_coord_0, float var_a_0, float2 var_size_0, constant const float *const_table) {
+    float var_cos_a_0 = cos(-var_a_0);
+    float var_sin_a_0 = sin(-var_a_0);
+    float2 var_centered_coord_0 = (var_coord_0 - float2(const_table[46], const_table[47]));
+    float2 var_denorm_coord_0 = float2(var_centered_coord_0.x, ((var_centered_coord_0.y * var_size_0.y) / var_size_0.x));
+    float2 var_demorm_rotated_0 = float2(((var_denorm_coord_0.x * var_cos_a_0) - (var_denorm_coord_0.y * var_sin_a_0)), ((var_denorm_coord_0.x * var_sin_a_0) + (var_denorm_coord_0.y * var_cos_a_0)));
+    float2 var_rotated_0 = float2(var_demorm_rotated_0.x, ((var_demorm_rotated_0.y * var_size_0.x) / var_size_0.y));
+    return (var_rotated_0 + float2(const_table[48], const_table[49]));
+}
+float2 fn_7_85_rotation_vertex_expansion(float var_rotation_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float var_horizontal_expansion_0 = ((((abs(cos(var_rotation_0)) * var_w_0) + (abs(sin(var_rotation_0)) * var_h_0)) / var_w_0) - const_table[44]);
+    float var_vertical_expansion_0 = ((((abs(sin(var_rotation_0)) * var_w_0) + (abs(cos(var_rotation_0)) * var_h_0)) / var_h_0) - const_table[45]);
+    return float2(var_horizontal_expansion_0, var_vertical_expansion_0);
+}
+float4 fn_7_87_get_color(constant const float *const_table, thread Varyings &varyings, Textures textures) {
+    float2 var_rot_padding_0 = (fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y, const_table) / const_table[40]);
+    float2 var_current_pos_0 = (varyings.ds_pos.xy - var_rot_padding_0);
+    float2 var_original_pos_0 = fn_7_86_rotate_2d_from_center (var_current_pos_0, varyings.ds_rotation, varyings.ds_rect_size, const_table);
+    float2 var_scaled_pos_0 = (var_original_pos_0 / varyings.ds_scale);
+    float4 var_color_0 = sample2d(textures.ds_image, var_scaled_pos_0).xyzw;
+    float4 var_faded_color_0 = (var_color_0 * float4(const_table[41], const_table[42], const_table[43], varyings.ds_opacity));
+    return var_faded_color_0;
+}
+void fn_6_64_box(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0, constant const float *const_table) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[33] * var_w_0), (const_table[34] * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[35] * var_r_0), (const_table[36] * var_r_0)).xy)), float2(const_table[37], const_table[38]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[39] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[27], const_table[28]);
+    float var_wb_0 = const_table[29];
+    if(var_self_0.f_blur > const_table[30]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[31], const_table[32]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_50_fill_keep_premul(thread struct_6_28 & var_self_0, float4 var_source_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[23] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[24]) {
+            float var_f2_0 = (const_table[25] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[26] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_51_fill_premul(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_6_50_fill_keep_premul (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[20]));
+    (var_self_0.f_clip = -const_table[21]);
+    (var_self_0.f_has_clip = const_table[22]);
+    return var_self_0.f_result;
+}
+void fn_6_44_rotate(thread struct_6_28 & var_self_0, float var_a_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    float var_ca_0 = cos(-var_a_0);
+    float var_sa_0 = sin(-var_a_0);
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    (var_self_0.f_pos = (float2(((var_p_0.x * var_ca_0) - (var_p_0.y * var_sa_0)), ((var_p_0.x * var_sa_0) + (var_p_0.y * var_ca_0))) + float2(var_x_0, var_y_0)));
+}
+float2 fn_6_43_translate(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    (var_self_0.f_pos -= float2(var_x_0, var_y_0));
+    return var_self_0.f_pos;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[19] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[9]),float2(const_table[10]),float2(const_table[11]),const_table[12],-const_table[13],const_table[14],const_table[15],const_table[16],fn_6_41_antialias (var_pos_0, const_table),const_table[17],const_table[18]};
+}
+float4 fn_6_15_premul(float4 var_v_0, constant const float *const_table) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_7_80_pixel(constant const float *const_table, thread Varyings &varyings, Textures textures) {
+    float2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y, const_table);
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    float2 var_translation_offset_0 = float2(((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[3]), (((varyings.ds_rect_size.y * varyings.ds_scale) * var_rot_expansion_0.y) / const_table[4]));
+    fn_6_43_translate (var_sdf_0, var_translation_offset_0.x, var_translation_offset_0.y, const_table);
+    float2 var_center_0 = (varyings.ds_rect_size * const_table[5]);
+    fn_6_44_rotate (var_sdf_0, varyings.ds_rotation, var_center_0.x, var_center_0.y, const_table);
+    float2 var_scaled_size_0 = (varyings.ds_rect_size * varyings.ds_scale);
+    fn_6_64_box (var_sdf_0, const_table[6], const_table[7], var_scaled_size_0.x, var_scaled_size_0.y, const_table[8], const_table);
+    fn_6_51_fill_premul (var_sdf_0, fn_6_15_premul (fn_7_87_get_color (const_table, varyings, textures), const_table), const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_7_79_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_rot_expansion_0 = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y, const_table);
+    float2 var_adjusted_pos_0 = float2((instances.ds_rect_pos.x - ((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[0])), (instances.ds_rect_pos.y - ((varyings.ds_rect_size.y * var_rot_expansion_0.y) / const_table[1])));
+    float2 var_expanded_size_0 = float2((varyings.ds_rect_size.x * (varyings.ds_scale + var_rot_expansion_0.x)), (varyings.ds_rect_size.y * (varyings.ds_scale + var_rot_expansion_0.y)));
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_expanded_size_0) + var_adjusted_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_adjusted_pos_0) / varyings.ds_rect_size));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[2])))))));
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_rotation = instances.ds_rotation;
+    varyings.ds_opacity = instances.ds_opacity;
+    varyings.ds_scale = instances.ds_scale;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_7_79_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_7_80_pixel(const_table, varyings, textures);
+}
@@ -0,0 +1,169 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 13>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+@group(0) @binding(5) var ds_image: texture_2d<f32>;
+@group(0) @binding(6) var default_texture_sampler: sampler;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_rotation: f32,
+    @location(2) ds_opacity: f32,
+    @location(3) ds_scale: f32,
+    @location(4) ds_rect_pos: vec2<f32>,
+    @location(5) ds_rect_size: vec2<f32>,
+    @location(6) ds_draw_clip: vec4<f32>,
+    @location(7) ds_draw_depth: f32,
+    @location(8) pad_0: f32,
+    @location(9) ds_color: vec4<f32>,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_rotation: f32,
+    @location(1) ds_opacity: f32,
+    @location(2) ds_scale: f32,
+    @location(3) ds_rect_size: vec2<f32>,
+    @location(4) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn sample2d(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}
+fn fn_7_86_rotate_2d_from_center(var_coord_0: vec2<f32>, var_a_0: f32, var_size_0: vec2<f32>) -> vec2<f32> {
+    var var_cos_a_0: f32 = cos(-var_a_0);
+    var var_sin_a_0: f32 = sin(-var_a_0);
+    var var_centered_coord_0: vec2<f32> = (var_coord_0 - vec2<f32>(const_table[11].z, const_table[11].w));
+    var var_denorm_coord_0: vec2<f32> = vec2<f32>(var_centered_coord_0.x, ((var_centered_coord_0.y * var_size_0.y) / var_size_0.x));
+    var var_demorm_rotated_0: vec2<f32> = vec2<f32>(((var_denorm_coord_0.x * var_cos_a_0) - (var_denorm_coord_0.y * var_sin_a_0)), ((var_denorm_coord_0.x * var_sin_a_0) + (var_denorm_coord_0.y * var_cos_a_0)));
+    var var_rotated_0: vec2<f32> = vec2<f32>(var_demorm_rotated_0.x, ((var_demorm_rotated_0.y * var_size_0.x) / var_size_0.y));
+    return (var_rotated_0 + vec2<f32>(const_table[12].x, const_table[12].y));
+}
+fn fn_7_85_rotation_vertex_expansion(var_rotation_0: f32, var_w_0: f32, var_h_0: f32) -> vec2<f32> {
+    var var_horizontal_expansion_0: f32 = ((((abs(cos(var_rotation_0)) * var_w_0) + (abs(sin(var_rotation_0)) * var_h_0)) / var_w_0) - const_table[11].x);
+    var var_vertical_expansion_0: f32 = ((((abs(sin(var_rotation_0)) * var_w_0) + (abs(cos(var_rotation_0)) * var_h_0)) / var_h_0) - const_table[11].y);
+    return vec2<f32>(var_horizontal_expansion_0, var_vertical_expansion_0);
+}
+fn fn_7_87_get_color() -> vec4<f32> {
+    var var_rot_padding_0: vec2<f32> = (fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y) / const_table[10].x);
+    var var_current_pos_0: vec2<f32> = (varyings.ds_pos.xy - var_rot_padding_0);
+    var var_original_pos_0: vec2<f32> = fn_7_86_rotate_2d_from_center (var_current_pos_0, varyings.ds_rotation, varyings.ds_rect_size);
+    var var_scaled_pos_0: vec2<f32> = (var_original_pos_0 / varyings.ds_scale);
+    var var_color_0: vec4<f32> = sample2d(ds_image, var_scaled_pos_0).xyzw;
+    var var_faded_color_0: vec4<f32> = (var_color_0 * vec4<f32>(const_table[10].y, const_table[10].z, const_table[10].w, varyings.ds_opacity));
+    return var_faded_color_0;
+}
+fn fn_6_64_box(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_0: f32) {
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[8].y * var_w_0), (const_table[8].z * var_h_0));
+    var var_bp_0: vec2<f32> = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2<f32>((const_table[8].w * var_r_0), (const_table[9].x * var_r_0)).xy)), vec2<f32>(const_table[9].y, const_table[9].z));
+    (*var_self_0).f_dist = ((length(var_bp_0) - (const_table[9].w * var_r_0)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[6].w, const_table[7].x);
+    var var_wb_0: f32 = const_table[7].y;
+    if((*var_self_0).f_blur > const_table[7].z) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[7].w, const_table[8].x);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_50_fill_keep_premul(var_self_0: ptr<function, struct_6_28>, var_source_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[5].w - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[6].x) {
+            var var_f2_0: f32 = (const_table[6].y - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[6].z - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_51_fill_premul(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    fn_6_50_fill_keep_premul (var_self_0, var_color_0);
+    (*var_self_0).f_shape = const_table[5].x;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[5].y;
+    (*var_self_0).f_has_clip = const_table[5].z;
+    return (*var_self_0).f_result;
+}
+fn fn_6_44_rotate(var_self_0: ptr<function, struct_6_28>, var_a_0: f32, var_x_0: f32, var_y_0: f32) {
+    var var_ca_0: f32 = cos(-var_a_0);
+    var var_sa_0: f32 = sin(-var_a_0);
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    (*var_self_0).f_pos = (vec2<f32>(((var_p_0.x * var_ca_0) - (var_p_0.y * var_sa_0)), ((var_p_0.x * var_sa_0) + (var_p_0.y * var_ca_0))) + vec2<f32>(var_x_0, var_y_0));
+}
+fn fn_6_43_translate(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) -> vec2<f32> {
+    (*var_self_0).f_pos -= vec2<f32>(var_x_0, var_y_0);
+    return (*var_self_0).f_pos;
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[4].w / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[2].y),vec2<f32>(const_table[2].z),vec2<f32>(const_table[2].w),const_table[3].x,-const_table[3].y,const_table[3].z,const_table[3].w,const_table[4].x,fn_6_41_antialias (var_pos_0),const_table[4].y,const_table[4].z);
+}
+fn fn_6_15_premul(var_v_0: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+fn fn_7_80_pixel() -> vec4<f32> {
+    var var_rot_expansion_0: vec2<f32> = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y);
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    var var_translation_offset_0: vec2<f32> = vec2<f32>(((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[0].w), (((varyings.ds_rect_size.y * varyings.ds_scale) * var_rot_expansion_0.y) / const_table[1].x));
+    fn_6_43_translate (&var_sdf_0, var_translation_offset_0.x, var_translation_offset_0.y);
+    var var_center_0: vec2<f32> = (varyings.ds_rect_size * const_table[1].y);
+    fn_6_44_rotate (&var_sdf_0, varyings.ds_rotation, var_center_0.x, var_center_0.y);
+    var var_scaled_size_0: vec2<f32> = (varyings.ds_rect_size * varyings.ds_scale);
+    fn_6_64_box (&var_sdf_0, const_table[1].z, const_table[1].w, var_scaled_size_0.x, var_scaled_size_0.y, const_table[2].x);
+    fn_6_51_fill_premul (&var_sdf_0, fn_6_15_premul (fn_7_87_get_color ()));
+    return var_sdf_0.f_result;
+}
+fn fn_7_79_vertex() -> vec4<f32> {
+    var var_rot_expansion_0: vec2<f32> = fn_7_85_rotation_vertex_expansion (varyings.ds_rotation, varyings.ds_rect_size.x, varyings.ds_rect_size.y);
+    var var_adjusted_pos_0: vec2<f32> = vec2<f32>((instances.ds_rect_pos.x - ((varyings.ds_rect_size.x * var_rot_expansion_0.x) / const_table[0].x)), (instances.ds_rect_pos.y - ((varyings.ds_rect_size.y * var_rot_expansion_0.y) / const_table[0].y)));
+    var var_expanded_size_0: vec2<f32> = vec2<f32>((varyings.ds_rect_size.x * (varyings.ds_scale + var_rot_expansion_0.x)), (varyings.ds_rect_size.y * (varyings.ds_scale + var_rot_expansion_0.y)));
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_expanded_size_0) + var_adjusted_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_adjusted_pos_0) / varyings.ds_rect_size);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].z))));
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_rotation = instances.ds_rotation;
+    varyings.ds_opacity = instances.ds_opacity;
+    varyings.ds_scale = instances.ds_scale;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_7_79_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_7_80_pixel();
+}
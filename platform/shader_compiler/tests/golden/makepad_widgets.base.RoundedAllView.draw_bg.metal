@@ -0,0 +1,167 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_border_width;
+    packed_float4 ds_border_color;
+    packed_float4 ds_inset;
+    packed_float4 ds_radius;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    packed_float4 ds_color;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_border_width;
+    float4 ds_border_color;
+    float4 ds_inset;
+    float4 ds_radius;
+    float2 ds_rect_size;
+    float4 ds_color;
+    float2 ds_pos;
+};
+float4 fn_7_255_get_border_color(constant const float *const_table, thread Varyings &varyings) {
+    return varyings.ds_border_color;
+}
+float4 fn_7_254_get_color(constant const float *const_table, thread Varyings &varyings) {
+    return varyings.ds_color;
+}
+void fn_6_67_box_all(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_top_0, float var_r_right_top_0, float var_r_right_bottom_0, float var_r_left_bottom_0, constant const float *const_table) {
+    float2 var_size_0 = float2((const_table[29] * var_w_0), (const_table[30] * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_lt_0 = max((var_p_0 + float2((const_table[31] * var_r_left_top_0), (const_table[32] * var_r_left_top_0)).xy), float2(const_table[33], const_table[34]));
+    float2 var_bp_rt_0 = max((var_p_0 + float2((const_table[35] * var_r_right_top_0), (const_table[36] * var_r_right_top_0)).xy), float2(const_table[37], const_table[38]));
+    float2 var_bp_rb_0 = max((var_p_0 + float2((const_table[39] * var_r_right_bottom_0), (const_table[40] * var_r_right_bottom_0)).xy), float2(const_table[41], const_table[42]));
+    float2 var_bp_lb_0 = max((var_p_0 + float2((const_table[43] * var_r_left_bottom_0), (const_table[44] * var_r_left_bottom_0)).xy), float2(const_table[45], const_table[46]));
+    (var_self_0.f_dist = (mix(mix((length(var_bp_lt_0) - (const_table[47] * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[48] * var_r_left_bottom_0)), step((const_table[49] * var_h_0), var_p_r_0.y)), mix((length(var_bp_rt_0) - (const_table[50] * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[51] * var_r_right_bottom_0)), step((const_table[52] * var_h_0), var_p_r_0.y)), step((const_table[53] * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[23], const_table[24]);
+    float var_wb_0 = const_table[25];
+    if(var_self_0.f_blur > const_table[26]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[27], const_table[28]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[22] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[19]));
+    (var_self_0.f_clip = -const_table[20]);
+    (var_self_0.f_has_clip = const_table[21]);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[15] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[16]) {
+            float var_f2_0 = (const_table[17] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[18] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[14] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[4]),float2(const_table[5]),float2(const_table[6]),const_table[7],-const_table[8],const_table[9],const_table[10],const_table[11],fn_6_41_antialias (var_pos_0, const_table),const_table[12],const_table[13]};
+}
+float4 fn_7_249_pixel(constant const float *const_table, thread Varyings &varyings) {
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    fn_6_67_box_all (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[1]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[2]))), varyings.ds_radius.x, varyings.ds_radius.y, varyings.ds_radius.z, varyings.ds_radius.w, const_table);
+    fn_6_48_fill_keep (var_sdf_0, fn_7_254_get_color (const_table, varyings), const_table);
+    if(varyings.ds_border_width > const_table[3]) {
+            fn_6_53_stroke (var_sdf_0, fn_7_255_get_border_color (const_table, varyings), varyings.ds_border_width, const_table);
+    }
+
+    return var_sdf_0.f_result;
+}
+float4 fn_7_246_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_7_248_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_7_246_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_7_248_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_7_249_pixel(const_table, varyings);
+}
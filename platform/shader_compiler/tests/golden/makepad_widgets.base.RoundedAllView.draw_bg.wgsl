@@ -0,0 +1,151 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 14>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_border_width: f32,
+    @location(2) ds_border_color: vec4<f32>,
+    @location(3) ds_inset: vec4<f32>,
+    @location(4) ds_radius: vec4<f32>,
+    @location(5) ds_rect_pos: vec2<f32>,
+    @location(6) ds_rect_size: vec2<f32>,
+    @location(7) ds_draw_clip: vec4<f32>,
+    @location(8) ds_draw_depth: f32,
+    @location(9) pad_0: f32,
+    @location(10) ds_color: vec4<f32>,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_border_width: f32,
+    @location(1) ds_border_color: vec4<f32>,
+    @location(2) ds_inset: vec4<f32>,
+    @location(3) ds_radius: vec4<f32>,
+    @location(4) ds_rect_size: vec2<f32>,
+    @location(5) ds_color: vec4<f32>,
+    @location(6) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_7_255_get_border_color() -> vec4<f32> {
+    return varyings.ds_border_color;
+}
+fn fn_7_254_get_color() -> vec4<f32> {
+    return varyings.ds_color;
+}
+fn fn_6_67_box_all(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_left_top_0: f32, var_r_right_top_0: f32, var_r_right_bottom_0: f32, var_r_left_bottom_0: f32) {
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[7].y * var_w_0), (const_table[7].z * var_h_0));
+    var var_p_r_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_p_0: vec2<f32> = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    var var_bp_lt_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[7].w * var_r_left_top_0), (const_table[8].x * var_r_left_top_0)).xy), vec2<f32>(const_table[8].y, const_table[8].z));
+    var var_bp_rt_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[8].w * var_r_right_top_0), (const_table[9].x * var_r_right_top_0)).xy), vec2<f32>(const_table[9].y, const_table[9].z));
+    var var_bp_rb_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[9].w * var_r_right_bottom_0), (const_table[10].x * var_r_right_bottom_0)).xy), vec2<f32>(const_table[10].y, const_table[10].z));
+    var var_bp_lb_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[10].w * var_r_left_bottom_0), (const_table[11].x * var_r_left_bottom_0)).xy), vec2<f32>(const_table[11].y, const_table[11].z));
+    (*var_self_0).f_dist = (mix(mix((length(var_bp_lt_0) - (const_table[11].w * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[12].x * var_r_left_bottom_0)), step((const_table[12].y * var_h_0), var_p_r_0.y)), mix((length(var_bp_rt_0) - (const_table[12].z * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[12].w * var_r_right_bottom_0)), step((const_table[13].x * var_h_0), var_p_r_0.y)), step((const_table[13].y * var_w_0), var_p_r_0.x)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[5].w, const_table[6].x);
+    var var_wb_0: f32 = const_table[6].y;
+    if((*var_self_0).f_blur > const_table[6].z) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[6].w, const_table[7].x);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_52_stroke_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (abs((*var_self_0).f_shape) - (var_width_0 / (*var_self_0).f_scale_factor)));
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    var var_dest_0: vec4<f32> = (*var_self_0).f_result;
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[5].z - (var_source_0.a * var_f_0))));
+    return (*var_self_0).f_result;
+}
+fn fn_6_53_stroke(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (*var_self_0).f_shape = const_table[4].w;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[5].x;
+    (*var_self_0).f_has_clip = const_table[5].y;
+    return (*var_self_0).f_result;
+}
+fn fn_6_48_fill_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[3].w - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[4].x) {
+            var var_f2_0: f32 = (const_table[4].y - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[4].z - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[3].z / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[1].x),vec2<f32>(const_table[1].y),vec2<f32>(const_table[1].z),const_table[1].w,-const_table[2].x,const_table[2].y,const_table[2].z,const_table[2].w,fn_6_41_antialias (var_pos_0),const_table[3].x,const_table[3].y);
+}
+fn fn_7_249_pixel() -> vec4<f32> {
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    fn_6_67_box_all (&var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[0].y))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[0].z))), varyings.ds_radius.x, varyings.ds_radius.y, varyings.ds_radius.z, varyings.ds_radius.w);
+    fn_6_48_fill_keep (&var_sdf_0, fn_7_254_get_color ());
+    if(varyings.ds_border_width > const_table[0].w) {
+            fn_6_53_stroke (&var_sdf_0, fn_7_255_get_border_color (), varyings.ds_border_width);
+    }
+
+    return var_sdf_0.f_result;
+}
+fn fn_7_246_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_7_248_vertex() -> vec4<f32> {
+    return fn_7_246_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_color = instances.ds_color;
+    varyings.position = fn_7_248_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_7_249_pixel();
+}
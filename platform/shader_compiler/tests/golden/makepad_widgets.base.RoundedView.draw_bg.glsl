@@ -0,0 +1,237 @@
+// vertex
+uniform float const_table[40];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute vec4 packed_instance_4;
+attribute vec4 packed_instance_5;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+varying vec4 packed_varying_3;
+varying vec2 packed_varying_4;
+
+float ds_border_width=0.0;
+vec4 ds_border_color=vec4(0.0);
+vec4 ds_inset=vec4(0.0);
+float ds_radius=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+vec4 ds_color=vec4(0.0);
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_7_180_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_7_182_vertex() {
+    return fn_7_180_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_border_width = packed_instance_0.x;
+    ds_border_color.xyz = packed_instance_0.yzw;
+    ds_border_color.w = packed_instance_1.x;
+    ds_inset.xyz = packed_instance_1.yzw;
+    ds_inset.w = packed_instance_2.x;
+    ds_radius = packed_instance_2.y;
+    ds_rect_pos.xy = packed_instance_2.zw;
+    ds_rect_size.xy = packed_instance_3.xy;
+    ds_draw_clip.xy = packed_instance_3.zw;
+    ds_draw_clip.zw = packed_instance_4.xy;
+    ds_draw_depth = packed_instance_4.z;
+    ds_0 = packed_instance_4.w;
+    ds_color.xyzw = packed_instance_5.xyzw;
+
+    gl_Position = fn_7_182_vertex();
+
+    packed_varying_0.x = ds_border_width;
+    packed_varying_0.yzw = ds_border_color.xyz;
+    packed_varying_1.x = ds_border_color.w;
+    packed_varying_1.yzw = ds_inset.xyz;
+    packed_varying_2.x = ds_inset.w;
+    packed_varying_2.y = ds_radius;
+    packed_varying_2.zw = ds_rect_size.xy;
+    packed_varying_3.xyzw = ds_color.xyzw;
+    packed_varying_4.xy = ds_pos.xy;
+}
+// pixel
+uniform float const_table[40];
+
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+varying vec4 packed_varying_3;
+varying vec2 packed_varying_4;
+
+float ds_border_width=0.0;
+vec4 ds_border_color=vec4(0.0);
+vec4 ds_inset=vec4(0.0);
+float ds_radius=0.0;
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_color=vec4(0.0);
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+vec4 fn_7_189_get_border_color() {
+    return ds_border_color;
+}
+
+vec4 fn_7_188_get_color() {
+    return ds_color;
+}
+
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_size_0 = vec2((const_table[30] * var_w_0), (const_table[31] * var_h_0));
+    vec2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2((const_table[32] * var_r_0), (const_table[33] * var_r_0)).xy)), vec2(const_table[34], const_table[35]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[36] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[24], const_table[25]);
+    float var_wb_0 = const_table[26];
+    if(var_self_0.f_blur > const_table[27]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[28], const_table[29]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_52_stroke_keep(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    vec4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[23] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_53_stroke(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[20]));
+    (var_self_0.f_clip = -const_table[21]);
+    (var_self_0.f_has_clip = const_table[22]);
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[16] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[17]) {
+            float var_f2_0 = (const_table[18] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[19] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[15] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[5]),vec2(const_table[6]),vec2(const_table[7]),const_table[8],-const_table[9],const_table[10],const_table[11],const_table[12],fn_6_41_antialias (var_pos_0),const_table[13],const_table[14]);
+}
+
+vec4 fn_7_183_pixel() {
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * ds_rect_size));
+    fn_6_64_box (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[1]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[2]))), max(const_table[3], ds_radius));
+    fn_6_48_fill_keep (var_sdf_0, fn_7_188_get_color ());
+    if(ds_border_width > const_table[4]) {
+            fn_6_53_stroke (var_sdf_0, fn_7_189_get_border_color (), ds_border_width);
+    }
+
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_border_width = packed_varying_0.x;
+    ds_border_color.xyz = packed_varying_0.yzw;
+    ds_border_color.w = packed_varying_1.x;
+    ds_inset.xyz = packed_varying_1.yzw;
+    ds_inset.w = packed_varying_2.x;
+    ds_radius = packed_varying_2.y;
+    ds_rect_size.xy = packed_varying_2.zw;
+    ds_color.xyzw = packed_varying_3.xyzw;
+    ds_pos.xy = packed_varying_4.xy;
+
+    gl_FragColor = fn_7_183_pixel();
+}
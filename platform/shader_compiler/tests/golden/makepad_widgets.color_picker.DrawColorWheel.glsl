@@ -0,0 +1,285 @@
+// vertex
+uniform float const_table[116];
+
+uniform float live_table[2];
+float live_6_1 = 0.0;
+float live_6_8 = 0.0;
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec3 packed_instance_3;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying float packed_varying_2;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+float ds_hue=0.0;
+float ds_sat=0.0;
+float ds_val=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_10_9_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_10_11_vertex() {
+    return fn_10_9_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_6_1 = live_table[0];
+    live_6_8 = live_table[1];
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_hover = packed_instance_0.x;
+    ds_pressed = packed_instance_0.y;
+    ds_rect_pos.xy = packed_instance_0.zw;
+    ds_rect_size.xy = packed_instance_1.xy;
+    ds_draw_clip.xy = packed_instance_1.zw;
+    ds_draw_clip.zw = packed_instance_2.xy;
+    ds_draw_depth = packed_instance_2.z;
+    ds_0 = packed_instance_2.w;
+    ds_hue = packed_instance_3.x;
+    ds_sat = packed_instance_3.y;
+    ds_val = packed_instance_3.z;
+
+    gl_Position = fn_10_11_vertex();
+
+    packed_varying_0.x = ds_hover;
+    packed_varying_0.y = ds_pressed;
+    packed_varying_0.zw = ds_rect_size.xy;
+    packed_varying_1.x = ds_hue;
+    packed_varying_1.y = ds_sat;
+    packed_varying_1.z = ds_val;
+    packed_varying_1.w = ds_pos.x;
+    packed_varying_2 = ds_pos.y;
+}
+// pixel
+uniform float const_table[116];
+
+uniform float live_table[2];
+float live_6_1 = 0.0;
+float live_6_8 = 0.0;
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying float packed_varying_2;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_rect_size=vec2(0.0);
+float ds_hue=0.0;
+float ds_sat=0.0;
+float ds_val=0.0;
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+vec2 fn_10_15_circ_to_rect(float var_u_0, float var_v_0) {
+    float var_u2_0 = (var_u_0 * var_u_0);
+    float var_v2_0 = (var_v_0 * var_v_0);
+    return vec2(((const_table[96] * sqrt((((const_table[97] + ((const_table[98] * sqrt(const_table[99])) * var_u_0)) + var_u2_0) - var_v2_0))) - (const_table[100] * sqrt((((const_table[101] - ((const_table[102] * sqrt(const_table[103])) * var_u_0)) + var_u2_0) - var_v2_0)))), ((const_table[104] * sqrt((((const_table[105] + ((const_table[106] * sqrt(const_table[107])) * var_v_0)) - var_u2_0) + var_v2_0))) - (const_table[108] * sqrt((((const_table[109] - ((const_table[110] * sqrt(const_table[111])) * var_v_0)) - var_u2_0) + var_v2_0)))));
+}
+
+void fn_6_69_hexagon(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[94]);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[95] * live_6_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_68_rect(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    vec2 var_s_0 = (vec2(var_w_0, var_h_0) * const_table[89]);
+    vec2 var_d_0 = (abs(((vec2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    vec2 var_dm_0 = min(var_d_0, vec2(const_table[90], const_table[91]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2(const_table[92], const_table[93])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_61_circle(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    vec2 var_c_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_58_subtract(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+
+void fn_6_57_intersect(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[83], const_table[84]);
+    float var_wb_0 = const_table[85];
+    if(var_self_0.f_blur > const_table[86]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[87], const_table[88]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[79] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[80]) {
+            float var_f2_0 = (const_table[81] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[82] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_49_fill(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[76]));
+    (var_self_0.f_clip = -const_table[77]);
+    (var_self_0.f_has_clip = const_table[78]);
+    return var_self_0.f_result;
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[75] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[65]),vec2(const_table[66]),vec2(const_table[67]),const_table[68],-const_table[69],const_table[70],const_table[71],const_table[72],fn_6_41_antialias (var_pos_0),const_table[73],const_table[74]);
+}
+
+vec4 fn_6_25_hsv2rgb(vec4 var_c_0) {
+    vec4 var_K_0 = vec4(const_table[56], (const_table[57] / const_table[58]), (const_table[59] / const_table[60]), const_table[61]);
+    vec3 var_p_0 = abs(((fract((var_c_0.xxx + var_K_0.xyz)) * const_table[62]) - var_K_0.www));
+    return vec4((var_c_0.z * mix(var_K_0.xxx, clamp((var_p_0 - var_K_0.xxx), const_table[63], const_table[64]), var_c_0.y)), var_c_0.w);
+}
+
+vec4 fn_10_12_pixel() {
+    vec4 var_rgbv_0 = fn_6_25_hsv2rgb (vec4(ds_hue, ds_sat, ds_val, const_table[1]));
+    float var_w_0 = ds_rect_size.x;
+    float var_h_0 = ds_rect_size.y;
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * vec2(var_w_0, var_h_0)));
+    float var_cx_0 = (var_w_0 * const_table[2]);
+    float var_cy_0 = (var_h_0 * const_table[3]);
+    float var_radius_0 = (var_w_0 * const_table[4]);
+    float var_inner_0 = (var_w_0 * const_table[5]);
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[6]));
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[7]));
+    fn_6_58_subtract (var_sdf_0);
+    float var_ang_0 = (((atan(((ds_pos.x * var_w_0) - var_cx_0), ((const_table[8] + (ds_pos.y * var_h_0)) - var_cy_0)) / live_6_1) * const_table[9]) - const_table[10]);
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (vec4(var_ang_0, const_table[11], const_table[12], const_table[13])));
+    float var_rsize_0 = (var_inner_0 / sqrt(const_table[14]));
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[15]), (var_rsize_0 * const_table[16]));
+    vec2 var_norm_rect_0 = (vec2(((ds_pos.x * var_w_0) - (var_cx_0 - var_inner_0)), ((ds_pos.y * var_h_0) - (var_cy_0 - var_inner_0))) / (const_table[17] * var_inner_0));
+    vec2 var_circ_0 = clamp(fn_10_15_circ_to_rect (((var_norm_rect_0.x * const_table[18]) - const_table[19]), ((var_norm_rect_0.y * const_table[20]) - const_table[21])), vec2(-const_table[22]), vec2(const_table[23]));
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (vec4(ds_hue, ((var_circ_0.x * const_table[24]) + const_table[25]), (const_table[26] - ((var_circ_0.y * const_table[27]) + const_table[28])), const_table[29])));
+    float var_col_angle_0 = (((ds_hue + const_table[30]) * const_table[31]) * live_6_1);
+    vec2 var_circle_puk_0 = vec2(((sin(var_col_angle_0) * var_radius_0) + var_cx_0), ((cos(var_col_angle_0) * var_radius_0) + var_cy_0));
+    vec2 var_rect_puk_0 = vec2(((var_cx_0 + ((ds_sat * const_table[32]) * var_rsize_0)) - var_rsize_0), ((var_cy_0 + (((const_table[33] - ds_val) * const_table[34]) * var_rsize_0)) - var_rsize_0));
+    vec4 var_color_0 = mix(mix(vec4(const_table[35], const_table[36], const_table[37], const_table[38]), vec4(const_table[39], const_table[40], const_table[41], const_table[42]), ds_hover), vec4(const_table[43], const_table[44], const_table[45], const_table[46]), ds_pressed);
+    float var_puck_size_0 = (const_table[47] * var_w_0);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, var_puck_size_0);
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[48]), (var_rsize_0 * const_table[49]));
+    fn_6_57_intersect (var_sdf_0);
+    fn_6_49_fill (var_sdf_0, var_color_0);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, (((var_puck_size_0 - const_table[50]) - (const_table[51] * ds_hover)) + ds_pressed));
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[52]), (var_rsize_0 * const_table[53]));
+    fn_6_57_intersect (var_sdf_0);
+    fn_6_49_fill (var_sdf_0, var_rgbv_0);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, var_puck_size_0);
+    fn_6_49_fill (var_sdf_0, var_color_0);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, (((var_puck_size_0 - const_table[54]) - (const_table[55] * ds_hover)) + ds_pressed));
+    fn_6_49_fill (var_sdf_0, var_rgbv_0);
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_6_1 = live_table[0];
+    live_6_8 = live_table[1];
+    ds_hover = packed_varying_0.x;
+    ds_pressed = packed_varying_0.y;
+    ds_rect_size.xy = packed_varying_0.zw;
+    ds_hue = packed_varying_1.x;
+    ds_sat = packed_varying_1.y;
+    ds_val = packed_varying_1.z;
+    ds_pos.x = packed_varying_1.w;
+    ds_pos.y = packed_varying_2;
+
+    gl_FragColor = fn_10_12_pixel();
+}
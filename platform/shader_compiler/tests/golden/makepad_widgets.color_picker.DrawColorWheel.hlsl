@@ -0,0 +1,208 @@
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct_6_28 consfn_struct_6_28(
+float2 f_pos, float4 f_result, float2 f_last_pos, float2 f_start_pos, float f_shape, float f_clip, float f_has_clip, float f_old_shape, float f_blur, float f_aa, float f_scale_factor, float f_dist){;
+struct_6_28 r;
+r.f_pos = f_pos;
+r.f_result = f_result;
+r.f_last_pos = f_last_pos;
+r.f_start_pos = f_start_pos;
+r.f_shape = f_shape;
+r.f_clip = f_clip;
+r.f_has_clip = f_has_clip;
+r.f_old_shape = f_old_shape;
+r.f_blur = f_blur;
+r.f_aa = f_aa;
+r.f_scale_factor = f_scale_factor;
+r.f_dist = f_dist;
+return r;
+}
+cbuffer LiveUniforms : register(b0) {
+    float live_6_1;
+    float live_6_8;
+};
+cbuffer ConstTable : register(b1){float4 const_table[29];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_hover: INSTA;
+    float ds_pressed: INSTB;
+    float2 ds_rect_pos: INSTC;
+    float2 ds_rect_size: INSTD;
+    float4 ds_draw_clip: INSTE;
+    float ds_draw_depth: INSTF;
+    float ds_0: INSTG;
+    float ds_hue: INSTH;
+    float ds_sat: INSTI;
+    float ds_val: INSTJ;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_hover: VARYA;
+    float ds_pressed: VARYB;
+    float2 ds_rect_size: VARYC;
+    float ds_hue: VARYD;
+    float ds_sat: VARYE;
+    float ds_val: VARYF;
+    float2 ds_pos: VARYG;
+};
+float2 consfn_vec2_float(float x) {    return float2(x, x);}
+float4 consfn_vec4_float(float x) {    return float4(x, x, x, x);}
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+float2 fn_10_15_circ_to_rect(float var_u_0, float var_v_0) {
+    float var_u2_0 = (var_u_0 * var_u_0);
+    float var_v2_0 = (var_v_0 * var_v_0);
+    return float2(((const_table[24].x * sqrt((((const_table[24].y + ((const_table[24].z * sqrt(const_table[24].w)) * var_u_0)) + var_u2_0) - var_v2_0))) - (const_table[25].x * sqrt((((const_table[25].y - ((const_table[25].z * sqrt(const_table[25].w)) * var_u_0)) + var_u2_0) - var_v2_0)))), ((const_table[26].x * sqrt((((const_table[26].y + ((const_table[26].z * sqrt(const_table[26].w)) * var_v_0)) - var_u2_0) + var_v2_0))) - (const_table[27].x * sqrt((((const_table[27].y - ((const_table[27].z * sqrt(const_table[27].w)) * var_v_0)) - var_u2_0) + var_v2_0)))));
+}
+void fn_6_69_hexagon(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[23].z);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[23].w * live_6_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_68_rect(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[22].y);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[22].z, const_table[22].w));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[23].x, const_table[23].y)))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_61_circle(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_58_subtract(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+void fn_6_57_intersect(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[20].w, const_table[21].x);
+    float var_wb_0 = const_table[21].y;
+    if(var_self_0.f_blur > const_table[21].z) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[21].w, const_table[22].x));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[19].w - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[20].x) {
+            float var_f2_0 = (const_table[20].y - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[20].z - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(inout struct_6_28 var_self_0, float4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[19].x));
+    (var_self_0.f_clip = -const_table[19].y);
+    (var_self_0.f_has_clip = const_table[19].z);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0) {
+    return (const_table[18].w / length(float2(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_6_28 fn_6_42_viewport(float2 var_pos_0) {
+    return consfn_struct_6_28(var_pos_0,consfn_vec4_float(const_table[16].y),consfn_vec2_float(const_table[16].z),consfn_vec2_float(const_table[16].w),const_table[17].x,-const_table[17].y,const_table[17].z,const_table[17].w,const_table[18].x,fn_6_41_antialias (var_pos_0),const_table[18].y,const_table[18].z);
+}
+float4 fn_6_25_hsv2rgb(float4 var_c_0) {
+    float4 var_K_0 = float4(const_table[14].x, (const_table[14].y / const_table[14].z), (const_table[14].w / const_table[15].x), const_table[15].y);
+    float3 var_p_0 = abs(((frac((var_c_0.xxx + var_K_0.xyz)) * const_table[15].z) - var_K_0.www));
+    return consfn_vec4_vec3_float((var_c_0.z * lerp(var_K_0.xxx, clamp((var_p_0 - var_K_0.xxx), const_table[15].w, const_table[16].x), var_c_0.y)), var_c_0.w);
+}
+float4 fn_10_12_pixel(inout Varyings varyings) {
+    float4 var_rgbv_0 = fn_6_25_hsv2rgb (float4(varyings.ds_hue, varyings.ds_sat, varyings.ds_val, const_table[0].y));
+    float var_w_0 = varyings.ds_rect_size.x;
+    float var_h_0 = varyings.ds_rect_size.y;
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * float2(var_w_0, var_h_0)));
+    float var_cx_0 = (var_w_0 * const_table[0].z);
+    float var_cy_0 = (var_h_0 * const_table[0].w);
+    float var_radius_0 = (var_w_0 * const_table[1].x);
+    float var_inner_0 = (var_w_0 * const_table[1].y);
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[1].z));
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[1].w));
+    fn_6_58_subtract (var_sdf_0);
+    float var_ang_0 = (((atan2(((varyings.ds_pos.x * var_w_0) - var_cx_0), ((const_table[2].x + (varyings.ds_pos.y * var_h_0)) - var_cy_0)) / live_6_1) * const_table[2].y) - const_table[2].z);
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (float4(var_ang_0, const_table[2].w, const_table[3].x, const_table[3].y)));
+    float var_rsize_0 = (var_inner_0 / sqrt(const_table[3].z));
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[3].w), (var_rsize_0 * const_table[4].x));
+    float2 var_norm_rect_0 = (float2(((varyings.ds_pos.x * var_w_0) - (var_cx_0 - var_inner_0)), ((varyings.ds_pos.y * var_h_0) - (var_cy_0 - var_inner_0))) / (const_table[4].y * var_inner_0));
+    float2 var_circ_0 = clamp(fn_10_15_circ_to_rect (((var_norm_rect_0.x * const_table[4].z) - const_table[4].w), ((var_norm_rect_0.y * const_table[5].x) - const_table[5].y)), consfn_vec2_float(-const_table[5].z), consfn_vec2_float(const_table[5].w));
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (float4(varyings.ds_hue, ((var_circ_0.x * const_table[6].x) + const_table[6].y), (const_table[6].z - ((var_circ_0.y * const_table[6].w) + const_table[7].x)), const_table[7].y)));
+    float var_col_angle_0 = (((varyings.ds_hue + const_table[7].z) * const_table[7].w) * live_6_1);
+    float2 var_circle_puk_0 = float2(((sin(var_col_angle_0) * var_radius_0) + var_cx_0), ((cos(var_col_angle_0) * var_radius_0) + var_cy_0));
+    float2 var_rect_puk_0 = float2(((var_cx_0 + ((varyings.ds_sat * const_table[8].x) * var_rsize_0)) - var_rsize_0), ((var_cy_0 + (((const_table[8].y - varyings.ds_val) * const_table[8].z) * var_rsize_0)) - var_rsize_0));
+    float4 var_color_0 = lerp(lerp(float4(const_table[8].w, const_table[9].x, const_table[9].y, const_table[9].z), float4(const_table[9].w, const_table[10].x, const_table[10].y, const_table[10].z), varyings.ds_hover), float4(const_table[10].w, const_table[11].x, const_table[11].y, const_table[11].z), varyings.ds_pressed);
+    float var_puck_size_0 = (const_table[11].w * var_w_0);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, var_puck_size_0);
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[12].x), (var_rsize_0 * const_table[12].y));
+    fn_6_57_intersect (var_sdf_0);
+    fn_6_49_fill (var_sdf_0, var_color_0);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, (((var_puck_size_0 - const_table[12].z) - (const_table[12].w * varyings.ds_hover)) + varyings.ds_pressed));
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[13].x), (var_rsize_0 * const_table[13].y));
+    fn_6_57_intersect (var_sdf_0);
+    fn_6_49_fill (var_sdf_0, var_rgbv_0);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, var_puck_size_0);
+    fn_6_49_fill (var_sdf_0, var_color_0);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, (((var_puck_size_0 - const_table[13].z) - (const_table[13].w * varyings.ds_hover)) + varyings.ds_pressed));
+    fn_6_49_fill (var_sdf_0, var_rgbv_0);
+    return var_sdf_0.f_result;
+}
+float4 fn_10_9_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_10_11_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_10_9_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, float2(0.0,0.0), 0.0, 0.0, 0.0, float2(0.0,0.0)};
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_hue = instances.ds_hue;
+    varyings.ds_sat = instances.ds_sat;
+    varyings.ds_val = instances.ds_val;
+    varyings.position = fn_10_11_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_10_12_pixel(varyings);
+}
@@ -0,0 +1,210 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float live_6_1;
+    float live_6_8;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_hover;
+    float ds_pressed;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_hue;
+    float ds_sat;
+    float ds_val;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_hover;
+    float ds_pressed;
+    float2 ds_rect_size;
+    float ds_hue;
+    float ds_sat;
+    float ds_val;
+    float2 ds_pos;
+};
+float2 fn_10_15_circ_to_rect(float var_u_0, float var_v_0, constant const float *const_table) {
+    float var_u2_0 = (var_u_0 * var_u_0);
+    float var_v2_0 = (var_v_0 * var_v_0);
+    return float2(((const_table[96] * sqrt((((const_table[97] + ((const_table[98] * sqrt(const_table[99])) * var_u_0)) + var_u2_0) - var_v2_0))) - (const_table[100] * sqrt((((const_table[101] - ((const_table[102] * sqrt(const_table[103])) * var_u_0)) + var_u2_0) - var_v2_0)))), ((const_table[104] * sqrt((((const_table[105] + ((const_table[106] * sqrt(const_table[107])) * var_v_0)) - var_u2_0) + var_v2_0))) - (const_table[108] * sqrt((((const_table[109] - ((const_table[110] * sqrt(const_table[111])) * var_v_0)) - var_u2_0) + var_v2_0)))));
+}
+void fn_6_69_hexagon(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table, constant LiveUniforms &live_uniforms) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[94]);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[95] * live_uniforms.live_6_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_68_rect(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[89]);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[90], const_table[91]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[92], const_table[93])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_61_circle(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_58_subtract(thread struct_6_28 & var_self_0, constant const float *const_table) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+void fn_6_57_intersect(thread struct_6_28 & var_self_0, constant const float *const_table) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[83], const_table[84]);
+    float var_wb_0 = const_table[85];
+    if(var_self_0.f_blur > const_table[86]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[87], const_table[88]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[79] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[80]) {
+            float var_f2_0 = (const_table[81] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[82] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_6_48_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[76]));
+    (var_self_0.f_clip = -const_table[77]);
+    (var_self_0.f_has_clip = const_table[78]);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[75] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[65]),float2(const_table[66]),float2(const_table[67]),const_table[68],-const_table[69],const_table[70],const_table[71],const_table[72],fn_6_41_antialias (var_pos_0, const_table),const_table[73],const_table[74]};
+}
+float4 fn_6_25_hsv2rgb(float4 var_c_0, constant const float *const_table) {
+    float4 var_K_0 = float4(const_table[56], (const_table[57] / const_table[58]), (const_table[59] / const_table[60]), const_table[61]);
+    float3 var_p_0 = abs(((fract((var_c_0.xxx + var_K_0.xyz)) * const_table[62]) - var_K_0.www));
+    return float4((var_c_0.z * mix(var_K_0.xxx, clamp((var_p_0 - var_K_0.xxx), const_table[63], const_table[64]), var_c_0.y)), var_c_0.w);
+}
+float4 fn_10_12_pixel(constant const float *const_table, thread Varyings &varyings, constant LiveUniforms &live_uniforms) {
+    float4 var_rgbv_0 = fn_6_25_hsv2rgb (float4(varyings.ds_hue, varyings.ds_sat, varyings.ds_val, const_table[1]), const_table);
+    float var_w_0 = varyings.ds_rect_size.x;
+    float var_h_0 = varyings.ds_rect_size.y;
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * float2(var_w_0, var_h_0)), const_table);
+    float var_cx_0 = (var_w_0 * const_table[2]);
+    float var_cy_0 = (var_h_0 * const_table[3]);
+    float var_radius_0 = (var_w_0 * const_table[4]);
+    float var_inner_0 = (var_w_0 * const_table[5]);
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[6]), const_table, live_uniforms);
+    fn_6_69_hexagon (var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[7]), const_table, live_uniforms);
+    fn_6_58_subtract (var_sdf_0, const_table);
+    float var_ang_0 = (((atan2(((varyings.ds_pos.x * var_w_0) - var_cx_0), ((const_table[8] + (varyings.ds_pos.y * var_h_0)) - var_cy_0)) / live_uniforms.live_6_1) * const_table[9]) - const_table[10]);
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (float4(var_ang_0, const_table[11], const_table[12], const_table[13]), const_table), const_table);
+    float var_rsize_0 = (var_inner_0 / sqrt(const_table[14]));
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[15]), (var_rsize_0 * const_table[16]), const_table);
+    float2 var_norm_rect_0 = (float2(((varyings.ds_pos.x * var_w_0) - (var_cx_0 - var_inner_0)), ((varyings.ds_pos.y * var_h_0) - (var_cy_0 - var_inner_0))) / (const_table[17] * var_inner_0));
+    float2 var_circ_0 = clamp(fn_10_15_circ_to_rect (((var_norm_rect_0.x * const_table[18]) - const_table[19]), ((var_norm_rect_0.y * const_table[20]) - const_table[21]), const_table), float2(-const_table[22]), float2(const_table[23]));
+    fn_6_49_fill (var_sdf_0, fn_6_25_hsv2rgb (float4(varyings.ds_hue, ((var_circ_0.x * const_table[24]) + const_table[25]), (const_table[26] - ((var_circ_0.y * const_table[27]) + const_table[28])), const_table[29]), const_table), const_table);
+    float var_col_angle_0 = (((varyings.ds_hue + const_table[30]) * const_table[31]) * live_uniforms.live_6_1);
+    float2 var_circle_puk_0 = float2(((sin(var_col_angle_0) * var_radius_0) + var_cx_0), ((cos(var_col_angle_0) * var_radius_0) + var_cy_0));
+    float2 var_rect_puk_0 = float2(((var_cx_0 + ((varyings.ds_sat * const_table[32]) * var_rsize_0)) - var_rsize_0), ((var_cy_0 + (((const_table[33] - varyings.ds_val) * const_table[34]) * var_rsize_0)) - var_rsize_0));
+    float4 var_color_0 = mix(mix(float4(const_table[35], const_table[36], const_table[37], const_table[38]), float4(const_table[39], const_table[40], const_table[41], const_table[42]), varyings.ds_hover), float4(const_table[43], const_table[44], const_table[45], const_table[46]), varyings.ds_pressed);
+    float var_puck_size_0 = (const_table[47] * var_w_0);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, var_puck_size_0, const_table);
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[48]), (var_rsize_0 * const_table[49]), const_table);
+    fn_6_57_intersect (var_sdf_0, const_table);
+    fn_6_49_fill (var_sdf_0, var_color_0, const_table);
+    fn_6_61_circle (var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, (((var_puck_size_0 - const_table[50]) - (const_table[51] * varyings.ds_hover)) + varyings.ds_pressed), const_table);
+    fn_6_68_rect (var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[52]), (var_rsize_0 * const_table[53]), const_table);
+    fn_6_57_intersect (var_sdf_0, const_table);
+    fn_6_49_fill (var_sdf_0, var_rgbv_0, const_table);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, var_puck_size_0, const_table);
+    fn_6_49_fill (var_sdf_0, var_color_0, const_table);
+    fn_6_61_circle (var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, (((var_puck_size_0 - const_table[54]) - (const_table[55] * varyings.ds_hover)) + varyings.ds_pressed), const_table);
+    fn_6_49_fill (var_sdf_0, var_rgbv_0, const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_10_9_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_10_11_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_10_9_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_hue = instances.ds_hue;
+    varyings.ds_sat = instances.ds_sat;
+    varyings.ds_val = instances.ds_val;
+    varyings.position = fn_10_11_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_10_12_pixel(const_table, varyings, live_uniforms);
+}
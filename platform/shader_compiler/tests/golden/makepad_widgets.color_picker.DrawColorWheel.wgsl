@@ -0,0 +1,197 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+struct LiveUniforms {
+    live_6_1: f32,
+    live_6_8: f32,
+};
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 29>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_hover: f32,
+    @location(2) ds_pressed: f32,
+    @location(3) ds_rect_pos: vec2<f32>,
+    @location(4) ds_rect_size: vec2<f32>,
+    @location(5) ds_draw_clip: vec4<f32>,
+    @location(6) ds_draw_depth: f32,
+    @location(7) pad_0: f32,
+    @location(8) ds_hue: f32,
+    @location(9) ds_sat: f32,
+    @location(10) ds_val: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_hover: f32,
+    @location(1) ds_pressed: f32,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_hue: f32,
+    @location(4) ds_sat: f32,
+    @location(5) ds_val: f32,
+    @location(6) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_10_15_circ_to_rect(var_u_0: f32, var_v_0: f32) -> vec2<f32> {
+    var var_u2_0: f32 = (var_u_0 * var_u_0);
+    var var_v2_0: f32 = (var_v_0 * var_v_0);
+    return vec2<f32>(((const_table[24].x * sqrt((((const_table[24].y + ((const_table[24].z * sqrt(const_table[24].w)) * var_u_0)) + var_u2_0) - var_v2_0))) - (const_table[25].x * sqrt((((const_table[25].y - ((const_table[25].z * sqrt(const_table[25].w)) * var_u_0)) + var_u2_0) - var_v2_0)))), ((const_table[26].x * sqrt((((const_table[26].y + ((const_table[26].z * sqrt(const_table[26].w)) * var_v_0)) - var_u2_0) + var_v2_0))) - (const_table[27].x * sqrt((((const_table[27].y - ((const_table[27].z * sqrt(const_table[27].w)) * var_v_0)) - var_u2_0) + var_v2_0)))));
+}
+fn fn_6_69_hexagon(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_r_0: f32) {
+    var var_dx_0: f32 = (abs((var_x_0 - (*var_self_0).f_pos.x)) * const_table[23].z);
+    var var_dy_0: f32 = abs((var_y_0 - (*var_self_0).f_pos.y));
+    (*var_self_0).f_dist = max(((var_dy_0 + (cos((const_table[23].w * live_uniforms.live_6_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0));
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_68_rect(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32) {
+    var var_s_0: vec2<f32> = (vec2<f32>(var_w_0, var_h_0) * const_table[22].y);
+    var var_d_0: vec2<f32> = (abs(((vec2<f32>(var_x_0, var_y_0) - (*var_self_0).f_pos) + var_s_0)) - var_s_0);
+    var var_dm_0: vec2<f32> = min(var_d_0, vec2<f32>(const_table[22].z, const_table[22].w));
+    (*var_self_0).f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2<f32>(const_table[23].x, const_table[23].y))));
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_61_circle(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_r_0: f32) {
+    var var_c_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_len_0: f32 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (*var_self_0).f_dist = ((var_len_0 - var_r_0) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_58_subtract(var_self_0: ptr<function, struct_6_28>) {
+    (*var_self_0).f_shape = max(-(*var_self_0).f_dist, (*var_self_0).f_old_shape);    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+}
+fn fn_6_57_intersect(var_self_0: ptr<function, struct_6_28>) {
+    (*var_self_0).f_shape = max((*var_self_0).f_dist, (*var_self_0).f_old_shape);    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[20].w, const_table[21].x);
+    var var_wb_0: f32 = const_table[21].y;
+    if((*var_self_0).f_blur > const_table[21].z) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[21].w, const_table[22].x);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_48_fill_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[19].w - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[20].x) {
+            var var_f2_0: f32 = (const_table[20].y - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[20].z - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_49_fill(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (*var_self_0).f_shape = const_table[19].x;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[19].y;
+    (*var_self_0).f_has_clip = const_table[19].z;
+    return (*var_self_0).f_result;
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[18].w / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[16].y),vec2<f32>(const_table[16].z),vec2<f32>(const_table[16].w),const_table[17].x,-const_table[17].y,const_table[17].z,const_table[17].w,const_table[18].x,fn_6_41_antialias (var_pos_0),const_table[18].y,const_table[18].z);
+}
+fn fn_6_25_hsv2rgb(var_c_0: vec4<f32>) -> vec4<f32> {
+    var var_K_0: vec4<f32> = vec4<f32>(const_table[14].x, (const_table[14].y / const_table[14].z), (const_table[14].w / const_table[15].x), const_table[15].y);
+    var var_p_0: vec3<f32> = abs(((fract((var_c_0.xxx + var_K_0.xyz)) * const_table[15].z) - var_K_0.www));
+    return vec4<f32>((var_c_0.z * mix(var_K_0.xxx, clamp((var_p_0 - var_K_0.xxx), vec3<f32>(const_table[15].w), vec3<f32>(const_table[16].x)), vec3<f32>(var_c_0.y))), var_c_0.w);
+}
+fn fn_10_12_pixel() -> vec4<f32> {
+    var var_rgbv_0: vec4<f32> = fn_6_25_hsv2rgb (vec4<f32>(varyings.ds_hue, varyings.ds_sat, varyings.ds_val, const_table[0].y));
+    var var_w_0: f32 = varyings.ds_rect_size.x;
+    var var_h_0: f32 = varyings.ds_rect_size.y;
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * vec2<f32>(var_w_0, var_h_0)));
+    var var_cx_0: f32 = (var_w_0 * const_table[0].z);
+    var var_cy_0: f32 = (var_h_0 * const_table[0].w);
+    var var_radius_0: f32 = (var_w_0 * const_table[1].x);
+    var var_inner_0: f32 = (var_w_0 * const_table[1].y);
+    fn_6_69_hexagon (&var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[1].z));
+    fn_6_69_hexagon (&var_sdf_0, var_cx_0, var_cy_0, (var_w_0 * const_table[1].w));
+    fn_6_58_subtract (&var_sdf_0);
+    var var_ang_0: f32 = (((atan2(((varyings.ds_pos.x * var_w_0) - var_cx_0), ((const_table[2].x + (varyings.ds_pos.y * var_h_0)) - var_cy_0)) / live_uniforms.live_6_1) * const_table[2].y) - const_table[2].z);
+    fn_6_49_fill (&var_sdf_0, fn_6_25_hsv2rgb (vec4<f32>(var_ang_0, const_table[2].w, const_table[3].x, const_table[3].y)));
+    var var_rsize_0: f32 = (var_inner_0 / sqrt(const_table[3].z));
+    fn_6_68_rect (&var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[3].w), (var_rsize_0 * const_table[4].x));
+    var var_norm_rect_0: vec2<f32> = (vec2<f32>(((varyings.ds_pos.x * var_w_0) - (var_cx_0 - var_inner_0)), ((varyings.ds_pos.y * var_h_0) - (var_cy_0 - var_inner_0))) / (const_table[4].y * var_inner_0));
+    var var_circ_0: vec2<f32> = clamp(fn_10_15_circ_to_rect (((var_norm_rect_0.x * const_table[4].z) - const_table[4].w), ((var_norm_rect_0.y * const_table[5].x) - const_table[5].y)), vec2<f32>(-const_table[5].z), vec2<f32>(const_table[5].w));
+    fn_6_49_fill (&var_sdf_0, fn_6_25_hsv2rgb (vec4<f32>(varyings.ds_hue, ((var_circ_0.x * const_table[6].x) + const_table[6].y), (const_table[6].z - ((var_circ_0.y * const_table[6].w) + const_table[7].x)), const_table[7].y)));
+    var var_col_angle_0: f32 = (((varyings.ds_hue + const_table[7].z) * const_table[7].w) * live_uniforms.live_6_1);
+    var var_circle_puk_0: vec2<f32> = vec2<f32>(((sin(var_col_angle_0) * var_radius_0) + var_cx_0), ((cos(var_col_angle_0) * var_radius_0) + var_cy_0));
+    var var_rect_puk_0: vec2<f32> = vec2<f32>(((var_cx_0 + ((varyings.ds_sat * const_table[8].x) * var_rsize_0)) - var_rsize_0), ((var_cy_0 + (((const_table[8].y - varyings.ds_val) * const_table[8].z) * var_rsize_0)) - var_rsize_0));
+    var var_color_0: vec4<f32> = mix(mix(vec4<f32>(const_table[8].w, const_table[9].x, const_table[9].y, const_table[9].z), vec4<f32>(const_table[9].w, const_table[10].x, const_table[10].y, const_table[10].z), vec4<f32>(varyings.ds_hover)), vec4<f32>(const_table[10].w, const_table[11].x, const_table[11].y, const_table[11].z), vec4<f32>(varyings.ds_pressed));
+    var var_puck_size_0: f32 = (const_table[11].w * var_w_0);
+    fn_6_61_circle (&var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, var_puck_size_0);
+    fn_6_68_rect (&var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[12].x), (var_rsize_0 * const_table[12].y));
+    fn_6_57_intersect (&var_sdf_0);
+    fn_6_49_fill (&var_sdf_0, var_color_0);
+    fn_6_61_circle (&var_sdf_0, var_rect_puk_0.x, var_rect_puk_0.y, (((var_puck_size_0 - const_table[12].z) - (const_table[12].w * varyings.ds_hover)) + varyings.ds_pressed));
+    fn_6_68_rect (&var_sdf_0, (var_cx_0 - var_rsize_0), (var_cy_0 - var_rsize_0), (var_rsize_0 * const_table[13].x), (var_rsize_0 * const_table[13].y));
+    fn_6_57_intersect (&var_sdf_0);
+    fn_6_49_fill (&var_sdf_0, var_rgbv_0);
+    fn_6_61_circle (&var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, var_puck_size_0);
+    fn_6_49_fill (&var_sdf_0, var_color_0);
+    fn_6_61_circle (&var_sdf_0, var_circle_puk_0.x, var_circle_puk_0.y, (((var_puck_size_0 - const_table[13].z) - (const_table[13].w * varyings.ds_hover)) + varyings.ds_pressed));
+    fn_6_49_fill (&var_sdf_0, var_rgbv_0);
+    return var_sdf_0.f_result;
+}
+fn fn_10_9_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_10_11_vertex() -> vec4<f32> {
+    return fn_10_9_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_hue = instances.ds_hue;
+    varyings.ds_sat = instances.ds_sat;
+    varyings.ds_val = instances.ds_val;
+    varyings.position = fn_10_11_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_10_12_pixel();
+}
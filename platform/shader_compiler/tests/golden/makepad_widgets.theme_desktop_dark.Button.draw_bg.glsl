@@ -0,0 +1,258 @@
+// vertex
+uniform float const_table[56];
+
+uniform float live_table[24];
+vec4 live_41_147 = vec4(0.0);
+vec4 live_41_148 = vec4(0.0);
+vec4 live_41_149 = vec4(0.0);
+vec4 live_41_150 = vec4(0.0);
+vec4 live_41_151 = vec4(0.0);
+vec4 live_41_152 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float user_table[1];
+float ds_border_radius = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec3 packed_instance_2;
+
+varying vec4 packed_varying_0;
+varying vec2 packed_varying_1;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_41_458_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_41_460_vertex() {
+    return fn_41_458_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_border_radius = user_table[0];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_147 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_148 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_149 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_150 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    live_41_151 = vec4(live_table[16], live_table[17], live_table[18], live_table[19]);
+    live_41_152 = vec4(live_table[20], live_table[21], live_table[22], live_table[23]);
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_hover = packed_instance_0.x;
+    ds_pressed = packed_instance_0.y;
+    ds_rect_pos.xy = packed_instance_0.zw;
+    ds_rect_size.xy = packed_instance_1.xy;
+    ds_draw_clip.xy = packed_instance_1.zw;
+    ds_draw_clip.zw = packed_instance_2.xy;
+    ds_draw_depth = packed_instance_2.z;
+
+    gl_Position = fn_41_460_vertex();
+
+    packed_varying_0.x = ds_hover;
+    packed_varying_0.y = ds_pressed;
+    packed_varying_0.zw = ds_rect_size.xy;
+    packed_varying_1.xy = ds_pos.xy;
+}
+// pixel
+uniform float const_table[56];
+
+uniform float live_table[24];
+vec4 live_41_147 = vec4(0.0);
+vec4 live_41_148 = vec4(0.0);
+vec4 live_41_149 = vec4(0.0);
+vec4 live_41_150 = vec4(0.0);
+vec4 live_41_151 = vec4(0.0);
+vec4 live_41_152 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float user_table[1];
+float ds_border_radius = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec2 packed_varying_1;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_rect_size=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_6_71_line_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    vec2 var_p_0 = vec2(var_x_0, var_y_0);
+    vec2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    vec2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[50], const_table[51]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[52]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+
+void fn_6_70_move_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = vec2(var_x_0, var_y_0)));
+}
+
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_size_0 = vec2((const_table[43] * var_w_0), (const_table[44] * var_h_0));
+    vec2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2((const_table[45] * var_r_0), (const_table[46] * var_r_0)).xy)), vec2(const_table[47], const_table[48]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[49] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[37], const_table[38]);
+    float var_wb_0 = const_table[39];
+    if(var_self_0.f_blur > const_table[40]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[41], const_table[42]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_52_stroke_keep(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    vec4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[36] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_53_stroke(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[33]));
+    (var_self_0.f_clip = -const_table[34]);
+    (var_self_0.f_has_clip = const_table[35]);
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[29] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[30]) {
+            float var_f2_0 = (const_table[31] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[32] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[28] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[18]),vec2(const_table[19]),vec2(const_table[20]),const_table[21],-const_table[22],const_table[23],const_table[24],const_table[25],fn_6_41_antialias (var_pos_0),const_table[26],const_table[27]);
+}
+
+vec4 fn_41_461_pixel() {
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * ds_rect_size));
+    float var_grad_top_0 = const_table[1];
+    float var_grad_bot_0 = const_table[2];
+    vec4 var_body_0 = mix(mix(live_41_147, live_41_148, ds_hover), live_41_149, ds_pressed);
+    vec4 var_body_transp_0 = vec4(var_body_0.xyz, const_table[3]);
+    vec4 var_top_gradient_0 = mix(var_body_transp_0, mix(live_41_150, live_41_152, ds_pressed), (max(const_table[4], (var_grad_top_0 - var_sdf_0.f_pos.y)) / var_grad_top_0));
+    vec4 var_bot_gradient_0 = mix(mix(var_body_transp_0, live_41_148, ds_pressed), var_top_gradient_0, clamp(((((ds_rect_size.y - var_grad_bot_0) - var_sdf_0.f_pos.y) - const_table[5]) / var_grad_bot_0), const_table[6], const_table[7]));
+    float var_shift_inward_0 = (ds_border_radius + const_table[8]);
+    fn_6_70_move_to (var_sdf_0, var_shift_inward_0, (ds_rect_size.y - ds_border_radius));
+    fn_6_71_line_to (var_sdf_0, (ds_rect_size.x - var_shift_inward_0), (ds_rect_size.y - ds_border_radius));
+    fn_6_53_stroke (var_sdf_0, mix(mix(live_41_151, live_41_152, ds_hover), vec4(const_table[9], const_table[10], const_table[11], const_table[12]), ds_pressed), ds_border_radius);
+    fn_6_64_box (var_sdf_0, const_table[13], const_table[14], (ds_rect_size.x - const_table[15]), (ds_rect_size.y - const_table[16]), ds_border_radius);
+    fn_6_48_fill_keep (var_sdf_0, var_body_0);
+    fn_6_53_stroke (var_sdf_0, var_bot_gradient_0, const_table[17]);
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_border_radius = user_table[0];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_147 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_148 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_149 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_150 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    live_41_151 = vec4(live_table[16], live_table[17], live_table[18], live_table[19]);
+    live_41_152 = vec4(live_table[20], live_table[21], live_table[22], live_table[23]);
+    ds_hover = packed_varying_0.x;
+    ds_pressed = packed_varying_0.y;
+    ds_rect_size.xy = packed_varying_0.zw;
+    ds_pos.xy = packed_varying_1.xy;
+
+    gl_FragColor = fn_41_461_pixel();
+}
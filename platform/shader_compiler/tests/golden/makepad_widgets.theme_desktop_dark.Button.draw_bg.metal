@@ -0,0 +1,181 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float4 live_41_147;
+    float4 live_41_148;
+    float4 live_41_149;
+    float4 live_41_150;
+    float4 live_41_151;
+    float4 live_41_152;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_user {
+    float ds_border_radius;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_hover;
+    float ds_pressed;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_hover;
+    float ds_pressed;
+    float2 ds_rect_size;
+    float2 ds_pos;
+};
+void fn_6_71_line_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    float2 var_p_0 = float2(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[50], const_table[51]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[52]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_6_70_move_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = float2(var_x_0, var_y_0)));
+}
+void fn_6_64_box(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0, constant const float *const_table) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[43] * var_w_0), (const_table[44] * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[45] * var_r_0), (const_table[46] * var_r_0)).xy)), float2(const_table[47], const_table[48]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[49] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[37], const_table[38]);
+    float var_wb_0 = const_table[39];
+    if(var_self_0.f_blur > const_table[40]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[41], const_table[42]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[36] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[33]));
+    (var_self_0.f_clip = -const_table[34]);
+    (var_self_0.f_has_clip = const_table[35]);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[29] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[30]) {
+            float var_f2_0 = (const_table[31] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[32] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[28] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[18]),float2(const_table[19]),float2(const_table[20]),const_table[21],-const_table[22],const_table[23],const_table[24],const_table[25],fn_6_41_antialias (var_pos_0, const_table),const_table[26],const_table[27]};
+}
+float4 fn_41_461_pixel(constant const float *const_table, thread Varyings &varyings, constant Uniforms_user &uniforms_user, constant LiveUniforms &live_uniforms) {
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    float var_grad_top_0 = const_table[1];
+    float var_grad_bot_0 = const_table[2];
+    float4 var_body_0 = mix(mix(live_uniforms.live_41_147, live_uniforms.live_41_148, varyings.ds_hover), live_uniforms.live_41_149, varyings.ds_pressed);
+    float4 var_body_transp_0 = float4(var_body_0.xyz, const_table[3]);
+    float4 var_top_gradient_0 = mix(var_body_transp_0, mix(live_uniforms.live_41_150, live_uniforms.live_41_152, varyings.ds_pressed), (max(const_table[4], (var_grad_top_0 - var_sdf_0.f_pos.y)) / var_grad_top_0));
+    float4 var_bot_gradient_0 = mix(mix(var_body_transp_0, live_uniforms.live_41_148, varyings.ds_pressed), var_top_gradient_0, clamp(((((varyings.ds_rect_size.y - var_grad_bot_0) - var_sdf_0.f_pos.y) - const_table[5]) / var_grad_bot_0), const_table[6], const_table[7]));
+    float var_shift_inward_0 = (uniforms_user.ds_border_radius + const_table[8]);
+    fn_6_70_move_to (var_sdf_0, var_shift_inward_0, (varyings.ds_rect_size.y - uniforms_user.ds_border_radius), const_table);
+    fn_6_71_line_to (var_sdf_0, (varyings.ds_rect_size.x - var_shift_inward_0), (varyings.ds_rect_size.y - uniforms_user.ds_border_radius), const_table);
+    fn_6_53_stroke (var_sdf_0, mix(mix(live_uniforms.live_41_151, live_uniforms.live_41_152, varyings.ds_hover), float4(const_table[9], const_table[10], const_table[11], const_table[12]), varyings.ds_pressed), uniforms_user.ds_border_radius, const_table);
+    fn_6_64_box (var_sdf_0, const_table[13], const_table[14], (varyings.ds_rect_size.x - const_table[15]), (varyings.ds_rect_size.y - const_table[16]), uniforms_user.ds_border_radius, const_table);
+    fn_6_48_fill_keep (var_sdf_0, var_body_0, const_table);
+    fn_6_53_stroke (var_sdf_0, var_bot_gradient_0, const_table[17], const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_41_458_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_41_460_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_41_458_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_41_460_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+) {
+    return     fn_41_461_pixel(const_table, varyings, uniforms_user, live_uniforms);
+}
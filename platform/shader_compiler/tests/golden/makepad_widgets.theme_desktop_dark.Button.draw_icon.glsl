@@ -0,0 +1,172 @@
+// vertex
+uniform float const_table[8];
+
+uniform float live_table[8];
+vec4 live_41_136 = vec4(0.0);
+vec4 live_41_138 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float user_table[2];
+float ds_u_brightness = 0.0;
+float ds_u_curve = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+uniform sampler2D ds_tex;
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute vec3 packed_instance_4;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+vec4 ds_color=vec4(0.0);
+vec2 ds_icon_t1=vec2(0.0);
+vec2 ds_icon_t2=vec2(0.0);
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+vec2 ds_tex_coord1=vec2(0.0);
+vec2 ds_clipped=vec2(0.0);
+
+vec4 fn_41_507_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    (ds_tex_coord1 = mix(ds_icon_t1.xy, ds_icon_t2.xy, ds_pos.xy));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_41_508_vertex() {
+    return fn_41_507_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_u_brightness = user_table[0];
+    ds_u_curve = user_table[1];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_136 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_138 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_hover = packed_instance_0.x;
+    ds_pressed = packed_instance_0.y;
+    ds_rect_pos.xy = packed_instance_0.zw;
+    ds_rect_size.xy = packed_instance_1.xy;
+    ds_draw_clip.xy = packed_instance_1.zw;
+    ds_draw_clip.zw = packed_instance_2.xy;
+    ds_draw_depth = packed_instance_2.z;
+    ds_color.x = packed_instance_2.w;
+    ds_color.yzw = packed_instance_3.xyz;
+    ds_icon_t1.x = packed_instance_3.w;
+    ds_icon_t1.y = packed_instance_4.x;
+    ds_icon_t2.xy = packed_instance_4.yz;
+
+    gl_Position = fn_41_508_vertex();
+
+    packed_varying_0.x = ds_hover;
+    packed_varying_0.y = ds_pressed;
+    packed_varying_0.zw = ds_pos.xy;
+    packed_varying_1.xy = ds_tex_coord1.xy;
+    packed_varying_1.zw = ds_clipped.xy;
+}
+// pixel
+uniform float const_table[8];
+
+uniform float live_table[8];
+vec4 live_41_136 = vec4(0.0);
+vec4 live_41_138 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float user_table[2];
+float ds_u_brightness = 0.0;
+float ds_u_curve = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+uniform sampler2D ds_tex;
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_pos=vec2(0.0);
+vec2 ds_tex_coord1=vec2(0.0);
+vec2 ds_clipped=vec2(0.0);
+
+vec4 fn_41_509_get_color() {
+    return mix(mix(live_41_136, live_41_138, ds_hover), live_41_136, ds_pressed);
+}
+
+vec4 fn_41_510_pixel() {
+    float var_dx_0 = dFdx(vec2((ds_tex_coord1.x * const_table[1]), const_table[2])).x;
+    float var_dp_0 = (const_table[3] / const_table[4]);
+    float var_s_0 = sample2d_rt(ds_tex, ds_tex_coord1.xy).x;
+    (var_s_0 = pow(var_s_0, ds_u_curve));
+    vec4 var_col_0 = fn_41_509_get_color ();
+    return vec4((((var_s_0 * var_col_0.rgb) * ds_u_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_u_brightness = user_table[0];
+    ds_u_curve = user_table[1];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_136 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_138 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    ds_hover = packed_varying_0.x;
+    ds_pressed = packed_varying_0.y;
+    ds_pos.xy = packed_varying_0.zw;
+    ds_tex_coord1.xy = packed_varying_1.xy;
+    ds_clipped.xy = packed_varying_1.zw;
+
+    gl_FragColor = fn_41_510_pixel();
+}
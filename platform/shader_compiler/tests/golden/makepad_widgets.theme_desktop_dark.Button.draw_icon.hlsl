@@ -0,0 +1,77 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d_rt(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+cbuffer LiveUniforms : register(b0) {
+    float4 live_41_136;
+    float4 live_41_138;
+};
+cbuffer ConstTable : register(b1){float4 const_table[2];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_user : register(b5) {
+    float ds_u_brightness;
+    float ds_u_curve;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_tex: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_hover: INSTA;
+    float ds_pressed: INSTB;
+    float2 ds_rect_pos: INSTC;
+    float2 ds_rect_size: INSTD;
+    float4 ds_draw_clip: INSTE;
+    float ds_draw_depth: INSTF;
+    float4 ds_color: INSTG;
+    float2 ds_icon_t1: INSTH;
+    float2 ds_icon_t2: INSTI;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_hover: VARYA;
+    float ds_pressed: VARYB;
+    float2 ds_pos: VARYC;
+    float2 ds_tex_coord1: VARYD;
+    float2 ds_clipped: VARYE;
+};
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+float4 fn_41_509_get_color(inout Varyings varyings) {
+    return lerp(lerp(live_41_136, live_41_138, varyings.ds_hover), live_41_136, varyings.ds_pressed);
+}
+float4 fn_41_510_pixel(inout Varyings varyings) {
+    float var_dx_0 = ddx(float2((varyings.ds_tex_coord1.x * const_table[0].y), const_table[0].z)).x;
+    float var_dp_0 = (const_table[0].w / const_table[1].x);
+    float var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord1.xy).x;
+    (var_s_0 = pow(var_s_0, ds_u_curve));
+    float4 var_col_0 = fn_41_509_get_color (varyings);
+    return consfn_vec4_vec3_float((((var_s_0 * var_col_0.rgb) * ds_u_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+float4 fn_41_507_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    (varyings.ds_tex_coord1 = lerp(instances.ds_icon_t1.xy, instances.ds_icon_t2.xy, varyings.ds_pos.xy));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_41_508_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_41_507_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.position = fn_41_508_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_41_510_pixel(varyings);
+}
@@ -0,0 +1,223 @@
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct_6_28 consfn_struct_6_28(
+float2 f_pos, float4 f_result, float2 f_last_pos, float2 f_start_pos, float f_shape, float f_clip, float f_has_clip, float f_old_shape, float f_blur, float f_aa, float f_scale_factor, float f_dist){;
+struct_6_28 r;
+r.f_pos = f_pos;
+r.f_result = f_result;
+r.f_last_pos = f_last_pos;
+r.f_start_pos = f_start_pos;
+r.f_shape = f_shape;
+r.f_clip = f_clip;
+r.f_has_clip = f_has_clip;
+r.f_old_shape = f_old_shape;
+r.f_blur = f_blur;
+r.f_aa = f_aa;
+r.f_scale_factor = f_scale_factor;
+r.f_dist = f_dist;
+return r;
+}
+cbuffer LiveUniforms : register(b0) {
+    float4 live_41_153;
+    float4 live_41_154;
+};
+cbuffer ConstTable : register(b1){float4 const_table[24];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_user : register(b5) {
+    float ds_size;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    int ds_check_type: INSTF;
+    float ds_hover: INSTG;
+    float ds_focus: INSTH;
+    float ds_selected: INSTI;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_rect_size: VARYA;
+    int ds_check_type: VARYB;
+    float ds_hover: VARYC;
+    float ds_selected: VARYD;
+    float2 ds_pos: VARYE;
+};
+float2 consfn_vec2_float(float x) {    return float2(x, x);}
+float4 consfn_vec4_float(float x) {    return float4(x, x, x, x);}
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+void fn_6_71_line_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    float2 var_p_0 = float2(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[22].z, const_table[22].w);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[23].x);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_6_70_move_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = float2(var_x_0, var_y_0)));
+}
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[20].w * var_w_0), (const_table[21].x * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[21].y * var_r_0), (const_table[21].z * var_r_0)).xy)), float2(const_table[21].w, const_table[22].x));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[22].y * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_61_circle(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_60_blend(inout struct_6_28 var_self_0, float var_k_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = lerp(var_self_0.f_old_shape, var_self_0.f_dist, var_k_0)));
+}
+void fn_6_58_subtract(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[19].y, const_table[19].z);
+    float var_wb_0 = const_table[19].w;
+    if(var_self_0.f_blur > const_table[20].x) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[20].y, const_table[20].z));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(inout struct_6_28 var_self_0, float4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[19].x - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(inout struct_6_28 var_self_0, float4 var_color_0, float var_width_0) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[18].y));
+    (var_self_0.f_clip = -const_table[18].z);
+    (var_self_0.f_has_clip = const_table[18].w);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[17].y - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[17].z) {
+            float var_f2_0 = (const_table[17].w - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[18].x - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(inout struct_6_28 var_self_0, float4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[16].z));
+    (var_self_0.f_clip = -const_table[16].w);
+    (var_self_0.f_has_clip = const_table[17].x);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0) {
+    return (const_table[16].y / length(float2(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_6_28 fn_6_42_viewport(float2 var_pos_0) {
+    return consfn_struct_6_28(var_pos_0,consfn_vec4_float(const_table[13].w),consfn_vec2_float(const_table[14].x),consfn_vec2_float(const_table[14].y),const_table[14].z,-const_table[14].w,const_table[15].x,const_table[15].y,const_table[15].z,fn_6_41_antialias (var_pos_0),const_table[15].w,const_table[16].x);
+}
+float4 fn_41_650_pixel(inout Varyings varyings) {
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    if(varyings.ds_check_type == 1){
+            float var_left_0 = const_table[0].y;
+        float var_sz_0 = ds_size;
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[0].z));
+        fn_6_64_box (var_sdf_0, var_left_0, (var_c_0.y - var_sz_0), (var_sz_0 * const_table[0].w), (var_sz_0 * const_table[1].x), const_table[1].y);
+        fn_6_48_fill_keep (var_sdf_0, lerp(lerp(float4(const_table[1].z, const_table[1].w, const_table[2].x, const_table[2].y), float4(const_table[2].z, const_table[2].w, const_table[3].x, const_table[3].y), pow(varyings.ds_pos.y, const_table[3].z)), lerp(float4(const_table[3].w, const_table[4].x, const_table[4].y, const_table[4].z), float4(const_table[4].w, const_table[5].x, const_table[5].y, const_table[5].z), pow(varyings.ds_pos.y, const_table[5].w)), varyings.ds_hover));
+        fn_6_53_stroke (var_sdf_0, float4(const_table[6].x, const_table[6].y, const_table[6].z, const_table[6].w), const_table[7].x);
+        float var_szs_0 = (var_sz_0 * const_table[7].y);
+        float var_dx_0 = const_table[7].z;
+        fn_6_70_move_to (var_sdf_0, (var_left_0 + const_table[7].w), var_c_0.y);
+        fn_6_71_line_to (var_sdf_0, var_c_0.x, (var_c_0.y + var_szs_0));
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_szs_0), (var_c_0.y - var_szs_0));
+        fn_6_53_stroke (var_sdf_0, lerp(float4(const_table[8].x, const_table[8].y, const_table[8].z, const_table[8].w), live_41_154, varyings.ds_selected), const_table[9].x);
+    }else  if(varyings.ds_check_type == 2){
+            float var_sz_0 = ds_size;
+        float var_left_0 = (var_sz_0 + const_table[9].y);
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[9].z));
+        fn_6_61_circle (var_sdf_0, var_left_0, var_c_0.y, var_sz_0);
+        fn_6_49_fill (var_sdf_0, live_41_153);
+        float var_isz_0 = (var_sz_0 * const_table[9].w);
+        fn_6_61_circle (var_sdf_0, var_left_0, var_c_0.y, var_isz_0);
+        fn_6_49_fill (var_sdf_0, lerp(float4(const_table[10].x, const_table[10].y, const_table[10].z, const_table[10].w), live_41_154, varyings.ds_selected));
+    }else  if(varyings.ds_check_type == 3){
+            float var_sz_0 = ds_size;
+        float var_left_0 = (var_sz_0 + const_table[11].x);
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[11].y));
+        fn_6_64_box (var_sdf_0, var_left_0, (var_c_0.y - var_sz_0), (var_sz_0 * const_table[11].z), (var_sz_0 * const_table[11].w), (const_table[12].x * var_sz_0));
+        fn_6_49_fill (var_sdf_0, live_41_153);
+        float var_isz_0 = (var_sz_0 * const_table[12].y);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, var_isz_0);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, (const_table[12].z * var_isz_0));
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, var_isz_0);
+        fn_6_60_blend (var_sdf_0, varyings.ds_selected);
+        fn_6_49_fill (var_sdf_0, live_41_154);
+    }else  if(varyings.ds_check_type == 4){
+            return float4(const_table[12].w, const_table[13].x, const_table[13].y, const_table[13].z);
+    }
+    return var_sdf_0.f_result;
+}
+float4 fn_41_647_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_41_649_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_41_647_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), 0, 0.0, 0.0, float2(0.0,0.0)};
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_check_type = instances.ds_check_type;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.position = fn_41_649_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_41_650_pixel(varyings);
+}
@@ -0,0 +1,227 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float4 live_41_153;
+    float4 live_41_154;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_user {
+    float ds_size;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    uint32_t  ds_check_type;
+    float ds_hover;
+    float ds_focus;
+    float ds_selected;
+};
+struct Varyings {
+    float4 position [[position]];
+    float2 ds_rect_size;
+    uint32_t  ds_check_type;
+    float ds_hover;
+    float ds_selected;
+    float2 ds_pos;
+};
+void fn_6_71_line_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    float2 var_p_0 = float2(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[90], const_table[91]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[92]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_6_70_move_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = float2(var_x_0, var_y_0)));
+}
+void fn_6_64_box(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0, constant const float *const_table) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[83] * var_w_0), (const_table[84] * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[85] * var_r_0), (const_table[86] * var_r_0)).xy)), float2(const_table[87], const_table[88]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[89] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_61_circle(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_60_blend(thread struct_6_28 & var_self_0, float var_k_0, constant const float *const_table) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = mix(var_self_0.f_old_shape, var_self_0.f_dist, var_k_0)));
+}
+void fn_6_58_subtract(thread struct_6_28 & var_self_0, constant const float *const_table) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[77], const_table[78]);
+    float var_wb_0 = const_table[79];
+    if(var_self_0.f_blur > const_table[80]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[81], const_table[82]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[76] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[73]));
+    (var_self_0.f_clip = -const_table[74]);
+    (var_self_0.f_has_clip = const_table[75]);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[69] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[70]) {
+            float var_f2_0 = (const_table[71] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[72] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_6_48_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[66]));
+    (var_self_0.f_clip = -const_table[67]);
+    (var_self_0.f_has_clip = const_table[68]);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[65] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[55]),float2(const_table[56]),float2(const_table[57]),const_table[58],-const_table[59],const_table[60],const_table[61],const_table[62],fn_6_41_antialias (var_pos_0, const_table),const_table[63],const_table[64]};
+}
+float4 fn_41_650_pixel(constant const float *const_table, thread Varyings &varyings, constant Uniforms_user &uniforms_user, constant LiveUniforms &live_uniforms) {
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    if(varyings.ds_check_type == 1){
+            float var_left_0 = const_table[1];
+        float var_sz_0 = uniforms_user.ds_size;
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[2]));
+        fn_6_64_box (var_sdf_0, var_left_0, (var_c_0.y - var_sz_0), (var_sz_0 * const_table[3]), (var_sz_0 * const_table[4]), const_table[5], const_table);
+        fn_6_48_fill_keep (var_sdf_0, mix(mix(float4(const_table[6], const_table[7], const_table[8], const_table[9]), float4(const_table[10], const_table[11], const_table[12], const_table[13]), pow(varyings.ds_pos.y, const_table[14])), mix(float4(const_table[15], const_table[16], const_table[17], const_table[18]), float4(const_table[19], const_table[20], const_table[21], const_table[22]), pow(varyings.ds_pos.y, const_table[23])), varyings.ds_hover), const_table);
+        fn_6_53_stroke (var_sdf_0, float4(const_table[24], const_table[25], const_table[26], const_table[27]), const_table[28], const_table);
+        float var_szs_0 = (var_sz_0 * const_table[29]);
+        float var_dx_0 = const_table[30];
+        fn_6_70_move_to (var_sdf_0, (var_left_0 + const_table[31]), var_c_0.y, const_table);
+        fn_6_71_line_to (var_sdf_0, var_c_0.x, (var_c_0.y + var_szs_0), const_table);
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_szs_0), (var_c_0.y - var_szs_0), const_table);
+        fn_6_53_stroke (var_sdf_0, mix(float4(const_table[32], const_table[33], const_table[34], const_table[35]), live_uniforms.live_41_154, varyings.ds_selected), const_table[36], const_table);
+    }else  if(varyings.ds_check_type == 2){
+            float var_sz_0 = uniforms_user.ds_size;
+        float var_left_0 = (var_sz_0 + const_table[37]);
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[38]));
+        fn_6_61_circle (var_sdf_0, var_left_0, var_c_0.y, var_sz_0, const_table);
+        fn_6_49_fill (var_sdf_0, live_uniforms.live_41_153, const_table);
+        float var_isz_0 = (var_sz_0 * const_table[39]);
+        fn_6_61_circle (var_sdf_0, var_left_0, var_c_0.y, var_isz_0, const_table);
+        fn_6_49_fill (var_sdf_0, mix(float4(const_table[40], const_table[41], const_table[42], const_table[43]), live_uniforms.live_41_154, varyings.ds_selected), const_table);
+    }else  if(varyings.ds_check_type == 3){
+            float var_sz_0 = uniforms_user.ds_size;
+        float var_left_0 = (var_sz_0 + const_table[44]);
+        float2 var_c_0 = float2((var_left_0 + var_sz_0), (varyings.ds_rect_size.y * const_table[45]));
+        fn_6_64_box (var_sdf_0, var_left_0, (var_c_0.y - var_sz_0), (var_sz_0 * const_table[46]), (var_sz_0 * const_table[47]), (const_table[48] * var_sz_0), const_table);
+        fn_6_49_fill (var_sdf_0, live_uniforms.live_41_153, const_table);
+        float var_isz_0 = (var_sz_0 * const_table[49]);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, var_isz_0, const_table);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, (const_table[50] * var_isz_0), const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_61_circle (var_sdf_0, ((var_left_0 + var_sz_0) + (varyings.ds_selected * var_sz_0)), var_c_0.y, var_isz_0, const_table);
+        fn_6_60_blend (var_sdf_0, varyings.ds_selected, const_table);
+        fn_6_49_fill (var_sdf_0, live_uniforms.live_41_154, const_table);
+    }else  if(varyings.ds_check_type == 4){
+            return float4(const_table[51], const_table[52], const_table[53], const_table[54]);
+    }
+    return var_sdf_0.f_result;
+}
+float4 fn_41_647_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_41_649_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_41_647_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_check_type = instances.ds_check_type;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.position = fn_41_649_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+) {
+    return     fn_41_650_pixel(const_table, varyings, uniforms_user, live_uniforms);
+}
@@ -0,0 +1,325 @@
+// vertex
+uniform float const_table[128];
+
+uniform float live_table[16];
+vec4 live_41_155 = vec4(0.0);
+vec4 live_41_156 = vec4(0.0);
+vec4 live_41_157 = vec4(0.0);
+vec4 live_41_158 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute float packed_instance_3;
+
+varying vec4 packed_varying_0;
+varying vec3 packed_varying_1;
+
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+float ds_hover=0.0;
+float ds_pressed=0.0;
+float ds_button_type=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_41_843_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_41_845_vertex() {
+    return fn_41_843_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_155 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_156 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_157 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_158 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_rect_pos.xy = packed_instance_0.xy;
+    ds_rect_size.xy = packed_instance_0.zw;
+    ds_draw_clip.xyzw = packed_instance_1.xyzw;
+    ds_draw_depth = packed_instance_2.x;
+    ds_0 = packed_instance_2.y;
+    ds_hover = packed_instance_2.z;
+    ds_pressed = packed_instance_2.w;
+    ds_button_type = packed_instance_3;
+
+    gl_Position = fn_41_845_vertex();
+
+    packed_varying_0.xy = ds_rect_size.xy;
+    packed_varying_0.z = ds_hover;
+    packed_varying_0.w = ds_pressed;
+    packed_varying_1.x = ds_button_type;
+    packed_varying_1.yz = ds_pos.xy;
+}
+// pixel
+uniform float const_table[128];
+
+uniform float live_table[16];
+vec4 live_41_155 = vec4(0.0);
+vec4 live_41_156 = vec4(0.0);
+vec4 live_41_157 = vec4(0.0);
+vec4 live_41_158 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec3 packed_varying_1;
+
+vec2 ds_rect_size=vec2(0.0);
+float ds_hover=0.0;
+float ds_pressed=0.0;
+float ds_button_type=0.0;
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_6_71_line_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    vec2 var_p_0 = vec2(var_x_0, var_y_0);
+    vec2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    vec2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[123], const_table[124]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[125]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+
+void fn_6_70_move_to(inout struct_6_28 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = vec2(var_x_0, var_y_0)));
+}
+
+void fn_6_68_rect(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    vec2 var_s_0 = (vec2(var_w_0, var_h_0) * const_table[118]);
+    vec2 var_d_0 = (abs(((vec2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    vec2 var_dm_0 = min(var_d_0, vec2(const_table[119], const_table[120]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2(const_table[121], const_table[122])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_64_box(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_size_0 = vec2((const_table[111] * var_w_0), (const_table[112] * var_h_0));
+    vec2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2((const_table[113] * var_r_0), (const_table[114] * var_r_0)).xy)), vec2(const_table[115], const_table[116]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[117] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_61_circle(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    vec2 var_c_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_6_58_subtract(inout struct_6_28 var_self_0) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[105], const_table[106]);
+    float var_wb_0 = const_table[107];
+    if(var_self_0.f_blur > const_table[108]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[109], const_table[110]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_52_stroke_keep(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    vec4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[104] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_53_stroke(inout struct_6_28 var_self_0, vec4 var_color_0, float var_width_0) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[101]));
+    (var_self_0.f_clip = -const_table[102]);
+    (var_self_0.f_has_clip = const_table[103]);
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[97] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[98]) {
+            float var_f2_0 = (const_table[99] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[100] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_49_fill(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[94]));
+    (var_self_0.f_clip = -const_table[95]);
+    (var_self_0.f_has_clip = const_table[96]);
+    return var_self_0.f_result;
+}
+
+void fn_6_46_clear(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    (var_self_0.f_result = vec4(((var_color_0.rgb * var_color_0.a) + (var_self_0.f_result.rgb * (const_table[93] - var_color_0.a))), var_color_0.a));
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[92] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[82]),vec2(const_table[83]),vec2(const_table[84]),const_table[85],-const_table[86],const_table[87],const_table[88],const_table[89],fn_6_41_antialias (var_pos_0),const_table[90],const_table[91]);
+}
+
+vec4 fn_41_846_pixel() {
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * ds_rect_size));
+    (var_sdf_0.f_aa *= const_table[1]);
+    float var_sz_0 = const_table[2];
+    vec2 var_c_0 = (ds_rect_size * vec2(const_table[3], const_table[4]));
+    if(abs(ds_button_type - 1.0)<0.5){
+            fn_6_46_clear (var_sdf_0, mix(live_41_155, mix(live_41_156, live_41_157, ds_pressed), ds_hover));
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), var_c_0.y);
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), var_c_0.y);
+        fn_6_53_stroke (var_sdf_0, live_41_158, (const_table[5] + (const_table[6] * ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_button_type - 2.0)<0.5){
+            fn_6_46_clear (var_sdf_0, mix(live_41_155, mix(live_41_156, live_41_157, ds_pressed), ds_hover));
+        fn_6_68_rect (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[7] * var_sz_0), (const_table[8] * var_sz_0));
+        fn_6_53_stroke (var_sdf_0, live_41_158, (const_table[9] + (const_table[10] * ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_button_type - 3.0)<0.5){
+            vec4 var_clear_0 = mix(live_41_155, mix(live_41_156, live_41_157, ds_pressed), ds_hover);
+        fn_6_46_clear (var_sdf_0, var_clear_0);
+        float var_sz_1 = const_table[11];
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_1) + const_table[12]), ((var_c_0.y - var_sz_1) - const_table[13]), (const_table[14] * var_sz_1), (const_table[15] * var_sz_1));
+        fn_6_53_stroke (var_sdf_0, live_41_158, (const_table[16] + (const_table[17] * ds_dpi_dilate)));
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_1) - const_table[18]), ((var_c_0.y - var_sz_1) + const_table[19]), (const_table[20] * var_sz_1), (const_table[21] * var_sz_1));
+        fn_6_48_fill_keep (var_sdf_0, var_clear_0);
+        fn_6_53_stroke (var_sdf_0, live_41_158, (const_table[22] + (const_table[23] * ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_button_type - 4.0)<0.5){
+            fn_6_46_clear (var_sdf_0, mix(live_41_155, mix(vec4(const_table[24], const_table[25], const_table[26], const_table[27]), vec4(const_table[28], const_table[29], const_table[30], const_table[31]), ds_pressed), ds_hover));
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0));
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y + var_sz_0));
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y + var_sz_0));
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0));
+        fn_6_53_stroke (var_sdf_0, live_41_158, (const_table[32] + (const_table[33] * ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_button_type - 5.0)<0.5){
+            fn_6_46_clear (var_sdf_0, mix(live_41_155, mix(vec4(const_table[34], const_table[35], const_table[36], const_table[37]), vec4(const_table[38], const_table[39], const_table[40], const_table[41]), ds_pressed), ds_hover));
+        float var_w_0 = const_table[42];
+        float var_h_0 = const_table[43];
+        fn_6_64_box (var_sdf_0, (var_c_0.x - var_w_0), (var_c_0.y - var_h_0), (const_table[44] * var_w_0), (const_table[45] * var_h_0), const_table[46]);
+        fn_6_61_circle (var_sdf_0, (var_c_0.x - const_table[47]), var_c_0.y, const_table[48]);
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_61_circle (var_sdf_0, (var_c_0.x + const_table[49]), var_c_0.y, const_table[50]);
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_61_circle (var_sdf_0, var_c_0.x, ((var_c_0.y + var_h_0) - const_table[51]), const_table[52]);
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_49_fill (var_sdf_0, vec4(const_table[53], const_table[54], const_table[55], const_table[56]));
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_button_type - 6.0)<0.5){
+            (var_sz_0 = const_table[57]);
+        fn_6_46_clear (var_sdf_0, mix(live_41_155, mix(live_41_156, live_41_157, ds_pressed), ds_hover));
+        fn_6_68_rect (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[58] * var_sz_0), (const_table[59] * var_sz_0));
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[60]), ((var_c_0.y - var_sz_0) + const_table[61]), (const_table[62] * (var_sz_0 - const_table[63])), (const_table[64] * (var_sz_0 - const_table[65])));
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[66]), ((var_c_0.y - var_sz_0) - const_table[67]), (const_table[68] * (var_sz_0 - const_table[69])), (const_table[70] * (var_sz_0 + const_table[71])));
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) - const_table[72]), ((var_c_0.y - var_sz_0) + const_table[73]), (const_table[74] * (var_sz_0 + const_table[75])), (const_table[76] * (var_sz_0 - const_table[77])));
+        fn_6_58_subtract (var_sdf_0);
+        fn_6_49_fill (var_sdf_0, live_41_158);
+        return var_sdf_0.f_result;
+    }
+    return vec4(const_table[78], const_table[79], const_table[80], const_table[81]);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_155 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_156 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_157 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_158 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    ds_rect_size.xy = packed_varying_0.xy;
+    ds_hover = packed_varying_0.z;
+    ds_pressed = packed_varying_0.w;
+    ds_button_type = packed_varying_1.x;
+    ds_pos.xy = packed_varying_1.yz;
+
+    gl_FragColor = fn_41_846_pixel();
+}
@@ -0,0 +1,251 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float4 live_41_155;
+    float4 live_41_156;
+    float4 live_41_157;
+    float4 live_41_158;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_hover;
+    float ds_pressed;
+    uint32_t  ds_button_type;
+};
+struct Varyings {
+    float4 position [[position]];
+    float2 ds_rect_size;
+    float ds_hover;
+    float ds_pressed;
+    uint32_t  ds_button_type;
+    float2 ds_pos;
+};
+void fn_6_71_line_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    float2 var_p_0 = float2(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[123], const_table[124]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[125]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_6_70_move_to(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = float2(var_x_0, var_y_0)));
+}
+void fn_6_68_rect(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[118]);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[119], const_table[120]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[121], const_table[122])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_64_box(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0, constant const float *const_table) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[111] * var_w_0), (const_table[112] * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[113] * var_r_0), (const_table[114] * var_r_0)).xy)), float2(const_table[115], const_table[116]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[117] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_61_circle(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_6_58_subtract(thread struct_6_28 & var_self_0, constant const float *const_table) {
+    (var_self_0.f_old_shape = (var_self_0.f_shape = max(-var_self_0.f_dist, var_self_0.f_old_shape)));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[105], const_table[106]);
+    float var_wb_0 = const_table[107];
+    if(var_self_0.f_blur > const_table[108]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[109], const_table[110]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_52_stroke_keep(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[104] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_6_53_stroke(thread struct_6_28 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[101]));
+    (var_self_0.f_clip = -const_table[102]);
+    (var_self_0.f_has_clip = const_table[103]);
+    return var_self_0.f_result;
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[97] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[98]) {
+            float var_f2_0 = (const_table[99] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[100] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_6_48_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[94]));
+    (var_self_0.f_clip = -const_table[95]);
+    (var_self_0.f_has_clip = const_table[96]);
+    return var_self_0.f_result;
+}
+void fn_6_46_clear(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    (var_self_0.f_result = float4(((var_color_0.rgb * var_color_0.a) + (var_self_0.f_result.rgb * (const_table[93] - var_color_0.a))), var_color_0.a));
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[92] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[82]),float2(const_table[83]),float2(const_table[84]),const_table[85],-const_table[86],const_table[87],const_table[88],const_table[89],fn_6_41_antialias (var_pos_0, const_table),const_table[90],const_table[91]};
+}
+float4 fn_41_846_pixel(constant const float *const_table, thread Varyings &varyings, constant Uniforms_pass &uniforms_pass, constant LiveUniforms &live_uniforms) {
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    (var_sdf_0.f_aa *= const_table[1]);
+    float var_sz_0 = const_table[2];
+    float2 var_c_0 = (varyings.ds_rect_size * float2(const_table[3], const_table[4]));
+    if(varyings.ds_button_type == 1){
+            fn_6_46_clear (var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, varyings.ds_pressed), varyings.ds_hover), const_table);
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), var_c_0.y, const_table);
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), var_c_0.y, const_table);
+        fn_6_53_stroke (var_sdf_0, live_uniforms.live_41_158, (const_table[5] + (const_table[6] * uniforms_pass.ds_dpi_dilate)), const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 2){
+            fn_6_46_clear (var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, varyings.ds_pressed), varyings.ds_hover), const_table);
+        fn_6_68_rect (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[7] * var_sz_0), (const_table[8] * var_sz_0), const_table);
+        fn_6_53_stroke (var_sdf_0, live_uniforms.live_41_158, (const_table[9] + (const_table[10] * uniforms_pass.ds_dpi_dilate)), const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 3){
+            float4 var_clear_0 = mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, varyings.ds_pressed), varyings.ds_hover);
+        fn_6_46_clear (var_sdf_0, var_clear_0, const_table);
+        float var_sz_1 = const_table[11];
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_1) + const_table[12]), ((var_c_0.y - var_sz_1) - const_table[13]), (const_table[14] * var_sz_1), (const_table[15] * var_sz_1), const_table);
+        fn_6_53_stroke (var_sdf_0, live_uniforms.live_41_158, (const_table[16] + (const_table[17] * uniforms_pass.ds_dpi_dilate)), const_table);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_1) - const_table[18]), ((var_c_0.y - var_sz_1) + const_table[19]), (const_table[20] * var_sz_1), (const_table[21] * var_sz_1), const_table);
+        fn_6_48_fill_keep (var_sdf_0, var_clear_0, const_table);
+        fn_6_53_stroke (var_sdf_0, live_uniforms.live_41_158, (const_table[22] + (const_table[23] * uniforms_pass.ds_dpi_dilate)), const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 4){
+            fn_6_46_clear (var_sdf_0, mix(live_uniforms.live_41_155, mix(float4(const_table[24], const_table[25], const_table[26], const_table[27]), float4(const_table[28], const_table[29], const_table[30], const_table[31]), varyings.ds_pressed), varyings.ds_hover), const_table);
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), const_table);
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y + var_sz_0), const_table);
+        fn_6_70_move_to (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y + var_sz_0), const_table);
+        fn_6_71_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0), const_table);
+        fn_6_53_stroke (var_sdf_0, live_uniforms.live_41_158, (const_table[32] + (const_table[33] * uniforms_pass.ds_dpi_dilate)), const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 5){
+            fn_6_46_clear (var_sdf_0, mix(live_uniforms.live_41_155, mix(float4(const_table[34], const_table[35], const_table[36], const_table[37]), float4(const_table[38], const_table[39], const_table[40], const_table[41]), varyings.ds_pressed), varyings.ds_hover), const_table);
+        float var_w_0 = const_table[42];
+        float var_h_0 = const_table[43];
+        fn_6_64_box (var_sdf_0, (var_c_0.x - var_w_0), (var_c_0.y - var_h_0), (const_table[44] * var_w_0), (const_table[45] * var_h_0), const_table[46], const_table);
+        fn_6_61_circle (var_sdf_0, (var_c_0.x - const_table[47]), var_c_0.y, const_table[48], const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_61_circle (var_sdf_0, (var_c_0.x + const_table[49]), var_c_0.y, const_table[50], const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_61_circle (var_sdf_0, var_c_0.x, ((var_c_0.y + var_h_0) - const_table[51]), const_table[52], const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_49_fill (var_sdf_0, float4(const_table[53], const_table[54], const_table[55], const_table[56]), const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 6){
+            (var_sz_0 = const_table[57]);
+        fn_6_46_clear (var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, varyings.ds_pressed), varyings.ds_hover), const_table);
+        fn_6_68_rect (var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[58] * var_sz_0), (const_table[59] * var_sz_0), const_table);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[60]), ((var_c_0.y - var_sz_0) + const_table[61]), (const_table[62] * (var_sz_0 - const_table[63])), (const_table[64] * (var_sz_0 - const_table[65])), const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[66]), ((var_c_0.y - var_sz_0) - const_table[67]), (const_table[68] * (var_sz_0 - const_table[69])), (const_table[70] * (var_sz_0 + const_table[71])), const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_68_rect (var_sdf_0, ((var_c_0.x - var_sz_0) - const_table[72]), ((var_c_0.y - var_sz_0) + const_table[73]), (const_table[74] * (var_sz_0 + const_table[75])), (const_table[76] * (var_sz_0 - const_table[77])), const_table);
+        fn_6_58_subtract (var_sdf_0, const_table);
+        fn_6_49_fill (var_sdf_0, live_uniforms.live_41_158, const_table);
+        return var_sdf_0.f_result;
+    }
+    return float4(const_table[78], const_table[79], const_table[80], const_table[81]);
+}
+float4 fn_41_843_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_41_845_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_41_843_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_button_type = instances.ds_button_type;
+    varyings.position = fn_41_845_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_41_846_pixel(const_table, varyings, uniforms_pass, live_uniforms);
+}
@@ -0,0 +1,238 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+struct LiveUniforms {
+    live_41_155: vec4<f32>,
+    live_41_156: vec4<f32>,
+    live_41_157: vec4<f32>,
+    live_41_158: vec4<f32>,
+};
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 32>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_rect_pos: vec2<f32>,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_draw_clip: vec4<f32>,
+    @location(4) ds_draw_depth: f32,
+    @location(5) pad_0: f32,
+    @location(6) ds_hover: f32,
+    @location(7) ds_pressed: f32,
+    @location(8) ds_button_type: u32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_rect_size: vec2<f32>,
+    @location(1) ds_hover: f32,
+    @location(2) ds_pressed: f32,
+    @location(3) @interpolate(flat) ds_button_type: u32,
+    @location(4) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_6_71_line_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    var var_p_0: vec2<f32> = vec2<f32>(var_x_0, var_y_0);
+    var var_pa_0: vec2<f32> = ((*var_self_0).f_pos - (*var_self_0).f_last_pos);
+    var var_ba_0: vec2<f32> = (var_p_0 - (*var_self_0).f_last_pos);
+    var var_h_0: f32 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[30].w, const_table[31].x);
+    var var_s_0: f32 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (*var_self_0).f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+    (*var_self_0).f_clip = max((*var_self_0).f_clip, ((*var_self_0).f_dist * var_s_0));
+    (*var_self_0).f_has_clip = const_table[31].y;
+    (*var_self_0).f_last_pos = var_p_0;
+}
+fn fn_6_70_move_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    (*var_self_0).f_start_pos = vec2<f32>(var_x_0, var_y_0);    (*var_self_0).f_last_pos = (*var_self_0).f_start_pos;
+}
+fn fn_6_68_rect(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32) {
+    var var_s_0: vec2<f32> = (vec2<f32>(var_w_0, var_h_0) * const_table[29].z);
+    var var_d_0: vec2<f32> = (abs(((vec2<f32>(var_x_0, var_y_0) - (*var_self_0).f_pos) + var_s_0)) - var_s_0);
+    var var_dm_0: vec2<f32> = min(var_d_0, vec2<f32>(const_table[29].w, const_table[30].x));
+    (*var_self_0).f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2<f32>(const_table[30].y, const_table[30].z))));
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_64_box(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_0: f32) {
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[27].w * var_w_0), (const_table[28].x * var_h_0));
+    var var_bp_0: vec2<f32> = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2<f32>((const_table[28].y * var_r_0), (const_table[28].z * var_r_0)).xy)), vec2<f32>(const_table[28].w, const_table[29].x));
+    (*var_self_0).f_dist = ((length(var_bp_0) - (const_table[29].y * var_r_0)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_61_circle(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_r_0: f32) {
+    var var_c_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_len_0: f32 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (*var_self_0).f_dist = ((var_len_0 - var_r_0) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_58_subtract(var_self_0: ptr<function, struct_6_28>) {
+    (*var_self_0).f_shape = max(-(*var_self_0).f_dist, (*var_self_0).f_old_shape);    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[26].y, const_table[26].z);
+    var var_wb_0: f32 = const_table[26].w;
+    if((*var_self_0).f_blur > const_table[27].x) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[27].y, const_table[27].z);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_52_stroke_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (abs((*var_self_0).f_shape) - (var_width_0 / (*var_self_0).f_scale_factor)));
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    var var_dest_0: vec4<f32> = (*var_self_0).f_result;
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[26].x - (var_source_0.a * var_f_0))));
+    return (*var_self_0).f_result;
+}
+fn fn_6_53_stroke(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    fn_6_52_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (*var_self_0).f_shape = const_table[25].y;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[25].z;
+    (*var_self_0).f_has_clip = const_table[25].w;
+    return (*var_self_0).f_result;
+}
+fn fn_6_48_fill_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[24].y - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[24].z) {
+            var var_f2_0: f32 = (const_table[24].w - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[25].x - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_49_fill(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (*var_self_0).f_shape = const_table[23].z;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[23].w;
+    (*var_self_0).f_has_clip = const_table[24].x;
+    return (*var_self_0).f_result;
+}
+fn fn_6_46_clear(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) {
+    (*var_self_0).f_result = vec4<f32>(((var_color_0.rgb * var_color_0.a) + ((*var_self_0).f_result.rgb * (const_table[23].y - var_color_0.a))), var_color_0.a);
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[23].x / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[20].z),vec2<f32>(const_table[20].w),vec2<f32>(const_table[21].x),const_table[21].y,-const_table[21].z,const_table[21].w,const_table[22].x,const_table[22].y,fn_6_41_antialias (var_pos_0),const_table[22].z,const_table[22].w);
+}
+fn fn_41_846_pixel() -> vec4<f32> {
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    var_sdf_0.f_aa *= const_table[0].y;
+    var var_sz_0: f32 = const_table[0].z;
+    var var_c_0: vec2<f32> = (varyings.ds_rect_size * vec2<f32>(const_table[0].w, const_table[1].x));
+    if(varyings.ds_button_type == 1){
+            fn_6_46_clear (&var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover)));
+        fn_6_70_move_to (&var_sdf_0, (var_c_0.x - var_sz_0), var_c_0.y);
+        fn_6_71_line_to (&var_sdf_0, (var_c_0.x + var_sz_0), var_c_0.y);
+        fn_6_53_stroke (&var_sdf_0, live_uniforms.live_41_158, (const_table[1].y + (const_table[1].z * uniforms_pass.ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 2){
+            fn_6_46_clear (&var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover)));
+        fn_6_68_rect (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[1].w * var_sz_0), (const_table[2].x * var_sz_0));
+        fn_6_53_stroke (&var_sdf_0, live_uniforms.live_41_158, (const_table[2].y + (const_table[2].z * uniforms_pass.ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 3){
+            var var_clear_0: vec4<f32> = mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover));
+        fn_6_46_clear (&var_sdf_0, var_clear_0);
+        var var_sz_1: f32 = const_table[2].w;
+        fn_6_68_rect (&var_sdf_0, ((var_c_0.x - var_sz_1) + const_table[3].x), ((var_c_0.y - var_sz_1) - const_table[3].y), (const_table[3].z * var_sz_1), (const_table[3].w * var_sz_1));
+        fn_6_53_stroke (&var_sdf_0, live_uniforms.live_41_158, (const_table[4].x + (const_table[4].y * uniforms_pass.ds_dpi_dilate)));
+        fn_6_68_rect (&var_sdf_0, ((var_c_0.x - var_sz_1) - const_table[4].z), ((var_c_0.y - var_sz_1) + const_table[4].w), (const_table[5].x * var_sz_1), (const_table[5].y * var_sz_1));
+        fn_6_48_fill_keep (&var_sdf_0, var_clear_0);
+        fn_6_53_stroke (&var_sdf_0, live_uniforms.live_41_158, (const_table[5].z + (const_table[5].w * uniforms_pass.ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 4){
+            fn_6_46_clear (&var_sdf_0, mix(live_uniforms.live_41_155, mix(vec4<f32>(const_table[6].x, const_table[6].y, const_table[6].z, const_table[6].w), vec4<f32>(const_table[7].x, const_table[7].y, const_table[7].z, const_table[7].w), vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover)));
+        fn_6_70_move_to (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0));
+        fn_6_71_line_to (&var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y + var_sz_0));
+        fn_6_70_move_to (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y + var_sz_0));
+        fn_6_71_line_to (&var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0));
+        fn_6_53_stroke (&var_sdf_0, live_uniforms.live_41_158, (const_table[8].x + (const_table[8].y * uniforms_pass.ds_dpi_dilate)));
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 5){
+            fn_6_46_clear (&var_sdf_0, mix(live_uniforms.live_41_155, mix(vec4<f32>(const_table[8].z, const_table[8].w, const_table[9].x, const_table[9].y), vec4<f32>(const_table[9].z, const_table[9].w, const_table[10].x, const_table[10].y), vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover)));
+        var var_w_0: f32 = const_table[10].z;
+        var var_h_0: f32 = const_table[10].w;
+        fn_6_64_box (&var_sdf_0, (var_c_0.x - var_w_0), (var_c_0.y - var_h_0), (const_table[11].x * var_w_0), (const_table[11].y * var_h_0), const_table[11].z);
+        fn_6_61_circle (&var_sdf_0, (var_c_0.x - const_table[11].w), var_c_0.y, const_table[12].x);
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_61_circle (&var_sdf_0, (var_c_0.x + const_table[12].y), var_c_0.y, const_table[12].z);
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_61_circle (&var_sdf_0, var_c_0.x, ((var_c_0.y + var_h_0) - const_table[12].w), const_table[13].x);
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_49_fill (&var_sdf_0, vec4<f32>(const_table[13].y, const_table[13].z, const_table[13].w, const_table[14].x));
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_button_type == 6){
+            var_sz_0 = const_table[14].y;
+        fn_6_46_clear (&var_sdf_0, mix(live_uniforms.live_41_155, mix(live_uniforms.live_41_156, live_uniforms.live_41_157, vec4<f32>(varyings.ds_pressed)), vec4<f32>(varyings.ds_hover)));
+        fn_6_68_rect (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0), (const_table[14].z * var_sz_0), (const_table[14].w * var_sz_0));
+        fn_6_68_rect (&var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[15].x), ((var_c_0.y - var_sz_0) + const_table[15].y), (const_table[15].z * (var_sz_0 - const_table[15].w)), (const_table[16].x * (var_sz_0 - const_table[16].y)));
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_68_rect (&var_sdf_0, ((var_c_0.x - var_sz_0) + const_table[16].z), ((var_c_0.y - var_sz_0) - const_table[16].w), (const_table[17].x * (var_sz_0 - const_table[17].y)), (const_table[17].z * (var_sz_0 + const_table[17].w)));
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_68_rect (&var_sdf_0, ((var_c_0.x - var_sz_0) - const_table[18].x), ((var_c_0.y - var_sz_0) + const_table[18].y), (const_table[18].z * (var_sz_0 + const_table[18].w)), (const_table[19].x * (var_sz_0 - const_table[19].y)));
+        fn_6_58_subtract (&var_sdf_0);
+        fn_6_49_fill (&var_sdf_0, live_uniforms.live_41_158);
+        return var_sdf_0.f_result;
+    }
+    return vec4<f32>(const_table[19].z, const_table[19].w, const_table[20].x, const_table[20].y);
+}
+fn fn_41_843_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_41_845_vertex() -> vec4<f32> {
+    return fn_41_843_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.ds_button_type = instances.ds_button_type;
+    varyings.position = fn_41_845_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_41_846_pixel();
+}
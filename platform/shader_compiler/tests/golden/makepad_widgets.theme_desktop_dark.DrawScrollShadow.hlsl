@@ -0,0 +1,70 @@
+cbuffer LiveUniforms : register(b0) {
+    float4 live_41_60;
+};
+cbuffer ConstTable : register(b1){float4 const_table[4];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    float ds_shadow_is_top: INSTF;
+    float ds_scroll: INSTG;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_shadow_is_top: VARYA;
+    float ds_scroll: VARYB;
+    float2 ds_pos: VARYC;
+};
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+float4 fn_6_15_premul(float4 var_v_0) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_41_6819_pixel(inout Varyings varyings) {
+    float var_is_viz_0 = clamp((varyings.ds_scroll * const_table[0].y), const_table[0].z, const_table[0].w);
+    float2 var_pos_0 = varyings.ds_pos;
+    float3 var_base_0 = live_41_60.xyz;
+    float var_alpha_0 = const_table[1].x;
+    if(varyings.ds_shadow_is_top > const_table[1].y) {
+            (var_alpha_0 = pow(var_pos_0.y, const_table[1].z));
+    }else{
+            (var_alpha_0 = pow(var_pos_0.x, const_table[1].w));
+    }
+
+    return fn_6_15_premul (lerp(consfn_vec4_vec3_float(float4(const_table[2].x, const_table[2].y, const_table[2].z, const_table[2].w).xyz, var_is_viz_0), consfn_vec4_vec3_float(var_base_0, const_table[3].x), var_alpha_0));
+}
+float4 fn_41_6816_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_41_6818_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_41_6816_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, float2(0.0,0.0)};
+    varyings.ds_shadow_is_top = instances.ds_shadow_is_top;
+    varyings.ds_scroll = instances.ds_scroll;
+    varyings.position = fn_41_6818_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_41_6819_pixel(varyings);
+}
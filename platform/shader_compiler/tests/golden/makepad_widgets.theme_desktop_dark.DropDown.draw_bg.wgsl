@@ -0,0 +1,159 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+struct LiveUniforms {
+    live_41_138: vec4<f32>,
+    live_41_161: vec4<f32>,
+    live_41_162: vec4<f32>,
+    live_41_163: vec4<f32>,
+};
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 11>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_user {
+    ds_border_radius: f32,
+};
+@group(0) @binding(4) var<uniform> uniforms_user: Uniforms_user;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(5) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_hover: f32,
+    @location(2) ds_pressed: f32,
+    @location(3) ds_focus: f32,
+    @location(4) ds_rect_pos: vec2<f32>,
+    @location(5) ds_rect_size: vec2<f32>,
+    @location(6) ds_draw_clip: vec4<f32>,
+    @location(7) ds_draw_depth: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_hover: f32,
+    @location(1) ds_rect_size: vec2<f32>,
+    @location(2) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_6_64_box(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_0: f32) {
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[8].z * var_w_0), (const_table[8].w * var_h_0));
+    var var_bp_0: vec2<f32> = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2<f32>((const_table[9].x * var_r_0), (const_table[9].y * var_r_0)).xy)), vec2<f32>(const_table[9].z, const_table[9].w));
+    (*var_self_0).f_dist = ((length(var_bp_0) - (const_table[10].x * var_r_0)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[7].x, const_table[7].y);
+    var var_wb_0: f32 = const_table[7].z;
+    if((*var_self_0).f_blur > const_table[7].w) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[8].x, const_table[8].y);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_48_fill_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[6].x - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[6].y) {
+            var var_f2_0: f32 = (const_table[6].z - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[6].w - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_49_fill(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (*var_self_0).f_shape = const_table[5].y;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[5].z;
+    (*var_self_0).f_has_clip = const_table[5].w;
+    return (*var_self_0).f_result;
+}
+fn fn_41_3032_get_bg(var_sdf_0: ptr<function, struct_6_28>) {
+    fn_6_64_box (var_sdf_0, const_table[4].w, const_table[5].x, varyings.ds_rect_size.x, varyings.ds_rect_size.y, uniforms_user.ds_border_radius);
+    fn_6_49_fill (var_sdf_0, mix(live_uniforms.live_41_161, live_uniforms.live_41_162, vec4<f32>(varyings.ds_hover)));
+}
+fn fn_6_71_line_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    var var_p_0: vec2<f32> = vec2<f32>(var_x_0, var_y_0);
+    var var_pa_0: vec2<f32> = ((*var_self_0).f_pos - (*var_self_0).f_last_pos);
+    var var_ba_0: vec2<f32> = (var_p_0 - (*var_self_0).f_last_pos);
+    var var_h_0: f32 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[4].x, const_table[4].y);
+    var var_s_0: f32 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (*var_self_0).f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+    (*var_self_0).f_clip = max((*var_self_0).f_clip, ((*var_self_0).f_dist * var_s_0));
+    (*var_self_0).f_has_clip = const_table[4].z;
+    (*var_self_0).f_last_pos = var_p_0;
+}
+fn fn_6_72_close_path(var_self_0: ptr<function, struct_6_28>) {
+    fn_6_71_line_to (var_self_0, (*var_self_0).f_start_pos.x, (*var_self_0).f_start_pos.y);
+}
+fn fn_6_70_move_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    (*var_self_0).f_start_pos = vec2<f32>(var_x_0, var_y_0);    (*var_self_0).f_last_pos = (*var_self_0).f_start_pos;
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[3].w / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[1].y),vec2<f32>(const_table[1].z),vec2<f32>(const_table[1].w),const_table[2].x,-const_table[2].y,const_table[2].z,const_table[2].w,const_table[3].x,fn_6_41_antialias (var_pos_0),const_table[3].y,const_table[3].z);
+}
+fn fn_41_3027_pixel() -> vec4<f32> {
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    fn_41_3032_get_bg (&var_sdf_0);
+    var var_c_0: vec2<f32> = vec2<f32>((varyings.ds_rect_size.x - const_table[0].y), (varyings.ds_rect_size.y * const_table[0].z));
+    var var_sz_0: f32 = const_table[0].w;
+    fn_6_70_move_to (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y - var_sz_0));
+    fn_6_71_line_to (&var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0));
+    fn_6_71_line_to (&var_sdf_0, var_c_0.x, (var_c_0.y + (var_sz_0 * const_table[1].x)));
+    fn_6_72_close_path (&var_sdf_0);
+    fn_6_49_fill (&var_sdf_0, mix(live_uniforms.live_41_163, live_uniforms.live_41_138, vec4<f32>(varyings.ds_hover)));
+    return var_sdf_0.f_result;
+}
+fn fn_41_3024_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_41_3026_vertex() -> vec4<f32> {
+    return fn_41_3024_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_41_3026_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_41_3027_pixel();
+}
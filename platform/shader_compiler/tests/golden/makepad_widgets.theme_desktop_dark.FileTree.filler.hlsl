@@ -0,0 +1,66 @@
+cbuffer LiveUniforms : register(b0) {
+    float4 live_41_60;
+    float4 live_41_65;
+    float4 live_41_70;
+    float4 live_41_73;
+};
+cbuffer ConstTable : register(b1){float4 const_table[1];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    float ds_is_even: INSTF;
+    float ds_scale: INSTG;
+    float ds_is_folder: INSTH;
+    float ds_focussed: INSTI;
+    float ds_selected: INSTJ;
+    float ds_hover: INSTK;
+    float ds_opened: INSTL;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_is_even: VARYA;
+    float ds_focussed: VARYB;
+    float ds_selected: VARYC;
+    float2 ds_pos: VARYD;
+};
+float4 fn_41_3767_pixel(inout Varyings varyings) {
+    return lerp(lerp(live_41_60, live_41_65, varyings.ds_is_even), lerp(live_41_73, live_41_70, varyings.ds_focussed), varyings.ds_selected);
+}
+float4 fn_41_3764_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_41_3766_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_41_3764_clip_and_transform_vertex (instances.ds_rect_pos, instances.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, 0.0, float2(0.0,0.0)};
+    varyings.ds_is_even = instances.ds_is_even;
+    varyings.ds_focussed = instances.ds_focussed;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.position = fn_41_3766_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_41_3767_pixel(varyings);
+}
@@ -0,0 +1,154 @@
+// vertex
+uniform float const_table[4];
+
+uniform float live_table[16];
+vec4 live_41_60 = vec4(0.0);
+vec4 live_41_65 = vec4(0.0);
+vec4 live_41_70 = vec4(0.0);
+vec4 live_41_73 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute float packed_instance_4;
+
+varying vec4 packed_varying_0;
+varying float packed_varying_1;
+
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+float ds_is_even=0.0;
+float ds_scale=0.0;
+float ds_is_folder=0.0;
+float ds_focussed=0.0;
+float ds_selected=0.0;
+float ds_hover=0.0;
+float ds_opened=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_41_3341_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_41_3343_vertex() {
+    return fn_41_3341_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_60 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_65 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_70 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_73 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_rect_pos.xy = packed_instance_0.xy;
+    ds_rect_size.xy = packed_instance_0.zw;
+    ds_draw_clip.xyzw = packed_instance_1.xyzw;
+    ds_draw_depth = packed_instance_2.x;
+    ds_0 = packed_instance_2.y;
+    ds_is_even = packed_instance_2.z;
+    ds_scale = packed_instance_2.w;
+    ds_is_folder = packed_instance_3.x;
+    ds_focussed = packed_instance_3.y;
+    ds_selected = packed_instance_3.z;
+    ds_hover = packed_instance_3.w;
+    ds_opened = packed_instance_4;
+
+    gl_Position = fn_41_3343_vertex();
+
+    packed_varying_0.x = ds_is_even;
+    packed_varying_0.y = ds_focussed;
+    packed_varying_0.z = ds_selected;
+    packed_varying_0.w = ds_pos.x;
+    packed_varying_1 = ds_pos.y;
+}
+// pixel
+uniform float const_table[4];
+
+uniform float live_table[16];
+vec4 live_41_60 = vec4(0.0);
+vec4 live_41_65 = vec4(0.0);
+vec4 live_41_70 = vec4(0.0);
+vec4 live_41_73 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying float packed_varying_1;
+
+float ds_is_even=0.0;
+float ds_focussed=0.0;
+float ds_selected=0.0;
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_41_3344_pixel() {
+    return mix(mix(live_41_60, live_41_65, ds_is_even), mix(live_41_73, live_41_70, ds_focussed), ds_selected);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_60 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_65 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_70 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_73 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    ds_is_even = packed_varying_0.x;
+    ds_focussed = packed_varying_0.y;
+    ds_selected = packed_varying_0.z;
+    ds_pos.x = packed_varying_0.w;
+    ds_pos.y = packed_varying_1;
+
+    gl_FragColor = fn_41_3344_pixel();
+}
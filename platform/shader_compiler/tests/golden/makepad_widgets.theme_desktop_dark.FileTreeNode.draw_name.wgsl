@@ -0,0 +1,122 @@
+struct LiveUniforms {
+    live_41_94: vec4<f32>,
+    live_41_97: vec4<f32>,
+    live_41_103: vec4<f32>,
+};
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 5>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_user {
+    ds_brightness: f32,
+    ds_curve: f32,
+};
+@group(0) @binding(4) var<uniform> uniforms_user: Uniforms_user;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(5) var<uniform> uniforms_view: Uniforms_view;
+@group(0) @binding(6) var ds_tex: texture_2d<f32>;
+@group(0) @binding(7) var default_texture_sampler: sampler;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_color: vec4<f32>,
+    @location(2) ds_font_t1: vec2<f32>,
+    @location(3) ds_font_t2: vec2<f32>,
+    @location(4) ds_rect_pos: vec2<f32>,
+    @location(5) ds_rect_size: vec2<f32>,
+    @location(6) ds_draw_clip: vec4<f32>,
+    @location(7) ds_char_depth: f32,
+    @location(8) ds_delta: vec2<f32>,
+    @location(9) ds_font_size: f32,
+    @location(10) ds_advance: f32,
+    @location(11) pad_0: f32,
+    @location(12) ds_is_even: f32,
+    @location(13) ds_scale: f32,
+    @location(14) ds_is_folder: f32,
+    @location(15) ds_focussed: f32,
+    @location(16) ds_selected: f32,
+    @location(17) ds_hover: f32,
+    @location(18) ds_opened: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_scale: f32,
+    @location(1) ds_selected: f32,
+    @location(2) ds_hover: f32,
+    @location(3) ds_tex_coord1: vec2<f32>,
+    @location(4) ds_tex_coord2: vec2<f32>,
+    @location(5) ds_tex_coord3: vec2<f32>,
+    @location(6) ds_clipped: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn sample2d_rt(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_texture_sampler, pos, 0.0);}
+fn fn_41_3375_get_color() -> vec4<f32> {
+    return mix(mix((live_uniforms.live_41_94 * varyings.ds_scale), live_uniforms.live_41_103, vec4<f32>(varyings.ds_selected)), live_uniforms.live_41_97, vec4<f32>(varyings.ds_hover));
+}
+fn fn_41_3376_pixel() -> vec4<f32> {
+    var var_dx_0: f32 = dpdx(vec2<f32>((varyings.ds_tex_coord1.x * const_table[0].w), const_table[1].x)).x;
+    var var_dp_0: f32 = (const_table[1].y / const_table[1].z);
+    var var_s_0: f32 = const_table[1].w;
+    if(var_dx_0 > const_table[2].x) {
+            var_s_0 = const_table[2].y;
+    }else{
+            if(var_dx_0 > const_table[2].z) {
+                    var_s_0 = ((((sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + vec2<f32>(const_table[2].w, const_table[3].x))).z + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + vec2<f32>(var_dp_0, const_table[3].y))).z) + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + vec2<f32>(const_table[3].z, var_dp_0))).z) + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + vec2<f32>(var_dp_0, var_dp_0))).z) * const_table[3].w);
+        }else{
+                    if(var_dx_0 > const_table[4].x) {
+                            var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord3.xy).z;
+            }else{
+                            if(var_dx_0 > const_table[4].y) {
+                                    var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord2.xy).y;
+                }else{
+                                    var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord1.xy).x;
+                }
+
+            }
+
+        }
+
+    }
+
+    var_s_0 = pow(var_s_0, uniforms_user.ds_curve);
+    var var_col_0: vec4<f32> = fn_41_3375_get_color ();
+    return vec4<f32>((((var_s_0 * var_col_0.rgb) * uniforms_user.ds_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+fn fn_41_3374_vertex() -> vec4<f32> {
+    var var_min_pos_0: vec2<f32> = vec2<f32>(instances.ds_rect_pos.x, instances.ds_rect_pos.y);
+    var var_max_pos_0: vec2<f32> = vec2<f32>((instances.ds_rect_pos.x + instances.ds_rect_size.x), (instances.ds_rect_pos.y - instances.ds_rect_size.y));
+    varyings.ds_clipped = clamp(mix(var_min_pos_0, var_max_pos_0, geometries.ds_geom_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    var var_normalized_0: vec2<f32> = ((varyings.ds_clipped - var_min_pos_0) / vec2<f32>(instances.ds_rect_size.x, -instances.ds_rect_size.y));
+    varyings.ds_tex_coord1 = mix(instances.ds_font_t1.xy, instances.ds_font_t2.xy, var_normalized_0.xy);
+    varyings.ds_tex_coord2 = mix(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[0].x)), var_normalized_0.xy);
+    varyings.ds_tex_coord3 = mix(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[0].y)), var_normalized_0.xy);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(varyings.ds_clipped.x, varyings.ds_clipped.y, (instances.ds_char_depth + uniforms_draw.ds_draw_zbias), const_table[0].z))));
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_scale = instances.ds_scale;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.position = fn_41_3374_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_41_3376_pixel();
+}
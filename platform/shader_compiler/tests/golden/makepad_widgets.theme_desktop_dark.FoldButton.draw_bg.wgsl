@@ -0,0 +1,157 @@
+struct struct_6_28 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+};
+struct LiveUniforms {
+    live_6_1: f32,
+    live_41_139: vec4<f32>,
+    live_41_164: vec4<f32>,
+};
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 9>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+};
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+};
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_user {
+    ds_fade: f32,
+};
+@group(0) @binding(4) var<uniform> uniforms_user: Uniforms_user;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+};
+@group(0) @binding(5) var<uniform> uniforms_view: Uniforms_view;
+struct Geometries {
+    @location(0) ds_geom_pos: vec2<f32>,
+};
+var<private> geometries: Geometries;
+struct Instances {
+    @location(1) ds_opened: f32,
+    @location(2) ds_hover: f32,
+    @location(3) ds_rect_pos: vec2<f32>,
+    @location(4) ds_rect_size: vec2<f32>,
+    @location(5) ds_draw_clip: vec4<f32>,
+    @location(6) ds_draw_depth: f32,
+};
+var<private> instances: Instances;
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_opened: f32,
+    @location(1) ds_hover: f32,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_pos: vec2<f32>,
+};
+var<private> varyings: Varyings;
+fn fn_6_71_line_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    var var_p_0: vec2<f32> = vec2<f32>(var_x_0, var_y_0);
+    var var_pa_0: vec2<f32> = ((*var_self_0).f_pos - (*var_self_0).f_last_pos);
+    var var_ba_0: vec2<f32> = (var_p_0 - (*var_self_0).f_last_pos);
+    var var_h_0: f32 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[8].x, const_table[8].y);
+    var var_s_0: f32 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (*var_self_0).f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+    (*var_self_0).f_clip = max((*var_self_0).f_clip, ((*var_self_0).f_dist * var_s_0));
+    (*var_self_0).f_has_clip = const_table[8].z;
+    (*var_self_0).f_last_pos = var_p_0;
+}
+fn fn_6_72_close_path(var_self_0: ptr<function, struct_6_28>) {
+    fn_6_71_line_to (var_self_0, (*var_self_0).f_start_pos.x, (*var_self_0).f_start_pos.y);
+}
+fn fn_6_70_move_to(var_self_0: ptr<function, struct_6_28>, var_x_0: f32, var_y_0: f32) {
+    (*var_self_0).f_start_pos = vec2<f32>(var_x_0, var_y_0);    (*var_self_0).f_last_pos = (*var_self_0).f_start_pos;
+}
+fn fn_6_47_calc_blur(var_self_0: ptr<function, struct_6_28>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[6].z, const_table[6].w);
+    var var_wb_0: f32 = const_table[7].x;
+    if((*var_self_0).f_blur > const_table[7].y) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[7].z, const_table[7].w);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_6_48_fill_keep(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_6_47_calc_blur (var_self_0, (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[5].z - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[5].w) {
+            var var_f2_0: f32 = (const_table[6].x - fn_6_47_calc_blur (var_self_0, -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[6].y - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_6_49_fill(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) -> vec4<f32> {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (*var_self_0).f_shape = const_table[4].w;    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[5].x;
+    (*var_self_0).f_has_clip = const_table[5].y;
+    return (*var_self_0).f_result;
+}
+fn fn_6_46_clear(var_self_0: ptr<function, struct_6_28>, var_color_0: vec4<f32>) {
+    (*var_self_0).f_result = vec4<f32>(((var_color_0.rgb * var_color_0.a) + ((*var_self_0).f_result.rgb * (const_table[4].z - var_color_0.a))), var_color_0.a);
+}
+fn fn_6_44_rotate(var_self_0: ptr<function, struct_6_28>, var_a_0: f32, var_x_0: f32, var_y_0: f32) {
+    var var_ca_0: f32 = cos(-var_a_0);
+    var var_sa_0: f32 = sin(-var_a_0);
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    (*var_self_0).f_pos = (vec2<f32>(((var_p_0.x * var_ca_0) - (var_p_0.y * var_sa_0)), ((var_p_0.x * var_sa_0) + (var_p_0.y * var_ca_0))) + vec2<f32>(var_x_0, var_y_0));
+}
+fn fn_6_41_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[4].y / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_6_42_viewport(var_pos_0: vec2<f32>) -> struct_6_28 {
+    return struct_6_28(var_pos_0,vec4<f32>(const_table[1].w),vec2<f32>(const_table[2].x),vec2<f32>(const_table[2].y),const_table[2].z,-const_table[2].w,const_table[3].x,const_table[3].y,const_table[3].z,fn_6_41_antialias (var_pos_0),const_table[3].w,const_table[4].x);
+}
+fn fn_41_4347_pixel() -> vec4<f32> {
+    var var_sz_0: f32 = const_table[0].y;
+    var var_c_0: vec2<f32> = vec2<f32>(const_table[0].z, (const_table[0].w * varyings.ds_rect_size.y));
+    var var_sdf_0: struct_6_28 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    fn_6_46_clear (&var_sdf_0, vec4<f32>(const_table[1].x));
+    fn_6_44_rotate (&var_sdf_0, (((varyings.ds_opened * const_table[1].y) * live_uniforms.live_6_1) + (const_table[1].z * live_uniforms.live_6_1)), var_c_0.x, var_c_0.y);
+    fn_6_70_move_to (&var_sdf_0, (var_c_0.x - var_sz_0), (var_c_0.y + var_sz_0));
+    fn_6_71_line_to (&var_sdf_0, var_c_0.x, (var_c_0.y - var_sz_0));
+    fn_6_71_line_to (&var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y + var_sz_0));
+    fn_6_72_close_path (&var_sdf_0);
+    fn_6_49_fill (&var_sdf_0, mix(live_uniforms.live_41_164, live_uniforms.live_41_139, vec4<f32>(varyings.ds_hover)));
+    return (var_sdf_0.f_result * uniforms_user.ds_fade);
+}
+fn fn_41_4344_clip_and_transform_vertex(var_rect_pos_0: vec2<f32>, var_rect_size_0: vec2<f32>) -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_41_4346_vertex() -> vec4<f32> {
+    return fn_41_4344_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size);
+}
+@vertex fn vertex_main(geometries_in: Geometries, instances_in: Instances) -> Varyings {
+    geometries = geometries_in;
+    instances = instances_in;
+    varyings.ds_opened = instances.ds_opened;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.position = fn_41_4346_vertex();
+    return varyings;
+}
+@fragment fn fragment_main(varyings_in: Varyings) -> @location(0) vec4<f32> {
+    varyings = varyings_in;
+    return fn_41_4347_pixel();
+}
@@ -0,0 +1,259 @@
+// vertex
+uniform float const_table[44];
+
+uniform float live_table[28];
+vec4 live_41_165 = vec4(0.0);
+vec4 live_41_166 = vec4(0.0);
+vec4 live_41_167 = vec4(0.0);
+vec4 live_41_168 = vec4(0.0);
+vec4 live_41_169 = vec4(0.0);
+vec4 live_41_170 = vec4(0.0);
+vec4 live_41_171 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec3 packed_instance_3;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying float packed_varying_2;
+
+float ds_hover=0.0;
+float ds_focus=0.0;
+float ds_drag=0.0;
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+float ds_slide_pos=0.0;
+float ds_slider_type=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_41_6163_clip_and_transform_vertex(vec2 var_rect_pos_0, vec2 var_rect_size_0) {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * var_rect_size_0) + var_rect_pos_0), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_41_6165_vertex() {
+    return fn_41_6163_clip_and_transform_vertex (ds_rect_pos, ds_rect_size);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_165 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_166 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_167 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_168 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    live_41_169 = vec4(live_table[16], live_table[17], live_table[18], live_table[19]);
+    live_41_170 = vec4(live_table[20], live_table[21], live_table[22], live_table[23]);
+    live_41_171 = vec4(live_table[24], live_table[25], live_table[26], live_table[27]);
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_hover = packed_instance_0.x;
+    ds_focus = packed_instance_0.y;
+    ds_drag = packed_instance_0.z;
+    ds_rect_pos.x = packed_instance_0.w;
+    ds_rect_pos.y = packed_instance_1.x;
+    ds_rect_size.xy = packed_instance_1.yz;
+    ds_draw_clip.x = packed_instance_1.w;
+    ds_draw_clip.yzw = packed_instance_2.xyz;
+    ds_draw_depth = packed_instance_2.w;
+    ds_0 = packed_instance_3.x;
+    ds_slide_pos = packed_instance_3.y;
+    ds_slider_type = packed_instance_3.z;
+
+    gl_Position = fn_41_6165_vertex();
+
+    packed_varying_0.x = ds_hover;
+    packed_varying_0.y = ds_focus;
+    packed_varying_0.z = ds_drag;
+    packed_varying_0.w = ds_rect_size.x;
+    packed_varying_1.x = ds_rect_size.y;
+    packed_varying_1.y = ds_slide_pos;
+    packed_varying_1.z = ds_slider_type;
+    packed_varying_1.w = ds_pos.x;
+    packed_varying_2 = ds_pos.y;
+}
+// pixel
+uniform float const_table[44];
+
+uniform float live_table[28];
+vec4 live_41_165 = vec4(0.0);
+vec4 live_41_166 = vec4(0.0);
+vec4 live_41_167 = vec4(0.0);
+vec4 live_41_168 = vec4(0.0);
+vec4 live_41_169 = vec4(0.0);
+vec4 live_41_170 = vec4(0.0);
+vec4 live_41_171 = vec4(0.0);
+uniform float draw_table[1];
+float ds_draw_zbias = 0.0;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection = mat4(0.0);
+mat4 ds_camera_view = mat4(0.0);
+mat4 ds_camera_inv = mat4(0.0);
+float ds_dpi_factor = 0.0;
+float ds_dpi_dilate = 0.0;
+
+uniform float view_table[16];
+mat4 ds_view_transform = mat4(0.0);
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying float packed_varying_2;
+
+float ds_hover=0.0;
+float ds_focus=0.0;
+float ds_drag=0.0;
+vec2 ds_rect_size=vec2(0.0);
+float ds_slide_pos=0.0;
+float ds_slider_type=0.0;
+vec2 ds_pos=vec2(0.0);
+struct struct_6_28 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_6_68_rect(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    vec2 var_s_0 = (vec2(var_w_0, var_h_0) * const_table[37]);
+    vec2 var_d_0 = (abs(((vec2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    vec2 var_dm_0 = min(var_d_0, vec2(const_table[38], const_table[39]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2(const_table[40], const_table[41])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[31], const_table[32]);
+    float var_wb_0 = const_table[33];
+    if(var_self_0.f_blur > const_table[34]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[35], const_table[36]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[27] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[28]) {
+            float var_f2_0 = (const_table[29] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[30] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+vec4 fn_6_49_fill(inout struct_6_28 var_self_0, vec4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[24]));
+    (var_self_0.f_clip = -const_table[25]);
+    (var_self_0.f_has_clip = const_table[26]);
+    return var_self_0.f_result;
+}
+
+float fn_6_41_antialias(vec2 var_p_0) {
+    return (const_table[23] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_6_28 fn_6_42_viewport(vec2 var_pos_0) {
+    return struct_6_28(var_pos_0,vec4(const_table[13]),vec2(const_table[14]),vec2(const_table[15]),const_table[16],-const_table[17],const_table[18],const_table[19],const_table[20],fn_6_41_antialias (var_pos_0),const_table[21],const_table[22]);
+}
+
+vec4 fn_41_6166_pixel() {
+    float var_slider_height_0 = const_table[1];
+    float var_nub_size_0 = mix(const_table[2], const_table[3], ds_hover);
+    float var_nubbg_size_0 = const_table[4];
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((ds_pos * ds_rect_size));
+    vec4 var_slider_bg_color_0 = mix(live_41_165, live_41_166, ds_focus);
+    vec4 var_slider_color_0 = mix(mix(live_41_167, live_41_168, ds_hover), live_41_168, ds_focus);
+    vec4 var_nub_color_0 = mix(mix(live_41_169, live_41_170, ds_hover), mix(live_41_171, live_41_170, ds_drag), ds_focus);
+    vec4 var_nubbg_color_0 = mix(vec4(const_table[5], const_table[6], const_table[7], const_table[8]), live_41_169, ds_drag);
+    if(abs(ds_slider_type - 1.0)<0.5){
+            fn_6_68_rect (var_sdf_0, const_table[9], (ds_rect_size.y - var_slider_height_0), ds_rect_size.x, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_slider_bg_color_0);
+        fn_6_68_rect (var_sdf_0, const_table[10], (ds_rect_size.y - var_slider_height_0), ((ds_slide_pos * (ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_slider_color_0);
+        float var_nubbg_x_0 = (((ds_slide_pos * (ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[11])) + (const_table[12] * var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nubbg_x_0, (ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_nubbg_color_0);
+        float var_nub_x_0 = (ds_slide_pos * (ds_rect_size.x - var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nub_x_0, (ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_nub_color_0);
+    }else  if(abs(ds_slider_type - 2.0)<0.5){
+    }else  if(abs(ds_slider_type - 3.0)<0.5){
+    }
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_41_165 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_41_166 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    live_41_167 = vec4(live_table[8], live_table[9], live_table[10], live_table[11]);
+    live_41_168 = vec4(live_table[12], live_table[13], live_table[14], live_table[15]);
+    live_41_169 = vec4(live_table[16], live_table[17], live_table[18], live_table[19]);
+    live_41_170 = vec4(live_table[20], live_table[21], live_table[22], live_table[23]);
+    live_41_171 = vec4(live_table[24], live_table[25], live_table[26], live_table[27]);
+    ds_hover = packed_varying_0.x;
+    ds_focus = packed_varying_0.y;
+    ds_drag = packed_varying_0.z;
+    ds_rect_size.x = packed_varying_0.w;
+    ds_rect_size.y = packed_varying_1.x;
+    ds_slide_pos = packed_varying_1.y;
+    ds_slider_type = packed_varying_1.z;
+    ds_pos.x = packed_varying_1.w;
+    ds_pos.y = packed_varying_2;
+
+    gl_FragColor = fn_41_6166_pixel();
+}
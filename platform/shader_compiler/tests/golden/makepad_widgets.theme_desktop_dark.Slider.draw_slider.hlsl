@@ -0,0 +1,170 @@
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct_6_28 consfn_struct_6_28(
+float2 f_pos, float4 f_result, float2 f_last_pos, float2 f_start_pos, float f_shape, float f_clip, float f_has_clip, float f_old_shape, float f_blur, float f_aa, float f_scale_factor, float f_dist){;
+struct_6_28 r;
+r.f_pos = f_pos;
+r.f_result = f_result;
+r.f_last_pos = f_last_pos;
+r.f_start_pos = f_start_pos;
+r.f_shape = f_shape;
+r.f_clip = f_clip;
+r.f_has_clip = f_has_clip;
+r.f_old_shape = f_old_shape;
+r.f_blur = f_blur;
+r.f_aa = f_aa;
+r.f_scale_factor = f_scale_factor;
+r.f_dist = f_dist;
+return r;
+}
+cbuffer LiveUniforms : register(b0) {
+    float4 live_41_165;
+    float4 live_41_166;
+    float4 live_41_167;
+    float4 live_41_168;
+    float4 live_41_169;
+    float4 live_41_170;
+    float4 live_41_171;
+};
+cbuffer ConstTable : register(b1){float4 const_table[11];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_hover: INSTA;
+    float ds_focus: INSTB;
+    float ds_drag: INSTC;
+    float2 ds_rect_pos: INSTD;
+    float2 ds_rect_size: INSTE;
+    float4 ds_draw_clip: INSTF;
+    float ds_draw_depth: INSTG;
+    float ds_0: INSTH;
+    float ds_slide_pos: INSTI;
+    int ds_slider_type: INSTJ;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_hover: VARYA;
+    float ds_focus: VARYB;
+    float ds_drag: VARYC;
+    float2 ds_rect_size: VARYD;
+    float ds_slide_pos: VARYE;
+    int ds_slider_type: VARYF;
+    float2 ds_pos: VARYG;
+};
+float2 consfn_vec2_float(float x) {    return float2(x, x);}
+float4 consfn_vec4_float(float x) {    return float4(x, x, x, x);}
+float4 consfn_vec4_vec3_float(float3 x0, float x1) {    return float4(x0[0], x0[1], x0[2], x1);}
+void fn_6_68_rect(inout struct_6_28 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[9].y);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[9].z, const_table[9].w));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[10].x, const_table[10].y)))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(inout struct_6_28 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[7].w, const_table[8].x);
+    float var_wb_0 = const_table[8].y;
+    if(var_self_0.f_blur > const_table[8].z) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[8].w, const_table[9].x));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_48_fill_keep(inout struct_6_28 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[6].w - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[7].x) {
+            float var_f2_0 = (const_table[7].y - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[7].z - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(inout struct_6_28 var_self_0, float4 var_color_0) {
+    fn_6_48_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[6].x));
+    (var_self_0.f_clip = -const_table[6].y);
+    (var_self_0.f_has_clip = const_table[6].z);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0) {
+    return (const_table[5].w / length(float2(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_6_28 fn_6_42_viewport(float2 var_pos_0) {
+    return consfn_struct_6_28(var_pos_0,consfn_vec4_float(const_table[3].y),consfn_vec2_float(const_table[3].z),consfn_vec2_float(const_table[3].w),const_table[4].x,-const_table[4].y,const_table[4].z,const_table[4].w,const_table[5].x,fn_6_41_antialias (var_pos_0),const_table[5].y,const_table[5].z);
+}
+float4 fn_41_6166_pixel(inout Varyings varyings) {
+    float var_slider_height_0 = const_table[0].y;
+    float var_nub_size_0 = lerp(const_table[0].z, const_table[0].w, varyings.ds_hover);
+    float var_nubbg_size_0 = const_table[1].x;
+    struct_6_28 var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size));
+    float4 var_slider_bg_color_0 = lerp(live_41_165, live_41_166, varyings.ds_focus);
+    float4 var_slider_color_0 = lerp(lerp(live_41_167, live_41_168, varyings.ds_hover), live_41_168, varyings.ds_focus);
+    float4 var_nub_color_0 = lerp(lerp(live_41_169, live_41_170, varyings.ds_hover), lerp(live_41_171, live_41_170, varyings.ds_drag), varyings.ds_focus);
+    float4 var_nubbg_color_0 = lerp(float4(const_table[1].y, const_table[1].z, const_table[1].w, const_table[2].x), live_41_169, varyings.ds_drag);
+    if(varyings.ds_slider_type == 1){
+            fn_6_68_rect (var_sdf_0, const_table[2].y, (varyings.ds_rect_size.y - var_slider_height_0), varyings.ds_rect_size.x, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_slider_bg_color_0);
+        fn_6_68_rect (var_sdf_0, const_table[2].z, (varyings.ds_rect_size.y - var_slider_height_0), ((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_slider_color_0);
+        float var_nubbg_x_0 = (((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[2].w)) + (const_table[3].x * var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nubbg_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_nubbg_color_0);
+        float var_nub_x_0 = (varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nub_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0);
+        fn_6_49_fill (var_sdf_0, var_nub_color_0);
+    }else  if(varyings.ds_slider_type == 2){
+    }else  if(varyings.ds_slider_type == 3){
+    }
+    return var_sdf_0.f_result;
+}
+float4 fn_41_6163_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_41_6165_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings) {
+    return fn_41_6163_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, geometries, instances, varyings);
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, 0.0, float2(0.0,0.0), 0.0, 0, float2(0.0,0.0)};
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_focus = instances.ds_focus;
+    varyings.ds_drag = instances.ds_drag;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_slide_pos = instances.ds_slide_pos;
+    varyings.ds_slider_type = instances.ds_slider_type;
+    varyings.position = fn_41_6165_vertex(geometries, instances, varyings);
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_41_6166_pixel(varyings);
+}
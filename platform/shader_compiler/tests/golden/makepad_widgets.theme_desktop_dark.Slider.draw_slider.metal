@@ -0,0 +1,172 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_6_28 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float4 live_41_165;
+    float4 live_41_166;
+    float4 live_41_167;
+    float4 live_41_168;
+    float4 live_41_169;
+    float4 live_41_170;
+    float4 live_41_171;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_hover;
+    float ds_focus;
+    float ds_drag;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_slide_pos;
+    uint32_t  ds_slider_type;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_hover;
+    float ds_focus;
+    float ds_drag;
+    float2 ds_rect_size;
+    float ds_slide_pos;
+    uint32_t  ds_slider_type;
+    float2 ds_pos;
+};
+void fn_6_68_rect(thread struct_6_28 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[37]);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[38], const_table[39]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[40], const_table[41])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_6_47_calc_blur(thread struct_6_28 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[31], const_table[32]);
+    float var_wb_0 = const_table[33];
+    if(var_self_0.f_blur > const_table[34]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[35], const_table[36]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_6_48_fill_keep(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_6_47_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[27] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[28]) {
+            float var_f2_0 = (const_table[29] - fn_6_47_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[30] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_6_49_fill(thread struct_6_28 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_6_48_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[24]));
+    (var_self_0.f_clip = -const_table[25]);
+    (var_self_0.f_has_clip = const_table[26]);
+    return var_self_0.f_result;
+}
+float fn_6_41_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[23] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_6_28  fn_6_42_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_6_28{var_pos_0,float4(const_table[13]),float2(const_table[14]),float2(const_table[15]),const_table[16],-const_table[17],const_table[18],const_table[19],const_table[20],fn_6_41_antialias (var_pos_0, const_table),const_table[21],const_table[22]};
+}
+float4 fn_41_6166_pixel(constant const float *const_table, thread Varyings &varyings, constant LiveUniforms &live_uniforms) {
+    float var_slider_height_0 = const_table[1];
+    float var_nub_size_0 = mix(const_table[2], const_table[3], varyings.ds_hover);
+    float var_nubbg_size_0 = const_table[4];
+    struct_6_28  var_sdf_0 = fn_6_42_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    float4 var_slider_bg_color_0 = mix(live_uniforms.live_41_165, live_uniforms.live_41_166, varyings.ds_focus);
+    float4 var_slider_color_0 = mix(mix(live_uniforms.live_41_167, live_uniforms.live_41_168, varyings.ds_hover), live_uniforms.live_41_168, varyings.ds_focus);
+    float4 var_nub_color_0 = mix(mix(live_uniforms.live_41_169, live_uniforms.live_41_170, varyings.ds_hover), mix(live_uniforms.live_41_171, live_uniforms.live_41_170, varyings.ds_drag), varyings.ds_focus);
+    float4 var_nubbg_color_0 = mix(float4(const_table[5], const_table[6], const_table[7], const_table[8]), live_uniforms.live_41_169, varyings.ds_drag);
+    if(varyings.ds_slider_type == 1){
+            fn_6_68_rect (var_sdf_0, const_table[9], (varyings.ds_rect_size.y - var_slider_height_0), varyings.ds_rect_size.x, var_slider_height_0, const_table);
+        fn_6_49_fill (var_sdf_0, var_slider_bg_color_0, const_table);
+        fn_6_68_rect (var_sdf_0, const_table[10], (varyings.ds_rect_size.y - var_slider_height_0), ((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0, const_table);
+        fn_6_49_fill (var_sdf_0, var_slider_color_0, const_table);
+        float var_nubbg_x_0 = (((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[11])) + (const_table[12] * var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nubbg_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0, const_table);
+        fn_6_49_fill (var_sdf_0, var_nubbg_color_0, const_table);
+        float var_nub_x_0 = (varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0));
+        fn_6_68_rect (var_sdf_0, var_nub_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0, const_table);
+        fn_6_49_fill (var_sdf_0, var_nub_color_0, const_table);
+    }else  if(varyings.ds_slider_type == 2){
+    }else  if(varyings.ds_slider_type == 3){
+    }
+    return var_sdf_0.f_result;
+}
+float4 fn_41_6163_clip_and_transform_vertex(float2 var_rect_pos_0, float2 var_rect_size_0, constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * var_rect_size_0) + var_rect_pos_0), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - var_rect_pos_0) / var_rect_size_0));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_41_6165_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_41_6163_clip_and_transform_vertex (instances.ds_rect_pos, varyings.ds_rect_size, const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_focus = instances.ds_focus;
+    varyings.ds_drag = instances.ds_drag;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_slide_pos = instances.ds_slide_pos;
+    varyings.ds_slider_type = instances.ds_slider_type;
+    varyings.position = fn_41_6165_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_41_6166_pixel(const_table, varyings, live_uniforms);
+}
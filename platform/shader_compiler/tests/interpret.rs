@@ -0,0 +1,237 @@
+use {
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+    makepad_shader_compiler::{
+        makepad_live_compiler::live_rust_types::module_path_in_crate,
+        makepad_live_id::{LiveId, live_id},
+        interpret::{NoSampler, LANES},
+        shader_ast::Ident,
+        OfflineShaders,
+        DrawShaderBlendMode,
+    },
+};
+
+// Runs draw shaders on the CPU with the interpreter, the way a unit test of shader logic would
+
+const TEST_FILE: &str = "test/src/draw_test.rs";
+
+const TEST_SOURCE: &str = r#"
+use crate::makepad_draw::*;
+
+live_design!{
+    import makepad_draw::shader::std::*;
+
+    TEST_GREEN = #0f0
+
+    DrawCircle = {{DrawCircle}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.circle(self.rect_size.x * 0.5, self.rect_size.y * 0.5, self.radius);
+            return sdf.fill(self.color);
+        }
+    }
+
+    DrawLive = {{DrawLive}} {
+        fn pixel(self) -> vec4 {
+            return mix((TEST_GREEN), #f00, self.pos.x > 0.5 ? 1.0 : 0.0);
+        }
+    }
+
+    DrawInverse = {{DrawInverse}} {
+        fn pixel(self) -> vec4 {
+            let m = mat4(
+                self.scale, 0.0, 0.0, 0.0,
+                0.0, 2.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0
+            );
+            let v = inverse(m) * vec4(1.0, 1.0, 0.0, 1.0);
+            return v;
+        }
+    }
+
+    DrawDivide = {{DrawDivide}} {
+        fn pixel(self) -> vec4 {
+            let zero = int(self.scale - 1.0);
+            return vec4(float(int(self.scale) / zero));
+        }
+    }
+
+    DrawGlow = {{DrawGlow}} {
+        blend_mode: Additive
+        depth_write: false
+        fn pixel(self) -> vec4 {
+            return #f80;
+        }
+    }
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawCircle {
+    #[deref] draw_super: DrawQuad,
+    #[live] color: Vec4,
+    #[live] radius: f32,
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawLive {
+    #[deref] draw_super: DrawQuad,
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawInverse {
+    #[deref] draw_super: DrawQuad,
+    #[live] scale: f32,
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawDivide {
+    #[deref] draw_super: DrawQuad,
+    #[live] scale: f32,
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawGlow {
+    #[deref] draw_super: DrawQuad,
+}
+"#;
+
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            rust_files(&path, out);
+        }
+        else if path.extension().is_some_and( | ext | ext == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+fn load() -> OfflineShaders {
+    let mut offline = OfflineShaders::default();
+    offline.add_source(TEST_FILE, "makepad_test::draw_test", TEST_SOURCE.to_string());
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../draw");
+    let mut paths = Vec::new();
+    rust_files(&crate_dir.join("src"), &mut paths);
+    for path in paths {
+        let relative = path.strip_prefix(&crate_dir).unwrap();
+        let module_path = module_path_in_crate("makepad_draw", relative).unwrap();
+        let file_name = format!("draw/{}", relative.to_string_lossy().replace('\\', "/"));
+        offline.add_source(&file_name, &module_path, fs::read_to_string(&path).unwrap());
+    }
+    let errors = offline.build();
+    assert!(errors.is_empty(), "{}", errors.iter().map( | e | e.to_string()).collect::<Vec<_>>().join("\n"));
+    offline
+}
+
+// runs the pixel shader of a draw shader for the 2x2 pixels starting at (x, y) in a
+// rect of the given size, after set_fields has filled in the rest of the instance
+fn eval_pixels(file_name: &str, name: &str, size: [f32; 2], x: f32, y: f32, set_fields: &[(Ident, &[f32])]) -> Result<[[f32; 4]; LANES], String> {
+    let mut offline = load();
+    let draw_shader_ptr = offline.find_draw_shader(file_name, name).unwrap();
+    let const_table = offline.analyse(draw_shader_ptr).unwrap();
+    let mut interp = offline.interpreter(draw_shader_ptr, &const_table, &NoSampler).unwrap();
+    assert!(interp.set_field(Ident(live_id!(rect_size)), &size));
+    // the pixel centers of the quad, in the 0..1 space the vertex shader hands to pos
+    assert!(interp.set_field_with(Ident(live_id!(pos)), | lane, comp | {
+        let pixel = [x + (lane % 2) as f32, y + (lane / 2) as f32];
+        (pixel[comp] + 0.5) / size[comp]
+    }));
+    for (ident, values) in set_fields {
+        assert!(interp.set_field(*ident, values), "no field {}", ident);
+    }
+    interp.eval_pixel()
+}
+
+fn assert_color(got: [f32; 4], expected: [f32; 4]) {
+    for i in 0..4 {
+        assert!((got[i] - expected[i]).abs() < 0.01, "expected {:?} got {:?}", expected, got);
+    }
+}
+
+#[test]
+fn draw_color_premultiplies() {
+    let pixels = eval_pixels("draw/src/shader/draw_color.rs", "DrawColor", [10.0, 10.0], 0.0, 0.0, &[
+        (Ident(live_id!(color)), &[1.0, 0.5, 0.0, 0.5])
+    ]).unwrap();
+    for pixel in pixels {
+        assert_color(pixel, [0.5, 0.25, 0.0, 0.5]);
+    }
+}
+
+#[test]
+fn sdf_circle_fills_inside_only() {
+    let fields: &[(Ident, &[f32])] = &[
+        (Ident(live_id!(color)), &[0.0, 0.0, 1.0, 1.0]),
+        (Ident(live_id!(radius)), &[20.0]),
+    ];
+    let center = eval_pixels(TEST_FILE, "DrawCircle", [100.0, 100.0], 49.0, 49.0, fields).unwrap();
+    for pixel in center {
+        assert_color(pixel, [0.0, 0.0, 1.0, 1.0]);
+    }
+    let corner = eval_pixels(TEST_FILE, "DrawCircle", [100.0, 100.0], 0.0, 0.0, fields).unwrap();
+    for pixel in corner {
+        assert_color(pixel, [0.0, 0.0, 0.0, 0.0]);
+    }
+    // on the edge the antialiasing blends, which needs the lanes to see each other for dFdx
+    let edge = eval_pixels(TEST_FILE, "DrawCircle", [100.0, 100.0], 69.0, 49.0, fields).unwrap();
+    assert!(edge[0][3] > 0.0 && edge[0][3] < 1.0, "edge is not antialiased {:?}", edge);
+}
+
+#[test]
+fn live_values_come_from_the_registry() {
+    let pixels = eval_pixels(TEST_FILE, "DrawLive", [10.0, 10.0], 0.0, 0.0, &[]).unwrap();
+    assert_color(pixels[0], [0.0, 1.0, 0.0, 1.0]);
+    let pixels = eval_pixels(TEST_FILE, "DrawLive", [10.0, 10.0], 8.0, 0.0, &[]).unwrap();
+    assert_color(pixels[0], [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn inverse_survives_nan() {
+    let pixels = eval_pixels(TEST_FILE, "DrawInverse", [10.0, 10.0], 0.0, 0.0, &[
+        (Ident(live_id!(scale)), &[4.0])
+    ]).unwrap();
+    assert_color(pixels[0], [0.25, 0.5, 0.0, 1.0]);
+    // picking the pivot used to panic comparing against NaN
+    let pixels = eval_pixels(TEST_FILE, "DrawInverse", [10.0, 10.0], 0.0, 0.0, &[
+        (Ident(live_id!(scale)), &[f32::NAN])
+    ]).unwrap();
+    assert!(pixels[0][0].is_nan());
+}
+
+#[test]
+fn failing_pixel_shader_returns_the_error() {
+    let pixels = eval_pixels(TEST_FILE, "DrawDivide", [10.0, 10.0], 0.0, 0.0, &[
+        (Ident(live_id!(scale)), &[3.0])
+    ]).unwrap();
+    assert_color(pixels[0], [1.0, 1.0, 1.0, 1.0]);
+    let result = eval_pixels(TEST_FILE, "DrawDivide", [10.0, 10.0], 0.0, 0.0, &[
+        (Ident(live_id!(scale)), &[1.0])
+    ]);
+    assert_eq!(result.err().as_deref(), Some("integer division by zero"));
+}
+
+#[test]
+fn pipeline_state_flags() {
+    let mut offline = load();
+    let glow = offline.find_draw_shader(TEST_FILE, "DrawGlow").unwrap();
+    offline.analyse(glow).unwrap();
+    let flags = offline.draw_shader_def(glow).unwrap().flags;
+    assert_eq!(flags.blend_mode, DrawShaderBlendMode::Additive);
+    assert!(flags.depth_test && !flags.depth_write);
+    
+    let live = offline.find_draw_shader(TEST_FILE, "DrawLive").unwrap();
+    offline.analyse(live).unwrap();
+    let live_flags = &offline.draw_shader_def(live).unwrap().flags;
+    assert_eq!(live_flags.blend_mode, DrawShaderBlendMode::Normal);
+    assert!(live_flags.depth_test && live_flags.depth_write);
+    assert!(!flags.same_pipeline_state(live_flags));
+}
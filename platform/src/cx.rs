@@ -13,7 +13,8 @@ use {
     crate::{
         makepad_live_compiler::{
             LiveRegistry,
-            LiveFileChange
+            LiveFileChange,
+            LiveDiagnostic
         },
         makepad_shader_compiler::ShaderRegistry,
         draw_shader::CxDrawShaders,
@@ -39,7 +40,7 @@ use {
         window::CxWindowPool,
         draw_list::CxDrawListPool,
         pass::CxPassPool,
-        texture::CxTexturePool,
+        texture::{CxTexturePool, TexturePixelsEvent},
         geometry::{
             Geometry,
             CxGeometryPool,
@@ -89,8 +90,14 @@ pub struct Cx {
     
     pub (crate) triggers: HashMap<Area, Vec<Trigger >>,
     
+    pub (crate) texture_pixels: Vec<TexturePixelsEvent>,
+    
     pub live_registry: Rc<RefCell<LiveRegistry >>,
     pub live_file_changes: Option<std::sync::mpsc::Receiver<Vec<LiveFileChange>>>,
+    pub (crate) queued_live_file_changes: Vec<LiveFileChange>,
+    pub (crate) live_edit_pending: bool,
+    // when set, live errors are collected here instead of logged
+    pub (crate) live_diagnostics: Option<Vec<LiveDiagnostic>>,
     pub shader_registry: ShaderRegistry,
     
     #[allow(dead_code)]
@@ -243,8 +250,13 @@ impl Cx {
             
             triggers: Default::default(),
             
+            texture_pixels: Vec::new(),
+            
             live_registry: Rc::new(RefCell::new(LiveRegistry::default())),
             live_file_changes: None,
+            queued_live_file_changes: Vec::new(),
+            live_edit_pending: false,
+            live_diagnostics: if std::env::args().any( | v | v == "--live-check") {Some(Vec::new())} else {None},
             shader_registry: ShaderRegistry::new(),
             
             command_settings: HashMap::new(),
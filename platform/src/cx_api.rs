@@ -211,6 +211,21 @@ impl Cx {
         return 1.0;
     }
     
+    /// The window an area ends up in, following its pass up through any parent passes
+    pub fn get_window_id_of(&self, area: &Area) -> Option<WindowId> {
+        let mut pass_id_walk = self.draw_lists[area.draw_list_id()?].pass_id?;
+        for _ in 0..25 {
+            match self.passes[pass_id_walk].parent {
+                CxPassParent::Window(window_id) => return Some(window_id),
+                CxPassParent::Pass(next_pass_id) => {
+                    pass_id_walk = next_pass_id;
+                },
+                _ => {break;}
+            }
+        }
+        None
+    }
+    
     pub fn get_delegated_dpi_factor(&mut self, pass_id: PassId) -> f64 {
         let mut pass_id_walk = pass_id;
         for _ in 0..25 {
@@ -391,10 +406,13 @@ impl Cx {
         &self.spawner
     }
 
+    // on the Linux X11 and direct backends only http:// is supported, https:// urls
+    // come back as a NetworkResponse::HttpRequestError saying so
     pub fn http_request(&mut self, request_id: LiveId, request: HttpRequest) {
         self.platform_ops.push(CxOsOp::HttpRequest{request_id, request});
     }
            
+    // like http_request, wss:// is not supported on Linux and reports a WebSocketError
     pub fn web_socket_open(&mut self, request_id: LiveId, request: HttpRequest) {
         self.platform_ops.push(CxOsOp::WebSocketOpen{
             request,
@@ -409,6 +427,13 @@ impl Cx {
         });
     }
     
+    pub fn web_socket_send_string(&mut self, request_id: LiveId, data: String) {
+        self.platform_ops.push(CxOsOp::WebSocketSendString{
+            request_id,
+            data,
+        });
+    }
+    
     pub fn println_resources(&self){
         println!("Num textures: {}",self.textures.0.pool.len());
     }
@@ -142,6 +142,10 @@ impl DrawShaderFingerprint {
                         value: LiveValue::DSL {token_start, token_count, expand_index: None}
                     });
                 }
+                // the same code with other pipeline state needs its own shader
+                _ if matches!(node.id, live_id!(blend_mode) | live_id!(depth_test) | live_id!(depth_write)) => {
+                    fingerprint.push(node.clone());
+                }
                 _ => ()
             }
             node_iter = doc.nodes.next_child(node_index);
@@ -11,7 +11,10 @@ use {
             LiveType,
             LiveId,
             LiveNode,
-            LiveNodeSliceApi
+            LiveNodeSliceApi,
+            LiveDiagnostic,
+            LiveDiagnosticStage,
+            LiveSeverity,
         },
         makepad_live_tokenizer::{LiveErrorOrigin, live_error_origin},
         makepad_shader_compiler::*,
@@ -291,7 +294,12 @@ impl DrawVars {
                     cx.draw_shaders.error_fingerprints.push(fingerprint);
                     // ok so. lets get the source for this file id
                     let err = live_registry.live_error_to_live_file_error(e);
-                    log!("Error {}", err);
+                    if let Some(diagnostics) = &mut cx.live_diagnostics {
+                        diagnostics.push(LiveDiagnostic::from_live_file_error(LiveSeverity::Error, LiveDiagnosticStage::Shader, err));
+                    }
+                    else {
+                        log!("Error {}", err);
+                    }
                 }
                 Ok(()) => {
                     // OK! SO the shader parsed
@@ -577,6 +585,9 @@ impl DrawVars {
             live_id!(debug) => false,
             live_id!(debug_id) => false,
             live_id!(draw_call_group) => false,
+            live_id!(blend_mode) => false,
+            live_id!(depth_test) => false,
+            live_id!(depth_write) => false,
             _ => true
         };
         
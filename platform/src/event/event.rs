@@ -21,6 +21,7 @@ use {
         video::VideoInputsEvent,
         draw_list::DrawListId,
         menu::MenuCommand,
+        texture::TexturePixelsEvent,
     },
 };
 
@@ -72,6 +73,7 @@ pub enum Event {
     MidiPorts(MidiPortsEvent),
     VideoInputs(VideoInputsEvent),
     NetworkResponses(Vec<NetworkResponseEvent>),
+    TexturePixels(Vec<TexturePixelsEvent>),
 
     #[cfg(target_arch = "wasm32")]
     ToWasmMsg(ToWasmMsgEvent),
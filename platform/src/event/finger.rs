@@ -11,12 +11,13 @@ use {
             LiveTypeField,
             LiveFieldKind,
             LiveNode,
+            LiveValue,
             LiveId,
             LiveModuleId,
             LiveTypeInfo,
             LiveNodeSliceApi
         },
-        live_traits::{LiveNew, LiveHook, LiveHookDeref, LiveApplyValue, LiveApply, ApplyFrom},
+        live_traits::{LiveNew, LiveHook, LiveHookDeref, LiveApplyValue, LiveApply, LiveRead, ApplyFrom},
         makepad_derive_live::*,
         makepad_math::*,
         makepad_live_id::{FromLiveId, live_id, live_id_num},
@@ -116,7 +117,7 @@ pub struct TouchUpdateEvent {
 // Finger API
 
 
-#[derive(Clone, Copy, Default, Debug, Live)]
+#[derive(Clone, Copy, Default, Debug, Live, LiveRead)]
 #[live_ignore]
 pub struct Margin {
     #[live] pub left: f64,
@@ -191,7 +191,7 @@ impl HttpResponse {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum HttpMethod{
     GET,
     HEAD,
@@ -14,6 +14,7 @@ pub struct WindowGeom {
     pub can_fullscreen: bool,
     pub xr_is_presenting: bool,
     pub is_fullscreen: bool, 
+    pub is_maximized: bool,
     pub is_topmost: bool,
     pub position: DVec2,
     pub inner_size: DVec2,
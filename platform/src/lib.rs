@@ -10,6 +10,7 @@ mod cx_api;
 pub mod live_traits;
 pub mod live_cx;
 pub mod live_atomic;
+mod live_state;
 
 pub mod thread;
 pub mod audio;
@@ -52,6 +53,9 @@ pub use makepad_objc_sys;
 pub use ::makepad_windows as windows;
 
 pub use makepad_futures;
+
+#[cfg(target_os = "linux")]
+pub use makepad_zune_png;
  
 pub use {
     makepad_shader_compiler,
@@ -102,6 +106,9 @@ pub use {
         LiveNodeVec,
         LiveNodeSliceApi,
         LiveNodeVecApi,
+        LiveDiagnostic,
+        LiveDiagnosticStage,
+        LiveSeverity,
     },
     component_map::ComponentMap,
     makepad_shader_compiler::{
@@ -201,7 +208,10 @@ pub use {
             Texture,
             TextureId,
             TextureFormat,
-            TextureDesc
+            TextureDimension,
+            TextureDesc,
+            TexturePixels,
+            TexturePixelsEvent
         },
         live_prims::{
             LiveDependency,
@@ -218,6 +228,10 @@ pub use {
             ToLiveValue,
             ApplyFrom,
         },
+        live_state::{
+            save_live_state,
+            load_live_state,
+        },
         animator::{
             Ease,
             Play,
@@ -1,6 +1,8 @@
 use {
+    std::path::Path,
     crate::{
         makepad_live_tokenizer::{LiveErrorOrigin, live_error_origin},
+        makepad_live_id::live_id,
         makepad_live_compiler::{
             LiveFileChange,
             TextPos,
@@ -15,6 +17,12 @@ use {
             LivePtr,
             /*LiveTokenId,*/
             LiveFileId,
+            LiveFileError,
+            TextSpan,
+            LiveDiagnostic,
+            LiveDiagnosticStage,
+            LiveSeverity,
+            nearest_name,
         },
         makepad_error_log::*,
         makepad_live_compiler::LiveTypeInfo,
@@ -81,7 +89,11 @@ impl Cx {
     }
     
     pub fn apply_error_no_matching_field(&mut self, origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode]) {
-        self.apply_error(origin, index, nodes, format!("no matching field: {}", nodes[index].id))
+        let fields = self.live_registry.borrow().live_type_field_names(nodes, index);
+        let fields: Vec<String> = fields.iter().map( | id | id.to_string()).collect();
+        let suggestion = nearest_name(&nodes[index].id.to_string(), fields.iter().map( | s | s.as_str()))
+            .map( | name | format!("did you mean `{}`?", name));
+        self.apply_diagnostic(LiveSeverity::Error, origin, index, nodes, format!("no matching field: {}", nodes[index].id), suggestion)
     }
     
     pub fn apply_error_wrong_type_for_value(&mut self, origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode]) {
@@ -137,15 +149,25 @@ impl Cx {
     }
     
     pub fn apply_resource_not_loaded(&mut self, origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], path: &str, msg: &str) {
-        self.apply_error(origin, index, nodes, format!("Resource not loaded {} {}", path, msg))
+        self.apply_diagnostic(LiveSeverity::Warning, origin, index, nodes, format!("Resource not loaded {} {}", path, msg), None)
     }
     
     pub fn apply_error_eval(&mut self, err: LiveError) {
         let live_registry = self.live_registry.borrow();
-        error!("{}", live_registry.live_error_to_live_file_error(err));
+        let err = live_registry.live_error_to_live_file_error(err);
+        if let Some(diagnostics) = &mut self.live_diagnostics {
+            diagnostics.push(LiveDiagnostic::from_live_file_error(LiveSeverity::Error, LiveDiagnosticStage::Apply, err));
+        }
+        else {
+            error!("{}", err);
+        }
     }
     
     pub fn apply_error(&mut self, origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], message: String) {
+        self.apply_diagnostic(LiveSeverity::Error, origin, index, nodes, message, None)
+    }
+    
+    fn apply_diagnostic(&mut self, severity: LiveSeverity, origin: LiveErrorOrigin, index: usize, nodes: &[LiveNode], message: String, suggestion: Option<String>) {
         let live_registry = self.live_registry.borrow();
         if let Some(token_id) = &nodes[index].origin.token_id() {
             let err = LiveError {
@@ -153,15 +175,52 @@ impl Cx {
                 message,
                 span: (*token_id).into()
             };
+            let err = live_registry.live_error_to_live_file_error(err);
+            if let Some(diagnostics) = &mut self.live_diagnostics {
+                diagnostics.push(LiveDiagnostic::from_live_file_error(severity, LiveDiagnosticStage::Apply, err).with_suggestion(suggestion));
+                return
+            }
             #[cfg(not(lines))]
             line_nr_error_once();
-            error!("Apply error: {} {:?}", live_registry.live_error_to_live_file_error(err), nodes[index].value);
+            if let Some(suggestion) = suggestion {
+                error!("Apply error: {} {:?} ({})", err, nodes[index].value, suggestion);
+            }
+            else {
+                error!("Apply error: {} {:?}", err, nodes[index].value);
+            }
         }
         else {
             error!("Apply without file, at index {} {} origin: {}", index, message, origin);
         }
     }
     
+    // from here on live errors are collected as diagnostics instead of logged.
+    // Starting an app with --live-check turns this on before its live_design runs
+    pub fn collect_live_diagnostics(&mut self) {
+        if self.live_diagnostics.is_none() {
+            self.live_diagnostics = Some(Vec::new());
+        }
+    }
+    
+    pub fn take_live_diagnostics(&mut self) -> Vec<LiveDiagnostic> {
+        if let Some(diagnostics) = &mut self.live_diagnostics {
+            std::mem::take(diagnostics)
+        }
+        else {
+            Vec::new()
+        }
+    }
+    
+    // --live-check needs the headless soft renderer which only the linux backends have, elsewhere
+    // exit with an error rather than open a window and never report the diagnostics
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn exit_if_live_check_unsupported(&self, platform: &str) {
+        if std::env::args().any( | v | v == "--live-check") {
+            error!("--live-check is not supported on {}, run it on Linux", platform);
+            std::process::exit(2);
+        }
+    }
+    
     pub fn start_live_file_watcher(&mut self){
         let live_registry = self.live_registry.borrow();
         let (send, recv) = std::sync::mpsc::channel();
@@ -197,12 +256,20 @@ impl Cx {
         });
     }
     
+    // file contents pushed to us by a host process (Studio), picked up by the next was_live_edit
+    pub fn queue_live_file_change(&mut self, file_name: String, content: String){
+        self.queued_live_file_changes.push(LiveFileChange{
+            file_name,
+            content
+        });
+    }
+    
     pub fn was_live_edit(&mut self)->bool{
         // ok so we have a life filechange
         // now what. now we need to 'reload' our entire live system.. how.
         // what we can do is tokenize the entire file
         // then find the token-slice we need
-        let mut all_changes = Vec::new();
+        let mut all_changes = std::mem::take(&mut self.queued_live_file_changes);
         if let Some(live_file_changes) = &self.live_file_changes{
             while let Ok(changes) = live_file_changes.try_recv(){
                 all_changes.extend(changes);
@@ -210,6 +277,21 @@ impl Cx {
         }
         if all_changes.len()>0{
             let mut live_registry = self.live_registry.borrow_mut();
+            // the host can have a different root for its paths, so match on the trailing path components
+            all_changes.retain_mut(|change|{
+                let path = Path::new(&change.file_name);
+                if let Some(file) = live_registry.live_files.iter().find(|file| path.ends_with(&file.file_name) || Path::new(&file.file_name).ends_with(path)){
+                    change.file_name = file.file_name.clone();
+                    true
+                }
+                else{
+                    error!("Live reload of a file without live_design {}", change.file_name);
+                    false
+                }
+            });
+            if all_changes.len() == 0{
+                return false
+            }
             let mut errs = Vec::new();
             live_registry.process_file_changes(all_changes, &mut errs);
             for err in errs {
@@ -231,12 +313,50 @@ impl Cx {
         for file in &live_registry.live_files {
             log!("{}. {}", file.module_id.0, file.module_id.1);        // lets expand the f'er
         }*/
+        live_registry.set_condition(live_id!(os), LiveValue::Id(Self::live_os_id()));
         live_registry.expand_all_documents(&mut errs);
         for err in errs {
-            error!("Error expanding live file {}", live_registry.live_error_to_live_file_error(err));
+            let err = live_registry.live_error_to_live_file_error(err);
+            if let Some(diagnostics) = &mut self.live_diagnostics {
+                diagnostics.push(LiveDiagnostic::from_live_file_error(LiveSeverity::Error, LiveDiagnosticStage::Expand, err));
+            }
+            else {
+                error!("Error expanding live file {}", err);
+            }
         }
     }
     
+    // the os if blocks in live_design test against, os_type only gets filled in by the event loop
+    fn live_os_id() -> LiveId {
+        if cfg!(target_os = "macos") {live_id!(macos)}
+        else if cfg!(target_os = "ios") {live_id!(ios)}
+        else if cfg!(target_os = "android") {live_id!(android)}
+        else if cfg!(target_os = "windows") {live_id!(windows)}
+        else if cfg!(target_arch = "wasm32") {live_id!(web)}
+        else if cfg!(target_os = "linux") {live_id!(linux)}
+        else {live_id!(unknown)}
+    }
+    
+    // sets a value if blocks in live_design can test, like a feature flag or a dpi class.
+    // before startup the first expand picks it up, after it the documents using if blocks
+    // are expanded again and the app is re-applied with Event::LiveEdit after the current event
+    pub fn set_live_condition(&mut self, id: LiveId, value: LiveValue) {
+        let mut errs = Vec::new();
+        {
+            let mut live_registry = self.live_registry.borrow_mut();
+            let expanded = live_registry.live_files.iter().any( | file | !file.expanded.nodes.is_empty());
+            if !live_registry.set_condition(id, value) || !expanded {
+                return
+            }
+            live_registry.expand_all_documents(&mut errs);
+            for err in errs {
+                error!("set_live_condition: error expanding live file {}", live_registry.live_error_to_live_file_error(err));
+            }
+        }
+        self.draw_shaders.reset_for_live_reload();
+        self.live_edit_pending = true;
+    }
+    
     pub fn live_scan_dependencies(&mut self) {
         let live_registry = self.live_registry.borrow();
         for file in &live_registry.live_files {
@@ -266,11 +386,162 @@ impl Cx {
         );
         //println!("END");
         if let Err(err) = result {
+            if let Some(diagnostics) = &mut self.live_diagnostics {
+                diagnostics.push(LiveDiagnostic::from_live_file_error(LiveSeverity::Error, LiveDiagnosticStage::Parse, err));
+                return
+            }
             #[cfg(not(lines))]
             line_nr_error_once();
             error!("Error parsing live file {}", err);
         }
     }
+    // loads a .live document from disk at runtime, module_path is what other documents import it as.
+    // loading the same path again replaces its contents, widgets made from it need to be re-applied by the app
+    pub fn load_live_file(&mut self, path: &str, module_path: &str) -> Result<LiveModuleId, Vec<LiveFileError>> {
+        let source = std::fs::read_to_string(path).map_err( | err | {
+            vec![Self::live_load_error(path, format!("cannot read live file: {}", err))]
+        }) ?;
+        self.load_live_file_from_str(path, module_path, source)
+    }
+    
+    // loads a .live document that was pulled in as a dependency, ie dep("crate://self/skins/dark.live")
+    pub fn load_live_file_from_dependency(&mut self, path: &str, module_path: &str) -> Result<LiveModuleId, Vec<LiveFileError>> {
+        let data = self.get_dependency(path).map_err( | err | vec![Self::live_load_error(path, err)]) ?;
+        let source = String::from_utf8(data.to_vec()).map_err( | _ | {
+            vec![Self::live_load_error(path, "live file is not valid utf8".to_string())]
+        }) ?;
+        self.load_live_file_from_str(path, module_path, source)
+    }
+    
+    pub fn load_live_file_from_str(&mut self, file_name: &str, module_path: &str, source: String) -> Result<LiveModuleId, Vec<LiveFileError>> {
+        let module_id = LiveModuleId::from_str(module_path).map_err( | err | {
+            vec![Self::live_load_error(file_name, format!("invalid module path {}: {}", module_path, err))]
+        }) ?;
+        // crate://self/ dependencies in the document are relative to the file itself
+        let base_path = Path::new(file_name).parent().map( | p | p.to_string_lossy().to_string()).unwrap_or_default();
+        let mut errs = Vec::new();
+        let result = self.live_registry.borrow_mut().register_standalone_live_file(file_name, &base_path, module_id, source, &mut errs);
+        let mut errors: Vec<LiveFileError> = {
+            let live_registry = self.live_registry.borrow();
+            errs.into_iter().map( | err | live_registry.live_error_to_live_file_error(err)).collect()
+        };
+        if let Err(err) = result {
+            errors.push(err);
+        }
+        if errors.len() > 0 {
+            return Err(errors)
+        }
+        self.live_load_new_dependencies();
+        self.draw_shaders.reset_for_live_reload();
+        Ok(module_id)
+    }
+    
+    // expands module_id with the top level definitions of overlay on top of it (or as written for None)
+    // and re-applies the app with Event::LiveEdit after the current event
+    pub fn set_live_module_overlay(&mut self, module_id: LiveModuleId, overlay: Option<LiveModuleId>) -> Result<(), Vec<LiveFileError>> {
+        if self.live_registry.borrow().module_overlay(module_id) == overlay {
+            return Ok(())
+        }
+        let mut errs = Vec::new();
+        let result = self.live_registry.borrow_mut().set_module_overlay(module_id, overlay, &mut errs);
+        let mut errors: Vec<LiveFileError> = {
+            let live_registry = self.live_registry.borrow();
+            errs.into_iter().map( | err | live_registry.live_error_to_live_file_error(err)).collect()
+        };
+        match result {
+            Ok(()) => {
+                self.draw_shaders.reset_for_live_reload();
+                self.live_edit_pending = true;
+            }
+            Err(err) => errors.push(err)
+        }
+        if errors.len() > 0 {
+            return Err(errors)
+        }
+        Ok(())
+    }
+    
+    // loads the translation catalog of a locale, replacing what it had. See makepad_live_compiler::live_localization
+    // for the syntax. The app is re-applied with Event::LiveEdit after the current event
+    pub fn load_locale_file(&mut self, locale: &str, path: &str) -> Result<(), LiveFileError> {
+        let source = std::fs::read_to_string(path).map_err( | err | {
+            Self::live_load_error(path, format!("cannot read locale file: {}", err))
+        }) ?;
+        self.load_locale_from_str(locale, path, &source)
+    }
+    
+    // the catalog has to be pulled in with dep() somewhere in a live_design, ie
+    // Locales = {nl: dep("crate://self/locales/nl.ftl")}
+    pub fn load_locale_from_dependency(&mut self, locale: &str, path: &str) -> Result<(), LiveFileError> {
+        let data = self.get_dependency(path).map_err( | err | Self::live_load_error(path, err)) ?;
+        let source = std::str::from_utf8(&data).map_err( | _ | {
+            Self::live_load_error(path, "locale file is not valid utf8".to_string())
+        }) ?;
+        self.load_locale_from_str(locale, path, source)
+    }
+    
+    pub fn load_locale_from_str(&mut self, locale: &str, file_name: &str, source: &str) -> Result<(), LiveFileError> {
+        if let Err(err) = self.live_registry.borrow_mut().localization.add_catalog(locale, source) {
+            let mut file_err = Self::live_load_error(file_name, err.message);
+            file_err.span.start.line = err.line as u32 - 1;
+            return Err(file_err)
+        }
+        self.live_edit_pending = true;
+        Ok(())
+    }
+    
+    // switches the language tr() messages are in, the app is re-applied with Event::LiveEdit after the current event
+    pub fn set_locale(&mut self, locale: &str) {
+        if self.live_registry.borrow_mut().localization.set_locale(locale) {
+            self.live_edit_pending = true;
+        }
+    }
+    
+    pub fn locale(&self) -> String {
+        self.live_registry.borrow().localization.locale().to_string()
+    }
+    
+    // formats a message for text set from code, cx.tr("inbox-count", &[(live_id!(count), LiveEval::Int64(3))])
+    pub fn tr(&self, id: &str, args: &[(LiveId, LiveEval)]) -> String {
+        self.live_registry.borrow().localization.format(id, args)
+    }
+    
+    fn live_load_error(file_name: &str, message: String) -> LiveFileError {
+        LiveFileError {
+            origin: live_error_origin!(),
+            file: file_name.to_string(),
+            span: TextSpan::default(),
+            message
+        }
+    }
+    
+    // picks up dep() values that werent there when the app started
+    fn live_load_new_dependencies(&mut self) {
+        let mut new_deps = false;
+        let live_registry = self.live_registry.borrow();
+        for file in &live_registry.live_files {
+            for node in &file.expanded.nodes {
+                if let LiveValue::Dependency(dep) = &node.value {
+                    if !self.dependencies.contains_key(dep.as_str()) {
+                        self.dependencies.insert(dep.as_str().to_string(), CxDependency {
+                            data: None
+                        });
+                        new_deps = true;
+                    }
+                }
+            }
+        }
+        drop(live_registry);
+        #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+        if new_deps {
+            self.native_load_dependencies();
+        }
+        #[cfg(any(target_arch = "wasm32", target_os = "android"))]
+        if new_deps {
+            error!("Dependencies of a runtime loaded live file can't be loaded on this platform");
+        }
+    }
+    
     /*
     fn update_buffer_from_live_value(slots: usize, output: &mut [f32], offset: usize, v: &LiveValue) {
         match slots {
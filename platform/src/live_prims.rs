@@ -25,7 +25,11 @@ macro_rules!live_primitive {
         }
         impl LiveRead for $ ty {
             fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>){
-                out.push(LiveNode::from_id_value(id, self.to_live_value()));
+                match self.to_live_value(){
+                    // types that only point into the live documents have nothing to read back
+                    LiveValue::None => (),
+                    value => out.push(LiveNode::from_id_value(id, value))
+                }
             } 
         }
         impl LiveApply for $ ty {
@@ -971,7 +975,10 @@ live_primitive!(
             }
         }
     },
-    fn to_live_value(&self) -> LiveValue { panic!() }
+    fn to_live_value(&self) -> LiveValue {
+        // dependencies are resources of the live documents, not state
+        LiveValue::None
+    }
 );
 
 
@@ -985,7 +992,8 @@ live_primitive!(
         nodes.skip_node(index)
     },
     fn to_live_value(&self) -> LiveValue {
-        panic!()
+        // pointers into the live documents aren't state either
+        LiveValue::None
     }
 );
 
@@ -0,0 +1,24 @@
+use {
+    std::{
+        fs,
+        io,
+    },
+    crate::{
+        makepad_live_compiler::*,
+    }
+};
+
+// Keeps LiveRead state across application restarts. Save what live_read() returns, and on the
+// next start load the file and apply_over it, after the objects are created from their live_design
+
+pub fn save_live_state(file_name: &str, nodes: &[LiveNode]) -> io::Result<()> {
+    let data = nodes.to_cbor(0).map_err( | err | io::Error::new(io::ErrorKind::InvalidData, err)) ?;
+    fs::write(file_name, data)
+}
+
+pub fn load_live_state(file_name: &str) -> io::Result<Vec<LiveNode>> {
+    let data = fs::read(file_name) ?;
+    let mut nodes = Vec::new();
+    nodes.from_cbor(&data).map_err( | err | io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", err))) ?;
+    Ok(nodes)
+}
@@ -136,6 +136,8 @@ pub trait LiveApply {
 
 pub trait LiveRead{
     fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>);
+    // writes only the fields of a struct, without the surrounding object. used for walk, layout and deref
+    fn live_read_fields(&self, _out:&mut Vec<LiveNode>){}
     fn live_read(&self)->Vec<LiveNode>{
         let mut out = Vec::new();
         self.live_read_to(LiveId(0),&mut out);
@@ -144,6 +146,30 @@ pub trait LiveRead{
     
 }
 
+impl<T> LiveRead for Option<T> where T: LiveRead {
+    fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>){
+        // a None isn't written, applying it back leaves the field as it is
+        if let Some(v) = self{
+            v.live_read_to(id, out);
+        }
+    }
+    fn live_read_fields(&self, out:&mut Vec<LiveNode>){
+        if let Some(v) = self{
+            v.live_read_fields(out);
+        }
+    }
+}
+
+impl<T> LiveRead for Vec<T> where T: LiveRead {
+    fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>){
+        out.open_array(id);
+        for (i, item) in self.iter().enumerate(){
+            item.live_read_to(LiveId(i as u64), out);
+        }
+        out.close();
+    }
+}
+
 impl<T, const N:usize> LiveRead for [T;N]  where T: LiveRead {
     fn live_read_to(&self, id:LiveId, out:&mut Vec<LiveNode>){
         out.open_array(id);
@@ -551,7 +551,10 @@ pub struct MTLClearColor {
 #[allow(non_camel_case_types)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum MTLPixelFormat {
+    R8Unorm = 10,
+    R32Float = 55,
     RGBA8Unorm = 70,
+    RGBA16Float = 115,
     BGRA8Unorm = 80,
     Depth32Float = 252,
     Stencil8 = 253,
@@ -233,6 +233,7 @@ impl CocoaWindow {
             xr_is_presenting: false,
             is_topmost: false,
             is_fullscreen: self.is_fullscreen,
+            is_maximized: false,
             can_fullscreen: false,
             inner_size: self.get_inner_size(),
             outer_size: self.get_outer_size(),
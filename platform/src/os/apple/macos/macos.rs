@@ -44,6 +44,7 @@ const KEEP_ALIVE_COUNT: usize = 5;
 impl Cx {
     
     pub fn event_loop(cx:Rc<RefCell<Cx>>) {
+        cx.borrow().exit_if_live_check_unsupported("macos");
         for arg in std::env::args() {
             if arg == "--metal-xpc" {
                 return start_xpc_service();
@@ -11,6 +11,8 @@ use {
         makepad_shader_compiler::{
             generate_metal,
             generate_metal::MetalGeneratedShader,
+            DrawShaderFlags,
+            BlendFactor,
         },
         makepad_math::*,
         makepad_live_id::*,
@@ -31,8 +33,12 @@ use {
         pass::{PassClearColor, PassClearDepth, PassId},
         window::WindowId,
         texture::{
+            CxTexture,
             TextureFormat,
             TextureDesc,
+            TextureDimension,
+            TextureId,
+            TexturePixels,
         },
     },
     std::sync::{
@@ -116,6 +122,10 @@ impl Cx {
                 }
                 let render_pipeline_state = shp.render_pipeline_state.as_id();
                 unsafe {let () = msg_send![encoder, setRenderPipelineState: render_pipeline_state];}
+                if self.passes[pass_id].os.mtl_depth_state.is_some() {
+                    let depth_state = shp.depth_stencil_state.as_id();
+                    unsafe {let () = msg_send![encoder, setDepthStencilState: depth_state];}
+                }
                 
                 let geometry_id = if let Some(geometry_id) = draw_call.geometry_id {geometry_id}
                 else {
@@ -214,11 +224,7 @@ impl Cx {
                     }
                     else if cxtexture.update_image {
                         cxtexture.update_image = false;
-                        cxtexture.os.update_normal_texture(
-                            metal_cx,
-                            &cxtexture.desc,
-                            &cxtexture.image_u32
-                        );
+                        cxtexture.update_normal_texture(metal_cx);
                     }
                     
                     if let Some(inner) = cxtexture.os.inner.as_ref() {
@@ -437,6 +443,13 @@ impl Cx {
         
         let () = unsafe {msg_send![encoder, endEncoding]};
         
+        let readbacks = if mode.is_drawable().is_none() {
+            self.encode_pass_readbacks(pass_id, metal_cx, command_buffer)
+        }
+        else {
+            Vec::new()
+        };
+        
         match mode {
             DrawPassMode::Texture => {
                 self.commit_command_buffer(None, command_buffer, gpu_read_guards);
@@ -454,9 +467,59 @@ impl Cx {
                 let () = unsafe {msg_send![drawable, present]};
             }
         }
+        if readbacks.len() != 0 {
+            // reading back is rare enough (screenshots, exports) to just wait for the gpu here
+            let () = unsafe {msg_send![command_buffer, waitUntilCompleted]};
+            for (texture_id, width, height, buffer) in readbacks {
+                let contents: *const u32 = unsafe {msg_send![buffer.as_id(), contents]};
+                // BGRA8Unorm is already 0xAARRGGBB as little endian u32s
+                let data = unsafe {std::slice::from_raw_parts(contents, width * height)}.to_vec();
+                self.texture_pixels_ready(texture_id, Ok(TexturePixels {width, height, data}));
+            }
+        }
         let () = unsafe {msg_send![pool, release]};
     }
     
+    // blits the color textures that have read_pixels requests into cpu readable buffers
+    fn encode_pass_readbacks(&mut self, pass_id: PassId, metal_cx: &MetalCx, command_buffer: ObjcId) -> Vec<(TextureId, usize, usize, RcObjcId)> {
+        let mut readbacks = Vec::new();
+        for color_texture in &self.passes[pass_id].color_textures {
+            let cxtexture = &self.textures[color_texture.texture_id];
+            if cxtexture.read_pixels.len() == 0 {
+                continue
+            }
+            let inner = if let Some(inner) = &cxtexture.os.inner {inner} else {
+                continue
+            };
+            let (width, height) = (inner.width, inner.height);
+            let buffer = RcObjcId::from_owned(NonNull::new(unsafe {
+                msg_send![
+                    metal_cx.device,
+                    newBufferWithLength: width * height * 4
+                    options: MTLResourceOptions::StorageModeShared
+                ]
+            }).unwrap());
+            unsafe {
+                let encoder: ObjcId = msg_send![command_buffer, blitCommandEncoder];
+                let () = msg_send![
+                    encoder,
+                    copyFromTexture: inner.texture.as_id()
+                    sourceSlice: 0u64
+                    sourceLevel: 0u64
+                    sourceOrigin: MTLOrigin {x: 0, y: 0, z: 0}
+                    sourceSize: MTLSize {width, height, depth: 1}
+                    toBuffer: buffer.as_id()
+                    destinationOffset: 0u64
+                    destinationBytesPerRow: width * 4
+                    destinationBytesPerImage: width * height * 4
+                ];
+                let () = msg_send![encoder, endEncoding];
+            }
+            readbacks.push((color_texture.texture_id, width as usize, height as usize, buffer));
+        }
+        readbacks
+    }
+    
     fn commit_command_buffer(&mut self, stdin_frame: Option<u32>, command_buffer: ObjcId, gpu_read_guards: Vec<MetalRwLockGpuReadGuard>) {
         let gpu_read_guards = Mutex::new(Some(gpu_read_guards));
         let () = unsafe {msg_send![
@@ -488,13 +551,13 @@ impl Cx {
                 }
                 // lets see if we have the shader already
                 for (index, ds) in self.draw_shaders.os_shaders.iter().enumerate() {
-                    if ds.mtlsl == gen.mtlsl {
+                    if ds.mtlsl == gen.mtlsl && ds.flags.same_pipeline_state(&cx_shader.mapping.flags) {
                         cx_shader.os_shader_id = Some(index);
                         break;
                     }
                 }
                 if cx_shader.os_shader_id.is_none() {
-                    if let Some(shp) = CxOsDrawShader::new(metal_cx, gen) {
+                    if let Some(shp) = CxOsDrawShader::new(metal_cx, gen, cx_shader.mapping.flags) {
                         cx_shader.os_shader_id = Some(self.draw_shaders.os_shaders.len());
                         self.draw_shaders.os_shaders.push(shp);
                     }
@@ -645,6 +708,8 @@ impl MetalCx {
 pub struct CxOsDrawShader {
     _library: RcObjcId,
     render_pipeline_state: RcObjcId,
+    depth_stencil_state: RcObjcId,
+    flags: DrawShaderFlags,
     draw_uniform_buffer_id: Option<u64>,
     pass_uniform_buffer_id: Option<u64>,
     view_uniform_buffer_id: Option<u64>,
@@ -656,6 +721,7 @@ impl CxOsDrawShader {
     pub (crate) fn new(
         metal_cx: &MetalCx,
         shader: MetalGeneratedShader,
+        flags: DrawShaderFlags,
     ) -> Option<Self> {
         let options = RcObjcId::from_owned(unsafe {msg_send![class!(MTLCompileOptions), new]});
         unsafe {
@@ -704,13 +770,21 @@ impl CxOsDrawShader {
             let color_attachments: ObjcId = msg_send![descriptor.as_id(), colorAttachments];
             let color_attachment: ObjcId = msg_send![color_attachments, objectAtIndexedSubscript: 0];
             let () = msg_send![color_attachment, setPixelFormat: MTLPixelFormat::BGRA8Unorm];
+            let mtl_factor = | factor | match factor {
+                BlendFactor::Zero => MTLBlendFactor::Zero,
+                BlendFactor::One => MTLBlendFactor::One,
+                BlendFactor::OneMinusSrcColor => MTLBlendFactor::OneMinusSourceColor,
+                BlendFactor::OneMinusSrcAlpha => MTLBlendFactor::OneMinusSourceAlpha,
+                BlendFactor::DstColor => MTLBlendFactor::DestinationColor,
+            };
+            let [src_rgb, dst_rgb, src_alpha, dst_alpha] = flags.blend_mode.blend_factors();
             let () = msg_send![color_attachment, setBlendingEnabled: YES];
             let () = msg_send![color_attachment, setRgbBlendOperation: MTLBlendOperation::Add];
             let () = msg_send![color_attachment, setAlphaBlendOperation: MTLBlendOperation::Add];
-            let () = msg_send![color_attachment, setSourceRGBBlendFactor: MTLBlendFactor::One];
-            let () = msg_send![color_attachment, setSourceAlphaBlendFactor: MTLBlendFactor::One];
-            let () = msg_send![color_attachment, setDestinationRGBBlendFactor: MTLBlendFactor::OneMinusSourceAlpha];
-            let () = msg_send![color_attachment, setDestinationAlphaBlendFactor: MTLBlendFactor::OneMinusSourceAlpha];
+            let () = msg_send![color_attachment, setSourceRGBBlendFactor: mtl_factor(src_rgb)];
+            let () = msg_send![color_attachment, setSourceAlphaBlendFactor: mtl_factor(src_alpha)];
+            let () = msg_send![color_attachment, setDestinationRGBBlendFactor: mtl_factor(dst_rgb)];
+            let () = msg_send![color_attachment, setDestinationAlphaBlendFactor: mtl_factor(dst_alpha)];
             
             let () = msg_send![descriptor.as_id(), setDepthAttachmentPixelFormat: MTLPixelFormat::Depth32Float_Stencil8];
             
@@ -722,6 +796,14 @@ impl CxOsDrawShader {
             ]
         }).unwrap());
         
+        let depth_stencil_state = RcObjcId::from_owned(NonNull::new(unsafe {
+            let desc: ObjcId = msg_send![class!(MTLDepthStencilDescriptor), new];
+            let compare = if flags.depth_test {MTLCompareFunction::LessEqual} else {MTLCompareFunction::Always};
+            let () = msg_send![desc, setDepthCompareFunction: compare];
+            let () = msg_send![desc, setDepthWriteEnabled: flags.depth_write];
+            msg_send![metal_cx.device, newDepthStencilStateWithDescriptor: desc]
+        }).unwrap());
+        
         let mut draw_uniform_buffer_id = None;
         let mut pass_uniform_buffer_id = None;
         let mut view_uniform_buffer_id = None;
@@ -742,6 +824,8 @@ impl CxOsDrawShader {
         return Some(Self {
             _library: library,
             render_pipeline_state,
+            depth_stencil_state,
+            flags,
             draw_uniform_buffer_id,
             pass_uniform_buffer_id,
             view_uniform_buffer_id,
@@ -835,38 +919,24 @@ pub struct CxOsTexture {
     inner: Option<CxOsTextureInner>
 }
 
-impl CxOsTexture {
-    
-    
-    fn update_normal_texture(
-        &mut self,
-        metal_cx: &MetalCx,
-        desc: &TextureDesc,
-        data: &[u32],
-    ) {
-        // we need a width/height for this one.
-        if desc.width.is_none() || desc.height.is_none() {
-            log!("Normal texture width/height is undefined, cannot allocate it");
+impl CxTexture {
+    fn update_normal_texture(&mut self, metal_cx: &MetalCx) {
+        if !self.image_is_complete() {
             return
         }
-        
+        let desc = self.desc;
         let width = desc.width.unwrap() as u64;
         let height = desc.height.unwrap() as u64;
+        let depth = if desc.dimension == TextureDimension::D3 {desc.depth.unwrap_or(1) as u64} else {1};
+        let (pixel_format, texel_size) = match desc.format {
+            TextureFormat::ImageR8 => (MTLPixelFormat::R8Unorm, 1),
+            TextureFormat::ImageRf32 => (MTLPixelFormat::R32Float, 4),
+            TextureFormat::ImageRGBAf16 => (MTLPixelFormat::RGBA16Float, 8),
+            _ => (MTLPixelFormat::BGRA8Unorm, 4),
+        };
         
-        match desc.format {
-            TextureFormat::ImageBGRA | TextureFormat::Default => {
-                if (width * height)as usize != data.len() {
-                    if data.len() != 0 {
-                        error!("Texture buffer not correct size {}*{} != {}", width, height, data.len());
-                    }
-                    return
-                }
-            }
-            _ => panic!(),
-        }
-        
-        let need_alloc = if let Some(inner) = &self.inner {
-            CxOsTextureInner::need_alloc(width, height, desc, inner)
+        let need_alloc = if let Some(inner) = &self.os.inner {
+            CxOsTextureInner::need_alloc(width, height, &desc, inner)
         }
         else {
             true
@@ -878,67 +948,74 @@ impl CxOsTexture {
             }).unwrap());
             
             let texture = RcObjcId::from_owned(NonNull::new(unsafe {
-                let _: () = msg_send![descriptor.as_id(), setTextureType: MTLTextureType::D2];
-                let _: () = msg_send![descriptor.as_id(), setWidth: width as u64];
-                let _: () = msg_send![descriptor.as_id(), setHeight: height as u64];
-                let _: () = msg_send![descriptor.as_id(), setDepth: 1u64];
+                let texture_type = match desc.dimension {
+                    TextureDimension::D2 => MTLTextureType::D2,
+                    TextureDimension::D3 => MTLTextureType::D3,
+                    TextureDimension::Cube => MTLTextureType::Cube,
+                };
+                let mip_levels = if desc.mipmaps {64 - width.max(height).max(depth).leading_zeros() as u64} else {1};
+                let _: () = msg_send![descriptor.as_id(), setTextureType: texture_type];
+                let _: () = msg_send![descriptor.as_id(), setWidth: width];
+                let _: () = msg_send![descriptor.as_id(), setHeight: height];
+                let _: () = msg_send![descriptor.as_id(), setDepth: depth];
+                let _: () = msg_send![descriptor.as_id(), setMipmapLevelCount: mip_levels];
                 let _: () = msg_send![descriptor.as_id(), setStorageMode: MTLStorageMode::Managed];
                 let _: () = msg_send![descriptor.as_id(), setUsage: MTLTextureUsage::ShaderRead];
-                match desc.format {
-                    TextureFormat::ImageBGRA | TextureFormat::Default => {
-                        let _: () = msg_send![
-                            descriptor.as_id(),
-                            setPixelFormat: MTLPixelFormat::BGRA8Unorm
-                        ];
-                        msg_send![metal_cx.device, newTextureWithDescriptor: descriptor]
-                    }
-                    /*
-                    TextureFormat::SharedBGRA(shared_id) => {
-                        log!("GOT HERE");
-                        let _: () = msg_send![descriptor.as_id(), setStorageMode: MTLStorageMode::Private];
-                        let _: () = msg_send![descriptor.as_id(), setUsage: MTLTextureUsage::RenderTarget];
-                        let texture: ObjcId = msg_send![metal_cx.device, newSharedTextureWithDescriptor: descriptor];
-                        // lets send this to the other side.
-                        let shared: ObjcId = msg_send![texture, makeSharedTextureHandle];
-                        // lets send it over
-                       
-                        store_xpc_service_texture(shared_id, shared);
-                        
-                        texture
-                    }*/
-                    _ => panic!(),
-                }
+                let _: () = msg_send![descriptor.as_id(), setPixelFormat: pixel_format];
+                msg_send![metal_cx.device, newTextureWithDescriptor: descriptor]
             }).unwrap());
             
-            self.inner = Some(CxOsTextureInner {
+            self.os.inner = Some(CxOsTextureInner {
                 is_initial: true,
                 width,
                 height,
+                depth: desc.depth.unwrap_or(1) as u64,
+                dimension: desc.dimension,
+                mipmaps: desc.mipmaps,
                 format: desc.format,
                 texture,
             });
-            
-            if desc.format.is_shared() {
-                return
-            }
         }
         
-        let inner = self.inner.as_ref().unwrap();
-        
-        // ok now update the texture
-        let region = MTLRegion {
-            origin: MTLOrigin {x: 0, y: 0, z: 0},
-            size: MTLSize {width: width as u64, height: height as u64, depth: 1}
-        };
+        let inner = self.os.inner.as_ref().unwrap();
+        let data = self.image_bytes();
+        let bytes_per_row = width * texel_size;
+        let bytes_per_image = bytes_per_row * height;
+        // a 3D texture goes up as a single region, cube faces one slice at a time
+        let slices = if desc.dimension == TextureDimension::Cube {6} else {1};
+        for slice in 0..slices {
+            let region = MTLRegion {
+                origin: MTLOrigin {x: 0, y: 0, z: 0},
+                size: MTLSize {width, height, depth}
+            };
+            let offset = (slice * bytes_per_image) as usize;
+            let () = unsafe {msg_send![
+                inner.texture.as_id(),
+                replaceRegion: region
+                mipmapLevel: 0u64
+                slice: slice
+                withBytes: data[offset..].as_ptr() as *const std::ffi::c_void
+                bytesPerRow: bytes_per_row
+                bytesPerImage: if desc.dimension == TextureDimension::D3 {bytes_per_image} else {0}
+            ]};
+        }
         
-        let () = unsafe {msg_send![
-            inner.texture.as_id(),
-            replaceRegion: region
-            mipmapLevel: 0
-            withBytes: data.as_ptr() as *const std::ffi::c_void
-            bytesPerRow: (width * std::mem::size_of::<u32>() as u64)
-        ]};
+        if desc.mipmaps {
+            // the mips have to be ready before the render command buffer samples them,
+            // it's committed later on the same queue so a blit ahead of it is enough
+            unsafe {
+                let command_buffer: ObjcId = msg_send![metal_cx.command_queue, commandBuffer];
+                let encoder: ObjcId = msg_send![command_buffer, blitCommandEncoder];
+                let () = msg_send![encoder, generateMipmapsForTexture: inner.texture.as_id()];
+                let () = msg_send![encoder, endEncoding];
+                let () = msg_send![command_buffer, commit];
+            }
+        }
     }
+}
+
+impl CxOsTexture {
+    
     
     #[cfg(target_os = "macos")]
     fn update_shared_texture(
@@ -992,6 +1069,9 @@ impl CxOsTexture {
                 is_initial: true,
                 width,
                 height,
+                depth: 1,
+                dimension: TextureDimension::D2,
+                mipmaps: false,
                 format: desc.format,
                 texture,
             });
@@ -1013,6 +1093,9 @@ impl CxOsTexture {
             is_initial: true,
             width,
             height,
+            depth: 1,
+            dimension: TextureDimension::D2,
+            mipmaps: false,
             format: TextureFormat::SharedBGRA(0),
             texture,
         });
@@ -1079,6 +1162,9 @@ impl CxOsTexture {
             is_initial: true,
             width,
             height,
+            depth: 1,
+            dimension: TextureDimension::D2,
+            mipmaps: false,
             format: desc.format,
             texture,
         });
@@ -1089,6 +1175,9 @@ struct CxOsTextureInner {
     is_initial: bool,
     width: u64,
     height: u64,
+    depth: u64,
+    dimension: TextureDimension,
+    mipmaps: bool,
     format: TextureFormat,
     texture: RcObjcId
 }
@@ -1104,6 +1193,9 @@ impl CxOsTextureInner {
         if inner.format != desc.format {
             return true;
         }
+        if inner.depth != desc.depth.unwrap_or(1) as u64 || inner.dimension != desc.dimension || inner.mipmaps != desc.mipmaps {
+            return true;
+        }
         false
     }
     
@@ -20,6 +20,9 @@ impl Cx {
     
     pub fn native_load_dependencies(&mut self){
         for (path,dep) in &mut self.dependencies{
+            if dep.data.is_some(){ // already loaded
+                continue
+            }
             if let Ok(mut file_handle) = File::open(path) {
                 let mut buffer = Vec::<u8>::new();
                 if file_handle.read_to_end(&mut buffer).is_ok() {
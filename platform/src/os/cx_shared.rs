@@ -8,6 +8,8 @@ use {
             PassId,
             CxPassParent
         },
+        makepad_live_id::LiveId,
+        texture::{TextureId, TexturePixels, TexturePixelsEvent},
         event::{
             DrawEvent,
             TriggerEvent,
@@ -98,6 +100,19 @@ impl Cx {
         
     }
     
+    pub (crate) fn push_texture_pixels(&mut self, request_id: LiveId, pixels: Result<TexturePixels, String>) {
+        self.texture_pixels.push(TexturePixelsEvent {request_id, pixels});
+        // the pixels go out after the next event, make sure there is one
+        self.new_next_frame();
+    }
+    
+    // backends call this with what they read back from a render target that had read_pixels requests
+    pub (crate) fn texture_pixels_ready(&mut self, texture_id: TextureId, pixels: Result<TexturePixels, String>) {
+        for request_id in std::mem::take(&mut self.textures[texture_id].read_pixels) {
+            self.push_texture_pixels(request_id, pixels.clone());
+        }
+    }
+    
     pub (crate) fn need_redrawing(&self) -> bool {
         self.new_draw_event.will_redraw()
     }
@@ -146,6 +161,16 @@ impl Cx {
         self.inner_call_event_handler(event);
         self.inner_key_focus_change();
         self.handle_triggers();
+        if self.texture_pixels.len() != 0 {
+            let texture_pixels = std::mem::take(&mut self.texture_pixels);
+            self.inner_call_event_handler(&Event::TexturePixels(texture_pixels));
+        }
+        // the live documents were changed from inside the app, re-apply once the event is done
+        if self.live_edit_pending {
+            self.live_edit_pending = false;
+            self.inner_call_event_handler(&Event::LiveEdit);
+            self.redraw_all();
+        }
     }
 
     // helpers
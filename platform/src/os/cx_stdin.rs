@@ -129,7 +129,13 @@ pub enum HostToStdin{
         contents:String
     },
     
-    Dx11SharedHandle(u64)
+    Dx11SharedHandle(u64),
+    // a file in /dev/shm the child copies its frames into, see linux/stdin_shared_memory.rs
+    SharedMemoryFramebuffer{
+        path: String,
+        width: usize,
+        height: usize,
+    }
 }
 
 #[derive(Clone, Debug, SerBin, DeBin, SerJson, DeJson)]
@@ -66,6 +66,7 @@ impl Cx {
         self.os.dpi_factor = params.density;
         self.os_type = OsType::Android(params);
         self.gpu_info.performance = GpuPerformance::Tier1;
+        self.exit_if_live_check_unsupported("android");
         self.call_event_handler(&Event::Construct);
     } 
     
@@ -147,6 +148,7 @@ impl Cx {
             can_fullscreen: false,
             xr_is_presenting: false,
             is_fullscreen: true,
+            is_maximized: false,
             is_topmost: true,
             position: dvec2(0.0, 0.0),
             inner_size: size,
@@ -410,6 +412,7 @@ impl Cx {
             PassClearDepth::ClearWith(depth) => depth
         };
         
+        Self::set_default_depth_and_blend_mode();
         if !self.passes[pass_id].dont_clear {
             unsafe {
                 //gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
@@ -418,7 +421,6 @@ impl Cx {
                 gl_sys::Clear(gl_sys::COLOR_BUFFER_BIT | gl_sys::DEPTH_BUFFER_BIT);
             }
         }
-        Self::set_default_depth_and_blend_mode();
          
         let mut zbias = 0.0;
         let zbias_step = self.passes[pass_id].zbias_step;
@@ -490,6 +492,7 @@ impl Cx {
                         can_fullscreen: false,
                         xr_is_presenting: false,
                         is_fullscreen: true,
+                        is_maximized: false,
                         is_topmost: true,
                         position: dvec2(0.0, 0.0),
                         inner_size: size,
@@ -8,9 +8,9 @@ use {
     self::super::{
         drm_sys::*,
         gbm_sys::*,
-        egl_sys::*,
     },
     self::super::super::{
+        egl_sys::*,
         gl_sys,
         libc_sys,
     },
@@ -365,13 +365,19 @@ impl Egl {
         }
         let egl_config = egl_config.unwrap();
         
-        let ctx_attribs = [
-            EGL_CONTEXT_CLIENT_VERSION,
-            2,
-            EGL_NONE
-        ];
-        
-        let egl_context = eglCreateContext(egl_display, egl_config, EGL_NO_CONTEXT, ctx_attribs.as_ptr());
+        // ask for ES 3 like the other linux backends, fall back to ES 2
+        let mut egl_context = std::ptr::null_mut();
+        for version in [3, 2] {
+            let ctx_attribs = [
+                EGL_CONTEXT_CLIENT_VERSION,
+                version,
+                EGL_NONE
+            ];
+            egl_context = eglCreateContext(egl_display, egl_config, EGL_NO_CONTEXT, ctx_attribs.as_ptr());
+            if egl_context != std::ptr::null_mut() {
+                break;
+            }
+        }
         if egl_context == std::ptr::null_mut() {
             println!("eglCreateContext failed");
             return None;
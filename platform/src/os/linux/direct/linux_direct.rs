@@ -1,6 +1,7 @@
 use {
     std::rc::Rc,
     std::cell::RefCell,
+    std::collections::HashMap,
     self::super::{
         direct_event::*,
         egl_drm::{Egl, Drm},
@@ -9,7 +10,10 @@ use {
     self::super::super::{
         gl_sys,
         select_timer::SelectTimers,
-        linux_media::CxLinuxMedia
+        linux_media::CxLinuxMedia,
+        linux_http::LinuxHttpRequest,
+        linux_websocket::LinuxWebSocket,
+        headless::HeadlessCx,
     },
     crate::{
         cx_api::{CxOsOp, CxOsApi},
@@ -20,6 +24,8 @@ use {
             TimerEvent,
             Event,
             WindowGeom,  
+            NetworkResponse,
+            NetworkResponseChannel,
         },
         window::CxWindowPool,
         pass::CxPassParent,
@@ -69,6 +75,12 @@ impl DirectApp {
 
 impl Cx {
     pub fn event_loop(cx:Rc<RefCell<Cx>>) {
+        if std::env::args().any( | v | v == "--live-check") {
+            if let Ok(cx) = Rc::try_unwrap(cx) {
+                std::process::exit(HeadlessCx::live_check(cx.into_inner()));
+            }
+            panic!("--live-check needs the only reference to cx");
+        }
         
         let mut cx = cx.borrow_mut();
         
@@ -176,6 +188,7 @@ impl Cx {
                         self.handle_media_signals();
                         self.call_event_handler(&Event::Signal);
                     }
+                    self.handle_networking_events();
                 }
                 else {
                     self.call_event_handler(&Event::Timer(e))
@@ -189,6 +202,20 @@ impl Cx {
         }
     }
     
+    pub(crate) fn handle_networking_events(&mut self) {
+        let mut out = Vec::new();
+        while let Ok(event) = self.os.network_response.receiver.try_recv() {
+            // a closed or failed socket is done, drop it so its threads and channel go away
+            if let NetworkResponse::WebSocketClose | NetworkResponse::WebSocketError(_) = event.response {
+                self.os.web_sockets.remove(&event.request_id);
+            }
+            out.push(event);
+        }
+        if out.len()>0 {
+            self.call_event_handler(&Event::NetworkResponses(out))
+        }
+    }
+    
     pub fn draw_pass_to_fullscreen(
         &mut self,
         pass_id: PassId,
@@ -220,6 +247,7 @@ impl Cx {
             PassClearDepth::ClearWith(depth) => depth
         };
         
+        Self::set_default_depth_and_blend_mode();
         if !self.passes[pass_id].dont_clear {
             unsafe {
                 gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
@@ -228,7 +256,6 @@ impl Cx {
                 gl_sys::Clear(gl_sys::COLOR_BUFFER_BIT | gl_sys::DEPTH_BUFFER_BIT);
             }
         }
-        Self::set_default_depth_and_blend_mode();
         
         let mut zbias = 0.0;
         let zbias_step = self.passes[pass_id].zbias_step;
@@ -276,6 +303,7 @@ impl Cx {
                         can_fullscreen: false,
                         xr_is_presenting: false,
                         is_fullscreen: true,
+                        is_maximized: false,
                         is_topmost: true,
                         position: dvec2(0.0, 0.0),
                         inner_size: size,
@@ -292,6 +320,23 @@ impl Cx {
                 CxOsOp::StopTimer(timer_id) => {
                     direct_app.timers.stop_timer(timer_id);
                 },
+                CxOsOp::HttpRequest {request_id, request} => {
+                    LinuxHttpRequest::new(request_id, request).start(self.os.network_response.sender.clone());
+                },
+                CxOsOp::WebSocketOpen {request_id, request} => {
+                    let web_socket = LinuxWebSocket::open(request_id, request, self.os.network_response.sender.clone());
+                    self.os.web_sockets.insert(request_id, web_socket);
+                }
+                CxOsOp::WebSocketSendBinary {request_id, data} => {
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id) {
+                        web_socket.send_binary(data);
+                    }
+                }
+                CxOsOp::WebSocketSendString {request_id, data} => {
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id) {
+                        web_socket.send_string(data);
+                    }
+                }
                 _ => ()
             }
         }
@@ -314,5 +359,7 @@ impl CxOsApi for Cx {
 #[derive(Default)]
 pub struct CxOs {
     pub (crate) media: CxLinuxMedia,
+    pub (crate) network_response: NetworkResponseChannel,
+    pub (crate) web_sockets: HashMap<LiveId, LinuxWebSocket>,
 }
 
@@ -2,6 +2,5 @@ pub mod linux_direct;
 pub mod direct_event;
 pub mod drm_sys;
 pub mod gbm_sys; 
-pub mod egl_sys;
 pub mod egl_drm;
 pub mod raw_input;
@@ -5,6 +5,7 @@ use std::os::raw::{
 };
 
 pub const EGL_PLATFORM_GBM_KHR: u32 = 12759;
+pub const EGL_PLATFORM_WAYLAND_KHR: u32 = 12760;
 pub const EGL_OPENGL_ES_API: u32 = 12448;
 pub const EGL_SURFACE_TYPE: u32 = 12339;
 pub const EGL_WINDOW_BIT: u32 = 4;
@@ -19,6 +20,7 @@ pub const EGL_NONE: u32 = 12344;
 pub const EGL_NATIVE_VISUAL_ID: u32 = 12334;
 pub const EGL_CONTEXT_CLIENT_VERSION: u32 = 12440;
 pub const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
+pub const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
   
 pub type EGLint = i32;
 pub type EGLenum = c_uint;
@@ -139,4 +141,6 @@ extern "C" {
         ctx: EGLContext,
     ) -> EGLBoolean;
     pub fn eglSwapBuffers(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+    pub fn eglSwapInterval(dpy: EGLDisplay, interval: EGLint) -> EGLBoolean;
+    pub fn eglDestroySurface(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
 }
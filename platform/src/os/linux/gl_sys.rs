@@ -21,6 +21,8 @@ pub mod types {
 pub use types::*; 
 
 pub const TRUE: types::GLboolean = 1;
+pub const FALSE: types::GLboolean = 0;
+pub const NO_ERROR: types::GLenum = 0;
 pub const ARRAY_BUFFER: types::GLenum = 0x8892;
 pub const FLOAT: types::GLenum = 0x1406;
 pub const ELEMENT_ARRAY_BUFFER: types::GLenum = 0x8893;
@@ -33,6 +35,10 @@ pub const LEQUAL: types::GLenum = 0x0203;
 pub const FUNC_ADD: types::GLenum = 0x8006;
 pub const ONE: types::GLenum = 1;
 pub const ONE_MINUS_SRC_ALPHA: types::GLenum = 0x0303;
+pub const ZERO: types::GLenum = 0;
+pub const ONE_MINUS_SRC_COLOR: types::GLenum = 0x0301;
+pub const DST_COLOR: types::GLenum = 0x0306;
+pub const ALWAYS: types::GLenum = 0x0207;
 pub const BLEND: types::GLenum = 0x0BE2;
 pub const FRAMEBUFFER: types::GLenum = 0x8D40;
 pub const COLOR_BUFFER_BIT: types::GLenum = 0x00004000;
@@ -54,12 +60,32 @@ pub const TEXTURE_MAX_LEVEL: types::GLenum = 0x813D;
 pub const TEXTURE_MAG_FILTER: types::GLenum = 0x2800;
 pub const RGBA: types::GLenum = 0x1908;
 pub const UNSIGNED_BYTE: types::GLenum = 0x1401;
+pub const PACK_ALIGNMENT: types::GLenum = 0x0D05;
 pub const DEPTH_COMPONENT32F: types::GLenum = 0x8CAC;
 pub const STATIC_DRAW: types::GLenum = 0x88E4;
 pub const NEAREST: types::GLenum = 0x2600;
 pub const TEXTURE_WRAP_S: types::GLenum = 0x2802;
 pub const TEXTURE_WRAP_T: types::GLenum = 0x2803;
 pub const CLAMP_TO_EDGE: types::GLenum = 0x812F;
+pub const TEXTURE_3D: types::GLenum = 0x806F;
+pub const TEXTURE_CUBE_MAP: types::GLenum = 0x8513;
+pub const TEXTURE_CUBE_MAP_POSITIVE_X: types::GLenum = 0x8515;
+pub const TEXTURE_WRAP_R: types::GLenum = 0x8072;
+pub const TEXTURE_SWIZZLE_R: types::GLenum = 0x8E42;
+pub const TEXTURE_SWIZZLE_G: types::GLenum = 0x8E43;
+pub const TEXTURE_SWIZZLE_B: types::GLenum = 0x8E44;
+pub const TEXTURE_SWIZZLE_A: types::GLenum = 0x8E45;
+pub const UNPACK_ALIGNMENT: types::GLenum = 0x0CF5;
+pub const RED: types::GLenum = 0x1903;
+pub const GREEN: types::GLenum = 0x1904;
+pub const BLUE: types::GLenum = 0x1905;
+pub const ALPHA: types::GLenum = 0x1906;
+pub const R8: types::GLenum = 0x8229;
+pub const R32F: types::GLenum = 0x822E;
+pub const RGBA16F: types::GLenum = 0x881A;
+pub const HALF_FLOAT: types::GLenum = 0x140B;
+pub const VERSION: types::GLenum = 0x1F02;
+pub const EXTENSIONS: types::GLenum = 0x1F03;
 pub const PROGRAM_BINARY_LENGTH: types::GLenum = 0x8741;
 
 #[inline] pub unsafe fn GenVertexArrays(n: types::GLsizei, arrays: *mut types::GLuint) -> () {mem::transmute::<_, extern "system" fn(types::GLsizei, *mut types::GLuint) -> ()>(storage::GenVertexArrays.f)(n, arrays)}
@@ -111,6 +137,8 @@ pub const PROGRAM_BINARY_LENGTH: types::GLenum = 0x8741;
 #[inline] pub unsafe fn Uniform1i(location: types::GLint, v0: types::GLint) -> () { mem::transmute::<_, extern "system" fn(types::GLint, types::GLint) -> ()>(storage::Uniform1i.f)(location, v0) }
 #[inline] pub unsafe fn GetError() -> types::GLenum { mem::transmute::<_, extern "system" fn() -> types::GLenum>(storage::GetError.f)() }
 #[inline] pub unsafe fn Finish() -> () { mem::transmute::<_, extern "system" fn() -> ()>(storage::Finish.f)() }
+#[inline] pub unsafe fn ReadPixels(x: types::GLint, y: types::GLint, width: types::GLsizei, height: types::GLsizei, format: types::GLenum, type_: types::GLenum, pixels: *mut raw::c_void) -> () { mem::transmute::<_, extern "system" fn(types::GLint, types::GLint, types::GLsizei, types::GLsizei, types::GLenum, types::GLenum, *mut raw::c_void) -> ()>(storage::ReadPixels.f)(x, y, width, height, format, type_, pixels) }
+#[inline] pub unsafe fn PixelStorei(pname: types::GLenum, param: types::GLint) -> () { mem::transmute::<_, extern "system" fn(types::GLenum, types::GLint) -> ()>(storage::PixelStorei.f)(pname, param) }
 #[inline] pub unsafe fn GetProgramBinary(program: types::GLuint, bufSize: types::GLsizei, length: *mut types::GLsizei, binaryFormat: *mut types::GLenum, binary: *mut raw::c_void) -> () { mem::transmute::<_, extern "system" fn(types::GLuint, types::GLsizei, *mut types::GLsizei, *mut types::GLenum, *mut raw::c_void) -> ()>(storage::GetProgramBinary.f)(program, bufSize, length, binaryFormat, binary) }
 #[inline] pub unsafe fn ProgramBinary(program: types::GLuint, binaryFormat: types::GLenum, binary: *const raw::c_void, length: types::GLsizei) -> () { mem::transmute::<_, extern "system" fn(types::GLuint, types::GLenum, *const raw::c_void, types::GLsizei) -> ()>(storage::ProgramBinary.f)(program, binaryFormat, binary, length) }            
 #[inline] pub unsafe fn DeleteRenderbuffers(n: types::GLsizei, renderbuffers: *const types::GLuint) -> () { mem::transmute::<_, extern "system" fn(types::GLsizei, *const types::GLuint) -> ()>(storage::DeleteRenderbuffers.f)(n, renderbuffers) }
@@ -118,6 +146,9 @@ pub const PROGRAM_BINARY_LENGTH: types::GLenum = 0x8741;
 #[inline] pub unsafe fn DeleteFramebuffers(n: types::GLsizei, framebuffers: *const types::GLuint) -> () { mem::transmute::<_, extern "system" fn(types::GLsizei, *const types::GLuint) -> ()>(storage::DeleteFramebuffers.f)(n, framebuffers) }
 #[inline] pub unsafe fn DeleteVertexArrays(n: types::GLsizei, arrays: *const types::GLuint) -> () { mem::transmute::<_, extern "system" fn(types::GLsizei, *const types::GLuint) -> ()>(storage::DeleteVertexArrays.f)(n, arrays) }
 #[inline] pub unsafe fn GenerateMipmap(target: types::GLenum) -> () { mem::transmute::<_, extern "system" fn(types::GLenum) -> ()>( storage::GenerateMipmap.f)(target)}
+#[inline] pub unsafe fn TexImage3D(target: types::GLenum, level: types::GLint, internalformat: types::GLint, width: types::GLsizei, height: types::GLsizei, depth: types::GLsizei, border: types::GLint, format: types::GLenum, type_: types::GLenum, pixels: *const raw::c_void) -> () { mem::transmute::<_, extern "system" fn(types::GLenum, types::GLint, types::GLint, types::GLsizei, types::GLsizei, types::GLsizei, types::GLint, types::GLenum, types::GLenum, *const raw::c_void) -> ()>(storage::TexImage3D.f)(target, level, internalformat, width, height, depth, border, format, type_, pixels) }
+#[inline] pub unsafe fn DepthMask(flag: types::GLboolean) -> () { mem::transmute::<_, extern "system" fn(types::GLboolean) -> ()>(storage::DepthMask.f)(flag) }
+#[inline] pub unsafe fn GetString(name: types::GLenum) -> *const types::GLubyte { mem::transmute::<_, extern "system" fn(types::GLenum) -> *const types::GLubyte>(storage::GetString.f)(name) }
 
 mod storage {
     use super::FnPtr;
@@ -171,6 +202,8 @@ mod storage {
     pub static mut Uniform1i: FnPtr = FnPtr::default();
     pub static mut GetError: FnPtr = FnPtr::default();
     pub static mut Finish: FnPtr = FnPtr::default();
+    pub static mut ReadPixels: FnPtr = FnPtr::default();
+    pub static mut PixelStorei: FnPtr = FnPtr::default();
     pub static mut GetProgramBinary: FnPtr = FnPtr::default();
     pub static mut ProgramBinary: FnPtr = FnPtr::default();
     pub static mut DeleteRenderbuffers: FnPtr = FnPtr::default();
@@ -178,6 +211,9 @@ mod storage {
     pub static mut DeleteFramebuffers: FnPtr = FnPtr::default();
     pub static mut DeleteVertexArrays: FnPtr = FnPtr::default();
     pub static mut GenerateMipmap: FnPtr = FnPtr::default();
+    pub static mut DepthMask: FnPtr = FnPtr::default();
+    pub static mut TexImage3D: FnPtr = FnPtr::default();
+    pub static mut GetString: FnPtr = FnPtr::default();
 }
 
 pub unsafe fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const raw::c_void {
@@ -230,6 +266,8 @@ pub unsafe fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const
     storage::Uniform1i = FnPtr::new(metaloadfn(&mut loadfn, "glUniform1i", &["glUniform1iARB"]));
     storage::GetError = FnPtr::new(metaloadfn(&mut loadfn, "glGetError", &[]));
     storage::Finish = FnPtr::new(metaloadfn(&mut loadfn, "glFinish", &[]));
+    storage::ReadPixels = FnPtr::new(metaloadfn(&mut loadfn, "glReadPixels", &[]));
+    storage::PixelStorei = FnPtr::new(metaloadfn(&mut loadfn, "glPixelStorei", &[]));
     storage::ClearDepthf = FnPtr::new(metaloadfn(&mut loadfn, "glClearDepthf", &["glClearDepthfOES"]));
     storage::GetProgramBinary = FnPtr::new(metaloadfn(&mut loadfn, "glGetProgramBinary", &["glGetProgramBinaryOES"]));
     storage::ProgramBinary = FnPtr::new(metaloadfn(&mut loadfn, "glProgramBinary", &["glProgramBinaryOES"]));
@@ -238,6 +276,9 @@ pub unsafe fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const
     storage::DeleteFramebuffers = FnPtr::new(metaloadfn(&mut loadfn, "glDeleteFramebuffers", &["glDeleteFramebuffersEXT"]));
     storage::DeleteVertexArrays = FnPtr::new(metaloadfn(&mut loadfn, "glDeleteVertexArrays", &["glDeleteVertexArraysAPPLE", "glDeleteVertexArraysOES"]));
     storage::GenerateMipmap = FnPtr::new(metaloadfn(&mut loadfn, "glGenerateMipmap", &[]));
+    storage::DepthMask = FnPtr::new(metaloadfn(&mut loadfn, "glDepthMask", &[]));
+    storage::TexImage3D = FnPtr::new(metaloadfn(&mut loadfn, "glTexImage3D", &["glTexImage3DOES"]));
+    storage::GetString = FnPtr::new(metaloadfn(&mut loadfn, "glGetString", &[]));
 }
 
 #[inline(never)]
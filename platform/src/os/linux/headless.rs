@@ -0,0 +1,384 @@
+use {
+    std::{
+        rc::Rc,
+        cell::RefCell,
+        path::Path,
+    },
+    crate::{
+        makepad_live_id::*,
+        makepad_math::*,
+        makepad_micro_serde::*,
+        makepad_zune_png::{
+            PngEncoder,
+            PngDecoder,
+            makepad_zune_core::{
+                options::EncoderOptions,
+                colorspace::ColorSpace,
+                bit_depth::BitDepth,
+            },
+        },
+        app_main::AppMain,
+        live_traits::LiveNew,
+        event::{Event, TimerEvent, WindowGeom},
+        window::WindowId,
+        thread::Signal,
+        os::{
+            cx_stdin::{HostToStdin, StdinWindowSize},
+            linux::soft_render::SoftFramebuffer,
+        },
+        cx_api::CxOsOp,
+        cx::Cx,
+    }
+};
+
+// Runs an app without a window or a GPU. Input comes in as the same
+// HostToStdin messages Studio sends to its child processes, frames are
+// rendered with the software renderer so they can be compared against PNGs.
+
+struct HeadlessTimer {
+    timer_id: u64,
+    interval: f64,
+    repeats: bool,
+    next: f64,
+}
+
+pub struct HeadlessCx {
+    pub cx: Cx,
+    pub framebuffers: Vec<(WindowId, SoftFramebuffer)>,
+    pub window_size: StdinWindowSize,
+    pub time: f64,
+    pub frame: u64,
+    window_ids: Vec<WindowId>,
+    timers: Vec<HeadlessTimer>,
+}
+
+impl HeadlessCx {
+    pub const FRAME_TIME: f64 = 1.0 / 60.0;
+    pub const LIVE_CHECK_FRAMES: usize = 10;
+
+    pub fn new(mut cx: Cx, window_size: StdinWindowSize) -> Self {
+        cx.live_expand();
+        cx.live_scan_dependencies();
+        cx.native_load_dependencies();
+        Self::new_expanded(cx, window_size)
+    }
+
+    fn new_expanded(cx: Cx, window_size: StdinWindowSize) -> Self {
+        let mut headless = Self {
+            cx,
+            framebuffers: Vec::new(),
+            window_size,
+            time: 0.0,
+            frame: 0,
+            window_ids: Vec::new(),
+            timers: Vec::new(),
+        };
+        headless.cx.call_event_handler(&Event::Construct);
+        headless.handle_platform_ops();
+        headless.cx.redraw_all();
+        headless
+    }
+
+    // the headless counterpart of app_main!, live_design is the crate level registration function
+    pub fn new_app<T: AppMain + LiveNew + 'static>(live_design: fn(&mut Cx), window_size: StdinWindowSize) -> Self {
+        let app = Rc::new(RefCell::new(None));
+        let mut cx = Cx::new(Box::new(move | cx, event | {
+            if let Event::Construct = event {
+                *app.borrow_mut() = Some(T::new_main(cx));
+            }
+            if let Event::LiveEdit = event {
+                app.borrow_mut().update_main(cx);
+            }
+            if let Some(app) = app.borrow_mut().as_mut() {
+                <dyn AppMain>::handle_event(app, cx, event);
+            }
+        }));
+        live_design(&mut cx);
+        Self::new(cx, window_size)
+    }
+
+    // what an app started with --live-check runs instead of its event loop. The cx already went
+    // through init_cx_os with diagnostics collected, here the app is constructed and drawn so apply
+    // and shader errors show up too. They are written to stdout for tools/live_check, the
+    // returned exit code is 1 if there were errors
+    pub fn live_check(mut cx: Cx) -> i32 {
+        cx.collect_live_diagnostics();
+        let mut headless = Self::new_expanded(cx, StdinWindowSize {
+            width: 800.0,
+            height: 600.0,
+            dpi_factor: 1.0
+        });
+        headless.settle(Self::LIVE_CHECK_FRAMES);
+        let diagnostics = headless.cx.take_live_diagnostics();
+        for diagnostic in &diagnostics {
+            println!("{}", diagnostic.to_live_check_line());
+        }
+        if diagnostics.iter().any( | diagnostic | diagnostic.is_error()) {1} else {0}
+    }
+
+    pub fn send(&mut self, msg: HostToStdin) {
+        match msg {
+            HostToStdin::KeyDown(e) => {
+                self.cx.call_event_handler(&Event::KeyDown(e));
+            }
+            HostToStdin::KeyUp(e) => {
+                self.cx.call_event_handler(&Event::KeyUp(e));
+            }
+            HostToStdin::MouseDown(e) => {
+                self.cx.fingers.process_tap_count(
+                    dvec2(e.x, e.y),
+                    e.time
+                );
+                self.cx.fingers.mouse_down(e.button);
+                self.cx.call_event_handler(&Event::MouseDown(e.into()));
+            }
+            HostToStdin::MouseMove(e) => {
+                self.cx.call_event_handler(&Event::MouseMove(e.into()));
+                self.cx.fingers.cycle_hover_area(live_id!(mouse).into());
+                self.cx.fingers.switch_captures();
+            }
+            HostToStdin::MouseUp(e) => {
+                let button = e.button;
+                self.cx.call_event_handler(&Event::MouseUp(e.into()));
+                self.cx.fingers.mouse_up(button);
+                self.cx.fingers.cycle_hover_area(live_id!(mouse).into());
+            }
+            HostToStdin::Scroll(e) => {
+                self.cx.call_event_handler(&Event::Scroll(e.into()))
+            }
+            HostToStdin::WindowSize(ws) => if self.window_size != ws {
+                self.window_size = ws;
+                for window_id in self.window_ids.clone() {
+                    self.cx.windows[window_id].window_geom = self.window_geom();
+                }
+                self.cx.redraw_all();
+            }
+            HostToStdin::Tick {frame, time} => {
+                self.frame = frame;
+                self.time = time;
+                self.handle_tick();
+            }
+            HostToStdin::ReloadFile {file, contents} => {
+                self.cx.queue_live_file_change(file, contents);
+            }
+            HostToStdin::Dx11SharedHandle(_) |
+            HostToStdin::SharedMemoryFramebuffer {..} => ()
+        }
+        self.handle_platform_ops();
+    }
+
+    // feeds a script of HostToStdin messages, one json object per line like on stdin
+    pub fn send_script(&mut self, script: &str) -> Result<(), DeJsonErr> {
+        for line in script.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let msg: HostToStdin = DeJson::deserialize_json(line) ?;
+            self.send(msg);
+        }
+        Ok(())
+    }
+
+    // advances the clock by one frame and renders
+    pub fn tick(&mut self) {
+        self.send(HostToStdin::Tick {frame: self.frame + 1, time: self.time + Self::FRAME_TIME});
+    }
+
+    // ticks until nothing wants to redraw anymore, returns false if it never settled
+    pub fn settle(&mut self, max_frames: usize) -> bool {
+        for _ in 0..max_frames {
+            self.tick();
+            if !self.cx.need_redrawing() && self.cx.new_next_frames.len() == 0 && !self.cx.any_passes_dirty() {
+                return true
+            }
+        }
+        false
+    }
+
+    pub fn framebuffer(&self, window_id: WindowId) -> Option<&SoftFramebuffer> {
+        self.framebuffers.iter().find( | (id, _) | *id == window_id).map( | (_, fb) | fb)
+    }
+
+    pub fn main_framebuffer(&self) -> Option<&SoftFramebuffer> {
+        self.window_ids.first().and_then( | window_id | self.framebuffer(*window_id))
+    }
+
+    fn window_geom(&self) -> WindowGeom {
+        WindowGeom {
+            dpi_factor: self.window_size.dpi_factor,
+            inner_size: dvec2(self.window_size.width, self.window_size.height),
+            outer_size: dvec2(self.window_size.width, self.window_size.height),
+            ..Default::default()
+        }
+    }
+
+    fn handle_tick(&mut self) {
+        // no audio or midi device enumeration here, CI machines have no sound server to ask
+        if Signal::check_and_clear_ui_signal() {
+            self.cx.call_event_handler(&Event::Signal);
+        }
+        if self.cx.was_live_edit() {
+            self.cx.call_event_handler(&Event::LiveEdit);
+            self.cx.redraw_all();
+        }
+        let mut fired = Vec::new();
+        for timer in &mut self.timers {
+            if timer.next <= self.time {
+                fired.push(timer.timer_id);
+                timer.next = if timer.repeats {self.time + timer.interval} else {f64::INFINITY};
+            }
+        }
+        self.timers.retain( | timer | timer.next.is_finite());
+        for timer_id in fired {
+            self.cx.call_event_handler(&Event::Timer(TimerEvent {timer_id}));
+        }
+        if self.cx.new_next_frames.len() != 0 {
+            self.cx.call_next_frame_event(self.time);
+        }
+        if self.cx.need_redrawing() {
+            self.cx.call_draw_event();
+            self.cx.soft_compile_shaders();
+        }
+        self.handle_platform_ops();
+        self.cx.soft_repaint(&mut self.framebuffers);
+    }
+
+    fn handle_platform_ops(&mut self) {
+        while let Some(op) = self.cx.platform_ops.pop() {
+            match op {
+                CxOsOp::CreateWindow(window_id) => {
+                    let window_geom = self.window_geom();
+                    let window = &mut self.cx.windows[window_id];
+                    window.is_created = true;
+                    window.window_geom = window_geom;
+                    self.window_ids.push(window_id);
+                },
+                CxOsOp::CloseWindow(window_id) => {
+                    self.cx.windows[window_id].is_created = false;
+                    self.window_ids.retain( | id | *id != window_id);
+                    self.framebuffers.retain( | (id, _) | *id != window_id);
+                }
+                CxOsOp::StartTimer {timer_id, interval, repeats} => {
+                    self.timers.push(HeadlessTimer {timer_id, interval, repeats, next: self.time + interval});
+                }
+                CxOsOp::StopTimer(timer_id) => {
+                    self.timers.retain( | timer | timer.timer_id != timer_id);
+                }
+                _ => ()
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ImageDiff {
+    pub width: usize,
+    pub height: usize,
+    pub different_pixels: usize,
+    pub max_channel_diff: u8,
+}
+
+impl SoftFramebuffer {
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.color.len() * 4);
+        for c in &self.color {
+            out.extend_from_slice(&[(c >> 16) as u8, (c >> 8) as u8, *c as u8, (c >> 24) as u8]);
+        }
+        out
+    }
+
+    pub fn encode_png(&self) -> Vec<u8> {
+        let rgba = self.to_rgba8();
+        let options = EncoderOptions::new(self.width, self.height, ColorSpace::RGBA, BitDepth::Eight);
+        PngEncoder::new(&rgba, options).encode()
+    }
+
+    pub fn decode_png(data: &[u8]) -> Result<SoftFramebuffer, String> {
+        let mut decoder = PngDecoder::new(data);
+        let image = decoder.decode().map_err( | err | format!("Error decoding PNG: {:?}", err)) ?;
+        let data = image.u8().ok_or_else( || "Error decoding PNG: image data empty".to_string()) ?;
+        let (width, height) = decoder.get_dimensions().unwrap();
+        let pixels = width * height;
+        let components = if pixels == 0 {4} else {data.len() / pixels};
+        if components != 3 && components != 4 {
+            return Err("Error decoding PNG: pixel alignment not 3 or 4".to_string())
+        }
+        let mut framebuffer = SoftFramebuffer::default();
+        framebuffer.resize(width, height);
+        for i in 0..pixels {
+            let p = &data[i * components..];
+            let a = if components == 4 {p[3]} else {255};
+            framebuffer.color[i] = ((a as u32) << 24) | ((p[0] as u32) << 16) | ((p[1] as u32) << 8) | p[2] as u32;
+        }
+        Ok(framebuffer)
+    }
+
+    // channels within tolerance of each other count as equal
+    pub fn diff(&self, other: &SoftFramebuffer, tolerance: u8) -> Option<ImageDiff> {
+        if self.width != other.width || self.height != other.height {
+            return Some(ImageDiff {
+                width: other.width,
+                height: other.height,
+                different_pixels: self.color.len().max(other.color.len()),
+                max_channel_diff: 255
+            })
+        }
+        let mut different_pixels = 0;
+        let mut max_channel_diff = 0;
+        for (a, b) in self.color.iter().zip(other.color.iter()) {
+            let mut pixel_diff = 0;
+            for shift in [0, 8, 16, 24] {
+                let d = (((a >> shift) & 0xff) as i32 - ((b >> shift) & 0xff) as i32).unsigned_abs() as u8;
+                pixel_diff = pixel_diff.max(d);
+            }
+            if pixel_diff > tolerance {
+                different_pixels += 1;
+            }
+            max_channel_diff = max_channel_diff.max(pixel_diff);
+        }
+        if different_pixels == 0 {
+            return None
+        }
+        Some(ImageDiff {width: self.width, height: self.height, different_pixels, max_channel_diff})
+    }
+
+    // compares against a golden PNG. With MAKEPAD_UPDATE_GOLDEN set the golden image is (re)written
+    // instead. On mismatch the rendered frame is written next to it as .actual.png
+    pub fn compare_golden(&self, path: impl AsRef<Path>, tolerance: u8) -> Result<(), String> {
+        let path = path.as_ref();
+        if std::env::var("MAKEPAD_UPDATE_GOLDEN").is_ok() {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            return std::fs::write(path, self.encode_png()).map_err( | err | format!("Cannot write {:?}: {}", path, err))
+        }
+        if !path.exists() {
+            return Err(format!("{:?} is missing, rerun with MAKEPAD_UPDATE_GOLDEN=1 to write it", path))
+        }
+        let data = std::fs::read(path).map_err( | err | format!("Cannot read {:?}: {}", path, err)) ?;
+        let golden = SoftFramebuffer::decode_png(&data) ?;
+        if let Some(diff) = self.diff(&golden, tolerance) {
+            let actual = path.with_extension("actual.png");
+            let _ = std::fs::write(&actual, self.encode_png());
+            return Err(format!(
+                "Image differs from {:?} in {} pixels (max channel diff {}), {}x{} expected {}x{}, wrote {:?}",
+                path,
+                diff.different_pixels,
+                diff.max_channel_diff,
+                self.width,
+                self.height,
+                golden.width,
+                golden.height,
+                actual
+            ))
+        }
+        Ok(())
+    }
+
+    pub fn assert_golden(&self, path: impl AsRef<Path>) {
+        if let Err(err) = self.compare_golden(path, 1) {
+            panic!("{}", err);
+        }
+    }
+}
@@ -19,6 +19,7 @@ pub use libc_64::*;
 
 pub type time_t = c_ulong;
 pub type suseconds_t = c_ulong;
+pub type off_t = std::os::raw::c_long;
 
 type c_int =  std::os::raw::c_int;
 type c_uint =  std::os::raw::c_uint;
@@ -30,6 +31,10 @@ type size_t = usize;
 pub const FD_SETSIZE: usize = 1024;
 pub const EPIPE: c_int = 32;
 pub const O_RDWR: c_int = 2;
+pub const PROT_READ: c_int = 1;
+pub const PROT_WRITE: c_int = 2;
+pub const MAP_SHARED: c_int = 1;
+pub const MAP_FAILED: *mut c_void = !0 as *mut c_void;
 
 #[repr(C)]
 pub struct fd_set {
@@ -51,6 +56,8 @@ extern "C"{
         timeout: *mut timeval,
     ) -> c_int;
     pub fn read(fd: c_int, buf: *mut c_void, count: size_t) -> c_int;
+    pub fn mmap(addr: *mut c_void, len: size_t, prot: c_int, flags: c_int, fd: c_int, offset: off_t) -> *mut c_void;
+    pub fn munmap(addr: *mut c_void, len: size_t) -> c_int;
 }
 
 pub unsafe fn FD_SET(fd: c_int, set: *mut fd_set) -> () {
@@ -0,0 +1,347 @@
+use {
+    std::{
+        io::{Read, Write},
+        net::TcpStream,
+        sync::mpsc::Sender,
+        time::Duration,
+    },
+    crate::{
+        makepad_live_id::*,
+        event::{
+            NetworkResponseEvent,
+            NetworkResponse,
+            HttpRequest,
+            HttpResponse,
+            HttpMethod,
+        },
+    }
+};
+
+const MAX_REDIRECTS: usize = 10;
+const MAX_HEADER_SIZE: usize = 64 * 1024;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpUrl {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl HttpUrl {
+    pub fn parse(url: &str) -> Result<HttpUrl, String> {
+        let rest = if let Some(rest) = url.strip_prefix("http://").or_else( || url.strip_prefix("ws://")) {
+            rest
+        }
+        else if url.starts_with("https://") || url.starts_with("wss://") {
+            // there is no tls stack on linux yet, say so instead of failing somewhere in the handshake
+            return Err(format!("https and wss are not supported by the Linux http client, use an http:// url or a local tls proxy: {}", url))
+        }
+        else {
+            return Err(format!("Invalid url: {}", url))
+        };
+        let (authority, path) = match rest.find(|c| c == '/' || c == '?') {
+            Some(pos) if rest[pos..].starts_with('?') => (&rest[..pos], format!("/{}", &rest[pos..])),
+            Some(pos) => (&rest[..pos], rest[pos..].to_string()),
+            None => (rest, "/".to_string())
+        };
+        // strip userinfo, we dont do auth from urls
+        let authority = authority.rsplit('@').next().unwrap_or(authority);
+        let (host, port) = if let Some(pos) = authority.rfind(':') {
+            if let Ok(port) = authority[pos + 1..].parse() {
+                (&authority[..pos], port)
+            }
+            else {
+                return Err(format!("Invalid port in url: {}", url))
+            }
+        }
+        else {
+            (authority, 80)
+        };
+        if host.is_empty() {
+            return Err(format!("No host in url: {}", url))
+        }
+        Ok(HttpUrl {
+            host: host.to_string(),
+            port,
+            path
+        })
+    }
+
+    pub fn host_header(&self) -> String {
+        if self.port == 80 {
+            self.host.clone()
+        }
+        else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    // resolves a Location header against this url
+    pub fn join(&self, location: &str) -> Result<HttpUrl, String> {
+        if location.starts_with("http://") || location.starts_with("https://") {
+            HttpUrl::parse(location)
+        }
+        else if location.starts_with("//") {
+            HttpUrl::parse(&format!("http:{}", location))
+        }
+        else if location.starts_with('/') {
+            Ok(HttpUrl {path: location.to_string(), ..self.clone()})
+        }
+        else {
+            let base = match self.path.rfind('/') {
+                Some(pos) => &self.path[..pos + 1],
+                None => "/"
+            };
+            Ok(HttpUrl {path: format!("{}{}", base, location), ..self.clone()})
+        }
+    }
+}
+
+pub struct HttpResponseHead {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpResponseHead {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find( | (k, _) | k.eq_ignore_ascii_case(name)).map( | (_, v) | v.as_str())
+    }
+
+    fn parse(data: &[u8]) -> Result<HttpResponseHead, String> {
+        let text = std::str::from_utf8(data).map_err( | _ | "Http header is not valid utf8".to_string()) ?;
+        let mut lines = text.split("\r\n");
+        let status_line = lines.next().unwrap_or("");
+        let mut parts = status_line.splitn(3, ' ');
+        let version = parts.next().unwrap_or("");
+        if !version.starts_with("HTTP/") {
+            return Err(format!("Invalid http status line: {}", status_line))
+        }
+        let status_code = parts.next().and_then( | v | v.parse().ok())
+            .ok_or_else( || format!("Invalid http status line: {}", status_line)) ?;
+        let mut headers = Vec::new();
+        for line in lines {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(pos) = line.find(':') {
+                headers.push((line[..pos].trim().to_string(), line[pos + 1..].trim().to_string()));
+            }
+        }
+        Ok(HttpResponseHead {status_code, headers})
+    }
+}
+
+/// A blocking HTTP/1.1 connection reading one response. Used from a background thread
+/// per request, so we dont need any pooling or async machinery.
+pub struct HttpConnection {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+}
+
+impl HttpConnection {
+    pub fn connect(url: &HttpUrl) -> Result<Self, String> {
+        let stream = TcpStream::connect((url.host.as_str(), url.port))
+            .map_err( | e | format!("Cannot connect to {}: {}", url.host_header(), e)) ?;
+        let _ = stream.set_read_timeout(Some(Duration::from_secs(60)));
+        Ok(Self {stream, buffer: Vec::new()})
+    }
+
+    pub fn write_request(&mut self, url: &HttpUrl, method: &HttpMethod, request: &HttpRequest, body: Option<&Vec<u8>>) -> Result<(), String> {
+        let mut head = format!("{} {} HTTP/1.1\r\nHost: {}\r\n", method.to_string(), url.path, url.host_header());
+        let mut has_user_agent = false;
+        for (key, values) in &request.headers {
+            if key.eq_ignore_ascii_case("host") || key.eq_ignore_ascii_case("content-length") || key.eq_ignore_ascii_case("connection") {
+                continue;
+            }
+            if key.eq_ignore_ascii_case("user-agent") {
+                has_user_agent = true;
+            }
+            head.push_str(&format!("{}: {}\r\n", key, values.join(",")));
+        }
+        if !has_user_agent {
+            head.push_str("User-Agent: makepad\r\n");
+        }
+        if let Some(body) = body {
+            head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        head.push_str("Connection: close\r\n\r\n");
+        self.stream.write_all(head.as_bytes()).map_err( | e | format!("Cannot write request: {}", e)) ?;
+        if let Some(body) = body {
+            self.stream.write_all(body).map_err( | e | format!("Cannot write request body: {}", e)) ?;
+        }
+        Ok(())
+    }
+
+    /// Hands out the socket and any bytes already read past the response head, used after a protocol upgrade
+    pub fn into_parts(self) -> (TcpStream, Vec<u8>) {
+        (self.stream, self.buffer)
+    }
+
+    pub fn write_raw(&mut self, data: &[u8]) -> Result<(), String> {
+        self.stream.write_all(data).map_err( | e | format!("Cannot write request: {}", e))
+    }
+
+    fn fill_buffer(&mut self) -> Result<usize, String> {
+        let mut data = [0u8; 65536];
+        let n = self.stream.read(&mut data).map_err( | e | format!("Cannot read response: {}", e)) ?;
+        self.buffer.extend_from_slice(&data[0..n]);
+        Ok(n)
+    }
+
+    pub fn read_head(&mut self) -> Result<HttpResponseHead, String> {
+        loop {
+            if let Some(pos) = self.buffer.windows(4).position( | w | w == b"\r\n\r\n") {
+                let head = HttpResponseHead::parse(&self.buffer[0..pos]) ?;
+                self.buffer.drain(0..pos + 4);
+                // skip 1xx interim responses, except a protocol switch
+                if head.status_code >= 100 && head.status_code < 200 && head.status_code != 101 {
+                    continue;
+                }
+                return Ok(head)
+            }
+            if self.buffer.len() > MAX_HEADER_SIZE {
+                return Err("Http response header too large".to_string())
+            }
+            if self.fill_buffer() ? == 0 {
+                return Err("Connection closed before response header".to_string())
+            }
+        }
+    }
+
+    fn read_line(&mut self) -> Result<String, String> {
+        loop {
+            if let Some(pos) = self.buffer.windows(2).position( | w | w == b"\r\n") {
+                let line = String::from_utf8_lossy(&self.buffer[0..pos]).to_string();
+                self.buffer.drain(0..pos + 2);
+                return Ok(line)
+            }
+            if self.fill_buffer() ? == 0 {
+                return Err("Connection closed in chunked body".to_string())
+            }
+        }
+    }
+
+    fn read_exact_into(&mut self, len: usize, out: &mut Vec<u8>) -> Result<(), String> {
+        while self.buffer.len() < len {
+            if self.fill_buffer() ? == 0 {
+                return Err("Connection closed in chunked body".to_string())
+            }
+        }
+        out.extend(self.buffer.drain(0..len));
+        Ok(())
+    }
+
+    /// Reads the body belonging to `head`, calling `progress(loaded, total)` as data arrives.
+    /// total is 0 when the length isnt known upfront
+    pub fn read_body(&mut self, method: &HttpMethod, head: &HttpResponseHead, mut progress: impl FnMut(usize, usize)) -> Result<Vec<u8>, String> {
+        if *method == HttpMethod::HEAD || head.status_code == 204 || head.status_code == 304 {
+            return Ok(Vec::new())
+        }
+        let mut body = Vec::new();
+        let is_chunked = head.header("Transfer-Encoding").map_or(false, | v | v.to_ascii_lowercase().contains("chunked"));
+        if is_chunked {
+            loop {
+                let line = self.read_line() ?;
+                let size_str = line.split(';').next().unwrap_or("").trim();
+                let size = usize::from_str_radix(size_str, 16).map_err( | _ | format!("Invalid chunk size: {}", line)) ?;
+                if size == 0 {
+                    // skip trailers
+                    while !self.read_line() ?.is_empty() {}
+                    break;
+                }
+                self.read_exact_into(size, &mut body) ?;
+                if !self.read_line() ?.is_empty() {
+                    return Err("Invalid chunk terminator".to_string())
+                }
+                progress(body.len(), 0);
+            }
+        }
+        else if let Some(len) = head.header("Content-Length") {
+            let total: usize = len.parse().map_err( | _ | format!("Invalid content length: {}", len)) ?;
+            while self.buffer.len() < total {
+                if self.fill_buffer() ? == 0 {
+                    return Err("Connection closed before end of body".to_string())
+                }
+                progress(self.buffer.len().min(total), total);
+            }
+            body.extend(self.buffer.drain(0..total));
+        }
+        else {
+            while self.fill_buffer() ? != 0 {
+                progress(self.buffer.len(), 0);
+            }
+            body.append(&mut self.buffer);
+        }
+        Ok(body)
+    }
+}
+
+pub struct LinuxHttpRequest {
+    pub request_id: LiveId,
+    pub request: HttpRequest,
+}
+
+impl LinuxHttpRequest {
+    pub fn new(request_id: LiveId, request: HttpRequest) -> Self {
+        Self {request_id, request}
+    }
+
+    /// Runs the request on its own thread, delivering progress, the response or an error on `response_sender`
+    pub fn start(self, response_sender: Sender<NetworkResponseEvent>) {
+        std::thread::spawn(move || {
+            let request_id = self.request_id;
+            let response = match self.run(&response_sender) {
+                Ok(response) => NetworkResponse::HttpResponse(response),
+                Err(err) => NetworkResponse::HttpRequestError(err)
+            };
+            let _ = response_sender.send(NetworkResponseEvent {request_id, response});
+        });
+    }
+
+    pub fn run(&self, response_sender: &Sender<NetworkResponseEvent>) -> Result<HttpResponse, String> {
+        let mut url = HttpUrl::parse(&self.request.url) ?;
+        let mut method = self.request.method.clone();
+        let mut body = self.request.body.as_ref();
+        for _ in 0..=MAX_REDIRECTS {
+            let mut connection = HttpConnection::connect(&url) ?;
+            connection.write_request(&url, &method, &self.request, body) ?;
+            let head = connection.read_head() ?;
+
+            if let (301 | 302 | 303 | 307 | 308, Some(location)) = (head.status_code, head.header("Location")) {
+                url = url.join(location) ?;
+                // 303 always becomes a GET, 301/302 do so for POST like browsers do
+                if head.status_code == 303 || (head.status_code != 307 && head.status_code != 308 && method == HttpMethod::POST) {
+                    if method != HttpMethod::HEAD {
+                        method = HttpMethod::GET;
+                    }
+                    body = None;
+                }
+                continue;
+            }
+
+            let request_id = self.request_id;
+            let mut last_loaded = 0;
+            let data = connection.read_body(&method, &head, | loaded, total | {
+                if loaded != last_loaded {
+                    last_loaded = loaded;
+                    let _ = response_sender.send(NetworkResponseEvent {
+                        request_id,
+                        response: NetworkResponse::HttpProgress {loaded: loaded as u32, total: total as u32}
+                    });
+                }
+            }) ?;
+
+            let mut response = HttpResponse::new(
+                self.request.metadata_id,
+                head.status_code,
+                "".to_string(),
+                Some(data),
+            );
+            for (key, value) in head.headers {
+                response.set_header(key, value);
+            }
+            return Ok(response)
+        }
+        Err(format!("Too many redirects for {}", self.request.url))
+    }
+}
@@ -0,0 +1,193 @@
+use {
+    std::{
+        io::{Read, Write},
+        net::{TcpStream, Shutdown},
+        sync::mpsc::{channel, Sender, Receiver, RecvTimeoutError},
+        time::{Duration, SystemTime},
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    },
+    makepad_http::{
+        digest::base64_encode,
+        websocket::{
+            WebSocket,
+            WebSocketMessage,
+            build_masked_frame,
+            OPCODE_TEXT,
+            OPCODE_BINARY,
+            OPCODE_CLOSE,
+            OPCODE_PING,
+            OPCODE_PONG,
+        },
+    },
+    self::super::linux_http::{HttpUrl, HttpConnection},
+    crate::{
+        makepad_live_id::*,
+        event::{
+            NetworkResponseEvent,
+            NetworkResponse,
+            HttpRequest,
+        },
+    }
+};
+
+const PING_INTERVAL: Duration = Duration::from_secs(10);
+
+enum WebSocketThreadMsg {
+    String(String),
+    Binary(Vec<u8>),
+    Pong(Vec<u8>),
+    Close,
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    // RandomState is seeded randomly per instance which is plenty for masks and handshake keys
+    let mut out = [0u8; N];
+    let mut hasher = RandomState::new().build_hasher();
+    for chunk in out.chunks_mut(8) {
+        hasher.write_u128(SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0));
+        let value = hasher.finish().to_le_bytes();
+        chunk.copy_from_slice(&value[0..chunk.len()]);
+    }
+    out
+}
+
+pub struct LinuxWebSocket {
+    sender: Sender<WebSocketThreadMsg>,
+}
+
+impl LinuxWebSocket {
+    /// Connects on a background thread, queued sends are flushed once the handshake completes
+    pub fn open(request_id: LiveId, request: HttpRequest, response_sender: Sender<NetworkResponseEvent>) -> LinuxWebSocket {
+        let (sender, receiver) = channel();
+        let pong_sender = sender.clone();
+        std::thread::spawn(move || {
+            let (read_stream, leftover) = match Self::handshake(&request) {
+                Ok(v) => v,
+                Err(err) => {
+                    let _ = response_sender.send(NetworkResponseEvent {
+                        request_id,
+                        response: NetworkResponse::WebSocketError(err)
+                    });
+                    return
+                }
+            };
+            let write_stream = match read_stream.try_clone() {
+                Ok(v) => v,
+                Err(err) => {
+                    let _ = response_sender.send(NetworkResponseEvent {
+                        request_id,
+                        response: NetworkResponse::WebSocketError(err.to_string())
+                    });
+                    return
+                }
+            };
+            let _ = response_sender.send(NetworkResponseEvent {
+                request_id,
+                response: NetworkResponse::WebSocketOpen
+            });
+            std::thread::spawn(move || Self::write_loop(write_stream, receiver));
+            Self::read_loop(request_id, read_stream, leftover, pong_sender, response_sender);
+        });
+        LinuxWebSocket {sender}
+    }
+
+    pub fn send_string(&self, data: String) {
+        let _ = self.sender.send(WebSocketThreadMsg::String(data));
+    }
+
+    pub fn send_binary(&self, data: Vec<u8>) {
+        let _ = self.sender.send(WebSocketThreadMsg::Binary(data));
+    }
+
+    fn handshake(request: &HttpRequest) -> Result<(TcpStream, Vec<u8>), String> {
+        let url = HttpUrl::parse(&request.url) ?;
+        let key = base64_encode(&random_bytes::<16>());
+        let mut extra_headers = String::new();
+        for (name, values) in &request.headers {
+            extra_headers.push_str(&format!("{}: {}\r\n", name, values.join(",")));
+        }
+        let mut connection = HttpConnection::connect(&url) ?;
+        connection.write_raw(WebSocket::create_upgrade_request(&url.host_header(), &url.path, &key, &extra_headers).as_bytes()) ?;
+        let head = connection.read_head() ?;
+        if head.status_code != 101 {
+            return Err(format!("Websocket upgrade failed with status {}", head.status_code))
+        }
+        if head.header("Sec-WebSocket-Accept") != Some(&WebSocket::create_accept_key(&key)) {
+            return Err("Websocket upgrade returned an invalid accept key".to_string())
+        }
+        let (stream, leftover) = connection.into_parts();
+        let _ = stream.set_read_timeout(None);
+        Ok((stream, leftover))
+    }
+
+    fn write_loop(mut stream: TcpStream, receiver: Receiver<WebSocketThreadMsg>) {
+        loop {
+            let frame = match receiver.recv_timeout(PING_INTERVAL) {
+                Ok(WebSocketThreadMsg::String(data)) => build_masked_frame(OPCODE_TEXT, data.as_bytes(), random_bytes()),
+                Ok(WebSocketThreadMsg::Binary(data)) => build_masked_frame(OPCODE_BINARY, &data, random_bytes()),
+                Ok(WebSocketThreadMsg::Pong(data)) => build_masked_frame(OPCODE_PONG, &data, random_bytes()),
+                Err(RecvTimeoutError::Timeout) => build_masked_frame(OPCODE_PING, &[], random_bytes()),
+                Ok(WebSocketThreadMsg::Close) | Err(RecvTimeoutError::Disconnected) => {
+                    let _ = stream.write_all(&build_masked_frame(OPCODE_CLOSE, &[], random_bytes()));
+                    break
+                }
+            };
+            if stream.write_all(&frame).is_err() {
+                break
+            }
+        }
+        let _ = stream.shutdown(Shutdown::Both);
+    }
+
+    fn read_loop(request_id: LiveId, mut stream: TcpStream, leftover: Vec<u8>, pong_sender: Sender<WebSocketThreadMsg>, response_sender: Sender<NetworkResponseEvent>) {
+        let mut web_socket = WebSocket::new();
+        let mut is_closed = false;
+        let mut handle_data = | data: &[u8], is_closed: &mut bool | {
+            web_socket.parse(data, | result | {
+                let response = match result {
+                    Ok(WebSocketMessage::Ping(data)) => {
+                        let _ = pong_sender.send(WebSocketThreadMsg::Pong(data.to_vec()));
+                        return
+                    }
+                    Ok(WebSocketMessage::Pong(_)) => return,
+                    Ok(WebSocketMessage::Text(text)) => NetworkResponse::WebSocketString(text.to_string()),
+                    Ok(WebSocketMessage::Binary(data)) => NetworkResponse::WebSocketBinary(data.to_vec()),
+                    Ok(WebSocketMessage::Close) => {
+                        *is_closed = true;
+                        NetworkResponse::WebSocketClose
+                    }
+                    Err(err) => {
+                        // the frame stream can't be resynced after a parse error
+                        *is_closed = true;
+                        NetworkResponse::WebSocketError(format!("{:?}", err))
+                    }
+                };
+                let _ = response_sender.send(NetworkResponseEvent {request_id, response});
+            });
+        };
+        if !leftover.is_empty() {
+            handle_data(&leftover, &mut is_closed);
+        }
+        let mut data = [0u8; 65535];
+        while !is_closed {
+            match stream.read(&mut data) {
+                Ok(0) | Err(_) => {
+                    let _ = response_sender.send(NetworkResponseEvent {
+                        request_id,
+                        response: NetworkResponse::WebSocketClose
+                    });
+                    break
+                }
+                Ok(n) => handle_data(&data[0..n], &mut is_closed)
+            }
+        }
+        let _ = pong_sender.send(WebSocketThreadMsg::Close);
+    }
+}
+
+impl Drop for LinuxWebSocket {
+    fn drop(&mut self) {
+        let _ = self.sender.send(WebSocketThreadMsg::Close);
+    }
+}
@@ -1,10 +1,15 @@
 #[cfg(not(any(linux_direct, target_os="android")))]
 pub mod x11; 
 
+#[cfg(not(any(linux_direct, target_os="android")))]
+pub mod wayland;
+
 #[cfg(linux_direct)]
 pub mod direct;
 
 pub mod gl_sys;
+#[cfg(not(target_os="android"))]
+pub mod egl_sys;
 pub mod libc_sys;
 pub mod opengl;
 
@@ -18,6 +23,16 @@ pub mod alsa_audio;
 pub mod alsa_midi;
 #[cfg(not(target_os="android"))]
 pub mod select_timer;
+#[cfg(not(target_os="android"))]
+pub mod linux_http;
+#[cfg(not(target_os="android"))]
+pub mod linux_websocket;
+#[cfg(not(target_os="android"))]
+pub mod soft_render;
+#[cfg(not(target_os="android"))]
+pub mod headless;
+#[cfg(not(target_os="android"))]
+pub mod stdin_shared_memory;
 #[cfg(not(target_os="android"))] 
 pub mod pulse_audio; 
 #[cfg(not(target_os="android"))]
@@ -10,9 +10,9 @@ use {
     crate::{
         makepad_live_id::*,
         makepad_error_log::*,
-        makepad_shader_compiler::generate_glsl,
+        makepad_shader_compiler::{generate_glsl, DrawShaderFlags, BlendFactor},
         cx::Cx,
-        texture::{TextureDesc, TextureFormat},
+        texture::{CxTexture, TextureDesc, TextureFormat, TextureDimension, TexturePixels},
         makepad_math::{Mat4, DVec2, Vec4},
         pass::{PassClearColor, PassClearDepth, PassId},
         draw_list::DrawListId,
@@ -162,6 +162,7 @@ impl Cx {
                     }
                 }
                 
+                Self::set_depth_and_blend_mode(&sh.mapping.flags);
                 unsafe {
                     gl_sys::UseProgram(shgl.program);
                     
@@ -191,13 +192,9 @@ impl Cx {
                             continue;
                         };
                         let cxtexture = &mut self.textures[texture_id];
-                        if cxtexture.update_image || cxtexture.image_u32.len() != 0 && cxtexture.os.gl_texture.is_none(){
+                        if cxtexture.update_image || !cxtexture.image_bytes().is_empty() && cxtexture.os.gl_texture.is_none(){
                             cxtexture.update_image = false;
-                            cxtexture.os.update_platform_texture_image2d(
-                                cxtexture.desc.width.unwrap() as u32,
-                                cxtexture.desc.height.unwrap() as u32,
-                                &cxtexture.image_u32
-                            );
+                            cxtexture.update_platform_texture_image();
                         }  
                     }
                     for i in 0..sh.mapping.textures.len() {
@@ -210,7 +207,7 @@ impl Cx {
                         // get the loc
                         gl_sys::ActiveTexture(gl_sys::TEXTURE0 + i as u32);
                         if let Some(texture) = cxtexture.os.gl_texture {
-                            gl_sys::BindTexture(gl_sys::TEXTURE_2D, texture);
+                            gl_sys::BindTexture(gl_texture_target(cxtexture.desc.dimension), texture);
                         }
                         else {
                             gl_sys::BindTexture(gl_sys::TEXTURE_2D, 0);
@@ -234,11 +231,27 @@ impl Cx {
     }
     
     pub fn set_default_depth_and_blend_mode() {
+        Self::set_depth_and_blend_mode(&DrawShaderFlags::default());
+    }
+    
+    pub fn set_depth_and_blend_mode(flags: &DrawShaderFlags) {
+        fn gl_factor(factor: BlendFactor) -> u32 {
+            match factor {
+                BlendFactor::Zero => gl_sys::ZERO,
+                BlendFactor::One => gl_sys::ONE,
+                BlendFactor::OneMinusSrcColor => gl_sys::ONE_MINUS_SRC_COLOR,
+                BlendFactor::OneMinusSrcAlpha => gl_sys::ONE_MINUS_SRC_ALPHA,
+                BlendFactor::DstColor => gl_sys::DST_COLOR,
+            }
+        }
+        let [src_rgb, dst_rgb, src_alpha, dst_alpha] = flags.blend_mode.blend_factors();
         unsafe {
+            // a disabled depth test in GL also stops writes, so always pass instead
             gl_sys::Enable(gl_sys::DEPTH_TEST);
-            gl_sys::DepthFunc(gl_sys::LEQUAL);
+            gl_sys::DepthFunc(if flags.depth_test {gl_sys::LEQUAL} else {gl_sys::ALWAYS});
+            gl_sys::DepthMask(if flags.depth_write {gl_sys::TRUE} else {gl_sys::FALSE});
             gl_sys::BlendEquationSeparate(gl_sys::FUNC_ADD, gl_sys::FUNC_ADD);
-            gl_sys::BlendFuncSeparate(gl_sys::ONE, gl_sys::ONE_MINUS_SRC_ALPHA, gl_sys::ONE, gl_sys::ONE_MINUS_SRC_ALPHA);
+            gl_sys::BlendFuncSeparate(gl_factor(src_rgb), gl_factor(dst_rgb), gl_factor(src_alpha), gl_factor(dst_alpha));
             gl_sys::Enable(gl_sys::BLEND);
         }
     }
@@ -363,6 +376,8 @@ impl Cx {
             gl_sys::Viewport(0, 0, (pass_size.x * dpi_factor) as i32, (pass_size.y * dpi_factor) as i32);
         }
         
+        // the depth clear needs depth writes on, a shader may have left them off
+        Self::set_default_depth_and_blend_mode();
         if clear_flags != 0 {
             unsafe {
                 if clear_flags & gl_sys::DEPTH_BUFFER_BIT != 0 {
@@ -372,7 +387,6 @@ impl Cx {
                 gl_sys::Clear(clear_flags);
             }
         }
-        Self::set_default_depth_and_blend_mode();
         
         let mut zbias = 0.0;
         let zbias_step = self.passes[pass_id].zbias_step;
@@ -384,12 +398,57 @@ impl Cx {
             zbias_step,
         );
         
+        self.read_pass_pixels(pass_id);
+        
         unsafe {
             gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
             //gl_sys::Finish();
         }
     }
     
+    // reads back the color textures of the bound pass framebuffer that have read_pixels requests
+    fn read_pass_pixels(&mut self, pass_id: PassId) {
+        for color_texture in self.passes[pass_id].color_textures.clone() {
+            let cxtexture = &self.textures[color_texture.texture_id];
+            if cxtexture.read_pixels.len() == 0 {
+                continue
+            }
+            // float render targets aren't allocated by this backend, see update_platform_render_target
+            if let TextureFormat::RenderBGRAf16 | TextureFormat::RenderBGRAf32 = cxtexture.desc.format {
+                let err = format!("Can't read back a {:?} render target with OpenGL", cxtexture.desc.format);
+                self.texture_pixels_ready(color_texture.texture_id, Err(err));
+                continue
+            }
+            let gl_texture = if let Some(gl_texture) = cxtexture.os.gl_texture {gl_texture} else {
+                self.texture_pixels_ready(color_texture.texture_id, Err("Texture was never rendered to".to_string()));
+                continue
+            };
+            let width = cxtexture.os.width as usize;
+            let height = cxtexture.os.height as usize;
+            let mut rgba = vec![0u8; width * height * 4];
+            let error = unsafe {
+                // errors of earlier calls would be taken for a failed read
+                gl_sys::GetError();
+                // es2 can only read attachment 0, so each texture takes a turn there
+                gl_sys::FramebufferTexture2D(gl_sys::FRAMEBUFFER, gl_sys::COLOR_ATTACHMENT0, gl_sys::TEXTURE_2D, gl_texture, 0);
+                gl_sys::ReadPixels(0, 0, width as i32, height as i32, gl_sys::RGBA, gl_sys::UNSIGNED_BYTE, rgba.as_mut_ptr() as *mut _);
+                gl_sys::GetError()
+            };
+            if error != gl_sys::NO_ERROR {
+                self.texture_pixels_ready(color_texture.texture_id, Err(format!("glReadPixels failed with error 0x{:x}", error)));
+                continue
+            }
+            // gl rows start at the bottom
+            let mut data = Vec::with_capacity(width * height);
+            for row in rgba.chunks_exact(width * 4).rev() {
+                for p in row.chunks_exact(4) {
+                    data.push((p[3] as u32) << 24 | (p[0] as u32) << 16 | (p[1] as u32) << 8 | p[2] as u32);
+                }
+            }
+            self.texture_pixels_ready(color_texture.texture_id, Ok(TexturePixels {width, height, data}));
+        }
+    }
+    
     pub fn opengl_compile_shaders(&mut self) {
         //let p = profile_start();
         for draw_shader_ptr in &self.draw_shaders.compile_set {
@@ -680,6 +739,7 @@ impl CxOsDrawShader {
         
         let vertex = format!("
             #version 100
+            #extension GL_OES_texture_3D : enable
             precision highp float;
             precision highp int;
             vec4 sample2d(sampler2D sampler, vec2 pos){{return texture2D(sampler, vec2(pos.x, pos.y)).zyxw;}} 
@@ -692,6 +752,7 @@ impl CxOsDrawShader {
         let pixel = format!("
             #version 100
             #extension GL_OES_standard_derivatives : enable
+            #extension GL_OES_texture_3D : enable
             precision highp float;
             precision highp int;
             vec4 sample2d(sampler2D sampler, vec2 pos){{return texture2D(sampler, vec2(pos.x, pos.y)).zyxw;}}
@@ -788,53 +849,174 @@ impl CxOsDrawCall {
     }    
 }
 
-#[derive(Default, Clone)]
-pub struct CxOsTexture {
-    pub alloc_desc: TextureDesc,
-    pub width: u64,
-    pub height: u64,
-    pub gl_texture: Option<u32>,
-    pub gl_renderbuffer: Option<u32>
+fn gl_texture_target(dimension: TextureDimension) -> u32 {
+    match dimension {
+        TextureDimension::D2 => gl_sys::TEXTURE_2D,
+        TextureDimension::D3 => gl_sys::TEXTURE_3D,
+        TextureDimension::Cube => gl_sys::TEXTURE_CUBE_MAP,
+    }
 }
 
-impl CxOsTexture {
-    
-    pub fn update_platform_texture_image2d(&mut self, width: u32, height: u32, image_u32: &Vec<u32>) {
-        
-        if image_u32.len() != (width * height) as usize {
-            log!("update_platform_texture_image2d with wrong buffer_u32 size! {} {} {}", image_u32.len(), width, height);
+impl CxTexture {
+    fn update_platform_texture_image(&mut self) {
+        if !self.image_is_complete() {
             return;
         }
         
-        if self.gl_texture.is_none() {
+        if self.os.gl_texture.is_none() {
             unsafe {
                 let mut gl_texture = std::mem::MaybeUninit::uninit();
                 gl_sys::GenTextures(1, gl_texture.as_mut_ptr());
-                self.gl_texture = Some(gl_texture.assume_init());
+                self.os.gl_texture = Some(gl_texture.assume_init());
             }
         }
+        let desc = self.desc;
+        let caps = GlCaps::get();
+        // es2 only has 2D and cube rgba images, without swizzles or texImage3D
+        if !caps.es3 && (desc.dimension == TextureDimension::D3 || !matches!(desc.format, TextureFormat::Default | TextureFormat::ImageBGRA)) {
+            error!("This OpenGL ES 2 context doesn't support {:?} {:?} images", desc.dimension, desc.format);
+            return
+        }
+        let gl_texture = self.os.gl_texture.unwrap();
+        let width = desc.width.unwrap() as i32;
+        let height = desc.height.unwrap() as i32;
+        // sample2d reads the BGRA images with a .zyxw, so the other formats are
+        // swizzled the opposite way to come out as rgba in the shader
+        let (internal_format, format, ty, swizzle) = match desc.format {
+            TextureFormat::ImageR8 => (gl_sys::R8, gl_sys::RED, gl_sys::UNSIGNED_BYTE, [gl_sys::ZERO, gl_sys::ZERO, gl_sys::RED, gl_sys::ONE]),
+            TextureFormat::ImageRf32 => (gl_sys::R32F, gl_sys::RED, gl_sys::FLOAT, [gl_sys::ZERO, gl_sys::ZERO, gl_sys::RED, gl_sys::ONE]),
+            TextureFormat::ImageRGBAf16 => (gl_sys::RGBA16F, gl_sys::RGBA, gl_sys::HALF_FLOAT, [gl_sys::BLUE, gl_sys::GREEN, gl_sys::RED, gl_sys::ALPHA]),
+            _ => (gl_sys::RGBA, gl_sys::RGBA, gl_sys::UNSIGNED_BYTE, [gl_sys::RED, gl_sys::GREEN, gl_sys::BLUE, gl_sys::ALPHA]),
+        };
+        let target = gl_texture_target(desc.dimension);
+        let data = self.image_bytes();
+        // an unfilterable texture with a linear filter is incomplete and samples as black, and
+        // generating mipmaps needs the format to be renderable as well
+        let (filterable, renderable) = match desc.format {
+            TextureFormat::ImageRf32 => (caps.float_linear, caps.float_render),
+            TextureFormat::ImageRGBAf16 => (true, caps.half_float_render),
+            _ => (true, true)
+        };
+        let mipmaps = desc.mipmaps && filterable && renderable;
         unsafe {
-            gl_sys::BindTexture(gl_sys::TEXTURE_2D, self.gl_texture.unwrap());
-            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_MIN_FILTER, gl_sys::LINEAR_MIPMAP_LINEAR as i32);            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_MAG_FILTER, gl_sys::NEAREST as i32);
-            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_WRAP_S, gl_sys::CLAMP_TO_EDGE as i32);
-            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_WRAP_T, gl_sys::CLAMP_TO_EDGE as i32);
-            gl_sys::TexImage2D(
-                gl_sys::TEXTURE_2D,
-                0,
-                gl_sys::RGBA as i32,
-                width as i32,
-                height as i32,
-                0,
-                gl_sys::RGBA,
-                gl_sys::UNSIGNED_BYTE,
-                image_u32.as_ptr() as *const _
-            );
-            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_BASE_LEVEL, 0);
-            gl_sys::TexParameteri(gl_sys::TEXTURE_2D, gl_sys::TEXTURE_MAX_LEVEL, 3);
-            gl_sys::GenerateMipmap(gl_sys::TEXTURE_2D);  
-            gl_sys::BindTexture(gl_sys::TEXTURE_2D, 0);
+            gl_sys::BindTexture(target, gl_texture);
+            let min_filter = if !filterable {gl_sys::NEAREST} else if mipmaps {gl_sys::LINEAR_MIPMAP_LINEAR} else {gl_sys::LINEAR};
+            // LUTs and cube maps blend between texels, images keep their pixels sharp
+            let mag_filter = if filterable && desc.dimension != TextureDimension::D2 {gl_sys::LINEAR} else {gl_sys::NEAREST};
+            gl_sys::TexParameteri(target, gl_sys::TEXTURE_MIN_FILTER, min_filter as i32);
+            gl_sys::TexParameteri(target, gl_sys::TEXTURE_MAG_FILTER, mag_filter as i32);
+            gl_sys::TexParameteri(target, gl_sys::TEXTURE_WRAP_S, gl_sys::CLAMP_TO_EDGE as i32);
+            gl_sys::TexParameteri(target, gl_sys::TEXTURE_WRAP_T, gl_sys::CLAMP_TO_EDGE as i32);
+            if caps.es3 {
+                gl_sys::TexParameteri(target, gl_sys::TEXTURE_WRAP_R, gl_sys::CLAMP_TO_EDGE as i32);
+                let swizzle_params = [gl_sys::TEXTURE_SWIZZLE_R, gl_sys::TEXTURE_SWIZZLE_G, gl_sys::TEXTURE_SWIZZLE_B, gl_sys::TEXTURE_SWIZZLE_A];
+                for (param, swizzle) in swizzle_params.iter().zip(swizzle.iter()) {
+                    gl_sys::TexParameteri(target, *param, *swizzle as i32);
+                }
+            }
+            // rows of single byte texels aren't 4 byte aligned
+            gl_sys::PixelStorei(gl_sys::UNPACK_ALIGNMENT, 1);
+            match desc.dimension {
+                TextureDimension::D2 => gl_sys::TexImage2D(
+                    target,
+                    0,
+                    internal_format as i32,
+                    width,
+                    height,
+                    0,
+                    format,
+                    ty,
+                    data.as_ptr() as *const _
+                ),
+                TextureDimension::D3 => gl_sys::TexImage3D(
+                    target,
+                    0,
+                    internal_format as i32,
+                    width,
+                    height,
+                    desc.depth.unwrap_or(1) as i32,
+                    0,
+                    format,
+                    ty,
+                    data.as_ptr() as *const _
+                ),
+                TextureDimension::Cube => {
+                    for (face, face_data) in data.chunks_exact(data.len() / 6).enumerate() {
+                        gl_sys::TexImage2D(
+                            gl_sys::TEXTURE_CUBE_MAP_POSITIVE_X + face as u32,
+                            0,
+                            internal_format as i32,
+                            width,
+                            height,
+                            0,
+                            format,
+                            ty,
+                            face_data.as_ptr() as *const _
+                        );
+                    }
+                }
+            }
+            gl_sys::PixelStorei(gl_sys::UNPACK_ALIGNMENT, 4);
+            // es2 has no max level, it samples the mips only with a mipmap min filter
+            if caps.es3 {
+                gl_sys::TexParameteri(target, gl_sys::TEXTURE_BASE_LEVEL, 0);
+                gl_sys::TexParameteri(target, gl_sys::TEXTURE_MAX_LEVEL, if mipmaps {1000} else {0});
+            }
+            if mipmaps {
+                gl_sys::GenerateMipmap(target);
+            }
+            gl_sys::BindTexture(target, 0);
         }
     }
+}
+
+// what the current context supports past ES 2. The x11 context is ES 3, the direct and
+// wayland backends can end up with ES 2 when the driver has nothing newer
+#[derive(Clone, Copy)]
+pub struct GlCaps {
+    pub es3: bool,
+    // R32F can be sampled with a linear filter
+    pub float_linear: bool,
+    // float and half float textures can be rendered to
+    pub float_render: bool,
+    pub half_float_render: bool,
+}
+
+impl GlCaps {
+    // the contexts of a process all come from one driver, so the first one that asks decides
+    pub fn get() -> GlCaps {
+        static CAPS: std::sync::OnceLock<GlCaps> = std::sync::OnceLock::new();
+        *CAPS.get_or_init( || unsafe {
+            let string = | name | {
+                let ptr = gl_sys::GetString(name);
+                if ptr.is_null() {String::new()} else {CStr::from_ptr(ptr as *const _).to_string_lossy().to_string()}
+            };
+            // "OpenGL ES 3.2 Mesa ..." for es, "4.6 ..." for desktop gl which has all of these
+            let version = string(gl_sys::VERSION);
+            let is_es = version.starts_with("OpenGL ES");
+            let major = version.trim_start_matches("OpenGL ES ").split('.').next().and_then( | major | major.parse::<u32>().ok()).unwrap_or(2);
+            let extensions = string(gl_sys::EXTENSIONS);
+            let has = | extension: &str | !is_es || extensions.split_whitespace().any( | e | e == extension);
+            GlCaps {
+                es3: major >= 3,
+                float_linear: has("GL_OES_texture_float_linear"),
+                float_render: has("GL_EXT_color_buffer_float"),
+                half_float_render: has("GL_EXT_color_buffer_float") || has("GL_EXT_color_buffer_half_float"),
+            }
+        })
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct CxOsTexture {
+    pub alloc_desc: TextureDesc,
+    pub width: u64,
+    pub height: u64,
+    pub gl_texture: Option<u32>,
+    pub gl_renderbuffer: Option<u32>
+}
+
+impl CxOsTexture {
     
     pub fn update_platform_render_target(&mut self, desc: &TextureDesc, default_size: DVec2, is_depth: bool) -> bool {
         let width = desc.width.unwrap_or(default_size.x as usize) as u64;
@@ -0,0 +1,747 @@
+use {
+    crate::{
+        makepad_live_id::*,
+        makepad_shader_compiler::{
+            ShaderTy,
+            DrawShaderFlags,
+            BlendFactor,
+            shader_ast::{Ident, DrawShaderFieldKind, ValuePtr, TyLit},
+            interpret::{Interpreter, InterpNum, InterpValue, InterpSampler, LANES, ALL_LANES},
+        },
+        makepad_live_tokenizer::{LiveErrorOrigin, live_error_origin},
+        makepad_live_compiler::{LiveError, LiveDiagnostic, LiveDiagnosticStage, LiveSeverity},
+        makepad_math::{Mat4, Vec4},
+        cx::Cx,
+        texture::{CxTexture, TextureFormat, TexturePixels},
+        pass::{PassClearColor, PassClearDepth, PassId, CxPassParent},
+        draw_list::DrawListId,
+        window::WindowId,
+    },
+};
+
+// A CPU renderer that runs the draw shaders through the shader interpreter.
+// It needs no GPU or display server, so CI can render real frames with it.
+
+#[derive(Default)]
+pub struct SoftFramebuffer {
+    pub width: usize,
+    pub height: usize,
+    // same packing as CxTexture image_u32, 0xAARRGGBB, top row first
+    pub color: Vec<u32>,
+    pub depth: Vec<f32>,
+}
+
+impl SoftFramebuffer {
+    // returns true if the buffers were (re)allocated
+    pub fn resize(&mut self, width: usize, height: usize) -> bool {
+        if self.width == width && self.height == height && self.color.len() == width * height {
+            return false
+        }
+        self.width = width;
+        self.height = height;
+        self.color = vec![0; width * height];
+        self.depth = vec![1.0; width * height];
+        true
+    }
+
+    pub fn clear_color(&mut self, color: Vec4) {
+        let color = pack_color([color.x, color.y, color.z, color.w]);
+        self.color.iter_mut().for_each( | v | *v = color);
+    }
+
+    pub fn clear_depth(&mut self, depth: f32) {
+        self.depth.iter_mut().for_each( | v | *v = depth);
+    }
+}
+
+fn pack_color(c: [f32; 4]) -> u32 {
+    let q = | v: f32 | (v.max(0.0).min(1.0) * 255.0 + 0.5) as u32;
+    (q(c[3]) << 24) | (q(c[0]) << 16) | (q(c[1]) << 8) | q(c[2])
+}
+
+fn unpack_color(c: u32) -> [f32; 4] {
+    [
+        ((c >> 16) & 0xff) as f32 / 255.0,
+        ((c >> 8) & 0xff) as f32 / 255.0,
+        (c & 0xff) as f32 / 255.0,
+        ((c >> 24) & 0xff) as f32 / 255.0,
+    ]
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 != 0 {-1.0} else {1.0};
+    let exp = ((half >> 10) & 0x1f) as i32;
+    let frac = (half & 0x3ff) as f32;
+    sign * match exp {
+        0 => frac * 2f32.powi(-24),
+        31 => if frac == 0.0 {f32::INFINITY} else {f32::NAN},
+        _ => (1.0 + frac / 1024.0) * 2f32.powi(exp - 15)
+    }
+}
+
+// the number of texels in whichever image the texture format uses
+fn image_texels(texture: &CxTexture) -> usize {
+    match texture.desc.format {
+        TextureFormat::ImageR8 => texture.image_u8.len(),
+        TextureFormat::ImageRf32 => texture.image_f32.len(),
+        TextureFormat::ImageRGBAf16 => texture.image_f16.len() / 4,
+        _ => texture.image_u32.len()
+    }
+}
+
+// single channel formats read as (r, 0, 0, 1) like they do on the GPU
+fn texel_rgba(texture: &CxTexture, index: usize) -> [f32; 4] {
+    match texture.desc.format {
+        TextureFormat::ImageR8 => [texture.image_u8[index] as f32 / 255.0, 0.0, 0.0, 1.0],
+        TextureFormat::ImageRf32 => [texture.image_f32[index], 0.0, 0.0, 1.0],
+        TextureFormat::ImageRGBAf16 => std::array::from_fn( | i | f16_to_f32(texture.image_f16[index * 4 + i])),
+        _ => unpack_color(texture.image_u32[index])
+    }
+}
+
+fn bilinear(x: f32, y: f32, texel: impl Fn(isize, isize) -> [f32; 4]) -> [f32; 4] {
+    let (x, y) = (x - 0.5, y - 0.5);
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (x0, y0) = (x0 as isize, y0 as isize);
+    let (a, b, c, d) = (texel(x0, y0), texel(x0 + 1, y0), texel(x0, y0 + 1), texel(x0 + 1, y0 + 1));
+    std::array::from_fn( | i | (a[i] * (1.0 - fx) + b[i] * fx) * (1.0 - fy) + (c[i] * (1.0 - fx) + d[i] * fx) * fy)
+}
+
+struct SoftSampler<'a> {
+    textures: Vec<Option<&'a CxTexture >>,
+}
+
+impl<'a> InterpSampler for SoftSampler<'a> {
+    fn sample_2d(&self, texture: usize, pos: [[f32; 2]; LANES], _is_render_target: bool) -> [[f32; 4]; LANES] {
+        let mut out = [[0.0; 4]; LANES];
+        let texture = if let Some(Some(texture)) = self.textures.get(texture) {texture} else {return out};
+        let (width, height) = if let (Some(width), Some(height)) = (texture.desc.width, texture.desc.height) {
+            (width, height)
+        }
+        else {
+            (texture.os.width as usize, texture.os.height as usize)
+        };
+        if width == 0 || height == 0 || image_texels(texture) < width * height {
+            return out
+        }
+        let texel = | x: isize, y: isize | {
+            let x = x.max(0).min(width as isize - 1) as usize;
+            let y = y.max(0).min(height as isize - 1) as usize;
+            texel_rgba(texture, y * width + x)
+        };
+        // like the gl texture parameters: render targets and magnified images sample nearest
+        let footprint = (pos[1][0] - pos[0][0]).abs().max((pos[2][0] - pos[0][0]).abs()) * width as f32;
+        let footprint = footprint.max((pos[1][1] - pos[0][1]).abs().max((pos[2][1] - pos[0][1]).abs()) * height as f32);
+        let nearest = match texture.desc.format {
+            TextureFormat::RenderBGRA | TextureFormat::RenderBGRAf16 | TextureFormat::RenderBGRAf32 => true,
+            _ => footprint <= 1.0
+        };
+        for lane in 0..LANES {
+            let x = pos[lane][0] * width as f32;
+            let y = pos[lane][1] * height as f32;
+            if nearest {
+                out[lane] = texel(x.floor() as isize, y.floor() as isize);
+            }
+            else {
+                out[lane] = bilinear(x, y, &texel);
+            }
+        }
+        out
+    }
+    
+    // LUTs sample linearly, between the slices as well
+    fn sample_3d(&self, texture: usize, pos: [[f32; 3]; LANES]) -> [[f32; 4]; LANES] {
+        let mut out = [[0.0; 4]; LANES];
+        let texture = if let Some(Some(texture)) = self.textures.get(texture) {texture} else {return out};
+        let size = [texture.desc.width.unwrap_or(0), texture.desc.height.unwrap_or(0), texture.desc.depth.unwrap_or(1)];
+        if size.contains(&0) || image_texels(texture) < size[0] * size[1] * size[2] {
+            return out
+        }
+        let clamp = | v: isize, axis: usize | v.max(0).min(size[axis] as isize - 1) as usize;
+        for lane in 0..LANES {
+            let z = pos[lane][2] * size[2] as f32 - 0.5;
+            let z0 = z.floor();
+            let fz = z - z0;
+            let slice = | z: isize | {
+                let z = clamp(z, 2);
+                bilinear(pos[lane][0] * size[0] as f32, pos[lane][1] * size[1] as f32, | x, y | {
+                    texel_rgba(texture, (z * size[1] + clamp(y, 1)) * size[0] + clamp(x, 0))
+                })
+            };
+            let (a, b) = (slice(z0 as isize), slice(z0 as isize + 1));
+            out[lane] = std::array::from_fn( | i | a[i] * (1.0 - fz) + b[i] * fz);
+        }
+        out
+    }
+    
+    fn sample_cube(&self, texture: usize, dir: [[f32; 3]; LANES]) -> [[f32; 4]; LANES] {
+        let mut out = [[0.0; 4]; LANES];
+        let texture = if let Some(Some(texture)) = self.textures.get(texture) {texture} else {return out};
+        let (width, height) = (texture.desc.width.unwrap_or(0), texture.desc.height.unwrap_or(0));
+        if width == 0 || height == 0 || image_texels(texture) < width * height * 6 {
+            return out
+        }
+        for lane in 0..LANES {
+            let [x, y, z] = dir[lane];
+            // the face and its (s, t) per the GL cube map table
+            let (face, s, t, major) = if x.abs() >= y.abs() && x.abs() >= z.abs() {
+                if x >= 0.0 {(0, -z, -y, x)} else {(1, z, -y, x)}
+            }
+            else if y.abs() >= z.abs() {
+                if y >= 0.0 {(2, x, z, y)} else {(3, x, -z, y)}
+            }
+            else if z >= 0.0 {(4, x, -y, z)} else {(5, -x, -y, z)};
+            if major == 0.0 {
+                continue;
+            }
+            let u = (s / major.abs() + 1.0) * 0.5;
+            let v = (t / major.abs() + 1.0) * 0.5;
+            out[lane] = bilinear(u * width as f32, v * height as f32, | x, y | {
+                let x = x.max(0).min(width as isize - 1) as usize;
+                let y = y.max(0).min(height as isize - 1) as usize;
+                texel_rgba(texture, (face * height + y) * width + x)
+            });
+        }
+        out
+    }
+}
+
+#[derive(Clone)]
+struct SoftVertex {
+    clip: [f32; 4],
+    values: Vec<f32>,
+}
+
+impl SoftVertex {
+    fn lerp(&self, other: &SoftVertex, t: f32) -> SoftVertex {
+        let mut clip = [0.0; 4];
+        for i in 0..4 {
+            clip[i] = self.clip[i] + (other.clip[i] - self.clip[i]) * t;
+        }
+        SoftVertex {
+            clip,
+            values: self.values.iter().zip(other.values.iter()).map( | (a, b) | a + (b - a) * t).collect()
+        }
+    }
+}
+
+// clips a polygon against the near and far planes and w > 0
+fn clip_polygon(mut poly: Vec<SoftVertex>) -> Vec<SoftVertex> {
+    let planes: [fn(&[f32; 4]) -> f32; 3] = [
+        | c | c[3] + c[2],
+        | c | c[3] - c[2],
+        | c | c[3] - 1e-6,
+    ];
+    for plane in &planes {
+        if poly.iter().all( | v | plane(&v.clip) >= 0.0) {
+            continue;
+        }
+        let mut out = Vec::new();
+        for i in 0..poly.len() {
+            let a = &poly[i];
+            let b = &poly[(i + 1) % poly.len()];
+            let (da, db) = (plane(&a.clip), plane(&b.clip));
+            if da >= 0.0 {
+                out.push(a.clone());
+            }
+            if (da >= 0.0) != (db >= 0.0) {
+                out.push(a.lerp(b, da / (da - db)));
+            }
+        }
+        poly = out;
+        if poly.len() < 3 {
+            break;
+        }
+    }
+    poly
+}
+
+struct SoftVarying {
+    field: usize,
+    ty_lit: TyLit,
+    offset: usize,
+    slots: usize,
+}
+
+struct SoftInput {
+    field: usize,
+    ty: ShaderTy,
+    offset: usize,
+}
+
+impl Cx {
+
+    pub fn soft_compile_shaders(&mut self) {
+        // the interpreter works straight off the analysed shader defs
+        self.draw_shaders.compile_set.clear();
+    }
+
+    // renders every dirty pass, window passes go into the framebuffer for their window
+    pub fn soft_repaint(&mut self, framebuffers: &mut Vec<(WindowId, SoftFramebuffer)>) {
+        let mut passes_todo = Vec::new();
+        self.compute_pass_repaint_order(&mut passes_todo);
+        self.repaint_id += 1;
+        for pass_id in &passes_todo {
+            match self.passes[*pass_id].parent.clone() {
+                CxPassParent::Window(window_id) => {
+                    let index = if let Some(index) = framebuffers.iter().position( | (id, _) | *id == window_id) {
+                        index
+                    }
+                    else {
+                        framebuffers.push((window_id, SoftFramebuffer::default()));
+                        framebuffers.len() - 1
+                    };
+                    self.soft_draw_pass_to_framebuffer(*pass_id, &mut framebuffers[index].1);
+                }
+                CxPassParent::Pass(_) | CxPassParent::None => {
+                    self.soft_draw_pass_to_texture(*pass_id);
+                }
+            }
+        }
+    }
+
+    pub fn soft_draw_pass_to_framebuffer(&mut self, pass_id: PassId, framebuffer: &mut SoftFramebuffer) {
+        let draw_list_id = self.passes[pass_id].main_draw_list_id.unwrap();
+        let pass_size = if let Some(pass_size) = self.setup_render_pass(pass_id) {
+            pass_size
+        }
+        else {
+            return
+        };
+        let dpi_factor = self.passes[pass_id].dpi_factor.unwrap();
+        let pass_size = pass_size * dpi_factor;
+        framebuffer.resize(pass_size.x.floor() as usize, pass_size.y.floor() as usize);
+
+        let pass = &self.passes[pass_id];
+        if !pass.dont_clear {
+            let clear_color = if pass.color_textures.len() == 0 {
+                pass.clear_color
+            }
+            else {
+                match pass.color_textures[0].clear_color {
+                    PassClearColor::InitWith(color) => color,
+                    PassClearColor::ClearWith(color) => color
+                }
+            };
+            let clear_depth = match pass.clear_depth {
+                PassClearDepth::InitWith(depth) => depth,
+                PassClearDepth::ClearWith(depth) => depth
+            };
+            framebuffer.clear_color(clear_color);
+            framebuffer.clear_depth(clear_depth);
+        }
+        self.soft_render_view(pass_id, draw_list_id, framebuffer, true);
+    }
+
+    pub fn soft_draw_pass_to_texture(&mut self, pass_id: PassId) {
+        let draw_list_id = self.passes[pass_id].main_draw_list_id.unwrap();
+        let pass_size = if let Some(pass_size) = self.setup_render_pass(pass_id) {
+            pass_size
+        }
+        else {
+            return
+        };
+        let dpi_factor = self.passes[pass_id].dpi_factor.unwrap();
+        let pass_size = pass_size * dpi_factor;
+
+        let color_texture = self.passes[pass_id].color_textures.get(0).cloned();
+        let depth_texture_id = self.passes[pass_id].depth_texture;
+
+        // move the texture images into a framebuffer while we render
+        let mut framebuffer = SoftFramebuffer::default();
+        if let Some(color_texture) = &color_texture {
+            let cxtexture = &mut self.textures[color_texture.texture_id];
+            let width = cxtexture.desc.width.unwrap_or(pass_size.x as usize);
+            let height = cxtexture.desc.height.unwrap_or(pass_size.y as usize);
+            let is_new = cxtexture.os.width as usize != width || cxtexture.os.height as usize != height || cxtexture.image_u32.len() != width * height;
+            framebuffer.width = width;
+            framebuffer.height = height;
+            framebuffer.color = std::mem::take(&mut cxtexture.image_u32);
+            framebuffer.color.resize(width * height, 0);
+            cxtexture.os.width = width as u64;
+            cxtexture.os.height = height as u64;
+            cxtexture.os.alloc_desc = cxtexture.desc.clone();
+            match color_texture.clear_color {
+                PassClearColor::InitWith(color) => if is_new {
+                    framebuffer.clear_color(color);
+                },
+                PassClearColor::ClearWith(color) => {
+                    framebuffer.clear_color(color);
+                }
+            }
+        }
+        else {
+            framebuffer.resize(pass_size.x as usize, pass_size.y as usize);
+        }
+
+        framebuffer.depth = vec![1.0; framebuffer.width * framebuffer.height];
+        if let Some(depth_texture_id) = depth_texture_id {
+            let cxtexture = &mut self.textures[depth_texture_id];
+            let is_new = cxtexture.os.width as usize != framebuffer.width || cxtexture.os.height as usize != framebuffer.height || cxtexture.image_u32.len() != framebuffer.depth.len();
+            cxtexture.os.width = framebuffer.width as u64;
+            cxtexture.os.height = framebuffer.height as u64;
+            if !is_new {
+                // depth is kept as f32 bits in the depth texture
+                for (d, v) in framebuffer.depth.iter_mut().zip(cxtexture.image_u32.iter()) {
+                    *d = f32::from_bits(*v);
+                }
+            }
+            match self.passes[pass_id].clear_depth {
+                PassClearDepth::InitWith(depth) => if is_new {
+                    framebuffer.clear_depth(depth);
+                },
+                PassClearDepth::ClearWith(depth) => {
+                    framebuffer.clear_depth(depth);
+                }
+            }
+        }
+
+        self.soft_render_view(pass_id, draw_list_id, &mut framebuffer, depth_texture_id.is_some());
+
+        if let Some(depth_texture_id) = depth_texture_id {
+            self.textures[depth_texture_id].image_u32 = framebuffer.depth.iter().map( | d | d.to_bits()).collect();
+        }
+        if let Some(color_texture) = color_texture {
+            if self.textures[color_texture.texture_id].read_pixels.len() != 0 {
+                let pixels = TexturePixels {
+                    width: framebuffer.width,
+                    height: framebuffer.height,
+                    data: framebuffer.color.clone(),
+                };
+                self.texture_pixels_ready(color_texture.texture_id, Ok(pixels));
+            }
+            self.textures[color_texture.texture_id].image_u32 = framebuffer.color;
+        }
+    }
+
+    fn soft_collect_draw_items(
+        &mut self,
+        draw_list_id: DrawListId,
+        zbias: &mut f32,
+        zbias_step: f32,
+        draw_items: &mut Vec<(DrawListId, usize)>
+    ) {
+        let draw_items_len = self.draw_lists[draw_list_id].draw_items.len();
+        self.draw_lists[draw_list_id].uniform_view_transform(&Mat4::identity());
+        for draw_item_id in 0..draw_items_len {
+            if let Some(sub_list_id) = self.draw_lists[draw_list_id].draw_items[draw_item_id].kind.sub_list() {
+                self.soft_collect_draw_items(sub_list_id, zbias, zbias_step, draw_items);
+            }
+            else {
+                let draw_item = &mut self.draw_lists[draw_list_id].draw_items[draw_item_id];
+                if let Some(draw_call) = draw_item.kind.draw_call_mut() {
+                    draw_call.draw_uniforms.set_zbias(*zbias);
+                    *zbias += zbias_step;
+                    draw_items.push((draw_list_id, draw_item_id));
+                }
+            }
+        }
+    }
+
+    fn soft_render_view(&mut self, pass_id: PassId, draw_list_id: DrawListId, framebuffer: &mut SoftFramebuffer, depth_test: bool) {
+        let mut zbias = 0.0;
+        let zbias_step = self.passes[pass_id].zbias_step;
+        let mut draw_items = Vec::new();
+        self.soft_collect_draw_items(draw_list_id, &mut zbias, zbias_step, &mut draw_items);
+        for (draw_list_id, draw_item_id) in draw_items {
+            if let Err(message) = self.soft_draw_item(pass_id, draw_list_id, draw_item_id, framebuffer, depth_test) {
+                self.soft_shader_error(draw_list_id, draw_item_id, message);
+            }
+        }
+    }
+
+    // a shader that fails stops its draw call, the error goes where shader compile errors go
+    fn soft_shader_error(&mut self, draw_list_id: DrawListId, draw_item_id: usize, message: String) {
+        let draw_call = self.draw_lists[draw_list_id].draw_items[draw_item_id].kind.draw_call().unwrap();
+        let draw_shader = &draw_call.draw_shader;
+        let message = format!("{} {}", self.draw_shaders.shaders[draw_shader.draw_shader_id].type_name, message);
+        let live_registry = self.live_registry.borrow();
+        let err = if let Some(token_id) = live_registry.ptr_to_node(draw_shader.draw_shader_ptr.0).origin.token_id() {
+            live_registry.live_error_to_live_file_error(LiveError {
+                origin: live_error_origin!(),
+                span: token_id.into(),
+                message
+            })
+        }
+        else {
+            crate::error!("Software renderer: {}", message);
+            return
+        };
+        if let Some(diagnostics) = &mut self.live_diagnostics {
+            diagnostics.push(LiveDiagnostic::from_live_file_error(LiveSeverity::Error, LiveDiagnosticStage::Shader, err));
+        }
+        else {
+            crate::error!("Software renderer: {}", err);
+        }
+    }
+
+    fn soft_draw_item(&self, pass_id: PassId, draw_list_id: DrawListId, draw_item_id: usize, framebuffer: &mut SoftFramebuffer, depth_test: bool) -> Result<(), String> {
+        let draw_list = &self.draw_lists[draw_list_id];
+        let draw_item = &draw_list.draw_items[draw_item_id];
+        let draw_call = if let Some(draw_call) = draw_item.kind.draw_call() {draw_call} else {return Ok(())};
+        let sh = &self.draw_shaders.shaders[draw_call.draw_shader.draw_shader_id];
+        let mapping = &sh.mapping;
+        let draw_shader_def = if let Some(def) = self.shader_registry.draw_shader_defs.get(&draw_call.draw_shader.draw_shader_ptr) {
+            def
+        }
+        else {
+            return Ok(())
+        };
+        let instances = if let Some(instances) = &draw_item.instances {instances} else {return Ok(())};
+        let geometry = if let Some(geometry_id) = draw_call.geometry_id {&self.geometries[geometry_id]} else {return Ok(())};
+        let instance_slots = mapping.instances.total_slots;
+        let geometry_slots = mapping.geometries.total_slots;
+        if instance_slots == 0 || geometry_slots == 0 || instances.len() < instance_slots {
+            return Ok(())
+        }
+
+        let sampler = SoftSampler {
+            textures: (0..mapping.textures.len()).map( | i | {
+                draw_call.texture_slots.get(i).cloned().flatten().map( | texture_id | &self.textures[texture_id])
+            }).collect()
+        };
+        let mut interp = Interpreter::new(&self.shader_registry, draw_shader_def, &sampler);
+        interp.const_table = Some(&mapping.const_table);
+
+        // uniforms and live values stay the same for the whole draw call
+        let pass_uniforms = self.passes[pass_id].pass_uniforms.as_slice();
+        let view_uniforms = draw_list.draw_list_uniforms.as_slice();
+        let draw_uniforms = draw_call.draw_uniforms.as_slice();
+        let mut geometry_inputs = Vec::new();
+        let mut instance_inputs = Vec::new();
+        for (field_index, field) in draw_shader_def.fields.iter().enumerate() {
+            let find = | inputs: &[crate::draw_shader::DrawShaderInput] | {
+                inputs.iter().find( | input | input.id == field.ident.0).map( | input | SoftInput {
+                    field: field_index,
+                    ty: input.ty.clone(),
+                    offset: input.offset
+                })
+            };
+            match &field.kind {
+                DrawShaderFieldKind::Geometry {..} => {
+                    geometry_inputs.extend(find(&mapping.geometries.inputs));
+                }
+                DrawShaderFieldKind::Instance {..} => {
+                    instance_inputs.extend(find(&mapping.instances.inputs));
+                }
+                DrawShaderFieldKind::Uniform {block_ident, ..} => {
+                    let (inputs, data): (_, &[f32]) = match block_ident.0 {
+                        live_id!(pass) => (&mapping.pass_uniforms, pass_uniforms),
+                        live_id!(view) => (&mapping.view_uniforms, view_uniforms),
+                        live_id!(draw) => (&mapping.draw_uniforms, draw_uniforms),
+                        live_id!(user) => (&mapping.user_uniforms, &draw_call.user_uniforms),
+                        _ => continue
+                    };
+                    if let Some(input) = find(&inputs.inputs) {
+                        interp.fields[field_index] = InterpValue::from_slots(&input.ty, data.get(input.offset..).unwrap_or(&[]));
+                    }
+                }
+                _ => ()
+            }
+        }
+        for input in &mapping.live_uniforms.inputs {
+            interp.live_values.insert(
+                ValuePtr(input.live_ptr.unwrap()),
+                InterpValue::from_slots(&input.ty, mapping.live_uniforms_buf.get(input.offset..).unwrap_or(&[]))
+            );
+        }
+
+        // everything the vertex shader can hand to the pixel shader gets interpolated
+        let mut varyings = Vec::new();
+        let mut varying_slots = 0;
+        let mut varying_defaults = Vec::new();
+        for (field_index, field) in draw_shader_def.fields.iter().enumerate() {
+            match field.kind {
+                DrawShaderFieldKind::Geometry {..} |
+                DrawShaderFieldKind::Instance {..} |
+                DrawShaderFieldKind::Varying {..} => if let InterpValue::Num(num) = &interp.fields[field_index] {
+                    if let DrawShaderFieldKind::Varying {..} = field.kind {
+                        varying_defaults.push((field_index, interp.fields[field_index].clone()));
+                    }
+                    varyings.push(SoftVarying {
+                        field: field_index,
+                        ty_lit: num.ty_lit,
+                        offset: varying_slots,
+                        slots: num.slots()
+                    });
+                    varying_slots += num.slots();
+                }
+                _ => ()
+            }
+        }
+
+        let vertex_ident = Ident(live_id!(vertex));
+        let pixel_ident = Ident(live_id!(pixel));
+        let vertex_count = geometry.vertices.len() / geometry_slots;
+        if vertex_count == 0 {
+            return Ok(())
+        }
+
+        for instance in instances.chunks_exact(instance_slots) {
+            for input in &instance_inputs {
+                interp.fields[input.field] = InterpValue::from_slots(&input.ty, &instance[input.offset..]);
+            }
+            // run the vertex shader for up to 4 vertices at once
+            let mut vertices = Vec::with_capacity(vertex_count);
+            for base in (0..vertex_count).step_by(LANES) {
+                for input in &geometry_inputs {
+                    let ty_lit = input.ty.maybe_ty_lit().unwrap_or(TyLit::Float);
+                    interp.fields[input.field] = InterpValue::Num(InterpNum::from_fn(ty_lit, | lane, comp | {
+                        let vertex = (base + lane).min(vertex_count - 1);
+                        geometry.vertices.get(vertex * geometry_slots + input.offset + comp).cloned().unwrap_or(0.0)
+                    }));
+                }
+                for (field_index, value) in &varying_defaults {
+                    interp.fields[*field_index] = value.clone();
+                }
+                let pos = match interp.call_method(vertex_ident, ALL_LANES) {
+                    Some(pos) => pos.num(),
+                    None => return Err(method_error(&interp, "vertex"))
+                };
+                for lane in 0..LANES.min(vertex_count - base) {
+                    let mut values = Vec::with_capacity(varying_slots);
+                    for varying in &varyings {
+                        let num = interp.fields[varying.field].num();
+                        values.extend_from_slice(&num.lanes[lane][0..varying.slots]);
+                    }
+                    vertices.push(SoftVertex {clip: pos.lane_vec4(lane), values});
+                }
+            }
+            for tri in geometry.indices.chunks_exact(3) {
+                if tri.iter().any( | i | *i as usize >= vertices.len()) {
+                    continue;
+                }
+                let poly = clip_polygon(tri.iter().map( | i | vertices[*i as usize].clone()).collect());
+                for i in 1..poly.len().saturating_sub(1) {
+                    soft_raster_triangle(&mut interp, pixel_ident, &varyings, [&poly[0], &poly[i], &poly[i + 1]], framebuffer, depth_test, &mapping.flags)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn method_error(interp: &Interpreter, method: &str) -> String {
+    format!("fn {} failed: {}", method, interp.error.as_deref().unwrap_or("it returned no value"))
+}
+
+struct ScreenVertex {
+    x: f32,
+    y: f32,
+    z: f32,
+    inv_w: f32,
+}
+
+fn soft_raster_triangle(
+    interp: &mut Interpreter,
+    pixel_ident: Ident,
+    varyings: &[SoftVarying],
+    mut tri: [&SoftVertex; 3],
+    framebuffer: &mut SoftFramebuffer,
+    has_depth: bool,
+    flags: &DrawShaderFlags,
+) -> Result<(), String> {
+    let (width, height) = (framebuffer.width, framebuffer.height);
+    if width == 0 || height == 0 {
+        return Ok(())
+    }
+    let to_screen = | v: &SoftVertex | {
+        let inv_w = 1.0 / v.clip[3];
+        ScreenVertex {
+            x: (v.clip[0] * inv_w * 0.5 + 0.5) * width as f32,
+            y: (0.5 - v.clip[1] * inv_w * 0.5) * height as f32,
+            z: v.clip[2] * inv_w * 0.5 + 0.5,
+            inv_w
+        }
+    };
+    let edge = | a: &ScreenVertex, b: &ScreenVertex, px: f32, py: f32 | {
+        (px - a.x) * (b.y - a.y) - (py - a.y) * (b.x - a.x)
+    };
+    let mut s = [to_screen(tri[0]), to_screen(tri[1]), to_screen(tri[2])];
+    let mut area = edge(&s[0], &s[1], s[2].x, s[2].y);
+    if area == 0.0 || !area.is_finite() {
+        return Ok(())
+    }
+    if area < 0.0 {
+        s.swap(1, 2);
+        tri.swap(1, 2);
+        area = -area;
+    }
+    // pixel centers exactly on an edge belong to one triangle only
+    let owns_edge = | a: &ScreenVertex, b: &ScreenVertex | {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        dy < 0.0 || (dy == 0.0 && dx > 0.0)
+    };
+    let owned = [owns_edge(&s[1], &s[2]), owns_edge(&s[2], &s[0]), owns_edge(&s[0], &s[1])];
+
+    let min_x = s.iter().map( | v | v.x).fold(f32::MAX, f32::min).floor().max(0.0) as usize & !1;
+    let min_y = s.iter().map( | v | v.y).fold(f32::MAX, f32::min).floor().max(0.0) as usize & !1;
+    let max_x = (s.iter().map( | v | v.x).fold(f32::MIN, f32::max).ceil().max(0.0) as usize).min(width - 1);
+    let max_y = (s.iter().map( | v | v.y).fold(f32::MIN, f32::max).ceil().max(0.0) as usize).min(height - 1);
+
+    for qy in (min_y..=max_y).step_by(2) {
+        for qx in (min_x..=max_x).step_by(2) {
+            let mut covered = [false; LANES];
+            let mut bary = [[0.0f32; 3]; LANES];
+            let mut depth = [0.0f32; LANES];
+            for lane in 0..LANES {
+                let (x, y) = (qx + (lane & 1), qy + (lane >> 1));
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                let e = [edge(&s[1], &s[2], px, py), edge(&s[2], &s[0], px, py), edge(&s[0], &s[1], px, py)];
+                bary[lane] = [e[0] / area, e[1] / area, e[2] / area];
+                depth[lane] = bary[lane][0] * s[0].z + bary[lane][1] * s[1].z + bary[lane][2] * s[2].z;
+                let inside = (0..3).all( | i | e[i] > 0.0 || (e[i] == 0.0 && owned[i]));
+                covered[lane] = inside && x < width && y < height
+                    && (!has_depth || !flags.depth_test || depth[lane] <= framebuffer.depth[y * width + x]);
+            }
+            if !covered.iter().any( | c | *c) {
+                continue;
+            }
+            // perspective correct weights, helper lanes outside the triangle extrapolate
+            let mut weights = [[0.0f32; 3]; LANES];
+            for lane in 0..LANES {
+                let w = [bary[lane][0] * s[0].inv_w, bary[lane][1] * s[1].inv_w, bary[lane][2] * s[2].inv_w];
+                let sum = w[0] + w[1] + w[2];
+                weights[lane] = if sum != 0.0 {[w[0] / sum, w[1] / sum, w[2] / sum]} else {bary[lane]};
+            }
+            for varying in varyings {
+                interp.fields[varying.field] = InterpValue::Num(InterpNum::from_fn(varying.ty_lit, | lane, comp | {
+                    let i = varying.offset + comp;
+                    weights[lane][0] * tri[0].values[i] + weights[lane][1] * tri[1].values[i] + weights[lane][2] * tri[2].values[i]
+                }));
+            }
+            let color = match interp.call_method(pixel_ident, ALL_LANES) {
+                Some(color) => color.num(),
+                None => return Err(method_error(interp, "pixel"))
+            };
+            let factors = flags.blend_mode.blend_factors();
+            for lane in 0..LANES {
+                if !covered[lane] {
+                    continue;
+                }
+                let index = (qy + (lane >> 1)) * width + qx + (lane & 1);
+                let src = color.lane_vec4(lane);
+                let src = [src[0].max(0.0).min(1.0), src[1].max(0.0).min(1.0), src[2].max(0.0).min(1.0), src[3].max(0.0).min(1.0)];
+                let dst = unpack_color(framebuffer.color[index]);
+                let factor = | factor: BlendFactor, i: usize | match factor {
+                    BlendFactor::Zero => 0.0,
+                    BlendFactor::One => 1.0,
+                    BlendFactor::OneMinusSrcColor => 1.0 - src[i],
+                    BlendFactor::OneMinusSrcAlpha => 1.0 - src[3],
+                    BlendFactor::DstColor => dst[i],
+                };
+                let mut out = [0.0; 4];
+                for i in 0..4 {
+                    let (src_factor, dst_factor) = if i < 3 {(factors[0], factors[1])} else {(factors[2], factors[3])};
+                    out[i] = (src[i] * factor(src_factor, i) + dst[i] * factor(dst_factor, i)).min(1.0);
+                }
+                framebuffer.color[index] = pack_color(out);
+                if has_depth && flags.depth_write {
+                    framebuffer.depth[index] = depth[lane];
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use {
+    std::{
+        io,
+        fs::{File, OpenOptions},
+        os::unix::io::AsRawFd,
+        sync::atomic::{AtomicU64, Ordering},
+    },
+    crate::os::linux::libc_sys,
+};
+
+// A framebuffer in /dev/shm shared between studio and a child running in stdin mode.
+// The header holds the sequence number of the last completed frame, followed by two
+// frames of width*height u32 pixels. The child alternates between them so the host
+// can copy out the previous frame while the next one is being written.
+const HEADER_SIZE: usize = 16;
+
+pub struct StdinSharedMemory {
+    pub path: String,
+    pub width: usize,
+    pub height: usize,
+    is_owner: bool,
+    _file: File,
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl StdinSharedMemory {
+    fn byte_len(width: usize, height: usize) -> usize {
+        HEADER_SIZE + width * height * 4 * 2
+    }
+
+    // called by the host, the file is removed again when this is dropped
+    pub fn create(path: &str, width: usize, height: usize) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path) ?;
+        file.set_len(Self::byte_len(width, height) as u64) ?;
+        Self::map(path, file, width, height, true)
+    }
+
+    // called by the child with the path and size the host sent over
+    pub fn open(path: &str, width: usize, height: usize) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path) ?;
+        if file.metadata() ?.len() < Self::byte_len(width, height) as u64 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "shared memory smaller than framebuffer"));
+        }
+        Self::map(path, file, width, height, false)
+    }
+
+    fn map(path: &str, file: File, width: usize, height: usize, is_owner: bool) -> io::Result<Self> {
+        let len = Self::byte_len(width, height);
+        let ptr = unsafe {libc_sys::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc_sys::PROT_READ | libc_sys::PROT_WRITE,
+            libc_sys::MAP_SHARED,
+            file.as_raw_fd(),
+            0
+        )};
+        if ptr == libc_sys::MAP_FAILED {
+            return Err(io::Error::last_os_error())
+        }
+        Ok(Self {
+            path: path.to_string(),
+            width,
+            height,
+            is_owner,
+            _file: file,
+            ptr: ptr as *mut u8,
+            len
+        })
+    }
+
+    fn sequence(&self) -> &AtomicU64 {
+        unsafe {&*(self.ptr as *const AtomicU64)}
+    }
+
+    fn frame_ptr(&self, sequence: u64) -> *mut u8 {
+        unsafe {self.ptr.add(HEADER_SIZE + (sequence & 1) as usize * self.width * self.height * 4)}
+    }
+
+    // hands out the frame that isn't being read, and publishes it after the closure returns
+    pub fn write_frame<F>(&mut self, f: F) where F: FnOnce(*mut u8) {
+        let next = self.sequence().load(Ordering::Acquire) + 1;
+        f(self.frame_ptr(next));
+        self.sequence().store(next, Ordering::Release);
+    }
+
+    // copies out the last completed frame, returns false if the child hasn't written one yet
+    pub fn read_frame(&self, image: &mut Vec<u32>) -> bool {
+        let sequence = self.sequence().load(Ordering::Acquire);
+        if sequence == 0 {
+            return false
+        }
+        let pixels = self.width * self.height;
+        image.resize(pixels, 0);
+        unsafe {std::ptr::copy_nonoverlapping(self.frame_ptr(sequence) as *const u32, image.as_mut_ptr(), pixels)};
+        true
+    }
+}
+
+impl Drop for StdinSharedMemory {
+    fn drop(&mut self) {
+        unsafe {libc_sys::munmap(self.ptr as *mut _, self.len);}
+        if self.is_owner {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use {
+    std::cell::RefCell,
+    std::rc::Rc,
+    std::ptr,
+    self::super::{
+        opengl_wayland::{OpenglWindow, OpenglCx},
+        wayland_event::*,
+        wayland_app::*,
+    },
+    crate::{
+        cx_api::CxOsOp,
+        makepad_math::{dvec2},
+        makepad_live_id::*,
+        thread::Signal,
+        event::Event,
+        pass::CxPassParent,
+        cx::{Cx, OsType, LinuxWindowParams},
+        gpu_info::GpuPerformance,
+        os::cx_native::EventFlow,
+        os::linux::{
+            linux_http::LinuxHttpRequest,
+            linux_websocket::LinuxWebSocket,
+        },
+    }
+};
+
+impl Cx {
+    /// Runs the app on a wayland compositor, returns false when the x11 backend should be used instead.
+    /// MAKEPAD=x11 or MAKEPAD=wayland at runtime overrides the WAYLAND_DISPLAY check.
+    pub fn wayland_event_loop(cx: Rc<RefCell<Cx>>) -> bool {
+        let configs = std::env::var("MAKEPAD").unwrap_or_default();
+        if configs.split('+').any( | c | c == "x11") {
+            return false
+        }
+        if std::env::var("WAYLAND_DISPLAY").is_err() && !configs.split('+').any( | c | c == "wayland") {
+            return false
+        }
+
+        let opengl_cx = Rc::new(RefCell::new(None));
+        let opengl_windows = Rc::new(RefCell::new(Vec::new()));
+        if !init_wayland_app_global(Box::new({
+            let cx = cx.clone();
+            let opengl_cx = opengl_cx.clone();
+            move | wayland_app,
+            event | {
+                let mut cx = cx.borrow_mut();
+                let mut opengl_cx = opengl_cx.borrow_mut();
+                let mut opengl_windows = opengl_windows.borrow_mut();
+                cx.wayland_event_callback(wayland_app, event, opengl_cx.as_mut().unwrap(), &mut *opengl_windows)
+            }
+        })) {
+            return false
+        }
+
+        // without server side decorations the desktop window has to draw its own caption
+        let wayland_app = get_wayland_app_global();
+        cx.borrow_mut().self_ref = Some(cx.clone());
+        cx.borrow_mut().os_type = OsType::LinuxWindow(LinuxWindowParams {
+            custom_window_chrome: wayland_app.decoration_manager == ptr::null_mut()
+        });
+        cx.borrow_mut().gpu_info.performance = GpuPerformance::Tier1;
+
+        *opengl_cx.borrow_mut() = Some(OpenglCx::new(wayland_app.display));
+
+        cx.borrow_mut().call_event_handler(&Event::Construct);
+        cx.borrow_mut().redraw_all();
+        wayland_app.start_timer(0, 0.008, true);
+        wayland_app.event_loop();
+        true
+    }
+
+    fn wayland_event_callback(
+        &mut self,
+        wayland_app: &mut WaylandApp,
+        event: WaylandEvent,
+        opengl_cx: &mut OpenglCx,
+        opengl_windows: &mut Vec<OpenglWindow>
+    ) -> EventFlow {
+        if let EventFlow::Exit = self.handle_wayland_platform_ops(opengl_windows, opengl_cx, wayland_app) {
+            return EventFlow::Exit
+        }
+
+        let mut paint_dirty = false;
+
+        match event {
+            WaylandEvent::AppGotFocus => {
+                for window in opengl_windows.iter_mut() {
+                    if let Some(main_pass_id) = self.windows[window.window_id].main_pass_id {
+                        self.repaint_pass(main_pass_id);
+                    }
+                }
+                paint_dirty = true;
+                self.call_event_handler(&Event::AppGotFocus);
+            }
+            WaylandEvent::AppLostFocus => {
+                self.call_event_handler(&Event::AppLostFocus);
+            }
+            WaylandEvent::WindowGeomChange(re) => {
+                if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == re.window_id) {
+                    window.window_geom = re.new_geom.clone();
+                    self.windows[re.window_id].window_geom = re.new_geom.clone();
+                    if re.old_geom.inner_size != re.new_geom.inner_size || re.old_geom.dpi_factor != re.new_geom.dpi_factor {
+                        if let Some(main_pass_id) = self.windows[re.window_id].main_pass_id {
+                            self.redraw_pass_and_child_passes(main_pass_id);
+                        }
+                    }
+                }
+                self.call_event_handler(&Event::WindowGeomChange(re));
+            }
+            WaylandEvent::WindowClosed(wc) => {
+                let window_id = wc.window_id;
+                self.call_event_handler(&Event::WindowClosed(wc));
+                self.windows[window_id].is_created = false;
+                if let Some(index) = opengl_windows.iter().position( | w | w.window_id == window_id) {
+                    opengl_windows[index].close_window(opengl_cx);
+                    opengl_windows.remove(index);
+                    if opengl_windows.len() == 0 {
+                        wayland_app.terminate_event_loop();
+                        return EventFlow::Exit
+                    }
+                }
+            }
+            WaylandEvent::Paint => {
+                if self.new_next_frames.len() != 0 {
+                    self.call_next_frame_event(wayland_app.time_now());
+                }
+                if self.need_redrawing() {
+                    self.call_draw_event();
+                    opengl_cx.make_current();
+                    self.opengl_compile_shaders();
+                }
+                self.handle_wayland_repaint(opengl_windows, opengl_cx);
+            }
+            WaylandEvent::MouseDown(e) => {
+                self.fingers.process_tap_count(
+                    e.abs,
+                    e.time
+                );
+                self.fingers.mouse_down(e.button);
+                self.call_event_handler(&Event::MouseDown(e.into()))
+            }
+            WaylandEvent::MouseMove(e) => {
+                self.call_event_handler(&Event::MouseMove(e.into()));
+                self.fingers.cycle_hover_area(live_id!(mouse).into());
+                self.fingers.switch_captures();
+            }
+            WaylandEvent::MouseUp(e) => {
+                let button = e.button;
+                self.call_event_handler(&Event::MouseUp(e.into()));
+                self.fingers.mouse_up(button);
+                self.fingers.cycle_hover_area(live_id!(mouse).into());
+            }
+            WaylandEvent::Scroll(e) => {
+                self.call_event_handler(&Event::Scroll(e.into()))
+            }
+            WaylandEvent::WindowDragQuery(e) => {
+                self.call_event_handler(&Event::WindowDragQuery(e))
+            }
+            WaylandEvent::WindowCloseRequested(e) => {
+                self.call_event_handler(&Event::WindowCloseRequested(e))
+            }
+            WaylandEvent::TextInput(e) => {
+                self.call_event_handler(&Event::TextInput(e))
+            }
+            WaylandEvent::DragEnd => {
+                self.call_event_handler(&Event::DragEnd);
+                self.drag_drop.cycle_drag();
+            }
+            WaylandEvent::KeyDown(e) => {
+                self.keyboard.process_key_down(e.clone());
+                self.call_event_handler(&Event::KeyDown(e))
+            }
+            WaylandEvent::KeyUp(e) => {
+                self.keyboard.process_key_up(e.clone());
+                self.call_event_handler(&Event::KeyUp(e))
+            }
+            WaylandEvent::TextCopy(e) => {
+                self.call_event_handler(&Event::TextCopy(e))
+            }
+            WaylandEvent::TextCut(e) => {
+                self.call_event_handler(&Event::TextCut(e))
+            }
+            WaylandEvent::Timer(e) => {
+                if e.timer_id == 0 {
+                    if Signal::check_and_clear_ui_signal() {
+                        self.handle_media_signals();
+                        self.call_event_handler(&Event::Signal);
+                    }
+                    self.handle_networking_events();
+                }
+                else {
+                    self.call_event_handler(&Event::Timer(e))
+                }
+            }
+        }
+
+        // windows waiting on a frame callback get woken up by the compositor
+        if opengl_windows.iter().any( | w | w.wayland_window.is_frame_pending()) {
+            return EventFlow::Wait
+        }
+        if self.any_passes_dirty() || self.need_redrawing() || self.new_next_frames.len() != 0 || paint_dirty {
+            EventFlow::Poll
+        } else {
+            EventFlow::Wait
+        }
+    }
+
+    fn handle_wayland_repaint(&mut self, opengl_windows: &mut Vec<OpenglWindow>, opengl_cx: &mut OpenglCx) {
+        opengl_cx.make_current();
+        let mut passes_todo = Vec::new();
+        self.compute_pass_repaint_order(&mut passes_todo);
+        self.repaint_id += 1;
+        for pass_id in &passes_todo {
+            match self.passes[*pass_id].parent.clone() {
+                CxPassParent::Window(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        if !window.wayland_window.is_configured || window.wayland_window.is_frame_pending() {
+                            continue;
+                        }
+                        window.resize_buffers(&opengl_cx);
+                        self.draw_pass_to_wayland_window(*pass_id, window, opengl_cx);
+                    }
+                }
+                CxPassParent::Pass(_) => {
+                    self.draw_pass_to_texture(*pass_id);
+                },
+                CxPassParent::None => {
+                    self.draw_pass_to_texture(*pass_id);
+                }
+            }
+        }
+    }
+
+    fn handle_wayland_platform_ops(&mut self, opengl_windows: &mut Vec<OpenglWindow>, opengl_cx: &OpenglCx, wayland_app: &mut WaylandApp) -> EventFlow {
+        let mut ret = EventFlow::Poll;
+        while let Some(op) = self.platform_ops.pop() {
+            match op {
+                CxOsOp::CreateWindow(window_id) => {
+                    let window = &mut self.windows[window_id];
+                    // wayland doesn't let clients position their windows
+                    let opengl_window = OpenglWindow::new(
+                        window_id,
+                        &opengl_cx,
+                        window.create_inner_size.unwrap_or(dvec2(800., 600.)),
+                        &window.create_title,
+                    );
+                    window.window_geom = opengl_window.window_geom.clone();
+                    opengl_windows.push(opengl_window);
+                    window.is_created = true;
+                },
+                CxOsOp::CloseWindow(window_id) => {
+                    if let Some(index) = opengl_windows.iter().position( | w | w.window_id == window_id) {
+                        self.windows[window_id].is_created = false;
+                        opengl_windows[index].close_window(opengl_cx);
+                        opengl_windows.remove(index);
+                        if opengl_windows.len() == 0 {
+                            ret = EventFlow::Exit
+                        }
+                    }
+                },
+                CxOsOp::MinimizeWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.wayland_window.minimize();
+                    }
+                },
+                CxOsOp::MaximizeWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.wayland_window.maximize();
+                    }
+                },
+                CxOsOp::RestoreWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.wayland_window.restore();
+                    }
+                },
+                CxOsOp::ShowClipboardActions(_) => {
+                }
+                CxOsOp::FullscreenWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.wayland_window.fullscreen();
+                    }
+                },
+                CxOsOp::NormalizeWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.wayland_window.normalize();
+                    }
+                }
+                CxOsOp::SetTopmost(_window_id, _is_topmost) => {
+                    // xdg-shell has no always on top
+                }
+                CxOsOp::XrStartPresenting => {
+                    //todo!()
+                },
+                CxOsOp::XrStopPresenting => {
+                    //todo!()
+                },
+                CxOsOp::ShowTextIME(area, pos) => {
+                    let pos = area.get_clipped_rect(self).pos + pos;
+                    let window_id = self.get_window_id_of(&area);
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | Some(w.window_id) == window_id) {
+                        wayland_app.show_text_ime(window.wayland_window.surface, pos);
+                    }
+                }
+                CxOsOp::HideTextIME => {
+                    wayland_app.hide_text_ime();
+                },
+                CxOsOp::SetCursor(cursor) => {
+                    wayland_app.set_mouse_cursor(cursor);
+                },
+                CxOsOp::StartTimer {timer_id, interval, repeats} => {
+                    wayland_app.start_timer(timer_id, interval, repeats);
+                },
+                CxOsOp::StopTimer(timer_id) => {
+                    wayland_app.stop_timer(timer_id);
+                },
+                CxOsOp::StartDragging(items) => {
+                    wayland_app.start_dragging(items);
+                },
+                CxOsOp::UpdateMenu(_menu) => {
+                },
+                CxOsOp::HttpRequest {request_id, request} => {
+                    LinuxHttpRequest::new(request_id, request).start(self.os.network_response.sender.clone());
+                },
+                CxOsOp::WebSocketOpen {request_id, request} => {
+                    let web_socket = LinuxWebSocket::open(request_id, request, self.os.network_response.sender.clone());
+                    self.os.web_sockets.insert(request_id, web_socket);
+                }
+                CxOsOp::WebSocketSendBinary {request_id, data} => {
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id) {
+                        web_socket.send_binary(data);
+                    }
+                }
+                CxOsOp::WebSocketSendString {request_id, data} => {
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id) {
+                        web_socket.send_string(data);
+                    }
+                }
+            }
+        }
+        ret
+    }
+}
@@ -0,0 +1,253 @@
+use {
+    std::{
+        ffi::CString,
+        ptr,
+    },
+    self::super::{
+        wayland_sys,
+        wayland_window::WaylandWindow,
+    },
+    self::super::super::{
+        egl_sys::*,
+        gl_sys,
+    },
+    crate::{
+        cx::Cx,
+        window::WindowId,
+        makepad_math::{DVec2},
+        pass::{PassClearColor, PassClearDepth, PassId},
+        event::*,
+    },
+};
+
+impl Cx {
+
+    pub fn draw_pass_to_wayland_window(
+        &mut self,
+        pass_id: PassId,
+        opengl_window: &mut OpenglWindow,
+        opengl_cx: &OpenglCx,
+    ) {
+        let draw_list_id = self.passes[pass_id].main_draw_list_id.unwrap();
+
+        self.setup_render_pass(pass_id);
+
+        self.passes[pass_id].paint_dirty = false;
+
+        unsafe {
+            eglMakeCurrent(opengl_cx.egl_display, opengl_window.egl_surface, opengl_window.egl_surface, opengl_cx.egl_context);
+            gl_sys::Viewport(0, 0, opengl_window.cal_size.x as i32, opengl_window.cal_size.y as i32);
+        }
+
+        let clear_color = if self.passes[pass_id].color_textures.len() == 0 {
+            self.passes[pass_id].clear_color
+        }
+        else {
+            match self.passes[pass_id].color_textures[0].clear_color {
+                PassClearColor::InitWith(color) => color,
+                PassClearColor::ClearWith(color) => color
+            }
+        };
+        let clear_depth = match self.passes[pass_id].clear_depth {
+            PassClearDepth::InitWith(depth) => depth,
+            PassClearDepth::ClearWith(depth) => depth
+        };
+
+        Self::set_default_depth_and_blend_mode();
+        if !self.passes[pass_id].dont_clear {
+            unsafe {
+                gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
+                gl_sys::ClearDepthf(clear_depth as f32);
+                gl_sys::ClearColor(clear_color.x, clear_color.y, clear_color.z, clear_color.w);
+                gl_sys::Clear(gl_sys::COLOR_BUFFER_BIT | gl_sys::DEPTH_BUFFER_BIT);
+            }
+        }
+
+        let mut zbias = 0.0;
+        let zbias_step = self.passes[pass_id].zbias_step;
+
+        self.render_view(
+            pass_id,
+            draw_list_id,
+            &mut zbias,
+            zbias_step,
+        );
+
+        // the frame callback has to be requested before the swap commits the surface
+        opengl_window.wayland_window.request_frame();
+        unsafe {
+            eglSwapBuffers(opengl_cx.egl_display, opengl_window.egl_surface);
+        }
+    }
+}
+
+pub struct OpenglCx {
+    pub egl_display: EGLDisplay,
+    pub egl_config: EGLConfig,
+    pub egl_context: EGLContext,
+}
+
+impl OpenglCx {
+    pub fn new(display: *mut wayland_sys::wl_display) -> OpenglCx {
+        unsafe {
+            #[allow(non_snake_case)]
+            let eglGetPlatformDisplayEXT: PFNEGLGETPLATFORMDISPLAYEXTPROC = std::mem::transmute(eglGetProcAddress("eglGetPlatformDisplayEXT\0".as_ptr()));
+            #[allow(non_snake_case)]
+            let eglInitialize: PFNEGLINITIALIZEPROC = std::mem::transmute(eglGetProcAddress("eglInitialize\0".as_ptr()));
+            #[allow(non_snake_case)]
+            let eglChooseConfig: PFNEGLCHOOSECONFIGPROC = std::mem::transmute(eglGetProcAddress("eglChooseConfig\0".as_ptr()));
+
+            let egl_display = (eglGetPlatformDisplayEXT.expect("can't load eglGetPlatformDisplayEXT"))(EGL_PLATFORM_WAYLAND_KHR, display, ptr::null());
+            assert!(egl_display != ptr::null_mut(), "can't get EGL display for wayland");
+
+            let mut major = 0;
+            let mut minor = 0;
+            assert!((eglInitialize.unwrap())(egl_display, &mut major, &mut minor) != 0, "can't initialize EGL");
+            assert!(eglBindAPI(EGL_OPENGL_ES_API) != 0, "can't bind EGL_OPENGL_ES_API");
+
+            let config_attribs = [
+                EGL_SURFACE_TYPE,
+                EGL_WINDOW_BIT,
+                EGL_RED_SIZE,
+                8,
+                EGL_GREEN_SIZE,
+                8,
+                EGL_BLUE_SIZE,
+                8,
+                // an alpha channel would make the compositor blend the window with what is behind it
+                EGL_ALPHA_SIZE,
+                0,
+                EGL_DEPTH_SIZE,
+                24,
+                EGL_RENDERABLE_TYPE,
+                EGL_OPENGL_ES2_BIT,
+                EGL_NONE
+            ];
+            let mut egl_config = ptr::null_mut();
+            let mut config_count = 0;
+            if (eglChooseConfig.unwrap())(egl_display, config_attribs.as_ptr(), &mut egl_config, 1, &mut config_count) == 0 || config_count == 0 {
+                panic!("can't choose EGL framebuffer configuration");
+            }
+
+            // ask for ES 3 like the glx backend, fall back to ES 2
+            let mut egl_context = EGL_NO_CONTEXT;
+            for version in [3, 2] {
+                let context_attribs = [EGL_CONTEXT_CLIENT_VERSION, version, EGL_NONE];
+                egl_context = eglCreateContext(egl_display, egl_config, EGL_NO_CONTEXT, context_attribs.as_ptr());
+                if egl_context != EGL_NO_CONTEXT {
+                    break;
+                }
+            }
+            assert!(egl_context != EGL_NO_CONTEXT, "can't create EGL context");
+
+            gl_sys::load_with( | symbol | {
+                let symbol = CString::new(symbol).unwrap();
+                eglGetProcAddress(symbol.as_ptr() as *const u8)
+            });
+
+            OpenglCx {
+                egl_display,
+                egl_config,
+                egl_context,
+            }
+        }
+    }
+
+    // resources are created without any window around, which needs EGL_KHR_surfaceless_context
+    pub fn make_current(&self) {
+        unsafe {eglMakeCurrent(self.egl_display, EGL_NO_SURFACE, EGL_NO_SURFACE, self.egl_context);}
+    }
+}
+
+pub struct OpenglWindow {
+    pub first_draw: bool,
+    pub window_id: WindowId,
+    pub window_geom: WindowGeom,
+    pub opening_repaint_count: u32,
+    pub cal_size: DVec2,
+    pub wayland_window: Box<WaylandWindow>,
+    pub egl_window: *mut wayland_sys::wl_egl_window,
+    pub egl_surface: EGLSurface,
+}
+
+impl OpenglWindow {
+    pub fn new(
+        window_id: WindowId,
+        opengl_cx: &OpenglCx,
+        inner_size: DVec2,
+        title: &str
+    ) -> OpenglWindow {
+
+        let mut wayland_window = Box::new(WaylandWindow::new(window_id));
+        wayland_window.init(title, inner_size);
+        let window_geom = wayland_window.get_window_geom();
+
+        let cal_size = Self::pixel_size(&window_geom);
+        let (egl_window, egl_surface) = unsafe {
+            let egl_window = wayland_sys::wl_egl_window_create(wayland_window.surface, cal_size.x as i32, cal_size.y as i32);
+            let egl_surface = eglCreateWindowSurface(opengl_cx.egl_display, opengl_cx.egl_config, egl_window as _, ptr::null());
+            assert!(egl_surface != EGL_NO_SURFACE, "can't create EGL window surface");
+            // the compositor paces us through frame callbacks, a blocking swap would stall every other window
+            eglMakeCurrent(opengl_cx.egl_display, egl_surface, egl_surface, opengl_cx.egl_context);
+            eglSwapInterval(opengl_cx.egl_display, 0);
+            (egl_window, egl_surface)
+        };
+
+        let mut window = OpenglWindow {
+            first_draw: true,
+            window_id,
+            opening_repaint_count: 0,
+            cal_size: DVec2::default(),
+            window_geom,
+            wayland_window,
+            egl_window,
+            egl_surface,
+        };
+        window.resize_buffers(opengl_cx);
+        window
+    }
+
+    fn pixel_size(window_geom: &WindowGeom) -> DVec2 {
+        DVec2 {
+            x: (window_geom.inner_size.x * window_geom.dpi_factor).round().max(1.0),
+            y: (window_geom.inner_size.y * window_geom.dpi_factor).round().max(1.0)
+        }
+    }
+
+    pub fn resize_buffers(&mut self, _opengl_cx: &OpenglCx) -> bool {
+        let cal_size = Self::pixel_size(&self.window_geom);
+        if self.cal_size != cal_size {
+            self.cal_size = cal_size;
+            unsafe {
+                wayland_sys::wl_egl_window_resize(self.egl_window, cal_size.x as i32, cal_size.y as i32, 0, 0);
+                let window = &self.wayland_window;
+                if window.viewport != ptr::null_mut() {
+                    let size = self.window_geom.inner_size;
+                    wayland_sys::wp_viewport_set_destination(window.viewport, size.x.round() as i32, size.y.round() as i32);
+                }
+                else {
+                    wayland_sys::wl_surface_set_buffer_scale(window.surface, self.window_geom.dpi_factor.round().max(1.0) as i32);
+                }
+            }
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    pub fn close_window(&mut self, opengl_cx: &OpenglCx) {
+        unsafe {
+            opengl_cx.make_current();
+            if self.egl_surface != EGL_NO_SURFACE {
+                eglDestroySurface(opengl_cx.egl_display, self.egl_surface);
+                self.egl_surface = EGL_NO_SURFACE;
+            }
+            if self.egl_window != ptr::null_mut() {
+                wayland_sys::wl_egl_window_destroy(self.egl_window);
+                self.egl_window = ptr::null_mut();
+            }
+        }
+        self.wayland_window.close_window();
+    }
+}
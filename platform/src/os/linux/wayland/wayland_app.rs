@@ -0,0 +1,1288 @@
+use {
+    std::{
+        collections::HashMap,
+        cell::{Cell, RefCell},
+        rc::Rc,
+        fs::File,
+        io::{Read, Write},
+        os::{
+            fd::FromRawFd,
+            unix::fs::FileExt,
+            raw::{c_char, c_int, c_void},
+        },
+        ffi::{CStr, CString},
+        ptr,
+    },
+    self::super::{
+        wayland_sys::*,
+        xkb_sys::*,
+        wayland_event::WaylandEvent,
+        wayland_window::*,
+        super::{
+            libc_sys,
+            select_timer::SelectTimers,
+            x11::xlib_app::keysym_to_keycode,
+            x11::xlib_window::{XimPreedit, dnd_items_to_uri_list},
+        },
+    },
+    crate::{
+        makepad_math::DVec2,
+        event::*,
+        cursor::MouseCursor,
+        os::cx_native::EventFlow,
+    },
+};
+
+// key repeat is driven by our own timer, cx timer ids count up from 1 so this one is free
+pub const KEY_REPEAT_TIMER_ID: u64 = u64::MAX;
+
+const TEXT_MIME_TYPES: [&'static [u8]; 5] = [
+    b"text/plain;charset=utf-8\0",
+    b"text/plain\0",
+    b"UTF8_STRING\0",
+    b"TEXT\0",
+    b"STRING\0",
+];
+
+static mut WAYLAND_APP: *mut WaylandApp = 0 as *mut _;
+
+pub fn get_wayland_app_global() -> &'static mut WaylandApp {
+    unsafe {
+        &mut *(WAYLAND_APP)
+    }
+}
+
+/// Connects to the compositor, returns false when there is none or it lacks xdg-shell
+pub fn init_wayland_app_global(event_callback: Box<dyn FnMut(&mut WaylandApp, WaylandEvent) -> EventFlow>) -> bool {
+    unsafe {
+        let display = wl_display_connect(ptr::null());
+        if display == ptr::null_mut() {
+            return false
+        }
+        WAYLAND_APP = Box::into_raw(Box::new(WaylandApp::new(display, event_callback)));
+        if !get_wayland_app_global().bind_globals() {
+            drop(Box::from_raw(WAYLAND_APP));
+            WAYLAND_APP = ptr::null_mut();
+            wl_display_disconnect(display);
+            return false
+        }
+        true
+    }
+}
+
+pub struct WaylandOutput {
+    pub output: *mut wl_output,
+    pub name: u32,
+    pub scale: i32,
+}
+
+pub struct WaylandApp {
+    pub display: *mut wl_display,
+    pub display_fd: c_int,
+    event_loop_running: bool,
+
+    pub registry: *mut wl_registry,
+    pub compositor: *mut wl_compositor,
+    pub wm_base: *mut xdg_wm_base,
+    pub shm: *mut wl_shm,
+    pub seat: *mut wl_seat,
+    pub seat_version: u32,
+    pub pointer: *mut wl_pointer,
+    pub keyboard: *mut wl_keyboard,
+    pub data_device_manager: *mut wl_data_device_manager,
+    pub data_device: *mut wl_data_device,
+    pub fractional_scale_manager: *mut wp_fractional_scale_manager_v1,
+    pub viewporter: *mut wp_viewporter,
+    pub decoration_manager: *mut zxdg_decoration_manager_v1,
+    pub text_input_manager: *mut zwp_text_input_manager_v3,
+    pub text_input: *mut zwp_text_input_v3,
+    pub outputs: Vec<WaylandOutput>,
+
+    pub window_map: HashMap<*mut wl_surface, *mut WaylandWindow>,
+    pub pointer_focus: *mut wl_surface,
+    pub keyboard_focus: *mut wl_surface,
+    pub pointer_serial: u32,
+    pub button_serial: u32,
+    pub last_serial: u32,
+    pub pointer_scroll: DVec2,
+    pub pointer_scroll_is_mouse: bool,
+    pub pointer_scroll_discrete: bool,
+
+    pub xkb_context: *mut xkb_context,
+    pub xkb_keymap: *mut xkb_keymap,
+    pub xkb_state: *mut xkb_state,
+    pub xkb_compose_state: *mut xkb_compose_state,
+    pub modifiers: KeyModifiers,
+    pub repeat_rate: i32,
+    pub repeat_delay: i32,
+    pub repeat_key: Option<u32>,
+    pub repeat_started: bool,
+
+    // the text input follows keyboard focus, ime_spot is where ShowTextIME wants the candidates
+    pub text_input_focus: *mut wl_surface,
+    pub text_input_enabled: bool,
+    pub ime_spot: Option<(*mut wl_surface, DVec2)>,
+    pub ime_preedit: XimPreedit,
+    pub pending_preedit: Option<String>,
+    pub pending_commit: Option<String>,
+
+    pub cursor_theme: *mut wl_cursor_theme,
+    pub cursor_theme_scale: i32,
+    pub cursor_surface: *mut wl_surface,
+    pub current_cursor: MouseCursor,
+
+    pub clipboard: String,
+    pub clipboard_source: *mut wl_data_source,
+    pub selection_offer: *mut wl_data_offer,
+    pub drag_offer: *mut wl_data_offer,
+    pub offer_mime_types: HashMap<*mut wl_data_offer, Vec<String>>,
+    pub drag_source: *mut wl_data_source,
+    pub drag_surface: *mut wl_surface,
+    pub drag_uri_list: String,
+
+    pub timers: SelectTimers,
+    pub last_click_time: f64,
+    pub last_click_pos: DVec2,
+    pub event_callback: Option<Box<dyn FnMut(&mut WaylandApp, WaylandEvent) -> EventFlow >>,
+    pub event_flow: EventFlow,
+}
+
+impl WaylandApp {
+    fn new(display: *mut wl_display, event_callback: Box<dyn FnMut(&mut WaylandApp, WaylandEvent) -> EventFlow>) -> WaylandApp {
+        WaylandApp {
+            display,
+            display_fd: unsafe {wl_display_get_fd(display)},
+            event_loop_running: true,
+            registry: ptr::null_mut(),
+            compositor: ptr::null_mut(),
+            wm_base: ptr::null_mut(),
+            shm: ptr::null_mut(),
+            seat: ptr::null_mut(),
+            seat_version: 0,
+            pointer: ptr::null_mut(),
+            keyboard: ptr::null_mut(),
+            data_device_manager: ptr::null_mut(),
+            data_device: ptr::null_mut(),
+            fractional_scale_manager: ptr::null_mut(),
+            viewporter: ptr::null_mut(),
+            decoration_manager: ptr::null_mut(),
+            text_input_manager: ptr::null_mut(),
+            text_input: ptr::null_mut(),
+            outputs: Vec::new(),
+            window_map: HashMap::new(),
+            pointer_focus: ptr::null_mut(),
+            keyboard_focus: ptr::null_mut(),
+            pointer_serial: 0,
+            button_serial: 0,
+            last_serial: 0,
+            pointer_scroll: DVec2::default(),
+            pointer_scroll_is_mouse: true,
+            pointer_scroll_discrete: false,
+            xkb_context: unsafe {xkb_context_new(0)},
+            xkb_keymap: ptr::null_mut(),
+            xkb_state: ptr::null_mut(),
+            xkb_compose_state: ptr::null_mut(),
+            modifiers: KeyModifiers::default(),
+            repeat_rate: 25,
+            repeat_delay: 600,
+            repeat_key: None,
+            repeat_started: false,
+            text_input_focus: ptr::null_mut(),
+            text_input_enabled: false,
+            ime_spot: None,
+            ime_preedit: XimPreedit::default(),
+            pending_preedit: None,
+            pending_commit: None,
+            cursor_theme: ptr::null_mut(),
+            cursor_theme_scale: 0,
+            cursor_surface: ptr::null_mut(),
+            current_cursor: MouseCursor::Default,
+            clipboard: String::new(),
+            clipboard_source: ptr::null_mut(),
+            selection_offer: ptr::null_mut(),
+            drag_offer: ptr::null_mut(),
+            offer_mime_types: HashMap::new(),
+            drag_source: ptr::null_mut(),
+            drag_surface: ptr::null_mut(),
+            drag_uri_list: String::new(),
+            timers: SelectTimers::new(),
+            last_click_time: 0.0,
+            last_click_pos: DVec2::default(),
+            event_callback: Some(event_callback),
+            event_flow: EventFlow::Poll,
+        }
+    }
+
+    unsafe fn bind_globals(&mut self) -> bool {
+        self.registry = wl_display_get_registry(self.display);
+        wl_proxy_add_listener_data(self.registry, &REGISTRY_LISTENER, ptr::null_mut());
+        // the first roundtrip announces the globals, the second delivers their initial state
+        wl_display_roundtrip(self.display);
+        if self.compositor == ptr::null_mut() || self.wm_base == ptr::null_mut() {
+            return false
+        }
+        if self.data_device_manager != ptr::null_mut() && self.seat != ptr::null_mut() {
+            self.data_device = wl_data_device_manager_get_data_device(self.data_device_manager, self.seat);
+            wl_proxy_add_listener_data(self.data_device, &DATA_DEVICE_LISTENER, ptr::null_mut());
+        }
+        if self.text_input_manager != ptr::null_mut() && self.seat != ptr::null_mut() {
+            self.text_input = zwp_text_input_manager_v3_get_text_input(self.text_input_manager, self.seat);
+            wl_proxy_add_listener_data(self.text_input, &TEXT_INPUT_LISTENER, ptr::null_mut());
+        }
+        if self.compositor != ptr::null_mut() {
+            self.cursor_surface = wl_compositor_create_surface(self.compositor);
+        }
+        wl_display_roundtrip(self.display);
+        true
+    }
+
+    pub fn event_loop(&mut self) {
+        unsafe {
+            self.do_callback(WaylandEvent::Paint);
+
+            let mut timer_ids = Vec::new();
+            while self.event_loop_running {
+                match self.event_flow {
+                    EventFlow::Exit => {
+                        break;
+                    }
+                    EventFlow::Wait => {
+                        self.fire_timers(&mut timer_ids);
+                        // libwayland wants a prepared read before we block on the socket
+                        while wl_display_prepare_read(self.display) != 0 {
+                            wl_display_dispatch_pending(self.display);
+                        }
+                        wl_display_flush(self.display);
+                        self.timers.select(self.display_fd);
+                        wl_display_read_events(self.display);
+                        self.event_flow = EventFlow::Poll;
+                    }
+                    EventFlow::Poll => {
+                        self.fire_timers(&mut timer_ids);
+                        self.event_loop_poll();
+                    }
+                }
+            }
+        }
+    }
+
+    fn fire_timers(&mut self, timer_ids: &mut Vec<u64>) {
+        self.timers.update_timers(timer_ids);
+        for timer_id in timer_ids.iter() {
+            if *timer_id == KEY_REPEAT_TIMER_ID {
+                self.repeat_key();
+            }
+            else {
+                self.do_callback(
+                    WaylandEvent::Timer(TimerEvent {timer_id: *timer_id})
+                );
+            }
+        }
+    }
+
+    pub unsafe fn event_loop_poll(&mut self) {
+        if self.display == ptr::null_mut() {
+            return
+        }
+        // reading never blocks, the socket is read with MSG_DONTWAIT
+        while wl_display_prepare_read(self.display) != 0 {
+            wl_display_dispatch_pending(self.display);
+        }
+        wl_display_flush(self.display);
+        wl_display_read_events(self.display);
+        wl_display_dispatch_pending(self.display);
+        if wl_display_get_error(self.display) != 0 {
+            crate::error!("Wayland connection error {}", wl_display_get_error(self.display));
+            self.terminate_event_loop();
+            return
+        }
+        self.do_callback(WaylandEvent::Paint);
+    }
+
+    pub fn do_callback(&mut self, event: WaylandEvent) {
+        if let Some(mut callback) = self.event_callback.take() {
+            self.event_flow = callback(self, event);
+            if let EventFlow::Exit = self.event_flow {
+                self.terminate_event_loop();
+            }
+            self.event_callback = Some(callback);
+        }
+    }
+
+    pub fn terminate_event_loop(&mut self) {
+        self.event_loop_running = false;
+        if self.display != ptr::null_mut() {
+            unsafe {
+                wl_display_flush(self.display);
+                wl_display_disconnect(self.display);
+            }
+            self.display = ptr::null_mut();
+        }
+    }
+
+    pub fn start_timer(&mut self, id: u64, timeout: f64, repeats: bool) {
+        self.timers.start_timer(id, timeout, repeats);
+    }
+
+    pub fn stop_timer(&mut self, id: u64) {
+        self.timers.stop_timer(id);
+    }
+
+    pub fn time_now(&self) -> f64 {
+        self.timers.time_now()
+    }
+
+    pub fn window_for_surface(&self, surface: *mut wl_surface) -> Option<&'static mut WaylandWindow> {
+        self.window_map.get(&surface).map( | window | unsafe {&mut **window})
+    }
+
+    pub fn output_scale(&self, output: *mut wl_output) -> i32 {
+        self.outputs.iter().find( | o | o.output == output).map_or(1, | o | o.scale)
+    }
+
+    fn cursor_names(cursor: MouseCursor) -> &'static [&'static [u8]] {
+        match cursor {
+            MouseCursor::Hidden => &[],
+            MouseCursor::EResize => &[b"e-resize\0", b"right_side\0"],
+            MouseCursor::NResize => &[b"n-resize\0", b"top_side\0"],
+            MouseCursor::NeResize => &[b"ne-resize\0", b"top_right_corner\0"],
+            MouseCursor::NwResize => &[b"nw-resize\0", b"top_left_corner\0"],
+            MouseCursor::SResize => &[b"s-resize\0", b"bottom_side\0"],
+            MouseCursor::SeResize => &[b"se-resize\0", b"bottom_right_corner\0"],
+            MouseCursor::SwResize => &[b"sw-resize\0", b"bottom_left_corner\0"],
+            MouseCursor::WResize => &[b"w-resize\0", b"left_side\0"],
+            MouseCursor::Default => &[b"default\0", b"left_ptr\0"],
+            MouseCursor::Crosshair => &[b"crosshair\0"],
+            MouseCursor::Hand => &[b"pointer\0", b"hand2\0", b"hand1\0"],
+            MouseCursor::Arrow => &[b"default\0", b"left_ptr\0"],
+            MouseCursor::Move => &[b"move\0", b"fleur\0"],
+            MouseCursor::NotAllowed => &[b"not-allowed\0", b"crossed_circle\0"],
+            MouseCursor::Text => &[b"text\0", b"xterm\0"],
+            MouseCursor::Wait => &[b"wait\0", b"watch\0"],
+            MouseCursor::Help => &[b"help\0", b"question_arrow\0"],
+            MouseCursor::NsResize => &[b"ns-resize\0", b"v_double_arrow\0"],
+            MouseCursor::NeswResize => &[b"nesw-resize\0", b"fd_double_arrow\0"],
+            MouseCursor::EwResize => &[b"ew-resize\0", b"h_double_arrow\0"],
+            MouseCursor::NwseResize => &[b"nwse-resize\0", b"bd_double_arrow\0"],
+            MouseCursor::ColResize => &[b"col-resize\0", b"sb_h_double_arrow\0"],
+            MouseCursor::RowResize => &[b"row-resize\0", b"sb_v_double_arrow\0"],
+        }
+    }
+
+    pub fn set_mouse_cursor(&mut self, cursor: MouseCursor) {
+        if self.current_cursor != cursor {
+            self.current_cursor = cursor;
+            self.apply_mouse_cursor();
+        }
+    }
+
+    // cursors are per pointer focus on wayland, so this is redone on every pointer enter
+    fn apply_mouse_cursor(&mut self) {
+        unsafe {
+            if self.pointer == ptr::null_mut() || self.pointer_focus == ptr::null_mut() {
+                return
+            }
+            if self.current_cursor == MouseCursor::Hidden {
+                wl_pointer_set_cursor(self.pointer, self.pointer_serial, ptr::null_mut(), 0, 0);
+                return
+            }
+            let scale = self.window_for_surface(self.pointer_focus)
+                .map_or(1, | w | w.get_dpi_factor().ceil() as i32).max(1);
+            if self.cursor_theme_scale != scale && self.shm != ptr::null_mut() {
+                if self.cursor_theme != ptr::null_mut() {
+                    wl_cursor_theme_destroy(self.cursor_theme);
+                }
+                let size = std::env::var("XCURSOR_SIZE").ok().and_then( | s | s.parse().ok()).unwrap_or(24);
+                let theme = std::env::var("XCURSOR_THEME").ok().and_then( | s | CString::new(s).ok());
+                self.cursor_theme = wl_cursor_theme_load(
+                    theme.as_ref().map_or(ptr::null(), | t | t.as_ptr()),
+                    size * scale,
+                    self.shm
+                );
+                self.cursor_theme_scale = scale;
+            }
+            if self.cursor_theme == ptr::null_mut() || self.cursor_surface == ptr::null_mut() {
+                return
+            }
+            for name in Self::cursor_names(self.current_cursor) {
+                let cursor = wl_cursor_theme_get_cursor(self.cursor_theme, name.as_ptr() as *const c_char);
+                if cursor == ptr::null_mut() || (*cursor).image_count == 0 {
+                    continue;
+                }
+                let image = *(*cursor).images;
+                let buffer = wl_cursor_image_get_buffer(image);
+                if buffer == ptr::null_mut() {
+                    continue;
+                }
+                wl_pointer_set_cursor(
+                    self.pointer,
+                    self.pointer_serial,
+                    self.cursor_surface,
+                    (*image).hotspot_x as i32 / scale,
+                    (*image).hotspot_y as i32 / scale
+                );
+                wl_surface_set_buffer_scale(self.cursor_surface, scale);
+                wl_surface_attach(self.cursor_surface, buffer, 0, 0);
+                wl_surface_damage(self.cursor_surface, 0, 0, (*image).width as i32, (*image).height as i32);
+                wl_surface_commit(self.cursor_surface);
+                return
+            }
+        }
+    }
+
+    pub fn copy_to_clipboard(&mut self, text: &String) {
+        unsafe {
+            if self.data_device_manager == ptr::null_mut() || self.data_device == ptr::null_mut() {
+                return
+            }
+            self.clipboard = text.clone();
+            if self.clipboard_source != ptr::null_mut() {
+                wl_data_source_destroy(self.clipboard_source);
+            }
+            let source = wl_data_device_manager_create_data_source(self.data_device_manager);
+            wl_proxy_add_listener_data(source, &DATA_SOURCE_LISTENER, ptr::null_mut());
+            for mime_type in TEXT_MIME_TYPES {
+                wl_data_source_offer(source, mime_type.as_ptr() as *const c_char);
+            }
+            wl_data_device_set_selection(self.data_device, source, self.last_serial);
+            self.clipboard_source = source;
+        }
+    }
+
+    pub fn paste_from_clipboard(&mut self) -> Option<String> {
+        // reading our own selection through a pipe would deadlock this thread
+        if self.clipboard_source != ptr::null_mut() {
+            return Some(self.clipboard.clone())
+        }
+        unsafe {
+            let offer = self.selection_offer;
+            let mime_types = self.offer_mime_types.get(&offer) ?;
+            let mime_type = TEXT_MIME_TYPES.iter().find( | m | {
+                let m = CStr::from_bytes_with_nul(m).unwrap().to_str().unwrap();
+                mime_types.iter().any( | t | t == m)
+            }) ?;
+            let mut fds = [0 as c_int; 2];
+            if libc_sys::pipe(fds.as_mut_ptr()) != 0 {
+                return None
+            }
+            wl_data_offer_receive(offer, mime_type.as_ptr() as *const c_char, fds[1]);
+            libc_sys::close(fds[1]);
+            wl_display_flush(self.display);
+            let mut file = File::from_raw_fd(fds[0]);
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).ok() ?;
+            Some(String::from_utf8_lossy(&data).to_string())
+        }
+    }
+
+    pub fn start_dragging(&mut self, items: Vec<DragItem>) {
+        unsafe {
+            // drags start from a mouse down, so the window under the pointer is the source
+            if self.data_device == ptr::null_mut() || self.pointer_focus == ptr::null_mut() {
+                crate::error!("start_dragging: no data device or no window under the pointer");
+                return
+            }
+            if self.drag_source != ptr::null_mut() {
+                wl_data_source_destroy(self.drag_source);
+            }
+            let source = wl_data_device_manager_create_data_source(self.data_device_manager);
+            wl_proxy_add_listener_data(source, &DATA_SOURCE_LISTENER, ptr::null_mut());
+            wl_data_source_offer(source, b"text/uri-list\0".as_ptr() as *const c_char);
+            if wl_proxy_get_version(source) >= 3 {
+                wl_data_source_set_actions(source, WL_DATA_DEVICE_MANAGER_DND_ACTION_COPY | WL_DATA_DEVICE_MANAGER_DND_ACTION_MOVE);
+            }
+            self.drag_uri_list = dnd_items_to_uri_list(&items);
+            self.drag_source = source;
+            self.drag_surface = self.pointer_focus;
+            wl_data_device_start_drag(self.data_device, source, self.pointer_focus, ptr::null_mut(), self.button_serial);
+        }
+    }
+
+    // the compositor keeps the button release of a drag, so the source window gets its mouse up here
+    unsafe fn end_dragging(&mut self, source: *mut wl_data_source) {
+        if source != self.drag_source {
+            return
+        }
+        wl_data_source_destroy(source);
+        self.drag_source = ptr::null_mut();
+        if let Some(window) = self.window_for_surface(self.drag_surface) {
+            window.send_mouse_up(0, self.modifiers.clone());
+        }
+        self.drag_surface = ptr::null_mut();
+        self.do_callback(WaylandEvent::DragEnd);
+    }
+
+    pub fn show_text_ime(&mut self, surface: *mut wl_surface, pos: DVec2) {
+        self.ime_spot = Some((surface, pos));
+        self.update_text_input();
+    }
+
+    pub fn hide_text_ime(&mut self) {
+        self.ime_spot = None;
+        if let Some(event) = self.ime_preedit.cancel() {
+            self.do_callback(WaylandEvent::TextInput(event));
+        }
+        self.update_text_input();
+    }
+
+    // enables the text input while an ime spot is shown in the window that has it, and moves the candidates there
+    fn update_text_input(&mut self) {
+        if self.text_input == ptr::null_mut() {
+            return
+        }
+        unsafe {
+            match self.ime_spot {
+                Some((surface, pos)) if surface == self.text_input_focus => {
+                    if !self.text_input_enabled {
+                        self.text_input_enabled = true;
+                        zwp_text_input_v3_enable(self.text_input);
+                        zwp_text_input_v3_set_content_type(self.text_input, ZWP_TEXT_INPUT_V3_CONTENT_HINT_NONE, ZWP_TEXT_INPUT_V3_CONTENT_PURPOSE_NORMAL);
+                    }
+                    zwp_text_input_v3_set_cursor_rectangle(self.text_input, pos.x as i32, pos.y as i32, 0, 0);
+                }
+                _ if self.text_input_enabled => {
+                    self.text_input_enabled = false;
+                    zwp_text_input_v3_disable(self.text_input);
+                }
+                _ => return
+            }
+            zwp_text_input_v3_commit(self.text_input);
+        }
+    }
+
+    // applies a text input done event, the committed text replaces the shown preedit and the new preedit goes after it
+    fn text_input_done(&mut self) {
+        let mut events = Vec::new();
+        if let Some(text) = self.pending_commit.take() {
+            events.push(self.ime_preedit.commit(text));
+        }
+        let preedit = self.pending_preedit.take().unwrap_or_default();
+        if preedit.is_empty() {
+            events.extend(self.ime_preedit.cancel());
+        }
+        else {
+            if self.ime_preedit.text().is_none() {
+                self.ime_preedit.start();
+            }
+            let shown = self.ime_preedit.text().map_or(0, | text | text.chars().count());
+            events.extend(self.ime_preedit.draw(0, shown as c_int, &preedit));
+        }
+        for event in events {
+            self.do_callback(WaylandEvent::TextInput(event));
+        }
+    }
+
+    unsafe fn update_keymap(&mut self, fd: i32, size: u32) {
+        let file = File::from_raw_fd(fd);
+        let mut data = vec![0u8; size as usize];
+        if file.read_exact_at(&mut data, 0).is_err() {
+            return
+        }
+        // the keymap string is null terminated inside size
+        if let Some(end) = data.iter().position( | b | *b == 0) {
+            data.truncate(end);
+        }
+        let keymap_string = if let Ok(s) = CString::new(data) {s} else {return};
+        let keymap = xkb_keymap_new_from_string(self.xkb_context, keymap_string.as_ptr(), XKB_KEYMAP_FORMAT_TEXT_V1, 0);
+        if keymap == ptr::null_mut() {
+            return
+        }
+        if self.xkb_state != ptr::null_mut() {
+            xkb_state_unref(self.xkb_state);
+        }
+        if self.xkb_keymap != ptr::null_mut() {
+            xkb_keymap_unref(self.xkb_keymap);
+        }
+        self.xkb_keymap = keymap;
+        self.xkb_state = xkb_state_new(keymap);
+
+        // dead keys and compose sequences come from the locale
+        if self.xkb_compose_state == ptr::null_mut() {
+            let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+                .filter_map( | v | std::env::var(v).ok())
+                .find( | v | !v.is_empty())
+                .unwrap_or("C".to_string());
+            let locale = CString::new(locale).unwrap();
+            let table = xkb_compose_table_new_from_locale(self.xkb_context, locale.as_ptr(), 0);
+            if table != ptr::null_mut() {
+                self.xkb_compose_state = xkb_compose_state_new(table, 0);
+                xkb_compose_table_unref(table);
+            }
+        }
+    }
+
+    unsafe fn update_modifiers(&mut self) {
+        let state = self.xkb_state;
+        let is_active = | name: &[u8] | xkb_state_mod_name_is_active(state, name.as_ptr() as *const c_char, XKB_STATE_MODS_EFFECTIVE) > 0;
+        self.modifiers = KeyModifiers {
+            shift: is_active(XKB_MOD_NAME_SHIFT),
+            control: is_active(XKB_MOD_NAME_CTRL),
+            alt: is_active(XKB_MOD_NAME_ALT),
+            logo: is_active(XKB_MOD_NAME_LOGO),
+        };
+    }
+
+    unsafe fn key_text(&mut self, keycode: u32) -> Option<String> {
+        let mut buffer = [0u8; 64];
+        let len = xkb_state_key_get_utf8(self.xkb_state, keycode, buffer.as_mut_ptr() as *mut c_char, buffer.len());
+        if len <= 0 {
+            return None
+        }
+        std::str::from_utf8(&buffer[0..(len as usize).min(buffer.len() - 1)]).ok().map( | s | s.to_string())
+    }
+
+    unsafe fn composed_key_text(&mut self, keycode: u32, keysym: u32) -> Option<String> {
+        let compose = self.xkb_compose_state;
+        if compose == ptr::null_mut() || xkb_compose_state_feed(compose, keysym) != XKB_COMPOSE_FEED_ACCEPTED {
+            return self.key_text(keycode)
+        }
+        match xkb_compose_state_get_status(compose) {
+            XKB_COMPOSE_COMPOSING => None,
+            XKB_COMPOSE_COMPOSED => {
+                let mut buffer = [0u8; 64];
+                let len = xkb_compose_state_get_utf8(compose, buffer.as_mut_ptr() as *mut c_char, buffer.len());
+                xkb_compose_state_reset(compose);
+                if len <= 0 {
+                    return None
+                }
+                std::str::from_utf8(&buffer[0..(len as usize).min(buffer.len() - 1)]).ok().map( | s | s.to_string())
+            }
+            XKB_COMPOSE_CANCELLED => {
+                xkb_compose_state_reset(compose);
+                None
+            }
+            _ => self.key_text(keycode)
+        }
+    }
+
+    fn send_text_input(&mut self, text: String) {
+        let char_code = text.chars().next().unwrap_or('\0');
+        if char_code >= ' ' && char_code != 127 as char {
+            self.do_callback(WaylandEvent::TextInput(TextInputEvent {
+                input: text,
+                was_paste: false,
+                replace_last: false
+            }));
+        }
+    }
+
+    unsafe fn key_down(&mut self, keycode: u32) {
+        if self.xkb_state == ptr::null_mut() {
+            return
+        }
+        let keysym = xkb_state_key_get_one_sym(self.xkb_state, keycode);
+        let key_code = keysym_to_keycode(keysym);
+        let modifiers = self.modifiers.clone();
+
+        if modifiers.control || modifiers.logo {
+            match key_code {
+                KeyCode::KeyV => {
+                    if let Some(text) = self.paste_from_clipboard() {
+                        self.do_callback(WaylandEvent::TextInput(TextInputEvent {
+                            input: text,
+                            was_paste: true,
+                            replace_last: false
+                        }));
+                    }
+                }
+                KeyCode::KeyC | KeyCode::KeyX => {
+                    let response = Rc::new(RefCell::new(None));
+                    let event = TextClipboardEvent {response: response.clone()};
+                    self.do_callback(if key_code == KeyCode::KeyC {
+                        WaylandEvent::TextCopy(event)
+                    } else {
+                        WaylandEvent::TextCut(event)
+                    });
+                    let response = response.borrow();
+                    if let Some(response) = response.as_ref() {
+                        self.copy_to_clipboard(response);
+                    }
+                }
+                _ => ()
+            }
+        }
+
+        self.do_callback(WaylandEvent::KeyDown(KeyEvent {
+            key_code,
+            is_repeat: false,
+            modifiers: modifiers.clone(),
+            time: self.time_now()
+        }));
+
+        if !(modifiers.control || modifiers.logo || modifiers.alt) {
+            if let Some(text) = self.composed_key_text(keycode, keysym) {
+                self.send_text_input(text);
+            }
+        }
+
+        if self.repeat_rate > 0 && xkb_keymap_key_repeats(self.xkb_keymap, keycode) != 0 {
+            self.repeat_key = Some(keycode);
+            self.repeat_started = false;
+            self.stop_timer(KEY_REPEAT_TIMER_ID);
+            self.start_timer(KEY_REPEAT_TIMER_ID, self.repeat_delay as f64 / 1000.0, false);
+        }
+    }
+
+    unsafe fn key_up(&mut self, keycode: u32) {
+        if self.repeat_key == Some(keycode) {
+            self.repeat_key = None;
+            self.stop_timer(KEY_REPEAT_TIMER_ID);
+        }
+        if self.xkb_state == ptr::null_mut() {
+            return
+        }
+        let keysym = xkb_state_key_get_one_sym(self.xkb_state, keycode);
+        self.do_callback(WaylandEvent::KeyUp(KeyEvent {
+            key_code: keysym_to_keycode(keysym),
+            is_repeat: false,
+            modifiers: self.modifiers.clone(),
+            time: self.time_now()
+        }));
+    }
+
+    fn repeat_key(&mut self) {
+        let keycode = if let Some(keycode) = self.repeat_key {keycode} else {return};
+        // the first firing is the repeat delay, switch to the repeat rate from here
+        if !self.repeat_started {
+            self.repeat_started = true;
+            self.start_timer(KEY_REPEAT_TIMER_ID, 1.0 / self.repeat_rate as f64, true);
+        }
+        unsafe {
+            let keysym = xkb_state_key_get_one_sym(self.xkb_state, keycode);
+            let modifiers = self.modifiers.clone();
+            self.do_callback(WaylandEvent::KeyDown(KeyEvent {
+                key_code: keysym_to_keycode(keysym),
+                is_repeat: true,
+                modifiers: modifiers.clone(),
+                time: self.time_now()
+            }));
+            if !(modifiers.control || modifiers.logo || modifiers.alt) {
+                if let Some(text) = self.key_text(keycode) {
+                    self.send_text_input(text);
+                }
+            }
+        }
+    }
+
+    unsafe fn pointer_motion(&mut self, pos: DVec2) {
+        let window = if let Some(window) = self.window_for_surface(self.pointer_focus) {window} else {return};
+        let response = Rc::new(Cell::new(WindowDragQueryResponse::NoAnswer));
+        window.do_callback(WaylandEvent::WindowDragQuery(WindowDragQueryEvent {
+            window_id: window.window_id,
+            abs: pos,
+            response: response.clone()
+        }));
+        window.send_mouse_move(pos, self.modifiers.clone());
+
+        // without server side decorations we do the resize borders and caption dragging ourselves
+        let size = window.last_window_geom.inner_size;
+        let edge = if window.server_side_decorations || window.is_fullscreen || window.is_maximized {
+            None
+        }
+        else if pos.x < 10.0 && pos.y < 10.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_TOP_LEFT, MouseCursor::NwResize))
+        }
+        else if pos.x < 10.0 && pos.y >= size.y - 10.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM_LEFT, MouseCursor::SwResize))
+        }
+        else if pos.x < 5.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_LEFT, MouseCursor::WResize))
+        }
+        else if pos.x >= size.x - 10.0 && pos.y < 10.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_TOP_RIGHT, MouseCursor::NeResize))
+        }
+        else if pos.x >= size.x - 10.0 && pos.y >= size.y - 10.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM_RIGHT, MouseCursor::SeResize))
+        }
+        else if pos.x >= size.x - 5.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_RIGHT, MouseCursor::EResize))
+        }
+        else if pos.y < 5.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_TOP, MouseCursor::NResize))
+        }
+        else if pos.y >= size.y - 5.0 {
+            Some((XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM, MouseCursor::SResize))
+        }
+        else {
+            None
+        };
+        if let Some((edge, cursor)) = edge {
+            window.last_nc_mode = Some(WaylandNcMode::Resize(edge));
+            self.set_mouse_cursor(cursor);
+        }
+        else if let WindowDragQueryResponse::Caption = response.get() {
+            window.last_nc_mode = Some(WaylandNcMode::Move);
+        }
+        else {
+            window.last_nc_mode = None;
+        }
+    }
+
+    unsafe fn pointer_button(&mut self, serial: u32, button: u32, state: u32) {
+        let window = if let Some(window) = self.window_for_surface(self.pointer_focus) {window} else {return};
+        let button = match button {
+            BTN_LEFT => 0,
+            BTN_RIGHT => 1,
+            BTN_MIDDLE => 2,
+            other => other.saturating_sub(BTN_LEFT) as usize
+        };
+        if state != WL_POINTER_BUTTON_STATE_PRESSED {
+            window.send_mouse_up(button, self.modifiers.clone());
+            return
+        }
+        self.button_serial = serial;
+        let time_now = self.time_now();
+        let pos = window.last_mouse_pos;
+        let is_double_click = time_now - self.last_click_time < 0.35 && (pos - self.last_click_pos).length() < 5.0;
+        self.last_click_time = time_now;
+        self.last_click_pos = pos;
+        match window.last_nc_mode {
+            Some(WaylandNcMode::Move) if button == 0 => {
+                if is_double_click {
+                    if window.is_maximized {
+                        window.restore();
+                    }
+                    else {
+                        window.maximize();
+                    }
+                }
+                else {
+                    xdg_toplevel_move(window.toplevel, self.seat, serial);
+                }
+            }
+            Some(WaylandNcMode::Resize(edge)) if button == 0 => {
+                xdg_toplevel_resize(window.toplevel, self.seat, serial, edge);
+            }
+            _ => {
+                window.send_mouse_down(button, self.modifiers.clone());
+            }
+        }
+    }
+
+    fn pointer_frame(&mut self) {
+        if self.pointer_scroll == DVec2::default() {
+            return
+        }
+        let scroll = self.pointer_scroll;
+        let is_mouse = self.pointer_scroll_is_mouse;
+        self.pointer_scroll = DVec2::default();
+        self.pointer_scroll_is_mouse = true;
+        self.pointer_scroll_discrete = false;
+        if let Some(window) = self.window_for_surface(self.pointer_focus) {
+            self.do_callback(WaylandEvent::Scroll(ScrollEvent {
+                window_id: window.window_id,
+                scroll,
+                abs: window.last_mouse_pos,
+                modifiers: self.modifiers.clone(),
+                is_mouse,
+                handled_x: Cell::new(false),
+                handled_y: Cell::new(false),
+                time: self.time_now()
+            }))
+        }
+    }
+}
+
+static REGISTRY_LISTENER: wl_registry_listener = wl_registry_listener {
+    global: registry_global,
+    global_remove: registry_global_remove,
+};
+
+unsafe extern "C" fn registry_global(_data: *mut c_void, registry: *mut wl_registry, name: u32, interface: *const c_char, version: u32) {
+    let app = get_wayland_app_global();
+    match CStr::from_ptr(interface).to_bytes() {
+        b"wl_compositor" => {
+            app.compositor = wl_registry_bind(registry, name, &wl_compositor_interface, version.min(4));
+        }
+        b"xdg_wm_base" => {
+            app.wm_base = wl_registry_bind(registry, name, &xdg_wm_base_interface, version.min(2));
+            wl_proxy_add_listener_data(app.wm_base, &WM_BASE_LISTENER, ptr::null_mut());
+        }
+        b"wl_shm" => {
+            app.shm = wl_registry_bind(registry, name, &wl_shm_interface, 1);
+        }
+        b"wl_seat" if app.seat == ptr::null_mut() => {
+            app.seat_version = version.min(5);
+            app.seat = wl_registry_bind(registry, name, &wl_seat_interface, app.seat_version);
+            wl_proxy_add_listener_data(app.seat, &SEAT_LISTENER, ptr::null_mut());
+        }
+        b"wl_output" => {
+            let output = wl_registry_bind(registry, name, &wl_output_interface, version.min(2));
+            wl_proxy_add_listener_data(output, &OUTPUT_LISTENER, ptr::null_mut());
+            app.outputs.push(WaylandOutput {output, name, scale: 1});
+        }
+        b"wl_data_device_manager" => {
+            app.data_device_manager = wl_registry_bind(registry, name, &wl_data_device_manager_interface, version.min(3));
+        }
+        b"wp_fractional_scale_manager_v1" => {
+            app.fractional_scale_manager = wl_registry_bind(registry, name, &wp_fractional_scale_manager_v1_interface, 1);
+        }
+        b"wp_viewporter" => {
+            app.viewporter = wl_registry_bind(registry, name, &wp_viewporter_interface, 1);
+        }
+        b"zxdg_decoration_manager_v1" => {
+            app.decoration_manager = wl_registry_bind(registry, name, &zxdg_decoration_manager_v1_interface, 1);
+        }
+        b"zwp_text_input_manager_v3" => {
+            app.text_input_manager = wl_registry_bind(registry, name, &zwp_text_input_manager_v3_interface, 1);
+        }
+        _ => ()
+    }
+}
+
+unsafe extern "C" fn registry_global_remove(_data: *mut c_void, _registry: *mut wl_registry, name: u32) {
+    let app = get_wayland_app_global();
+    if let Some(index) = app.outputs.iter().position( | o | o.name == name) {
+        let output = app.outputs.remove(index);
+        for window in app.window_map.values() {
+            (**window).outputs.retain( | o | *o != output.output);
+        }
+        wl_proxy_destroy(output.output);
+    }
+}
+
+static WM_BASE_LISTENER: xdg_wm_base_listener = xdg_wm_base_listener {
+    ping: wm_base_ping,
+};
+
+unsafe extern "C" fn wm_base_ping(_data: *mut c_void, wm_base: *mut xdg_wm_base, serial: u32) {
+    xdg_wm_base_pong(wm_base, serial);
+}
+
+static OUTPUT_LISTENER: wl_output_listener = wl_output_listener {
+    geometry: output_geometry,
+    mode: output_mode,
+    done: output_done,
+    scale: output_scale,
+};
+
+unsafe extern "C" fn output_geometry(_: *mut c_void, _: *mut wl_output, _: i32, _: i32, _: i32, _: i32, _: i32, _: *const c_char, _: *const c_char, _: i32) {}
+unsafe extern "C" fn output_mode(_: *mut c_void, _: *mut wl_output, _: u32, _: i32, _: i32, _: i32) {}
+
+unsafe extern "C" fn output_done(_data: *mut c_void, output: *mut wl_output) {
+    let app = get_wayland_app_global();
+    for window in app.window_map.values() {
+        let window = &mut **window;
+        if window.outputs.contains(&output) {
+            window.send_change_event();
+        }
+    }
+}
+
+unsafe extern "C" fn output_scale(_data: *mut c_void, output: *mut wl_output, factor: i32) {
+    let app = get_wayland_app_global();
+    if let Some(o) = app.outputs.iter_mut().find( | o | o.output == output) {
+        o.scale = factor;
+    }
+}
+
+static SEAT_LISTENER: wl_seat_listener = wl_seat_listener {
+    capabilities: seat_capabilities,
+    name: seat_name,
+};
+
+unsafe extern "C" fn seat_capabilities(_data: *mut c_void, seat: *mut wl_seat, capabilities: u32) {
+    let app = get_wayland_app_global();
+    let has_pointer = capabilities & WL_SEAT_CAPABILITY_POINTER != 0;
+    if has_pointer && app.pointer == ptr::null_mut() {
+        app.pointer = wl_seat_get_pointer(seat);
+        wl_proxy_add_listener_data(app.pointer, &POINTER_LISTENER, ptr::null_mut());
+    }
+    else if !has_pointer && app.pointer != ptr::null_mut() {
+        if app.seat_version >= 3 {wl_pointer_release(app.pointer)} else {wl_proxy_destroy(app.pointer)}
+        app.pointer = ptr::null_mut();
+    }
+    let has_keyboard = capabilities & WL_SEAT_CAPABILITY_KEYBOARD != 0;
+    if has_keyboard && app.keyboard == ptr::null_mut() {
+        app.keyboard = wl_seat_get_keyboard(seat);
+        wl_proxy_add_listener_data(app.keyboard, &KEYBOARD_LISTENER, ptr::null_mut());
+    }
+    else if !has_keyboard && app.keyboard != ptr::null_mut() {
+        if app.seat_version >= 3 {wl_keyboard_release(app.keyboard)} else {wl_proxy_destroy(app.keyboard)}
+        app.keyboard = ptr::null_mut();
+    }
+}
+
+unsafe extern "C" fn seat_name(_: *mut c_void, _: *mut wl_seat, _: *const c_char) {}
+
+static POINTER_LISTENER: wl_pointer_listener = wl_pointer_listener {
+    enter: pointer_enter,
+    leave: pointer_leave,
+    motion: pointer_motion,
+    button: pointer_button,
+    axis: pointer_axis,
+    frame: pointer_frame,
+    axis_source: pointer_axis_source,
+    axis_stop: pointer_axis_stop,
+    axis_discrete: pointer_axis_discrete,
+};
+
+unsafe extern "C" fn pointer_enter(_data: *mut c_void, _pointer: *mut wl_pointer, serial: u32, surface: *mut wl_surface, x: wl_fixed_t, y: wl_fixed_t) {
+    let app = get_wayland_app_global();
+    if !app.window_map.contains_key(&surface) {
+        return
+    }
+    app.pointer_focus = surface;
+    app.pointer_serial = serial;
+    app.apply_mouse_cursor();
+    app.pointer_motion(DVec2 {x: wl_fixed_to_f64(x), y: wl_fixed_to_f64(y)});
+}
+
+unsafe extern "C" fn pointer_leave(_data: *mut c_void, _pointer: *mut wl_pointer, _serial: u32, surface: *mut wl_surface) {
+    let app = get_wayland_app_global();
+    if app.pointer_focus == surface {
+        app.pointer_focus = ptr::null_mut();
+    }
+}
+
+unsafe extern "C" fn pointer_motion(_data: *mut c_void, _pointer: *mut wl_pointer, _time: u32, x: wl_fixed_t, y: wl_fixed_t) {
+    get_wayland_app_global().pointer_motion(DVec2 {x: wl_fixed_to_f64(x), y: wl_fixed_to_f64(y)});
+}
+
+unsafe extern "C" fn pointer_button(_data: *mut c_void, _pointer: *mut wl_pointer, serial: u32, _time: u32, button: u32, state: u32) {
+    let app = get_wayland_app_global();
+    app.last_serial = serial;
+    app.pointer_button(serial, button, state);
+}
+
+unsafe extern "C" fn pointer_axis(_data: *mut c_void, _pointer: *mut wl_pointer, _time: u32, axis: u32, value: wl_fixed_t) {
+    let app = get_wayland_app_global();
+    // wheels report whole notches through axis_discrete, which we scale like a windows wheel delta
+    if !app.pointer_scroll_discrete {
+        let value = wl_fixed_to_f64(value);
+        match axis {
+            WL_POINTER_AXIS_VERTICAL_SCROLL => app.pointer_scroll.y += value,
+            WL_POINTER_AXIS_HORIZONTAL_SCROLL => app.pointer_scroll.x += value,
+            _ => ()
+        }
+    }
+    // before seat version 5 there are no frame events to group axis events
+    if app.seat_version < 5 {
+        app.pointer_frame();
+    }
+}
+
+unsafe extern "C" fn pointer_frame(_data: *mut c_void, _pointer: *mut wl_pointer) {
+    get_wayland_app_global().pointer_frame();
+}
+
+unsafe extern "C" fn pointer_axis_source(_data: *mut c_void, _pointer: *mut wl_pointer, axis_source: u32) {
+    get_wayland_app_global().pointer_scroll_is_mouse = axis_source == WL_POINTER_AXIS_SOURCE_WHEEL;
+}
+
+unsafe extern "C" fn pointer_axis_stop(_: *mut c_void, _: *mut wl_pointer, _: u32, _: u32) {}
+
+unsafe extern "C" fn pointer_axis_discrete(_data: *mut c_void, _pointer: *mut wl_pointer, axis: u32, discrete: i32) {
+    let app = get_wayland_app_global();
+    app.pointer_scroll_discrete = true;
+    match axis {
+        WL_POINTER_AXIS_VERTICAL_SCROLL => app.pointer_scroll.y += discrete as f64 * 120.0,
+        WL_POINTER_AXIS_HORIZONTAL_SCROLL => app.pointer_scroll.x += discrete as f64 * 120.0,
+        _ => ()
+    }
+}
+
+static KEYBOARD_LISTENER: wl_keyboard_listener = wl_keyboard_listener {
+    keymap: keyboard_keymap,
+    enter: keyboard_enter,
+    leave: keyboard_leave,
+    key: keyboard_key,
+    modifiers: keyboard_modifiers,
+    repeat_info: keyboard_repeat_info,
+};
+
+unsafe extern "C" fn keyboard_keymap(_data: *mut c_void, _keyboard: *mut wl_keyboard, format: u32, fd: i32, size: u32) {
+    if format != WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1 {
+        libc_sys::close(fd);
+        return
+    }
+    get_wayland_app_global().update_keymap(fd, size);
+}
+
+unsafe extern "C" fn keyboard_enter(_data: *mut c_void, _keyboard: *mut wl_keyboard, serial: u32, surface: *mut wl_surface, _keys: *mut wl_array) {
+    let app = get_wayland_app_global();
+    app.last_serial = serial;
+    if let Some(window) = app.window_for_surface(surface) {
+        app.keyboard_focus = surface;
+        window.send_focus_event();
+    }
+}
+
+unsafe extern "C" fn keyboard_leave(_data: *mut c_void, _keyboard: *mut wl_keyboard, _serial: u32, surface: *mut wl_surface) {
+    let app = get_wayland_app_global();
+    app.repeat_key = None;
+    app.stop_timer(KEY_REPEAT_TIMER_ID);
+    if app.keyboard_focus == surface {
+        app.keyboard_focus = ptr::null_mut();
+    }
+    if let Some(window) = app.window_for_surface(surface) {
+        window.send_focus_lost_event();
+    }
+}
+
+unsafe extern "C" fn keyboard_key(_data: *mut c_void, _keyboard: *mut wl_keyboard, serial: u32, _time: u32, key: u32, state: u32) {
+    let app = get_wayland_app_global();
+    app.last_serial = serial;
+    // wayland sends evdev scancodes, xkb keycodes are offset by 8
+    if state == WL_KEYBOARD_KEY_STATE_PRESSED {
+        app.key_down(key + 8);
+    }
+    else {
+        app.key_up(key + 8);
+    }
+}
+
+unsafe extern "C" fn keyboard_modifiers(_data: *mut c_void, _keyboard: *mut wl_keyboard, _serial: u32, depressed: u32, latched: u32, locked: u32, group: u32) {
+    let app = get_wayland_app_global();
+    if app.xkb_state == ptr::null_mut() {
+        return
+    }
+    xkb_state_update_mask(app.xkb_state, depressed, latched, locked, 0, 0, group);
+    app.update_modifiers();
+}
+
+unsafe extern "C" fn keyboard_repeat_info(_data: *mut c_void, _keyboard: *mut wl_keyboard, rate: i32, delay: i32) {
+    let app = get_wayland_app_global();
+    app.repeat_rate = rate;
+    app.repeat_delay = delay;
+}
+
+static DATA_DEVICE_LISTENER: wl_data_device_listener = wl_data_device_listener {
+    data_offer: data_device_data_offer,
+    enter: data_device_enter,
+    leave: data_device_leave,
+    motion: data_device_motion,
+    drop: data_device_drop,
+    selection: data_device_selection,
+};
+
+unsafe extern "C" fn data_device_data_offer(_data: *mut c_void, _data_device: *mut wl_data_device, offer: *mut wl_data_offer) {
+    get_wayland_app_global().offer_mime_types.insert(offer, Vec::new());
+    wl_proxy_add_listener_data(offer, &DATA_OFFER_LISTENER, ptr::null_mut());
+}
+
+unsafe extern "C" fn data_device_enter(_data: *mut c_void, _data_device: *mut wl_data_device, _serial: u32, _surface: *mut wl_surface, _x: wl_fixed_t, _y: wl_fixed_t, offer: *mut wl_data_offer) {
+    // we dont accept drops yet, only keep track of the offer so it gets cleaned up
+    get_wayland_app_global().drag_offer = offer;
+}
+
+unsafe extern "C" fn data_device_leave(_data: *mut c_void, _data_device: *mut wl_data_device) {
+    let app = get_wayland_app_global();
+    if app.drag_offer != ptr::null_mut() {
+        app.offer_mime_types.remove(&app.drag_offer);
+        wl_data_offer_destroy(app.drag_offer);
+        app.drag_offer = ptr::null_mut();
+    }
+}
+
+unsafe extern "C" fn data_device_motion(_: *mut c_void, _: *mut wl_data_device, _: u32, _: wl_fixed_t, _: wl_fixed_t) {}
+unsafe extern "C" fn data_device_drop(_: *mut c_void, _: *mut wl_data_device) {}
+
+unsafe extern "C" fn data_device_selection(_data: *mut c_void, _data_device: *mut wl_data_device, offer: *mut wl_data_offer) {
+    let app = get_wayland_app_global();
+    if app.selection_offer != ptr::null_mut() && app.selection_offer != offer {
+        app.offer_mime_types.remove(&app.selection_offer);
+        wl_data_offer_destroy(app.selection_offer);
+    }
+    app.selection_offer = offer;
+}
+
+static DATA_OFFER_LISTENER: wl_data_offer_listener = wl_data_offer_listener {
+    offer: data_offer_offer,
+    source_actions: data_offer_source_actions,
+    action: data_offer_action,
+};
+
+unsafe extern "C" fn data_offer_offer(_data: *mut c_void, offer: *mut wl_data_offer, mime_type: *const c_char) {
+    if let Some(mime_types) = get_wayland_app_global().offer_mime_types.get_mut(&offer) {
+        mime_types.push(CStr::from_ptr(mime_type).to_string_lossy().to_string());
+    }
+}
+
+unsafe extern "C" fn data_offer_source_actions(_: *mut c_void, _: *mut wl_data_offer, _: u32) {}
+unsafe extern "C" fn data_offer_action(_: *mut c_void, _: *mut wl_data_offer, _: u32) {}
+
+static DATA_SOURCE_LISTENER: wl_data_source_listener = wl_data_source_listener {
+    target: data_source_target,
+    send: data_source_send,
+    cancelled: data_source_cancelled,
+    dnd_drop_performed: data_source_dnd_drop_performed,
+    dnd_finished: data_source_dnd_finished,
+    action: data_source_action,
+};
+
+unsafe extern "C" fn data_source_target(_: *mut c_void, _: *mut wl_data_source, _: *const c_char) {}
+
+unsafe extern "C" fn data_source_send(_data: *mut c_void, source: *mut wl_data_source, _mime_type: *const c_char, fd: i32) {
+    let app = get_wayland_app_global();
+    let mut file = File::from_raw_fd(fd);
+    let data = if source == app.drag_source {&app.drag_uri_list} else {&app.clipboard};
+    let _ = file.write_all(data.as_bytes());
+}
+
+unsafe extern "C" fn data_source_cancelled(_data: *mut c_void, source: *mut wl_data_source) {
+    let app = get_wayland_app_global();
+    if app.drag_source == source {
+        app.end_dragging(source);
+        return
+    }
+    if app.clipboard_source == source {
+        app.clipboard_source = ptr::null_mut();
+    }
+    wl_data_source_destroy(source);
+}
+
+unsafe extern "C" fn data_source_dnd_drop_performed(_: *mut c_void, _: *mut wl_data_source) {}
+
+unsafe extern "C" fn data_source_dnd_finished(_data: *mut c_void, source: *mut wl_data_source) {
+    get_wayland_app_global().end_dragging(source);
+}
+
+unsafe extern "C" fn data_source_action(_: *mut c_void, _: *mut wl_data_source, _: u32) {}
+
+static TEXT_INPUT_LISTENER: zwp_text_input_v3_listener = zwp_text_input_v3_listener {
+    enter: text_input_enter,
+    leave: text_input_leave,
+    preedit_string: text_input_preedit_string,
+    commit_string: text_input_commit_string,
+    delete_surrounding_text: text_input_delete_surrounding_text,
+    done: text_input_done,
+};
+
+unsafe extern "C" fn text_input_enter(_data: *mut c_void, _text_input: *mut zwp_text_input_v3, surface: *mut wl_surface) {
+    let app = get_wayland_app_global();
+    app.text_input_focus = surface;
+    app.update_text_input();
+}
+
+unsafe extern "C" fn text_input_leave(_data: *mut c_void, _text_input: *mut zwp_text_input_v3, surface: *mut wl_surface) {
+    let app = get_wayland_app_global();
+    if app.text_input_focus != surface {
+        return
+    }
+    // the compositor disables the text input on leave by itself
+    app.text_input_focus = ptr::null_mut();
+    app.text_input_enabled = false;
+    if let Some(event) = app.ime_preedit.cancel() {
+        app.do_callback(WaylandEvent::TextInput(event));
+    }
+}
+
+unsafe extern "C" fn text_input_preedit_string(_data: *mut c_void, _text_input: *mut zwp_text_input_v3, text: *const c_char, _cursor_begin: i32, _cursor_end: i32) {
+    get_wayland_app_global().pending_preedit = if text == ptr::null() {None} else {
+        Some(CStr::from_ptr(text).to_string_lossy().to_string())
+    };
+}
+
+unsafe extern "C" fn text_input_commit_string(_data: *mut c_void, _text_input: *mut zwp_text_input_v3, text: *const c_char) {
+    get_wayland_app_global().pending_commit = if text == ptr::null() {None} else {
+        Some(CStr::from_ptr(text).to_string_lossy().to_string())
+    };
+}
+
+// we never send surrounding text, so there is nothing the input method could ask to delete
+unsafe extern "C" fn text_input_delete_surrounding_text(_: *mut c_void, _: *mut zwp_text_input_v3, _: u32, _: u32) {}
+
+unsafe extern "C" fn text_input_done(_data: *mut c_void, _text_input: *mut zwp_text_input_v3, _serial: u32) {
+    get_wayland_app_global().text_input_done();
+}
@@ -0,0 +1,42 @@
+use {
+    crate::{
+        event::{
+            MouseDownEvent,
+            MouseUpEvent,
+            MouseMoveEvent,
+            ScrollEvent,
+            WindowGeomChangeEvent,
+            WindowDragQueryEvent,
+            WindowCloseRequestedEvent,
+            WindowClosedEvent,
+            TextInputEvent,
+            KeyEvent,
+            TextClipboardEvent,
+            TimerEvent,
+        },
+    }
+};
+
+#[derive(Debug)]
+pub enum WaylandEvent {
+    AppGotFocus,
+    AppLostFocus,
+    WindowGeomChange(WindowGeomChangeEvent),
+    WindowClosed(WindowClosedEvent),
+    Paint,
+    
+    MouseDown(MouseDownEvent),
+    MouseUp(MouseUpEvent),
+    MouseMove(MouseMoveEvent),
+    Scroll(ScrollEvent),
+    
+    WindowDragQuery(WindowDragQueryEvent),
+    WindowCloseRequested(WindowCloseRequestedEvent),
+    TextInput(TextInputEvent),
+    KeyDown(KeyEvent),
+    KeyUp(KeyEvent),
+    TextCopy(TextClipboardEvent),
+    TextCut(TextClipboardEvent),
+    Timer(TimerEvent),
+    DragEnd,
+}
@@ -0,0 +1,742 @@
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+#![allow(dead_code)]
+
+use std::{
+    ptr,
+    os::raw::{
+        c_void,
+        c_char,
+        c_int,
+        c_uint,
+    }
+};
+
+// all protocol objects are proxies on the client side, the aliases only document intent
+pub type wl_proxy = c_void;
+pub type wl_display = wl_proxy;
+pub type wl_registry = wl_proxy;
+pub type wl_compositor = wl_proxy;
+pub type wl_surface = wl_proxy;
+pub type wl_callback = wl_proxy;
+pub type wl_output = wl_proxy;
+pub type wl_seat = wl_proxy;
+pub type wl_pointer = wl_proxy;
+pub type wl_keyboard = wl_proxy;
+pub type wl_shm = wl_proxy;
+pub type wl_buffer = wl_proxy;
+pub type wl_data_device_manager = wl_proxy;
+pub type wl_data_device = wl_proxy;
+pub type wl_data_source = wl_proxy;
+pub type wl_data_offer = wl_proxy;
+pub type xdg_wm_base = wl_proxy;
+pub type xdg_surface = wl_proxy;
+pub type xdg_toplevel = wl_proxy;
+pub type wp_fractional_scale_manager_v1 = wl_proxy;
+pub type wp_fractional_scale_v1 = wl_proxy;
+pub type wp_viewporter = wl_proxy;
+pub type wp_viewport = wl_proxy;
+pub type zxdg_decoration_manager_v1 = wl_proxy;
+pub type zxdg_toplevel_decoration_v1 = wl_proxy;
+pub type zwp_text_input_manager_v3 = wl_proxy;
+pub type zwp_text_input_v3 = wl_proxy;
+
+pub type wl_fixed_t = i32;
+pub type wl_egl_window = c_void;
+pub type wl_cursor_theme = c_void;
+
+pub const WL_MARSHAL_FLAG_DESTROY: u32 = 1;
+
+pub const WL_SEAT_CAPABILITY_POINTER: u32 = 1;
+pub const WL_SEAT_CAPABILITY_KEYBOARD: u32 = 2;
+
+pub const WL_POINTER_BUTTON_STATE_PRESSED: u32 = 1;
+pub const WL_POINTER_AXIS_VERTICAL_SCROLL: u32 = 0;
+pub const WL_POINTER_AXIS_HORIZONTAL_SCROLL: u32 = 1;
+pub const WL_POINTER_AXIS_SOURCE_WHEEL: u32 = 0;
+
+pub const WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1: u32 = 1;
+pub const WL_KEYBOARD_KEY_STATE_PRESSED: u32 = 1;
+
+pub const WL_DATA_DEVICE_MANAGER_DND_ACTION_COPY: u32 = 1;
+pub const WL_DATA_DEVICE_MANAGER_DND_ACTION_MOVE: u32 = 2;
+
+pub const XDG_TOPLEVEL_STATE_MAXIMIZED: u32 = 1;
+pub const XDG_TOPLEVEL_STATE_FULLSCREEN: u32 = 2;
+pub const XDG_TOPLEVEL_STATE_RESIZING: u32 = 3;
+pub const XDG_TOPLEVEL_STATE_ACTIVATED: u32 = 4;
+
+pub const XDG_TOPLEVEL_RESIZE_EDGE_TOP: u32 = 1;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM: u32 = 2;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_LEFT: u32 = 4;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_TOP_LEFT: u32 = 5;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM_LEFT: u32 = 6;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_RIGHT: u32 = 8;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_TOP_RIGHT: u32 = 9;
+pub const XDG_TOPLEVEL_RESIZE_EDGE_BOTTOM_RIGHT: u32 = 10;
+
+pub const ZXDG_TOPLEVEL_DECORATION_V1_MODE_SERVER_SIDE: u32 = 2;
+
+pub const ZWP_TEXT_INPUT_V3_CONTENT_HINT_NONE: u32 = 0;
+pub const ZWP_TEXT_INPUT_V3_CONTENT_PURPOSE_NORMAL: u32 = 0;
+
+pub const BTN_LEFT: u32 = 0x110;
+pub const BTN_RIGHT: u32 = 0x111;
+pub const BTN_MIDDLE: u32 = 0x112;
+
+pub fn wl_fixed_to_f64(fixed: wl_fixed_t) -> f64 {
+    fixed as f64 / 256.0
+}
+
+#[repr(C)]
+pub struct wl_message {
+    pub name: *const c_char,
+    pub signature: *const c_char,
+    pub types: *const *const wl_interface,
+}
+
+#[repr(C)]
+pub struct wl_interface {
+    pub name: *const c_char,
+    pub version: c_int,
+    pub method_count: c_int,
+    pub methods: *const wl_message,
+    pub event_count: c_int,
+    pub events: *const wl_message,
+}
+
+// the protocol tables are immutable after construction
+unsafe impl Sync for wl_message {}
+unsafe impl Sync for wl_interface {}
+
+#[repr(transparent)]
+pub struct wl_interface_ptr(pub *const wl_interface);
+unsafe impl Sync for wl_interface_ptr {}
+
+#[repr(C)]
+pub struct wl_array {
+    pub size: usize,
+    pub alloc: usize,
+    pub data: *mut c_void,
+}
+
+#[repr(C)]
+pub struct wl_cursor_image {
+    pub width: u32,
+    pub height: u32,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+    pub delay: u32,
+}
+
+#[repr(C)]
+pub struct wl_cursor {
+    pub image_count: c_uint,
+    pub images: *mut *mut wl_cursor_image,
+    pub name: *mut c_char,
+}
+
+#[link(name = "wayland-client")]
+extern "C" {
+    pub static wl_registry_interface: wl_interface;
+    pub static wl_compositor_interface: wl_interface;
+    pub static wl_surface_interface: wl_interface;
+    pub static wl_callback_interface: wl_interface;
+    pub static wl_output_interface: wl_interface;
+    pub static wl_seat_interface: wl_interface;
+    pub static wl_pointer_interface: wl_interface;
+    pub static wl_keyboard_interface: wl_interface;
+    pub static wl_shm_interface: wl_interface;
+    pub static wl_data_device_manager_interface: wl_interface;
+    pub static wl_data_device_interface: wl_interface;
+    pub static wl_data_source_interface: wl_interface;
+    pub static wl_data_offer_interface: wl_interface;
+
+    pub fn wl_display_connect(name: *const c_char) -> *mut wl_display;
+    pub fn wl_display_disconnect(display: *mut wl_display);
+    pub fn wl_display_get_fd(display: *mut wl_display) -> c_int;
+    pub fn wl_display_roundtrip(display: *mut wl_display) -> c_int;
+    pub fn wl_display_flush(display: *mut wl_display) -> c_int;
+    pub fn wl_display_dispatch_pending(display: *mut wl_display) -> c_int;
+    pub fn wl_display_prepare_read(display: *mut wl_display) -> c_int;
+    pub fn wl_display_read_events(display: *mut wl_display) -> c_int;
+    pub fn wl_display_cancel_read(display: *mut wl_display);
+    pub fn wl_display_get_error(display: *mut wl_display) -> c_int;
+
+    pub fn wl_proxy_marshal_flags(proxy: *mut wl_proxy, opcode: u32, interface: *const wl_interface, version: u32, flags: u32, ...) -> *mut wl_proxy;
+    pub fn wl_proxy_add_listener(proxy: *mut wl_proxy, implementation: *const c_void, data: *mut c_void) -> c_int;
+    pub fn wl_proxy_get_version(proxy: *mut wl_proxy) -> u32;
+    pub fn wl_proxy_destroy(proxy: *mut wl_proxy);
+}
+
+#[link(name = "wayland-egl")]
+extern "C" {
+    pub fn wl_egl_window_create(surface: *mut wl_surface, width: c_int, height: c_int) -> *mut wl_egl_window;
+    pub fn wl_egl_window_destroy(egl_window: *mut wl_egl_window);
+    pub fn wl_egl_window_resize(egl_window: *mut wl_egl_window, width: c_int, height: c_int, dx: c_int, dy: c_int);
+}
+
+#[link(name = "wayland-cursor")]
+extern "C" {
+    pub fn wl_cursor_theme_load(name: *const c_char, size: c_int, shm: *mut wl_shm) -> *mut wl_cursor_theme;
+    pub fn wl_cursor_theme_destroy(theme: *mut wl_cursor_theme);
+    pub fn wl_cursor_theme_get_cursor(theme: *mut wl_cursor_theme, name: *const c_char) -> *mut wl_cursor;
+    pub fn wl_cursor_image_get_buffer(image: *mut wl_cursor_image) -> *mut wl_buffer;
+}
+
+// Protocol tables for the interfaces libwayland-client doesn't ship, as wayland-scanner would generate them.
+// Types of core interfaces are left null, libwayland only needs them to create objects from events.
+
+macro_rules!wl_message {
+    ( $ name: literal, $ signature: literal, $ types: expr) => {
+        wl_message {
+            name: concat!( $ name, "\0").as_ptr() as *const c_char,
+            signature: concat!( $ signature, "\0").as_ptr() as *const c_char,
+            types: $ types as *const _ as *const *const wl_interface,
+        }
+    }
+}
+
+static NULL_TYPES: [wl_interface_ptr; 4] = [
+    wl_interface_ptr(ptr::null()),
+    wl_interface_ptr(ptr::null()),
+    wl_interface_ptr(ptr::null()),
+    wl_interface_ptr(ptr::null()),
+];
+
+static XDG_SURFACE_TYPES: [wl_interface_ptr; 2] = [wl_interface_ptr(&xdg_surface_interface), wl_interface_ptr(ptr::null())];
+static XDG_TOPLEVEL_TYPES: [wl_interface_ptr; 1] = [wl_interface_ptr(&xdg_toplevel_interface)];
+static XDG_PARENT_TYPES: [wl_interface_ptr; 1] = [wl_interface_ptr(&xdg_toplevel_interface)];
+static FRACTIONAL_SCALE_TYPES: [wl_interface_ptr; 2] = [wl_interface_ptr(&wp_fractional_scale_v1_interface), wl_interface_ptr(ptr::null())];
+static VIEWPORT_TYPES: [wl_interface_ptr; 2] = [wl_interface_ptr(&wp_viewport_interface), wl_interface_ptr(ptr::null())];
+static DECORATION_TYPES: [wl_interface_ptr; 2] = [wl_interface_ptr(&zxdg_toplevel_decoration_v1_interface), wl_interface_ptr(&xdg_toplevel_interface)];
+static TEXT_INPUT_TYPES: [wl_interface_ptr; 2] = [wl_interface_ptr(&zwp_text_input_v3_interface), wl_interface_ptr(ptr::null())];
+
+static XDG_WM_BASE_REQUESTS: [wl_message; 4] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("create_positioner", "n", &NULL_TYPES),
+    wl_message!("get_xdg_surface", "no", &XDG_SURFACE_TYPES),
+    wl_message!("pong", "u", &NULL_TYPES),
+];
+static XDG_WM_BASE_EVENTS: [wl_message; 1] = [
+    wl_message!("ping", "u", &NULL_TYPES),
+];
+pub static xdg_wm_base_interface: wl_interface = wl_interface {
+    name: "xdg_wm_base\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 4,
+    methods: &XDG_WM_BASE_REQUESTS as *const _ as *const wl_message,
+    event_count: 1,
+    events: &XDG_WM_BASE_EVENTS as *const _ as *const wl_message,
+};
+
+static XDG_SURFACE_REQUESTS: [wl_message; 5] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("get_toplevel", "n", &XDG_TOPLEVEL_TYPES),
+    wl_message!("get_popup", "n?oo", &NULL_TYPES),
+    wl_message!("set_window_geometry", "iiii", &NULL_TYPES),
+    wl_message!("ack_configure", "u", &NULL_TYPES),
+];
+static XDG_SURFACE_EVENTS: [wl_message; 1] = [
+    wl_message!("configure", "u", &NULL_TYPES),
+];
+pub static xdg_surface_interface: wl_interface = wl_interface {
+    name: "xdg_surface\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 5,
+    methods: &XDG_SURFACE_REQUESTS as *const _ as *const wl_message,
+    event_count: 1,
+    events: &XDG_SURFACE_EVENTS as *const _ as *const wl_message,
+};
+
+static XDG_TOPLEVEL_REQUESTS: [wl_message; 14] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("set_parent", "?o", &XDG_PARENT_TYPES),
+    wl_message!("set_title", "s", &NULL_TYPES),
+    wl_message!("set_app_id", "s", &NULL_TYPES),
+    wl_message!("show_window_menu", "ouii", &NULL_TYPES),
+    wl_message!("move", "ou", &NULL_TYPES),
+    wl_message!("resize", "ouu", &NULL_TYPES),
+    wl_message!("set_max_size", "ii", &NULL_TYPES),
+    wl_message!("set_min_size", "ii", &NULL_TYPES),
+    wl_message!("set_maximized", "", &NULL_TYPES),
+    wl_message!("unset_maximized", "", &NULL_TYPES),
+    wl_message!("set_fullscreen", "?o", &NULL_TYPES),
+    wl_message!("unset_fullscreen", "", &NULL_TYPES),
+    wl_message!("set_minimized", "", &NULL_TYPES),
+];
+static XDG_TOPLEVEL_EVENTS: [wl_message; 2] = [
+    wl_message!("configure", "iia", &NULL_TYPES),
+    wl_message!("close", "", &NULL_TYPES),
+];
+pub static xdg_toplevel_interface: wl_interface = wl_interface {
+    name: "xdg_toplevel\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 14,
+    methods: &XDG_TOPLEVEL_REQUESTS as *const _ as *const wl_message,
+    event_count: 2,
+    events: &XDG_TOPLEVEL_EVENTS as *const _ as *const wl_message,
+};
+
+static FRACTIONAL_SCALE_MANAGER_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("get_fractional_scale", "no", &FRACTIONAL_SCALE_TYPES),
+];
+pub static wp_fractional_scale_manager_v1_interface: wl_interface = wl_interface {
+    name: "wp_fractional_scale_manager_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: &FRACTIONAL_SCALE_MANAGER_REQUESTS as *const _ as *const wl_message,
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static FRACTIONAL_SCALE_REQUESTS: [wl_message; 1] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+];
+static FRACTIONAL_SCALE_EVENTS: [wl_message; 1] = [
+    wl_message!("preferred_scale", "u", &NULL_TYPES),
+];
+pub static wp_fractional_scale_v1_interface: wl_interface = wl_interface {
+    name: "wp_fractional_scale_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 1,
+    methods: &FRACTIONAL_SCALE_REQUESTS as *const _ as *const wl_message,
+    event_count: 1,
+    events: &FRACTIONAL_SCALE_EVENTS as *const _ as *const wl_message,
+};
+
+static VIEWPORTER_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("get_viewport", "no", &VIEWPORT_TYPES),
+];
+pub static wp_viewporter_interface: wl_interface = wl_interface {
+    name: "wp_viewporter\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: &VIEWPORTER_REQUESTS as *const _ as *const wl_message,
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static VIEWPORT_REQUESTS: [wl_message; 3] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("set_source", "ffff", &NULL_TYPES),
+    wl_message!("set_destination", "ii", &NULL_TYPES),
+];
+pub static wp_viewport_interface: wl_interface = wl_interface {
+    name: "wp_viewport\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 3,
+    methods: &VIEWPORT_REQUESTS as *const _ as *const wl_message,
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static DECORATION_MANAGER_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("get_toplevel_decoration", "no", &DECORATION_TYPES),
+];
+pub static zxdg_decoration_manager_v1_interface: wl_interface = wl_interface {
+    name: "zxdg_decoration_manager_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: &DECORATION_MANAGER_REQUESTS as *const _ as *const wl_message,
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static TOPLEVEL_DECORATION_REQUESTS: [wl_message; 3] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("set_mode", "u", &NULL_TYPES),
+    wl_message!("unset_mode", "", &NULL_TYPES),
+];
+static TOPLEVEL_DECORATION_EVENTS: [wl_message; 1] = [
+    wl_message!("configure", "u", &NULL_TYPES),
+];
+pub static zxdg_toplevel_decoration_v1_interface: wl_interface = wl_interface {
+    name: "zxdg_toplevel_decoration_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 3,
+    methods: &TOPLEVEL_DECORATION_REQUESTS as *const _ as *const wl_message,
+    event_count: 1,
+    events: &TOPLEVEL_DECORATION_EVENTS as *const _ as *const wl_message,
+};
+
+static TEXT_INPUT_MANAGER_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("get_text_input", "no", &TEXT_INPUT_TYPES),
+];
+pub static zwp_text_input_manager_v3_interface: wl_interface = wl_interface {
+    name: "zwp_text_input_manager_v3\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: &TEXT_INPUT_MANAGER_REQUESTS as *const _ as *const wl_message,
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static TEXT_INPUT_REQUESTS: [wl_message; 8] = [
+    wl_message!("destroy", "", &NULL_TYPES),
+    wl_message!("enable", "", &NULL_TYPES),
+    wl_message!("disable", "", &NULL_TYPES),
+    wl_message!("set_surrounding_text", "sii", &NULL_TYPES),
+    wl_message!("set_text_change_cause", "u", &NULL_TYPES),
+    wl_message!("set_content_type", "uu", &NULL_TYPES),
+    wl_message!("set_cursor_rectangle", "iiii", &NULL_TYPES),
+    wl_message!("commit", "", &NULL_TYPES),
+];
+static TEXT_INPUT_EVENTS: [wl_message; 6] = [
+    wl_message!("enter", "o", &NULL_TYPES),
+    wl_message!("leave", "o", &NULL_TYPES),
+    wl_message!("preedit_string", "?sii", &NULL_TYPES),
+    wl_message!("commit_string", "?s", &NULL_TYPES),
+    wl_message!("delete_surrounding_text", "uu", &NULL_TYPES),
+    wl_message!("done", "u", &NULL_TYPES),
+];
+pub static zwp_text_input_v3_interface: wl_interface = wl_interface {
+    name: "zwp_text_input_v3\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 8,
+    methods: &TEXT_INPUT_REQUESTS as *const _ as *const wl_message,
+    event_count: 6,
+    events: &TEXT_INPUT_EVENTS as *const _ as *const wl_message,
+};
+
+// Listeners, the field order has to match the event order of the protocol
+
+#[repr(C)]
+pub struct wl_registry_listener {
+    pub global: unsafe extern "C" fn(data: *mut c_void, registry: *mut wl_registry, name: u32, interface: *const c_char, version: u32),
+    pub global_remove: unsafe extern "C" fn(data: *mut c_void, registry: *mut wl_registry, name: u32),
+}
+
+#[repr(C)]
+pub struct wl_callback_listener {
+    pub done: unsafe extern "C" fn(data: *mut c_void, callback: *mut wl_callback, callback_data: u32),
+}
+
+#[repr(C)]
+pub struct wl_surface_listener {
+    pub enter: unsafe extern "C" fn(data: *mut c_void, surface: *mut wl_surface, output: *mut wl_output),
+    pub leave: unsafe extern "C" fn(data: *mut c_void, surface: *mut wl_surface, output: *mut wl_output),
+}
+
+#[repr(C)]
+pub struct wl_output_listener {
+    pub geometry: unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, x: i32, y: i32, physical_width: i32, physical_height: i32, subpixel: i32, make: *const c_char, model: *const c_char, transform: i32),
+    pub mode: unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, flags: u32, width: i32, height: i32, refresh: i32),
+    pub done: unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output),
+    pub scale: unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, factor: i32),
+}
+
+#[repr(C)]
+pub struct wl_seat_listener {
+    pub capabilities: unsafe extern "C" fn(data: *mut c_void, seat: *mut wl_seat, capabilities: u32),
+    pub name: unsafe extern "C" fn(data: *mut c_void, seat: *mut wl_seat, name: *const c_char),
+}
+
+#[repr(C)]
+pub struct wl_pointer_listener {
+    pub enter: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, serial: u32, surface: *mut wl_surface, surface_x: wl_fixed_t, surface_y: wl_fixed_t),
+    pub leave: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, serial: u32, surface: *mut wl_surface),
+    pub motion: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, time: u32, surface_x: wl_fixed_t, surface_y: wl_fixed_t),
+    pub button: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, serial: u32, time: u32, button: u32, state: u32),
+    pub axis: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, time: u32, axis: u32, value: wl_fixed_t),
+    pub frame: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer),
+    pub axis_source: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, axis_source: u32),
+    pub axis_stop: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, time: u32, axis: u32),
+    pub axis_discrete: unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, axis: u32, discrete: i32),
+}
+
+#[repr(C)]
+pub struct wl_keyboard_listener {
+    pub keymap: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, format: u32, fd: i32, size: u32),
+    pub enter: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, serial: u32, surface: *mut wl_surface, keys: *mut wl_array),
+    pub leave: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, serial: u32, surface: *mut wl_surface),
+    pub key: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, serial: u32, time: u32, key: u32, state: u32),
+    pub modifiers: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, serial: u32, mods_depressed: u32, mods_latched: u32, mods_locked: u32, group: u32),
+    pub repeat_info: unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, rate: i32, delay: i32),
+}
+
+#[repr(C)]
+pub struct wl_data_device_listener {
+    pub data_offer: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device, offer: *mut wl_data_offer),
+    pub enter: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device, serial: u32, surface: *mut wl_surface, x: wl_fixed_t, y: wl_fixed_t, offer: *mut wl_data_offer),
+    pub leave: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device),
+    pub motion: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device, time: u32, x: wl_fixed_t, y: wl_fixed_t),
+    pub drop: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device),
+    pub selection: unsafe extern "C" fn(data: *mut c_void, data_device: *mut wl_data_device, offer: *mut wl_data_offer),
+}
+
+// the data device manager is bound at version 3, so these carry the drag and drop action events
+#[repr(C)]
+pub struct wl_data_offer_listener {
+    pub offer: unsafe extern "C" fn(data: *mut c_void, offer: *mut wl_data_offer, mime_type: *const c_char),
+    pub source_actions: unsafe extern "C" fn(data: *mut c_void, offer: *mut wl_data_offer, source_actions: u32),
+    pub action: unsafe extern "C" fn(data: *mut c_void, offer: *mut wl_data_offer, dnd_action: u32),
+}
+
+#[repr(C)]
+pub struct wl_data_source_listener {
+    pub target: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source, mime_type: *const c_char),
+    pub send: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source, mime_type: *const c_char, fd: i32),
+    pub cancelled: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source),
+    pub dnd_drop_performed: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source),
+    pub dnd_finished: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source),
+    pub action: unsafe extern "C" fn(data: *mut c_void, source: *mut wl_data_source, dnd_action: u32),
+}
+
+#[repr(C)]
+pub struct xdg_wm_base_listener {
+    pub ping: unsafe extern "C" fn(data: *mut c_void, wm_base: *mut xdg_wm_base, serial: u32),
+}
+
+#[repr(C)]
+pub struct xdg_surface_listener {
+    pub configure: unsafe extern "C" fn(data: *mut c_void, xdg_surface: *mut xdg_surface, serial: u32),
+}
+
+#[repr(C)]
+pub struct xdg_toplevel_listener {
+    pub configure: unsafe extern "C" fn(data: *mut c_void, toplevel: *mut xdg_toplevel, width: i32, height: i32, states: *mut wl_array),
+    pub close: unsafe extern "C" fn(data: *mut c_void, toplevel: *mut xdg_toplevel),
+}
+
+#[repr(C)]
+pub struct wp_fractional_scale_v1_listener {
+    pub preferred_scale: unsafe extern "C" fn(data: *mut c_void, fractional_scale: *mut wp_fractional_scale_v1, scale: u32),
+}
+
+#[repr(C)]
+pub struct zxdg_toplevel_decoration_v1_listener {
+    pub configure: unsafe extern "C" fn(data: *mut c_void, decoration: *mut zxdg_toplevel_decoration_v1, mode: u32),
+}
+
+#[repr(C)]
+pub struct zwp_text_input_v3_listener {
+    pub enter: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, surface: *mut wl_surface),
+    pub leave: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, surface: *mut wl_surface),
+    pub preedit_string: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, text: *const c_char, cursor_begin: i32, cursor_end: i32),
+    pub commit_string: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, text: *const c_char),
+    pub delete_surrounding_text: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, before_length: u32, after_length: u32),
+    pub done: unsafe extern "C" fn(data: *mut c_void, text_input: *mut zwp_text_input_v3, serial: u32),
+}
+
+pub unsafe fn wl_proxy_add_listener_data<T>(proxy: *mut wl_proxy, listener: &'static T, data: *mut c_void) -> c_int {
+    wl_proxy_add_listener(proxy, listener as *const T as *const c_void, data)
+}
+
+// Request stubs, like the static inline functions wayland-scanner generates
+
+pub unsafe fn wl_display_get_registry(display: *mut wl_display) -> *mut wl_registry {
+    wl_proxy_marshal_flags(display, 1, &wl_registry_interface, wl_proxy_get_version(display), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_registry_bind(registry: *mut wl_registry, name: u32, interface: *const wl_interface, version: u32) -> *mut wl_proxy {
+    wl_proxy_marshal_flags(registry, 0, interface, version, 0, name, (*interface).name, version, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_compositor_create_surface(compositor: *mut wl_compositor) -> *mut wl_surface {
+    wl_proxy_marshal_flags(compositor, 0, &wl_surface_interface, wl_proxy_get_version(compositor), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_surface_destroy(surface: *mut wl_surface) {
+    wl_proxy_marshal_flags(surface, 0, ptr::null(), wl_proxy_get_version(surface), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wl_surface_attach(surface: *mut wl_surface, buffer: *mut wl_buffer, x: i32, y: i32) {
+    wl_proxy_marshal_flags(surface, 1, ptr::null(), wl_proxy_get_version(surface), 0, buffer, x, y);
+}
+
+pub unsafe fn wl_surface_damage(surface: *mut wl_surface, x: i32, y: i32, width: i32, height: i32) {
+    wl_proxy_marshal_flags(surface, 2, ptr::null(), wl_proxy_get_version(surface), 0, x, y, width, height);
+}
+
+pub unsafe fn wl_surface_frame(surface: *mut wl_surface) -> *mut wl_callback {
+    wl_proxy_marshal_flags(surface, 3, &wl_callback_interface, wl_proxy_get_version(surface), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_surface_commit(surface: *mut wl_surface) {
+    wl_proxy_marshal_flags(surface, 6, ptr::null(), wl_proxy_get_version(surface), 0);
+}
+
+pub unsafe fn wl_surface_set_buffer_scale(surface: *mut wl_surface, scale: i32) {
+    wl_proxy_marshal_flags(surface, 8, ptr::null(), wl_proxy_get_version(surface), 0, scale);
+}
+
+pub unsafe fn wl_callback_destroy(callback: *mut wl_callback) {
+    wl_proxy_destroy(callback);
+}
+
+pub unsafe fn wl_seat_get_pointer(seat: *mut wl_seat) -> *mut wl_pointer {
+    wl_proxy_marshal_flags(seat, 0, &wl_pointer_interface, wl_proxy_get_version(seat), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_seat_get_keyboard(seat: *mut wl_seat) -> *mut wl_keyboard {
+    wl_proxy_marshal_flags(seat, 1, &wl_keyboard_interface, wl_proxy_get_version(seat), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_pointer_set_cursor(pointer: *mut wl_pointer, serial: u32, surface: *mut wl_surface, hotspot_x: i32, hotspot_y: i32) {
+    wl_proxy_marshal_flags(pointer, 0, ptr::null(), wl_proxy_get_version(pointer), 0, serial, surface, hotspot_x, hotspot_y);
+}
+
+pub unsafe fn wl_pointer_release(pointer: *mut wl_pointer) {
+    wl_proxy_marshal_flags(pointer, 1, ptr::null(), wl_proxy_get_version(pointer), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wl_keyboard_release(keyboard: *mut wl_keyboard) {
+    wl_proxy_marshal_flags(keyboard, 0, ptr::null(), wl_proxy_get_version(keyboard), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wl_data_device_manager_create_data_source(manager: *mut wl_data_device_manager) -> *mut wl_data_source {
+    wl_proxy_marshal_flags(manager, 0, &wl_data_source_interface, wl_proxy_get_version(manager), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn wl_data_device_manager_get_data_device(manager: *mut wl_data_device_manager, seat: *mut wl_seat) -> *mut wl_data_device {
+    wl_proxy_marshal_flags(manager, 1, &wl_data_device_interface, wl_proxy_get_version(manager), 0, ptr::null_mut::<c_void>(), seat)
+}
+
+pub unsafe fn wl_data_device_start_drag(data_device: *mut wl_data_device, source: *mut wl_data_source, origin: *mut wl_surface, icon: *mut wl_surface, serial: u32) {
+    wl_proxy_marshal_flags(data_device, 0, ptr::null(), wl_proxy_get_version(data_device), 0, source, origin, icon, serial);
+}
+
+pub unsafe fn wl_data_device_set_selection(data_device: *mut wl_data_device, source: *mut wl_data_source, serial: u32) {
+    wl_proxy_marshal_flags(data_device, 1, ptr::null(), wl_proxy_get_version(data_device), 0, source, serial);
+}
+
+pub unsafe fn wl_data_source_offer(source: *mut wl_data_source, mime_type: *const c_char) {
+    wl_proxy_marshal_flags(source, 0, ptr::null(), wl_proxy_get_version(source), 0, mime_type);
+}
+
+pub unsafe fn wl_data_source_destroy(source: *mut wl_data_source) {
+    wl_proxy_marshal_flags(source, 1, ptr::null(), wl_proxy_get_version(source), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wl_data_source_set_actions(source: *mut wl_data_source, dnd_actions: u32) {
+    wl_proxy_marshal_flags(source, 2, ptr::null(), wl_proxy_get_version(source), 0, dnd_actions);
+}
+
+pub unsafe fn wl_data_offer_receive(offer: *mut wl_data_offer, mime_type: *const c_char, fd: i32) {
+    wl_proxy_marshal_flags(offer, 1, ptr::null(), wl_proxy_get_version(offer), 0, mime_type, fd);
+}
+
+pub unsafe fn wl_data_offer_destroy(offer: *mut wl_data_offer) {
+    wl_proxy_marshal_flags(offer, 2, ptr::null(), wl_proxy_get_version(offer), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn xdg_wm_base_get_xdg_surface(wm_base: *mut xdg_wm_base, surface: *mut wl_surface) -> *mut xdg_surface {
+    wl_proxy_marshal_flags(wm_base, 2, &xdg_surface_interface, wl_proxy_get_version(wm_base), 0, ptr::null_mut::<c_void>(), surface)
+}
+
+pub unsafe fn xdg_wm_base_pong(wm_base: *mut xdg_wm_base, serial: u32) {
+    wl_proxy_marshal_flags(wm_base, 3, ptr::null(), wl_proxy_get_version(wm_base), 0, serial);
+}
+
+pub unsafe fn xdg_surface_destroy(xdg_surface: *mut xdg_surface) {
+    wl_proxy_marshal_flags(xdg_surface, 0, ptr::null(), wl_proxy_get_version(xdg_surface), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn xdg_surface_get_toplevel(xdg_surface: *mut xdg_surface) -> *mut xdg_toplevel {
+    wl_proxy_marshal_flags(xdg_surface, 1, &xdg_toplevel_interface, wl_proxy_get_version(xdg_surface), 0, ptr::null_mut::<c_void>())
+}
+
+pub unsafe fn xdg_surface_ack_configure(xdg_surface: *mut xdg_surface, serial: u32) {
+    wl_proxy_marshal_flags(xdg_surface, 4, ptr::null(), wl_proxy_get_version(xdg_surface), 0, serial);
+}
+
+pub unsafe fn xdg_toplevel_destroy(toplevel: *mut xdg_toplevel) {
+    wl_proxy_marshal_flags(toplevel, 0, ptr::null(), wl_proxy_get_version(toplevel), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn xdg_toplevel_set_title(toplevel: *mut xdg_toplevel, title: *const c_char) {
+    wl_proxy_marshal_flags(toplevel, 2, ptr::null(), wl_proxy_get_version(toplevel), 0, title);
+}
+
+pub unsafe fn xdg_toplevel_set_app_id(toplevel: *mut xdg_toplevel, app_id: *const c_char) {
+    wl_proxy_marshal_flags(toplevel, 3, ptr::null(), wl_proxy_get_version(toplevel), 0, app_id);
+}
+
+pub unsafe fn xdg_toplevel_move(toplevel: *mut xdg_toplevel, seat: *mut wl_seat, serial: u32) {
+    wl_proxy_marshal_flags(toplevel, 5, ptr::null(), wl_proxy_get_version(toplevel), 0, seat, serial);
+}
+
+pub unsafe fn xdg_toplevel_resize(toplevel: *mut xdg_toplevel, seat: *mut wl_seat, serial: u32, edges: u32) {
+    wl_proxy_marshal_flags(toplevel, 6, ptr::null(), wl_proxy_get_version(toplevel), 0, seat, serial, edges);
+}
+
+pub unsafe fn xdg_toplevel_set_maximized(toplevel: *mut xdg_toplevel) {
+    wl_proxy_marshal_flags(toplevel, 9, ptr::null(), wl_proxy_get_version(toplevel), 0);
+}
+
+pub unsafe fn xdg_toplevel_unset_maximized(toplevel: *mut xdg_toplevel) {
+    wl_proxy_marshal_flags(toplevel, 10, ptr::null(), wl_proxy_get_version(toplevel), 0);
+}
+
+pub unsafe fn xdg_toplevel_set_fullscreen(toplevel: *mut xdg_toplevel, output: *mut wl_output) {
+    wl_proxy_marshal_flags(toplevel, 11, ptr::null(), wl_proxy_get_version(toplevel), 0, output);
+}
+
+pub unsafe fn xdg_toplevel_unset_fullscreen(toplevel: *mut xdg_toplevel) {
+    wl_proxy_marshal_flags(toplevel, 12, ptr::null(), wl_proxy_get_version(toplevel), 0);
+}
+
+pub unsafe fn xdg_toplevel_set_minimized(toplevel: *mut xdg_toplevel) {
+    wl_proxy_marshal_flags(toplevel, 13, ptr::null(), wl_proxy_get_version(toplevel), 0);
+}
+
+pub unsafe fn wp_fractional_scale_manager_v1_get_fractional_scale(manager: *mut wp_fractional_scale_manager_v1, surface: *mut wl_surface) -> *mut wp_fractional_scale_v1 {
+    wl_proxy_marshal_flags(manager, 1, &wp_fractional_scale_v1_interface, wl_proxy_get_version(manager), 0, ptr::null_mut::<c_void>(), surface)
+}
+
+pub unsafe fn wp_fractional_scale_v1_destroy(fractional_scale: *mut wp_fractional_scale_v1) {
+    wl_proxy_marshal_flags(fractional_scale, 0, ptr::null(), wl_proxy_get_version(fractional_scale), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wp_viewporter_get_viewport(viewporter: *mut wp_viewporter, surface: *mut wl_surface) -> *mut wp_viewport {
+    wl_proxy_marshal_flags(viewporter, 1, &wp_viewport_interface, wl_proxy_get_version(viewporter), 0, ptr::null_mut::<c_void>(), surface)
+}
+
+pub unsafe fn wp_viewport_destroy(viewport: *mut wp_viewport) {
+    wl_proxy_marshal_flags(viewport, 0, ptr::null(), wl_proxy_get_version(viewport), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn wp_viewport_set_destination(viewport: *mut wp_viewport, width: i32, height: i32) {
+    wl_proxy_marshal_flags(viewport, 2, ptr::null(), wl_proxy_get_version(viewport), 0, width, height);
+}
+
+pub unsafe fn zxdg_decoration_manager_v1_get_toplevel_decoration(manager: *mut zxdg_decoration_manager_v1, toplevel: *mut xdg_toplevel) -> *mut zxdg_toplevel_decoration_v1 {
+    wl_proxy_marshal_flags(manager, 1, &zxdg_toplevel_decoration_v1_interface, wl_proxy_get_version(manager), 0, ptr::null_mut::<c_void>(), toplevel)
+}
+
+pub unsafe fn zxdg_toplevel_decoration_v1_destroy(decoration: *mut zxdg_toplevel_decoration_v1) {
+    wl_proxy_marshal_flags(decoration, 0, ptr::null(), wl_proxy_get_version(decoration), WL_MARSHAL_FLAG_DESTROY);
+}
+
+pub unsafe fn zxdg_toplevel_decoration_v1_set_mode(decoration: *mut zxdg_toplevel_decoration_v1, mode: u32) {
+    wl_proxy_marshal_flags(decoration, 1, ptr::null(), wl_proxy_get_version(decoration), 0, mode);
+}
+
+pub unsafe fn zwp_text_input_manager_v3_get_text_input(manager: *mut zwp_text_input_manager_v3, seat: *mut wl_seat) -> *mut zwp_text_input_v3 {
+    wl_proxy_marshal_flags(manager, 1, &zwp_text_input_v3_interface, wl_proxy_get_version(manager), 0, ptr::null_mut::<c_void>(), seat)
+}
+
+pub unsafe fn zwp_text_input_v3_enable(text_input: *mut zwp_text_input_v3) {
+    wl_proxy_marshal_flags(text_input, 1, ptr::null(), wl_proxy_get_version(text_input), 0);
+}
+
+pub unsafe fn zwp_text_input_v3_disable(text_input: *mut zwp_text_input_v3) {
+    wl_proxy_marshal_flags(text_input, 2, ptr::null(), wl_proxy_get_version(text_input), 0);
+}
+
+pub unsafe fn zwp_text_input_v3_set_content_type(text_input: *mut zwp_text_input_v3, hint: u32, purpose: u32) {
+    wl_proxy_marshal_flags(text_input, 5, ptr::null(), wl_proxy_get_version(text_input), 0, hint, purpose);
+}
+
+pub unsafe fn zwp_text_input_v3_set_cursor_rectangle(text_input: *mut zwp_text_input_v3, x: i32, y: i32, width: i32, height: i32) {
+    wl_proxy_marshal_flags(text_input, 6, ptr::null(), wl_proxy_get_version(text_input), 0, x, y, width, height);
+}
+
+pub unsafe fn zwp_text_input_v3_commit(text_input: *mut zwp_text_input_v3) {
+    wl_proxy_marshal_flags(text_input, 7, ptr::null(), wl_proxy_get_version(text_input), 0);
+}
@@ -0,0 +1,402 @@
+use {
+    std::{
+        cell::Cell,
+        rc::Rc,
+        os::raw::c_void,
+        ffi::CString,
+        ptr,
+    },
+    self::super::{
+        wayland_sys::*,
+        wayland_event::WaylandEvent,
+        wayland_app::*,
+    },
+    crate::{
+        area::Area,
+        window::WindowId,
+        makepad_math::DVec2,
+        event::*,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaylandNcMode {
+    Move,
+    Resize(u32),
+}
+
+pub struct WaylandWindow {
+    pub window_id: WindowId,
+    pub surface: *mut wl_surface,
+    pub xdg_surface: *mut xdg_surface,
+    pub toplevel: *mut xdg_toplevel,
+    pub decoration: *mut zxdg_toplevel_decoration_v1,
+    pub fractional_scale: *mut wp_fractional_scale_v1,
+    pub viewport: *mut wp_viewport,
+    pub frame_callback: *mut wl_callback,
+    pub outputs: Vec<*mut wl_output>,
+
+    pub inner_size: DVec2,
+    pub preferred_scale: Option<f64>,
+    pub is_configured: bool,
+    pub is_maximized: bool,
+    pub is_fullscreen: bool,
+    pub server_side_decorations: bool,
+    pending_size: Option<DVec2>,
+    pending_maximized: bool,
+    pending_fullscreen: bool,
+
+    pub last_nc_mode: Option<WaylandNcMode>,
+    pub last_window_geom: WindowGeom,
+    pub last_mouse_pos: DVec2,
+}
+
+impl WaylandWindow {
+
+    pub fn new(window_id: WindowId) -> WaylandWindow {
+        WaylandWindow {
+            window_id,
+            surface: ptr::null_mut(),
+            xdg_surface: ptr::null_mut(),
+            toplevel: ptr::null_mut(),
+            decoration: ptr::null_mut(),
+            fractional_scale: ptr::null_mut(),
+            viewport: ptr::null_mut(),
+            frame_callback: ptr::null_mut(),
+            outputs: Vec::new(),
+            inner_size: DVec2::default(),
+            preferred_scale: None,
+            is_configured: false,
+            is_maximized: false,
+            is_fullscreen: false,
+            server_side_decorations: false,
+            pending_size: None,
+            pending_maximized: false,
+            pending_fullscreen: false,
+            last_nc_mode: None,
+            last_window_geom: WindowGeom::default(),
+            last_mouse_pos: DVec2::default(),
+        }
+    }
+
+    // the window has to stay at the same address after init, the listeners point at it
+    pub fn init(&mut self, title: &str, size: DVec2) {
+        unsafe {
+            let app = get_wayland_app_global();
+            let data = self as *mut WaylandWindow as *mut c_void;
+            self.inner_size = size;
+
+            self.surface = wl_compositor_create_surface(app.compositor);
+            wl_proxy_add_listener_data(self.surface, &SURFACE_LISTENER, data);
+            app.window_map.insert(self.surface, self as *mut WaylandWindow);
+
+            // fractional scaling renders at the exact scale and lets the viewport map it back to logical size
+            if app.fractional_scale_manager != ptr::null_mut() && app.viewporter != ptr::null_mut() {
+                self.fractional_scale = wp_fractional_scale_manager_v1_get_fractional_scale(app.fractional_scale_manager, self.surface);
+                wl_proxy_add_listener_data(self.fractional_scale, &FRACTIONAL_SCALE_LISTENER, data);
+                self.viewport = wp_viewporter_get_viewport(app.viewporter, self.surface);
+            }
+
+            self.xdg_surface = xdg_wm_base_get_xdg_surface(app.wm_base, self.surface);
+            wl_proxy_add_listener_data(self.xdg_surface, &XDG_SURFACE_LISTENER, data);
+            self.toplevel = xdg_surface_get_toplevel(self.xdg_surface);
+            wl_proxy_add_listener_data(self.toplevel, &XDG_TOPLEVEL_LISTENER, data);
+
+            let title = CString::new(title).unwrap_or_default();
+            xdg_toplevel_set_title(self.toplevel, title.as_ptr());
+            let app_id = std::env::current_exe().ok()
+                .and_then( | exe | exe.file_stem().map( | s | s.to_string_lossy().to_string()))
+                .and_then( | s | CString::new(s).ok())
+                .unwrap_or_default();
+            xdg_toplevel_set_app_id(self.toplevel, app_id.as_ptr());
+
+            if app.decoration_manager != ptr::null_mut() {
+                self.decoration = zxdg_decoration_manager_v1_get_toplevel_decoration(app.decoration_manager, self.toplevel);
+                wl_proxy_add_listener_data(self.decoration, &DECORATION_LISTENER, data);
+                zxdg_toplevel_decoration_v1_set_mode(self.decoration, ZXDG_TOPLEVEL_DECORATION_V1_MODE_SERVER_SIDE);
+            }
+
+            // the initial commit without a buffer asks for the first configure
+            wl_surface_commit(self.surface);
+            wl_display_roundtrip(app.display);
+        }
+    }
+
+    pub fn close_window(&mut self) {
+        unsafe {
+            let app = get_wayland_app_global();
+            app.window_map.remove(&self.surface);
+            if app.pointer_focus == self.surface {
+                app.pointer_focus = ptr::null_mut();
+            }
+            if app.keyboard_focus == self.surface {
+                app.keyboard_focus = ptr::null_mut();
+            }
+            if app.display == ptr::null_mut() {
+                return
+            }
+            if self.frame_callback != ptr::null_mut() {
+                wl_callback_destroy(self.frame_callback);
+                self.frame_callback = ptr::null_mut();
+            }
+            if self.decoration != ptr::null_mut() {
+                zxdg_toplevel_decoration_v1_destroy(self.decoration);
+                self.decoration = ptr::null_mut();
+            }
+            if self.viewport != ptr::null_mut() {
+                wp_viewport_destroy(self.viewport);
+                self.viewport = ptr::null_mut();
+            }
+            if self.fractional_scale != ptr::null_mut() {
+                wp_fractional_scale_v1_destroy(self.fractional_scale);
+                self.fractional_scale = ptr::null_mut();
+            }
+            if self.toplevel != ptr::null_mut() {
+                xdg_toplevel_destroy(self.toplevel);
+                self.toplevel = ptr::null_mut();
+            }
+            if self.xdg_surface != ptr::null_mut() {
+                xdg_surface_destroy(self.xdg_surface);
+                self.xdg_surface = ptr::null_mut();
+            }
+            if self.surface != ptr::null_mut() {
+                wl_surface_destroy(self.surface);
+                self.surface = ptr::null_mut();
+            }
+            wl_display_flush(app.display);
+        }
+    }
+
+    pub fn restore(&self) {
+        unsafe {xdg_toplevel_unset_maximized(self.toplevel)};
+    }
+
+    pub fn maximize(&self) {
+        unsafe {xdg_toplevel_set_maximized(self.toplevel)};
+    }
+
+    pub fn fullscreen(&self) {
+        unsafe {xdg_toplevel_set_fullscreen(self.toplevel, ptr::null_mut())};
+    }
+
+    pub fn normalize(&self) {
+        unsafe {
+            xdg_toplevel_unset_fullscreen(self.toplevel);
+            xdg_toplevel_unset_maximized(self.toplevel);
+        }
+    }
+
+    pub fn minimize(&self) {
+        unsafe {xdg_toplevel_set_minimized(self.toplevel)};
+    }
+
+    pub fn get_window_geom(&self) -> WindowGeom {
+        WindowGeom {
+            xr_is_presenting: false,
+            can_fullscreen: true,
+            // xdg-shell has no way to keep a window on top
+            is_topmost: false,
+            is_fullscreen: self.is_fullscreen,
+            is_maximized: self.is_maximized,
+            inner_size: self.inner_size,
+            outer_size: self.inner_size,
+            dpi_factor: self.get_dpi_factor(),
+            // wayland clients can't know where they are on screen
+            position: DVec2::default(),
+        }
+    }
+
+    pub fn get_dpi_factor(&self) -> f64 {
+        if let Some(scale) = self.preferred_scale {
+            return scale
+        }
+        let app = get_wayland_app_global();
+        self.outputs.iter().map( | o | app.output_scale(*o)).max().unwrap_or(1) as f64
+    }
+
+    pub fn is_frame_pending(&self) -> bool {
+        self.frame_callback != ptr::null_mut()
+    }
+
+    // asks the compositor to tell us when it wants the next frame, which throttles drawing to the display
+    pub fn request_frame(&mut self) {
+        if self.frame_callback != ptr::null_mut() {
+            return
+        }
+        unsafe {
+            self.frame_callback = wl_surface_frame(self.surface);
+            wl_proxy_add_listener_data(self.frame_callback, &FRAME_LISTENER, self as *mut WaylandWindow as *mut c_void);
+        }
+    }
+
+    pub fn time_now(&self) -> f64 {
+        get_wayland_app_global().time_now()
+    }
+
+    pub fn do_callback(&mut self, event: WaylandEvent) {
+        get_wayland_app_global().do_callback(event);
+    }
+
+    pub fn send_change_event(&mut self) {
+        let new_geom = self.get_window_geom();
+        let old_geom = self.last_window_geom.clone();
+        if old_geom == new_geom {
+            return
+        }
+        self.last_window_geom = new_geom.clone();
+
+        self.do_callback(WaylandEvent::WindowGeomChange(WindowGeomChangeEvent {
+            window_id: self.window_id,
+            old_geom: old_geom,
+            new_geom: new_geom
+        }));
+        self.do_callback(WaylandEvent::Paint);
+    }
+
+    pub fn send_focus_event(&mut self) {
+        self.do_callback(WaylandEvent::AppGotFocus);
+    }
+
+    pub fn send_focus_lost_event(&mut self) {
+        self.do_callback(WaylandEvent::AppLostFocus);
+    }
+
+    pub fn send_mouse_down(&mut self, button: usize, modifiers: KeyModifiers) {
+        self.do_callback(WaylandEvent::MouseDown(MouseDownEvent {
+            button,
+            modifiers,
+            window_id: self.window_id,
+            abs: self.last_mouse_pos,
+            time: self.time_now(),
+            handled: Cell::new(Area::Empty),
+        }));
+    }
+
+    pub fn send_mouse_up(&mut self, button: usize, modifiers: KeyModifiers) {
+        self.do_callback(WaylandEvent::MouseUp(MouseUpEvent {
+            button,
+            modifiers,
+            window_id: self.window_id,
+            abs: self.last_mouse_pos,
+            time: self.time_now()
+        }));
+    }
+
+    pub fn send_mouse_move(&mut self, pos: DVec2, modifiers: KeyModifiers) {
+        self.last_mouse_pos = pos;
+        self.do_callback(WaylandEvent::MouseMove(MouseMoveEvent {
+            window_id: self.window_id,
+            abs: pos,
+            modifiers: modifiers,
+            time: self.time_now(),
+            handled: Cell::new(Area::Empty),
+        }));
+    }
+
+    pub fn send_close_requested_event(&mut self) -> bool {
+        let accept_close = Rc::new(Cell::new(true));
+        self.do_callback(WaylandEvent::WindowCloseRequested(WindowCloseRequestedEvent {
+            window_id: self.window_id,
+            accept_close: accept_close.clone()
+        }));
+        if !accept_close.get() {
+            return false
+        }
+        true
+    }
+}
+
+unsafe fn window_from_data(data: *mut c_void) -> &'static mut WaylandWindow {
+    &mut *(data as *mut WaylandWindow)
+}
+
+static SURFACE_LISTENER: wl_surface_listener = wl_surface_listener {
+    enter: surface_enter,
+    leave: surface_leave,
+};
+
+unsafe extern "C" fn surface_enter(data: *mut c_void, _surface: *mut wl_surface, output: *mut wl_output) {
+    let window = window_from_data(data);
+    if !window.outputs.contains(&output) {
+        window.outputs.push(output);
+    }
+    window.send_change_event();
+}
+
+unsafe extern "C" fn surface_leave(data: *mut c_void, _surface: *mut wl_surface, output: *mut wl_output) {
+    let window = window_from_data(data);
+    window.outputs.retain( | o | *o != output);
+    window.send_change_event();
+}
+
+static FRAME_LISTENER: wl_callback_listener = wl_callback_listener {
+    done: frame_done,
+};
+
+unsafe extern "C" fn frame_done(data: *mut c_void, callback: *mut wl_callback, _time: u32) {
+    let window = window_from_data(data);
+    wl_callback_destroy(callback);
+    if window.frame_callback == callback {
+        window.frame_callback = ptr::null_mut();
+    }
+}
+
+static XDG_SURFACE_LISTENER: xdg_surface_listener = xdg_surface_listener {
+    configure: xdg_surface_configure,
+};
+
+unsafe extern "C" fn xdg_surface_configure(data: *mut c_void, xdg_surface: *mut xdg_surface, serial: u32) {
+    let window = window_from_data(data);
+    xdg_surface_ack_configure(xdg_surface, serial);
+    if let Some(size) = window.pending_size.take() {
+        window.inner_size = size;
+    }
+    window.is_maximized = window.pending_maximized;
+    window.is_fullscreen = window.pending_fullscreen;
+    window.is_configured = true;
+    window.send_change_event();
+}
+
+static XDG_TOPLEVEL_LISTENER: xdg_toplevel_listener = xdg_toplevel_listener {
+    configure: xdg_toplevel_configure,
+    close: xdg_toplevel_close,
+};
+
+unsafe extern "C" fn xdg_toplevel_configure(data: *mut c_void, _toplevel: *mut xdg_toplevel, width: i32, height: i32, states: *mut wl_array) {
+    let window = window_from_data(data);
+    // a zero size means we get to pick
+    if width > 0 && height > 0 {
+        window.pending_size = Some(DVec2 {x: width as f64, y: height as f64});
+    }
+    let states = std::slice::from_raw_parts((*states).data as *const u32, (*states).size / 4);
+    window.pending_maximized = states.contains(&XDG_TOPLEVEL_STATE_MAXIMIZED);
+    window.pending_fullscreen = states.contains(&XDG_TOPLEVEL_STATE_FULLSCREEN);
+}
+
+unsafe extern "C" fn xdg_toplevel_close(data: *mut c_void, _toplevel: *mut xdg_toplevel) {
+    let window = window_from_data(data);
+    if window.send_close_requested_event() {
+        window.do_callback(WaylandEvent::WindowClosed(WindowClosedEvent {
+            window_id: window.window_id,
+        }));
+    }
+}
+
+static FRACTIONAL_SCALE_LISTENER: wp_fractional_scale_v1_listener = wp_fractional_scale_v1_listener {
+    preferred_scale: fractional_preferred_scale,
+};
+
+unsafe extern "C" fn fractional_preferred_scale(data: *mut c_void, _fractional_scale: *mut wp_fractional_scale_v1, scale: u32) {
+    let window = window_from_data(data);
+    // the scale is sent in 120ths
+    window.preferred_scale = Some(scale as f64 / 120.0);
+    window.send_change_event();
+}
+
+static DECORATION_LISTENER: zxdg_toplevel_decoration_v1_listener = zxdg_toplevel_decoration_v1_listener {
+    configure: decoration_configure,
+};
+
+unsafe extern "C" fn decoration_configure(data: *mut c_void, _decoration: *mut zxdg_toplevel_decoration_v1, mode: u32) {
+    window_from_data(data).server_side_decorations = mode == ZXDG_TOPLEVEL_DECORATION_V1_MODE_SERVER_SIDE;
+}
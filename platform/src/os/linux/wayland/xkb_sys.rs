@@ -0,0 +1,64 @@
+#![allow(non_camel_case_types)]
+#![allow(dead_code)]
+
+use std::os::raw::{
+    c_void,
+    c_char,
+    c_int,
+};
+
+pub type xkb_context = c_void;
+pub type xkb_keymap = c_void;
+pub type xkb_state = c_void;
+pub type xkb_compose_table = c_void;
+pub type xkb_compose_state = c_void;
+pub type xkb_keycode_t = u32;
+pub type xkb_keysym_t = u32;
+
+pub const XKB_KEYMAP_FORMAT_TEXT_V1: c_int = 1;
+pub const XKB_STATE_MODS_EFFECTIVE: c_int = 1 << 3;
+
+pub const XKB_MOD_NAME_SHIFT: &'static [u8; 6] = b"Shift\0";
+pub const XKB_MOD_NAME_CTRL: &'static [u8; 8] = b"Control\0";
+pub const XKB_MOD_NAME_ALT: &'static [u8; 5] = b"Mod1\0";
+pub const XKB_MOD_NAME_LOGO: &'static [u8; 5] = b"Mod4\0";
+
+pub const XKB_COMPOSE_NOTHING: c_int = 0;
+pub const XKB_COMPOSE_COMPOSING: c_int = 1;
+pub const XKB_COMPOSE_COMPOSED: c_int = 2;
+pub const XKB_COMPOSE_CANCELLED: c_int = 3;
+pub const XKB_COMPOSE_FEED_ACCEPTED: c_int = 1;
+
+#[link(name = "xkbcommon")]
+extern "C" {
+    pub fn xkb_context_new(flags: c_int) -> *mut xkb_context;
+    pub fn xkb_context_unref(context: *mut xkb_context);
+
+    pub fn xkb_keymap_new_from_string(context: *mut xkb_context, string: *const c_char, format: c_int, flags: c_int) -> *mut xkb_keymap;
+    pub fn xkb_keymap_unref(keymap: *mut xkb_keymap);
+    pub fn xkb_keymap_key_repeats(keymap: *mut xkb_keymap, key: xkb_keycode_t) -> c_int;
+
+    pub fn xkb_state_new(keymap: *mut xkb_keymap) -> *mut xkb_state;
+    pub fn xkb_state_unref(state: *mut xkb_state);
+    pub fn xkb_state_update_mask(
+        state: *mut xkb_state,
+        depressed_mods: u32,
+        latched_mods: u32,
+        locked_mods: u32,
+        depressed_layout: u32,
+        latched_layout: u32,
+        locked_layout: u32,
+    ) -> c_int;
+    pub fn xkb_state_key_get_one_sym(state: *mut xkb_state, key: xkb_keycode_t) -> xkb_keysym_t;
+    pub fn xkb_state_key_get_utf8(state: *mut xkb_state, key: xkb_keycode_t, buffer: *mut c_char, size: usize) -> c_int;
+    pub fn xkb_state_mod_name_is_active(state: *mut xkb_state, name: *const c_char, type_: c_int) -> c_int;
+
+    pub fn xkb_compose_table_new_from_locale(context: *mut xkb_context, locale: *const c_char, flags: c_int) -> *mut xkb_compose_table;
+    pub fn xkb_compose_table_unref(table: *mut xkb_compose_table);
+    pub fn xkb_compose_state_new(table: *mut xkb_compose_table, flags: c_int) -> *mut xkb_compose_state;
+    pub fn xkb_compose_state_unref(state: *mut xkb_compose_state);
+    pub fn xkb_compose_state_feed(state: *mut xkb_compose_state, keysym: xkb_keysym_t) -> c_int;
+    pub fn xkb_compose_state_reset(state: *mut xkb_compose_state);
+    pub fn xkb_compose_state_get_status(state: *mut xkb_compose_state) -> c_int;
+    pub fn xkb_compose_state_get_utf8(state: *mut xkb_compose_state, buffer: *mut c_char, size: usize) -> c_int;
+}
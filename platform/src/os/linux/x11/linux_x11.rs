@@ -1,13 +1,17 @@
 use {
     std::cell::RefCell,
     std::rc::Rc,
+    std::collections::HashMap,
     self::super::{
         opengl_x11::{OpenglWindow,OpenglCx},
     },
     self::super::super::{
         x11::xlib_event::*,
         x11::xlib_app::*,
-        linux_media::CxLinuxMedia
+        linux_media::CxLinuxMedia,
+        linux_http::LinuxHttpRequest,
+        linux_websocket::LinuxWebSocket,
+        headless::HeadlessCx,
     },
     crate::{
         cx_api::{CxOsOp, CxOsApi}, 
@@ -16,6 +20,8 @@ use {
         thread::Signal,
         event::{
             Event,
+            NetworkResponse,
+            NetworkResponseChannel,
         },
         pass::CxPassParent,
         cx::{Cx, OsType,LinuxWindowParams}, 
@@ -27,6 +33,16 @@ use {
 
 impl Cx {
     pub fn event_loop(cx:Rc<RefCell<Cx>>) {
+        if std::env::args().any( | v | v == "--live-check") {
+            if let Ok(cx) = Rc::try_unwrap(cx) {
+                std::process::exit(HeadlessCx::live_check(cx.into_inner()));
+            }
+            panic!("--live-check needs the only reference to cx");
+        }
+        let is_stdin_loop = std::env::args().find(|v| v=="--stdin-loop").is_some();
+        if !is_stdin_loop && Self::wayland_event_loop(cx.clone()){
+            return
+        }
         cx.borrow_mut().self_ref = Some(cx.clone());
         cx.borrow_mut().os_type = OsType::LinuxWindow(LinuxWindowParams{
             custom_window_chrome: false
@@ -37,7 +53,6 @@ impl Cx {
 
         
         let opengl_windows = Rc::new(RefCell::new(Vec::new()));
-        let is_stdin_loop = std::env::args().find(|v| v=="--stdin-loop").is_some();
         init_xlib_app_global(Box::new({
             let cx = cx.clone();
             let opengl_cx = opengl_cx.clone();
@@ -166,13 +181,16 @@ impl Cx {
                 self.call_event_handler(&Event::TextInput(e))
             }
             XlibEvent::Drag(e) => {
-                self.call_event_handler(&Event::Drag(e))
+                self.call_event_handler(&Event::Drag(e));
+                self.drag_drop.cycle_drag();
             }
             XlibEvent::Drop(e) => {
-                self.call_event_handler(&Event::Drop(e))
+                self.call_event_handler(&Event::Drop(e));
+                self.drag_drop.cycle_drag();
             }
             XlibEvent::DragEnd => {
-                self.call_event_handler(&Event::DragEnd)
+                self.call_event_handler(&Event::DragEnd);
+                self.drag_drop.cycle_drag();
             }
             XlibEvent::KeyDown(e) => {
                 self.keyboard.process_key_down(e.clone());
@@ -195,6 +213,7 @@ impl Cx {
                         self.handle_media_signals();
                         self.call_event_handler(&Event::Signal);
                     }
+                    self.handle_networking_events();
                 }
                 else{
                     self.call_event_handler(&Event::Timer(e))
@@ -211,6 +230,17 @@ impl Cx {
     }
 
     pub(crate) fn handle_networking_events(&mut self) {
+        let mut out = Vec::new();
+        while let Ok(event) = self.os.network_response.receiver.try_recv(){
+            // a closed or failed socket is done, drop it so its threads and channel go away
+            if let NetworkResponse::WebSocketClose | NetworkResponse::WebSocketError(_) = event.response {
+                self.os.web_sockets.remove(&event.request_id);
+            }
+            out.push(event);
+        }
+        if out.len()>0{
+            self.call_event_handler(&Event::NetworkResponses(out))
+        }
     }
     
     pub (crate) fn handle_repaint(&mut self, opengl_windows: &mut Vec<OpenglWindow>, opengl_cx: &mut OpenglCx) {
@@ -282,14 +312,20 @@ impl Cx {
                 },
                 CxOsOp::ShowClipboardActions(_) =>{
                 }
-                CxOsOp::FullscreenWindow(_window_id) => {
-                    todo!()
+                CxOsOp::FullscreenWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.xlib_window.fullscreen();
+                    }
                 },
-                CxOsOp::NormalizeWindow(_window_id) => {
-                    todo!()
+                CxOsOp::NormalizeWindow(window_id) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.xlib_window.normalize();
+                    }
                 }
-                CxOsOp::SetTopmost(_window_id, _is_topmost) => {
-                    todo!()
+                CxOsOp::SetTopmost(window_id, is_topmost) => {
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | w.window_id == window_id) {
+                        window.xlib_window.set_topmost(is_topmost);
+                    }
                 }
                 CxOsOp::XrStartPresenting => {
                     //todo!()
@@ -297,11 +333,18 @@ impl Cx {
                 CxOsOp::XrStopPresenting => {
                     //todo!()
                 },
-                CxOsOp::ShowTextIME(_area, _pos) => {
-                    //todo!()
+                CxOsOp::ShowTextIME(area, pos) => {
+                    let pos = area.get_clipped_rect(self).pos + pos;
+                    let window_id = self.get_window_id_of(&area);
+                    if let Some(window) = opengl_windows.iter_mut().find( | w | Some(w.window_id) == window_id) {
+                        window.xlib_window.set_ime_spot(pos);
+                        window.xlib_window.focus_ime();
+                    }
                 }
                 CxOsOp::HideTextIME => {
-                    //todo!()
+                    opengl_windows.iter_mut().for_each( | w | {
+                        w.xlib_window.hide_ime();
+                    });
                 },
                 CxOsOp::SetCursor(cursor) => {
                     xlib_app.set_mouse_cursor(cursor);
@@ -312,21 +355,27 @@ impl Cx {
                 CxOsOp::StopTimer(timer_id) => {
                     xlib_app.stop_timer(timer_id);
                 },
-                CxOsOp::StartDragging(_dragged_item) => {
+                CxOsOp::StartDragging(items) => {
+                    xlib_app.start_dragging(items);
                 },
                 CxOsOp::UpdateMenu(_menu) => {
                 },
-                CxOsOp::HttpRequest{request_id:_, request:_} => {
-                    todo!()
+                CxOsOp::HttpRequest{request_id, request} => {
+                    LinuxHttpRequest::new(request_id, request).start(self.os.network_response.sender.clone());
                 },
-                CxOsOp::WebSocketOpen{request_id:_, request:_}=>{
-                    todo!()
+                CxOsOp::WebSocketOpen{request_id, request}=>{
+                    let web_socket = LinuxWebSocket::open(request_id, request, self.os.network_response.sender.clone());
+                    self.os.web_sockets.insert(request_id, web_socket);
                 }
-                CxOsOp::WebSocketSendBinary{request_id:_, data:_}=>{
-                    todo!()
+                CxOsOp::WebSocketSendBinary{request_id, data}=>{
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id){
+                        web_socket.send_binary(data);
+                    }
                 }
-                CxOsOp::WebSocketSendString{request_id:_, data:_}=>{
-                    todo!()
+                CxOsOp::WebSocketSendString{request_id, data}=>{
+                    if let Some(web_socket) = self.os.web_sockets.get(&request_id){
+                        web_socket.send_string(data);
+                    }
                 }
             }
         }
@@ -349,5 +398,7 @@ impl CxOsApi for Cx {
 #[derive(Default)]
 pub struct CxOs {
     pub (crate) media: CxLinuxMedia,
+    pub (crate) network_response: NetworkResponseChannel,
+    pub (crate) web_sockets: HashMap<LiveId, LinuxWebSocket>,
 }
 
@@ -18,8 +18,9 @@ use {
         os::{
             x11::opengl_x11::{OpenglCx},
             cx_stdin::{HostToStdin, StdinToHost},
+            linux::{gl_sys, stdin_shared_memory::StdinSharedMemory},
         },
-        pass::{CxPassParent, PassClearColor, CxPassColorTexture},
+        pass::{PassId, CxPassParent, PassClearColor, CxPassColorTexture},
         cx_api::{CxOsOp},
         cx::{Cx},
     } 
@@ -27,33 +28,67 @@ use {
 
 impl Cx {
     
-    pub (crate) fn stdin_handle_repaint(&mut self, _opengl_cx: &mut OpenglCx) {
+    pub (crate) fn stdin_handle_repaint(&mut self, opengl_cx: &mut OpenglCx, mut shared_memory: Option<&mut StdinSharedMemory>) {
         let mut passes_todo = Vec::new();
         self.compute_pass_repaint_order(&mut passes_todo);
         self.repaint_id += 1;
+        opengl_cx.make_current();
         for pass_id in &passes_todo {
             match self.passes[*pass_id].parent.clone() {
                 CxPassParent::Window(_) => {
-                    //self.draw_pass(*pass_id, metal_cx, DrawPassMode::StdinMain);
+                    self.draw_pass_to_texture(*pass_id);
+                    if let Some(shared_memory) = shared_memory.as_mut() {
+                        self.stdin_copy_pass_to_shared_memory(*pass_id, shared_memory);
+                    }
                     let _ = io::stdout().write_all(StdinToHost::DrawComplete.to_json().as_bytes());
                 }
                 CxPassParent::Pass(_) => {
-                    //let dpi_factor = self.get_delegated_dpi_factor(parent_pass_id);
-                    //self.draw_pass(*pass_id, metal_cx, DrawPassMode::Texture);
+                    self.draw_pass_to_texture(*pass_id);
                 },
                 CxPassParent::None => {
-                    //self.draw_pass(*pass_id, metal_cx, DrawPassMode::Texture);
+                    self.draw_pass_to_texture(*pass_id);
                 }
             }
         }
     }
     
+    fn stdin_copy_pass_to_shared_memory(&mut self, pass_id: PassId, shared_memory: &mut StdinSharedMemory) {
+        let texture_id = if let Some(color_texture) = self.passes[pass_id].color_textures.first() {
+            color_texture.texture_id
+        }
+        else {
+            return
+        };
+        let gl_framebuffer = if let Some(gl_framebuffer) = self.passes[pass_id].os.gl_framebuffer {
+            gl_framebuffer
+        }
+        else {
+            return
+        };
+        // the host sends a new buffer after a resize, skip frames that dont fit until it arrives
+        let cxtexture = &self.textures[texture_id];
+        if cxtexture.os.width as usize != shared_memory.width || cxtexture.os.height as usize != shared_memory.height {
+            return
+        }
+        let (width, height) = (shared_memory.width as i32, shared_memory.height as i32);
+        unsafe {
+            gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, gl_framebuffer);
+            gl_sys::PixelStorei(gl_sys::PACK_ALIGNMENT, 4);
+            // rows come out bottom up like the texture itself, the host samples it with sample2d_rt
+            shared_memory.write_frame( | pixels | {
+                gl_sys::ReadPixels(0, 0, width, height, gl_sys::RGBA, gl_sys::UNSIGNED_BYTE, pixels as *mut _);
+            });
+            gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
+        }
+    }
+    
     pub fn stdin_event_loop(&mut self, opengl_cx: &mut OpenglCx) {
         let _ = io::stdout().write_all(StdinToHost::ReadyToStart.to_json().as_bytes());
         let fb_texture = Texture::new(self);
 
         let mut reader = BufReader::new(std::io::stdin());
         let mut window_size = None;
+        let mut shared_memory: Option<StdinSharedMemory> = None;
         
         self.call_event_handler(&Event::Construct);
         
@@ -68,9 +103,9 @@ impl Cx {
                 
                 match parsed {
                     Ok(msg) => match msg {
-                        HostToStdin::ReloadFile{file:_, contents:_}=>{
-                            // alright lets reload this file in our DSL system
-                            
+                        HostToStdin::ReloadFile{file, contents}=>{
+                            // picked up by was_live_edit on the next tick
+                            self.queue_live_file_change(file, contents);
                         }
                         HostToStdin::KeyDown(e) => {
                             self.call_event_handler(&Event::KeyDown(e));
@@ -115,6 +150,16 @@ impl Cx {
                                 self.stdin_handle_platform_ops(opengl_cx, &fb_texture);
                             }
                         }
+                        HostToStdin::SharedMemoryFramebuffer {path, width, height} => {
+                            shared_memory = match StdinSharedMemory::open(&path, width, height) {
+                                Ok(shared_memory) => Some(shared_memory),
+                                Err(err) => {
+                                    error!("Cant open shared framebuffer {} {:?}", path, err);
+                                    None
+                                }
+                            };
+                            self.redraw_all();
+                        }
                         HostToStdin::Tick {frame: _, time} => if let Some(_ws) = window_size {
                             // poll the service for updates
                             // check signals
@@ -140,7 +185,7 @@ impl Cx {
                             }
                             
                             // we need to make this shared texture handle into a true metal one
-                            self.stdin_handle_repaint(opengl_cx);
+                            self.stdin_handle_repaint(opengl_cx, shared_memory.as_mut());
                         }
                         _=>()
                     }
@@ -59,6 +59,7 @@ impl Cx {
             PassClearDepth::ClearWith(depth) => depth
         };
         
+        Self::set_default_depth_and_blend_mode();
         if !self.passes[pass_id].dont_clear {
             unsafe {
                 gl_sys::BindFramebuffer(gl_sys::FRAMEBUFFER, 0);
@@ -67,7 +68,6 @@ impl Cx {
                 gl_sys::Clear(gl_sys::COLOR_BUFFER_BIT | gl_sys::DEPTH_BUFFER_BIT);
             }
         }
-        Self::set_default_depth_and_blend_mode();
         
         let mut zbias = 0.0;
         let zbias_step = self.passes[pass_id].zbias_step;
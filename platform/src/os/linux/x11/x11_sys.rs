@@ -9,6 +9,7 @@ use std::os::raw::{
     c_void,
     c_char,
     c_uchar,
+    c_ushort,
 };
 
 
@@ -34,6 +35,9 @@ pub type XrmDatabase = *mut _XrmHashBucketRec;
 pub type XKeyPressedEvent = XKeyEvent;
 pub type XComposeStatus = _XComposeStatus;
 pub type GC = *mut _XGC;
+pub type XIMStyle = c_ulong;
+pub type XIMFeedback = c_ulong;
+pub type XIMProc = Option<unsafe extern "C" fn(arg1: XIC, arg2: XPointer, arg3: XPointer)>;
 
 pub const None: u32 = 0;
 pub const True: u32 = 1;
@@ -46,6 +50,9 @@ pub const SelectionRequest: u32 = 30;
 pub const PropModeReplace: u32 = 0;
 pub const DestroyNotify: u32 = 17;
 pub const ConfigureNotify: u32 = 22;
+pub const FocusIn: u32 = 9;
+pub const FocusOut: u32 = 10;
+pub const PropertyNotify: u32 = 28;
 pub const EnterNotify: u32 = 7;
 pub const LeaveNotify: u32 = 8;
 pub const MotionNotify: u32 = 6;
@@ -78,14 +85,32 @@ pub const VisibilityChangeMask: u32 = 65536;
 pub const FocusChangeMask: u32 = 2097152;
 pub const EnterWindowMask: u32 = 16;
 pub const LeaveWindowMask: u32 = 32;
+pub const PropertyChangeMask: u32 = 4194304;
+pub const GrabModeAsync: u32 = 1;
+pub const GrabSuccess: u32 = 0;
 pub const XBufferOverflow: i32 = -1;
+pub const XLookupNone: i32 = 1;
+pub const XLookupChars: i32 = 2;
+pub const XLookupKeySym: i32 = 3;
+pub const XLookupBoth: i32 = 4;
 
+pub const XIMPreeditCallbacks: u32 = 2;
+pub const XIMPreeditPosition: u32 = 4;
 pub const XIMPreeditNothing: u32 = 8;
 pub const XIMStatusNothing: u32 = 1024;
 
 pub const XNInputStyle: &'static [u8; 11usize] = b"inputStyle\0";
 pub const XNClientWindow: &'static [u8; 13usize] = b"clientWindow\0";
 pub const XNFocusWindow: &'static [u8; 12usize] = b"focusWindow\0";
+pub const XNQueryInputStyle: &'static [u8; 16usize] = b"queryInputStyle\0";
+pub const XNPreeditAttributes: &'static [u8; 18usize] = b"preeditAttributes\0";
+pub const XNSpotLocation: &'static [u8; 13usize] = b"spotLocation\0";
+pub const XNPreeditStartCallback: &'static [u8; 21usize] = b"preeditStartCallback\0";
+pub const XNPreeditDoneCallback: &'static [u8; 20usize] = b"preeditDoneCallback\0";
+pub const XNPreeditDrawCallback: &'static [u8; 20usize] = b"preeditDrawCallback\0";
+pub const XNPreeditCaretCallback: &'static [u8; 21usize] = b"preeditCaretCallback\0";
+
+pub const LC_CTYPE: c_int = 0;
 
 pub const Mod1Mask: u32 = 8;
 pub const ShiftMask: u32 = 1;
@@ -344,8 +369,38 @@ extern "C" {
     
     pub fn XCreateIC(arg1: XIM, ...) -> XIC;
     
+    pub fn XDestroyIC(arg1: XIC);
+    
+    pub fn XSetICValues(arg1: XIC, ...) -> *mut c_char;
+    
+    pub fn XGetIMValues(arg1: XIM, ...) -> *mut c_char;
+    
+    pub fn XVaCreateNestedList(arg1: c_int, ...) -> *mut c_void;
+    
+    pub fn XSetICFocus(arg1: XIC);
+    
+    pub fn XUnsetICFocus(arg1: XIC);
+    
+    pub fn XFilterEvent(arg1: *mut XEvent, arg2: Window) -> c_int;
+    
+    pub fn XSetLocaleModifiers(arg1: *const c_char) -> *mut c_char;
+    
     pub fn XDestroyWindow(arg1: *mut Display, arg2: Window) -> c_int;
     
+    pub fn XMoveWindow(
+        arg1: *mut Display,
+        arg2: Window,
+        arg3: c_int,
+        arg4: c_int,
+    ) -> c_int;
+    
+    pub fn XResizeWindow(
+        arg1: *mut Display,
+        arg2: Window,
+        arg3: c_uint,
+        arg4: c_uint,
+    ) -> c_int;
+    
     pub fn XIconifyWindow(
         arg1: *mut Display,
         arg2: Window,
@@ -388,6 +443,41 @@ extern "C" {
     
     pub fn XUngrabPointer(arg1: *mut Display, arg2: Time) -> c_int;
     
+    pub fn XGrabPointer(
+        arg1: *mut Display,
+        arg2: Window,
+        arg3: c_int,
+        arg4: c_uint,
+        arg5: c_int,
+        arg6: c_int,
+        arg7: Window,
+        arg8: Cursor,
+        arg9: Time,
+    ) -> c_int;
+    
+    pub fn XQueryPointer(
+        arg1: *mut Display,
+        arg2: Window,
+        arg3: *mut Window,
+        arg4: *mut Window,
+        arg5: *mut c_int,
+        arg6: *mut c_int,
+        arg7: *mut c_int,
+        arg8: *mut c_int,
+        arg9: *mut c_uint,
+    ) -> c_int;
+    
+    pub fn XTranslateCoordinates(
+        arg1: *mut Display,
+        arg2: Window,
+        arg3: Window,
+        arg4: c_int,
+        arg5: c_int,
+        arg6: *mut c_int,
+        arg7: *mut c_int,
+        arg8: *mut Window,
+    ) -> c_int;
+    
     pub fn XSetSelectionOwner(
         arg1: *mut Display,
         arg2: Atom,
@@ -415,6 +505,50 @@ extern "C" {
     ) -> c_int;
 }
 
+extern "C" {
+    pub fn setlocale(category: c_int, locale: *const c_char) -> *mut c_char;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XPoint {
+    pub x: c_short,
+    pub y: c_short,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XIMStyles {
+    pub count_styles: c_ushort,
+    pub supported_styles: *mut XIMStyle,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XIMCallback {
+    pub client_data: XPointer,
+    pub callback: XIMProc,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XIMText {
+    pub length: c_ushort,
+    pub feedback: *mut XIMFeedback,
+    pub encoding_is_wchar: c_int,
+    // union of multi_byte: *mut c_char and wide_char: *mut wchar_t
+    pub string: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XIMPreeditDrawCallbackStruct {
+    pub caret: c_int,
+    pub chg_first: c_int,
+    pub chg_length: c_int,
+    pub text: *mut XIMText,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _XrmHashBucketRec {
@@ -22,6 +22,9 @@ use {
     },
 };
 
+// timer ids handed out by cx count up from 1, so this one never collides
+const DND_FINISHED_TIMER_ID: u64 = u64::MAX;
+
 static mut XLIB_APP: *mut XlibApp = 0 as *mut _;
 
 pub fn get_xlib_app_global() -> &'static mut XlibApp {
@@ -50,6 +53,7 @@ pub struct XlibApp {
     pub last_scroll_time: f64,
     pub last_click_time: f64,
     pub last_click_pos: (i32, i32),
+    pub last_click_window: c_ulong,
     pub event_callback: Option<Box<dyn FnMut(&mut XlibApp, XlibEvent) -> EventFlow >>,
     //pub free_timers: Vec<usize>,
     pub event_flow: EventFlow,
@@ -63,6 +67,9 @@ impl XlibApp {
         unsafe {
             let display = x11_sys::XOpenDisplay(ptr::null());
             let display_fd = x11_sys::XConnectionNumber(display);
+            // input methods and compose sequences are picked from the locale, so adopt the users one
+            x11_sys::setlocale(x11_sys::LC_CTYPE, b"\0".as_ptr() as *const c_char);
+            x11_sys::XSetLocaleModifiers(b"\0".as_ptr() as *const c_char);
             let xim = x11_sys::XOpenIM(display, ptr::null_mut(), ptr::null_mut(), ptr::null_mut());
             //let mut signal_fds = [0, 0];
             //libc_sys::pipe(signal_fds.as_mut_ptr());
@@ -79,6 +86,7 @@ impl XlibApp {
                 last_scroll_time: 0.0,
                 last_click_time: 0.0,
                 last_click_pos: (0, 0),
+                last_click_window: 0,
                 window_map: HashMap::new(),
                 timers: SelectTimers::new(),
                 event_flow: EventFlow::Poll,
@@ -96,11 +104,21 @@ impl XlibApp {
             let mut event = mem::MaybeUninit::uninit();
             x11_sys::XNextEvent(self.display, event.as_mut_ptr());
             let mut event = event.assume_init();
+            // the input method consumes keys that are part of a composition or dead key sequence
+            if x11_sys::XFilterEvent(&mut event, x11_sys::None as c_ulong) != 0 {
+                continue;
+            }
             match event.type_ as u32 {
                 x11_sys::SelectionNotify => {
                     let selection = event.xselection;
-                    if selection.property == self.dnd.atoms.selection {
+                    if selection.selection == self.dnd.atoms.selection {
                         self.dnd.handle_selection_event(&selection);
+                        if self.dnd.drop_pending {
+                            self.dnd_send_drop();
+                        }
+                        else if self.dnd.position.is_some() {
+                            self.dnd_send_drag();
+                        }
                     } else {
                         // first get the size of the thing
                         let mut actual_type = mem::MaybeUninit::uninit();
@@ -170,7 +188,43 @@ impl XlibApp {
                         time: request.time,
                         property: request.property,
                     };
-                    if request.target == self.atoms.targets {
+                    if request.selection == self.dnd.atoms.selection {
+                        // someone, possibly ourselves, wants the files we are dragging
+                        if let Some(source) = &self.dnd.source {
+                            if request.target == self.atoms.targets {
+                                let mut targets = [self.dnd.atoms.uri_list];
+                                x11_sys::XChangeProperty(
+                                    self.display,
+                                    request.requestor,
+                                    request.property,
+                                    4,
+                                    32,
+                                    x11_sys::PropModeReplace as i32,
+                                    targets.as_mut() as *mut _ as *mut c_uchar,
+                                    targets.len() as i32
+                                );
+                            }
+                            else if request.target == self.dnd.atoms.uri_list {
+                                x11_sys::XChangeProperty(
+                                    self.display,
+                                    request.requestor,
+                                    request.property,
+                                    self.dnd.atoms.uri_list,
+                                    8,
+                                    x11_sys::PropModeReplace as i32,
+                                    source.uri_list.as_ptr() as *const _ as *const c_uchar,
+                                    source.uri_list.len() as i32
+                                );
+                            }
+                            else {
+                                response.property = 0;
+                            }
+                        }
+                        else {
+                            response.property = 0;
+                        }
+                    }
+                    else if request.target == self.atoms.targets {
                         let mut targets = [self.atoms.utf8_string];
                         x11_sys::XChangeProperty(
                             self.display,
@@ -209,6 +263,20 @@ impl XlibApp {
                         }));
                     }
                 },
+                x11_sys::FocusIn => {
+                    if let Some(window_ptr) = self.window_map.get(&event.xfocus.window) {
+                        let window = &mut (**window_ptr);
+                        window.focus_ime();
+                        window.send_focus_event();
+                    }
+                },
+                x11_sys::FocusOut => {
+                    if let Some(window_ptr) = self.window_map.get(&event.xfocus.window) {
+                        let window = &mut (**window_ptr);
+                        window.unfocus_ime();
+                        window.send_focus_lost_event();
+                    }
+                },
                 x11_sys::ConfigureNotify => {
                     let cfg = event.xconfigure;
                     if let Some(window_ptr) = self.window_map.get(&cfg.window) {
@@ -218,6 +286,16 @@ impl XlibApp {
                         }
                     }
                 },
+                x11_sys::PropertyNotify => {
+                    // the window manager changed our _NET_WM_STATE, ie fullscreen/topmost toggled
+                    let property = event.xproperty;
+                    if property.atom == self.atoms.net_wm_state {
+                        if let Some(window_ptr) = self.window_map.get(&property.window) {
+                            let window = &mut (**window_ptr);
+                            window.send_change_event();
+                        }
+                    }
+                },
                 x11_sys::EnterNotify => {},
                 x11_sys::LeaveNotify => {
                     let crossing = event.xcrossing;
@@ -242,6 +320,10 @@ impl XlibApp {
                         }
                     }
                 },
+                x11_sys::MotionNotify if self.dnd.is_dragging() => {
+                    let motion = event.xmotion;
+                    self.dnd.handle_source_motion(motion.x_root, motion.y_root, motion.time);
+                },
                 x11_sys::MotionNotify => { // mousemove
                     let motion = event.xmotion;
                     if let Some(window_ptr) = self.window_map.get(&motion.window) {
@@ -405,6 +487,21 @@ impl XlibApp {
                     }
                     self.last_click_time = time_now;
                     self.last_click_pos = (button.x_root, button.y_root);
+                    self.last_click_window = button.window;
+                },
+                x11_sys::ButtonRelease if self.dnd.is_dragging() => {
+                    // the grab of the drag sends the mouse up to the source window
+                    let button = event.xbutton;
+                    if let Some(window_ptr) = self.window_map.get(&button.window) {
+                        let window = &mut (**window_ptr);
+                        window.send_mouse_up(button.button as usize, self.xkeystate_to_modifiers(button.state))
+                    }
+                    if self.dnd.handle_source_release(button.time) {
+                        self.do_callback(XlibEvent::DragEnd);
+                    }
+                    else {
+                        self.start_timer(DND_FINISHED_TIMER_ID, DND_FINISHED_TIMEOUT, false);
+                    }
                 },
                 x11_sys::ButtonRelease => { // mouse up
                     let button = event.xbutton;
@@ -413,6 +510,11 @@ impl XlibApp {
                         window.send_mouse_up(button.button as usize, self.xkeystate_to_modifiers(button.state))
                     }
                 },
+                x11_sys::KeyPress if self.dnd.is_dragging() && self.xkeyevent_to_keycode(&mut event.xkey) == KeyCode::Escape => {
+                    if self.dnd.cancel_source(event.xkey.time) {
+                        self.do_callback(XlibEvent::DragEnd);
+                    }
+                },
                 x11_sys::KeyPress => {
                     if let Some(window_ptr) = self.window_map.get(&event.xkey.window) {
                         let window = &mut (**window_ptr);
@@ -477,29 +579,11 @@ impl XlibApp {
                         }else {false};
                         
                         if !block_text {
-                            // decode the character
-                            let mut buffer = [0u8; 32];
-                            let mut keysym = mem::MaybeUninit::uninit();
-                            let mut status = mem::MaybeUninit::uninit();
-                            let count = x11_sys::Xutf8LookupString(
-                                window.xic.unwrap(),
-                                &mut event.xkey,
-                                buffer.as_mut_ptr() as *mut c_char,
-                                buffer.len() as c_int,
-                                keysym.as_mut_ptr(),
-                                status.as_mut_ptr(),
-                            );
-                            //let keysym = keysym.assume_init();
-                            let status = status.assume_init();
-                            if status != x11_sys::XBufferOverflow {
-                                let utf8 = std::str::from_utf8(&buffer[..count as usize]).unwrap_or("").to_string();
+                            // decode the character, or the string the input method committed
+                            if let Some(utf8) = Self::lookup_key_text(window, &mut event.xkey) {
                                 let char_code = utf8.chars().next().unwrap_or('\0');
                                 if char_code >= ' ' && char_code != 127 as char {
-                                    self.do_callback(XlibEvent::TextInput(TextInputEvent {
-                                        input: utf8,
-                                        was_paste: false,
-                                        replace_last: false
-                                    }));
+                                    window.send_commit_text(utf8);
                                 }
                             }
                         }
@@ -524,11 +608,24 @@ impl XlibApp {
                     if event.message_type == self.dnd.atoms.enter {
                         self.dnd.handle_enter_event(&event);
                     } else if event.message_type == self.dnd.atoms.drop {
-                        self.dnd.handle_drop_event(&event);
+                        if self.dnd.handle_drop_event(&event) {
+                            self.dnd_send_drop();
+                        }
                     } else if event.message_type == self.dnd.atoms.leave {
-                        self.dnd.handle_leave_event(&event);
+                        if self.dnd.handle_leave_event(&event) {
+                            self.dnd_send_drag_out();
+                        }
                     } else if event.message_type == self.dnd.atoms.position {
-                        self.dnd.handle_position_event(&event);
+                        if self.dnd.handle_position_event(&event) {
+                            self.dnd_send_drag();
+                        }
+                    } else if event.message_type == self.dnd.atoms.status {
+                        self.dnd.handle_status_event(&event);
+                    } else if event.message_type == self.dnd.atoms.finished {
+                        if self.dnd.handle_finished_event(&event) {
+                            self.stop_timer(DND_FINISHED_TIMER_ID);
+                            self.do_callback(XlibEvent::DragEnd);
+                        }
                     }
                 },
                 x11_sys::Expose => {
@@ -559,9 +656,7 @@ impl XlibApp {
                     EventFlow::Wait => {
                         self.timers.update_timers(&mut timer_ids);
                         for timer_id in &timer_ids{
-                            self.do_callback(
-                                XlibEvent::Timer(TimerEvent {timer_id:*timer_id})
-                            );
+                            self.handle_timer(*timer_id);
                         }
                         self.timers.select(self.display_fd);
                         self.event_flow = EventFlow::Poll;
@@ -569,9 +664,7 @@ impl XlibApp {
                     EventFlow::Poll => { 
                         self.timers.update_timers(&mut timer_ids);
                         for timer_id in &timer_ids{
-                            self.do_callback(
-                                XlibEvent::Timer(TimerEvent {timer_id:*timer_id})
-                            );
+                            self.handle_timer(*timer_id);
                         }
                         self.event_loop_poll();
                     }
@@ -580,6 +673,17 @@ impl XlibApp {
         }
     }
     
+    fn handle_timer(&mut self, timer_id: u64) {
+        // the target of our drop never sent XdndFinished, dont wait on it forever
+        if timer_id == DND_FINISHED_TIMER_ID {
+            if unsafe {self.dnd.cancel_source(x11_sys::CurrentTime as x11_sys::Time)} {
+                self.do_callback(XlibEvent::DragEnd);
+            }
+            return
+        }
+        self.do_callback(XlibEvent::Timer(TimerEvent {timer_id}));
+    }
+    
     pub fn do_callback(&mut self, event: XlibEvent) {
         if let Some(mut callback) = self.event_callback.take() {
             self.event_flow = callback(self, event);
@@ -669,6 +773,42 @@ impl XlibApp {
         }
     }
     
+    unsafe fn lookup_key_text(window: &XlibWindow, key_event: &mut x11_sys::XKeyEvent) -> Option<String> {
+        let xic = if let Some(xic) = window.xic {xic} else {
+            let mut buffer = [0u8; 32];
+            let count = x11_sys::XLookupString(
+                key_event,
+                buffer.as_mut_ptr() as *mut c_char,
+                buffer.len() as c_int,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            return std::str::from_utf8(&buffer[..count as usize]).ok().map( | s | s.to_string())
+        };
+        // committed IME strings can be longer than a single key, so grow the buffer on overflow
+        let mut buffer = vec![0u8; 32];
+        loop {
+            let mut keysym = 0;
+            let mut status = 0;
+            let count = x11_sys::Xutf8LookupString(
+                xic,
+                key_event,
+                buffer.as_mut_ptr() as *mut c_char,
+                buffer.len() as c_int,
+                &mut keysym,
+                &mut status,
+            );
+            if status == x11_sys::XBufferOverflow {
+                buffer.resize(count as usize, 0);
+                continue;
+            }
+            if status != x11_sys::XLookupChars && status != x11_sys::XLookupBoth {
+                return None
+            }
+            return std::str::from_utf8(&buffer[..count as usize]).ok().map( | s | s.to_string())
+        }
+    }
+    
     fn xkeystate_to_modifiers(&self, state: c_uint) -> KeyModifiers {
         KeyModifiers {
             alt: state & x11_sys::Mod1Mask != 0,
@@ -689,143 +829,95 @@ impl XlibApp {
                 ptr::null_mut(),
             );
         }
-        match keysym as u32 {
-            x11_sys::XK_a => KeyCode::KeyA,
-            x11_sys::XK_A => KeyCode::KeyA,
-            x11_sys::XK_b => KeyCode::KeyB,
-            x11_sys::XK_B => KeyCode::KeyB,
-            x11_sys::XK_c => KeyCode::KeyC,
-            x11_sys::XK_C => KeyCode::KeyC,
-            x11_sys::XK_d => KeyCode::KeyD,
-            x11_sys::XK_D => KeyCode::KeyD,
-            x11_sys::XK_e => KeyCode::KeyE,
-            x11_sys::XK_E => KeyCode::KeyE,
-            x11_sys::XK_f => KeyCode::KeyF,
-            x11_sys::XK_F => KeyCode::KeyF,
-            x11_sys::XK_g => KeyCode::KeyG,
-            x11_sys::XK_G => KeyCode::KeyG,
-            x11_sys::XK_h => KeyCode::KeyH,
-            x11_sys::XK_H => KeyCode::KeyH,
-            x11_sys::XK_i => KeyCode::KeyI,
-            x11_sys::XK_I => KeyCode::KeyI,
-            x11_sys::XK_j => KeyCode::KeyJ,
-            x11_sys::XK_J => KeyCode::KeyJ,
-            x11_sys::XK_k => KeyCode::KeyK,
-            x11_sys::XK_K => KeyCode::KeyK,
-            x11_sys::XK_l => KeyCode::KeyL,
-            x11_sys::XK_L => KeyCode::KeyL,
-            x11_sys::XK_m => KeyCode::KeyM,
-            x11_sys::XK_M => KeyCode::KeyM,
-            x11_sys::XK_n => KeyCode::KeyN,
-            x11_sys::XK_N => KeyCode::KeyN,
-            x11_sys::XK_o => KeyCode::KeyO,
-            x11_sys::XK_O => KeyCode::KeyO,
-            x11_sys::XK_p => KeyCode::KeyP,
-            x11_sys::XK_P => KeyCode::KeyP,
-            x11_sys::XK_q => KeyCode::KeyQ,
-            x11_sys::XK_Q => KeyCode::KeyQ,
-            x11_sys::XK_r => KeyCode::KeyR,
-            x11_sys::XK_R => KeyCode::KeyR,
-            x11_sys::XK_s => KeyCode::KeyS,
-            x11_sys::XK_S => KeyCode::KeyS,
-            x11_sys::XK_t => KeyCode::KeyT,
-            x11_sys::XK_T => KeyCode::KeyT,
-            x11_sys::XK_u => KeyCode::KeyU,
-            x11_sys::XK_U => KeyCode::KeyU,
-            x11_sys::XK_v => KeyCode::KeyV,
-            x11_sys::XK_V => KeyCode::KeyV,
-            x11_sys::XK_w => KeyCode::KeyW,
-            x11_sys::XK_W => KeyCode::KeyW,
-            x11_sys::XK_x => KeyCode::KeyX,
-            x11_sys::XK_X => KeyCode::KeyX,
-            x11_sys::XK_y => KeyCode::KeyY,
-            x11_sys::XK_Y => KeyCode::KeyY,
-            x11_sys::XK_z => KeyCode::KeyZ,
-            x11_sys::XK_Z => KeyCode::KeyZ,
-            
-            x11_sys::XK_0 => KeyCode::Key0,
-            x11_sys::XK_1 => KeyCode::Key1,
-            x11_sys::XK_2 => KeyCode::Key2,
-            x11_sys::XK_3 => KeyCode::Key3,
-            x11_sys::XK_4 => KeyCode::Key4,
-            x11_sys::XK_5 => KeyCode::Key5,
-            x11_sys::XK_6 => KeyCode::Key6,
-            x11_sys::XK_7 => KeyCode::Key7,
-            x11_sys::XK_8 => KeyCode::Key8,
-            x11_sys::XK_9 => KeyCode::Key9,
-            
-            x11_sys::XK_Alt_L => KeyCode::Alt,
-            x11_sys::XK_Alt_R => KeyCode::Alt,
-            x11_sys::XK_Meta_L => KeyCode::Logo,
-            x11_sys::XK_Meta_R => KeyCode::Logo,
-            x11_sys::XK_Shift_L => KeyCode::Shift,
-            x11_sys::XK_Shift_R => KeyCode::Shift,
-            x11_sys::XK_Control_L => KeyCode::Control,
-            x11_sys::XK_Control_R => KeyCode::Control,
-            
-            x11_sys::XK_equal => KeyCode::Equals,
-            x11_sys::XK_minus => KeyCode::Minus,
-            x11_sys::XK_bracketright => KeyCode::RBracket,
-            x11_sys::XK_bracketleft => KeyCode::LBracket,
-            x11_sys::XK_Return => KeyCode::ReturnKey,
-            x11_sys::XK_grave => KeyCode::Backtick,
-            x11_sys::XK_semicolon => KeyCode::Semicolon,
-            x11_sys::XK_backslash => KeyCode::Backslash,
-            x11_sys::XK_comma => KeyCode::Comma,
-            x11_sys::XK_slash => KeyCode::Slash,
-            x11_sys::XK_period => KeyCode::Period,
-            x11_sys::XK_Tab => KeyCode::Tab,
-            x11_sys::XK_ISO_Left_Tab => KeyCode::Tab,
-            x11_sys::XK_space => KeyCode::Space,
-            x11_sys::XK_BackSpace => KeyCode::Backspace,
-            x11_sys::XK_Escape => KeyCode::Escape,
-            x11_sys::XK_Caps_Lock => KeyCode::Capslock,
-            x11_sys::XK_KP_Decimal => KeyCode::NumpadDecimal,
-            x11_sys::XK_KP_Multiply => KeyCode::NumpadMultiply,
-            x11_sys::XK_KP_Add => KeyCode::NumpadAdd,
-            x11_sys::XK_Num_Lock => KeyCode::Numlock,
-            x11_sys::XK_KP_Divide => KeyCode::NumpadDivide,
-            x11_sys::XK_KP_Enter => KeyCode::NumpadEnter,
-            x11_sys::XK_KP_Subtract => KeyCode::NumpadSubtract,
-            //keysim::XK_9 => KeyCode::NumpadEquals,
-            x11_sys::XK_KP_0 => KeyCode::Numpad0,
-            x11_sys::XK_KP_1 => KeyCode::Numpad1,
-            x11_sys::XK_KP_2 => KeyCode::Numpad2,
-            x11_sys::XK_KP_3 => KeyCode::Numpad3,
-            x11_sys::XK_KP_4 => KeyCode::Numpad4,
-            x11_sys::XK_KP_5 => KeyCode::Numpad5,
-            x11_sys::XK_KP_6 => KeyCode::Numpad6,
-            x11_sys::XK_KP_7 => KeyCode::Numpad7,
-            x11_sys::XK_KP_8 => KeyCode::Numpad8,
-            x11_sys::XK_KP_9 => KeyCode::Numpad9,
-            
-            x11_sys::XK_F1 => KeyCode::F1,
-            x11_sys::XK_F2 => KeyCode::F2,
-            x11_sys::XK_F3 => KeyCode::F3,
-            x11_sys::XK_F4 => KeyCode::F4,
-            x11_sys::XK_F5 => KeyCode::F5,
-            x11_sys::XK_F6 => KeyCode::F6,
-            x11_sys::XK_F7 => KeyCode::F7,
-            x11_sys::XK_F8 => KeyCode::F8,
-            x11_sys::XK_F9 => KeyCode::F9,
-            x11_sys::XK_F10 => KeyCode::F10,
-            x11_sys::XK_F11 => KeyCode::F11,
-            x11_sys::XK_F12 => KeyCode::F12,
-            
-            x11_sys::XK_Print => KeyCode::PrintScreen,
-            x11_sys::XK_Home => KeyCode::Home,
-            x11_sys::XK_Page_Up => KeyCode::PageUp,
-            x11_sys::XK_Delete => KeyCode::Delete,
-            x11_sys::XK_End => KeyCode::End,
-            x11_sys::XK_Page_Down => KeyCode::PageDown,
-            x11_sys::XK_Left => KeyCode::ArrowLeft,
-            x11_sys::XK_Right => KeyCode::ArrowRight,
-            x11_sys::XK_Down => KeyCode::ArrowDown,
-            x11_sys::XK_Up => KeyCode::ArrowUp,
-            _ => KeyCode::Unknown,
-        }
+        keysym_to_keycode(keysym as u32)
     }
 
+    pub fn start_dragging(&mut self, items: Vec<DragItem>) {
+        self.stop_timer(DND_FINISHED_TIMER_ID);
+        // drags start from a mouse down, so the window that got the last click is the source
+        if !unsafe {self.dnd.start_dragging(self.last_click_window, &items, x11_sys::CurrentTime as x11_sys::Time)} {
+            crate::error!("start_dragging: could not grab the pointer");
+        }
+    }
+    
+    // the position of the last XdndPosition in the coordinates of the window it went to
+    unsafe fn dnd_position(&self) -> DVec2 {
+        let (x_root, y_root) = self.dnd.position.unwrap_or((0, 0));
+        let root = x11_sys::XRootWindow(self.display, x11_sys::XDefaultScreen(self.display));
+        let mut x = 0;
+        let mut y = 0;
+        let mut child = 0;
+        x11_sys::XTranslateCoordinates(self.display, root, self.dnd.target_window, x_root, y_root, &mut x, &mut y, &mut child);
+        let dpi_factor = if let Some(window_ptr) = self.window_map.get(&self.dnd.target_window) {
+            (**window_ptr).last_window_geom.dpi_factor
+        }
+        else {
+            1.0
+        };
+        DVec2 {x: x as f64 / dpi_factor, y: y as f64 / dpi_factor}
+    }
+    
+    // client messages dont carry the keyboard state, so ask the server
+    unsafe fn dnd_modifiers(&self) -> KeyModifiers {
+        let mut root = 0;
+        let mut child = 0;
+        let mut pos = (0, 0, 0, 0);
+        let mut mask = 0;
+        x11_sys::XQueryPointer(self.display, self.dnd.target_window, &mut root, &mut child, &mut pos.0, &mut pos.1, &mut pos.2, &mut pos.3, &mut mask);
+        self.xkeystate_to_modifiers(mask)
+    }
+    
+    unsafe fn dnd_send_drag(&mut self) {
+        let response = self.dnd_drag_response();
+        self.dnd.send_status_event(response);
+    }
+    
+    // asks the application whether it takes the drag at the current position
+    unsafe fn dnd_drag_response(&mut self) -> DragResponse {
+        let items = if let Some(items) = self.dnd.items.clone() {items} else {return DragResponse::None};
+        let response = Rc::new(Cell::new(DragResponse::None));
+        self.do_callback(XlibEvent::Drag(DragEvent {
+            modifiers: self.dnd_modifiers(),
+            handled: Cell::new(false),
+            abs: self.dnd_position(),
+            items,
+            response: response.clone()
+        }));
+        response.get()
+    }
+    
+    // the drag left our window, send it somewhere far away so every drag area sees it go out
+    unsafe fn dnd_send_drag_out(&mut self) {
+        self.do_callback(XlibEvent::Drag(DragEvent {
+            modifiers: KeyModifiers::default(),
+            handled: Cell::new(false),
+            abs: DVec2 {x: -100000.0, y: -100000.0},
+            items: Rc::new(Vec::new()),
+            response: Rc::new(Cell::new(DragResponse::None))
+        }));
+    }
+    
+    // Only drops the application accepted are delivered, the others are finished as rejected. A
+    // drop that came in before the items were known was never answered, so ask for it now.
+    unsafe fn dnd_send_drop(&mut self) {
+        let items = if let Some(items) = self.dnd.items.clone() {items} else {return};
+        if self.dnd.drop_pending {
+            self.dnd.response = self.dnd_drag_response();
+        }
+        if self.dnd.response != DragResponse::None {
+            self.do_callback(XlibEvent::Drop(DropEvent {
+                modifiers: self.dnd_modifiers(),
+                handled: Cell::new(false),
+                abs: self.dnd_position(),
+                items,
+            }));
+        }
+        else {
+            self.dnd_send_drag_out();
+        }
+        self.dnd.send_finished_event();
+    }
+    
     unsafe fn copy_to_clipboard(&mut self, text: &String, window: &XlibWindow, event: &XEvent) {
         // store the text on the clipboard
         self.clipboard = text.clone();
@@ -840,6 +932,145 @@ impl XlibApp {
     }
 }
 
+// x11 keysyms are shared with xkbcommon, so the wayland backend maps keys through here as well
+pub fn keysym_to_keycode(keysym: u32) -> KeyCode {
+    match keysym {
+        x11_sys::XK_a => KeyCode::KeyA,
+        x11_sys::XK_A => KeyCode::KeyA,
+        x11_sys::XK_b => KeyCode::KeyB,
+        x11_sys::XK_B => KeyCode::KeyB,
+        x11_sys::XK_c => KeyCode::KeyC,
+        x11_sys::XK_C => KeyCode::KeyC,
+        x11_sys::XK_d => KeyCode::KeyD,
+        x11_sys::XK_D => KeyCode::KeyD,
+        x11_sys::XK_e => KeyCode::KeyE,
+        x11_sys::XK_E => KeyCode::KeyE,
+        x11_sys::XK_f => KeyCode::KeyF,
+        x11_sys::XK_F => KeyCode::KeyF,
+        x11_sys::XK_g => KeyCode::KeyG,
+        x11_sys::XK_G => KeyCode::KeyG,
+        x11_sys::XK_h => KeyCode::KeyH,
+        x11_sys::XK_H => KeyCode::KeyH,
+        x11_sys::XK_i => KeyCode::KeyI,
+        x11_sys::XK_I => KeyCode::KeyI,
+        x11_sys::XK_j => KeyCode::KeyJ,
+        x11_sys::XK_J => KeyCode::KeyJ,
+        x11_sys::XK_k => KeyCode::KeyK,
+        x11_sys::XK_K => KeyCode::KeyK,
+        x11_sys::XK_l => KeyCode::KeyL,
+        x11_sys::XK_L => KeyCode::KeyL,
+        x11_sys::XK_m => KeyCode::KeyM,
+        x11_sys::XK_M => KeyCode::KeyM,
+        x11_sys::XK_n => KeyCode::KeyN,
+        x11_sys::XK_N => KeyCode::KeyN,
+        x11_sys::XK_o => KeyCode::KeyO,
+        x11_sys::XK_O => KeyCode::KeyO,
+        x11_sys::XK_p => KeyCode::KeyP,
+        x11_sys::XK_P => KeyCode::KeyP,
+        x11_sys::XK_q => KeyCode::KeyQ,
+        x11_sys::XK_Q => KeyCode::KeyQ,
+        x11_sys::XK_r => KeyCode::KeyR,
+        x11_sys::XK_R => KeyCode::KeyR,
+        x11_sys::XK_s => KeyCode::KeyS,
+        x11_sys::XK_S => KeyCode::KeyS,
+        x11_sys::XK_t => KeyCode::KeyT,
+        x11_sys::XK_T => KeyCode::KeyT,
+        x11_sys::XK_u => KeyCode::KeyU,
+        x11_sys::XK_U => KeyCode::KeyU,
+        x11_sys::XK_v => KeyCode::KeyV,
+        x11_sys::XK_V => KeyCode::KeyV,
+        x11_sys::XK_w => KeyCode::KeyW,
+        x11_sys::XK_W => KeyCode::KeyW,
+        x11_sys::XK_x => KeyCode::KeyX,
+        x11_sys::XK_X => KeyCode::KeyX,
+        x11_sys::XK_y => KeyCode::KeyY,
+        x11_sys::XK_Y => KeyCode::KeyY,
+        x11_sys::XK_z => KeyCode::KeyZ,
+        x11_sys::XK_Z => KeyCode::KeyZ,
+        
+        x11_sys::XK_0 => KeyCode::Key0,
+        x11_sys::XK_1 => KeyCode::Key1,
+        x11_sys::XK_2 => KeyCode::Key2,
+        x11_sys::XK_3 => KeyCode::Key3,
+        x11_sys::XK_4 => KeyCode::Key4,
+        x11_sys::XK_5 => KeyCode::Key5,
+        x11_sys::XK_6 => KeyCode::Key6,
+        x11_sys::XK_7 => KeyCode::Key7,
+        x11_sys::XK_8 => KeyCode::Key8,
+        x11_sys::XK_9 => KeyCode::Key9,
+        
+        x11_sys::XK_Alt_L => KeyCode::Alt,
+        x11_sys::XK_Alt_R => KeyCode::Alt,
+        x11_sys::XK_Meta_L => KeyCode::Logo,
+        x11_sys::XK_Meta_R => KeyCode::Logo,
+        x11_sys::XK_Shift_L => KeyCode::Shift,
+        x11_sys::XK_Shift_R => KeyCode::Shift,
+        x11_sys::XK_Control_L => KeyCode::Control,
+        x11_sys::XK_Control_R => KeyCode::Control,
+        
+        x11_sys::XK_equal => KeyCode::Equals,
+        x11_sys::XK_minus => KeyCode::Minus,
+        x11_sys::XK_bracketright => KeyCode::RBracket,
+        x11_sys::XK_bracketleft => KeyCode::LBracket,
+        x11_sys::XK_Return => KeyCode::ReturnKey,
+        x11_sys::XK_grave => KeyCode::Backtick,
+        x11_sys::XK_semicolon => KeyCode::Semicolon,
+        x11_sys::XK_backslash => KeyCode::Backslash,
+        x11_sys::XK_comma => KeyCode::Comma,
+        x11_sys::XK_slash => KeyCode::Slash,
+        x11_sys::XK_period => KeyCode::Period,
+        x11_sys::XK_Tab => KeyCode::Tab,
+        x11_sys::XK_ISO_Left_Tab => KeyCode::Tab,
+        x11_sys::XK_space => KeyCode::Space,
+        x11_sys::XK_BackSpace => KeyCode::Backspace,
+        x11_sys::XK_Escape => KeyCode::Escape,
+        x11_sys::XK_Caps_Lock => KeyCode::Capslock,
+        x11_sys::XK_KP_Decimal => KeyCode::NumpadDecimal,
+        x11_sys::XK_KP_Multiply => KeyCode::NumpadMultiply,
+        x11_sys::XK_KP_Add => KeyCode::NumpadAdd,
+        x11_sys::XK_Num_Lock => KeyCode::Numlock,
+        x11_sys::XK_KP_Divide => KeyCode::NumpadDivide,
+        x11_sys::XK_KP_Enter => KeyCode::NumpadEnter,
+        x11_sys::XK_KP_Subtract => KeyCode::NumpadSubtract,
+        //keysim::XK_9 => KeyCode::NumpadEquals,
+        x11_sys::XK_KP_0 => KeyCode::Numpad0,
+        x11_sys::XK_KP_1 => KeyCode::Numpad1,
+        x11_sys::XK_KP_2 => KeyCode::Numpad2,
+        x11_sys::XK_KP_3 => KeyCode::Numpad3,
+        x11_sys::XK_KP_4 => KeyCode::Numpad4,
+        x11_sys::XK_KP_5 => KeyCode::Numpad5,
+        x11_sys::XK_KP_6 => KeyCode::Numpad6,
+        x11_sys::XK_KP_7 => KeyCode::Numpad7,
+        x11_sys::XK_KP_8 => KeyCode::Numpad8,
+        x11_sys::XK_KP_9 => KeyCode::Numpad9,
+        
+        x11_sys::XK_F1 => KeyCode::F1,
+        x11_sys::XK_F2 => KeyCode::F2,
+        x11_sys::XK_F3 => KeyCode::F3,
+        x11_sys::XK_F4 => KeyCode::F4,
+        x11_sys::XK_F5 => KeyCode::F5,
+        x11_sys::XK_F6 => KeyCode::F6,
+        x11_sys::XK_F7 => KeyCode::F7,
+        x11_sys::XK_F8 => KeyCode::F8,
+        x11_sys::XK_F9 => KeyCode::F9,
+        x11_sys::XK_F10 => KeyCode::F10,
+        x11_sys::XK_F11 => KeyCode::F11,
+        x11_sys::XK_F12 => KeyCode::F12,
+        
+        x11_sys::XK_Print => KeyCode::PrintScreen,
+        x11_sys::XK_Home => KeyCode::Home,
+        x11_sys::XK_Page_Up => KeyCode::PageUp,
+        x11_sys::XK_Delete => KeyCode::Delete,
+        x11_sys::XK_End => KeyCode::End,
+        x11_sys::XK_Page_Down => KeyCode::PageDown,
+        x11_sys::XK_Left => KeyCode::ArrowLeft,
+        x11_sys::XK_Right => KeyCode::ArrowRight,
+        x11_sys::XK_Down => KeyCode::ArrowDown,
+        x11_sys::XK_Up => KeyCode::ArrowUp,
+        _ => KeyCode::Unknown,
+    }
+}
+
 pub struct XlibAtoms {
     pub clipboard: x11_sys::Atom,
     pub net_wm_moveresize: x11_sys::Atom,
@@ -849,6 +1080,8 @@ pub struct XlibAtoms {
     pub net_wm_state: x11_sys::Atom,
     pub new_wm_state_maximized_horz: x11_sys::Atom,
     pub new_wm_state_maximized_vert: x11_sys::Atom,
+    pub net_wm_state_fullscreen: x11_sys::Atom,
+    pub net_wm_state_above: x11_sys::Atom,
     pub targets: x11_sys::Atom,
     pub utf8_string: x11_sys::Atom,
     pub text: x11_sys::Atom,
@@ -868,6 +1101,8 @@ impl XlibAtoms {
             net_wm_state: x11_sys::XInternAtom(display, "_NET_WM_STATE\0".as_ptr() as *const _, 0),
             new_wm_state_maximized_horz: x11_sys::XInternAtom(display, "_NET_WM_STATE_MAXIMIZED_HORZ\0".as_ptr() as *const _, 0),
             new_wm_state_maximized_vert: x11_sys::XInternAtom(display, "_NET_WM_STATE_MAXIMIZED_VERT\0".as_ptr() as *const _, 0),
+            net_wm_state_fullscreen: x11_sys::XInternAtom(display, "_NET_WM_STATE_FULLSCREEN\0".as_ptr() as *const _, 0),
+            net_wm_state_above: x11_sys::XInternAtom(display, "_NET_WM_STATE_ABOVE\0".as_ptr() as *const _, 0),
             targets: x11_sys::XInternAtom(display, "TARGETS\0".as_ptr() as *const _, 0),
             utf8_string: x11_sys::XInternAtom(display, "UTF8_STRING\0".as_ptr() as *const _, 1),
             atom: x11_sys::XInternAtom(display, "ATOM\0".as_ptr() as *const _, 0),
@@ -3,9 +3,9 @@ use {
         mem,
         cell::Cell,
         rc::Rc,
-        os::raw::{c_ulong, c_long, c_void, c_char},
+        os::raw::{c_ulong, c_long, c_void, c_char, c_int, c_uint, c_short},
         ptr,
-        ffi::{CStr,CString}, 
+        ffi::CStr, 
     },
     self::super::{
         x11_sys,
@@ -13,6 +13,7 @@ use {
         xlib_app::*,
     },
     crate::{
+        makepad_live_id::LiveId,
         area::Area,
         window::WindowId,
         makepad_math::{DVec2},
@@ -34,6 +35,8 @@ pub struct XlibWindow {
     pub last_window_geom: WindowGeom,
     
     pub ime_spot: DVec2,
+    pub ime_preedit: XimPreedit,
+    pub ime_callbacks: Option<Box<[x11_sys::XIMCallback; 3]>>,
     pub current_cursor: MouseCursor,
     pub last_mouse_pos: DVec2,
 }
@@ -62,6 +65,8 @@ impl XlibWindow {
             last_window_geom: WindowGeom::default(),
             last_nc_mode: None,
             ime_spot: DVec2::default(),
+            ime_preedit: XimPreedit::default(),
+            ime_callbacks: None,
             current_cursor: MouseCursor::Default,
             last_mouse_pos: DVec2::default(),
         }
@@ -96,6 +101,7 @@ impl XlibWindow {
                     | x11_sys::FocusChangeMask
                     | x11_sys::EnterWindowMask
                     | x11_sys::LeaveWindowMask
+                    | x11_sys::PropertyChangeMask
             ) as c_long;
             
             let dpi_factor = self.get_dpi_factor();
@@ -150,24 +156,13 @@ impl XlibWindow {
             let title_bytes = format!("{}\0", title);
             x11_sys::XStoreName(display, window, title_bytes.as_bytes().as_ptr() as *const c_char);
             
-            let xic = x11_sys::XCreateIC(
-                get_xlib_app_global().xim,
-                x11_sys::XNInputStyle.as_ptr(),
-                (x11_sys::XIMPreeditNothing | x11_sys::XIMStatusNothing) as i32,
-                x11_sys::XNClientWindow.as_ptr(),
-                window,
-                x11_sys::XNFocusWindow.as_ptr(),
-                window,
-                ptr::null_mut() as *mut c_void
-            );
-            
             // Create a window
             get_xlib_app_global().window_map.insert(window, self);
             
             self.attributes = Some(attributes);
             self.visual_info = Some(visual_info);
             self.window = Some(window);
-            self.xic = Some(xic);
+            self.xic = self.create_xic(window);
             self.last_window_geom = self.get_window_geom();
             
             let new_geom = self.get_window_geom();
@@ -180,7 +175,7 @@ impl XlibWindow {
         }
     }
     
-    fn restore_or_maximize(&self, add_remove: c_long) {
+    fn change_net_wm_state(&self, add_remove: c_long, state1: x11_sys::Atom, state2: x11_sys::Atom) {
         unsafe {
             let default_screen = x11_sys::XDefaultScreen(get_xlib_app_global().display);
             let root_window = x11_sys::XRootWindow(get_xlib_app_global().display, default_screen);
@@ -195,8 +190,9 @@ impl XlibWindow {
                 data: {
                     let mut msg = mem::zeroed::<x11_sys::XClientMessageEvent__bindgen_ty_1>();
                     msg.l[0] = add_remove;
-                    msg.l[1] = get_xlib_app_global().atoms.new_wm_state_maximized_horz as c_long;
-                    msg.l[2] = get_xlib_app_global().atoms.new_wm_state_maximized_vert as c_long;
+                    msg.l[1] = state1 as c_long;
+                    msg.l[2] = state2 as c_long;
+                    msg.l[3] = 1; // source indication: normal application
                     msg
                 }
             };
@@ -207,19 +203,35 @@ impl XlibWindow {
                 (x11_sys::SubstructureNotifyMask | x11_sys::SubstructureRedirectMask) as c_long,
                 &mut xclient as *mut _ as *mut x11_sys::XEvent
             );
+            x11_sys::XFlush(get_xlib_app_global().display);
         }
     }
     
     pub fn restore(&self) {
-        self.restore_or_maximize(_NET_WM_STATE_REMOVE);
+        let atoms = &get_xlib_app_global().atoms;
+        self.change_net_wm_state(_NET_WM_STATE_REMOVE, atoms.new_wm_state_maximized_horz, atoms.new_wm_state_maximized_vert);
     }
     
     pub fn maximize(&self) {
-        self.restore_or_maximize(_NET_WM_STATE_ADD);
+        let atoms = &get_xlib_app_global().atoms;
+        self.change_net_wm_state(_NET_WM_STATE_ADD, atoms.new_wm_state_maximized_horz, atoms.new_wm_state_maximized_vert);
+    }
+    
+    pub fn fullscreen(&self) {
+        self.change_net_wm_state(_NET_WM_STATE_ADD, get_xlib_app_global().atoms.net_wm_state_fullscreen, 0);
+    }
+    
+    pub fn normalize(&self) {
+        let atoms = &get_xlib_app_global().atoms;
+        self.change_net_wm_state(_NET_WM_STATE_REMOVE, atoms.net_wm_state_fullscreen, 0);
+        self.change_net_wm_state(_NET_WM_STATE_REMOVE, atoms.new_wm_state_maximized_horz, atoms.new_wm_state_maximized_vert);
     }
     
     pub fn close_window(&mut self) {
         unsafe {
+            if let Some(xic) = self.xic.take() {
+                x11_sys::XDestroyIC(xic);
+            }
             x11_sys::XDestroyWindow(get_xlib_app_global().display, self.window.unwrap());
             self.window = None;
             // lets remove us from the mapping
@@ -235,19 +247,34 @@ impl XlibWindow {
         }
     }
     
-    pub fn set_topmost(&self, _topmost: bool) {
+    pub fn set_topmost(&self, topmost: bool) {
+        self.change_net_wm_state(
+            if topmost {_NET_WM_STATE_ADD} else {_NET_WM_STATE_REMOVE},
+            get_xlib_app_global().atoms.net_wm_state_above,
+            0
+        );
     }
     
     pub fn get_is_topmost(&self) -> bool {
-        false
+        self.get_net_wm_state().is_topmost
+    }
+    
+    pub fn get_is_fullscreen(&self) -> bool {
+        self.get_net_wm_state().is_fullscreen
+    }
+    
+    pub fn get_is_maximized(&self) -> bool {
+        self.get_net_wm_state().is_maximized
     }
     
     pub fn get_window_geom(&self) -> WindowGeom {
+        let state = self.get_net_wm_state();
         WindowGeom {
             xr_is_presenting: false,
-            can_fullscreen: false,
-            is_topmost: self.get_is_topmost(),
-            is_fullscreen: self.get_is_maximized(),
+            can_fullscreen: true,
+            is_topmost: state.is_topmost,
+            is_fullscreen: state.is_fullscreen,
+            is_maximized: state.is_maximized,
             inner_size: self.get_inner_size(),
             outer_size: self.get_outer_size(),
             dpi_factor: self.get_dpi_factor(),
@@ -255,8 +282,9 @@ impl XlibWindow {
         }
     }
     
-    pub fn get_is_maximized(&self) -> bool {
-        let mut maximized = false;
+    fn get_net_wm_state(&self) -> NetWmState {
+        let atoms = &get_xlib_app_global().atoms;
+        let mut state = NetWmState::default();
         unsafe {
             let mut prop_type = mem::MaybeUninit::uninit();
             let mut format = mem::MaybeUninit::uninit();
@@ -266,7 +294,7 @@ impl XlibWindow {
             let result = x11_sys::XGetWindowProperty(
                 get_xlib_app_global().display,
                 self.window.unwrap(),
-                get_xlib_app_global().atoms.net_wm_state,
+                atoms.net_wm_state,
                 0,
                 !0,
                 0,
@@ -284,21 +312,196 @@ impl XlibWindow {
             let properties = properties.assume_init();
             if result == 0 && properties != ptr::null_mut() {
                 let items = std::slice::from_raw_parts::<c_ulong>(properties as *mut _, n_item as usize);
-                for item in items {
-                    if *item == get_xlib_app_global().atoms.new_wm_state_maximized_horz
-                        || *item == get_xlib_app_global().atoms.new_wm_state_maximized_vert {
-                        maximized = true;
-                        break;
-                    }
-                }
+                state = NetWmState::from_items(
+                    items,
+                    atoms.net_wm_state_fullscreen,
+                    [atoms.new_wm_state_maximized_horz, atoms.new_wm_state_maximized_vert],
+                    atoms.net_wm_state_above
+                );
                 x11_sys::XFree(properties as *mut _);
             }
         }
-        maximized
+        state
+    }
+    
+    unsafe fn create_xic(&mut self, window: c_ulong) -> Option<x11_sys::XIC> {
+        let xim = get_xlib_app_global().xim;
+        if xim == ptr::null_mut() {
+            return None
+        }
+        // pick the richest preedit style the input method supports. With callbacks we render
+        // the composition inline ourselves, with position the IM draws it at our spot
+        let mut styles: *mut x11_sys::XIMStyles = ptr::null_mut();
+        let mut supports_callbacks = false;
+        let mut supports_position = false;
+        if x11_sys::XGetIMValues(xim, x11_sys::XNQueryInputStyle.as_ptr(), &mut styles, ptr::null_mut() as *mut c_void) == ptr::null_mut()
+            && styles != ptr::null_mut() {
+            let supported = std::slice::from_raw_parts((*styles).supported_styles, (*styles).count_styles as usize);
+            for style in supported {
+                if *style == (x11_sys::XIMPreeditCallbacks | x11_sys::XIMStatusNothing) as c_ulong {
+                    supports_callbacks = true;
+                }
+                if *style == (x11_sys::XIMPreeditPosition | x11_sys::XIMStatusNothing) as c_ulong {
+                    supports_position = true;
+                }
+            }
+            x11_sys::XFree(styles as *mut c_void);
+        }
+        
+        let mut spot = self.ime_spot_xpoint();
+        let xic = if supports_callbacks {
+            let client_data = self as *mut XlibWindow as x11_sys::XPointer;
+            let callbacks = self.ime_callbacks.insert(Box::new([
+                x11_sys::XIMCallback {client_data, callback: Some(xim_preedit_start)},
+                x11_sys::XIMCallback {client_data, callback: Some(xim_preedit_done)},
+                x11_sys::XIMCallback {client_data, callback: Some(xim_preedit_draw)},
+            ]));
+            let preedit_attributes = x11_sys::XVaCreateNestedList(
+                0,
+                x11_sys::XNSpotLocation.as_ptr(),
+                &mut spot,
+                x11_sys::XNPreeditStartCallback.as_ptr(),
+                callbacks.as_mut_ptr().add(0),
+                x11_sys::XNPreeditDoneCallback.as_ptr(),
+                callbacks.as_mut_ptr().add(1),
+                x11_sys::XNPreeditDrawCallback.as_ptr(),
+                callbacks.as_mut_ptr().add(2),
+                ptr::null_mut() as *mut c_void
+            );
+            let xic = x11_sys::XCreateIC(
+                xim,
+                x11_sys::XNInputStyle.as_ptr(),
+                (x11_sys::XIMPreeditCallbacks | x11_sys::XIMStatusNothing) as c_ulong,
+                x11_sys::XNClientWindow.as_ptr(),
+                window,
+                x11_sys::XNFocusWindow.as_ptr(),
+                window,
+                x11_sys::XNPreeditAttributes.as_ptr(),
+                preedit_attributes,
+                ptr::null_mut() as *mut c_void
+            );
+            x11_sys::XFree(preedit_attributes);
+            xic
+        }
+        else if supports_position {
+            let preedit_attributes = x11_sys::XVaCreateNestedList(
+                0,
+                x11_sys::XNSpotLocation.as_ptr(),
+                &mut spot,
+                ptr::null_mut() as *mut c_void
+            );
+            let xic = x11_sys::XCreateIC(
+                xim,
+                x11_sys::XNInputStyle.as_ptr(),
+                (x11_sys::XIMPreeditPosition | x11_sys::XIMStatusNothing) as c_ulong,
+                x11_sys::XNClientWindow.as_ptr(),
+                window,
+                x11_sys::XNFocusWindow.as_ptr(),
+                window,
+                x11_sys::XNPreeditAttributes.as_ptr(),
+                preedit_attributes,
+                ptr::null_mut() as *mut c_void
+            );
+            x11_sys::XFree(preedit_attributes);
+            xic
+        }
+        else {
+            ptr::null_mut()
+        };
+        
+        // fall back to no preedit at all, which still gives us dead keys and compose
+        let xic = if xic == ptr::null_mut() {
+            x11_sys::XCreateIC(
+                xim,
+                x11_sys::XNInputStyle.as_ptr(),
+                (x11_sys::XIMPreeditNothing | x11_sys::XIMStatusNothing) as c_ulong,
+                x11_sys::XNClientWindow.as_ptr(),
+                window,
+                x11_sys::XNFocusWindow.as_ptr(),
+                window,
+                ptr::null_mut() as *mut c_void
+            )
+        }
+        else {
+            xic
+        };
+        if xic == ptr::null_mut() {
+            None
+        }
+        else {
+            Some(xic)
+        }
+    }
+    
+    fn ime_spot_xpoint(&self) -> x11_sys::XPoint {
+        let dpi_factor = self.last_window_geom.dpi_factor.max(1.0);
+        x11_sys::XPoint {
+            x: (self.ime_spot.x * dpi_factor) as c_short,
+            y: (self.ime_spot.y * dpi_factor) as c_short
+        }
     }
     
     pub fn set_ime_spot(&mut self, spot: DVec2) {
         self.ime_spot = spot;
+        if let Some(xic) = self.xic {
+            unsafe {
+                let mut spot = self.ime_spot_xpoint();
+                let preedit_attributes = x11_sys::XVaCreateNestedList(
+                    0,
+                    x11_sys::XNSpotLocation.as_ptr(),
+                    &mut spot,
+                    ptr::null_mut() as *mut c_void
+                );
+                x11_sys::XSetICValues(
+                    xic,
+                    x11_sys::XNPreeditAttributes.as_ptr(),
+                    preedit_attributes,
+                    ptr::null_mut() as *mut c_void
+                );
+                x11_sys::XFree(preedit_attributes);
+            }
+        }
+    }
+    
+    pub fn focus_ime(&mut self) {
+        if let Some(xic) = self.xic {
+            unsafe {x11_sys::XSetICFocus(xic)};
+        }
+    }
+    
+    pub fn unfocus_ime(&mut self) {
+        if let Some(xic) = self.xic {
+            unsafe {x11_sys::XUnsetICFocus(xic)};
+        }
+        self.cancel_preedit();
+    }
+    
+    // the text input went away, forget the composition without touching the document
+    pub fn hide_ime(&mut self) {
+        if let Some(xic) = self.xic {
+            unsafe {x11_sys::XUnsetICFocus(xic)};
+        }
+        self.ime_preedit.clear();
+    }
+    
+    // drops an in-progress composition by replacing it with nothing
+    pub fn cancel_preedit(&mut self) {
+        if let Some(event) = self.ime_preedit.cancel() {
+            self.do_callback(XlibEvent::TextInput(event));
+        }
+    }
+    
+    fn update_preedit(&mut self, draw: &x11_sys::XIMPreeditDrawCallbackStruct) {
+        let text = unsafe {xim_text_to_string(draw.text)};
+        if let Some(event) = self.ime_preedit.draw(draw.chg_first, draw.chg_length, &text) {
+            self.do_callback(XlibEvent::TextInput(event));
+        }
+    }
+    
+    /// Text committed by the input method, replaces whatever composition was shown
+    pub fn send_commit_text(&mut self, input: String) {
+        let event = self.ime_preedit.commit(input);
+        self.do_callback(XlibEvent::TextInput(event));
     }
     
     pub fn get_position(&self) -> DVec2 {
@@ -344,13 +547,25 @@ impl XlibWindow {
         }
     }
     
-    pub fn set_position(&mut self, _pos: DVec2) {
+    pub fn set_position(&mut self, pos: DVec2) {
+        unsafe {
+            let display = get_xlib_app_global().display;
+            x11_sys::XMoveWindow(display, self.window.unwrap(), pos.x as c_int, pos.y as c_int);
+            x11_sys::XFlush(display);
+        }
     }
     
-    pub fn set_outer_size(&self, _size: DVec2) {
+    // outer size is in physical pixels, same as get_outer_size
+    pub fn set_outer_size(&self, size: DVec2) {
+        unsafe {
+            let display = get_xlib_app_global().display;
+            x11_sys::XResizeWindow(display, self.window.unwrap(), size.x.max(1.0) as c_uint, size.y.max(1.0) as c_uint);
+            x11_sys::XFlush(display);
+        }
     }
     
-    pub fn set_inner_size(&self, _size: DVec2) {
+    pub fn set_inner_size(&self, size: DVec2) {
+        self.set_outer_size(size * self.get_dpi_factor());
     }
     
     pub fn get_dpi_factor(&self) -> f64 {
@@ -473,6 +688,38 @@ impl XlibWindow {
 }
 
 
+unsafe fn xim_text_to_string(text: *const x11_sys::XIMText) -> String {
+    if text == ptr::null() || (*text).string == ptr::null_mut() {
+        return String::new()
+    }
+    let text = &*text;
+    if text.encoding_is_wchar != 0 {
+        let wide = std::slice::from_raw_parts(text.string as *const u32, text.length as usize);
+        wide.iter().filter_map( | c | char::from_u32(*c)).collect()
+    }
+    else {
+        // length counts characters, not bytes, so rely on the terminator
+        CStr::from_ptr(text.string as *const c_char).to_string_lossy().to_string()
+    }
+}
+
+unsafe extern "C" fn xim_preedit_start(_xic: x11_sys::XIC, client_data: x11_sys::XPointer, _call_data: x11_sys::XPointer) {
+    let window = &mut *(client_data as *mut XlibWindow);
+    window.ime_preedit.start();
+}
+
+unsafe extern "C" fn xim_preedit_done(_xic: x11_sys::XIC, client_data: x11_sys::XPointer, _call_data: x11_sys::XPointer) {
+    let window = &mut *(client_data as *mut XlibWindow);
+    window.cancel_preedit();
+}
+
+unsafe extern "C" fn xim_preedit_draw(_xic: x11_sys::XIC, client_data: x11_sys::XPointer, call_data: x11_sys::XPointer) {
+    let window = &mut *(client_data as *mut XlibWindow);
+    if call_data != ptr::null_mut() {
+        window.update_preedit(&*(call_data as *const x11_sys::XIMPreeditDrawCallbackStruct));
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 #[repr(C)]
 struct MwmHints {
@@ -510,11 +757,82 @@ pub const _NET_WM_STATE_TOGGLE: c_long = 2;/* toggle property  */
 
 /* move via keyboard */
 
+// an outbound drag started with StartDragging, we own XdndSelection while it runs
+pub struct DndSource {
+    pub window: x11_sys::Window,
+    pub uri_list: String,
+    pub target: Option<x11_sys::Window>,
+    pub accepted: bool,
+    pub waiting_for_status: bool,
+    pub pending_position: Option<(c_int, c_int, x11_sys::Time)>,
+    pub dropped: bool,
+}
+
+/// What the button release at the end of our drag sends to the window under the mouse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DndRelease {
+    Drop(x11_sys::Window),
+    Leave(x11_sys::Window),
+    Nothing,
+}
+
+impl DndSource {
+    pub fn new(window: x11_sys::Window, items: &[DragItem]) -> DndSource {
+        DndSource {
+            window,
+            uri_list: dnd_items_to_uri_list(items),
+            target: None,
+            accepted: false,
+            waiting_for_status: false,
+            pending_position: None,
+            dropped: false,
+        }
+    }
+    
+    /// Handles the flags of a XdndStatus from `target`, returns the position that was held back
+    /// while waiting for it.
+    pub fn handle_status(&mut self, target: x11_sys::Window, flags: c_long) -> Option<(c_int, c_int, x11_sys::Time)> {
+        if self.target != Some(target) {
+            return None
+        }
+        self.accepted = flags & (1 << 0) != 0;
+        self.waiting_for_status = false;
+        self.pending_position.take()
+    }
+    
+    /// Only drops the target accepted with its last status are sent, everything else leaves.
+    pub fn release(&mut self) -> DndRelease {
+        match self.target {
+            Some(target) if self.accepted => {
+                self.dropped = true;
+                DndRelease::Drop(target)
+            }
+            Some(target) => DndRelease::Leave(target),
+            None => DndRelease::Nothing
+        }
+    }
+    
+    /// true if a XdndFinished from `target` ends this drag.
+    pub fn is_finished_by(&self, target: x11_sys::Window) -> bool {
+        self.dropped && self.target == Some(target)
+    }
+}
+
+/// How long we wait for XdndFinished after a drop before giving up on the target.
+pub const DND_FINISHED_TIMEOUT: f64 = 5.0;
+
 pub struct Dnd {
     pub atoms: DndAtoms,
     pub display: *mut x11_sys::Display,
     pub type_list: Option<Vec<x11_sys::Atom >>,
-    pub selection: Option<CString>,
+    pub source_window: x11_sys::Window,
+    pub target_window: x11_sys::Window,
+    pub items: Option<Rc<Vec<DragItem >>>,
+    pub selection_requested: bool,
+    pub position: Option<(c_int, c_int)>,
+    pub drop_pending: bool,
+    pub response: DragResponse,
+    pub source: Option<DndSource>,
 }
 
 impl Dnd {
@@ -523,7 +841,14 @@ impl Dnd {
             atoms: DndAtoms::new(display),
             display,
             type_list: None,
-            selection: None,
+            source_window: 0,
+            target_window: 0,
+            items: None,
+            selection_requested: false,
+            position: None,
+            drop_pending: false,
+            response: DragResponse::None,
+            source: None,
         }
     }
     
@@ -563,81 +888,97 @@ impl Dnd {
         self.type_list = Some(if has_more_types {
             self.get_type_list_property(source_window)
         } else {
-            event.data.l[2..4]
+            event.data.l[2..5]
                 .iter()
                 .map( | &l | l as x11_sys::Atom)
                 .filter( | &atom | atom != x11_sys::None as x11_sys::Atom)
                 .collect()
         });
+        self.source_window = source_window;
+        self.target_window = event.window;
+        self.reset_inbound();
+    }
+    
+    fn reset_inbound(&mut self) {
+        self.items = None;
+        self.selection_requested = false;
+        self.position = None;
+        self.drop_pending = false;
+        self.response = DragResponse::None;
     }
     
-    /// Handles a XDndDrop event.
-    pub unsafe fn handle_drop_event(&mut self, event: &x11_sys::XClientMessageEvent) {
-        // The XDndLeave event is sent by the source window when a drag is confirmed. That is, the
+    /// Handles a XDndDrop event, returns true if the dragged items are known and the drop can be
+    /// delivered right away.
+    pub unsafe fn handle_drop_event(&mut self, event: &x11_sys::XClientMessageEvent) -> bool {
+        // The XDndDrop event is sent by the source window when a drag is confirmed. That is, the
         // mouse button is released while the mouse is inside the client rectangle of the target
-        // window. The target window is supposed to respond to this by requesting that the selection
-        // representing the thing being dragged is converted to the appropriate data type (in our
-        // case, a URI list). The source window, in turn, is supposed to respond this by sending a
-        // selection event containing the data to the source window.
-        
-        let target_window = event.window as x11_sys::Window;
-        self.convert_selection(target_window);
-        self.type_list = None;
+        // window. If we haven't got the URI list yet we request it now, and deliver the drop
+        // once the source answers with a selection event.
+        if self.items.is_some() {
+            return true
+        }
+        self.drop_pending = true;
+        if !self.selection_requested {
+            self.selection_requested = true;
+            self.convert_selection(self.target_window, event.data.l[2] as x11_sys::Time);
+        }
+        false
     }
     
-    /// Handles a XDndLeave event.
-    pub unsafe fn handle_leave_event(&mut self, _event: &x11_sys::XClientMessageEvent) {
+    /// Handles a XDndLeave event, returns true if the drag was visible to the application.
+    pub unsafe fn handle_leave_event(&mut self, _event: &x11_sys::XClientMessageEvent) -> bool {
         // The XDndLeave event is sent by the source window when a drag is canceled. That is, the
         // mouse leaves the client rectangle of the target window. The target window is supposed to
         // repsond this this by pretending the drag never happened.
-        
+        let was_visible = self.items.is_some();
         self.type_list = None;
+        self.reset_inbound();
+        was_visible
     }
     
-    /// Handles a XDndPosition event.
-    pub unsafe fn handle_position_event(&mut self, event: &x11_sys::XClientMessageEvent) {
+    /// Handles a XDndPosition event, returns true if the application should be asked whether
+    /// it accepts the drag at this position.
+    pub unsafe fn handle_position_event(&mut self, event: &x11_sys::XClientMessageEvent) -> bool {
         // The XDndPosition event is sent by the source window after the XDndEnter event, every time
         // the mouse is moved. The target window is supposed to respond to this by sending a status
         // event to the source window notifying whether it can accept the drag at this position.
+        self.position = Some(((event.data.l[2] >> 16) as c_int, (event.data.l[2] & 0xffff) as c_int));
         
-        let target_window = event.window as x11_sys::Window;
-        let source_window = event.data.l[0] as x11_sys::Window;
-        
-        // For now we accept te drag if and only if the list of types supported by the source
-        // includes a uri list.
-        //
-        // TODO: Extend this test by taking into account the position of the mouse as well.
-        let accepted = self.type_list.as_ref().map_or(false, | type_list | type_list.contains(&self.atoms.uri_list));
-        
-        // Notify the source window whether we can accept the drag at this position.
-        self.send_status_event(source_window, target_window, accepted);
-        
-        // If this is the first time we've accepted the drag, request that the drag-and-drop
-        // selection be converted to a URI list. The target window is supposed to respond to this by
-        // sending a XSelectionEvent containing the URI list.
+        // We only ever accept file paths, which come in as a uri list.
+        let has_uri_list = self.type_list.as_ref().map_or(false, | type_list | type_list.contains(&self.atoms.uri_list));
+        if !has_uri_list {
+            self.send_status_event(DragResponse::None);
+            return false
+        }
         
-        // Since this is an asynchronous operation, its possible for another XDndPosition event to
-        // come in before the response to the first conversion request has been received. In this
-        // case, a second conversion request will be sent, the response to which will be ignored.
-        if accepted && self.selection.is_none() {
+        // The items are needed to answer, so request the selection the first time around. Until
+        // the answer comes in we reject the drag, and send a real status once the data is in.
+        if self.items.is_none() {
+            if !self.selection_requested {
+                self.selection_requested = true;
+                self.convert_selection(self.target_window, event.data.l[3] as x11_sys::Time);
+            }
+            self.send_status_event(DragResponse::None);
+            return false
         }
+        true
     }
     
-    /// Handles a XSelectionEvent.
-    pub unsafe fn handle_selection_event(&mut self, _event: &x11_sys::XSelectionEvent) {
-        // The XSelectionEvent is sent by the source window in response to a request by the source
-        // window to convert the selection representing the thing being dragged to the appropriate
-        // data type. This request is always sent in response to a XDndDrop event, so this event
-        // should only be received after a drop operation has completed.
-        
-        //let source_window = event.requestor;
-        //let selection = CString::new(self.get_selection_property(source_window)).unwrap();
-        
-        // TODO: Actually use the selection
+    /// Handles a XSelectionEvent for XdndSelection.
+    pub unsafe fn handle_selection_event(&mut self, event: &x11_sys::XSelectionEvent) {
+        // The XSelectionEvent is sent in response to our request to convert the selection
+        // representing the thing being dragged to a URI list. The data is stored in a property on
+        // our own window.
+        if event.property == x11_sys::None as x11_sys::Atom {
+            self.items = Some(Rc::new(Vec::new()));
+            return
+        }
+        let uri_list = self.get_selection_property(event.requestor);
+        self.items = Some(Rc::new(dnd_uri_list_to_items(&String::from_utf8_lossy(&uri_list))));
     }
     
-    /// Gets the XDndSelection property from the source window.
-    pub unsafe fn get_selection_property(&mut self, source_window: x11_sys::Window) -> Vec< std::os::raw::c_uchar> {
+    /// Gets the XDndSelection property from the given window.
+    pub unsafe fn get_selection_property(&mut self, window: x11_sys::Window) -> Vec< std::os::raw::c_uchar> {
         let mut selection = Vec::new();
         let mut offset = 0;
         let length = 1024;
@@ -649,7 +990,7 @@ impl Dnd {
         loop {
             x11_sys::XGetWindowProperty(
                 self.display,
-                source_window,
+                window,
                 self.atoms.selection,
                 offset,
                 length,
@@ -661,11 +1002,15 @@ impl Dnd {
                 &mut bytes_after,
                 &mut prop,
             );
+            if prop.is_null() {
+                break;
+            }
             selection.extend_from_slice(std::slice::from_raw_parts(prop as *mut  std::os::raw::c_uchar, nitems as usize));
             x11_sys::XFree(prop as *mut c_void);
             if bytes_after == 0 {
                 break;
             }
+            // the offset is counted in 32 bit units
             offset += length;
         };
         selection
@@ -696,6 +1041,9 @@ impl Dnd {
                 &mut bytes_after,
                 &mut prop,
             );
+            if prop.is_null() {
+                break;
+            }
             type_list.extend_from_slice(std::slice::from_raw_parts(prop as *mut x11_sys::Atom, nitems as usize));
             x11_sys::XFree(prop as *mut c_void);
             if bytes_after == 0 {
@@ -706,11 +1054,19 @@ impl Dnd {
         type_list
     }
     
-    /// Sends a XDndStatus event to the target window.
-    pub unsafe fn send_status_event(&mut self, source_window: x11_sys::Window, target_window: x11_sys::Window, accepted: bool) {
+    fn response_to_action(&self, response: DragResponse) -> x11_sys::Atom {
+        match response {
+            DragResponse::None => self.atoms.none,
+            DragResponse::Copy => self.atoms.action_copy,
+            DragResponse::Link => self.atoms.action_link,
+            DragResponse::Move => self.atoms.action_move,
+        }
+    }
+    
+    unsafe fn send_client_message(&self, window: x11_sys::Window, message_type: x11_sys::Atom, l: [c_long; 5]) {
         x11_sys::XSendEvent(
             self.display,
-            source_window,
+            window,
             x11_sys::False as  std::os::raw::c_int,
             x11_sys::NoEventMask as  std::os::raw::c_long,
             &mut x11_sys::XClientMessageEvent {
@@ -718,16 +1074,12 @@ impl Dnd {
                 serial: 0,
                 send_event: 0,
                 display: self.display,
-                window: source_window,
-                message_type: self.atoms.status,
+                window,
+                message_type,
                 format: 32,
                 data: {
                     let mut data = mem::zeroed::<x11_sys::XClientMessageEvent__bindgen_ty_1>();
-                    data.l[0] = target_window as c_long;
-                    data.l[1] = if accepted {1 << 0} else {0};
-                    data.l[2] = 0;
-                    data.l[3] = 0;
-                    data.l[4] = if accepted {self.atoms.action_private} else {self.atoms.none} as c_long;
+                    data.l = l;
                     data
                 }
             } as *mut x11_sys::XClientMessageEvent as *mut x11_sys::XEvent
@@ -735,25 +1087,397 @@ impl Dnd {
         x11_sys::XFlush(self.display);
     }
     
+    /// Sends a XDndStatus event to the source window.
+    pub unsafe fn send_status_event(&mut self, response: DragResponse) {
+        self.response = response;
+        self.send_client_message(self.source_window, self.atoms.status, [
+            self.target_window as c_long,
+            dnd_status_flags(response),
+            0,
+            0,
+            self.response_to_action(response) as c_long
+        ]);
+    }
+    
+    /// Sends a XDndFinished event to the source window, and forgets about the drag. A drop the
+    /// application answered with `DragResponse::None` is reported as not accepted.
+    pub unsafe fn send_finished_event(&mut self) {
+        self.send_client_message(self.source_window, self.atoms.finished, [
+            self.target_window as c_long,
+            dnd_finished_flags(self.response),
+            self.response_to_action(self.response) as c_long,
+            0,
+            0
+        ]);
+        self.type_list = None;
+        self.reset_inbound();
+    }
+    
     // Requests that the selection representing the thing being dragged is converted to the
     // appropriate data type (in our case, a URI list).
-    pub unsafe fn convert_selection(&self, target_window: x11_sys::Window) {
+    pub unsafe fn convert_selection(&self, target_window: x11_sys::Window, time: x11_sys::Time) {
         x11_sys::XConvertSelection(
             self.display,
             self.atoms.selection,
             self.atoms.uri_list,
             self.atoms.selection,
             target_window,
-            x11_sys::CurrentTime as x11_sys::Time,
+            time,
         );
     }
+    
+    // true until the button is released, after a drop we only wait for XdndFinished
+    pub fn is_dragging(&self) -> bool {
+        self.source.as_ref().map_or(false, | source | !source.dropped)
+    }
+    
+    /// Starts an outbound drag from the given window, returns false if we couldn't grab the pointer.
+    pub unsafe fn start_dragging(&mut self, window: x11_sys::Window, items: &[DragItem], time: x11_sys::Time) -> bool {
+        // Everything the drag goes over is found by the pointer position, so we need all the
+        // motion and the final button release even when they happen outside our windows.
+        let grab = x11_sys::XGrabPointer(
+            self.display,
+            window,
+            x11_sys::False as c_int,
+            x11_sys::ButtonReleaseMask | x11_sys::PointerMotionMask,
+            x11_sys::GrabModeAsync as c_int,
+            x11_sys::GrabModeAsync as c_int,
+            x11_sys::None as x11_sys::Window,
+            x11_sys::None as x11_sys::Cursor,
+            time
+        );
+        if grab != x11_sys::GrabSuccess as c_int {
+            return false
+        }
+        x11_sys::XSetSelectionOwner(self.display, self.atoms.selection, window, time);
+        self.source = Some(DndSource::new(window, items));
+        true
+    }
+    
+    /// Gives up on our drag, returns true if there was one. Escape cancels a drag that is still
+    /// running, and a drop is given up on when the target never answers it with XdndFinished.
+    pub unsafe fn cancel_source(&mut self, time: x11_sys::Time) -> bool {
+        let source = if let Some(source) = self.source.take() {source} else {return false};
+        if !source.dropped {
+            x11_sys::XUngrabPointer(self.display, time);
+            if let Some(target) = source.target {
+                self.send_client_message(target, self.atoms.leave, [source.window as c_long, 0, 0, 0, 0]);
+            }
+        }
+        true
+    }
+    
+    // Walks down from the root window to the window under the mouse that has XdndAware set.
+    // Window managers reparent our top level windows, so the aware window is usually a child.
+    unsafe fn find_aware_window(&self, x_root: c_int, y_root: c_int) -> Option<x11_sys::Window> {
+        let root = x11_sys::XRootWindow(self.display, x11_sys::XDefaultScreen(self.display));
+        let mut window = root;
+        loop {
+            let mut x = 0;
+            let mut y = 0;
+            let mut child = 0;
+            x11_sys::XTranslateCoordinates(self.display, root, window, x_root, y_root, &mut x, &mut y, &mut child);
+            if child == x11_sys::None as x11_sys::Window {
+                return None
+            }
+            if self.is_aware(child) {
+                return Some(child)
+            }
+            window = child;
+        }
+    }
+    
+    unsafe fn is_aware(&self, window: x11_sys::Window) -> bool {
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut nitems = 0;
+        let mut bytes_after = 0;
+        let mut prop = ptr::null_mut();
+        x11_sys::XGetWindowProperty(
+            self.display,
+            window,
+            self.atoms.aware,
+            0,
+            1,
+            x11_sys::False as c_int,
+            4, // XA_ATOM
+            &mut actual_type,
+            &mut actual_format,
+            &mut nitems,
+            &mut bytes_after,
+            &mut prop,
+        );
+        if prop.is_null() {
+            return false
+        }
+        x11_sys::XFree(prop as *mut c_void);
+        nitems > 0
+    }
+    
+    /// Handles pointer motion while we are the drag source.
+    pub unsafe fn handle_source_motion(&mut self, x_root: c_int, y_root: c_int, time: x11_sys::Time) {
+        let target = self.find_aware_window(x_root, y_root);
+        let source = if let Some(source) = &mut self.source {source} else {return};
+        let source_window = source.window;
+        if target != source.target {
+            let old_target = source.target.take();
+            source.target = target;
+            source.accepted = false;
+            source.waiting_for_status = false;
+            source.pending_position = None;
+            if let Some(old_target) = old_target {
+                self.send_client_message(old_target, self.atoms.leave, [source_window as c_long, 0, 0, 0, 0]);
+            }
+            if let Some(target) = target {
+                self.send_client_message(target, self.atoms.enter, [
+                    source_window as c_long,
+                    5 << 24,
+                    self.atoms.uri_list as c_long,
+                    0,
+                    0
+                ]);
+            }
+        }
+        self.send_source_position(x_root, y_root, time);
+    }
+    
+    // Sources have to wait for a status before sending the next position, so we keep only the
+    // latest one around in the meantime.
+    unsafe fn send_source_position(&mut self, x_root: c_int, y_root: c_int, time: x11_sys::Time) {
+        let source = if let Some(source) = &mut self.source {source} else {return};
+        let target = if let Some(target) = source.target {target} else {return};
+        if source.waiting_for_status {
+            source.pending_position = Some((x_root, y_root, time));
+            return
+        }
+        source.waiting_for_status = true;
+        let source_window = source.window;
+        self.send_client_message(target, self.atoms.position, [
+            source_window as c_long,
+            0,
+            ((x_root as c_long) << 16) | (y_root as c_long & 0xffff),
+            time as c_long,
+            self.atoms.action_copy as c_long
+        ]);
+    }
+    
+    /// Handles a XDndStatus event from the target of our drag.
+    pub unsafe fn handle_status_event(&mut self, event: &x11_sys::XClientMessageEvent) {
+        let source = if let Some(source) = &mut self.source {source} else {return};
+        if let Some((x_root, y_root, time)) = source.handle_status(event.data.l[0] as x11_sys::Window, event.data.l[1]) {
+            self.send_source_position(x_root, y_root, time);
+        }
+    }
+    
+    /// Handles the button release that ends our drag, returns true if the drag is over. Otherwise
+    /// the drop was sent and the drag ends when the target sends XdndFinished.
+    pub unsafe fn handle_source_release(&mut self, time: x11_sys::Time) -> bool {
+        x11_sys::XUngrabPointer(self.display, time);
+        let source = if let Some(source) = &mut self.source {source} else {return true};
+        let source_window = source.window;
+        match source.release() {
+            DndRelease::Drop(target) => {
+                self.send_client_message(target, self.atoms.drop, [source_window as c_long, 0, time as c_long, 0, 0]);
+                false
+            }
+            DndRelease::Leave(target) => {
+                self.send_client_message(target, self.atoms.leave, [source_window as c_long, 0, 0, 0, 0]);
+                self.source = None;
+                true
+            }
+            DndRelease::Nothing => {
+                self.source = None;
+                true
+            }
+        }
+    }
+    
+    /// Handles a XDndFinished event, returns true if it ended our drag.
+    pub unsafe fn handle_finished_event(&mut self, event: &x11_sys::XClientMessageEvent) -> bool {
+        match &self.source {
+            Some(source) if source.is_finished_by(event.data.l[0] as x11_sys::Window) => {
+                self.source = None;
+                true
+            }
+            _ => false
+        }
+    }
+}
+
+/// An in-progress XIM composition, the wayland text input uses it too. The preedit is shown inline
+/// by sending it as text input that replaces the previously shown preedit, until it is committed or cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct XimPreedit {
+    // text of the composition as last sent with replace_last
+    text: Option<String>,
+}
+
+impl XimPreedit {
+    pub fn start(&mut self) {
+        self.text = Some(String::new());
+    }
+    
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+    
+    fn is_shown(&self) -> bool {
+        self.text.as_ref().map_or(false, | p | !p.is_empty())
+    }
+    
+    /// Replaces `chg_length` characters at `chg_first` with `text`, as a preedit draw callback does
+    pub fn draw(&mut self, chg_first: c_int, chg_length: c_int, text: &str) -> Option<TextInputEvent> {
+        let mut chars: Vec<char> = self.text.as_deref().unwrap_or("").chars().collect();
+        let first = (chg_first.max(0) as usize).min(chars.len());
+        let last = (first + chg_length.max(0) as usize).min(chars.len());
+        chars.splice(first..last, text.chars());
+        let preedit: String = chars.into_iter().collect();
+        let replace_last = self.is_shown();
+        if preedit.is_empty() && !replace_last {
+            return None
+        }
+        self.text = Some(preedit.clone());
+        Some(TextInputEvent {input: preedit, was_paste: false, replace_last})
+    }
+    
+    pub fn commit(&mut self, input: String) -> TextInputEvent {
+        let replace_last = self.is_shown();
+        self.text = None;
+        TextInputEvent {input, was_paste: false, replace_last}
+    }
+    
+    /// Removes the shown composition from the text again, if there is any
+    pub fn cancel(&mut self) -> Option<TextInputEvent> {
+        let replace_last = self.is_shown();
+        self.text = None;
+        if replace_last {
+            Some(TextInputEvent {input: String::new(), was_paste: false, replace_last})
+        }
+        else {
+            None
+        }
+    }
+    
+    /// Forgets the composition without touching the text
+    pub fn clear(&mut self) {
+        self.text = None;
+    }
+}
+
+/// The window states we read out of a `_NET_WM_STATE` property. A window counts as
+/// maximized when it is maximized in either direction, fullscreen is reported on its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NetWmState {
+    pub is_fullscreen: bool,
+    pub is_maximized: bool,
+    pub is_topmost: bool,
+}
+
+impl NetWmState {
+    pub fn from_items(items: &[x11_sys::Atom], fullscreen: x11_sys::Atom, maximized: [x11_sys::Atom; 2], above: x11_sys::Atom) -> Self {
+        Self {
+            is_fullscreen: items.contains(&fullscreen),
+            is_maximized: items.iter().any( | item | maximized.contains(item)),
+            is_topmost: items.contains(&above),
+        }
+    }
+}
+
+/// The flags of a XdndStatus answering with `response`. Bit 0 accepts the drop, bit 1 asks for
+/// position events even when the mouse doesnt leave a rectangle.
+pub fn dnd_status_flags(response: DragResponse) -> c_long {
+    if response != DragResponse::None {1 << 0 | 1 << 1} else {1 << 1}
+}
+
+/// The flags of a XdndFinished for a drop answered with `response`, bit 0 tells the source the
+/// drop was performed.
+pub fn dnd_finished_flags(response: DragResponse) -> c_long {
+    if response != DragResponse::None {1} else {0}
+}
+
+/// Formats drag items as a text/uri-list. Internal ids and empty paths are smuggled through
+/// the same way as on macos, so drags between our own windows keep them. File uris are always
+/// absolute, so relative paths are resolved against the current directory.
+pub fn dnd_items_to_uri_list(items: &[DragItem]) -> String {
+    let mut uri_list = String::new();
+    for item in items {
+        if let DragItem::FilePath {path, internal_id} = item {
+            let path = if path.len() == 0 {
+                "makepad_internal_empty".to_string()
+            }
+            else {
+                dnd_absolute_path(path)
+            };
+            uri_list.push_str("file://");
+            for byte in path.bytes() {
+                match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => uri_list.push(byte as char),
+                    _ => uri_list.push_str(&format!("%{:02X}", byte))
+                }
+            }
+            if let Some(internal_id) = internal_id {
+                uri_list.push_str(&format!("#makepad_internal_id={}", internal_id.0));
+            }
+            uri_list.push_str("\r\n");
+        }
+    }
+    uri_list
+}
+
+// canonicalize needs the file to exist, paths that dont are joined onto the current directory
+fn dnd_absolute_path(path: &str) -> String {
+    let absolute = std::fs::canonicalize(path).or_else( | _ | std::env::current_dir().map( | dir | dir.join(path)));
+    match absolute {
+        Ok(absolute) => absolute.to_string_lossy().to_string(),
+        Err(_) => path.to_string()
+    }
+}
+
+/// Parses a text/uri-list into file path drag items, skipping comments and non file uris.
+pub fn dnd_uri_list_to_items(uri_list: &str) -> Vec<DragItem> {
+    let mut items = Vec::new();
+    for line in uri_list.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let uri = if let Some(uri) = line.strip_prefix("file://") {uri} else {continue};
+        let uri = uri.strip_prefix("localhost").unwrap_or(uri);
+        let mut bits = uri.splitn(2, "#makepad_internal_id=");
+        let path = bits.next().unwrap();
+        let internal_id = bits.next().and_then( | id | id.parse::<u64>().ok()).map(LiveId);
+        
+        let mut bytes = Vec::new();
+        let mut iter = path.bytes();
+        while let Some(byte) = iter.next() {
+            if byte == b'%' {
+                let hex: Vec<u8> = iter.clone().take(2).collect();
+                if let Some(value) = std::str::from_utf8(&hex).ok().and_then( | hex | u8::from_str_radix(hex, 16).ok()) {
+                    bytes.push(value);
+                    iter.nth(1);
+                    continue;
+                }
+            }
+            bytes.push(byte);
+        }
+        let path = String::from_utf8_lossy(&bytes).to_string();
+        items.push(DragItem::FilePath {
+            path: if path == "makepad_internal_empty" {"".to_string()} else {path},
+            internal_id
+        });
+    }
+    items
 }
 
 pub struct DndAtoms {
+    pub action_copy: x11_sys::Atom,
+    pub action_link: x11_sys::Atom,
+    pub action_move: x11_sys::Atom,
     pub action_private: x11_sys::Atom,
     pub aware: x11_sys::Atom,
     pub drop: x11_sys::Atom,
     pub enter: x11_sys::Atom,
+    pub finished: x11_sys::Atom,
     pub leave: x11_sys::Atom,
     pub none: x11_sys::Atom,
     pub position: x11_sys::Atom,
@@ -766,10 +1490,14 @@ pub struct DndAtoms {
 impl DndAtoms {
     pub unsafe fn new(display: *mut x11_sys::Display) -> DndAtoms {
         DndAtoms {
+            action_copy: x11_sys::XInternAtom(display, "XdndActionCopy\0".as_ptr() as *const _, 0),
+            action_link: x11_sys::XInternAtom(display, "XdndActionLink\0".as_ptr() as *const _, 0),
+            action_move: x11_sys::XInternAtom(display, "XdndActionMove\0".as_ptr() as *const _, 0),
             action_private: x11_sys::XInternAtom(display, "XdndActionPrivate\0".as_ptr() as *const _, 0),
             aware: x11_sys::XInternAtom(display, "XdndAware\0".as_ptr() as *const _, 0),
             drop: x11_sys::XInternAtom(display, "XdndDrop\0".as_ptr() as *const _, 0),
             enter: x11_sys::XInternAtom(display, "XdndEnter\0".as_ptr() as *const _, 0),
+            finished: x11_sys::XInternAtom(display, "XdndFinished\0".as_ptr() as *const _, 0),
             leave: x11_sys::XInternAtom(display, "XdndLeave\0".as_ptr() as *const _, 0),
             none: x11_sys::XInternAtom(display, "None\0".as_ptr() as *const _, 0),
             position: x11_sys::XInternAtom(display, "XdndPosition\0".as_ptr() as *const _, 0),
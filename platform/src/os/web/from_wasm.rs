@@ -264,6 +264,8 @@ pub struct FromWasmDrawCall {
     pub live_uniforms: WasmDataF32,
     pub const_table: WasmDataF32,
     pub textures: [Option<usize>; DRAW_CALL_TEXTURE_SLOTS],
+    // src rgb, dst rgb, src alpha, dst alpha. Depth isn't used on the web yet
+    pub blend_factors: [u32; 4],
 }
 
 #[derive(FromWasm)]
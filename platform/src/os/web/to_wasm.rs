@@ -89,6 +89,7 @@ impl Into<WindowGeom> for WWindowInfo {
     fn into(self) -> WindowGeom {
         WindowGeom {
             is_fullscreen: self.is_fullscreen,
+            is_maximized: false,
             is_topmost: false,
             inner_size: DVec2 {x: self.inner_width, y: self.inner_height},
             dpi_factor: self.dpi_factor,
@@ -84,6 +84,7 @@ impl Cx {
                     self.os.window_geom = tw.window_info.into();
                     //self.default_inner_window_size = self.os.window_geom.inner_size;
                     
+                    self.exit_if_live_check_unsupported("web");
                     self.call_event_handler(&Event::Construct);
                     //self.platform.from_wasm(FromWasmCreateThread{thread_id:1});
                 },
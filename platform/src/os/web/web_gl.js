@@ -348,6 +348,7 @@ export class WasmWebGL extends WasmWebBrowser {
         let shader = this.draw_shaders[args.shader_id];
         
         gl.useProgram(shader.program);
+        gl.blendFuncSeparate(args.blend_factors[0], args.blend_factors[1], args.blend_factors[2], args.blend_factors[3]);
         
         let vao = this.vaos[args.vao_id];
         
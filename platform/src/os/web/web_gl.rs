@@ -3,6 +3,7 @@ use {
         makepad_error_log::*,
         makepad_shader_compiler::{
             generate_glsl,
+            BlendFactor,
         },
         makepad_wasm_bridge::*,
         makepad_math::*,
@@ -15,6 +16,7 @@ use {
         cx::Cx,
         draw_list::DrawListId,
         pass::{PassId, PassClearColor, PassClearDepth},
+        texture::{TextureFormat, TextureDimension},
     },
 };
 
@@ -82,6 +84,11 @@ impl Cx {
                     let cxtexture = &mut self.textures[texture_id];
                     if cxtexture.update_image {
                         cxtexture.update_image = false;
+                        // the webgl1 bridge only takes 2D rgba images
+                        if cxtexture.desc.dimension != TextureDimension::D2 || !matches!(cxtexture.desc.format, TextureFormat::Default | TextureFormat::ImageBGRA) {
+                            error!("Web textures don't support {:?} {:?} images", cxtexture.desc.dimension, cxtexture.desc.format);
+                            continue;
+                        }
                         self.os.from_wasm(FromWasmAllocTextureImage2D {
                             texture_id: texture_id.0,
                             width: cxtexture.desc.width.unwrap(),
@@ -170,7 +177,15 @@ impl Cx {
                     user_uniforms: WasmDataF32::new(draw_call.user_uniforms.as_slice()),
                     live_uniforms: WasmDataF32::new(&sh.mapping.live_uniforms_buf),
                     const_table: WasmDataF32::new(&sh.mapping.const_table.table),
-                    textures
+                    textures,
+                    blend_factors: sh.mapping.flags.blend_mode.blend_factors().map( | factor | match factor {
+                        // the WebGL enum values
+                        BlendFactor::Zero => 0,
+                        BlendFactor::One => 1,
+                        BlendFactor::OneMinusSrcColor => 0x0301,
+                        BlendFactor::OneMinusSrcAlpha => 0x0303,
+                        BlendFactor::DstColor => 0x0306,
+                    }),
                 });
             }
         }
@@ -305,6 +320,13 @@ impl Cx {
             &mut zbias,
             zbias_step
         );
+        
+        // the render targets live on the js side, which has no way to send pixels back yet
+        for color_texture in self.passes[pass_id].color_textures.clone() {
+            if self.textures[color_texture.texture_id].read_pixels.len() != 0 {
+                self.texture_pixels_ready(color_texture.texture_id, Err("Reading back textures isn't supported on the web".to_string()));
+            }
+        }
     }
     
     pub fn webgl_compile_shaders(&mut self) {
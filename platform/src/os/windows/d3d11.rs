@@ -1,5 +1,5 @@
 use crate::{
-    makepad_shader_compiler::generate_hlsl,
+    makepad_shader_compiler::{generate_hlsl, DrawShaderFlags, BlendFactor},
     makepad_math::*,
     makepad_error_log::*,
     os::{
@@ -14,8 +14,11 @@ use crate::{
     pass::{PassClearColor, PassClearDepth, PassId},
     window::WindowId,
     texture::{ 
+        CxTexture,
         TextureFormat,
+        TextureDimension,
         TextureDesc,
+        TexturePixels,
     },  
     windows::{
         core::{
@@ -42,10 +45,15 @@ use crate::{
                     D3D11_BIND_FLAG,
                     D3D11_BIND_SHADER_RESOURCE,
                     D3D11_TEXTURE2D_DESC,
+                    D3D11_TEXTURE3D_DESC,
+                    D3D11_SHADER_RESOURCE_VIEW_DESC,
+                    D3D11_SHADER_RESOURCE_VIEW_DESC_0,
+                    D3D11_TEXCUBE_SRV,
                     D3D11_BIND_RENDER_TARGET,
                     D3D11_BIND_DEPTH_STENCIL,
                     D3D11_DEPTH_STENCIL_DESC,
                     D3D11_DEPTH_WRITE_MASK_ALL,
+                    D3D11_DEPTH_WRITE_MASK_ZERO,
                     D3D11_COMPARISON_LESS_EQUAL,
                     D3D11_DEPTH_STENCILOP_DESC,
                     D3D11_STENCIL_OP_REPLACE,
@@ -56,8 +64,12 @@ use crate::{
                     D3D11_CLEAR_STENCIL,
                     D3D11_BLEND_DESC,
                     D3D11_RENDER_TARGET_BLEND_DESC,
+                    D3D11_BLEND,
+                    D3D11_BLEND_ZERO,
                     D3D11_BLEND_ONE,
+                    D3D11_BLEND_INV_SRC_COLOR,
                     D3D11_BLEND_INV_SRC_ALPHA,
+                    D3D11_BLEND_DEST_COLOR,
                     D3D11_BLEND_OP_ADD,
                     D3D11_COLOR_WRITE_ENABLE_ALL,
                     D3D11_RASTERIZER_DESC,
@@ -68,12 +80,16 @@ use crate::{
                     D3D11_INPUT_PER_INSTANCE_DATA,
                     D3D11_MAPPED_SUBRESOURCE,
                     D3D11_USAGE_DYNAMIC,
+                    D3D11_USAGE_STAGING,
                     D3D11_CPU_ACCESS_WRITE,
+                    D3D11_CPU_ACCESS_READ,
                     D3D11_MAP_WRITE_DISCARD,
+                    D3D11_MAP_READ,
                     ID3D11Device,
                     ID3D11DeviceContext,
                     ID3D11RenderTargetView,
                     ID3D11Texture2D,
+                    ID3D11Texture3D,
                     ID3D11ShaderResourceView,
                     ID3D11DepthStencilView,
                     ID3D11BlendState,
@@ -92,6 +108,7 @@ use crate::{
                     D3D11_PRIMITIVE_TOPOLOGY_TRIANGLELIST,
                     D3D_DRIVER_TYPE_UNKNOWN,
                     D3D_FEATURE_LEVEL_11_0,
+                    D3D_SRV_DIMENSION_TEXTURECUBE,
                 },
                 Dxgi::{
                     IDXGIFactory2,
@@ -113,6 +130,8 @@ use crate::{
                         DXGI_FORMAT_D32_FLOAT_S8X24_UINT,
                         DXGI_FORMAT_R32_UINT,
                         DXGI_FORMAT_R32_FLOAT,
+                        DXGI_FORMAT_R8_UNORM,
+                        DXGI_FORMAT_R16G16B16A16_FLOAT,
                         DXGI_FORMAT_R32G32_FLOAT,
                         DXGI_FORMAT_R32G32B32_FLOAT,
                     },
@@ -208,6 +227,9 @@ impl Cx {
                 }
                 
                 unsafe {
+                    let blend_factor = [0., 0., 0., 0.];
+                    d3d11_cx.context.OMSetBlendState(&shp.blend_state, Some(&blend_factor), 0xffffffff);
+                    d3d11_cx.context.OMSetDepthStencilState(&shp.depth_stencil_state, 0);
                     d3d11_cx.context.VSSetShader(&shp.vertex_shader, None);
                     d3d11_cx.context.PSSetShader(&shp.pixel_shader, None);
                     d3d11_cx.context.IASetPrimitiveTopology(D3D11_PRIMITIVE_TOPOLOGY_TRIANGLELIST);
@@ -255,15 +277,11 @@ impl Cx {
                     let cxtexture = &mut self.textures[texture_id];
                     
                     match cxtexture.desc.format { // we only allocate Image, Mapped and Shared textures.
-                        TextureFormat::Default | TextureFormat::ImageBGRA => {
+                        TextureFormat::Default | TextureFormat::ImageBGRA | TextureFormat::ImageR8
+                            | TextureFormat::ImageRf32 | TextureFormat::ImageRGBAf16 => {
                             if cxtexture.update_image {
                                 cxtexture.update_image = false;
-                                cxtexture.os.update_platform_texture_image_bgra(
-                                    d3d11_cx,
-                                    cxtexture.desc.width.unwrap() as u32,
-                                    cxtexture.desc.height.unwrap() as u32,
-                                    &cxtexture.image_u32,
-                                );
+                                cxtexture.update_platform_texture_image(d3d11_cx);
                             }
                         },
                         TextureFormat::SharedBGRA(_) => {
@@ -445,6 +463,7 @@ impl Cx {
             zbias_step,
             &d3d11_cx,
         );
+        self.read_pass_pixels(pass_id, d3d11_cx);
     }
     
     pub fn draw_pass_to_magic_texture(&mut self, pass_id: PassId,  d3d11_cx: &D3d11Cx) {
@@ -462,6 +481,69 @@ impl Cx {
             zbias_step,
             &d3d11_cx,
         );
+        self.read_pass_pixels(pass_id, d3d11_cx);
+    }
+    
+    // copies the color textures that have read_pixels requests into staging textures the cpu can map
+    fn read_pass_pixels(&mut self, pass_id: PassId, d3d11_cx: &D3d11Cx) {
+        for color_texture in self.passes[pass_id].color_textures.clone() {
+            let cxtexture = &self.textures[color_texture.texture_id];
+            if cxtexture.read_pixels.len() == 0 {
+                continue
+            }
+            let texture = if let Some(texture) = &cxtexture.os.texture {texture} else {
+                self.texture_pixels_ready(color_texture.texture_id, Err("Texture was never rendered to".to_string()));
+                continue
+            };
+            let width = cxtexture.os.width as usize;
+            let height = cxtexture.os.height as usize;
+            let is_float = matches!(cxtexture.desc.format, TextureFormat::RenderBGRAf16 | TextureFormat::RenderBGRAf32);
+            let texture_desc = D3D11_TEXTURE2D_DESC {
+                Width: width as u32,
+                Height: height as u32,
+                MipLevels: 1,
+                ArraySize: 1,
+                Format: if is_float {DXGI_FORMAT_R32G32B32A32_FLOAT} else {DXGI_FORMAT_R8G8B8A8_UNORM},
+                SampleDesc: DXGI_SAMPLE_DESC {
+                    Count: 1,
+                    Quality: 0
+                },
+                Usage: D3D11_USAGE_STAGING,
+                BindFlags: 0,
+                CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+                MiscFlags: 0,
+            };
+            let mut staging = None;
+            unsafe {d3d11_cx.device.CreateTexture2D(&texture_desc, None, Some(&mut staging)).unwrap()};
+            let staging: ID3D11Resource = staging.unwrap().cast().unwrap();
+            let source: ID3D11Resource = texture.cast().unwrap();
+            
+            let mut data = Vec::with_capacity(width * height);
+            let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+            let p_mapped : *mut _ = &mut mapped;
+            unsafe {
+                d3d11_cx.context.CopyResource(&staging, &source);
+                // Map waits for the copy, fine for the occasional screenshot
+                d3d11_cx.context.Map(&staging, 0, D3D11_MAP_READ, 0, Some(p_mapped)).unwrap();
+                for y in 0..height {
+                    let row = (mapped.pData as *const u8).add(y * mapped.RowPitch as usize);
+                    for x in 0..width {
+                        let rgba = if is_float {
+                            let p = (row as *const f32).add(x * 4);
+                            let q = | i: usize | (p.add(i).read().max(0.0).min(1.0) * 255.0 + 0.5) as u32;
+                            [q(0), q(1), q(2), q(3)]
+                        }
+                        else {
+                            let p = row.add(x * 4);
+                            [p.read() as u32, p.add(1).read() as u32, p.add(2).read() as u32, p.add(3).read() as u32]
+                        };
+                        data.push(rgba[3] << 24 | rgba[0] << 16 | rgba[1] << 8 | rgba[2]);
+                    }
+                }
+                d3d11_cx.context.Unmap(&staging, 0);
+            }
+            self.texture_pixels_ready(color_texture.texture_id, Ok(TexturePixels {width, height, data}));
+        }
     }
     
     pub (crate) fn hlsl_compile_shaders(&mut self, d3d11_cx: &D3d11Cx) {
@@ -480,7 +562,7 @@ impl Cx {
                 }
                 // lets see if we have the shader already
                 for (index, ds) in self.draw_shaders.os_shaders.iter().enumerate() {
-                    if ds.hlsl == hlsl {
+                    if ds.hlsl == hlsl && ds.flags.same_pipeline_state(&cx_shader.mapping.flags) {
                         cx_shader.os_shader_id = Some(index);
                         break;
                     }
@@ -743,6 +825,7 @@ pub struct CxOsTexture {
     height: u32,
     //slots_per_pixel: usize,
     texture: Option<ID3D11Texture2D >,
+    texture_3d: Option<ID3D11Texture3D >,
     shared_handle: HANDLE,
     shader_resource_view: Option<ID3D11ShaderResourceView >,
     render_target_view: Option<ID3D11RenderTargetView >,
@@ -877,52 +960,6 @@ impl CxOsTexture {
         return true
     }
     
-    pub fn update_platform_texture_image_bgra(
-        &mut self,
-        d3d11_cx: &D3d11Cx,
-        width: u32,
-        height: u32,
-        image_u32: &Vec<u32>
-    ) {
-        if image_u32.len() != (width * height) as usize {
-            println!("update_platform_texture_image_bgra with wrong buffer_u32 size!");
-            return;
-        }
-        
-        let sub_data = D3D11_SUBRESOURCE_DATA {
-            pSysMem: image_u32.as_ptr() as *const _,
-            SysMemPitch: (width * 4) as u32,
-            SysMemSlicePitch: 0
-        };
-        
-        let texture_desc = D3D11_TEXTURE2D_DESC {
-            Width: width as u32,
-            Height: height as u32,
-            MipLevels: 1,
-            ArraySize: 1,
-            Format: DXGI_FORMAT_R8G8B8A8_UNORM,
-            SampleDesc: DXGI_SAMPLE_DESC {
-                Count: 1,
-                Quality: 0
-            },
-            Usage: D3D11_USAGE_DEFAULT,
-            BindFlags: D3D11_BIND_SHADER_RESOURCE.0 as u32,
-            CPUAccessFlags: 0,
-            MiscFlags: 0,
-        };
-        
-        let mut texture = None;
-        unsafe {d3d11_cx.device.CreateTexture2D(&texture_desc, Some(&sub_data), Some(&mut texture)).unwrap()};
-        let resource: ID3D11Resource = texture.clone().unwrap().cast().unwrap();
-        let mut shader_resource_view = None;
-        unsafe {d3d11_cx.device.CreateShaderResourceView(&resource, None, Some(&mut shader_resource_view)).unwrap()};
-
-        self.width = width;
-        self.height = height;
-        self.texture = texture;
-        self.shader_resource_view = shader_resource_view;
-    }
-
     pub fn update_shared_texture(
         &mut self,
         d3d11_cx: &D3d11Cx,
@@ -998,24 +1035,116 @@ impl CxOsTexture {
     }
 }
 
+impl CxTexture {
+    fn update_platform_texture_image(&mut self, d3d11_cx: &D3d11Cx) {
+        if !self.image_is_complete() {
+            return;
+        }
+        let desc = self.desc;
+        let width = desc.width.unwrap() as u32;
+        let height = desc.height.unwrap() as u32;
+        let depth = desc.depth.unwrap_or(1) as u32;
+        let (format, texel_size) = match desc.format {
+            TextureFormat::ImageR8 => (DXGI_FORMAT_R8_UNORM, 1),
+            TextureFormat::ImageRf32 => (DXGI_FORMAT_R32_FLOAT, 4),
+            TextureFormat::ImageRGBAf16 => (DXGI_FORMAT_R16G16B16A16_FLOAT, 8),
+            _ => (DXGI_FORMAT_R8G8B8A8_UNORM, 4),
+        };
+        // GenerateMips needs the full chain allocated and the texture bindable as a render target
+        let mip_levels = if desc.mipmaps {
+            32 - width.max(height).max(if desc.dimension == TextureDimension::D3 {depth} else {1}).leading_zeros()
+        } else {
+            1
+        };
+        let (bind_flags, mip_flags) = if desc.mipmaps {
+            ((D3D11_BIND_SHADER_RESOURCE.0 | D3D11_BIND_RENDER_TARGET.0) as u32, 1) // D3D11_RESOURCE_MISC_GENERATE_MIPS
+        } else {
+            (D3D11_BIND_SHADER_RESOURCE.0 as u32, 0)
+        };
+        let row_pitch = width * texel_size;
+        let slice_pitch = row_pitch * height;
+        let data = self.image_bytes();
+        
+        let mut shader_resource_view = None;
+        match desc.dimension {
+            TextureDimension::D3 => {
+                let texture_desc = D3D11_TEXTURE3D_DESC {
+                    Width: width,
+                    Height: height,
+                    Depth: depth,
+                    MipLevels: mip_levels,
+                    Format: format,
+                    Usage: D3D11_USAGE_DEFAULT,
+                    BindFlags: bind_flags,
+                    CPUAccessFlags: 0,
+                    MiscFlags: mip_flags,
+                };
+                let mut texture = None;
+                unsafe {d3d11_cx.device.CreateTexture3D(&texture_desc, None, Some(&mut texture)).unwrap()};
+                let resource: ID3D11Resource = texture.clone().unwrap().cast().unwrap();
+                unsafe {
+                    d3d11_cx.context.UpdateSubresource(&resource, 0, None, data.as_ptr() as *const _, row_pitch, slice_pitch);
+                    d3d11_cx.device.CreateShaderResourceView(&resource, None, Some(&mut shader_resource_view)).unwrap();
+                }
+                self.os.texture = None;
+                self.os.texture_3d = texture;
+            }
+            TextureDimension::D2 | TextureDimension::Cube => {
+                let array_size = if desc.dimension == TextureDimension::Cube {6} else {1};
+                let texture_desc = D3D11_TEXTURE2D_DESC {
+                    Width: width,
+                    Height: height,
+                    MipLevels: mip_levels,
+                    ArraySize: array_size,
+                    Format: format,
+                    SampleDesc: DXGI_SAMPLE_DESC {
+                        Count: 1,
+                        Quality: 0
+                    },
+                    Usage: D3D11_USAGE_DEFAULT,
+                    BindFlags: bind_flags,
+                    CPUAccessFlags: 0,
+                    MiscFlags: mip_flags | if array_size == 6 {4} else {0}, // D3D11_RESOURCE_MISC_TEXTURECUBE
+                };
+                let mut texture = None;
+                unsafe {d3d11_cx.device.CreateTexture2D(&texture_desc, None, Some(&mut texture)).unwrap()};
+                let resource: ID3D11Resource = texture.clone().unwrap().cast().unwrap();
+                // faces are array slices, their first mip is subresource slice * mip_levels
+                for slice in 0..array_size {
+                    let face = &data[(slice * slice_pitch) as usize..];
+                    unsafe {d3d11_cx.context.UpdateSubresource(&resource, slice * mip_levels, None, face.as_ptr() as *const _, row_pitch, slice_pitch)};
+                }
+                // without a desc the 6 slices would be viewed as a 2D array instead of a cube
+                let cube_desc = D3D11_SHADER_RESOURCE_VIEW_DESC {
+                    Format: format,
+                    ViewDimension: D3D_SRV_DIMENSION_TEXTURECUBE,
+                    Anonymous: D3D11_SHADER_RESOURCE_VIEW_DESC_0 {
+                        TextureCube: D3D11_TEXCUBE_SRV {
+                            MostDetailedMip: 0,
+                            MipLevels: mip_levels,
+                        }
+                    }
+                };
+                let srv_desc = if array_size == 6 {Some(&cube_desc as *const _)} else {None};
+                unsafe {d3d11_cx.device.CreateShaderResourceView(&resource, srv_desc, Some(&mut shader_resource_view)).unwrap()};
+                self.os.texture = texture;
+                self.os.texture_3d = None;
+            }
+        }
+        if desc.mipmaps {
+            unsafe {d3d11_cx.context.GenerateMips(shader_resource_view.as_ref().unwrap())};
+        }
+        self.os.width = width;
+        self.os.height = height;
+        self.os.shader_resource_view = shader_resource_view;
+    }
+}
+
 impl CxOsPass {
     pub fn set_states(&mut self, d3d11_cx: &D3d11Cx,) {
         
         if self.blend_state.is_none() {
-            
-            let mut blend_desc: D3D11_BLEND_DESC = Default::default();
-            blend_desc.AlphaToCoverageEnable = FALSE;
-            blend_desc.RenderTarget[0] = D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: TRUE,
-                SrcBlend: D3D11_BLEND_ONE,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: D3D11_COLOR_WRITE_ENABLE_ALL.0 as u8,
-            };
-            unsafe {d3d11_cx.device.CreateBlendState(&blend_desc, Some(&mut self.blend_state)).unwrap()}
+            self.blend_state = Some(create_blend_state(d3d11_cx, &DrawShaderFlags::default()));
         }
         
         if self.raster_state.is_none() {
@@ -1035,27 +1164,7 @@ impl CxOsPass {
         }
         
         if self.depth_stencil_state.is_none() {
-            let ds_desc = D3D11_DEPTH_STENCIL_DESC {
-                DepthEnable: TRUE,
-                DepthWriteMask: D3D11_DEPTH_WRITE_MASK_ALL,
-                DepthFunc: D3D11_COMPARISON_LESS_EQUAL,
-                StencilEnable: FALSE,
-                StencilReadMask: 0xff,
-                StencilWriteMask: 0xff,
-                FrontFace: D3D11_DEPTH_STENCILOP_DESC {
-                    StencilFailOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilDepthFailOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilPassOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilFunc: D3D11_COMPARISON_ALWAYS,
-                },
-                BackFace: D3D11_DEPTH_STENCILOP_DESC {
-                    StencilFailOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilDepthFailOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilPassOp: D3D11_STENCIL_OP_REPLACE,
-                    StencilFunc: D3D11_COMPARISON_ALWAYS,
-                },
-            };
-            unsafe {d3d11_cx.device.CreateDepthStencilState(&ds_desc, Some(&mut self.depth_stencil_state)).unwrap()}
+            self.depth_stencil_state = Some(create_depth_stencil_state(d3d11_cx, &DrawShaderFlags::default()));
         }
         
         unsafe {
@@ -1067,6 +1176,61 @@ impl CxOsPass {
     }
 }
 
+fn create_blend_state(d3d11_cx: &D3d11Cx, flags: &DrawShaderFlags) -> ID3D11BlendState {
+    fn d3d11_factor(factor: BlendFactor) -> D3D11_BLEND {
+        match factor {
+            BlendFactor::Zero => D3D11_BLEND_ZERO,
+            BlendFactor::One => D3D11_BLEND_ONE,
+            BlendFactor::OneMinusSrcColor => D3D11_BLEND_INV_SRC_COLOR,
+            BlendFactor::OneMinusSrcAlpha => D3D11_BLEND_INV_SRC_ALPHA,
+            BlendFactor::DstColor => D3D11_BLEND_DEST_COLOR,
+        }
+    }
+    let [src_rgb, dst_rgb, src_alpha, dst_alpha] = flags.blend_mode.blend_factors();
+    let mut blend_desc: D3D11_BLEND_DESC = Default::default();
+    blend_desc.AlphaToCoverageEnable = FALSE;
+    blend_desc.RenderTarget[0] = D3D11_RENDER_TARGET_BLEND_DESC {
+        BlendEnable: TRUE,
+        SrcBlend: d3d11_factor(src_rgb),
+        SrcBlendAlpha: d3d11_factor(src_alpha),
+        DestBlend: d3d11_factor(dst_rgb),
+        DestBlendAlpha: d3d11_factor(dst_alpha),
+        BlendOp: D3D11_BLEND_OP_ADD,
+        BlendOpAlpha: D3D11_BLEND_OP_ADD,
+        RenderTargetWriteMask: D3D11_COLOR_WRITE_ENABLE_ALL.0 as u8,
+    };
+    let mut blend_state = None;
+    unsafe {d3d11_cx.device.CreateBlendState(&blend_desc, Some(&mut blend_state)).unwrap()}
+    blend_state.unwrap()
+}
+
+fn create_depth_stencil_state(d3d11_cx: &D3d11Cx, flags: &DrawShaderFlags) -> ID3D11DepthStencilState {
+    // with the test off depth is still enabled when writing, the compare just always passes
+    let ds_desc = D3D11_DEPTH_STENCIL_DESC {
+        DepthEnable: if flags.depth_test || flags.depth_write {TRUE} else {FALSE},
+        DepthWriteMask: if flags.depth_write {D3D11_DEPTH_WRITE_MASK_ALL} else {D3D11_DEPTH_WRITE_MASK_ZERO},
+        DepthFunc: if flags.depth_test {D3D11_COMPARISON_LESS_EQUAL} else {D3D11_COMPARISON_ALWAYS},
+        StencilEnable: FALSE,
+        StencilReadMask: 0xff,
+        StencilWriteMask: 0xff,
+        FrontFace: D3D11_DEPTH_STENCILOP_DESC {
+            StencilFailOp: D3D11_STENCIL_OP_REPLACE,
+            StencilDepthFailOp: D3D11_STENCIL_OP_REPLACE,
+            StencilPassOp: D3D11_STENCIL_OP_REPLACE,
+            StencilFunc: D3D11_COMPARISON_ALWAYS,
+        },
+        BackFace: D3D11_DEPTH_STENCILOP_DESC {
+            StencilFailOp: D3D11_STENCIL_OP_REPLACE,
+            StencilDepthFailOp: D3D11_STENCIL_OP_REPLACE,
+            StencilPassOp: D3D11_STENCIL_OP_REPLACE,
+            StencilFunc: D3D11_COMPARISON_ALWAYS,
+        },
+    };
+    let mut depth_stencil_state = None;
+    unsafe {d3d11_cx.device.CreateDepthStencilState(&ds_desc, Some(&mut depth_stencil_state)).unwrap()}
+    depth_stencil_state.unwrap()
+}
+
 #[derive(Default, Clone)]
 pub struct CxOsPass {
     pass_uniforms: D3d11Buffer,
@@ -1090,7 +1254,10 @@ pub struct CxOsDrawShader {
     pub vertex_shader: ID3D11VertexShader,
     pub pixel_shader_blob: ID3DBlob,
     pub vertex_shader_blob: ID3DBlob,
-    pub input_layout: ID3D11InputLayout
+    pub input_layout: ID3D11InputLayout,
+    pub blend_state: ID3D11BlendState,
+    pub depth_stencil_state: ID3D11DepthStencilState,
+    pub flags: DrawShaderFlags,
 }
 
 impl CxOsDrawShader {
@@ -1269,7 +1436,10 @@ impl CxOsDrawShader {
             vertex_shader: vs.unwrap(),
             pixel_shader_blob: ps_blob,
             vertex_shader_blob: vs_blob,
-            input_layout: input_layout.unwrap()
+            input_layout: input_layout.unwrap(),
+            blend_state: create_blend_state(d3d11_cx, &mapping.flags),
+            depth_stencil_state: create_depth_stencil_state(d3d11_cx, &mapping.flags),
+            flags: mapping.flags,
         })
     }
 }
@@ -25,6 +25,7 @@ use {
 impl Cx {
     
     pub fn event_loop(cx:Rc<RefCell<Cx>>) {
+        cx.borrow().exit_if_live_check_unsupported("windows");
         
         cx.borrow_mut().self_ref = Some(cx.clone());
         cx.borrow_mut().os_type = OsType::Windows;
@@ -75,9 +75,9 @@ impl Cx {
                 
                 match parsed {
                     Ok(msg) => match msg {
-                        HostToStdin::ReloadFile{file:_, contents:_}=>{
-                            // alright lets reload this file in our DSL system
-                            
+                        HostToStdin::ReloadFile{file, contents}=>{
+                            // picked up by was_live_edit on the next tick
+                            self.queue_live_file_change(file, contents);
                         }
                         HostToStdin::KeyDown(e) => {
                             self.call_event_handler(&Event::KeyDown(e));
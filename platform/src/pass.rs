@@ -197,6 +197,20 @@ impl Pass {
         cxpass.debug = debug;
     }
     
+    // repaints the pass and reads back its first color texture, see Texture::read_pixels
+    pub fn capture(&self, cx: &mut Cx, request_id: LiveId) {
+        let cxpass = &mut cx.passes[self.pass_id()];
+        if let Some(color_texture) = cxpass.color_textures.get(0) {
+            let texture_id = color_texture.texture_id;
+            cxpass.paint_dirty = true;
+            cx.textures[texture_id].read_pixels.push(request_id);
+        }
+        else {
+            let error = format!("Pass {} has no color texture to capture, render it into a texture first", cxpass.debug_name);
+            cx.push_texture_pixels(request_id, Err(error));
+        }
+    }
+    
 }
 
 #[derive(Clone)]
@@ -64,13 +64,15 @@ impl std::ops::IndexMut<TextureId> for CxTexturePool {
 pub enum TextureFormat {
     Default,
     ImageBGRA,
+    ImageR8,
+    ImageRf32,
+    ImageRGBAf16,
     Depth32Stencil8,
     RenderBGRA,
     RenderBGRAf16,
     RenderBGRAf32,
     SharedBGRA(u64),
     //    ImageBGRAf32,
-    //    ImageRf32,
     //    ImageRGf32,
     //    MappedBGRA,
     //    MappedBGRAf32,
@@ -84,12 +86,38 @@ impl TextureFormat{
              _=>false
          }
     }
+    
+    // the bytes per texel of the formats you upload images for
+    pub fn image_texel_size(&self) -> Option<usize> {
+        match self {
+            Self::Default | Self::ImageBGRA => Some(4),
+            Self::ImageR8 => Some(1),
+            Self::ImageRf32 => Some(4),
+            Self::ImageRGBAf16 => Some(8),
+            _ => None
+        }
+    }
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextureDimension {
+    #[default]
+    D2,
+    // depth slices of width*height, sampled with sample3d
+    D3,
+    // 6 faces of width*height in the order +x, -x, +y, -y, +z, -z, sampled with sample_cube
+    Cube,
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub struct TextureDesc {
     pub format: TextureFormat,
     pub width: Option<usize>,
     pub height: Option<usize>,
+    pub depth: Option<usize>,
+    pub dimension: TextureDimension,
+    // generates the mip chain on upload so minified sampling is trilinear
+    pub mipmaps: bool,
 }
 
 impl Default for TextureDesc {
@@ -98,6 +126,20 @@ impl Default for TextureDesc {
             format: TextureFormat::Default,
             width: None,
             height: None,
+            depth: None,
+            dimension: TextureDimension::D2,
+            mipmaps: false,
+        }
+    }
+}
+
+impl TextureDesc {
+    pub fn image_texels(&self) -> usize {
+        let texels = self.width.unwrap_or(0) * self.height.unwrap_or(0);
+        match self.dimension {
+            TextureDimension::D2 => texels,
+            TextureDimension::D3 => texels * self.depth.unwrap_or(1),
+            TextureDimension::Cube => texels * 6,
         }
     }
 }
@@ -162,6 +204,80 @@ impl Texture {
         std::mem::swap(&mut cxtexture.image_u32, image_u32);
         cxtexture.update_image = true;
     }
+    
+    pub fn swap_image_u8(&self, cx: &mut Cx, image_u8: &mut Vec<u8>) {
+        let cxtexture = &mut cx.textures[self.texture_id()];
+        std::mem::swap(&mut cxtexture.image_u8, image_u8);
+        cxtexture.update_image = true;
+    }
+    
+    pub fn swap_image_f32(&self, cx: &mut Cx, image_f32: &mut Vec<f32>) {
+        let cxtexture = &mut cx.textures[self.texture_id()];
+        std::mem::swap(&mut cxtexture.image_f32, image_f32);
+        cxtexture.update_image = true;
+    }
+    
+    // ImageRGBAf16 texels as the raw bits of 4 IEEE half floats
+    pub fn swap_image_f16(&self, cx: &mut Cx, image_f16: &mut Vec<u16>) {
+        let cxtexture = &mut cx.textures[self.texture_id()];
+        std::mem::swap(&mut cxtexture.image_f16, image_f16);
+        cxtexture.update_image = true;
+    }
+    
+    // the pixels arrive as an Event::TexturePixels carrying request_id. Render targets are read
+    // back after the pass that renders into them paints again, which this marks dirty like
+    // Pass::capture does. BGRA images come from their image right away and any other format
+    // gets an error back
+    pub fn read_pixels(&self, cx: &mut Cx, request_id: LiveId) {
+        let texture_id = self.texture_id();
+        let cxtexture = &cx.textures[texture_id];
+        let pixels = match cxtexture.desc.format {
+            TextureFormat::ImageBGRA => cxtexture.image_pixels(),
+            TextureFormat::Default if !cxtexture.image_u32.is_empty() => cxtexture.image_pixels(),
+            TextureFormat::Default | TextureFormat::RenderBGRA | TextureFormat::RenderBGRAf16 | TextureFormat::RenderBGRAf32 => {
+                let mut is_rendered = false;
+                for pass_id in cx.passes.id_iter() {
+                    let cxpass = &mut cx.passes[pass_id];
+                    if cxpass.color_textures.iter().any( | ct | ct.texture_id == texture_id) {
+                        cxpass.paint_dirty = true;
+                        is_rendered = true;
+                    }
+                }
+                if is_rendered {
+                    cx.textures[texture_id].read_pixels.push(request_id);
+                    return
+                }
+                Err("Texture is not rendered into by any pass".to_string())
+            }
+            format => Err(format!("Can't read back the pixels of a {:?} texture", format))
+        };
+        cx.push_texture_pixels(request_id, pixels);
+    }
+}
+
+// a render target read back to the cpu, top row first
+#[derive(Clone, Debug, Default)]
+pub struct TexturePixels {
+    pub width: usize,
+    pub height: usize,
+    // same packing as swap_image_u32, 0xAARRGGBB
+    pub data: Vec<u32>,
+}
+
+impl TexturePixels {
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.data.len() * 4);
+        for c in &self.data {
+            out.extend_from_slice(&[(c >> 16) as u8, (c >> 8) as u8, *c as u8, (c >> 24) as u8]);
+        }
+        out
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TexturePixelsEvent {
+    pub request_id: LiveId,
+    pub pixels: Result<TexturePixels, String>,
 }
 
 
@@ -169,7 +285,57 @@ impl Texture {
 pub struct CxTexture {
     pub (crate) desc: TextureDesc,
     pub (crate) image_u32: Vec<u32>,
-    //pub(crate) _image_f32: Vec<f32>,
+    pub (crate) image_u8: Vec<u8>,
+    pub (crate) image_f32: Vec<f32>,
+    pub (crate) image_f16: Vec<u16>,
     pub (crate) update_image: bool,
+    pub (crate) read_pixels: Vec<LiveId>,
     pub os: CxOsTexture
 }
+
+impl CxTexture {
+    // the image of whichever vec the format uploads from, as bytes
+    pub (crate) fn image_bytes(&self) -> &[u8] {
+        unsafe fn as_bytes<T>(data: &[T]) -> &[u8] {
+            std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+        }
+        unsafe {
+            match self.desc.format {
+                TextureFormat::ImageR8 => &self.image_u8,
+                TextureFormat::ImageRf32 => as_bytes(&self.image_f32),
+                TextureFormat::ImageRGBAf16 => as_bytes(&self.image_f16),
+                _ => as_bytes(&self.image_u32),
+            }
+        }
+    }
+    
+    // checks the image holds every texel of the desc, logging when it doesn't
+    pub (crate) fn image_is_complete(&self) -> bool {
+        let bytes = self.image_bytes().len();
+        let texel_size = if let Some(texel_size) = self.desc.format.image_texel_size() {texel_size} else {
+            error!("Texture format {:?} can't be uploaded as an image", self.desc.format);
+            return false
+        };
+        let expected = self.desc.image_texels() * texel_size;
+        if bytes == 0 || bytes != expected {
+            if bytes != 0 {
+                error!("Texture image has {} bytes but its {:?} desc needs {}", bytes, self.desc.dimension, expected);
+            }
+            return false
+        }
+        true
+    }
+    
+    // a BGRA image texture as read_pixels hands it out
+    pub (crate) fn image_pixels(&self) -> Result<TexturePixels, String> {
+        let desc = &self.desc;
+        if desc.dimension != TextureDimension::D2 {
+            return Err(format!("Can't read back the pixels of a {:?} texture", desc.dimension))
+        }
+        let (width, height) = (desc.width.unwrap_or(0), desc.height.unwrap_or(0));
+        if width * height == 0 || self.image_u32.len() != width * height {
+            return Err(format!("Texture image has {} texels but its desc is {}x{}", self.image_u32.len(), width, height))
+        }
+        Ok(TexturePixels {width, height, data: self.image_u32.clone()})
+    }
+}
@@ -162,6 +162,10 @@ impl Window {
         cx.windows[self.window_id()].window_geom.is_fullscreen
     }
     
+    pub fn is_maximized(&mut self, cx: &mut Cx) -> bool {
+        cx.windows[self.window_id()].window_geom.is_maximized
+    }
+    
     pub fn xr_is_presenting(&mut self, cx: &mut Cx) -> bool {
         cx.windows[self.window_id()].window_geom.xr_is_presenting
     }
@@ -0,0 +1,330 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+// Golden images live in tests/golden, run with MAKEPAD_UPDATE_GOLDEN=1 to rewrite them
+
+use {
+    std::{rc::Rc, cell::RefCell},
+    makepad_platform::{
+        *,
+        os::{
+            cx_stdin::{HostToStdin, StdinWindowSize},
+            linux::{headless::HeadlessCx, soft_render::SoftFramebuffer},
+        },
+    },
+};
+
+live_design!{
+    TestQuadGeometry = {{TestQuadGeometry}} {}
+
+    DrawTestQuad = {{DrawTestQuad}} {
+        fn vertex(self) -> vec4 {
+            let p = self.geom_pos * self.rect_size + self.rect_pos;
+            return self.camera_projection * (self.camera_view * (self.view_transform * vec4(
+                p.x,
+                p.y,
+                self.draw_depth + self.draw_zbias,
+                1.
+            )))
+        }
+
+        fn pixel(self) -> vec4 {
+            let c = mix(self.color, #fff, self.geom_pos.y * 0.5);
+            return vec4(c.rgb * c.a, c.a)
+        }
+    }
+
+    App = {{App}} {
+        quad: {color: #f80}
+    }
+
+    CaptureApp = {{CaptureApp}} {
+        quad: {color: #f00}
+    }
+}
+
+#[derive(Live)]
+struct TestQuadGeometry {
+    #[rust] geometry_ref: Option<GeometryRef>,
+}
+
+impl LiveHook for TestQuadGeometry {
+    fn after_apply(&mut self, cx: &mut Cx, _apply_from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        let geometry_ref = cx.get_geometry_ref(GeometryFingerprint::new(LiveType::of::<Self>()));
+        geometry_ref.0.update(cx, vec![0, 1, 2, 2, 3, 0], vec![0., 0., 1., 0., 1., 1., 0., 1.]);
+        self.geometry_ref = Some(geometry_ref);
+    }
+}
+
+impl GeometryFields for TestQuadGeometry {
+    fn geometry_fields(&self, fields: &mut Vec<GeometryField>) {
+        fields.push(GeometryField {id: live_id!(geom_pos), ty: ShaderTy::Vec2});
+    }
+
+    fn get_geometry_id(&self) -> Option<GeometryId> {
+        self.geometry_ref.as_ref().map( | gr | gr.0.geometry_id())
+    }
+
+    fn live_type_check(&self) -> LiveType {
+        LiveType::of::<Self>()
+    }
+}
+
+#[derive(Live)]
+#[repr(C)]
+struct DrawTestQuad {
+    #[live] geometry: TestQuadGeometry,
+    #[deref] draw_vars: DrawVars,
+    #[live] color: Vec4,
+    #[calc] rect_pos: Vec2,
+    #[calc] rect_size: Vec2,
+    #[live(1.0)] draw_depth: f32,
+}
+
+impl LiveHook for DrawTestQuad {
+    fn before_apply(&mut self, cx: &mut Cx, apply_from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        self.draw_vars.before_apply_init_shader(cx, apply_from, index, nodes, &self.geometry);
+    }
+    fn after_apply(&mut self, cx: &mut Cx, apply_from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        self.draw_vars.after_apply_update_self(cx, apply_from, index, nodes, &self.geometry);
+    }
+}
+
+// a minimal app that draws a quad into its window, and moves it on every click
+#[derive(Live)]
+struct App {
+    #[live] quad: DrawTestQuad,
+    #[rust] window: Option<Window>,
+    #[rust] pass: Option<Pass>,
+    #[rust] draw_list: Option<DrawList>,
+    #[rust] clicks: usize,
+}
+
+impl LiveHook for App {}
+
+impl AppMain for App {
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event) {
+        match event {
+            Event::Construct => {
+                let window = Window::new(cx);
+                let pass = Pass::new(cx);
+                window.set_pass(cx, &pass);
+                pass.set_window_clear_color(cx, vec4(0.1, 0.2, 0.3, 1.0));
+                self.draw_list = Some(cx.draw_lists.alloc());
+                self.window = Some(window);
+                self.pass = Some(pass);
+            }
+            Event::MouseDown(_) => {
+                self.clicks += 1;
+                cx.redraw_all();
+            }
+            Event::Draw(_) => {
+                let window_id = self.window.as_ref().unwrap().window_id();
+                let pass_id = self.pass.as_ref().unwrap().pass_id();
+                let draw_list_id = self.draw_list.as_ref().unwrap().id();
+                let size = cx.windows[window_id].get_inner_size();
+                self.pass.as_ref().unwrap().set_size(cx, size);
+                cx.passes[pass_id].dpi_factor = Some(1.0);
+                cx.passes[pass_id].main_draw_list_id = Some(draw_list_id);
+                let redraw_id = cx.redraw_id;
+                cx.draw_lists[draw_list_id].clear_draw_items(redraw_id);
+
+                self.quad.rect_pos = vec2(4.0 + 8.0 * self.clicks as f32, 4.0);
+                self.quad.rect_size = vec2(12.0, 16.0);
+                let sh = &cx.draw_shaders[self.quad.draw_vars.draw_shader.unwrap().draw_shader_id];
+                let draw_item = cx.draw_lists[draw_list_id].append_draw_call(redraw_id, sh, &self.quad.draw_vars);
+                draw_item.instances.as_mut().unwrap().extend_from_slice(self.quad.draw_vars.as_slice());
+                cx.repaint_pass(pass_id);
+            }
+            _ => ()
+        }
+    }
+}
+
+// draws a quad into an offscreen texture, and asks for pixels back from it and from a few
+// textures that can or can't be read
+#[derive(Live)]
+struct CaptureApp {
+    #[live] quad: DrawTestQuad,
+    #[rust] pass: Option<Pass>,
+    #[rust] draw_list: Option<DrawList>,
+    #[rust] textures: Vec<Texture>,
+}
+
+impl LiveHook for CaptureApp {}
+
+impl AppMain for CaptureApp {
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event) {
+        match event {
+            Event::Construct => {
+                let color_texture = Texture::new(cx);
+                let pass = Pass::new(cx);
+                pass.add_color_texture(cx, &color_texture, PassClearColor::ClearWith(vec4(0.0, 0.0, 0.0, 1.0)));
+                pass.capture(cx, live_id!(capture));
+                color_texture.read_pixels(cx, live_id!(render_target));
+                Pass::new(cx).capture(cx, live_id!(no_color_texture));
+                let unused = Texture::new(cx);
+                unused.read_pixels(cx, live_id!(no_pass));
+
+                let image = Texture::new(cx);
+                image.set_desc(cx, TextureDesc {
+                    format: TextureFormat::ImageBGRA,
+                    width: Some(2),
+                    height: Some(1),
+                    ..Default::default()
+                });
+                image.swap_image_u32(cx, &mut vec![0xff112233, 0x80445566]);
+                image.read_pixels(cx, live_id!(image));
+
+                let single_channel = Texture::new(cx);
+                single_channel.set_desc(cx, TextureDesc {
+                    format: TextureFormat::ImageR8,
+                    width: Some(1),
+                    height: Some(1),
+                    ..Default::default()
+                });
+                single_channel.read_pixels(cx, live_id!(single_channel));
+
+                self.draw_list = Some(cx.draw_lists.alloc());
+                self.pass = Some(pass);
+                self.textures = vec![color_texture, image, single_channel, unused];
+            }
+            Event::Draw(_) => {
+                let pass_id = self.pass.as_ref().unwrap().pass_id();
+                let draw_list_id = self.draw_list.as_ref().unwrap().id();
+                self.pass.as_ref().unwrap().set_size(cx, dvec2(8.0, 8.0));
+                cx.passes[pass_id].dpi_factor = Some(1.0);
+                cx.passes[pass_id].main_draw_list_id = Some(draw_list_id);
+                let redraw_id = cx.redraw_id;
+                cx.draw_lists[draw_list_id].clear_draw_items(redraw_id);
+
+                self.quad.rect_pos = vec2(2.0, 0.0);
+                self.quad.rect_size = vec2(4.0, 2.0);
+                let sh = &cx.draw_shaders[self.quad.draw_vars.draw_shader.unwrap().draw_shader_id];
+                let draw_item = cx.draw_lists[draw_list_id].append_draw_call(redraw_id, sh, &self.quad.draw_vars);
+                draw_item.instances.as_mut().unwrap().extend_from_slice(self.quad.draw_vars.as_slice());
+                cx.repaint_pass(pass_id);
+            }
+            _ => ()
+        }
+    }
+}
+
+fn golden(name: &str) -> String {
+    format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[test]
+fn scripted_clicks_match_golden_images() {
+    let mut headless = HeadlessCx::new_app::<App>(live_design, StdinWindowSize {
+        width: 32.0,
+        height: 24.0,
+        dpi_factor: 1.0
+    });
+    assert!(headless.settle(10));
+    let framebuffer = headless.main_framebuffer().unwrap();
+    assert_eq!((framebuffer.width, framebuffer.height), (32, 24));
+    framebuffer.assert_golden(golden("headless_quad.png"));
+
+    headless.send_script(r#"
+        {"MouseDown":[{"button":0,"x":8.0,"y":8.0,"time":0.1}]}
+        {"MouseUp":[{"button":0,"x":8.0,"y":8.0,"time":0.2}]}
+    "#).unwrap();
+    assert!(headless.settle(10));
+    headless.main_framebuffer().unwrap().assert_golden(golden("headless_quad_clicked.png"));
+
+    // the golden image round trips through png without loss
+    let framebuffer = headless.main_framebuffer().unwrap();
+    let decoded = SoftFramebuffer::decode_png(&framebuffer.encode_png()).unwrap();
+    assert!(framebuffer.diff(&decoded, 0).is_none());
+    let before = SoftFramebuffer::decode_png(&std::fs::read(golden("headless_quad.png")).unwrap()).unwrap();
+    assert!(framebuffer.diff(&before, 1).unwrap().different_pixels > 0);
+}
+
+#[test]
+fn reload_file_reapplies_live_design() {
+    let mut headless = HeadlessCx::new_app::<App>(live_design, StdinWindowSize {
+        width: 32.0,
+        height: 24.0,
+        dpi_factor: 1.0
+    });
+    assert!(headless.settle(10));
+    let quad_pixel = | headless: &HeadlessCx | {
+        let framebuffer = headless.main_framebuffer().unwrap();
+        framebuffer.color[5 * framebuffer.width + 5]
+    };
+    let before = quad_pixel(&headless);
+    assert!((before >> 16) & 0xff > before & 0xff);
+
+    // the host sends paths relative to its own root, only the trailing components have to match
+    let source = std::fs::read_to_string(format!("{}/tests/linux_headless.rs", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    headless.send(HostToStdin::ReloadFile {
+        file: "tests/linux_headless.rs".to_string(),
+        contents: source.replace("quad: {color: #f80}", "quad: {color: #08f}")
+    });
+    assert!(headless.settle(10));
+    let after = quad_pixel(&headless);
+    assert!((after >> 16) & 0xff < after & 0xff);
+}
+
+#[test]
+fn capture_delivers_texture_pixels() {
+    // new_app keeps the app to itself, this handler also records every TexturePixels event
+    let received = Rc::new(RefCell::new(Vec::<TexturePixelsEvent>::new()));
+    let app = Rc::new(RefCell::new(None));
+    let mut cx = Cx::new(Box::new({
+        let received = received.clone();
+        move | cx, event | {
+            if let Event::Construct = event {
+                *app.borrow_mut() = Some(CaptureApp::new_main(cx));
+            }
+            if let Event::TexturePixels(events) = event {
+                received.borrow_mut().extend(events.iter().cloned());
+            }
+            if let Some(app) = app.borrow_mut().as_mut() {
+                <dyn AppMain>::handle_event(app, cx, event);
+            }
+        }
+    }));
+    live_design(&mut cx);
+    let mut headless = HeadlessCx::new(cx, StdinWindowSize {
+        width: 32.0,
+        height: 24.0,
+        dpi_factor: 1.0
+    });
+    assert!(headless.settle(10));
+
+    let received = received.borrow();
+    let pixels = | request_id: LiveId | {
+        let mut found = received.iter().filter( | event | event.request_id == request_id);
+        let event = found.next().unwrap_or_else( || panic!("no pixels for {}", request_id));
+        assert!(found.next().is_none(), "pixels for {} arrived twice", request_id);
+        event.pixels.clone()
+    };
+
+    // image textures resolve right away with their own pixels
+    let image = pixels(live_id!(image)).unwrap();
+    assert_eq!((image.width, image.height), (2, 1));
+    assert_eq!(image.data, vec![0xff112233, 0x80445566]);
+    assert!(pixels(live_id!(single_channel)).is_err());
+    assert!(pixels(live_id!(no_color_texture)).is_err());
+    assert!(pixels(live_id!(no_pass)).is_err());
+
+    // the render target arrives after its pass is drawn, top row first
+    let capture = pixels(live_id!(capture)).unwrap();
+    assert_eq!((capture.width, capture.height), (8, 8));
+    assert_eq!(capture.data.len(), 64);
+    let at = | x: usize, y: usize | capture.data[y * capture.width + x];
+    assert_eq!(at(0, 0), 0xff000000);
+    assert_eq!(at(3, 7), 0xff000000);
+    assert_eq!(at(7, 1), 0xff000000);
+    for (x, y) in [(2, 0), (5, 0), (3, 1)] {
+        let pixel = at(x, y);
+        assert_eq!(pixel >> 24, 0xff);
+        assert_eq!((pixel >> 16) & 0xff, 0xff);
+        assert!(pixel & 0xff < 0xff);
+    }
+    // the quad fades to white downwards
+    assert!(at(3, 1) & 0xff > at(3, 0) & 0xff);
+    // reading the texture itself waits for the same paint of its pass
+    assert_eq!(pixels(live_id!(render_target)).unwrap().data, capture.data);
+}
@@ -0,0 +1,80 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use {
+    std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc::channel,
+    },
+    makepad_platform::{
+        os::linux::linux_http::LinuxHttpRequest,
+        HttpRequest,
+        HttpMethod,
+        NetworkResponse,
+        LiveId,
+    }
+};
+
+// serves a fixed set of responses on loopback, one connection per request
+fn start_loopback_server(connections: usize) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().take(connections) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok() && line != "\r\n" {
+                line.clear();
+            }
+            let path = request_line.split(' ').nth(1).unwrap().to_string();
+            let response = match path.as_str() {
+                "/redirect" => "HTTP/1.1 302 Found\r\nLocation: /chunked\r\nContent-Length: 0\r\n\r\n".to_string(),
+                "/chunked" => "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nX-Test: a:b\r\n\r\n5\r\nhello\r\n6;ext=1\r\n world\r\n0\r\n\r\n".to_string(),
+                "/length" => "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\ndata".to_string(),
+                _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string(),
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    port
+}
+
+#[test]
+fn chunked_response_after_redirect() {
+    let port = start_loopback_server(2);
+    let (sender, receiver) = channel();
+    let request = HttpRequest::new(format!("http://127.0.0.1:{}/redirect", port), HttpMethod::GET);
+    let response = LinuxHttpRequest::new(LiveId(1), request).run(&sender).unwrap();
+    assert_eq!(response.status_code, 200);
+    assert_eq!(response.get_string_body().unwrap(), "hello world");
+    assert_eq!(response.headers.get("X-Test").unwrap(), &vec!["a:b".to_string()]);
+    let progress: Vec<_> = receiver.try_iter().collect();
+    assert!(matches!(progress.last().unwrap().response, NetworkResponse::HttpProgress {loaded: 11, total: 0}));
+}
+
+#[test]
+fn content_length_response() {
+    let port = start_loopback_server(1);
+    let (sender, _receiver) = channel();
+    let request = HttpRequest::new(format!("http://127.0.0.1:{}/length", port), HttpMethod::GET);
+    let response = LinuxHttpRequest::new(LiveId(1), request).run(&sender).unwrap();
+    assert_eq!(response.get_string_body().unwrap(), "data");
+}
+
+#[test]
+fn unsupported_scheme_is_an_error() {
+    let (sender, _receiver) = channel();
+    let request = HttpRequest::new("ftp://127.0.0.1/".to_string(), HttpMethod::GET);
+    assert!(LinuxHttpRequest::new(LiveId(1), request).run(&sender).is_err());
+}
+
+#[test]
+fn https_is_reported_as_unsupported() {
+    let (sender, _receiver) = channel();
+    let request = HttpRequest::new("https://api.openai.com/v1/chat/completions".to_string(), HttpMethod::POST);
+    let err = LinuxHttpRequest::new(LiveId(1), request).run(&sender).unwrap_err();
+    assert!(err.starts_with("https and wss are not supported"), "{}", err);
+}
@@ -0,0 +1,67 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use makepad_platform::*;
+
+mod theme {
+    use makepad_platform::*;
+
+    live_design!{
+        Theme = {{Theme}} {
+            size: 10.0
+            label: "base"
+        }
+    }
+
+    #[derive(Live, LiveHook)]
+    pub struct Theme {
+        #[live] pub size: f64,
+        #[live] pub label: String,
+    }
+}
+
+use theme::Theme;
+
+#[test]
+fn load_live_file_from_disk() {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    theme::live_design(&mut cx);
+    cx.live_expand();
+
+    let path = std::env::temp_dir().join(format!("makepad_skin_{}.live", std::process::id()));
+    let path = path.to_str().unwrap();
+    std::fs::write(path, r#"
+        import linux_live_file::theme::*;
+        Big = <Theme> {size: (10.0 * 2), label: "big"}
+    "#).unwrap();
+
+    let module_id = cx.load_live_file(path, "skins::big").unwrap();
+    let big = Theme::new_from_module(&mut cx, module_id, live_id!(Big)).unwrap();
+    assert_eq!(big.size, 20.0);
+    assert_eq!(big.label, "big");
+
+    // loading it again replaces the document in place
+    std::fs::write(path, r#"
+        import linux_live_file::theme::*;
+        Big = <Theme> {size: 30.0}
+    "#).unwrap();
+    assert_eq!(cx.load_live_file(path, "skins::big").unwrap(), module_id);
+    let big = Theme::new_from_module(&mut cx, module_id, live_id!(Big)).unwrap();
+    assert_eq!(big.size, 30.0);
+    assert_eq!(big.label, "base");
+
+    // a broken reload keeps the previous version around
+    std::fs::write(path, "Big = <Theme> {size: }").unwrap();
+    assert!(cx.load_live_file(path, "skins::big").is_err());
+    assert_eq!(Theme::new_from_module(&mut cx, module_id, live_id!(Big)).unwrap().size, 30.0);
+    std::fs::remove_file(path).unwrap();
+
+    // imports only resolve against modules that are already registered
+    let errors = cx.load_live_file_from_str("skins/missing.live", "skins::missing", r#"
+        import linux_live_file::nowhere::*;
+        Other = <Theme> {}
+    "#.to_string()).unwrap_err();
+    assert!(errors.iter().any( | err | err.message.contains("Import statement nothing found")));
+
+    let errors = cx.load_live_file_from_str("skins/other.live", "skins::big", "Other = {}".to_string()).unwrap_err();
+    assert!(errors[0].message.contains("already registered"));
+}
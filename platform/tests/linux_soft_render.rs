@@ -0,0 +1,167 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use makepad_platform::*;
+
+live_design!{
+    TestQuadGeometry = {{TestQuadGeometry}} {}
+
+    DrawTestQuad = {{DrawTestQuad}} {
+        fn vertex(self) -> vec4 {
+            let p = self.geom_pos * self.rect_size + self.rect_pos;
+            return self.camera_projection * (self.camera_view * (self.view_transform * vec4(
+                p.x,
+                p.y,
+                self.draw_depth + self.draw_zbias,
+                1.
+            )))
+        }
+
+        fn pixel(self) -> vec4 {
+            return vec4(self.color.rgb * self.color.a, self.color.a)
+        }
+    }
+
+    DrawFailingQuad = {{DrawFailingQuad}} {
+        // an opaque color makes this divide by zero
+        fn pixel(self) -> vec4 {
+            let zero = int(self.color.a - 1.0);
+            return vec4(float(int(self.color.a) / zero))
+        }
+    }
+}
+
+#[derive(Live)]
+struct TestQuadGeometry {
+    #[rust] geometry_ref: Option<GeometryRef>,
+}
+
+impl LiveHook for TestQuadGeometry {
+    fn after_apply(&mut self, cx: &mut Cx, _apply_from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        let geometry_ref = cx.get_geometry_ref(GeometryFingerprint::new(LiveType::of::<Self>()));
+        geometry_ref.0.update(cx, vec![0, 1, 2, 2, 3, 0], vec![0., 0., 1., 0., 1., 1., 0., 1.]);
+        self.geometry_ref = Some(geometry_ref);
+    }
+}
+
+impl GeometryFields for TestQuadGeometry {
+    fn geometry_fields(&self, fields: &mut Vec<GeometryField>) {
+        fields.push(GeometryField {id: live_id!(geom_pos), ty: ShaderTy::Vec2});
+    }
+
+    fn get_geometry_id(&self) -> Option<GeometryId> {
+        self.geometry_ref.as_ref().map( | gr | gr.0.geometry_id())
+    }
+
+    fn live_type_check(&self) -> LiveType {
+        LiveType::of::<Self>()
+    }
+}
+
+#[derive(Live)]
+#[repr(C)]
+struct DrawTestQuad {
+    #[live] geometry: TestQuadGeometry,
+    #[deref] draw_vars: DrawVars,
+    #[live] color: Vec4,
+    #[calc] rect_pos: Vec2,
+    #[calc] rect_size: Vec2,
+    #[live(1.0)] draw_depth: f32,
+}
+
+impl LiveHook for DrawTestQuad {
+    fn before_apply(&mut self, cx: &mut Cx, apply_from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        self.draw_vars.before_apply_init_shader(cx, apply_from, index, nodes, &self.geometry);
+    }
+    fn after_apply(&mut self, cx: &mut Cx, apply_from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        self.draw_vars.after_apply_update_self(cx, apply_from, index, nodes, &self.geometry);
+    }
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+struct DrawFailingQuad {
+    #[deref] draw_super: DrawTestQuad,
+}
+
+fn offscreen_pass(cx: &mut Cx, texture: &Texture) -> (Pass, DrawListId) {
+    let pass = Pass::new(cx);
+    pass.set_size(cx, dvec2(16., 16.));
+    pass.add_color_texture(cx, texture, PassClearColor::ClearWith(vec4(0., 0., 0., 1.)));
+    let draw_list_id = cx.draw_lists.alloc().id();
+    let pass_id = pass.pass_id();
+    cx.passes[pass_id].main_draw_list_id = Some(draw_list_id);
+    cx.passes[pass_id].dpi_factor = Some(1.0);
+    (pass, draw_list_id)
+}
+
+fn push_quad(cx: &mut Cx, draw_list_id: DrawListId, quad: &mut DrawTestQuad, pos: Vec2, size: Vec2, color: Vec4) {
+    quad.rect_pos = pos;
+    quad.rect_size = size;
+    quad.color = color;
+    let draw_shader_id = quad.draw_vars.draw_shader.unwrap().draw_shader_id;
+    let sh = &cx.draw_shaders[draw_shader_id];
+    let draw_item = cx.draw_lists[draw_list_id].append_draw_call(1, sh, &quad.draw_vars);
+    draw_item.instances.as_mut().unwrap().extend_from_slice(quad.draw_vars.as_slice());
+}
+
+#[test]
+fn render_quads_to_texture() {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    live_design(&mut cx);
+    cx.live_expand();
+    let mut quad = DrawTestQuad::new_main(&mut cx);
+    assert!(quad.draw_vars.draw_shader.is_some());
+    cx.soft_compile_shaders();
+
+    let texture = Texture::new(&mut cx);
+    let (pass, draw_list_id) = offscreen_pass(&mut cx, &texture);
+    let pass_id = pass.pass_id();
+
+    push_quad(&mut cx, draw_list_id, &mut quad, vec2(4., 4.), vec2(8., 8.), vec4(1., 0., 0., 1.));
+    push_quad(&mut cx, draw_list_id, &mut quad, vec2(8., 8.), vec2(8., 8.), vec4(0., 0., 1., 0.5));
+    cx.soft_draw_pass_to_texture(pass_id);
+
+    let mut image = Vec::new();
+    texture.swap_image_u32(&mut cx, &mut image);
+    assert_eq!(image.len(), 16 * 16);
+    let pixel = | x: usize, y: usize | image[y * 16 + x];
+
+    assert_eq!(pixel(0, 0), 0xff000000);
+    assert_eq!(pixel(4, 4), 0xffff0000);
+    assert_eq!(pixel(7, 11), 0xffff0000);
+    assert_eq!(pixel(3, 4), 0xff000000);
+    assert_eq!(pixel(12, 4), 0xff000000);
+    // half transparent blue blended over red and over the clear color
+    assert_eq!(pixel(9, 9), 0xff800080);
+    assert_eq!(pixel(14, 14), 0xff000080);
+}
+
+#[test]
+fn failing_shaders_are_reported_once_per_draw_call() {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    live_design(&mut cx);
+    cx.live_expand();
+    let mut quad = DrawTestQuad::new_main(&mut cx);
+    let mut failing = DrawFailingQuad::new_main(&mut cx);
+    assert!(failing.draw_super.draw_vars.draw_shader.is_some());
+    cx.soft_compile_shaders();
+    cx.collect_live_diagnostics();
+
+    let texture = Texture::new(&mut cx);
+    let (pass, draw_list_id) = offscreen_pass(&mut cx, &texture);
+    push_quad(&mut cx, draw_list_id, &mut failing.draw_super, vec2(0., 0.), vec2(8., 8.), vec4(1., 1., 1., 1.));
+    push_quad(&mut cx, draw_list_id, &mut quad, vec2(8., 8.), vec2(8., 8.), vec4(1., 0., 0., 1.));
+    cx.soft_draw_pass_to_texture(pass.pass_id());
+
+    let diagnostics = cx.take_live_diagnostics();
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].stage, LiveDiagnosticStage::Shader);
+    assert!(diagnostics[0].file.ends_with("linux_soft_render.rs"), "{}", diagnostics[0].file);
+    assert_eq!(diagnostics[0].message, "DrawFailingQuad fn pixel failed: integer division by zero");
+
+    // the failing draw call draws nothing, the ones after it still do
+    let mut image = Vec::new();
+    texture.swap_image_u32(&mut cx, &mut image);
+    assert_eq!(image[2 * 16 + 2], 0xff000000);
+    assert_eq!(image[12 * 16 + 12], 0xffff0000);
+}
@@ -0,0 +1,33 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use makepad_platform::os::linux::stdin_shared_memory::StdinSharedMemory;
+
+#[test]
+fn frames_pass_from_child_to_host() {
+    let path = std::env::temp_dir().join(format!("makepad_stdin_shared_memory_{}", std::process::id()));
+    let path = path.to_str().unwrap();
+    let host = StdinSharedMemory::create(path, 4, 2).unwrap();
+    let mut child = StdinSharedMemory::open(path, 4, 2).unwrap();
+    assert!(StdinSharedMemory::open(path, 8, 8).is_err());
+
+    let mut image = Vec::new();
+    assert!(!host.read_frame(&mut image));
+
+    // the child alternates between two frames, the host always sees the last completed one
+    for frame in 1..4u32 {
+        child.write_frame( | pixels | {
+            let pixels = unsafe {std::slice::from_raw_parts_mut(pixels as *mut u32, 8)};
+            for (i, pixel) in pixels.iter_mut().enumerate() {
+                *pixel = frame << 16 | i as u32;
+            }
+        });
+        assert!(host.read_frame(&mut image));
+        assert_eq!(image.len(), 8);
+        assert_eq!(image[0], frame << 16);
+        assert_eq!(image[7], frame << 16 | 7);
+    }
+
+    drop(child);
+    drop(host);
+    assert!(!std::path::Path::new(path).exists());
+}
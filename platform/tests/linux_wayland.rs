@@ -0,0 +1,62 @@
+#![cfg(all(target_os = "linux", not(target_os = "android"), not(linux_direct)))]
+
+// Runs against whatever compositor WAYLAND_DISPLAY points at and is skipped without one, for instance
+// weston --backend=headless-backend.so --socket=makepad-test
+// WAYLAND_DISPLAY=makepad-test cargo test -p makepad-platform --test linux_wayland
+
+use makepad_platform::{
+    os::{
+        cx_native::EventFlow,
+        linux::wayland::{
+            wayland_app::*,
+            wayland_sys::wl_display_roundtrip,
+            wayland_window::WaylandWindow,
+        },
+    },
+    makepad_math::dvec2,
+    Cx,
+    Window,
+    LiveNew,
+};
+
+#[test]
+fn window_configure_and_state_changes() {
+    if std::env::var("WAYLAND_DISPLAY").is_err() {
+        println!("WAYLAND_DISPLAY not set, skipping");
+        return
+    }
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    let window_id = Window::new(&mut cx).window_id();
+
+    assert!(init_wayland_app_global(Box::new( | _, _ | EventFlow::Wait)));
+    let app = get_wayland_app_global();
+
+    let mut window = Box::new(WaylandWindow::new(window_id));
+    window.init("makepad wayland test", dvec2(320., 240.));
+    assert!(window.is_configured);
+    assert!(window.last_window_geom.dpi_factor >= 1.0);
+    assert!(app.window_map.contains_key(&window.surface));
+
+    window.fullscreen();
+    for _ in 0..10 {
+        if window.is_fullscreen {
+            break;
+        }
+        unsafe {wl_display_roundtrip(app.display);}
+    }
+    assert!(window.is_fullscreen);
+    assert!(window.get_window_geom().is_fullscreen);
+
+    window.normalize();
+    for _ in 0..10 {
+        if !window.is_fullscreen {
+            break;
+        }
+        unsafe {wl_display_roundtrip(app.display);}
+    }
+    assert!(!window.is_fullscreen);
+
+    window.close_window();
+    assert!(app.window_map.is_empty());
+    app.terminate_event_loop();
+}
@@ -0,0 +1,72 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use {
+    std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::channel,
+        time::Duration,
+    },
+    makepad_http::websocket::{WebSocket, WebSocketMessage, PING_MESSAGE},
+    makepad_platform::{
+        os::linux::linux_websocket::LinuxWebSocket,
+        HttpRequest,
+        HttpMethod,
+        NetworkResponse,
+        LiveId,
+    }
+};
+
+#[test]
+fn fragmented_messages_ping_and_echo() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut key = None;
+        let mut line = String::new();
+        while reader.read_line(&mut line).is_ok() && line != "\r\n" {
+            if let Some(v) = line.strip_prefix("Sec-WebSocket-Key: ") {
+                key = Some(v.trim().to_string());
+            }
+            line.clear();
+        }
+        stream.write_all(WebSocket::create_upgrade_response(&key.unwrap()).as_bytes()).unwrap();
+        // a text message split over three frames with a ping in between
+        stream.write_all(&[1, 3]).unwrap();
+        stream.write_all(b"hel").unwrap();
+        stream.write_all(&PING_MESSAGE).unwrap();
+        stream.write_all(&[0, 2]).unwrap();
+        stream.write_all(b"lo").unwrap();
+        stream.write_all(&[128, 0]).unwrap();
+        // expect the pong and then the echo from the client
+        let mut web_socket = WebSocket::new();
+        let mut got = Vec::new();
+        let mut data = [0u8; 1024];
+        while got.len() < 2 {
+            let n = stream.read(&mut data).unwrap();
+            web_socket.parse(&data[0..n], | result | match result {
+                Ok(WebSocketMessage::Pong(_)) => got.push("pong".to_string()),
+                Ok(WebSocketMessage::Text(text)) => got.push(text.to_string()),
+                _ => ()
+            });
+        }
+        stream.write_all(&[128 | 8, 0]).unwrap();
+        got
+    });
+
+    let (sender, receiver) = channel();
+    let request = HttpRequest::new(format!("ws://127.0.0.1:{}/", port), HttpMethod::GET);
+    let web_socket = LinuxWebSocket::open(LiveId(1), request, sender);
+
+    let next = || receiver.recv_timeout(Duration::from_secs(5)).unwrap().response;
+    assert!(matches!(next(), NetworkResponse::WebSocketOpen));
+    match next() {
+        NetworkResponse::WebSocketString(s) => assert_eq!(s, "hello"),
+        other => panic!("unexpected {:?}", other)
+    }
+    web_socket.send_string("echo".to_string());
+    assert!(matches!(next(), NetworkResponse::WebSocketClose));
+    assert_eq!(server.join().unwrap(), vec!["pong".to_string(), "echo".to_string()]);
+}
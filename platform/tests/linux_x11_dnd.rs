@@ -0,0 +1,78 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use makepad_platform::{
+    *,
+    os::linux::x11::xlib_window::{
+        dnd_items_to_uri_list, dnd_uri_list_to_items, dnd_status_flags, dnd_finished_flags, DndSource, DndRelease
+    },
+};
+
+#[test]
+fn uri_list_round_trips_drag_items() {
+    let items = vec![
+        DragItem::FilePath {path: "/home/user/my file.rs".to_string(), internal_id: None},
+        DragItem::FilePath {path: "".to_string(), internal_id: Some(LiveId(42))},
+        DragItem::FilePath {path: "/src/ünïcode#1.rs".to_string(), internal_id: Some(LiveId(7))},
+    ];
+    let uri_list = dnd_items_to_uri_list(&items);
+    assert!(uri_list.starts_with("file:///home/user/my%20file.rs\r\n"));
+    assert_eq!(dnd_uri_list_to_items(&uri_list), items);
+}
+
+#[test]
+fn uri_list_makes_relative_paths_absolute() {
+    let uri_list = dnd_items_to_uri_list(&[
+        DragItem::FilePath {path: "Cargo.toml".to_string(), internal_id: None},
+        DragItem::FilePath {path: "not/there.rs".to_string(), internal_id: None},
+    ]);
+    let dir = std::env::current_dir().unwrap();
+    assert_eq!(dnd_uri_list_to_items(&uri_list), vec![
+        DragItem::FilePath {path: dir.join("Cargo.toml").canonicalize().unwrap().to_string_lossy().to_string(), internal_id: None},
+        DragItem::FilePath {path: dir.join("not/there.rs").to_string_lossy().to_string(), internal_id: None},
+    ]);
+}
+
+#[test]
+fn uri_list_from_file_manager() {
+    // comments, hosts and other schemes as file managers send them
+    let items = dnd_uri_list_to_items("# dragged from nautilus\r\nfile:///tmp/a%2Bb.txt\r\nfile://localhost/tmp/c.txt\r\nhttps://makepad.dev\r\n");
+    assert_eq!(items, vec![
+        DragItem::FilePath {path: "/tmp/a+b.txt".to_string(), internal_id: None},
+        DragItem::FilePath {path: "/tmp/c.txt".to_string(), internal_id: None},
+    ]);
+}
+
+#[test]
+fn status_and_finished_follow_the_response() {
+    // a None response rejects the drag, and a drop answered with it is finished as not accepted
+    assert_eq!(dnd_status_flags(DragResponse::None), 0b10);
+    assert_eq!(dnd_status_flags(DragResponse::Copy), 0b11);
+    assert_eq!(dnd_finished_flags(DragResponse::None), 0);
+    assert_eq!(dnd_finished_flags(DragResponse::Move), 1);
+}
+
+#[test]
+fn source_only_drops_on_an_accepting_target() {
+    let items = [DragItem::FilePath {path: "/tmp/a.txt".to_string(), internal_id: None}];
+    let mut source = DndSource::new(1, &items);
+    assert_eq!(source.release(), DndRelease::Nothing);
+    
+    source.target = Some(2);
+    source.waiting_for_status = true;
+    source.pending_position = Some((10, 20, 0));
+    // a status from a window we are not over is ignored
+    assert_eq!(source.handle_status(3, 0b11), None);
+    assert!(!source.accepted);
+    // the target rejects, the held back position goes out next
+    assert_eq!(source.handle_status(2, 0b10), Some((10, 20, 0)));
+    assert!(!source.waiting_for_status);
+    assert_eq!(source.release(), DndRelease::Leave(2));
+    assert!(!source.dropped);
+    
+    // the target accepts, the release drops and we wait for its XdndFinished
+    source.handle_status(2, 0b11);
+    assert_eq!(source.release(), DndRelease::Drop(2));
+    assert!(source.dropped);
+    assert!(!source.is_finished_by(3));
+    assert!(source.is_finished_by(2));
+}
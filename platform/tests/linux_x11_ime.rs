@@ -0,0 +1,55 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use makepad_platform::{
+    *,
+    os::linux::x11::xlib_window::XimPreedit,
+};
+
+fn assert_input(event: Option<TextInputEvent>, input: &str, replace_last: bool) {
+    let event = event.expect("expected a text input event");
+    assert_eq!(event.input, input);
+    assert_eq!(event.replace_last, replace_last);
+    assert!(!event.was_paste);
+}
+
+#[test]
+fn preedit_is_replaced_by_the_commit() {
+    let mut preedit = XimPreedit::default();
+    preedit.start();
+    // an empty first draw shows nothing yet
+    assert!(preedit.draw(0, 0, "").is_none());
+    assert_input(preedit.draw(0, 0, "n"), "n", false);
+    assert_input(preedit.draw(0, 1, "に"), "に", true);
+    assert_input(preedit.draw(1, 0, "ほ"), "にほ", true);
+    assert_eq!(preedit.text(), Some("にほ"));
+    assert_input(Some(preedit.commit("日本".to_string())), "日本", true);
+    assert_eq!(preedit.text(), None);
+    // plain typing afterwards inserts rather than replaces
+    assert_input(Some(preedit.commit("!".to_string())), "!", false);
+}
+
+#[test]
+fn preedit_draws_clamp_to_the_composition() {
+    let mut preedit = XimPreedit::default();
+    preedit.start();
+    assert_input(preedit.draw(0, 0, "abc"), "abc", false);
+    assert_input(preedit.draw(1, 100, "X"), "aX", true);
+    assert_input(preedit.draw(-3, 1, ""), "X", true);
+    assert_input(preedit.draw(50, 0, "yz"), "Xyz", true);
+}
+
+#[test]
+fn cancelling_removes_a_shown_preedit() {
+    let mut preedit = XimPreedit::default();
+    assert!(preedit.cancel().is_none());
+    preedit.start();
+    assert_input(preedit.draw(0, 0, "ㅎ"), "ㅎ", false);
+    assert_input(preedit.cancel(), "", true);
+    assert!(preedit.cancel().is_none());
+
+    // clearing forgets the composition without an event, so the next commit inserts
+    preedit.start();
+    assert_input(preedit.draw(0, 0, "ㅎ"), "ㅎ", false);
+    preedit.clear();
+    assert_input(Some(preedit.commit("한".to_string())), "한", false);
+}
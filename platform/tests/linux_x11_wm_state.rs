@@ -0,0 +1,42 @@
+#![cfg(all(target_os = "linux", not(target_os = "android")))]
+
+use {
+    std::os::raw::c_ulong,
+    makepad_platform::os::linux::x11::xlib_window::NetWmState,
+};
+
+const FULLSCREEN: c_ulong = 10;
+const MAXIMIZED_HORZ: c_ulong = 11;
+const MAXIMIZED_VERT: c_ulong = 12;
+const ABOVE: c_ulong = 13;
+const HIDDEN: c_ulong = 14;
+
+fn state(items: &[c_ulong]) -> NetWmState {
+    NetWmState::from_items(items, FULLSCREEN, [MAXIMIZED_HORZ, MAXIMIZED_VERT], ABOVE)
+}
+
+#[test]
+fn maximized_is_not_reported_as_fullscreen() {
+    assert_eq!(state(&[MAXIMIZED_HORZ, MAXIMIZED_VERT]), NetWmState {
+        is_fullscreen: false,
+        is_maximized: true,
+        is_topmost: false,
+    });
+    // some window managers only maximize in one direction
+    assert!(state(&[HIDDEN, MAXIMIZED_VERT]).is_maximized);
+}
+
+#[test]
+fn fullscreen_and_topmost_are_reported_on_their_own() {
+    assert_eq!(state(&[FULLSCREEN, ABOVE]), NetWmState {
+        is_fullscreen: true,
+        is_maximized: false,
+        is_topmost: true,
+    });
+    assert_eq!(state(&[FULLSCREEN, MAXIMIZED_HORZ, MAXIMIZED_VERT]), NetWmState {
+        is_fullscreen: true,
+        is_maximized: true,
+        is_topmost: false,
+    });
+    assert_eq!(state(&[HIDDEN]), NetWmState::default());
+}
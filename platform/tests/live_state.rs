@@ -0,0 +1,75 @@
+use makepad_platform::*;
+
+#[derive(Live, LiveHook, LiveRead)]
+struct Base {
+    #[live] depth: f32,
+}
+
+#[derive(Live, LiveHook, LiveRead, PartialEq, Debug)]
+#[live_ignore]
+enum Mode {
+    #[pick] Off,
+    #[live(1.0)] Scaled(f64),
+}
+
+#[derive(Live, LiveHook, LiveRead)]
+struct Panel {
+    #[deref] base: Base,
+    #[live] size: Vec2,
+    #[live] title: String,
+    #[live] mode: Mode,
+    #[live] open: bool,
+    #[live] tabs: Vec<LiveId>,
+    #[live] ratio: Option<f64>,
+    #[live] template: Option<LivePtr>,
+    #[rust] clicks: usize,
+}
+
+#[test]
+fn live_read_state_survives_a_restart() {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    let mut panel = Panel::new(&mut cx);
+    panel.base.depth = 2.5;
+    panel.size = vec2(320.0, 240.5);
+    panel.title = "Inspector".to_string();
+    panel.mode = Mode::Scaled(1.5);
+    panel.open = true;
+    panel.tabs = vec![live_id!(files), live_id!(search)];
+    panel.ratio = Some(0.25);
+    panel.clicks = 3;
+
+    let nodes = panel.live_read();
+    // the deref base is read as if its fields were ours, the rust field and the None aren't read
+    assert_eq!(nodes.child_value_by_path(0, &[live_id!(depth).as_field()]), Some(&LiveValue::Float32(2.5)));
+    assert!(nodes.child_by_name(0, live_id!(clicks).as_field()).is_none());
+    assert!(nodes.child_by_name(0, live_id!(template).as_field()).is_none());
+
+    let file_name = std::env::temp_dir().join("makepad_live_state_test.cbor");
+    let file_name = file_name.to_str().unwrap();
+    save_live_state(file_name, &nodes).unwrap();
+    let loaded = load_live_state(file_name).unwrap();
+    std::fs::remove_file(file_name).unwrap();
+
+    let mut restored = Panel::new(&mut cx);
+    restored.apply_over(&mut cx, &loaded);
+    assert_eq!(restored.base.depth, 2.5);
+    assert_eq!(restored.size, vec2(320.0, 240.5));
+    assert_eq!(restored.title, "Inspector");
+    assert_eq!(restored.mode, Mode::Scaled(1.5));
+    assert!(restored.open);
+    assert_eq!(restored.tabs, vec![live_id!(files), live_id!(search)]);
+    assert_eq!(restored.ratio, Some(0.25));
+    assert_eq!(restored.clicks, 0);
+
+    // children of a widget tree are instance props, they keep that through cbor
+    let mut nodes = Vec::new();
+    nodes.open_object(LiveId(0));
+    nodes.push(LiveNode {origin: LiveNodeOrigin::instance(), id: live_id!(sidebar), value: LiveValue::Object});
+    nodes.push(LiveNode::from_id_value(live_id!(open), LiveValue::Bool(false)));
+    nodes.close();
+    nodes.close();
+    let mut loaded = Vec::new();
+    loaded.from_cbor(&nodes.to_cbor(0).unwrap()).unwrap();
+    let index = loaded.child_by_name(0, live_id!(sidebar).as_instance()).unwrap();
+    assert_eq!(loaded.child_value_by_path(index, &[live_id!(open).as_field()]), Some(&LiveValue::Bool(false)));
+}
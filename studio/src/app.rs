@@ -1,6 +1,7 @@
 use crate::{
     makepad_code_editor::code_editor::*,
     makepad_platform::*,
+    makepad_platform::os::cx_stdin::HostToStdin,
     makepad_draw::*,
     makepad_widgets::*,
     makepad_widgets::file_tree::*,
@@ -184,7 +185,7 @@ impl AppMain for App {
                     );
                 }
                 else if let Some(mut run_view) = run_view.has_widget(&next).borrow_mut() {
-                    run_view.draw(cx, &self.build_manager);
+                    run_view.draw(cx, &mut self.build_manager);
                 }
                 else if let Some(mut list_view) = log_list.has_widget(&next).borrow_mut() {
                     self.build_manager.draw_log(cx, &mut *list_view);
@@ -223,7 +224,11 @@ impl AppMain for App {
                     self.build_manager.start_recompile_timer(cx);
                     run_view.recompile_started(cx);
                 }
-                FileSystemAction::LiveReloadNeeded=>{
+                FileSystemAction::LiveReloadNeeded(live_file_change)=>{
+                    self.build_manager.send_host_to_stdin(None, HostToStdin::ReloadFile{
+                        file: live_file_change.file_name,
+                        contents: live_file_change.content
+                    });
                 }
             }
         }
@@ -34,6 +34,9 @@ use {
     },
 };
 
+#[cfg(target_os = "linux")]
+use crate::makepad_platform::os::linux::stdin_shared_memory::StdinSharedMemory;
+
 live_design!{
     import makepad_draw::shader::std::*;
     import makepad_widgets::theme_desktop_dark::*;
@@ -161,7 +164,10 @@ live_design!{
 
 pub struct BuildClientProcess {
     pub cmd_id: BuildCmdId,
-    pub texture: Texture
+    pub texture: Texture,
+    // the child copies its frames in here, there is no shared gpu texture on linux yet
+    #[cfg(target_os = "linux")]
+    pub shared_memory: Option<StdinSharedMemory>,
 }
 
 pub struct BuildClientWrap {
@@ -255,7 +261,9 @@ impl BuildManager {
         
         client.processes.insert(WHAT_TO_BUILD.into(), BuildClientProcess {
             texture,
-            cmd_id: BuildCmdId(0)
+            cmd_id: BuildCmdId(0),
+            #[cfg(target_os = "linux")]
+            shared_memory: None,
         });
         
         self.clients.push(client);
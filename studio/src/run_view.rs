@@ -8,6 +8,12 @@ use crate::{
     }
 };
 
+#[cfg(target_os = "linux")]
+use crate::{
+    makepad_platform::os::linux::stdin_shared_memory::StdinSharedMemory,
+    build_manager::build_manager::BuildClientProcess,
+};
+
 live_design!{
     import makepad_draw::shader::std::*;
     
@@ -161,6 +167,15 @@ impl RunView {
                 self.redraw(cx);
             }
             StdinToHost::DrawComplete => {
+                #[cfg(target_os = "linux")]
+                if let Some(process) = _manager.get_process(_cmd_id) {
+                    if let Some(shared_memory) = &process.shared_memory {
+                        let mut image = Vec::new();
+                        if shared_memory.read_frame(&mut image) {
+                            process.texture.swap_image_u32(cx, &mut image);
+                        }
+                    }
+                }
                 self.redraw(cx);
             }
         }
@@ -170,7 +185,7 @@ impl RunView {
         self.draw_app.redraw(cx);
     }
     
-    pub fn draw(&mut self, cx: &mut Cx2d, manager: &BuildManager) {
+    pub fn draw(&mut self, cx: &mut Cx2d, manager: &mut BuildManager) {
         
         // alright so here we draw em texturezs
         // pick a texture off the buildstate
@@ -178,24 +193,35 @@ impl RunView {
         let walk = if let Some(walk) = self.draw_state.get() {walk}else {panic!()};
         let rect = cx.walk_turtle(walk).dpi_snap(dpi_factor);
         // lets pixelsnap rect in position and size
-        for client in &manager.clients {
-            for process in client.processes.values() {
+        let mut resized = None;
+        for client in &mut manager.clients {
+            for process in client.processes.values_mut() {
                 
                 let new_size = ((rect.size.x * dpi_factor) as usize, (rect.size.y * dpi_factor) as usize);
                 if new_size != self.last_size {
                     self.last_size = new_size;
-
+                    let (width, height) = (new_size.0.max(1), new_size.1.max(1));
+                    
+                    // on linux the frames come in through shared memory and get uploaded as an image
+                    #[cfg(target_os = "linux")]
+                    let format = TextureFormat::ImageBGRA;
+                    #[cfg(not(target_os = "linux"))]
+                    let format = TextureFormat::SharedBGRA(0);
+                    
                     process.texture.set_desc(cx, TextureDesc {
-                        format: TextureFormat::SharedBGRA(0),
-                        width: Some(new_size.0.max(1)),
-                        height: Some(new_size.1.max(1)),
+                        format,
+                        width: Some(width),
+                        height: Some(height),
+                        mipmaps: true,
+                        ..Default::default()
                     });
-
-                    manager.send_host_to_stdin(Some(process.cmd_id), HostToStdin::WindowSize(StdinWindowSize {
-                        width: rect.size.x,
-                        height: rect.size.y,
-                        dpi_factor: dpi_factor,
-                    }));
+                    
+                    #[cfg(target_os = "linux")]
+                    let shared_memory = Self::create_shared_memory(process, width, height);
+                    #[cfg(not(target_os = "linux"))]
+                    let shared_memory = None;
+                    
+                    resized = Some((process.cmd_id, shared_memory));
                 }
                 
                 self.draw_app.set_texture(0, &process.texture);
@@ -203,8 +229,34 @@ impl RunView {
                 break
             }
         }
+        if let Some((cmd_id, shared_memory)) = resized {
+            manager.send_host_to_stdin(Some(cmd_id), HostToStdin::WindowSize(StdinWindowSize {
+                width: rect.size.x,
+                height: rect.size.y,
+                dpi_factor: dpi_factor,
+            }));
+            if let Some(shared_memory) = shared_memory {
+                manager.send_host_to_stdin(Some(cmd_id), shared_memory);
+            }
+        }
         self.draw_app.draw_abs(cx, rect);
     }
+    
+    #[cfg(target_os = "linux")]
+    fn create_shared_memory(process: &mut BuildClientProcess, width: usize, height: usize) -> Option<HostToStdin> {
+        let path = format!("/dev/shm/makepad_studio_{}_{}_{}x{}", std::process::id(), process.cmd_id.0, width, height);
+        match StdinSharedMemory::create(&path, width, height) {
+            Ok(shared_memory) => {
+                process.shared_memory = Some(shared_memory);
+                Some(HostToStdin::SharedMemoryFramebuffer {path, width, height})
+            }
+            Err(err) => {
+                error!("Cant create shared framebuffer {} {:?}", path, err);
+                process.shared_memory = None;
+                None
+            }
+        }
+    }
 }
 
 impl Widget for RunView {
@@ -0,0 +1,93 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use makepad_live_compiler::{LiveDiagnostic, makepad_micro_serde::*};
+
+// Builds and starts a makepad application with --live-check. Instead of opening a window the
+// app expands its live_design documents, constructs and draws itself headlessly and writes every
+// parse, expand, apply and shader error it ran into to stdout. This collects and reports them
+// so CI can fail on typos in live_design blocks. Headless running is only supported on Linux
+
+fn show_help(err: &str) {
+    if !err.is_empty() {
+        println!("{}", err);
+    }
+    println!("Makepad live_design checker");
+    println!();
+    println!("Usage cargo run -p makepad-live-check -- [options] <cargo run args>");
+    println!();
+    println!("    Example: cargo run -p makepad-live-check -- -p makepad-example-ironfish --release");
+    println!();
+    println!("    [options]:");
+    println!();
+    println!("       --deny-warnings                           Warnings also make the check fail");
+    println!("       --json                                    Print the diagnostics as json, one per line");
+    println!();
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut deny_warnings = false;
+    let mut json = false;
+    let mut cargo_args = Vec::new();
+    for arg in args {
+        match arg.as_ref() {
+            "--deny-warnings" => deny_warnings = true,
+            "--json" => json = true,
+            "--help" | "-h" => return show_help(""),
+            _ => cargo_args.push(arg)
+        }
+    }
+    if cargo_args.is_empty() {
+        return show_help("no package to check given");
+    }
+
+    let mut child = match Command::new("cargo")
+        .arg("run")
+        .args(&cargo_args)
+        .arg("--")
+        .arg("--live-check")
+        .stdout(Stdio::piped())
+        .spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            eprintln!("live_check: cannot start cargo: {}", err);
+            std::process::exit(2);
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    for line in BufReader::new(child.stdout.take().unwrap()).lines() {
+        let line = if let Ok(line) = line {line} else {break};
+        if let Some(diagnostic) = LiveDiagnostic::from_live_check_line(&line) {
+            diagnostics.push(diagnostic);
+        }
+        else {
+            // whatever else the app prints
+            println!("{}", line);
+        }
+    }
+
+    let status = child.wait().unwrap();
+    // the app exits with 0 or 1, anything else is a build failure or a crash
+    if !matches!(status.code(), Some(0) | Some(1)) {
+        eprintln!("live_check: application did not run to completion ({})", status);
+        std::process::exit(2);
+    }
+
+    let errors = diagnostics.iter().filter( | d | d.is_error()).count();
+    let warnings = diagnostics.len() - errors;
+    for diagnostic in &diagnostics {
+        if json {
+            println!("{}", diagnostic.serialize_json());
+        }
+        else {
+            println!("{}", diagnostic);
+        }
+    }
+    if !json {
+        println!("live_check: {} errors, {} warnings", errors, warnings);
+    }
+    if errors > 0 || (deny_warnings && warnings > 0) {
+        std::process::exit(1);
+    }
+}
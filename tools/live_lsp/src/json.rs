@@ -0,0 +1,273 @@
+use std::{fmt, str::Chars, iter::Peekable};
+
+// language server messages are full of fields we dont care about, which the derived DeJson
+// refuses. So messages are read into and written from this untyped tree instead
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn object(fields: Vec<(&str, JsonValue)>) -> Self {
+        JsonValue::Object(fields.into_iter().map( | (key, value) | (key.to_string(), value)).collect())
+    }
+
+    pub fn string(s: &str) -> Self {
+        JsonValue::String(s.to_string())
+    }
+
+    pub fn get(&self, key: &str) -> &JsonValue {
+        if let JsonValue::Object(fields) = self {
+            if let Some((_, value)) = fields.iter().find( | (k, _) | k == key) {
+                return value
+            }
+        }
+        &JsonValue::Null
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        if let JsonValue::String(s) = self {Some(s)} else {None}
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        if let JsonValue::Number(n) = self {Some(*n as u32)} else {None}
+    }
+
+    pub fn as_array(&self) -> &[JsonValue] {
+        if let JsonValue::Array(values) = self {values} else {&[]}
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, JsonValue::Null)
+    }
+
+    pub fn parse(input: &str) -> Result<JsonValue, String> {
+        let mut chars = input.chars().peekable();
+        let value = parse_value(&mut chars) ?;
+        skip_ws(&mut chars);
+        if chars.peek().is_some() {
+            return Err("trailing characters after json value".to_string())
+        }
+        Ok(value)
+    }
+}
+
+fn skip_ws(chars: &mut Peekable<Chars>) {
+    while let Some(' ' | '\t' | '\r' | '\n') = chars.peek() {
+        chars.next();
+    }
+}
+
+fn expect_word(chars: &mut Peekable<Chars>, word: &str, value: JsonValue) -> Result<JsonValue, String> {
+    for c in word.chars() {
+        if chars.next() != Some(c) {
+            return Err(format!("expected {}", word))
+        }
+    }
+    Ok(value)
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Result<JsonValue, String> {
+    skip_ws(chars);
+    match chars.peek() {
+        Some('n') => expect_word(chars, "null", JsonValue::Null),
+        Some('t') => expect_word(chars, "true", JsonValue::Bool(true)),
+        Some('f') => expect_word(chars, "false", JsonValue::Bool(false)),
+        Some('"') => Ok(JsonValue::String(parse_string(chars) ?)),
+        Some('[') => {
+            chars.next();
+            let mut values = Vec::new();
+            skip_ws(chars);
+            if chars.peek() == Some(&']') {
+                chars.next();
+                return Ok(JsonValue::Array(values))
+            }
+            loop {
+                values.push(parse_value(chars) ?);
+                skip_ws(chars);
+                match chars.next() {
+                    Some(',') => (),
+                    Some(']') => return Ok(JsonValue::Array(values)),
+                    _ => return Err("expected , or ] in array".to_string())
+                }
+            }
+        }
+        Some('{') => {
+            chars.next();
+            let mut fields = Vec::new();
+            skip_ws(chars);
+            if chars.peek() == Some(&'}') {
+                chars.next();
+                return Ok(JsonValue::Object(fields))
+            }
+            loop {
+                skip_ws(chars);
+                let key = parse_string(chars) ?;
+                skip_ws(chars);
+                if chars.next() != Some(':') {
+                    return Err("expected : in object".to_string())
+                }
+                fields.push((key, parse_value(chars) ?));
+                skip_ws(chars);
+                match chars.next() {
+                    Some(',') => (),
+                    Some('}') => return Ok(JsonValue::Object(fields)),
+                    _ => return Err("expected , or } in object".to_string())
+                }
+            }
+        }
+        Some('-' | '0'..='9') => {
+            let mut num = String::new();
+            while let Some(c @ ('-' | '+' | '.' | 'e' | 'E' | '0'..='9')) = chars.peek() {
+                num.push(*c);
+                chars.next();
+            }
+            num.parse().map(JsonValue::Number).map_err( | _ | format!("invalid number {}", num))
+        }
+        _ => Err("unexpected character in json".to_string())
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    if chars.next() != Some('"') {
+        return Err("expected string".to_string())
+    }
+    let mut out = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(out),
+            Some('\\') => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('r') => out.push('\r'),
+                Some('b') => out.push('\u{8}'),
+                Some('f') => out.push('\u{c}'),
+                Some('u') => {
+                    let mut code = parse_hex4(chars) ?;
+                    // utf16 surrogate pairs come in as two escapes
+                    if (0xd800..0xdc00).contains(&code) {
+                        if chars.next() != Some('\\') || chars.next() != Some('u') {
+                            return Err("unpaired surrogate in string".to_string())
+                        }
+                        let low = parse_hex4(chars) ?;
+                        code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                    }
+                    out.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                }
+                Some(c) => out.push(c),
+                None => return Err("unterminated string".to_string())
+            },
+            Some(c) => out.push(c),
+            None => return Err("unterminated string".to_string())
+        }
+    }
+}
+
+fn parse_hex4(chars: &mut Peekable<Chars>) -> Result<u32, String> {
+    let mut code = 0;
+    for _ in 0..4 {
+        let digit = chars.next().and_then( | c | c.to_digit(16)).ok_or("invalid unicode escape") ?;
+        code = code * 16 + digit;
+    }
+    Ok(code)
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            JsonValue::Number(n) => if n.fract() == 0.0 && n.abs() < 1e15 {
+                write!(f, "{}", *n as i64)
+            }
+            else {
+                write!(f, "{}", n)
+            },
+            JsonValue::String(s) => {
+                write!(f, "\"") ?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"") ?,
+                        '\\' => write!(f, "\\\\") ?,
+                        '\n' => write!(f, "\\n") ?,
+                        '\r' => write!(f, "\\r") ?,
+                        '\t' => write!(f, "\\t") ?,
+                        c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32) ?,
+                        c => write!(f, "{}", c) ?
+                    }
+                }
+                write!(f, "\"")
+            }
+            JsonValue::Array(values) => {
+                write!(f, "[") ?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",") ?;
+                    }
+                    write!(f, "{}", value) ?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(fields) => {
+                write!(f, "{{") ?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",") ?;
+                    }
+                    write!(f, "{}:{}", JsonValue::String(key.clone()), value) ?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_nested_objects() {
+        let value = JsonValue::object(vec![
+            ("jsonrpc", JsonValue::string("2.0")),
+            ("id", JsonValue::Number(7.0)),
+            ("params", JsonValue::object(vec![
+                ("position", JsonValue::object(vec![
+                    ("line", JsonValue::Number(3.0)),
+                    ("character", JsonValue::Number(-1.5)),
+                ])),
+                ("items", JsonValue::Array(vec![JsonValue::Null, JsonValue::Bool(true), JsonValue::Array(vec![])])),
+                ("empty", JsonValue::Object(vec![])),
+            ])),
+        ]);
+        let text = value.to_string();
+        assert_eq!(text, r#"{"jsonrpc":"2.0","id":7,"params":{"position":{"line":3,"character":-1.5},"items":[null,true,[]],"empty":{}}}"#);
+        assert_eq!(JsonValue::parse(&text), Ok(value.clone()));
+        assert_eq!(value.get("params").get("position").get("line").as_u32(), Some(3));
+        assert!(value.get("params").get("missing").is_null());
+    }
+
+    #[test]
+    fn round_trips_escapes() {
+        let s = "quote \" backslash \\ newline \n tab \t return \r bell \u{7} ünïcode 🦀";
+        let text = JsonValue::string(s).to_string();
+        assert_eq!(text, "\"quote \\\" backslash \\\\ newline \\n tab \\t return \\r bell \\u0007 ünïcode 🦀\"");
+        assert_eq!(JsonValue::parse(&text), Ok(JsonValue::string(s)));
+        // escapes editors send that we never write
+        let parsed = JsonValue::parse(r#" { "a" : "é\/\b\f🦀" } "#).unwrap();
+        assert_eq!(parsed.get("a").as_str(), Some("é/\u{8}\u{c}🦀"));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for input in [r#"{"a":1"#, r#"[1,]"#, r#""unterminated"#, r#"{"a" 1}"#, r#"nul"#, r#""\ud83e""#, "1 2"] {
+            assert!(JsonValue::parse(input).is_err(), "{} parsed", input);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use {
+    std::{
+        collections::HashSet,
+        io::{self, BufRead, Write},
+        path::PathBuf,
+    },
+    makepad_live_compiler::{TextPos, TextSpan},
+    crate::{
+        json::JsonValue,
+        workspace::Workspace,
+    }
+};
+
+mod json;
+mod workspace;
+
+// A language server for the live_design! blocks in rust files. Editors start it and talk LSP over
+// stdin and stdout. It reads the workspace sources, registers every live_design block the way an
+// app does at startup and answers go to definition, hover and completion from the registry, and
+// publishes the parse and expand errors as diagnostics
+
+fn show_help() {
+    println!("Makepad live_design language server");
+    println!();
+    println!("Usage: makepad-live-lsp");
+    println!();
+    println!("    Speaks the language server protocol over stdin/stdout, configure your editor");
+    println!("    to start it for rust files. The workspace root comes from the editor.");
+    println!();
+}
+
+fn uri_to_path(uri: &str) -> String {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    let mut out = Vec::new();
+    let bytes = path.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(byte) = std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then( | hex | u8::from_str_radix(hex, 16).ok()) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    let path = String::from_utf8_lossy(&out).to_string();
+    // file:///C:/dir on windows
+    if path.as_bytes().get(2) == Some(&b':') {
+        return path[1..].to_string()
+    }
+    path
+}
+
+fn path_to_uri(path: &str) -> String {
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+    for c in path.replace('\\', "/").chars() {
+        match c {
+            ' ' => uri.push_str("%20"),
+            '%' => uri.push_str("%25"),
+            '#' => uri.push_str("%23"),
+            '?' => uri.push_str("%3F"),
+            c => uri.push(c)
+        }
+    }
+    uri
+}
+
+fn position(params: &JsonValue) -> TextPos {
+    let position = params.get("position");
+    TextPos {
+        line: position.get("line").as_u32().unwrap_or(0),
+        column: position.get("character").as_u32().unwrap_or(0)
+    }
+}
+
+fn range(span: &TextSpan) -> JsonValue {
+    JsonValue::object(vec![
+        ("start", JsonValue::object(vec![
+            ("line", JsonValue::Number(span.start.line as f64)),
+            ("character", JsonValue::Number(span.start.column as f64)),
+        ])),
+        ("end", JsonValue::object(vec![
+            ("line", JsonValue::Number(span.end.line as f64)),
+            ("character", JsonValue::Number(span.end.column as f64)),
+        ])),
+    ])
+}
+
+fn document_path(params: &JsonValue) -> String {
+    uri_to_path(params.get("textDocument").get("uri").as_str().unwrap_or(""))
+}
+
+fn read_message(input: &mut impl BufRead) -> Option<String> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line).ok() ? == 0 {
+            return None
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            length = value.trim().parse().ok();
+        }
+    }
+    let mut body = vec![0; length ?];
+    input.read_exact(&mut body).ok() ?;
+    String::from_utf8(body).ok()
+}
+
+struct Server {
+    workspace: Workspace,
+    open: HashSet<String>,
+    published: HashSet<String>,
+    shutdown: bool,
+}
+
+impl Server {
+    fn send(&self, message: JsonValue) {
+        let body = message.to_string();
+        let mut out = io::stdout().lock();
+        let _ = write!(out, "Content-Length: {}\r\n\r\n{}", body.len(), body);
+        let _ = out.flush();
+    }
+
+    fn respond(&self, id: JsonValue, result: JsonValue) {
+        self.send(JsonValue::object(vec![
+            ("jsonrpc", JsonValue::string("2.0")),
+            ("id", id),
+            ("result", result),
+        ]));
+    }
+
+    fn notify(&self, method: &str, params: JsonValue) {
+        self.send(JsonValue::object(vec![
+            ("jsonrpc", JsonValue::string("2.0")),
+            ("method", JsonValue::string(method)),
+            ("params", params),
+        ]));
+    }
+
+    fn rebuild(&mut self) {
+        self.workspace.rebuild();
+        // files that had diagnostics get an empty list to clear them
+        let previous = std::mem::take(&mut self.published);
+        let mut files: Vec<String> = self.workspace.live_file_names();
+        files.extend(previous.iter().cloned());
+        files.sort();
+        files.dedup();
+        for file in files {
+            let diagnostics: Vec<JsonValue> = self.workspace.errors.iter().filter( | err | err.file == file).map( | err | {
+                JsonValue::object(vec![
+                    ("range", range(&err.span)),
+                    ("severity", JsonValue::Number(1.0)),
+                    ("source", JsonValue::string("live_design")),
+                    ("message", JsonValue::string(&err.message)),
+                ])
+            }).collect();
+            if diagnostics.is_empty() && !previous.contains(&file) {
+                continue;
+            }
+            let has_diagnostics = !diagnostics.is_empty();
+            self.notify("textDocument/publishDiagnostics", JsonValue::object(vec![
+                ("uri", JsonValue::String(path_to_uri(&file))),
+                ("diagnostics", JsonValue::Array(diagnostics)),
+            ]));
+            if has_diagnostics {
+                self.published.insert(file);
+            }
+        }
+        for err in self.workspace.errors.iter().filter( | err | err.file.is_empty()) {
+            self.notify("window/logMessage", JsonValue::object(vec![
+                ("type", JsonValue::Number(1.0)),
+                ("message", JsonValue::string(&err.message)),
+            ]));
+        }
+    }
+
+    fn handle(&mut self, message: JsonValue) {
+        let method = message.get("method").as_str().unwrap_or("").to_string();
+        let id = message.get("id").clone();
+        let params = message.get("params");
+        match method.as_ref() {
+            "initialize" => {
+                let root = params.get("rootUri").as_str().map(uri_to_path)
+                    .or_else( | | params.get("rootPath").as_str().map( | s | s.to_string()))
+                    .map(PathBuf::from)
+                    .or_else( | | std::env::current_dir().ok())
+                    .unwrap_or_default();
+                self.workspace = Workspace::new(root);
+                self.respond(id, JsonValue::object(vec![
+                    ("capabilities", JsonValue::object(vec![
+                        // full document sync
+                        ("textDocumentSync", JsonValue::Number(1.0)),
+                        ("definitionProvider", JsonValue::Bool(true)),
+                        ("hoverProvider", JsonValue::Bool(true)),
+                        ("completionProvider", JsonValue::object(vec![
+                            ("triggerCharacters", JsonValue::Array(vec![JsonValue::string("<")])),
+                        ])),
+                    ])),
+                    ("serverInfo", JsonValue::object(vec![
+                        ("name", JsonValue::string("makepad-live-lsp")),
+                        ("version", JsonValue::string(env!("CARGO_PKG_VERSION"))),
+                    ])),
+                ]));
+            }
+            "initialized" => {
+                self.workspace.scan();
+                self.rebuild();
+            }
+            "textDocument/didOpen" => {
+                let path = document_path(params);
+                let text = params.get("textDocument").get("text").as_str().unwrap_or("").to_string();
+                self.open.insert(path.clone());
+                self.workspace.set_source(&path, text);
+                self.rebuild();
+            }
+            "textDocument/didChange" => {
+                let path = document_path(params);
+                if let Some(change) = params.get("contentChanges").as_array().last() {
+                    self.workspace.set_source(&path, change.get("text").as_str().unwrap_or("").to_string());
+                    self.rebuild();
+                }
+            }
+            "textDocument/didClose" => {
+                let path = document_path(params);
+                self.open.remove(&path);
+                self.workspace.reload_from_disk(&path);
+                self.rebuild();
+            }
+            "workspace/didChangeWatchedFiles" => {
+                for change in params.get("changes").as_array() {
+                    let path = uri_to_path(change.get("uri").as_str().unwrap_or(""));
+                    if path.ends_with(".rs") && !self.open.contains(&path) {
+                        self.workspace.reload_from_disk(&path);
+                    }
+                }
+                self.rebuild();
+            }
+            "textDocument/definition" => {
+                let result = match self.workspace.definition(&document_path(params), position(params)) {
+                    Some((file, span)) => JsonValue::object(vec![
+                        ("uri", JsonValue::String(path_to_uri(&file))),
+                        ("range", range(&span)),
+                    ]),
+                    None => JsonValue::Null
+                };
+                self.respond(id, result);
+            }
+            "textDocument/hover" => {
+                let result = match self.workspace.hover(&document_path(params), position(params)) {
+                    Some(value) => JsonValue::object(vec![
+                        ("contents", JsonValue::object(vec![
+                            ("kind", JsonValue::string("markdown")),
+                            ("value", JsonValue::String(value)),
+                        ])),
+                    ]),
+                    None => JsonValue::Null
+                };
+                self.respond(id, result);
+            }
+            "textDocument/completion" => {
+                let items = self.workspace.completion(&document_path(params), position(params));
+                self.respond(id, JsonValue::Array(items.into_iter().map( | item | {
+                    JsonValue::object(vec![
+                        ("label", JsonValue::String(item.label)),
+                        // Field or Class
+                        ("kind", JsonValue::Number(if item.is_field {5.0} else {7.0})),
+                        ("detail", JsonValue::String(item.detail)),
+                    ])
+                }).collect()));
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                self.respond(id, JsonValue::Null);
+            }
+            "exit" => {
+                std::process::exit(if self.shutdown {0} else {1});
+            }
+            _ => if !id.is_null() {
+                self.send(JsonValue::object(vec![
+                    ("jsonrpc", JsonValue::string("2.0")),
+                    ("id", id),
+                    ("error", JsonValue::object(vec![
+                        ("code", JsonValue::Number(-32601.0)),
+                        ("message", JsonValue::String(format!("method not found {}", method))),
+                    ])),
+                ]));
+            }
+        }
+    }
+}
+
+fn main() {
+    if std::env::args().skip(1).any( | arg | arg == "--help" || arg == "-h") {
+        return show_help();
+    }
+    let mut server = Server {
+        workspace: Workspace::new(PathBuf::new()),
+        open: HashSet::new(),
+        published: HashSet::new(),
+        shutdown: false,
+    };
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    while let Some(body) = read_message(&mut input) {
+        match JsonValue::parse(&body) {
+            Ok(message) => server.handle(message),
+            Err(err) => eprintln!("live_lsp: cannot parse message: {}", err)
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use {
+    std::{
+        collections::{BTreeMap, HashMap},
+        fs,
+        panic::{self, AssertUnwindSafe},
+        path::{Path, PathBuf},
+    },
+    makepad_live_compiler::{
+        makepad_live_id::*,
+        makepad_live_tokenizer::Delim,
+        live_registry::LiveScopeTarget,
+        LiveRegistry,
+        LiveFileId,
+        LiveFileError,
+        LiveModuleId,
+        LivePtr,
+        LiveNodeSliceApi,
+        LiveToken,
+        LiveTokenId,
+        LiveTypeInfo,
+        LiveFieldKind,
+        LiveValue,
+        TextPos,
+        TextSpan,
+        live_error_origin,
+        LiveErrorOrigin,
+        live_rust_types::{RustTypes, RustType, LiveTypeInfoBuilder, scan_rust_types, module_path_in_crate, WALK_SPLAT, LAYOUT_SPLAT},
+    },
+};
+
+// The language server side of the live system. It keeps the rust sources of a workspace that
+// have live_design blocks or Live derives, and registers and expands all of them in a fresh
+// LiveRegistry whenever one changes, the same way the app does at startup. Positions are lines
+// and char columns in the rust file
+
+struct Source {
+    text: String,
+    module_path: Option<String>,
+    cargo_dir: String,
+}
+
+pub struct CompletionItem {
+    pub label: String,
+    pub detail: String,
+    pub is_field: bool,
+}
+
+pub struct Workspace {
+    root: PathBuf,
+    sources: BTreeMap<String, Source>,
+    crate_names: HashMap<PathBuf, Option<String>>,
+    scanned_types: BTreeMap<String, Vec<RustType>>,
+    rust_types: RustTypes,
+    registry: LiveRegistry,
+    pub errors: Vec<LiveFileError>,
+}
+
+impl Workspace {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            sources: BTreeMap::new(),
+            crate_names: HashMap::new(),
+            scanned_types: BTreeMap::new(),
+            rust_types: RustTypes::default(),
+            registry: LiveRegistry::default(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn scan(&mut self) {
+        fn walk(dir: &Path, out: &mut Vec<PathBuf>) {
+            let entries = if let Ok(entries) = fs::read_dir(dir) {entries} else {return};
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                if name.starts_with('.') || name == "target" {
+                    continue;
+                }
+                if path.is_dir() {
+                    walk(&path, out);
+                }
+                else if name.ends_with(".rs") {
+                    out.push(path);
+                }
+            }
+        }
+        let mut files = Vec::new();
+        walk(&self.root.clone(), &mut files);
+        for path in files {
+            if let Ok(text) = fs::read_to_string(&path) {
+                self.set_source(&path.to_string_lossy(), text);
+            }
+        }
+    }
+
+    // an open editor buffer replaces what is on disk until it is closed
+    pub fn set_source(&mut self, path: &str, text: String) {
+        if !(text.contains("live_design!") || text.contains("derive(") && text.contains("Live")) {
+            if self.sources.remove(path).is_some() {
+                self.scanned_types.remove(path);
+            }
+            return
+        }
+        let (module_path, cargo_dir) = self.module_path(Path::new(path));
+        let mut types = Vec::new();
+        if let Some(module_path) = &module_path {
+            scan_rust_types(path, module_path, &text, &mut types);
+        }
+        self.scanned_types.insert(path.to_string(), types);
+        self.sources.insert(path.to_string(), Source {text, module_path, cargo_dir});
+    }
+
+    pub fn reload_from_disk(&mut self, path: &str) {
+        let text = fs::read_to_string(path).unwrap_or_default();
+        self.set_source(path, text);
+    }
+
+    fn crate_name(&mut self, dir: &Path) -> Option<String> {
+        if let Some(name) = self.crate_names.get(dir) {
+            return name.clone()
+        }
+        let name = fs::read_to_string(dir.join("Cargo.toml")).ok().and_then( | toml | {
+            let toml = makepad_toml_parser::parse_toml(&toml).ok() ?;
+            toml.get("lib.name").or_else( | | toml.get("package.name")).cloned() ?.into_str()
+        }).map( | name | name.replace('-', "_"));
+        self.crate_names.insert(dir.to_path_buf(), name.clone());
+        name
+    }
+
+    // what module_path!() says inside the file, worked out from the crate layout
+    fn module_path(&mut self, path: &Path) -> (Option<String>, String) {
+        for dir in path.ancestors().skip(1) {
+            let crate_name = if let Some(crate_name) = self.crate_name(dir) {crate_name} else {continue};
+            let cargo_dir = dir.to_string_lossy().to_string();
+            return (module_path_in_crate(&crate_name, path.strip_prefix(dir).unwrap()), cargo_dir)
+        }
+        (None, String::new())
+    }
+
+    pub fn rebuild(&mut self) {
+        let mut rust_types = RustTypes {
+            types: self.scanned_types.values().flatten().cloned().collect(),
+            ..Default::default()
+        };
+
+        let mut live_files = Vec::new();
+        for (path, source) in &self.sources {
+            let module_path = if let Some(module_path) = &source.module_path {module_path} else {continue};
+            if !source.text.contains("live_design!") {
+                continue;
+            }
+            let class_names = rust_types.live_class_names(&source.text, module_path);
+            live_files.push((path.clone(), module_path.clone(), class_names));
+        }
+
+        let mut registry = LiveRegistry::default();
+        let mut errors = Vec::new();
+        let result = panic::catch_unwind(AssertUnwindSafe( | | {
+            let mut builder = LiveTypeInfoBuilder::new(&rust_types);
+            for (path, module_path, class_names) in &live_files {
+                let live_type_infos = class_names.iter().map( | name | builder.live_type_info(name, module_path)).collect();
+                let source = &self.sources[path];
+                let module_id = if let Ok(module_id) = LiveModuleId::from_str(module_path) {module_id} else {continue};
+                if let Err(err) = registry.register_live_design_file(path, &source.cargo_dir, module_id, source.text.clone(), live_type_infos) {
+                    errors.push(err);
+                }
+            }
+            let mut live_errors = Vec::new();
+            registry.expand_all_documents(&mut live_errors);
+            for live_error in live_errors {
+                errors.push(registry.live_error_to_live_file_error(live_error));
+            }
+        }));
+        if result.is_err() {
+            // whatever did expand is still useful, the panic message went to stderr
+            errors.push(LiveFileError {
+                origin: live_error_origin!(),
+                file: String::new(),
+                span: TextSpan::default(),
+                message: "live_design expansion panicked".to_string()
+            });
+        }
+        self.registry = registry;
+        self.rust_types = rust_types;
+        self.errors = errors;
+    }
+
+    pub fn live_file_names(&self) -> Vec<String> {
+        self.registry.live_files.iter().map( | file | file.file_name.clone()).collect()
+    }
+
+    fn module_path_of(&self, path: &str) -> &str {
+        self.sources.get(path).and_then( | source | source.module_path.as_deref()).unwrap_or("")
+    }
+
+    fn token_location(&self, token_id: LiveTokenId) -> Option<(String, TextSpan)> {
+        let file_id = token_id.file_id() ?;
+        Some((self.registry.file_id_to_file_name(file_id).to_string(), self.registry.token_id_to_span(token_id)))
+    }
+
+    fn ptr_location(&self, ptr: LivePtr) -> Option<(String, TextSpan)> {
+        self.token_location(self.registry.ptr_to_node(ptr).origin.token_id() ?)
+    }
+
+    fn rust_location(file: &str, line: u32, column: u32, len: usize) -> (String, TextSpan) {
+        (file.to_string(), TextSpan {
+            file_id: LiveFileId(0),
+            start: TextPos {line, column},
+            end: TextPos {line, column: column + len as u32}
+        })
+    }
+
+    fn rust_type_of(&self, info: &LiveTypeInfo) -> Option<&RustType> {
+        self.rust_types.find(info.module_id, info.type_name)
+    }
+
+    // the first expanded node that came from the token
+    fn token_to_node(&self, file_id: LiveFileId, token_index: usize) -> Option<usize> {
+        let token_id = LiveTokenId::new(file_id, token_index);
+        let file = self.registry.file_id_to_file(file_id);
+        if let Some(index) = file.expanded.nodes.iter().position( | node | node.origin.token_id() == Some(token_id)) {
+            return Some(index)
+        }
+        // overrides of objects inside a clone keep the origin of what they override,
+        // so follow the same path of props through the expanded document
+        let mut index = file.original.nodes.iter().position( | node | node.origin.token_id() == Some(token_id)) ?;
+        let mut path = Vec::new();
+        while index != 0 {
+            path.push(file.original.nodes[index].prop());
+            index = file.original.nodes.parent(index) ?;
+        }
+        path.reverse();
+        file.expanded.nodes.child_by_path(0, &path)
+    }
+
+    // the ident under pos, and the file and index of its token
+    fn ident_at(&self, path: &str, pos: TextPos) -> Option<(LiveFileId, usize, LiveId)> {
+        let file_id = self.registry.file_name_to_file_id(path) ?;
+        let file = self.registry.file_id_to_file(file_id);
+        let token_index = file.original.find_token_by_pos(pos) ?;
+        if let LiveToken::Ident(id) = file.original.tokens[token_index].token {
+            return Some((file_id, token_index, id))
+        }
+        None
+    }
+
+    fn token_is(&self, file_id: LiveFileId, token_index: usize, token: LiveToken) -> bool {
+        self.registry.file_id_to_file(file_id).original.tokens.get(token_index).is_some_and(| t | t.token == token)
+    }
+
+    // for an ident inside an import statement, the module it names or is imported from
+    fn import_module(&self, file_id: LiveFileId, token_index: usize) -> Option<(LiveModuleId, bool)> {
+        let tokens = &self.registry.file_id_to_file(file_id).original.tokens;
+        let mut segments = Vec::new();
+        let mut index = token_index;
+        loop {
+            match tokens[index].token {
+                LiveToken::Ident(live_id!(import)) => break,
+                LiveToken::Ident(id) => segments.push(id),
+                LiveToken::Punct(live_id!(::)) => (),
+                _ => return None
+            }
+            index = index.checked_sub(1) ?;
+        }
+        segments.reverse();
+        let is_name = tokens[token_index + 1].token != LiveToken::Punct(live_id!(::));
+        if is_name {
+            segments.pop();
+        }
+        if segments.first() == Some(&live_id!(crate)) {
+            segments[0] = self.registry.file_id_to_file(file_id).module_id.0;
+        }
+        let module_path: Vec<String> = segments.iter().map( | id | id.to_string()).collect();
+        Some((LiveModuleId::from_str(&module_path.join("::")).ok() ?, is_name))
+    }
+
+    pub fn definition(&self, path: &str, pos: TextPos) -> Option<(String, TextSpan)> {
+        let (file_id, token_index, id) = self.ident_at(path, pos) ?;
+        let file = self.registry.file_id_to_file(file_id);
+        let name = id.to_string();
+
+        // {{Type}} goes to the rust struct
+        if token_index >= 2 && self.token_is(file_id, token_index - 1, LiveToken::Open(Delim::Brace)) && self.token_is(file_id, token_index - 2, LiveToken::Open(Delim::Brace)) {
+            let rust_type = self.rust_types.resolve(&name, self.module_path_of(path)) ?;
+            return Some(Self::rust_location(&rust_type.file, rust_type.line, rust_type.column, rust_type.name.len()))
+        }
+        // imports go to the definition they pull in, or the start of the module
+        if let Some((module_id, is_name)) = self.import_module(file_id, token_index) {
+            if is_name {
+                return self.ptr_location(self.registry.module_id_and_name_to_ptr(module_id, id) ?)
+            }
+            let module_file_id = self.registry.module_id_to_file_id(module_id) ?;
+            let token = self.registry.file_id_to_file(module_file_id).original.tokens.first() ?;
+            return Some((self.registry.file_id_to_file_name(module_file_id).to_string(), token.span))
+        }
+        // field names go to the rust field they are applied to
+        if self.token_is(file_id, token_index + 1, LiveToken::Punct(live_id!(:))) {
+            let nodes = &file.expanded.nodes;
+            let index = self.token_to_node(file_id, token_index) ?;
+            let info = self.registry.node_live_type_info(nodes, nodes.parent(index) ?) ?;
+            let rust_type = self.rust_type_of(info) ?;
+            let (declared_in, field) = rust_type.field(&name, &self.rust_types) ?;
+            return Some(Self::rust_location(&declared_in.file, field.line, field.column, field.name.len()))
+        }
+        // anything else resolves like the expander does it, <Clone> or a name in an expression
+        match self.registry.find_scope_target(id, &file.expanded.nodes) ? {
+            LiveScopeTarget::LocalPtr(index) => self.token_location(file.expanded.nodes[index].origin.token_id() ?),
+            LiveScopeTarget::LivePtr(ptr) => self.ptr_location(ptr)
+        }
+    }
+
+    fn type_summary(&self, info: &LiveTypeInfo) -> String {
+        let mut out = format!("```rust\nstruct {}\n```\n", info.type_name);
+        let rust_type = self.rust_type_of(info);
+        if let Some(rust_type) = rust_type {
+            out.push_str(&format!("defined in `{}`\n", rust_type.module_path));
+            if !rust_type.doc.is_empty() {
+                out.push_str(&format!("\n{}\n", rust_type.doc));
+            }
+        }
+        if !info.fields.is_empty() {
+            out.push_str("\n```rust\n");
+            for field in &info.fields {
+                out.push_str(&format!("{}{}: {}\n", Self::field_prefix(field.live_field_kind), field.id, field.live_type_info.type_name));
+            }
+            out.push_str("```\n");
+        }
+        out
+    }
+
+    fn field_prefix(kind: LiveFieldKind) -> &'static str {
+        match kind {
+            LiveFieldKind::Calc => "#[calc] ",
+            LiveFieldKind::Deref => "#[deref] ",
+            LiveFieldKind::Animator => "#[animator] ",
+            LiveFieldKind::Live | LiveFieldKind::LiveOption => "",
+        }
+    }
+
+    pub fn hover(&self, path: &str, pos: TextPos) -> Option<String> {
+        let (file_id, token_index, id) = self.ident_at(path, pos) ?;
+        let file = self.registry.file_id_to_file(file_id);
+        let nodes = &file.expanded.nodes;
+        let name = id.to_string();
+
+        if token_index >= 2 && self.token_is(file_id, token_index - 1, LiveToken::Open(Delim::Brace)) && self.token_is(file_id, token_index - 2, LiveToken::Open(Delim::Brace)) {
+            let rust_type = self.rust_types.resolve(&name, self.module_path_of(path)) ?;
+            let info = self.registry.live_type_infos.values().find( | info | info.module_id == rust_type.module_id() && info.type_name == id) ?;
+            return Some(self.type_summary(info))
+        }
+
+        let is_field = self.token_is(file_id, token_index + 1, LiveToken::Punct(live_id!(:)));
+        let is_instance = self.token_is(file_id, token_index + 1, LiveToken::Punct(live_id!(=)));
+        if is_field || is_instance {
+            let index = self.token_to_node(file_id, token_index) ?;
+            if let Some(parent_info) = nodes.parent(index).and_then( | parent | self.registry.node_live_type_info(nodes, parent)) {
+                if let Some(field) = LiveRegistry::find_live_type_field(parent_info, id) {
+                    let mut out = format!(
+                        "```rust\n{}{}: {}\n```\nfield of `{}`\n",
+                        Self::field_prefix(field.live_field_kind),
+                        name,
+                        field.live_type_info.type_name,
+                        parent_info.type_name
+                    );
+                    if let Some((_, rust_field)) = self.rust_type_of(parent_info).and_then( | t | t.field(&name, &self.rust_types)) {
+                        if !rust_field.doc.is_empty() {
+                            out.push_str(&format!("\n{}\n", rust_field.doc));
+                        }
+                    }
+                    return Some(out)
+                }
+                // #[walk] and #[layout] splats arent part of the type info
+                if let Some((_, rust_field)) = self.rust_type_of(parent_info).and_then( | t | t.field(&name, &self.rust_types)) {
+                    return Some(format!("```rust\n{}: {}\n```\nfield of `{}`\n", name, rust_field.ty, parent_info.type_name))
+                }
+            }
+            if let LiveValue::Class {..} = nodes[index].value {
+                let info = self.registry.node_live_type_info(nodes, index) ?;
+                return Some(format!("```rust\n{}: {}\n```\n", name, info.type_name))
+            }
+            return None
+        }
+
+        let (nodes, index) = match self.registry.find_scope_target(id, nodes) ? {
+            LiveScopeTarget::LocalPtr(index) => (nodes.as_slice(), index),
+            LiveScopeTarget::LivePtr(ptr) => self.registry.ptr_to_nodes_index(ptr)
+        };
+        match &nodes[index].value {
+            LiveValue::Class {..} => {
+                let info = self.registry.node_live_type_info(nodes, index) ?;
+                Some(self.type_summary(info))
+            }
+            value => Some(format!("```\n{}: {:?}\n```\n", name, value))
+        }
+    }
+
+    pub fn completion(&self, path: &str, pos: TextPos) -> Vec<CompletionItem> {
+        let mut items = Vec::new();
+        let file_id = if let Some(file_id) = self.registry.file_name_to_file_id(path) {file_id} else {return items};
+        let file = self.registry.file_id_to_file(file_id);
+        let tokens = &file.original.tokens;
+        let nodes = &file.expanded.nodes;
+        if nodes.is_empty() {
+            return items
+        }
+        // the last token that starts before the cursor
+        let before = tokens.iter().rposition( | t | t.token != LiveToken::Eof && (t.span.start.line, t.span.start.column) < (pos.line, pos.column));
+        let before = if let Some(before) = before {before} else {return items};
+
+        // after a < come the definitions in scope
+        let after_angle = tokens[before].token == LiveToken::Punct(live_id!(<))
+            || before > 0 && matches!(tokens[before].token, LiveToken::Ident(_)) && tokens[before - 1].token == LiveToken::Punct(live_id!(<));
+        if after_angle {
+            if let LiveValue::Root {id_resolve} = &nodes[0].value {
+                for id in id_resolve.keys() {
+                    items.push(CompletionItem {label: id.to_string(), detail: String::new(), is_field: false});
+                }
+            }
+            items.sort_by( | a, b | a.label.cmp(&b.label));
+            return items
+        }
+
+        // otherwise the fields of whatever the enclosing { } is applied to
+        let mut depth = 0;
+        let mut open = None;
+        for index in (0..=before).rev() {
+            match tokens[index].token {
+                LiveToken::Close(Delim::Brace) => depth += 1,
+                LiveToken::Open(Delim::Brace) if depth == 0 => {
+                    open = Some(index);
+                    break
+                }
+                LiveToken::Open(Delim::Brace) => depth -= 1,
+                _ => ()
+            }
+        }
+        let open = if let Some(open) = open {open} else {return items};
+        let mut container = None;
+        for (index, node) in nodes.iter().enumerate() {
+            if let Some(token_id) = node.origin.token_id() {
+                if token_id.file_id() == Some(file_id) && token_id.token_index() < open && container.is_none_or( | (t, _) | token_id.token_index() > t) {
+                    container = Some((token_id.token_index(), index));
+                }
+            }
+        }
+        let info = if let Some(info) = container.and_then( | (_, index) | self.registry.node_live_type_info(nodes, index)) {info} else {return items};
+        fn collect(info: &LiveTypeInfo, items: &mut Vec<CompletionItem>) {
+            for field in &info.fields {
+                match field.live_field_kind {
+                    LiveFieldKind::Deref => collect(&field.live_type_info, items),
+                    LiveFieldKind::Calc => (),
+                    _ => items.push(CompletionItem {
+                        label: field.id.to_string(),
+                        detail: field.live_type_info.type_name.to_string(),
+                        is_field: true
+                    })
+                }
+            }
+        }
+        collect(info, &mut items);
+        if let Some(rust_type) = self.rust_type_of(info) {
+            for splat in WALK_SPLAT.iter().chain(LAYOUT_SPLAT) {
+                if items.iter().any( | item | item.label == *splat) {
+                    continue;
+                }
+                if let Some((_, field)) = rust_type.field(splat, &self.rust_types) {
+                    items.push(CompletionItem {label: field.name.clone(), detail: field.ty.clone(), is_field: true});
+                }
+            }
+        }
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (Workspace, String, String) {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture");
+        let mut workspace = Workspace::new(root.clone());
+        workspace.scan();
+        workspace.rebuild();
+        let path = root.join("src/lib.rs").to_string_lossy().to_string();
+        let text = fs::read_to_string(&path).unwrap();
+        (workspace, path, text)
+    }
+
+    // the position of the nth occurrence of needle, plus offset chars into it
+    fn pos_of(text: &str, needle: &str, nth: usize, offset: u32) -> TextPos {
+        let (line, line_text) = text.lines().enumerate().filter( | (_, l) | l.contains(needle)).nth(nth).unwrap();
+        let column = line_text[..line_text.find(needle).unwrap()].chars().count() as u32;
+        TextPos {line: line as u32, column: column + offset}
+    }
+
+    #[test]
+    fn fixture_expands_cleanly() {
+        let (workspace, path, _) = fixture();
+        assert!(workspace.errors.is_empty(), "{:?}", workspace.errors.iter().map( | e | e.to_string()).collect::<Vec<_>>());
+        assert_eq!(workspace.live_file_names(), vec![path]);
+    }
+
+    #[test]
+    fn definition() {
+        let (workspace, path, text) = fixture();
+        // {{FixtureLabel}} goes to the rust struct
+        let (file, span) = workspace.definition(&path, pos_of(&text, "{{FixtureLabel}}", 0, 3)).unwrap();
+        assert_eq!(file, path);
+        assert_eq!(span.start, pos_of(&text, "FixtureLabel {", 0, 0));
+        // a field goes to the rust field
+        let (file, span) = workspace.definition(&path, pos_of(&text, "font_size:", 0, 1)).unwrap();
+        assert_eq!(file, path);
+        assert_eq!(span.start, pos_of(&text, "font_size: f64", 0, 0));
+        // <Clone> and names in expressions go to the live definition
+        let (_, span) = workspace.definition(&path, pos_of(&text, "<FixtureLabel>", 0, 1)).unwrap();
+        assert_eq!(span.start, pos_of(&text, "FixtureLabel = ", 0, 0));
+        let (_, span) = workspace.definition(&path, pos_of(&text, "(FIXTURE_SIZE)", 0, 1)).unwrap();
+        assert_eq!(span.start, pos_of(&text, "FIXTURE_SIZE = ", 0, 0));
+    }
+
+    #[test]
+    fn hover() {
+        let (workspace, path, text) = fixture();
+        let hover = workspace.hover(&path, pos_of(&text, "{{FixtureLabel}}", 0, 3)).unwrap();
+        assert!(hover.contains("struct FixtureLabel"), "{}", hover);
+        assert!(hover.contains("A label for the language server tests"), "{}", hover);
+        assert!(hover.contains("font_size: f64"), "{}", hover);
+        let hover = workspace.hover(&path, pos_of(&text, "text:", 0, 1)).unwrap();
+        assert!(hover.contains("text: String"), "{}", hover);
+        assert!(hover.contains("field of `FixtureLabel`"), "{}", hover);
+        assert!(hover.contains("what the label shows"), "{}", hover);
+        assert!(workspace.hover(&path, TextPos {line: 0, column: 0}).is_none());
+    }
+
+    #[test]
+    fn completion() {
+        let (workspace, path, text) = fixture();
+        // inside FixtureTitle come the fields of the type it clones
+        let items = workspace.completion(&path, pos_of(&text, "font_size: 20.0", 0, 0));
+        let labels: Vec<&str> = items.iter().map( | item | item.label.as_str()).collect();
+        assert_eq!(labels, vec!["text", "font_size", "is_bold"]);
+        assert!(items.iter().all( | item | item.is_field));
+        assert_eq!(items[2].detail, "bool");
+        // after a < the definitions in scope
+        let items = workspace.completion(&path, pos_of(&text, "<FixtureLabel>", 0, 1));
+        let labels: Vec<&str> = items.iter().map( | item | item.label.as_str()).collect();
+        assert!(labels.contains(&"FixtureLabel") && labels.contains(&"FIXTURE_SIZE"), "{:?}", labels);
+    }
+}
@@ -0,0 +1,23 @@
+use makepad_widgets::*;
+
+live_design!{
+    FIXTURE_SIZE = 12.0
+
+    FixtureLabel = {{FixtureLabel}} {
+        text: "fixture"
+        font_size: (FIXTURE_SIZE)
+    }
+
+    FixtureTitle = <FixtureLabel> {
+        font_size: 20.0
+    }
+}
+
+/// A label for the language server tests
+#[derive(Live, LiveHook)]
+pub struct FixtureLabel {
+    /// what the label shows
+    #[live] text: String,
+    #[live] font_size: f64,
+    #[live] is_bold: bool,
+}
@@ -0,0 +1,193 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use makepad_shader_compiler::{
+    makepad_live_compiler::live_rust_types::module_path_in_crate,
+    generate_glsl,
+    generate_hlsl,
+    generate_metal,
+    generate_wgsl,
+    OfflineShaders,
+};
+
+// Compiles a draw shader from a live_design block without building the app. The crate of the
+// file and the makepad_draw and makepad_widgets crates are read from source, so shaders can
+// use their libraries and base shaders, then the named shader is analysed like DrawVars does
+// at runtime and every backend is generated from it
+
+fn show_help(err: &str) {
+    if !err.is_empty() {
+        println!("{}", err);
+    }
+    println!("Makepad offline shader compiler");
+    println!();
+    println!("Usage makepad-shaderc [options] <file.rs> [shader]");
+    println!();
+    println!("    Without a shader it lists the draw shaders in the file");
+    println!("    Example: makepad-shaderc widgets/src/theme_desktop_dark.rs Button.draw_bg");
+    println!();
+    println!("    [options]:");
+    println!();
+    println!("       --glsl                                    Only print the GLSL vertex and pixel shader");
+    println!("       --hlsl                                    Only print the HLSL shader");
+    println!("       --metal                                   Only print the Metal shader");
+    println!("       --wgsl                                    Only print the WGSL shader");
+    println!("       --crate <dir>                             Also read the sources of this crate");
+    println!();
+}
+
+fn crate_name(dir: &Path) -> Option<String> {
+    let toml = fs::read_to_string(dir.join("Cargo.toml")).ok() ?;
+    let toml = makepad_toml_parser::parse_toml(&toml).ok() ?;
+    toml.get("lib.name").or_else( | | toml.get("package.name")).cloned() ?.into_str()
+}
+
+fn crate_dir_of(file: &Path) -> Option<PathBuf> {
+    file.ancestors().skip(1).find( | dir | crate_name(dir).is_some()).map( | dir | dir.to_path_buf())
+}
+
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = if let Ok(entries) = fs::read_dir(dir) {
+        entries.flatten().map( | e | e.path()).collect()
+    }
+    else {
+        return
+    };
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            rust_files(&path, out);
+        }
+        else if path.extension().is_some_and( | ext | ext == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+fn add_crate(offline: &mut OfflineShaders, crate_dir: &Path, extra_file: Option<&Path>) -> Result<(), String> {
+    let crate_name = crate_name(crate_dir).ok_or_else( | | format!("no crate name in {}", crate_dir.join("Cargo.toml").display())) ?;
+    let mut paths = Vec::new();
+    rust_files(&crate_dir.join("src"), &mut paths);
+    // an example or test file lives outside of src
+    if let Some(file) = extra_file {
+        if !paths.iter().any( | p | p == file) {
+            paths.push(file.to_path_buf());
+        }
+    }
+    for path in paths {
+        let module_path = if let Some(module_path) = module_path_in_crate(&crate_name, path.strip_prefix(crate_dir).unwrap()) {module_path} else {continue};
+        let source = fs::read_to_string(&path).map_err( | err | format!("cannot read {}: {}", path.display(), err)) ?;
+        offline.add_source(&path.to_string_lossy(), &module_path, source);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut backends = Vec::new();
+    let mut crate_dirs = Vec::new();
+    let mut positional = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_ref() {
+            "--glsl" | "--hlsl" | "--metal" | "--wgsl" => backends.push(arg[2..].to_string()),
+            "--crate" => match args.next() {
+                Some(dir) => crate_dirs.push(PathBuf::from(dir)),
+                None => return show_help("--crate needs a directory")
+            },
+            "--help" | "-h" => return show_help(""),
+            _ => positional.push(arg)
+        }
+    }
+    if positional.is_empty() || positional.len() > 2 {
+        return show_help("give a file and optionally a shader name");
+    }
+    if backends.is_empty() {
+        backends = vec!["glsl".to_string(), "hlsl".to_string(), "metal".to_string(), "wgsl".to_string()];
+    }
+
+    let file = match fs::canonicalize(&positional[0]) {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!("shaderc: cannot open {}: {}", positional[0], err);
+            std::process::exit(2);
+        }
+    };
+    let own_crate = if let Some(dir) = crate_dir_of(&file) {dir} else {
+        eprintln!("shaderc: {} is not inside a crate", file.display());
+        std::process::exit(2);
+    };
+    let repo_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    crate_dirs.push(repo_dir.join("draw"));
+    crate_dirs.push(repo_dir.join("widgets"));
+
+    let mut offline = OfflineShaders::default();
+    let mut added = vec![own_crate.clone()];
+    let mut result = add_crate(&mut offline, &own_crate, Some(&file));
+    for dir in crate_dirs {
+        let dir = fs::canonicalize(&dir).unwrap_or(dir);
+        if result.is_ok() && !added.contains(&dir) {
+            result = add_crate(&mut offline, &dir, None);
+            added.push(dir);
+        }
+    }
+    if let Err(err) = result {
+        eprintln!("shaderc: {}", err);
+        std::process::exit(2);
+    }
+
+    // errors elsewhere dont matter as long as the shader itself makes it
+    let errors = offline.build();
+    let file_name = file.to_string_lossy().to_string();
+    let draw_shaders = offline.draw_shaders(&file_name);
+
+    let name = if let Some(name) = positional.get(1) {name} else {
+        for error in errors.iter().filter( | e | e.file == file_name) {
+            eprintln!("{}", error);
+        }
+        for (name, _) in &draw_shaders {
+            println!("{}", name);
+        }
+        return
+    };
+
+    let draw_shader_ptr = if let Some((_, ptr)) = draw_shaders.iter().find( | (n, _) | n == name) {*ptr} else {
+        for error in errors.iter().filter( | e | e.file == file_name) {
+            eprintln!("{}", error);
+        }
+        eprintln!("shaderc: no draw shader {} in {}, run without a shader name to list them", name, file.display());
+        std::process::exit(1);
+    };
+    let const_table = match offline.analyse(draw_shader_ptr) {
+        Ok(const_table) => const_table,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    let def = offline.draw_shader_def(draw_shader_ptr).unwrap();
+    let shader_registry = &offline.shader_registry;
+    for backend in &backends {
+        match backend.as_ref() {
+            "glsl" => {
+                println!("// glsl vertex shader");
+                print!("{}", generate_glsl::generate_vertex_shader(def, &const_table, shader_registry));
+                println!("// glsl pixel shader");
+                print!("{}", generate_glsl::generate_pixel_shader(def, &const_table, shader_registry));
+            }
+            "hlsl" => {
+                println!("// hlsl shader");
+                print!("{}", generate_hlsl::generate_shader(def, &const_table, shader_registry));
+            }
+            "wgsl" => {
+                println!("// wgsl shader");
+                print!("{}", generate_wgsl::generate_shader(def, &const_table, shader_registry).wgsl);
+            }
+            _ => {
+                println!("// metal shader");
+                print!("{}", generate_metal::generate_shader(def, &const_table, shader_registry).mtlsl);
+            }
+        }
+    }
+}
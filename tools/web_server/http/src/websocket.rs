@@ -34,9 +34,13 @@ pub struct WebSocket {
     mask_counter: usize,
     is_ping: bool,
     is_pong: bool,
-    is_partial: bool,
+    is_final: bool,
+    is_continuation: bool,
     is_text: bool,
     is_masked: bool,
+    // reassembly buffer for fragmented messages
+    fragments: Vec<u8>,
+    fragments_are_text: bool,
     state: State
 }
 
@@ -57,6 +61,14 @@ pub enum WebSocketError<'a> {
 pub const PING_MESSAGE:[u8;2] = [128 | 9,0];
 pub const PONG_MESSAGE:[u8;2] = [128 | 10,0];
 
+pub const OPCODE_TEXT: u8 = 1;
+pub const OPCODE_BINARY: u8 = 2;
+pub const OPCODE_CLOSE: u8 = 8;
+pub const OPCODE_PING: u8 = 9;
+pub const OPCODE_PONG: u8 = 10;
+
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
 pub struct BinaryMessageHeader{
     len: usize,
     data:[u8;10]
@@ -95,6 +107,26 @@ impl BinaryMessageHeader{
     }
 }
 
+/// Builds a masked frame as clients have to send them, the server side just uses BinaryMessageHeader
+pub fn build_masked_frame(opcode: u8, data: &[u8], mask: [u8; 4]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(data.len() + 14);
+    frame.push(128 | opcode);
+    if data.len() < 126 {
+        frame.push(128 | data.len() as u8);
+    }
+    else if data.len() < 65536 {
+        frame.push(128 | 126);
+        frame.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    }
+    else {
+        frame.push(128 | 127);
+        frame.extend_from_slice(&(data.len() as u64).to_be_bytes());
+    }
+    frame.extend_from_slice(&mask);
+    frame.extend(data.iter().enumerate().map( | (i, byte) | byte ^ mask[i & 3]));
+    frame
+}
+
 impl WebSocket {
     pub fn new() -> Self {
         Self {
@@ -108,25 +140,42 @@ impl WebSocket {
             is_ping: false,
             is_pong: false,
             is_masked: false,
-            is_partial: false,
+            is_final: false,
+            is_continuation: false,
             is_text: false,
+            fragments: Vec::new(),
+            fragments_are_text: false,
             state: State::Opcode
         }
     }
     
-    pub fn create_upgrade_response(key: &str) -> String {
-        let to_hash = format!("{}258EAFA5-E914-47DA-95CA-C5AB0DC85B11", key);
+    pub fn create_accept_key(key: &str) -> String {
+        let to_hash = format!("{}{}", key, WEBSOCKET_GUID);
         let mut sha1 = Sha1::new();
         sha1.update(to_hash.as_bytes());
         let out_bytes = sha1.finalise();
-        let base64 = base64_encode(&out_bytes);
+        base64_encode(&out_bytes)
+    }
+    
+    pub fn create_upgrade_response(key: &str) -> String {
         let response_ack = format!(
             "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
-            base64
+            Self::create_accept_key(key)
         );
         response_ack
     }
     
+    /// The client half of the handshake, `key` is 16 random bytes base64 encoded
+    pub fn create_upgrade_request(host: &str, path: &str, key: &str, extra_headers: &str) -> String {
+        format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n{}\r\n",
+            path,
+            host,
+            key,
+            extra_headers
+        )
+    }
+    
     fn parse_head(&mut self, input: &[u8]) -> bool {
         while self.head_expected > 0
             && self.input_read < input.len()
@@ -149,7 +198,8 @@ impl WebSocket {
             State::Opcode => {
                 self.is_ping = false;
                 self.is_pong = false;
-                self.is_partial = false;
+                self.is_final = false;
+                self.is_continuation = false;
                 self.is_text = false;
                 self.is_masked = false;
             },
@@ -160,6 +210,20 @@ impl WebSocket {
         self.state = state;
     }
     
+    fn emit_message<F>(is_text: bool, data: &[u8], result: &mut F) where F: FnMut(Result<WebSocketMessage, WebSocketError>){
+        if is_text{
+            if let Ok(text) = std::str::from_utf8(data){
+                result(Ok(WebSocketMessage::Text(text)));
+            }
+            else{
+                result(Err(WebSocketError::TextNotUTF8(data)))
+            }
+        }
+        else{
+            result(Ok(WebSocketMessage::Binary(data)));
+        }
+    }
+    
     pub fn parse<F>(&mut self, input: &[u8], mut result: F) where F: FnMut(Result<WebSocketMessage, WebSocketError>){
         self.input_read = 0;
         // parse a header
@@ -171,7 +235,8 @@ impl WebSocket {
                     }
                     let opcode = self.head[0] & 15;
                     if opcode <= 2 {
-                        self.is_partial = (self.head[0] & 128) != 0;
+                        self.is_final = (self.head[0] & 128) != 0;
+                        self.is_continuation = opcode == 0;
                         self.is_text = opcode == 1;
                         self.to_state(State::Len1);
                     }
@@ -272,16 +337,20 @@ impl WebSocket {
                         else if self.is_pong {
                             result(Ok(WebSocketMessage::Pong(&self.data)));
                         }
-                        else if self.is_text{
-                            if let Ok(text) = std::str::from_utf8(&self.data){
-                                result(Ok(WebSocketMessage::Text(text)));
+                        else if !self.is_final || self.is_continuation {
+                            // fragmented message, collect until the final frame
+                            if !self.is_continuation {
+                                self.fragments.clear();
+                                self.fragments_are_text = self.is_text;
                             }
-                            else{
-                                result(Err(WebSocketError::TextNotUTF8(&self.data)))
+                            self.fragments.extend_from_slice(&self.data);
+                            if self.is_final {
+                                Self::emit_message(self.fragments_are_text, &self.fragments, &mut result);
+                                self.fragments.clear();
                             }
                         }
                         else{
-                            result(Ok(WebSocketMessage::Binary(&self.data)));
+                            Self::emit_message(self.is_text, &self.data, &mut result);
                         }
                         
                         self.to_state(State::Opcode);
@@ -402,11 +402,11 @@ live_design!{
         
         fn pixel(self) -> vec4 {
             let sdf = Sdf2d::viewport(self.pos * self.rect_size)
-            if self.radius.x > 0.0 {
+            if self.radius > 0.0 {
                 sdf.circle(
                     self.rect_size.x * 0.5,
                     self.rect_size.y * 0.5,
-                    self.radius.x
+                    self.radius
                 )
             }
             else {
@@ -431,7 +431,7 @@ live_design!{
         instance border_width: 0.0
         instance border_color: #0000
         instance inset: vec4(0.0, 0.0, 0.0, 0.0)
-        instance radius: 5
+        instance radius: 5.0
         
         fn get_color(self) -> vec4 {
             return self.color
@@ -443,7 +443,7 @@ live_design!{
         
         fn pixel(self) -> vec4 {
             let sdf = Sdf2d::viewport(self.pos * self.rect_size)
-            if self.radius.x > 0.0 {
+            if self.radius > 0.0 {
                 sdf.hexagon(
                     self.rect_size.x * 0.5,
                     self.rect_size.y * 0.5,
@@ -460,9 +460,9 @@ live_design!{
                     )
                 )
             }
-            sdf.fill_keep(color)
+            sdf.fill_keep(self.get_color())
             if self.border_width > 0.0 {
-                sdf.stroke(self.border_color, self.border_width)
+                sdf.stroke(self.get_border_color(), self.border_width)
             }
             return sdf.result
         }
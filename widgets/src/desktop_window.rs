@@ -75,6 +75,10 @@ impl LiveHook for DesktopWindow {
             OsType::Macos => {
                 // self.frame.get_view(id!(caption_bar)).set_visible(false);
             }
+            OsType::LinuxWindow(params) if params.custom_window_chrome => {
+                self.view.view(id!(caption_bar)).set_visible(true);
+                self.view.view(id!(windows_buttons)).set_visible(true);
+            }
             OsType::LinuxWindow(_) |
             OsType::LinuxDirect |
             OsType::Android(_) => {
@@ -167,7 +171,10 @@ impl DesktopWindow {
                     self.window.minimize(cx);
                 }
                 if self.view.button(id!(max)).clicked(&actions) {
-                    if self.window.is_fullscreen(cx) {
+                    // macos maximizes by going fullscreen
+                    let is_maximized = self.window.is_maximized(cx)
+                        || matches!(cx.os_type(), OsType::Macos) && self.window.is_fullscreen(cx);
+                    if is_maximized {
                         self.window.restore(cx);
                     }
                     else {
@@ -269,6 +276,19 @@ impl Widget for DesktopWindow {
         self.view.find_widgets(path, cached, results);
     }
     
+    fn live_read_state(&self, cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        // the window picks these up when it opens, so restore before that happens
+        let cxwindow = &cx.windows[self.window.window_id()];
+        if cxwindow.is_created {
+            let (inner_size, position) = (cxwindow.window_geom.inner_size, cxwindow.window_geom.position);
+            out.open_object(live_id!(window));
+            inner_size.into_vec2().live_read_to(live_id!(inner_size), out);
+            position.into_vec2().live_read_to(live_id!(position), out);
+            out.close();
+        }
+        self.view.live_read_state(cx, out);
+    }
+    
     fn draw_walk_widget(&mut self, cx: &mut Cx2d, _walk: Walk) -> WidgetDraw {
         if self.draw_state.begin(cx, DrawState::Drawing) {
             if self.begin(cx).is_not_redrawing() {
@@ -134,7 +134,7 @@ pub enum DropPart {
     Tab
 }
 
-#[derive(Clone, Debug, Live, LiveHook)]
+#[derive(Clone, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum DockItem {
     #[live {axis: Axis::Vertical, align: SplitterAlign::Weighted(0.5), a: LiveId(0), b: LiveId(0)}]
@@ -186,6 +186,16 @@ impl LiveHook for Dock {
                     cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
                 }
             }
+            ApplyFrom::ApplyOver => {
+                // an arrangement saved with live_read_state
+                if nodes[index].origin.has_prop_type(LivePropType::Instance) && nodes[index].value.is_enum() {
+                    let mut dock_item = DockItem::new(cx);
+                    let index = dock_item.apply(cx, from, index, nodes);
+                    self.dock_items.insert(id, dock_item);
+                    self.area.redraw(cx);
+                    return index;
+                }
+            }
             _ => ()
         }
         nodes.skip_node(index)
@@ -751,6 +761,14 @@ impl Widget for Dock {
         
     }
     
+    fn live_read_state(&self, _cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        for (id, dock_item) in self.dock_items.iter() {
+            let index = out.len();
+            dock_item.live_read_to(*id, out);
+            out[index].origin.set_prop_type(LivePropType::Instance);
+        }
+    }
+    
     fn find_widgets(&mut self, path: &[LiveId], cached: WidgetCache, results: &mut WidgetSet) {
         if let Some(DockItem::Tab {kind, ..}) = self.dock_items.get(&path[0]) {
             if let Some(widget) = self.items.get_mut(&DockItemId {id: path[0], kind: *kind}) {
@@ -68,6 +68,9 @@ impl ImageBuffer {
                 format: TextureFormat::ImageBGRA,
                 width: Some(self.width),
                 height: Some(self.height),
+                // images are often drawn smaller than they are
+                mipmaps: true,
+                ..Default::default()
             },
         );
         texture.swap_image_u32(cx, &mut self.data);
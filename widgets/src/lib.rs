@@ -51,6 +51,9 @@ pub mod data_binding;
 
 mod base;
 mod theme_desktop_dark;
+mod theme_desktop_light;
+mod theme_desktop_high_contrast;
+pub mod theme;
 pub mod image_cache;
 
 pub use crate::{
@@ -72,6 +75,7 @@ pub use crate::{
     scroll_shadow::{DrawScrollShadow},
     scroll_bar::{ScrollBar},
     slides_view::{SlidesView},
+    theme::{Theme, CxThemeApi},
     widget::{
         WidgetSet,
         WidgetSetIterator,
@@ -99,6 +103,8 @@ pub fn live_design(cx: &mut Cx) {
     crate::splitter::live_design(cx);
     crate::base::live_design(cx);
     crate::theme_desktop_dark::live_design(cx);
+    crate::theme_desktop_light::live_design(cx);
+    crate::theme_desktop_high_contrast::live_design(cx);
     crate::slider::live_design(cx);
     crate::label::live_design(cx);
     crate::nav_control::live_design(cx);
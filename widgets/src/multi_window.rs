@@ -38,6 +38,11 @@ impl LiveHook for MultiWindow {
                     cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
                 }
             }
+            ApplyFrom::ApplyOver => {
+                if let Some(window) = self.windows.get_mut(&id) {
+                    return window.apply(cx, from, index, nodes);
+                }
+            }
             _ => ()
         }
         nodes.skip_node(index)
@@ -62,6 +67,14 @@ impl Widget for MultiWindow {
         }
     }
     
+    fn live_read_state(&self, cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        for (id, window) in self.windows.iter() {
+            out.push(LiveNode {origin: LiveNodeOrigin::instance(), id: *id, value: LiveValue::Object});
+            window.live_read_state(cx, out);
+            out.close();
+        }
+    }
+    
     fn handle_widget_event_with(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)) {
         for window in self.windows.values_mut() {
             window.handle_widget_event_with(cx, event, dispatch_action);
@@ -62,6 +62,22 @@ impl LiveHook for Slider{
     fn before_live_design(cx:&mut Cx){
         register_widget!(cx,Slider)
     }
+    
+    // the value is rust state, live_read_state writes it out as value so it can be applied back
+    fn apply_value_unknown(&mut self, cx: &mut Cx, _from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        if nodes[index].id == live_id!(value) {
+            if let Some(value) = nodes[index].value.as_float() {
+                if self.set_internal(value) {
+                    self.redraw(cx);
+                }
+                self.update_text_input(cx);
+            }
+        }
+        else if !nodes[index].origin.node_has_prefix() {
+            cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+        }
+        nodes.skip_node(index)
+    }
 }
 
 #[derive(Clone, WidgetAction)]
@@ -223,6 +239,10 @@ impl Widget for Slider {
         }
     }
     
+    fn live_read_state(&self, _cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        self.to_external().live_read_to(live_id!(value), out);
+    }
+    
     fn data_to_widget(&mut self, cx: &mut Cx, nodes:&[LiveNode], path: &[LiveId]){
         if let Some(value) = nodes.read_field_value(path) {
             if let Some(value) = value.as_float() {
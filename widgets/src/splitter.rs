@@ -90,6 +90,12 @@ impl Widget for Splitter {
         self.b.find_widgets(path, cached, results);
     }
     
+    fn live_read_state(&self, cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        self.align.live_read_to(live_id!(align), out);
+        self.a.live_read_state_to(cx, live_id!(a).as_field(), out);
+        self.b.live_read_state_to(cx, live_id!(b).as_field(), out);
+    }
+    
     fn draw_walk_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
         if self.draw_state.begin(cx, DrawState::DrawA) {
             self.begin(cx, walk);
@@ -268,7 +274,7 @@ fn margin(&self) -> Margin {
 }
 }
 
-#[derive(Clone, Copy, Debug, Live, LiveHook)]
+#[derive(Clone, Copy, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum SplitterAlign {
     #[live(50.0)] FromA(f64),
@@ -341,7 +341,7 @@ impl TextInput {
             Hit::TextInput(te) => {
                 let mut input = String::new();
                 self.filter_input(&te.input, Some(&mut input));
-                if input.len() == 0 {
+                if input.len() == 0 && !te.replace_last {
                     return
                 }
                 let last_undo = self.last_undo.take();
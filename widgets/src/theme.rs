@@ -0,0 +1,45 @@
+use crate::makepad_platform::*;
+
+// the widget styles live in theme_desktop_dark and take their colors from its THEME_* constants.
+// the other themes only redefine those constants, switching expands them on top of
+// theme_desktop_dark and re-applies the app on the next event.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Theme {
+    #[default]
+    DesktopDark,
+    DesktopLight,
+    DesktopHighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::DesktopDark, Theme::DesktopLight, Theme::DesktopHighContrast];
+
+    pub fn module_id(&self) -> LiveModuleId {
+        LiveModuleId(live_id!(makepad_widgets), match self {
+            Self::DesktopDark => live_id!(theme_desktop_dark),
+            Self::DesktopLight => live_id!(theme_desktop_light),
+            Self::DesktopHighContrast => live_id!(theme_desktop_high_contrast),
+        })
+    }
+}
+
+pub trait CxThemeApi {
+    fn theme(&self) -> Theme;
+    fn set_theme(&mut self, theme: Theme);
+}
+
+impl CxThemeApi for Cx {
+    fn theme(&self) -> Theme {
+        let overlay = self.live_registry.borrow().module_overlay(Theme::DesktopDark.module_id());
+        Theme::ALL.into_iter().find( | theme | Some(theme.module_id()) == overlay).unwrap_or_default()
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        let overlay = if theme == Theme::DesktopDark {None} else {Some(theme.module_id())};
+        if let Err(errors) = self.set_live_module_overlay(Theme::DesktopDark.module_id(), overlay) {
+            for err in errors {
+                error!("set_theme: cannot switch to {:?} {}", theme, err);
+            }
+        }
+    }
+}
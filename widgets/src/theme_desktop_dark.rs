@@ -133,6 +133,51 @@ live_design!{
     const THEME_COLOR_DRAG_QUAD = (THEME_COLOR_UP_50)
     const THEME_COLOR_PANIC = #f0f
     
+    // CONTROLS
+    
+    const THEME_COLOR_TEXT_LABEL = #8
+    const THEME_COLOR_TEXT_CONTROL = #9
+    const THEME_COLOR_TEXT_CONTROL_FOCUS = #b
+    const THEME_COLOR_TEXT_CONTROL_HOVER = #c
+    const THEME_COLOR_TEXT_CONTROL_ACTIVE = #f
+    const THEME_COLOR_TEXT_CHECK_BOX = #fff6
+    const THEME_COLOR_TEXT_INPUT = #xFFFFFF55
+    const THEME_COLOR_TEXT_INPUT_HOVER = #xFFFFFF88
+    const THEME_COLOR_TEXT_INPUT_FOCUS = #xFFFFFFCC
+    const THEME_COLOR_TEXT_INPUT_EMPTY = #3
+    const THEME_COLOR_TEXT_INPUT_CURSOR = #f
+    const THEME_COLOR_TEXT_INPUT_SELECTION = #xFFFFFF40
+    
+    const THEME_COLOR_BUTTON = #53
+    const THEME_COLOR_BUTTON_HOVER = #5c
+    const THEME_COLOR_BUTTON_PRESSED = #33
+    const THEME_COLOR_BUTTON_TOP = #6d
+    const THEME_COLOR_BUTTON_SHADOW = #2f
+    const THEME_COLOR_BUTTON_SHADOW_HOVER = #1f
+    
+    const THEME_COLOR_CHECK_WELL = #2
+    const THEME_COLOR_CHECK_MARK = #f
+    
+    const THEME_COLOR_DESKTOP_BUTTON = #3
+    const THEME_COLOR_DESKTOP_BUTTON_HOVER = #6
+    const THEME_COLOR_DESKTOP_BUTTON_PRESSED = #9
+    const THEME_COLOR_DESKTOP_BUTTON_ICON = #f
+    
+    const THEME_COLOR_POPUP = #0
+    const THEME_COLOR_POPUP_ITEM_HOVER = #4
+    const THEME_COLOR_DROP_DOWN = #2
+    const THEME_COLOR_DROP_DOWN_HOVER = #3
+    const THEME_COLOR_DROP_DOWN_ARROW = #8
+    const THEME_COLOR_FOLD_BUTTON = #a
+    
+    const THEME_COLOR_SLIDER_TRACK = #38
+    const THEME_COLOR_SLIDER_TRACK_FOCUS = #30
+    const THEME_COLOR_SLIDER = #5
+    const THEME_COLOR_SLIDER_HOVER = #68
+    const THEME_COLOR_SLIDER_NUB = #8
+    const THEME_COLOR_SLIDER_NUB_HOVER = #f
+    const THEME_COLOR_SLIDER_NUB_FOCUS = #c
+    
     const THEME_TAB_HEIGHT = 26.0,
     const THEME_SPLITTER_HORIZONTAL = 16.0,
     const THEME_SPLITTER_MIN_HORIZONTAL = (THEME_TAB_HEIGHT),
@@ -235,7 +280,7 @@ live_design!{
         width: Fit
         height: Fit
         draw_text: {
-            color: #8,
+            color: (THEME_COLOR_TEXT_LABEL),
             text_style: <THEME_FONT_LABEL>{}
             wrap: Word
         }
@@ -266,11 +311,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #9,
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #9,
+                    THEME_COLOR_TEXT_CONTROL,
                     self.pressed
                 )
             }
@@ -282,11 +327,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #9,
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #9,
+                    THEME_COLOR_TEXT_CONTROL,
                     self.pressed
                 )
             }
@@ -301,11 +346,11 @@ live_design!{
                 let sdf = Sdf2d::viewport(self.pos * self.rect_size);
                 let grad_top = 5.0;
                 let grad_bot = 1.0;
-                let body = mix(mix(#53, #5c, self.hover), #33, self.pressed);
+                let body = mix(mix(THEME_COLOR_BUTTON, THEME_COLOR_BUTTON_HOVER, self.hover), THEME_COLOR_BUTTON_PRESSED, self.pressed);
                 let body_transp = vec4(body.xyz, 0.0);
-                let top_gradient = mix(body_transp, mix(#6d, #1f, self.pressed), max(0.0, grad_top - sdf.pos.y) / grad_top);
+                let top_gradient = mix(body_transp, mix(THEME_COLOR_BUTTON_TOP, THEME_COLOR_BUTTON_SHADOW_HOVER, self.pressed), max(0.0, grad_top - sdf.pos.y) / grad_top);
                 let bot_gradient = mix(
-                    mix(body_transp, #5c, self.pressed),
+                    mix(body_transp, THEME_COLOR_BUTTON_HOVER, self.pressed),
                     top_gradient,
                     clamp((self.rect_size.y - grad_bot - sdf.pos.y - 1.0) / grad_bot, 0.0, 1.0)
                 );
@@ -315,7 +360,7 @@ live_design!{
                 sdf.move_to(shift_inward, self.rect_size.y - self.border_radius);
                 sdf.line_to(self.rect_size.x - shift_inward, self.rect_size.y - self.border_radius);
                 sdf.stroke(
-                    mix(mix(#2f, #1f, self.hover), #0000, self.pressed),
+                    mix(mix(THEME_COLOR_BUTTON_SHADOW, THEME_COLOR_BUTTON_SHADOW_HOVER, self.hover), #0000, self.pressed),
                     self.border_radius
                 )
                 
@@ -410,31 +455,31 @@ live_design!{
                         sdf.move_to(left + 4.0, c.y);
                         sdf.line_to(c.x, c.y + szs);
                         sdf.line_to(c.x + szs, c.y - szs);
-                        sdf.stroke(mix(#fff0, #f, self.selected), 1.25);
+                        sdf.stroke(mix(#fff0, THEME_COLOR_CHECK_MARK, self.selected), 1.25);
                     }
                     CheckType::Radio => {
                         let sz = self.size;
                         let left = sz + 1.;
                         let c = vec2(left + sz, self.rect_size.y * 0.5);
                         sdf.circle(left, c.y, sz);
-                        sdf.fill(#2);
+                        sdf.fill(THEME_COLOR_CHECK_WELL);
                         let isz = sz * 0.5;
                         sdf.circle(left, c.y, isz);
-                        sdf.fill(mix(#fff0, #f, self.selected));
+                        sdf.fill(mix(#fff0, THEME_COLOR_CHECK_MARK, self.selected));
                     }
                     CheckType::Toggle => {
                         let sz = self.size;
                         let left = sz + 1.;
                         let c = vec2(left + sz, self.rect_size.y * 0.5);
                         sdf.box(left, c.y - sz, sz * 3.0, sz * 2.0, 0.5 * sz);
-                        sdf.fill(#2);
+                        sdf.fill(THEME_COLOR_CHECK_WELL);
                         let isz = sz * 0.5;
                         sdf.circle(left + sz + self.selected * sz, c.y, isz);
                         sdf.circle(left + sz + self.selected * sz, c.y, 0.5 * isz);
                         sdf.subtract();
                         sdf.circle(left + sz + self.selected * sz, c.y, isz);
                         sdf.blend(self.selected)
-                        sdf.fill(#f);
+                        sdf.fill(THEME_COLOR_CHECK_MARK);
                     }
                     CheckType::None => {
                         return #0000
@@ -444,7 +489,7 @@ live_design!{
             }
         }
         draw_text: {
-            color: #9,
+            color: (THEME_COLOR_TEXT_CONTROL),
             instance focus: 0.0
             instance selected: 0.0
             instance hover: 0.0
@@ -457,11 +502,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #fff6,
-                        #fff6,
+                        THEME_COLOR_TEXT_CHECK_BOX,
+                        THEME_COLOR_TEXT_CHECK_BOX,
                         self.hover
                     ),
-                    #fff6,
+                    THEME_COLOR_TEXT_CHECK_BOX,
                     self.selected
                 )
             }
@@ -474,11 +519,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #9,
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #f,
+                    THEME_COLOR_TEXT_CONTROL_ACTIVE,
                     self.selected
                 )
             }
@@ -558,40 +603,40 @@ live_design!{
                 // WindowsMin
                 match self.button_type {
                     DesktopButtonType::WindowsMin => {
-                        sdf.clear(mix(#3, mix(#6, #9, self.pressed), self.hover));
+                        sdf.clear(mix(THEME_COLOR_DESKTOP_BUTTON, mix(THEME_COLOR_DESKTOP_BUTTON_HOVER, THEME_COLOR_DESKTOP_BUTTON_PRESSED, self.pressed), self.hover));
                         sdf.move_to(c.x - sz, c.y);
                         sdf.line_to(c.x + sz, c.y);
-                        sdf.stroke(#f, 0.5 + 0.5 * self.dpi_dilate);
+                        sdf.stroke(THEME_COLOR_DESKTOP_BUTTON_ICON, 0.5 + 0.5 * self.dpi_dilate);
                         return sdf.result;
                     }
                     DesktopButtonType::WindowsMax => {
-                        sdf.clear(mix(#3, mix(#6, #9, self.pressed), self.hover));
+                        sdf.clear(mix(THEME_COLOR_DESKTOP_BUTTON, mix(THEME_COLOR_DESKTOP_BUTTON_HOVER, THEME_COLOR_DESKTOP_BUTTON_PRESSED, self.pressed), self.hover));
                         sdf.rect(c.x - sz, c.y - sz, 2. * sz, 2. * sz);
-                        sdf.stroke(#f, 0.5 + 0.5 * self.dpi_dilate);
+                        sdf.stroke(THEME_COLOR_DESKTOP_BUTTON_ICON, 0.5 + 0.5 * self.dpi_dilate);
                         return sdf.result;
                     }
                     DesktopButtonType::WindowsMaxToggled => {
-                        let clear = mix(#3, mix(#6, #9, self.pressed), self.hover);
+                        let clear = mix(THEME_COLOR_DESKTOP_BUTTON, mix(THEME_COLOR_DESKTOP_BUTTON_HOVER, THEME_COLOR_DESKTOP_BUTTON_PRESSED, self.pressed), self.hover);
                         sdf.clear(clear);
                         let sz = 3.5;
                         sdf.rect(c.x - sz + 1., c.y - sz - 1., 2. * sz, 2. * sz);
-                        sdf.stroke(#f, 0.5 + 0.5 * self.dpi_dilate);
+                        sdf.stroke(THEME_COLOR_DESKTOP_BUTTON_ICON, 0.5 + 0.5 * self.dpi_dilate);
                         sdf.rect(c.x - sz - 1., c.y - sz + 1., 2. * sz, 2. * sz);
                         sdf.fill_keep(clear);
-                        sdf.stroke(#f, 0.5 + 0.5 * self.dpi_dilate);
+                        sdf.stroke(THEME_COLOR_DESKTOP_BUTTON_ICON, 0.5 + 0.5 * self.dpi_dilate);
                         return sdf.result;
                     }
                     DesktopButtonType::WindowsClose => {
-                        sdf.clear(mix(#3, mix(#e00, #c00, self.pressed), self.hover));
+                        sdf.clear(mix(THEME_COLOR_DESKTOP_BUTTON, mix(#e00, #c00, self.pressed), self.hover));
                         sdf.move_to(c.x - sz, c.y - sz);
                         sdf.line_to(c.x + sz, c.y + sz);
                         sdf.move_to(c.x - sz, c.y + sz);
                         sdf.line_to(c.x + sz, c.y - sz);
-                        sdf.stroke(#f, 0.5 + 0.5 * self.dpi_dilate);
+                        sdf.stroke(THEME_COLOR_DESKTOP_BUTTON_ICON, 0.5 + 0.5 * self.dpi_dilate);
                         return sdf.result;
                     }
                     DesktopButtonType::XRMode => {
-                        sdf.clear(mix(#3, mix(#0aa, #077, self.pressed), self.hover));
+                        sdf.clear(mix(THEME_COLOR_DESKTOP_BUTTON, mix(#0aa, #077, self.pressed), self.hover));
                         let w = 12.;
                         let h = 8.;
                         sdf.box(c.x - w, c.y - h, 2. * w, 2. * h, 2.);
@@ -608,7 +653,7 @@ live_design!{
                     }
                     DesktopButtonType::Fullscreen => {
                         sz = 8.;
-                        sdf.clear(mix(#3, mix(#6, #9, self.pressed), self.hover));
+                        sdf.clear(mix(THEME_COLOR_DESKTOP_BUTTON, mix(THEME_COLOR_DESKTOP_BUTTON_HOVER, THEME_COLOR_DESKTOP_BUTTON_PRESSED, self.pressed), self.hover));
                         sdf.rect(c.x - sz, c.y - sz, 2. * sz, 2. * sz);
                         sdf.rect(c.x - sz + 1.5, c.y - sz + 1.5, 2. * (sz - 1.5), 2. * (sz - 1.5));
                         sdf.subtract();
@@ -616,7 +661,7 @@ live_design!{
                         sdf.subtract();
                         sdf.rect(c.x - sz - 2., c.y - sz + 4., 2. * (sz + 2.), 2. * (sz - 4.));
                         sdf.subtract();
-                        sdf.fill(#f); //, 0.5 + 0.5 * dpi_dilate);
+                        sdf.fill(THEME_COLOR_DESKTOP_BUTTON_ICON); //, 0.5 + 0.5 * dpi_dilate);
                         
                         return sdf.result;
                     }
@@ -1074,8 +1119,8 @@ live_design!{
         draw_bg: {
             instance selected: 0.0
             instance hover: 0.0
-            instance color: #0
-            instance color_selected: #4
+            instance color: (THEME_COLOR_POPUP)
+            instance color_selected: (THEME_COLOR_POPUP_ITEM_HOVER)
             
             fn pixel(self) -> vec4 {
                 let sdf = Sdf2d::viewport(self.pos * self.rect_size);
@@ -1096,7 +1141,7 @@ live_design!{
                 sdf.move_to(c.x - sz + dx * 0.5, c.y - sz + dx);
                 sdf.line_to(c.x, c.y + sz);
                 sdf.line_to(c.x + sz, c.y - sz);
-                sdf.stroke(mix(#fff0, #f, self.selected), 1.0);
+                sdf.stroke(mix(#fff0, THEME_COLOR_CHECK_MARK, self.selected), 1.0);
                 
                 return sdf.result;
             }
@@ -1154,7 +1199,7 @@ live_design!{
         height: Fit
         
         draw_bg: {
-            instance color: #0
+            instance color: (THEME_COLOR_POPUP)
             instance border_width: 0.0,
             instance border_color: #0000,
             instance inset: vec4(0.0, 0.0, 0.0, 0.0),
@@ -1193,14 +1238,14 @@ live_design!{
                 return mix(
                     mix(
                         mix(
-                            #9,
-                            #b,
+                            THEME_COLOR_TEXT_CONTROL,
+                            THEME_COLOR_TEXT_CONTROL_FOCUS,
                             self.focus
                         ),
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #9,
+                    THEME_COLOR_TEXT_CONTROL,
                     self.pressed
                 )
             }
@@ -1220,7 +1265,7 @@ live_design!{
                     self.rect_size.y,
                     self.border_radius
                 )
-                sdf.fill(mix(#2, #3, self.hover));
+                sdf.fill(mix(THEME_COLOR_DROP_DOWN, THEME_COLOR_DROP_DOWN_HOVER, self.hover));
             }
             
             fn pixel(self) -> vec4 {
@@ -1235,7 +1280,7 @@ live_design!{
                 sdf.line_to(c.x, c.y + sz * 0.75);
                 sdf.close_path();
                 
-                sdf.fill(mix(#8, #c, self.hover));
+                sdf.fill(mix(THEME_COLOR_DROP_DOWN_ARROW, THEME_COLOR_TEXT_CONTROL_HOVER, self.hover));
                 
                 return sdf.result
             }
@@ -1530,7 +1575,7 @@ live_design!{
                 sdf.line_to(c.x, c.y - sz);
                 sdf.line_to(c.x + sz, c.y + sz);
                 sdf.close_path();
-                sdf.fill(mix(#a, #f, self.hover));
+                sdf.fill(mix(THEME_COLOR_FOLD_BUTTON, THEME_COLOR_TEXT_CONTROL_ACTIVE, self.hover));
                 return sdf.result * self.fade;
             }
         }
@@ -1631,11 +1676,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #9,
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #9,
+                    THEME_COLOR_TEXT_CONTROL,
                     self.pressed
                 )
             }
@@ -1730,10 +1775,10 @@ live_design!{
                         let left = sz + 1.;
                         let c = vec2(left + sz, self.rect_size.y * 0.5);
                         sdf.circle(left, c.y, sz);
-                        sdf.fill(#2);
+                        sdf.fill(THEME_COLOR_CHECK_WELL);
                         let isz = sz * 0.5;
                         sdf.circle(left, c.y, isz);
-                        sdf.fill(mix(#fff0, #f, self.selected));
+                        sdf.fill(mix(#fff0, THEME_COLOR_CHECK_MARK, self.selected));
                     }
                     RadioType::Tab => {
                         let sz = self.size;
@@ -1760,7 +1805,7 @@ live_design!{
             uniform color_unselected_hover: #x000000CC
             uniform color_selected: #xFFFFFF66
             
-            color: #9
+            color: (THEME_COLOR_TEXT_CONTROL)
             text_style: {
                 font: {
                     //path: d"resources/ibmplexsans-semibold.ttf"
@@ -1787,11 +1832,11 @@ live_design!{
             fn get_color(self) -> vec4 {
                 return mix(
                     mix(
-                        #9,
-                        #c,
+                        THEME_COLOR_TEXT_CONTROL,
+                        THEME_COLOR_TEXT_CONTROL_HOVER,
                         self.hover
                     ),
-                    #9,
+                    THEME_COLOR_TEXT_CONTROL,
                     self.selected
                 )
             }
@@ -1910,14 +1955,14 @@ live_design!{
                 mix(
                     mix(
                         mix(
-                            #xFFFFFF55,
-                            #xFFFFFF88,
+                            THEME_COLOR_TEXT_INPUT,
+                            THEME_COLOR_TEXT_INPUT_HOVER,
                             self.hover
                         ),
-                        #xFFFFFFCC,
+                        THEME_COLOR_TEXT_INPUT_FOCUS,
                         self.focus
                     ),
-                    #3,
+                    THEME_COLOR_TEXT_INPUT_EMPTY,
                     self.is_empty
                 )
             }
@@ -1934,7 +1979,7 @@ live_design!{
                     self.rect_size.y,
                     self.border_radius
                 )
-                sdf.fill(mix(#ccc0, #f, self.focus));
+                sdf.fill(mix(#ccc0, THEME_COLOR_TEXT_INPUT_CURSOR, self.focus));
                 return sdf.result
             }
         }
@@ -1954,7 +1999,7 @@ live_design!{
                     self.rect_size.y,
                     self.border_radius
                 )
-                sdf.fill(mix(#5550, #xFFFFFF40, self.focus)); // Pad color
+                sdf.fill(mix(#5550, THEME_COLOR_TEXT_INPUT_SELECTION, self.focus)); // Pad color
                 return sdf.result
             }
         }
@@ -2068,10 +2113,10 @@ live_design!{
                 
                 let sdf = Sdf2d::viewport(self.pos * self.rect_size)
                 
-                let slider_bg_color = mix(#38, #30, self.focus);
-                let slider_color = mix(mix(#5, #68, self.hover), #68, self.focus);
-                let nub_color = mix(mix(#8, #f, self.hover), mix(#c, #f, self.drag), self.focus);
-                let nubbg_color = mix(#eee0, #8, self.drag);
+                let slider_bg_color = mix(THEME_COLOR_SLIDER_TRACK, THEME_COLOR_SLIDER_TRACK_FOCUS, self.focus);
+                let slider_color = mix(mix(THEME_COLOR_SLIDER, THEME_COLOR_SLIDER_HOVER, self.hover), THEME_COLOR_SLIDER_HOVER, self.focus);
+                let nub_color = mix(mix(THEME_COLOR_SLIDER_NUB, THEME_COLOR_SLIDER_NUB_HOVER, self.hover), mix(THEME_COLOR_SLIDER_NUB_FOCUS, THEME_COLOR_SLIDER_NUB_HOVER, self.drag), self.focus);
+                let nubbg_color = mix(#eee0, THEME_COLOR_SLIDER_NUB, self.drag);
                 
                 match self.slider_type {
                     SliderType::Horizontal => {
@@ -2102,7 +2147,7 @@ live_design!{
         }
         
         draw_text: {
-            color: #9
+            color: (THEME_COLOR_TEXT_CONTROL)
         }
         
         label_walk: {
@@ -2126,7 +2171,7 @@ live_design!{
             numeric_only: true,
             draw_bg: {
                 shape: None
-                color: #5
+                color: (THEME_COLOR_SLIDER)
                 radius: 2.0
             },
             
@@ -0,0 +1,57 @@
+use crate::makepad_platform::*;
+
+// redefines the colors of theme_desktop_dark, see crate::theme for how it gets applied
+live_design!{
+    const THEME_BRIGHTNESS = #x00
+    const THEME_COLOR_HIGHLIGHT = #x1C5FD0
+    
+    const THEME_COLOR_UP_80 = #FFFFFFFF
+    const THEME_COLOR_UP_50 = #FFFFFFEE
+    const THEME_COLOR_UP_25 = #FFFFFFAA
+    const THEME_COLOR_UP_15 = #FFFFFF77
+    const THEME_COLOR_UP_10 = #FFFFFF55
+    const THEME_COLOR_UP_4 = #FFFFFF30
+    
+    const THEME_COLOR_TEXT_LABEL = #f
+    const THEME_COLOR_TEXT_CONTROL = #f
+    const THEME_COLOR_TEXT_CONTROL_FOCUS = #xFFE000
+    const THEME_COLOR_TEXT_CONTROL_HOVER = #xFFE000
+    const THEME_COLOR_TEXT_CONTROL_ACTIVE = #xFFE000
+    const THEME_COLOR_TEXT_CHECK_BOX = #f
+    const THEME_COLOR_TEXT_INPUT = #f
+    const THEME_COLOR_TEXT_INPUT_HOVER = #f
+    const THEME_COLOR_TEXT_INPUT_FOCUS = #xFFE000
+    const THEME_COLOR_TEXT_INPUT_EMPTY = #x90
+    const THEME_COLOR_TEXT_INPUT_CURSOR = #xFFE000
+    const THEME_COLOR_TEXT_INPUT_SELECTION = #x1C5FD0
+    
+    const THEME_COLOR_BUTTON = #x00
+    const THEME_COLOR_BUTTON_HOVER = #x30
+    const THEME_COLOR_BUTTON_PRESSED = #x1C5FD0
+    const THEME_COLOR_BUTTON_TOP = #x80
+    const THEME_COLOR_BUTTON_SHADOW = #f
+    const THEME_COLOR_BUTTON_SHADOW_HOVER = #xFFE000
+    
+    const THEME_COLOR_CHECK_WELL = #x50
+    const THEME_COLOR_CHECK_MARK = #xFFE000
+    
+    const THEME_COLOR_DESKTOP_BUTTON = #x00
+    const THEME_COLOR_DESKTOP_BUTTON_HOVER = #x40
+    const THEME_COLOR_DESKTOP_BUTTON_PRESSED = #x80
+    const THEME_COLOR_DESKTOP_BUTTON_ICON = #f
+    
+    const THEME_COLOR_POPUP = #x20
+    const THEME_COLOR_POPUP_ITEM_HOVER = #x1C5FD0
+    const THEME_COLOR_DROP_DOWN = #x20
+    const THEME_COLOR_DROP_DOWN_HOVER = #x40
+    const THEME_COLOR_DROP_DOWN_ARROW = #f
+    const THEME_COLOR_FOLD_BUTTON = #f
+    
+    const THEME_COLOR_SLIDER_TRACK = #x60
+    const THEME_COLOR_SLIDER_TRACK_FOCUS = #x80
+    const THEME_COLOR_SLIDER = #f
+    const THEME_COLOR_SLIDER_HOVER = #xFFE000
+    const THEME_COLOR_SLIDER_NUB = #f
+    const THEME_COLOR_SLIDER_NUB_HOVER = #xFFE000
+    const THEME_COLOR_SLIDER_NUB_FOCUS = #xFFE000
+}
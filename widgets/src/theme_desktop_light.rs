@@ -0,0 +1,61 @@
+use crate::makepad_platform::*;
+
+// redefines the colors of theme_desktop_dark, see crate::theme for how it gets applied
+live_design!{
+    const THEME_BRIGHTNESS = #xE4
+    const THEME_COLOR_HIGHLIGHT = #xB4CCEE
+    
+    const THEME_COLOR_UP_80 = #000000CC
+    const THEME_COLOR_UP_50 = #00000099
+    const THEME_COLOR_UP_25 = #00000055
+    const THEME_COLOR_UP_15 = #00000030
+    const THEME_COLOR_UP_10 = #00000020
+    const THEME_COLOR_UP_4 = #0000000A
+    const THEME_COLOR_DOWN_7 = #FFFFFF26
+    const THEME_COLOR_DOWN_10 = #FFFFFF50
+    const THEME_COLOR_DOWN_20 = #FFFFFF66
+    const THEME_COLOR_DOWN_50 = #FFFFFFAA
+    
+    const THEME_COLOR_TEXT_LABEL = #5
+    const THEME_COLOR_TEXT_CONTROL = #3
+    const THEME_COLOR_TEXT_CONTROL_FOCUS = #2
+    const THEME_COLOR_TEXT_CONTROL_HOVER = #1
+    const THEME_COLOR_TEXT_CONTROL_ACTIVE = #0
+    const THEME_COLOR_TEXT_CHECK_BOX = #0009
+    const THEME_COLOR_TEXT_INPUT = #00000088
+    const THEME_COLOR_TEXT_INPUT_HOVER = #000000AA
+    const THEME_COLOR_TEXT_INPUT_FOCUS = #000000DD
+    const THEME_COLOR_TEXT_INPUT_EMPTY = #xA0
+    const THEME_COLOR_TEXT_INPUT_CURSOR = #0
+    const THEME_COLOR_TEXT_INPUT_SELECTION = #x3070E040
+    
+    const THEME_COLOR_BUTTON = #xF4
+    const THEME_COLOR_BUTTON_HOVER = #xFA
+    const THEME_COLOR_BUTTON_PRESSED = #xD4
+    const THEME_COLOR_BUTTON_TOP = #xFF
+    const THEME_COLOR_BUTTON_SHADOW = #xB8
+    const THEME_COLOR_BUTTON_SHADOW_HOVER = #xA8
+    
+    const THEME_COLOR_CHECK_WELL = #xC8
+    const THEME_COLOR_CHECK_MARK = #2
+    
+    const THEME_COLOR_DESKTOP_BUTTON = #xE4
+    const THEME_COLOR_DESKTOP_BUTTON_HOVER = #xCC
+    const THEME_COLOR_DESKTOP_BUTTON_PRESSED = #xB4
+    const THEME_COLOR_DESKTOP_BUTTON_ICON = #2
+    
+    const THEME_COLOR_POPUP = #xFA
+    const THEME_COLOR_POPUP_ITEM_HOVER = #xDC
+    const THEME_COLOR_DROP_DOWN = #xF4
+    const THEME_COLOR_DROP_DOWN_HOVER = #xFA
+    const THEME_COLOR_DROP_DOWN_ARROW = #6
+    const THEME_COLOR_FOLD_BUTTON = #6
+    
+    const THEME_COLOR_SLIDER_TRACK = #xC8
+    const THEME_COLOR_SLIDER_TRACK_FOCUS = #xBC
+    const THEME_COLOR_SLIDER = #xA0
+    const THEME_COLOR_SLIDER_HOVER = #x88
+    const THEME_COLOR_SLIDER_NUB = #6
+    const THEME_COLOR_SLIDER_NUB_HOVER = #2
+    const THEME_COLOR_SLIDER_NUB_FOCUS = #4
+}
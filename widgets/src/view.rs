@@ -503,6 +503,15 @@ impl Widget for View {
         }
     }
     
+    fn live_read_state(&self, cx: &mut Cx, out: &mut Vec<LiveNode>) {
+        self.visible.live_read_to(live_id!(visible), out);
+        for id in &self.draw_order {
+            if let Some(child) = self.children.get(id) {
+                child.live_read_state_to(cx, id.as_instance(), out);
+            }
+        }
+    }
+    
     fn is_visible(&self) -> bool {
         self.visible
     }
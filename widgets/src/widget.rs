@@ -60,6 +60,10 @@ pub trait Widget: LiveApply {
     fn widget_to_data(&self, _cx: &mut Cx, _actions: &WidgetActions, _nodes: &mut LiveNodeVec, _path: &[LiveId]) -> bool {false}
     fn data_to_widget(&mut self, _cx: &mut Cx, _nodes: &[LiveNode], _path: &[LiveId]) {}
     
+    // writes the state worth keeping across restarts as fields, child widgets as instance props.
+    // apply_over of the result brings it back
+    fn live_read_state(&self, _cx: &mut Cx, _out: &mut Vec<LiveNode>) {}
+    
     fn draw_walk_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw;
     fn walk(&self) -> Walk {Walk::default()}
     fn redraw(&mut self, _cx: &mut Cx);
@@ -438,6 +442,24 @@ impl WidgetRef {
         }
     }
     
+    pub fn live_read_state(&self, cx: &mut Cx) -> Vec<LiveNode> {
+        let mut out = Vec::new();
+        self.live_read_state_to(cx, LiveProp::field(LiveId(0)), &mut out);
+        out
+    }
+    
+    pub fn live_read_state_to(&self, cx: &mut Cx, prop: LiveProp, out: &mut Vec<LiveNode>) {
+        if let Some(inner) = self.0.borrow().as_ref() {
+            out.push(LiveNode {
+                origin: LiveNodeOrigin::empty().with_prop_type(prop.1),
+                id: prop.0,
+                value: LiveValue::Object
+            });
+            inner.live_read_state(cx, out);
+            out.close();
+        }
+    }
+    
     pub fn find_widgets(
         &mut self,
         path: &[LiveId],